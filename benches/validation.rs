@@ -0,0 +1,138 @@
+//! Benchmarks of the validators, run with `cargo bench`. See benches/README.md for the baseline.
+
+use base64::{engine::general_purpose, Engine};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use serde_json::{json, Value};
+use spector::{
+    attestations::decode_dsse_statement,
+    models::{
+        intoto::{options::ParseOptions, statement::InTotoStatementV1},
+        sbom::spdx23::Spdx23,
+    },
+    schema::registry::SchemaRegistry,
+    validate::{report::ValidationReport, GenericValidator, JSONSchemaValidator, Validator},
+};
+
+/// Numbers of packages in the generated SBOMs.
+const SBOM_SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!(
+        "{}/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    ))
+    .unwrap()
+}
+
+/// Returns an SPDX 2.3 SBOM with `packages` packages, made by repeating those of the fixture SBOM
+/// under distinct names, with a relationship from the document to each.
+fn sbom(packages: usize) -> Value {
+    let mut document = serde_json::from_str::<Value>(&fixture("spdx_v23_sbom.json")).unwrap();
+    let templates = document["packages"].as_array().unwrap().clone();
+    let packages = (0..packages)
+        .map(|i| {
+            let mut package = templates[i % templates.len()].clone();
+            let name = format!("{}-{}", package["name"].as_str().unwrap(), i);
+            let purl = package["externalRefs"][0]["referenceLocator"]
+                .as_str()
+                .unwrap()
+                .replacen('@', &format!("-{}@", i), 1);
+            package["SPDXID"] = json!(format!("SPDXRef-Package-{}", i));
+            package["name"] = json!(name);
+            package["externalRefs"][0]["referenceLocator"] = json!(purl);
+            package
+        })
+        .collect::<Vec<_>>();
+    document["relationships"] = packages
+        .iter()
+        .map(|p| {
+            json!({
+                "spdxElementId": "SPDXRef-DOCUMENT",
+                "relationshipType": "DESCRIBES",
+                "relatedSpdxElement": p["SPDXID"]
+            })
+        })
+        .collect();
+    document["packages"] = json!(packages);
+    document
+}
+
+fn statement_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("statement_parsing");
+    for name in ["slsa_provenance_v1.json", "slsa_provenance_v02.json"] {
+        let document = fixture(name);
+        group.throughput(Throughput::Bytes(document.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &document,
+            |b, document| {
+                b.iter(|| {
+                    let value = serde_json::from_str::<Value>(document).unwrap();
+                    let mut report = ValidationReport::new();
+                    InTotoStatementV1::from_value_with_options(
+                        value,
+                        &ParseOptions::default(),
+                        &mut report,
+                    )
+                    .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn schema_validation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("schema_validation");
+    let schema = SchemaRegistry::get("slsa-provenance-v1").unwrap().schema();
+    let document = serde_json::from_str::<Value>(&fixture("slsa_provenance_v1.json")).unwrap();
+    let validator = JSONSchemaValidator::<Value>::new(&schema);
+    group.bench_function("slsa-provenance-v1", |b| {
+        b.iter(|| validator.validate(black_box(&document)).unwrap())
+    });
+    group.finish();
+}
+
+fn dsse_unwrapping(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dsse_unwrapping");
+    let statement = fixture("slsa_provenance_v1.json");
+    let envelope = json!({
+        "payloadType": "application/vnd.in-toto+json",
+        "payload": general_purpose::STANDARD.encode(&statement),
+        "signatures": [{"keyid": "", "sig": "c2lnbmF0dXJl"}]
+    });
+    group.throughput(Throughput::Bytes(statement.len() as u64));
+    group.bench_function("slsa-provenance-v1", |b| {
+        b.iter(|| decode_dsse_statement(black_box(&envelope)).unwrap())
+    });
+    group.finish();
+}
+
+fn sbom_validation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sbom_validation");
+    group.sample_size(10);
+    let schema = SchemaRegistry::get("spdx-v23").unwrap().schema();
+    let schema_validator = JSONSchemaValidator::<Spdx23>::new(&schema);
+    let serde_validator = GenericValidator::<Spdx23>::new();
+    for packages in SBOM_SIZES {
+        let document = sbom(packages);
+        group.throughput(Throughput::Bytes(document.to_string().len() as u64));
+        group.bench_with_input(BenchmarkId::new("serde", packages), &document, |b, d| {
+            b.iter(|| serde_validator.validate(d).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("schema", packages), &document, |b, d| {
+            b.iter(|| schema_validator.validate(d).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    statement_parsing,
+    schema_validation,
+    dsse_unwrapping,
+    sbom_validation
+);
+criterion_main!(benches);
@@ -0,0 +1,17 @@
+//! Validates CycloneDX attestations and runs the attestation rules on whatever validates.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_json::Value;
+use spector::models::cdxa::CycloneDxAttestation;
+use spector::validate::{rules::cdxa::check_cdxa, GenericValidator, Validator};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(value) = serde_json::from_slice::<Value>(data) else {
+        return;
+    };
+    if let Ok(document) = GenericValidator::<CycloneDxAttestation>::new().validate(&value) {
+        check_cdxa(&document);
+    }
+});
@@ -0,0 +1,31 @@
+//! Unwraps DSSE envelopes and Sigstore bundles, and checks whatever unwraps against the trusted
+//! root from the fixtures.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_json::Value;
+use spector::attestations::decode_dsse_statement;
+use spector::trust::{self, TrustedRoot};
+use spector::verify;
+use std::sync::OnceLock;
+
+fn trusted_root() -> &'static TrustedRoot {
+    static ROOT: OnceLock<TrustedRoot> = OnceLock::new();
+    ROOT.get_or_init(|| {
+        TrustedRoot::from_slice(include_bytes!(
+            "../../tests/fixtures/sigstore_trusted_root.json"
+        ))
+        .expect("the fixture trusted root is valid")
+    })
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(value) = serde_json::from_slice::<Value>(data) else {
+        return;
+    };
+    let _ = decode_dsse_statement(&value);
+    if let Ok(signed) = verify::signed_statement(&value) {
+        trust::check(trusted_root(), &[signed], &[]);
+    }
+});
@@ -0,0 +1,36 @@
+//! Compiles JSON schemas and validates the schema against itself, which exercises the
+//! validators on a document of the same shape as their keywords.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_json::Value;
+use spector::schema::cache::SchemaCache;
+
+/// Returns true if any reference points outside the schema, which would be fetched while
+/// compiling and make runs slow and irreproducible.
+fn has_remote_reference(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => map.iter().any(|(key, value)| match value {
+            Value::String(reference) if key == "$ref" => !reference.starts_with('#'),
+            _ => has_remote_reference(value),
+        }),
+        Value::Array(items) => items.iter().any(has_remote_reference),
+        _ => false,
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(schema) = serde_json::from_slice::<Value>(data) else {
+        return;
+    };
+    if has_remote_reference(&schema) {
+        return;
+    }
+    // A fresh cache per input, so the process doesn't hold on to every schema it has compiled.
+    if let Ok(compiled) = SchemaCache::new().get_or_compile(&schema) {
+        if let Err(errors) = compiled.validate(&schema) {
+            errors.for_each(drop);
+        }
+    }
+});
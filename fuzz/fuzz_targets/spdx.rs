@@ -0,0 +1,21 @@
+//! Validates SPDX documents with the typed 2.3 and 2.2 models, and streams the raw bytes through
+//! the package-by-package validator.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_json::Value;
+use spector::models::sbom::{spdx22::Spdx22Document, spdx23::Spdx23};
+use spector::schema::registry::SchemaRegistry;
+use spector::validate::{stream, GenericValidator, Validator};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(entry) = SchemaRegistry::get("spdx-v23") {
+        let _ = stream::validate_spdx(entry, data);
+    }
+    let Ok(value) = serde_json::from_slice::<Value>(data) else {
+        return;
+    };
+    let _ = GenericValidator::<Spdx23>::new().validate(&value);
+    let _ = GenericValidator::<Spdx22Document>::new().validate(&value);
+});
@@ -0,0 +1,28 @@
+//! Parses In-Toto statements with the default and strict options, and runs the statement rules
+//! and the policy checks on whatever parses.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_json::Value;
+use spector::attestations::check_statement;
+use spector::models::intoto::{options::ParseOptions, statement::InTotoStatementV1};
+use spector::validate::{policy::Policy, report::ValidationReport, rules};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(value) = serde_json::from_slice::<Value>(data) else {
+        return;
+    };
+    let mut strict = Policy::default();
+    strict.strict = true;
+    for options in [ParseOptions::default(), ParseOptions::strict()] {
+        let mut report = ValidationReport::new();
+        if let Ok(statement) =
+            InTotoStatementV1::from_value_with_options(value.clone(), &options, &mut report)
+        {
+            rules::check_statement(&statement, &strict);
+            let _ = serde_json::to_value(&statement);
+        }
+    }
+    check_statement(&value, &Policy::default());
+});
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+# Seeds each fuzz target's corpus with the test fixtures it can parse.
+set -euo pipefail
+
+cd "$(dirname "$0")"
+fixtures=../tests/fixtures
+
+seed() {
+    local target=$1
+    shift
+    mkdir -p "corpus/$target"
+    for fixture in "$@"; do
+        cp "$fixtures/$fixture" "corpus/$target/"
+    done
+}
+
+seed statement \
+    apko_build_v1.json \
+    in_toto_v1_empty_predicate.json \
+    jvm_build_info_v1.json \
+    slsa_provenance_v02.json \
+    slsa_provenance_v1.json \
+    slsa_provenance_v1_bad_digest.json \
+    slsa_provenance_v1_invalid.json \
+    slsa_provenance_v1_invalid_predicate.json \
+    slsa_provenance_v1_mismatched_predicate.json \
+    slsa_provenance_v1_purl_dependencies.json \
+    spdx_v23_sbom_attestation.json
+seed dsse slsa_provenance_v1_release.sigstore.json
+# Each line of the JSON Lines bundle is a DSSE envelope of its own.
+split -l 1 -d "$fixtures/slsa_provenance_v1_release.intoto.jsonl" corpus/dsse/intoto-
+seed spdx spdx_v23_sbom.json
+seed cyclonedx cdxa.json
+seed schema in_toto_v1_schema.json in_toto_v1_schema_breaking.json invalid_schema.json slsa_provenance_v1_schema.json
@@ -0,0 +1,234 @@
+//! Fetching and validation of attestations published by package registries.
+//!
+//! Registries publish attestations as signed envelopes, such as DSSE, wrapping In-Toto
+//! statements about the artifacts they host. The statements are decoded and validated like any other document, and
+//! their subjects are checked against the digest of the artifact they are published for.
+//!
+//! Signatures on the envelopes are not verified, so a passing check shows the attestations are
+//! well formed and describe the artifact, not who produced them.
+
+pub mod npm;
+pub mod pypi;
+
+use anyhow::{anyhow, bail, Result};
+use chrono::DateTime;
+use serde::Deserialize;
+use serde_json::Value;
+use std::time::Instant;
+use tracing::{debug, instrument};
+
+use crate::models::dsse::{Envelope, IN_TOTO_PAYLOAD_TYPE};
+use crate::models::intoto::{options::ParseOptions, statement::InTotoStatementV1};
+use crate::validate::{
+    policy::Policy,
+    report::{Finding, ValidationReport},
+    rules::{self, provenance::FreshnessRule},
+};
+
+/// A statement published for an artifact, with the findings of validating it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attestation {
+    pub predicate_type: String,
+    pub statement: Value,
+    pub report: ValidationReport,
+}
+
+/// Decodes the In-Toto statement wrapped in a DSSE envelope.
+pub fn decode_dsse_statement(envelope: &Value) -> Result<Value> {
+    let envelope =
+        Envelope::deserialize(envelope).map_err(|e| anyhow!("Invalid DSSE envelope: {}", e))?;
+    if !envelope.is_in_toto() {
+        bail!(
+            "Unexpected DSSE payload type {}, expected {}",
+            envelope.payload_type,
+            IN_TOTO_PAYLOAD_TYPE
+        );
+    }
+    envelope
+        .decode_payload()
+        .map_err(|e| anyhow!("DSSE payload is not valid JSON: {}", e))
+}
+
+/// Validates a statement and runs the semantic checks for the policy against it.
+///
+/// A statement that can't be parsed is reported as an `invalid-statement` error.
+#[instrument(
+    skip_all,
+    fields(predicate_type = statement.get("predicateType").and_then(serde_json::Value::as_str))
+)]
+pub fn check_statement(statement: &Value, policy: &Policy) -> ValidationReport {
+    let start = Instant::now();
+    let mut report = ValidationReport::new();
+    match InTotoStatementV1::from_value_with_options(
+        statement.clone(),
+        &ParseOptions::default(),
+        &mut report,
+    ) {
+        Ok(statement) => report.extend(rules::check_statement(&statement, policy).findings),
+        Err(err) => report.push(Finding::error("invalid-statement", "", err.to_string())),
+    }
+    debug!(
+        errors = report.errors().count(),
+        warnings = report.warnings().count(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Checked statement"
+    );
+    report
+}
+
+/// Checks that a subject of the statement has the artifact's digest, reporting a
+/// `subject-digest-mismatch` error if none does.
+pub fn check_subject_digest(
+    statement: &Value,
+    algorithm: &str,
+    digest: &str,
+    report: &mut ValidationReport,
+) {
+    let subjects = statement
+        .get("subject")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let matches = subjects.iter().any(|subject| {
+        subject
+            .pointer(&format!("/digest/{}", algorithm))
+            .and_then(Value::as_str)
+            .map(|d| d.eq_ignore_ascii_case(digest))
+            .unwrap_or(false)
+    });
+    if !matches {
+        report.push(Finding::error(
+            "subject-digest-mismatch",
+            "/subject",
+            format!(
+                "No subject has the artifact's {} digest {}",
+                algorithm, digest
+            ),
+        ));
+    }
+}
+
+/// Checks the age of the times the transparency log entries of a bundle were integrated, against
+/// the policy's maximum age. `path` is the pointer to the entries.
+///
+/// Sigstore bundles give `integratedTime` as Unix seconds, usually as a string.
+pub fn check_integrated_times(
+    entries: Option<&Value>,
+    path: &str,
+    policy: &Policy,
+    report: &mut ValidationReport,
+) {
+    let rule = FreshnessRule::from_policy(policy);
+    let entries = entries
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for (i, entry) in entries.iter().enumerate() {
+        let seconds = match entry.get("integratedTime") {
+            Some(Value::String(seconds)) => seconds.parse().ok(),
+            Some(seconds) => seconds.as_i64(),
+            None => None,
+        };
+        if let Some(time) = seconds.and_then(|seconds| DateTime::from_timestamp(seconds, 0)) {
+            rule.check_age(&time, &format!("{}/{}/integratedTime", path, i), report);
+        }
+    }
+}
+
+/// Fetches a URL, failing on error statuses.
+#[instrument(level = "debug")]
+fn get(url: &str) -> Result<Vec<u8>> {
+    let start = Instant::now();
+    let response = reqwest::blocking::get(url)
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| anyhow!("Failed to fetch {}: {}", url, e))?;
+    let bytes = response.bytes()?.to_vec();
+    debug!(
+        bytes = bytes.len(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Fetched"
+    );
+    Ok(bytes)
+}
+
+/// Fetches a URL and parses the response as JSON.
+fn get_json(url: &str) -> Result<Value> {
+    serde_json::from_slice(&get(url)?).map_err(|e| anyhow!("Invalid JSON from {}: {}", url, e))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use base64::{engine::general_purpose, Engine};
+    use serde_json::json;
+
+    /// Wraps a statement in a DSSE envelope.
+    pub(crate) fn envelope(statement: &Value) -> Value {
+        json!({
+            "payloadType": IN_TOTO_PAYLOAD_TYPE,
+            "payload": general_purpose::STANDARD.encode(statement.to_string()),
+            "signatures": [{"keyid": "", "sig": "c2lnbmF0dXJl"}]
+        })
+    }
+
+    #[test]
+    fn test_decode_dsse_statement() {
+        let statement = json!({"_type": "https://in-toto.io/Statement/v1"});
+        assert_eq!(
+            decode_dsse_statement(&envelope(&statement)).unwrap(),
+            statement
+        );
+
+        let mut other = envelope(&statement);
+        other["payloadType"] = json!("application/json");
+        assert!(decode_dsse_statement(&other).is_err());
+    }
+
+    #[test]
+    fn test_check_subject_digest() {
+        let statement = json!({
+            "subject": [{"name": "a", "digest": {"sha512": "ABCD"}}]
+        });
+        let mut report = ValidationReport::new();
+        check_subject_digest(&statement, "sha512", "abcd", &mut report);
+        assert!(!report.has_errors());
+
+        check_subject_digest(&statement, "sha512", "ef01", &mut report);
+        assert_eq!(
+            report.errors().next().unwrap().code,
+            "subject-digest-mismatch"
+        );
+    }
+
+    #[test]
+    fn test_check_integrated_times() {
+        let policy = Policy {
+            max_age_seconds: Some(86400),
+            ..Policy::default()
+        };
+        let now = chrono::Utc::now().timestamp();
+        let entries = json!([
+            {"integratedTime": now.to_string()},
+            {"integratedTime": now - 2 * 86400},
+            {"logIndex": "1"}
+        ]);
+
+        let mut report = ValidationReport::new();
+        check_integrated_times(Some(&entries), "/tlogEntries", &policy, &mut report);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![("attestation-stale", "/tlogEntries/1/integratedTime")]
+        );
+    }
+
+    #[test]
+    fn test_check_statement_invalid() {
+        let report = check_statement(&json!({"subject": []}), &Policy::default());
+        assert_eq!(report.errors().next().unwrap().code, "invalid-statement");
+    }
+}
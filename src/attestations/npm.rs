@@ -0,0 +1,311 @@
+//! Attestations published to the npm registry.
+//!
+//! Packages published with `npm publish --provenance` have two attestations: the SLSA
+//! provenance of the build, and the registry's publish attestation. Both have the package
+//! tarball as their subject, as a `pkg:npm` purl with its SHA-512 digest.
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose, Engine};
+use serde_json::Value;
+use sha2::{Digest, Sha512};
+use std::fmt;
+use std::str::FromStr;
+
+use super::{
+    check_integrated_times, check_statement, check_subject_digest, decode_dsse_statement, get,
+    get_json, Attestation,
+};
+use crate::validate::{
+    policy::Policy,
+    report::{Finding, ValidationReport},
+};
+
+/// The public npm registry.
+pub const DEFAULT_REGISTRY: &str = "https://registry.npmjs.org";
+
+/// The predicate type of the SLSA provenance npm publishes.
+pub const PROVENANCE_PREDICATE_TYPE: &str = "https://slsa.dev/provenance/v1";
+
+/// The predicate type of the npm publish attestation.
+pub const PUBLISH_PREDICATE_TYPE: &str =
+    "https://github.com/npm/attestation/tree/main/specs/publish/v0.1";
+
+/// A package name and version, written as `<name>@<version>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageSpec {
+    pub name: String,
+    pub version: String,
+}
+
+impl PackageSpec {
+    /// Returns the name as it appears in registry URLs, with the scope's slash escaped.
+    fn escaped_name(&self) -> String {
+        self.name.replace('/', "%2f")
+    }
+}
+
+impl FromStr for PackageSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.rsplit_once('@') {
+            Some((name, version)) if !name.is_empty() && !version.is_empty() => Ok(Self {
+                name: name.to_string(),
+                version: version.to_string(),
+            }),
+            _ => bail!("Invalid package {}, expected <name>@<version>", s),
+        }
+    }
+}
+
+impl fmt::Display for PackageSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.name, self.version)
+    }
+}
+
+/// The attestations of a package version and the findings of checking them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpmVerification {
+    /// The SHA-512 digest of the package tarball, hex-encoded.
+    pub tarball_sha512: String,
+    pub attestations: Vec<Attestation>,
+    /// Findings about the package as a whole, such as a missing attestation.
+    pub report: ValidationReport,
+}
+
+impl NpmVerification {
+    /// Returns true if the package or any of its attestations has errors.
+    pub fn has_errors(&self) -> bool {
+        self.report.has_errors() || self.attestations.iter().any(|a| a.report.has_errors())
+    }
+}
+
+/// Fetches the attestations and tarball of a package version and checks them.
+///
+/// Each attestation's statement is validated with the policy and must have the tarball as a
+/// subject. The tarball must also match the integrity the registry lists for it, and both the
+/// provenance and publish attestations must be present.
+pub fn fetch_and_validate(
+    registry: &str,
+    package: &PackageSpec,
+    policy: &Policy,
+) -> Result<NpmVerification> {
+    let registry = registry.trim_end_matches('/');
+    let metadata = get_json(&format!(
+        "{}/{}/{}",
+        registry,
+        package.escaped_name(),
+        package.version
+    ))?;
+    let tarball_url = metadata
+        .pointer("/dist/tarball")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("No tarball listed for {}", package))?;
+    let tarball = get(tarball_url)?;
+    let tarball_sha512 = Sha512::digest(&tarball)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+
+    let mut report = ValidationReport::new();
+    if let Some(integrity) = metadata.pointer("/dist/integrity").and_then(Value::as_str) {
+        let expected = format!(
+            "sha512-{}",
+            general_purpose::STANDARD.encode(Sha512::digest(&tarball))
+        );
+        if integrity != expected {
+            report.push(Finding::error(
+                "integrity-mismatch",
+                "/dist/integrity",
+                format!(
+                    "Tarball has integrity {}, but the registry lists {}",
+                    expected, integrity
+                ),
+            ));
+        }
+    }
+
+    let response = get_json(&format!(
+        "{}/-/npm/v1/attestations/{}@{}",
+        registry,
+        package.escaped_name(),
+        package.version
+    ))
+    .map_err(|e| anyhow!("No attestations found for {}: {}", package, e))?;
+    let bundles = response
+        .get("attestations")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("No attestations found for {}", package))?;
+
+    let mut attestations = Vec::new();
+    for (i, bundle) in bundles.iter().enumerate() {
+        let envelope = bundle
+            .pointer("/bundle/dsseEnvelope")
+            .ok_or_else(|| anyhow!("Attestation {} for {} has no DSSE envelope", i, package))?;
+        let statement = decode_dsse_statement(envelope)?;
+        let predicate_type = statement
+            .get("predicateType")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let mut attestation_report = check_statement(&statement, policy);
+        check_subject_digest(
+            &statement,
+            "sha512",
+            &tarball_sha512,
+            &mut attestation_report,
+        );
+        check_integrated_times(
+            bundle.pointer("/bundle/verificationMaterial/tlogEntries"),
+            "/bundle/verificationMaterial/tlogEntries",
+            policy,
+            &mut attestation_report,
+        );
+        attestations.push(Attestation {
+            predicate_type,
+            statement,
+            report: attestation_report,
+        });
+    }
+
+    for predicate_type in [PROVENANCE_PREDICATE_TYPE, PUBLISH_PREDICATE_TYPE] {
+        if !attestations
+            .iter()
+            .any(|a| a.predicate_type == predicate_type)
+        {
+            report.push(Finding::error(
+                "missing-attestation",
+                "/attestations",
+                format!("No attestation with predicateType {}", predicate_type),
+            ));
+        }
+    }
+
+    Ok(NpmVerification {
+        tarball_sha512,
+        attestations,
+        report,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestations::tests::envelope;
+    use crate::test_server::serve_routes;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    const TARBALL: &[u8] = b"package tarball";
+
+    fn statement(predicate_type: &str, predicate: Value, digest: &str) -> Value {
+        json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "pkg:npm/%40scope/example@1.0.0", "digest": {"sha512": digest}}],
+            "predicateType": predicate_type,
+            "predicate": predicate
+        })
+    }
+
+    fn provenance() -> Value {
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        serde_json::from_str::<Value>(&fixture).unwrap()["predicate"].clone()
+    }
+
+    /// Serves a package whose attestations have the given statements.
+    fn registry(statements: Vec<Value>) -> String {
+        let attestations = statements
+            .iter()
+            .map(|s| {
+                json!({"predicateType": s["predicateType"], "bundle": {"dsseEnvelope": envelope(s)}})
+            })
+            .collect::<Vec<_>>();
+        serve_routes(|url| {
+            let metadata = json!({
+                "dist": {
+                    "tarball": format!("{}/example-1.0.0.tgz", url),
+                    "integrity": format!(
+                        "sha512-{}",
+                        general_purpose::STANDARD.encode(Sha512::digest(TARBALL))
+                    )
+                }
+            });
+            HashMap::from([
+                (
+                    "/@scope%2fexample/1.0.0".to_string(),
+                    metadata.to_string().into_bytes(),
+                ),
+                (
+                    "/-/npm/v1/attestations/@scope%2fexample@1.0.0".to_string(),
+                    json!({ "attestations": attestations })
+                        .to_string()
+                        .into_bytes(),
+                ),
+                ("/example-1.0.0.tgz".to_string(), TARBALL.to_vec()),
+            ])
+        })
+        .0
+    }
+
+    fn sha512() -> String {
+        Sha512::digest(TARBALL)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    #[test]
+    fn test_package_spec() {
+        let spec = "@scope/example@1.0.0".parse::<PackageSpec>().unwrap();
+        assert_eq!(spec.name, "@scope/example");
+        assert_eq!(spec.version, "1.0.0");
+        assert_eq!(spec.escaped_name(), "@scope%2fexample");
+        assert!("@scope/example".parse::<PackageSpec>().is_err());
+        assert!("example@".parse::<PackageSpec>().is_err());
+    }
+
+    #[test]
+    fn test_fetch_and_validate() {
+        let registry = registry(vec![
+            statement(PROVENANCE_PREDICATE_TYPE, provenance(), &sha512()),
+            statement(
+                PUBLISH_PREDICATE_TYPE,
+                json!({"name": "@scope/example", "version": "1.0.0", "registry": "https://registry.npmjs.org"}),
+                &sha512(),
+            ),
+        ]);
+        let package = "@scope/example@1.0.0".parse().unwrap();
+
+        let verification = fetch_and_validate(&registry, &package, &Policy::default()).unwrap();
+        assert_eq!(verification.tarball_sha512, sha512());
+        assert_eq!(verification.attestations.len(), 2);
+        assert!(!verification.has_errors(), "{:?}", verification);
+    }
+
+    #[test]
+    fn test_fetch_and_validate_digest_mismatch() {
+        let registry = registry(vec![statement(
+            PROVENANCE_PREDICATE_TYPE,
+            provenance(),
+            &"0".repeat(128),
+        )]);
+        let package = "@scope/example@1.0.0".parse().unwrap();
+
+        let verification = fetch_and_validate(&registry, &package, &Policy::default()).unwrap();
+        let codes = verification.attestations[0]
+            .report
+            .errors()
+            .map(|f| f.code.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(codes, vec!["subject-digest-mismatch"]);
+        assert_eq!(
+            verification.report.errors().next().unwrap().code,
+            "missing-attestation"
+        );
+    }
+}
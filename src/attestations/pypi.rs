@@ -0,0 +1,532 @@
+//! PEP 740 attestations published to PyPI.
+//!
+//! PyPI serves the attestations of each distribution file through its integrity API, grouped
+//! into bundles by the Trusted Publisher that uploaded them. Each attestation's statement has
+//! the distribution file as its subject, with its SHA-256 digest, and is signed with a Fulcio
+//! certificate issued to the publisher's workflow.
+//!
+//! The publisher the integrity API names for a bundle is not signed, so whoever serves the
+//! response could name any publisher. The publisher is instead read from the signing
+//! certificate, once it is chained up to the trusted root and has verified the signature.
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose, Engine};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+
+use super::{
+    check_integrated_times, check_statement, check_subject_digest, get, get_json, Attestation,
+};
+use crate::models::dsse::{Envelope, Signature, IN_TOTO_PAYLOAD_TYPE};
+use crate::trust::{self, certificate::CertificateIdentity, TrustedRoot};
+use crate::validate::{
+    policy::{Policy, TrustedPublisher},
+    report::{Finding, ValidationReport},
+};
+use crate::verify::SignedStatement;
+
+/// The public Python package index.
+pub const DEFAULT_INDEX: &str = "https://pypi.org";
+
+/// The predicate type of the PyPI publish attestation.
+pub const PUBLISH_PREDICATE_TYPE: &str = "https://docs.pypi.org/attestations/publish/v1";
+
+/// The OIDC issuers of the Trusted Publishers whose certificates are understood.
+const GITHUB_ISSUER: &str = "https://token.actions.githubusercontent.com";
+const GITLAB_ISSUER: &str = "https://gitlab.com";
+
+/// A distribution file of a release, identified by its file name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributionFile {
+    /// The project name, normalized as in PEP 503.
+    pub project: String,
+    pub version: String,
+    pub filename: String,
+}
+
+impl FromStr for DistributionFile {
+    type Err = anyhow::Error;
+
+    /// Parses the project and version from a wheel or source distribution file name.
+    fn from_str(filename: &str) -> Result<Self> {
+        let name_and_version = if let Some(stem) = filename.strip_suffix(".whl") {
+            let mut parts = stem.splitn(3, '-');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(name), Some(version), Some(_)) => Some((name, version)),
+                _ => None,
+            }
+        } else {
+            [".tar.gz", ".zip"]
+                .iter()
+                .find_map(|extension| filename.strip_suffix(extension))
+                .and_then(|stem| stem.rsplit_once('-'))
+        };
+        match name_and_version {
+            Some((name, version)) if !name.is_empty() && !version.is_empty() => Ok(Self {
+                project: normalize(name),
+                version: version.to_string(),
+                filename: filename.to_string(),
+            }),
+            _ => bail!(
+                "Invalid distribution file {}, expected a wheel or source distribution",
+                filename
+            ),
+        }
+    }
+}
+
+impl fmt::Display for DistributionFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.filename)
+    }
+}
+
+/// Normalizes a project name as in PEP 503.
+fn normalize(name: &str) -> String {
+    let mut normalized = String::new();
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
+
+/// The attestations of a distribution file and the findings of checking them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PypiVerification {
+    /// The SHA-256 digest of the distribution file, hex-encoded.
+    pub sha256: String,
+    /// The publishers certified by the signing certificates of the attestations that verified.
+    pub publishers: Vec<TrustedPublisher>,
+    pub attestations: Vec<Attestation>,
+    /// Findings about the file as a whole, such as an untrusted publisher.
+    pub report: ValidationReport,
+}
+
+impl PypiVerification {
+    /// Returns true if the file or any of its attestations has errors.
+    pub fn has_errors(&self) -> bool {
+        self.report.has_errors() || self.attestations.iter().any(|a| a.report.has_errors())
+    }
+}
+
+/// Fetches the attestations of a distribution file and checks them.
+///
+/// The file is downloaded and must match the digest the index lists for it. Each attestation's
+/// signature must verify with its certificate, chained up to the trusted root as
+/// [`trust::verify_signature`] does, and its statement is validated with the policy and must
+/// have the file as a subject. The publisher the certificate was issued to must be one of the
+/// policy's trusted publishers, if it has any. Certificates don't record the deployment
+/// environment, so trusted publishers pinned to one never match.
+pub fn fetch_and_validate(
+    index: &str,
+    file: &DistributionFile,
+    policy: &Policy,
+    root: &TrustedRoot,
+) -> Result<PypiVerification> {
+    let index = index.trim_end_matches('/');
+    let release = get_json(&format!(
+        "{}/pypi/{}/{}/json",
+        index, file.project, file.version
+    ))?;
+    let listed = release
+        .get("urls")
+        .and_then(Value::as_array)
+        .and_then(|urls| {
+            urls.iter()
+                .find(|url| url.get("filename").and_then(Value::as_str) == Some(&file.filename))
+        })
+        .ok_or_else(|| anyhow!("No file {} in release {}", file, file.version))?;
+    let url = listed
+        .get("url")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("No download URL listed for {}", file))?;
+    let sha256 = Sha256::digest(get(url)?)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+
+    let mut report = ValidationReport::new();
+    if let Some(expected) = listed.pointer("/digests/sha256").and_then(Value::as_str) {
+        if !expected.eq_ignore_ascii_case(&sha256) {
+            report.push(Finding::error(
+                "digest-mismatch",
+                "/digests/sha256",
+                format!(
+                    "{} has SHA-256 {}, but the index lists {}",
+                    file, sha256, expected
+                ),
+            ));
+        }
+    }
+
+    let provenance = get_json(&format!(
+        "{}/integrity/{}/{}/{}/provenance",
+        index, file.project, file.version, file.filename
+    ))
+    .map_err(|e| anyhow!("No attestations found for {}: {}", file, e))?;
+    let bundles = provenance
+        .get("attestation_bundles")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("No attestations found for {}", file))?;
+
+    let mut publishers = Vec::new();
+    let mut attestations = Vec::new();
+    for bundle in bundles {
+        let bundle_attestations = bundle
+            .get("attestations")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for attestation in bundle_attestations {
+            let signed = signed_statement(attestation)?;
+            let predicate_type = signed
+                .statement
+                .get("predicateType")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let mut attestation_report = check_statement(&signed.statement, policy);
+            check_subject_digest(
+                &signed.statement,
+                "sha256",
+                &sha256,
+                &mut attestation_report,
+            );
+            check_integrated_times(
+                attestation.pointer("/verification_material/transparency_entries"),
+                "/verification_material/transparency_entries",
+                policy,
+                &mut attestation_report,
+            );
+            match trust::verify_signature(root, &signed, &[]) {
+                Ok(signer) => {
+                    let publisher = signer
+                        .identity()
+                        .map(certified_publisher)
+                        .unwrap_or_default();
+                    check_publisher(&publisher, policy, &mut attestation_report);
+                    if !publishers.contains(&publisher) {
+                        publishers.push(publisher);
+                    }
+                }
+                Err(mut finding) => {
+                    finding.path = match finding.code.as_str() {
+                        "untrusted-certificate" => "/verification_material/certificate",
+                        _ => "/envelope/signature",
+                    }
+                    .to_string();
+                    attestation_report.push(finding);
+                }
+            }
+            attestations.push(Attestation {
+                predicate_type,
+                statement: signed.statement,
+                report: attestation_report,
+            });
+        }
+    }
+    if attestations.is_empty() {
+        report.push(Finding::error(
+            "missing-attestation",
+            "/attestation_bundles",
+            format!("No attestations were published for {}", file),
+        ));
+    }
+
+    Ok(PypiVerification {
+        sha256,
+        publishers,
+        attestations,
+        report,
+    })
+}
+
+/// Reads a PEP 740 attestation as a signed statement: its envelope has the base64 statement
+/// and the signature over it, and its verification material the base64 DER certificate.
+fn signed_statement(attestation: &Value) -> Result<SignedStatement> {
+    let field = |pointer: &str, name: &str| {
+        let value = attestation
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("Attestation has no {}", name))?;
+        general_purpose::STANDARD
+            .decode(value)
+            .map_err(|e| anyhow!("Attestation {} is not valid base64: {}", name, e))
+    };
+    let payload = field("/envelope/statement", "statement")?;
+    let sig = field("/envelope/signature", "signature")?;
+    let statement = serde_json::from_slice(&payload)
+        .map_err(|e| anyhow!("Attestation statement is not valid JSON: {}", e))?;
+    let certificates = attestation
+        .pointer("/verification_material/certificate")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .into_iter()
+        .collect();
+    Ok(SignedStatement {
+        statement,
+        key_ids: Vec::new(),
+        signatures: 1,
+        log_entries: Vec::new(),
+        certificates,
+        envelope: Some(Envelope {
+            payload,
+            payload_type: IN_TOTO_PAYLOAD_TYPE.to_string(),
+            signatures: vec![Signature { keyid: None, sig }],
+        }),
+    })
+}
+
+/// Returns the Trusted Publisher a signing certificate was issued to: its kind from the OIDC
+/// issuer, and its repository and workflow from the workflow URI in the subject alternative
+/// name, e.g. `https://github.com/pypa/sampleproject/.github/workflows/release.yml@refs/tags/v4`.
+fn certified_publisher(identity: &CertificateIdentity) -> TrustedPublisher {
+    let (kind, prefix, separator) = match identity.issuer.as_deref() {
+        Some(GITHUB_ISSUER) => ("GitHub", "https://github.com/", "/.github/workflows/"),
+        Some(GITLAB_ISSUER) => ("GitLab", "https://gitlab.com/", "//"),
+        _ => return TrustedPublisher::default(),
+    };
+    let (repository, workflow) = identity
+        .subject_alternative_names
+        .iter()
+        .find_map(|name| name.strip_prefix(prefix)?.split_once(separator))
+        .map(|(repository, workflow)| {
+            let workflow = workflow.split('@').next().unwrap_or(workflow);
+            (Some(repository.to_string()), Some(workflow.to_string()))
+        })
+        .unwrap_or_default();
+    TrustedPublisher {
+        kind: Some(kind.to_string()),
+        repository,
+        workflow,
+        environment: None,
+    }
+}
+
+/// Checks the publisher an attestation's certificate was issued to against the policy's
+/// trusted publishers.
+fn check_publisher(publisher: &TrustedPublisher, policy: &Policy, report: &mut ValidationReport) {
+    let path = "/verification_material/certificate";
+    let repository = publisher.repository.as_deref().unwrap_or("(unknown)");
+    if policy.trusted_publishers.is_empty() {
+        report.push(Finding::warning(
+            "unchecked-publisher",
+            path,
+            format!(
+                "The policy has no trusted publishers, so publisher {} was not checked",
+                repository
+            ),
+        ));
+    } else if !policy
+        .trusted_publishers
+        .iter()
+        .any(|trusted| trusted.matches(publisher))
+    {
+        report.push(Finding::error(
+            "untrusted-publisher",
+            path,
+            format!("Publisher {} is not trusted by the policy", repository),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_routes;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    const FILENAME: &str = "sample_project-4.0.0-py3-none-any.whl";
+    const WHEEL: &[u8] = b"wheel contents";
+
+    fn sha256() -> String {
+        Sha256::digest(WHEEL)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// Returns a publish attestation of the file with the given subject digest, signed with a
+    /// certificate from an authority made from the seed, and the trusted root of the authority
+    /// made from seed 3.
+    fn attestation(digest: &str, ca_seed: u8) -> (Value, TrustedRoot) {
+        let statement = json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": FILENAME, "digest": {"sha256": digest}}],
+            "predicateType": PUBLISH_PREDICATE_TYPE,
+            "predicate": {}
+        });
+        let key = p256::ecdsa::SigningKey::from_bytes(&[5; 32].into()).unwrap();
+        let (envelope, _) = trust::signature::tests::signed_envelope(&key, &statement);
+        let (_, leaf, _) = trust::sct::tests::fixture_with_ca("leaf", ca_seed);
+        let attestation = json!({
+            "version": 1,
+            "verification_material": {
+                "certificate": general_purpose::STANDARD.encode(leaf),
+                "transparency_entries": []
+            },
+            "envelope": {
+                "statement": general_purpose::STANDARD.encode(&envelope.payload),
+                "signature": general_purpose::STANDARD.encode(&envelope.signatures[0].sig)
+            }
+        });
+        (attestation, trust::sct::tests::fixture("leaf").2)
+    }
+
+    /// Serves a release whose file has the attestations, in a bundle whose unsigned publisher
+    /// claims to be `pypa/sampleproject`.
+    fn index(attestations: Vec<Value>) -> String {
+        let provenance = json!({
+            "version": 1,
+            "attestation_bundles": [{
+                "publisher": {
+                    "kind": "GitHub",
+                    "repository": "pypa/sampleproject",
+                    "workflow": "release.yml",
+                    "environment": "pypi",
+                    "claims": null
+                },
+                "attestations": attestations
+            }]
+        });
+        serve_routes(|url| {
+            let release = json!({
+                "urls": [{
+                    "filename": FILENAME,
+                    "url": format!("{}/files/{}", url, FILENAME),
+                    "digests": {"sha256": sha256()}
+                }]
+            });
+            HashMap::from([
+                (
+                    "/pypi/sample-project/4.0.0/json".to_string(),
+                    release.to_string().into_bytes(),
+                ),
+                (
+                    format!("/integrity/sample-project/4.0.0/{}/provenance", FILENAME),
+                    provenance.to_string().into_bytes(),
+                ),
+                (format!("/files/{}", FILENAME), WHEEL.to_vec()),
+            ])
+        })
+        .0
+    }
+
+    fn policy(repository: &str) -> Policy {
+        Policy {
+            trusted_publishers: vec![TrustedPublisher {
+                repository: Some(repository.to_string()),
+                ..TrustedPublisher::default()
+            }],
+            ..Policy::default()
+        }
+    }
+
+    fn codes(attestation: &Attestation) -> Vec<(&str, &str)> {
+        attestation
+            .report
+            .errors()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_distribution_file() {
+        let file = FILENAME.parse::<DistributionFile>().unwrap();
+        assert_eq!(file.project, "sample-project");
+        assert_eq!(file.version, "4.0.0");
+
+        let file = "Sample.Project-4.0.0.tar.gz"
+            .parse::<DistributionFile>()
+            .unwrap();
+        assert_eq!(file.project, "sample-project");
+        assert_eq!(file.version, "4.0.0");
+
+        assert!("sampleproject.egg".parse::<DistributionFile>().is_err());
+    }
+
+    #[test]
+    fn test_fetch_and_validate() {
+        let (attestation, root) = attestation(&sha256(), 3);
+        let index = index(vec![attestation]);
+        let file = FILENAME.parse().unwrap();
+
+        // The publisher is the one the certificate was issued to, not the unsigned one.
+        let trusted = policy("slsa-framework/slsa-github-generator");
+        let verification = fetch_and_validate(&index, &file, &trusted, &root).unwrap();
+        assert_eq!(verification.sha256, sha256());
+        assert_eq!(
+            verification.publishers,
+            vec![TrustedPublisher {
+                kind: Some("GitHub".to_string()),
+                repository: Some("slsa-framework/slsa-github-generator".to_string()),
+                workflow: Some("builder_go_slsa3.yml".to_string()),
+                environment: None,
+            }]
+        );
+        assert_eq!(verification.attestations.len(), 1);
+        assert!(!verification.has_errors(), "{:?}", verification);
+        assert!(verification.report.findings.is_empty());
+
+        let verification =
+            fetch_and_validate(&index, &file, &policy("pypa/sampleproject"), &root).unwrap();
+        assert_eq!(
+            codes(&verification.attestations[0]),
+            vec![("untrusted-publisher", "/verification_material/certificate")]
+        );
+
+        let verification = fetch_and_validate(&index, &file, &Policy::default(), &root).unwrap();
+        assert_eq!(
+            verification.attestations[0]
+                .report
+                .warnings()
+                .next()
+                .unwrap()
+                .code,
+            "unchecked-publisher"
+        );
+    }
+
+    #[test]
+    fn test_fetch_and_validate_unverified() {
+        let file = FILENAME.parse().unwrap();
+        let trusted = policy("slsa-framework/slsa-github-generator");
+
+        // A certificate from an authority the root doesn't have.
+        let (untrusted, root) = attestation(&sha256(), 9);
+        let verification =
+            fetch_and_validate(&index(vec![untrusted]), &file, &trusted, &root).unwrap();
+        assert_eq!(
+            codes(&verification.attestations[0]),
+            vec![(
+                "untrusted-certificate",
+                "/verification_material/certificate"
+            )]
+        );
+        assert!(verification.publishers.is_empty());
+
+        // A statement about another digest in place of the one that was signed.
+        let (mut forged, _) = attestation(&sha256(), 3);
+        let (other, _) = attestation(&"0".repeat(64), 3);
+        forged["envelope"]["statement"] = other["envelope"]["statement"].clone();
+        let verification =
+            fetch_and_validate(&index(vec![forged]), &file, &trusted, &root).unwrap();
+        assert_eq!(
+            codes(&verification.attestations[0]),
+            vec![
+                ("subject-digest-mismatch", "/subject"),
+                ("invalid-signature", "/envelope/signature"),
+            ]
+        );
+        assert!(verification.has_errors());
+    }
+}
@@ -4,23 +4,81 @@
 //! SLSA Provenance v1 and v0.2 predicates.
 //! TODO(mlieberman85): The CLI commands and args could probably be generalized better to minimize duplication.
 
-use std::{path::PathBuf, process};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process,
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant},
+};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use clap::{Parser, ValueEnum};
-use serde::de::DeserializeOwned;
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Serialize,
+};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 use spector::{
+    attestations::{self, decode_dsse_statement, npm, pypi, Attestation},
+    codegen::{self, rust::RustOptions, CodegenOptions, Language},
+    enrich::{self, clearlydefined, depsdev, osv},
+    guac::{self, GuacClient, GuacDocument},
+    import::bazel,
+    input,
     models::{
+        cdxa::CycloneDxAttestation,
         intoto::{
-            predicate::Predicate, provenancev1::SLSAProvenanceV1Predicate, provenancev02::SLSAProvenanceV02Predicate,
-            statement::InTotoStatementV1, scai::SCAIV02Predicate,
+            apko::ApkoBuildPredicate,
+            buildinfo::JvmBuildInfoPredicate,
+            codereview::CodeReviewPredicate,
+            csaf::CsafDocument,
+            dependencyreview::DependencyReviewPredicate,
+            licensescan::LicenseScanPredicate,
+            link::LinkPredicate,
+            melange::MelangeBuildPredicate,
+            options::{ParseOptions, StatementTypeCheck},
+            predicate::Predicate,
+            provenancev02::SLSAProvenanceV02Predicate,
+            provenancev1::SLSAProvenanceV1Predicate,
+            reference::ReferencePredicate,
+            sarif::SarifLog,
+            scai::{SCAIV02Predicate, SCAIV03Predicate},
+            spdx::SpdxDocumentPredicate,
+            statement::InTotoStatementV1,
+            testresult::TestResultPredicate,
+            vsa::VerificationSummaryV1Predicate,
         },
-        sbom::{spdx22::Spdx22Document, spdx23::Spdx23},
+        sbom::{cyclonedx::CycloneDxBom, spdx22::Spdx22Document, spdx23::Spdx23},
+    },
+    patch::{self, Patch},
+    plugin::{self, PluginRegistry},
+    push::{self, PushConfig, Submission},
+    schema::{
+        compat, corpus, docs,
+        fetch::{self, FetchOptions},
+        openapi,
+        registry::SchemaRegistry,
+    },
+    store::{self, SubjectDigest},
+    trust::{self, tuf::TufClient, TrustedRoot},
+    validate::{
+        self,
+        consistency::{self, NamedStatement},
+        detect, explain, meta_schema,
+        policy::Policy,
+        render,
+        report::{Finding, Severity, ValidationReport},
+        rules::{self, predicate_schema::PredicateSchemaRule, Rule},
+        stream, GenericValidator, Validator,
+    },
+    verify::{
+        self, offline::OfflineBundle, policy::VerificationPolicy,
+        provenance::ProvenanceExpectations,
     },
-    validate::{self, GenericValidator, Validator},
 };
-use typify::{TypeSpace, TypeSpaceSettings};
 
 #[derive(Parser)]
 #[clap(
@@ -30,6 +88,17 @@ use typify::{TypeSpace, TypeSpaceSettings};
 struct Spector {
     #[clap(subcommand)]
     command: Command,
+
+    /// Format of the log of the documents processed: findings as text on stderr, or a JSON record
+    /// per document on stderr, with its result, error codes and timing, instead of the findings
+    #[arg(value_enum)]
+    #[clap(long, global = true, default_value = "text")]
+    log_format: LogFormat,
+
+    /// Path to a shared library of document type plugins to load, can be repeated
+    #[cfg(feature = "dynamic-plugins")]
+    #[clap(long = "plugin", global = true, value_name = "PATH")]
+    plugins: Vec<PathBuf>,
 }
 
 // The available subcommands
@@ -39,6 +108,522 @@ enum Command {
     SchemaGenerate(SchemaGenerate),
     CodeGenerate(CodeGenerate),
     SchemaValidate(SchemaValidate),
+    Schema(Schema),
+    DocsGenerate(DocsGenerate),
+    Push(Push),
+    GuacPush(GuacPush),
+    Fetch(Fetch),
+    Sbom(Sbom),
+    Consistency(Consistency),
+    Patch(PatchDocuments),
+    Verify(Verify),
+    ExportBundle(ExportBundle),
+    Import(Import),
+    TestCorpus(TestCorpus),
+    Explain(Explain),
+}
+
+// The `verify` subcommand for checking attestations say what they are expected to
+#[derive(Parser)]
+#[clap(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
+struct Verify {
+    #[clap(subcommand)]
+    document: Option<VerifySubCommand>,
+
+    /// Path to a YAML or JSON verification policy to evaluate the attestations against, giving a
+    /// single pass or fail
+    #[clap(long)]
+    policy: Option<PathBuf>,
+
+    /// Path to an offline bundle from export-bundle to verify, against the pinned --trusted-root
+    /// or --tuf root its own must match, and --policy or its own policy if --policy-digest pins it
+    #[clap(long, conflicts_with = "files")]
+    bundle: Option<PathBuf>,
+
+    /// The digest of the bundle's own policy, as export-bundle prints it, to verify against it
+    #[clap(long, requires = "bundle", conflicts_with = "policy")]
+    policy_digest: Option<String>,
+
+    /// Format of the policy result: findings on stderr, a Kyverno PolicyReport or a Gatekeeper
+    /// constraint with its audit violations, printed as JSON
+    #[arg(value_enum)]
+    #[clap(long, default_value = "text")]
+    format: ReportFormat,
+
+    /// Path to a PEM public key trusted to sign attestations without a certificate. Can be repeated
+    #[clap(long = "key", value_name = "PATH")]
+    keys: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    trust: TrustedRootArgs,
+
+    /// Paths to the attestations for the policy: statements, DSSE envelopes, Sigstore bundles or
+    /// Rekor log entries with their attestation, or JSON Lines of them
+    #[clap(value_parser, num_args = 1..)]
+    files: Vec<PathBuf>,
+}
+
+// The options for the Sigstore trusted root to check the transparency log entries, inclusion
+// proofs and certificate timestamps of Sigstore bundles against
+#[derive(clap::Args)]
+struct TrustedRootArgs {
+    /// Path to a pinned Sigstore trusted_root.json to check the transparency log entries, inclusion
+    /// proofs and certificate timestamps of the bundles against
+    #[clap(long, conflicts_with = "tuf")]
+    trusted_root: Option<PathBuf>,
+
+    /// Fetch the Sigstore trusted root over TUF to check the bundles against. It is cached until
+    /// the TUF metadata expires
+    #[clap(long)]
+    tuf: bool,
+
+    /// URL of the TUF repository to fetch the trusted root from
+    #[clap(long, requires = "tuf", default_value = spector::trust::tuf::SIGSTORE_TUF_URL)]
+    tuf_url: String,
+
+    /// Path to the TUF root.json to start from when none is cached yet, e.g. from the Sigstore root
+    /// signing ceremony
+    #[clap(long, requires = "tuf")]
+    tuf_root: Option<PathBuf>,
+}
+
+impl TrustedRootArgs {
+    /// Reads the pinned trusted root or fetches it over TUF, if either was asked for.
+    fn load(&self) -> Result<Option<TrustedRoot>> {
+        if let Some(path) = &self.trusted_root {
+            return Ok(Some(TrustedRoot::from_file(path)?));
+        }
+        if !self.tuf {
+            return Ok(None);
+        }
+        let cache_dir = TufClient::default_cache_dir(&self.tuf_url).ok_or_else(|| {
+            anyhow!(
+                "No cache directory for {}, set XDG_CACHE_HOME",
+                self.tuf_url
+            )
+        })?;
+        let mut client = TufClient::new(&self.tuf_url, cache_dir);
+        if let Some(path) = &self.tuf_root {
+            client = client.with_pinned_root(std::fs::read(path)?);
+        }
+        Ok(Some(client.trusted_root()?))
+    }
+}
+
+// The `export-bundle` subcommand, which packages attestations with their trusted root into an
+// offline bundle, for verifying them in a disconnected network with `verify --bundle`
+#[derive(Parser)]
+struct ExportBundle {
+    /// Path to a YAML or JSON verification policy to include in the bundle. Its digest is printed
+    /// for verify --policy-digest to pin it
+    #[clap(long)]
+    policy: Option<PathBuf>,
+
+    #[clap(flatten)]
+    trust: TrustedRootArgs,
+
+    /// Path to write the bundle to, defaults to stdout
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+
+    /// Paths to the attestations: statements, DSSE envelopes, Sigstore bundles or Rekor log entries
+    /// with their attestation, or JSON Lines of them
+    #[clap(value_parser, required = true, num_args = 1..)]
+    files: Vec<PathBuf>,
+}
+
+// The `import` subcommand for turning the output of build tools into attestation skeletons
+#[derive(Parser)]
+struct Import {
+    #[clap(subcommand)]
+    command: ImportSubCommand,
+}
+
+#[derive(Parser)]
+enum ImportSubCommand {
+    /// Map a Bazel Build Event Protocol JSON file, from --build_event_json_file, to a SLSA v1
+    /// provenance statement of the build's outputs
+    BazelBep(ImportBazelBep),
+}
+
+#[derive(Parser)]
+struct ImportBazelBep {
+    /// Path to the build events, as JSON Lines, or an s3:// or gs:// URI
+    #[clap(value_parser)]
+    file: PathBuf,
+
+    /// The id of the builder that ran Bazel, e.g. the URL of the CI runner
+    #[clap(long)]
+    builder_id: url::Url,
+
+    /// Path to write the statement to, defaults to stdout
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+}
+
+// The `test-corpus` subcommand for producing conformance suites for verifiers
+#[derive(Parser)]
+struct TestCorpus {
+    #[clap(subcommand)]
+    command: TestCorpusSubCommand,
+}
+
+#[derive(Parser)]
+enum TestCorpusSubCommand {
+    /// Generate valid and systematically broken documents for every supported model, with a
+    /// manifest.json of what a verifier is expected to make of each
+    Generate(TestCorpusGenerate),
+}
+
+#[derive(Parser)]
+struct TestCorpusGenerate {
+    /// Directory to write the documents and manifest.json to
+    #[clap(long)]
+    out_dir: PathBuf,
+
+    /// Number of valid documents to generate per model, each of them broken in every way
+    #[clap(long, default_value = "1")]
+    seeds: u64,
+
+    /// Only generate documents for this schema registry model, e.g. slsa-provenance-v1
+    #[clap(long)]
+    model: Option<String>,
+}
+
+// The `explain` subcommand for showing why a document fails validation and how to fix it
+#[derive(Parser)]
+struct Explain {
+    /// Path to the document to explain: a statement, DSSE envelope, Sigstore bundle, SPDX or
+    /// CycloneDX document, or an s3:// or gs:// URI with the object-store feature
+    #[clap(long, short, required = true)]
+    file: PathBuf,
+
+    /// Path to a JSON policy file for the semantic checks
+    #[clap(long)]
+    policy: Option<PathBuf>,
+
+    /// Print the explanations as a JSON array instead of text
+    #[clap(long)]
+    json: bool,
+}
+
+#[derive(Parser)]
+enum VerifySubCommand {
+    /// Verify SLSA provenance was built from the expected source, ref and builder, like slsa-verifier
+    Provenance(Box<VerifyProvenance>),
+    /// Check an SBOM attestation is about the artifact of a provenance and lists the build's
+    /// dependencies, printing the linkage report as JSON
+    Linkage(VerifyLinkage),
+}
+
+#[derive(Parser)]
+struct VerifyLinkage {
+    /// Path to the SPDX SBOM attestation: a statement, DSSE envelope or Sigstore bundle
+    #[clap(value_parser)]
+    sbom: PathBuf,
+
+    /// Path to the SLSA provenance attestation: a statement, DSSE envelope or Sigstore bundle
+    #[clap(value_parser)]
+    provenance: PathBuf,
+}
+
+#[derive(Parser)]
+struct VerifyProvenance {
+    /// Path to the provenance: a statement, DSSE envelope or Sigstore bundle, or JSON Lines of them
+    /// such as an .intoto.jsonl file
+    #[clap(value_parser, required_unless_present = "store")]
+    file: Option<PathBuf>,
+
+    /// Where to look up the provenance of --artifact instead: a directory, oci://<registry>/<repository>,
+    /// rekor or rekor+<url>
+    #[clap(long, conflicts_with = "file")]
+    store: Option<String>,
+
+    /// An annotation the subject for --artifact must have in the signed provenance, as key=value.
+    /// Provenance without all of them is ignored. Can be repeated
+    #[clap(long = "annotation", short = 'a', value_name = "KEY=VALUE", value_parser = parse_key_value, requires = "store")]
+    annotations: Vec<(String, String)>,
+
+    /// The repository the artifact must have been built from, e.g. github.com/org/repo
+    #[clap(long)]
+    source_uri: String,
+
+    /// The builder that must have built the artifact. Without an @ version, any version is accepted
+    #[clap(long)]
+    builder_id: Option<String>,
+
+    /// The tag the source must have been built at, e.g. v1.2.3
+    #[clap(long, alias = "source-tag", conflicts_with = "branch")]
+    tag: Option<String>,
+
+    /// The branch the source must have been built at, e.g. main
+    #[clap(long, alias = "source-branch")]
+    branch: Option<String>,
+
+    /// A workflow input the build must have been run with, as name=value. Can be repeated
+    #[clap(long = "build-workflow-input", value_name = "NAME=VALUE", value_parser = parse_key_value)]
+    workflow_inputs: Vec<(String, String)>,
+
+    /// Path to the artifact. Only provenance with a subject of its SHA-256 digest is verified
+    #[clap(long)]
+    artifact: PathBuf,
+
+    /// Path to a JSON policy file for the semantic checks
+    #[clap(long)]
+    policy: Option<PathBuf>,
+
+    /// Path to a PEM public key trusted to sign provenance without a certificate. Can be repeated
+    #[clap(long = "key", value_name = "PATH")]
+    keys: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    trust: TrustedRootArgs,
+}
+
+/// Reads the PEM public keys pinned to verify signatures made without a certificate.
+fn read_pinned_keys(paths: &[PathBuf]) -> Result<Vec<p256::ecdsa::VerifyingKey>> {
+    paths
+        .iter()
+        .map(|path| {
+            trust::signature::pem_key(&std::fs::read_to_string(path)?)
+                .map_err(|e| anyhow!("{}: {}", path.display(), e))
+        })
+        .collect()
+}
+
+fn parse_key_value(input: &str) -> std::result::Result<(String, String), String> {
+    match input.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected key=value, found {:?}", input)),
+    }
+}
+
+// The `patch` subcommand for fixing In-Toto statements with a JSON Patch or JSON Merge Patch
+#[derive(Parser)]
+struct PatchDocuments {
+    /// Path to the patch, a JSON Patch array or a JSON Merge Patch object
+    #[clap(long, short, required = true)]
+    patch: PathBuf,
+
+    /// Paths to the In-Toto v1 statements to patch
+    #[clap(value_parser, required = true, num_args = 1..)]
+    files: Vec<PathBuf>,
+
+    /// Overwrite each statement with its patched version instead of printing it
+    #[clap(long, conflicts_with = "output_dir")]
+    in_place: bool,
+
+    /// Directory to write the patched statements to, under their original file names
+    #[clap(long)]
+    output_dir: Option<PathBuf>,
+
+    /// Path to a JSON policy file for validating the patched statements
+    #[clap(long)]
+    policy: Option<PathBuf>,
+}
+
+// The `consistency` subcommand for checking attestations about the same artifact against each other
+#[derive(Parser)]
+struct Consistency {
+    /// Paths to the statements or DSSE envelopes to check, or s3:// or gs:// URIs with the
+    /// object-store feature. The first one decides which artifact they must all be about
+    #[clap(value_parser, required_unless_present = "store", num_args = 1..)]
+    files: Vec<PathBuf>,
+
+    /// Where to look up more attestations about --digest: a directory, oci://<registry>/<repository>
+    /// for attestations attached by cosign, rekor for the public Rekor log or rekor+<url> for another
+    #[clap(long, requires = "digest")]
+    store: Option<String>,
+
+    /// The digest of the artifact to look up in the store, as algorithm:digest
+    #[clap(long, requires = "store")]
+    digest: Option<SubjectDigest>,
+}
+
+// The `sbom` subcommand for working with SBOMs beyond validating them
+#[derive(Parser)]
+struct Sbom {
+    #[clap(subcommand)]
+    command: SbomSubCommand,
+}
+
+#[derive(Parser)]
+enum SbomSubCommand {
+    /// Validate an SPDX SBOM and report known vulnerabilities of its packages from OSV
+    Scan(SbomScan),
+    /// Fill missing or NOASSERTION licenses of an SPDX SBOM's packages from ClearlyDefined
+    FillLicenses(SbomFillLicenses),
+}
+
+#[derive(Parser)]
+struct SbomFillLicenses {
+    /// Path to the SPDX v2.3 or v2.2 document to fill in, or an s3:// or gs:// URI
+    #[clap(long, short, required = true)]
+    file: PathBuf,
+
+    /// Path to write the patched document to, defaults to stdout
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+
+    /// URL of the ClearlyDefined API
+    #[clap(long, default_value = clearlydefined::DEFAULT_API)]
+    clearlydefined_api: String,
+}
+
+#[derive(Parser)]
+struct SbomScan {
+    /// Path to the SPDX v2.3 or v2.2 document to scan, or an s3:// or gs:// URI
+    #[clap(long, short, required = true)]
+    file: PathBuf,
+
+    /// URL of the OSV API
+    #[clap(long, default_value = osv::DEFAULT_API)]
+    osv_api: String,
+
+    /// Fail if any package has a known vulnerability
+    #[clap(long)]
+    fail_on_vulnerabilities: bool,
+}
+
+// The `fetch` subcommand for validating attestations published by package registries
+#[derive(Parser)]
+struct Fetch {
+    #[clap(subcommand)]
+    registry: FetchSubCommand,
+}
+
+#[derive(Parser)]
+enum FetchSubCommand {
+    /// Fetch and validate the provenance and publish attestations of an npm package
+    Npm(FetchNpm),
+    /// Fetch and validate the PEP 740 attestations of a PyPI distribution file
+    Pypi(FetchPypi),
+}
+
+#[derive(Parser)]
+struct FetchPypi {
+    /// The file name of the wheel or source distribution
+    #[clap(value_parser)]
+    file: String,
+
+    /// URL of the package index
+    #[clap(long, default_value = pypi::DEFAULT_INDEX)]
+    index: String,
+
+    /// Path to a JSON policy file for the semantic checks and trusted publishers
+    #[clap(long)]
+    policy: Option<PathBuf>,
+
+    #[clap(flatten)]
+    trust: TrustedRootArgs,
+}
+
+#[derive(Parser)]
+struct FetchNpm {
+    /// The package and version, as `<name>@<version>`
+    #[clap(value_parser)]
+    package: String,
+
+    /// URL of the npm registry
+    #[clap(long, default_value = npm::DEFAULT_REGISTRY)]
+    registry: String,
+
+    /// Path to a JSON policy file for the semantic checks
+    #[clap(long)]
+    policy: Option<PathBuf>,
+}
+
+// The `push` subcommand, which validates a document and uploads it with its validation report
+#[derive(Parser)]
+struct Push {
+    /// URL to post the document to, overriding SPECTOR_PUSH_URL and the config file
+    #[clap(long)]
+    endpoint: Option<String>,
+
+    /// Path to a JSON config file with `endpoint` and `token`, defaults to
+    /// ~/.config/spector/config.json. SPECTOR_PUSH_TOKEN overrides the token
+    #[clap(long)]
+    config: Option<PathBuf>,
+
+    #[clap(subcommand)]
+    document: ValidateDocumentSubCommand,
+}
+
+// The `guac-push` subcommand, which validates documents and ingests the valid ones into GUAC
+#[derive(Parser)]
+struct GuacPush {
+    /// URL of GUAC's GraphQL endpoint, e.g. http://localhost:8080/query, overriding SPECTOR_GUAC_URL.
+    /// SPECTOR_GUAC_TOKEN is sent as a bearer token if set
+    #[clap(long)]
+    endpoint: Option<String>,
+
+    /// Number of relations to send in each request
+    #[clap(long, default_value_t = 100)]
+    batch_size: usize,
+
+    /// Number of times to retry a request failing with a network or server error
+    #[clap(long, default_value_t = 3)]
+    retries: u32,
+
+    /// Path to a JSON policy file for the semantic checks of the attestations
+    #[clap(long)]
+    policy: Option<PathBuf>,
+
+    /// Paths to the SLSA provenance and SPDX SBOM attestations, as statements, DSSE envelopes,
+    /// Sigstore bundles or JSON Lines of them, and to SPDX v2.3 or v2.2 documents
+    #[clap(value_parser, required = true, num_args = 1..)]
+    files: Vec<PathBuf>,
+}
+
+// The `docs-generate` subcommand
+#[derive(Parser)]
+struct DocsGenerate {
+    /// Name of the document type to generate a field reference for, e.g. `slsa-provenance-v1`
+    #[clap(value_parser)]
+    #[clap(required_unless_present = "out_dir")]
+    name: Option<String>,
+
+    /// Directory to write the references to, one `<name>.md` file per document type
+    #[clap(long)]
+    out_dir: Option<PathBuf>,
+}
+
+// The `schema` subcommand for working with JSON schemas
+#[derive(Parser)]
+struct Schema {
+    #[clap(subcommand)]
+    command: SchemaSubCommand,
+}
+
+#[derive(Parser)]
+enum SchemaSubCommand {
+    /// Generate a sample document that is valid against a schema
+    Sample(SchemaSample),
+    /// Check whether a new version of a schema is backward-compatible with the old one
+    Compat(SchemaCompat),
+}
+
+#[derive(Parser)]
+struct SchemaCompat {
+    /// Path to the old version of the schema
+    #[clap(value_parser)]
+    old: PathBuf,
+
+    /// Path to the new version of the schema
+    #[clap(value_parser)]
+    new: PathBuf,
+}
+
+#[derive(Parser)]
+struct SchemaSample {
+    /// Path to the schema file
+    #[clap(value_parser)]
+    #[clap(long, short, required = true)]
+    file: PathBuf,
+
+    /// Seed for the values in the sample, the same seed always produces the same document
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
 }
 
 // The `code-generate` subcommand
@@ -52,6 +637,7 @@ struct CodeGenerate {
 #[derive(Parser)]
 enum CodeGenerateSubCommand {
     JsonSchema(JsonSchema),
+    Protobuf(Protobuf),
 }
 
 #[derive(Parser)]
@@ -62,13 +648,109 @@ struct JsonSchema {
     #[clap(value_parser)]
     #[clap(long, short, required = true)]
     file: PathBuf,
+    #[clap(flatten)]
+    output: CodeGenerateOutput,
+}
+
+#[derive(Parser)]
+struct Protobuf {
+    /// Path to a binary FileDescriptorSet, e.g. from `protoc --descriptor_set_out` or `buf build`
+    #[clap(value_parser)]
+    #[clap(long, short, required = true)]
+    file: PathBuf,
+    #[clap(flatten)]
+    output: CodeGenerateOutput,
+}
+
+// The options for the generated code shared by all code generation types
+#[derive(clap::Args)]
+struct CodeGenerateOutput {
+    /// Language to generate code for
+    #[arg(value_enum)]
+    #[clap(long, default_value = "rust")]
+    lang: LanguageOption,
+    /// Directory to write the generated files to instead of printing them
+    #[clap(long)]
+    out_dir: Option<PathBuf>,
+    /// Generate a standalone crate with this name in the output directory
+    #[clap(long, requires = "out_dir")]
+    crate_name: Option<String>,
+    /// Extra derive for every generated Rust type, e.g. `PartialEq`, can be repeated
+    #[clap(long = "derive")]
+    derives: Vec<String>,
+    /// Don't generate builders for the Rust structs
+    #[clap(long)]
+    no_builder: bool,
+    /// Reject unknown fields when deserializing Rust structs whose schema allows them
+    #[clap(long)]
+    deny_unknown_fields: bool,
+    /// Rename a generated Rust type, as `OldName=NewName`, can be repeated
+    #[clap(long = "rename", value_parser = parse_rename)]
+    renames: Vec<(String, String)>,
+    /// Also generate a Rust test module that round-trips sample documents against the schema
+    #[clap(long)]
+    round_trip_tests: bool,
+}
+
+impl CodeGenerateOutput {
+    fn options(&self) -> CodegenOptions {
+        CodegenOptions {
+            crate_name: self.crate_name.clone(),
+            rust: RustOptions {
+                derives: self.derives.clone(),
+                struct_builder: !self.no_builder,
+                deny_unknown_fields: self.deny_unknown_fields,
+                renames: self.renames.iter().cloned().collect(),
+                round_trip_tests: self.round_trip_tests,
+            },
+        }
+    }
+}
+
+fn parse_rename(rename: &str) -> std::result::Result<(String, String), String> {
+    match rename.split_once('=') {
+        Some((old, new)) if !old.is_empty() && !new.is_empty() => {
+            Ok((old.to_string(), new.to_string()))
+        }
+        _ => Err(format!("expected OldName=NewName, found {:?}", rename)),
+    }
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum LanguageOption {
+    Rust,
+    Typescript,
+    Zod,
+    Python,
+}
+
+impl From<LanguageOption> for Language {
+    fn from(option: LanguageOption) -> Self {
+        match option {
+            LanguageOption::Rust => Language::Rust,
+            LanguageOption::Typescript => Language::TypeScript,
+            LanguageOption::Zod => Language::Zod,
+            LanguageOption::Python => Language::Python,
+        }
+    }
 }
 
 // The `validate` subcommand
 #[derive(Parser)]
+#[clap(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
 struct Validate {
     #[clap(subcommand)]
-    document: ValidateDocumentSubCommand,
+    document: Option<ValidateDocumentSubCommand>,
+
+    /// Detect whether the document is a DSSE envelope, Sigstore bundle, In-Toto v0.1 or v1
+    /// statement, SPDX or CycloneDX document, and validate it as such
+    #[clap(long, requires = "file")]
+    auto: bool,
+
+    /// Path to the document to validate with --auto, or an s3:// or gs:// URI with the
+    /// object-store feature
+    #[clap(long, short, requires = "auto")]
+    file: Option<PathBuf>,
 }
 
 // The `generate` subcommand
@@ -81,11 +763,23 @@ struct SchemaGenerate {
 // The `schema-validate` subcommand
 #[derive(Parser)]
 struct SchemaValidate {
-    /// Path to the schema file
+    /// Path or URL of the schema, defaults to the embedded schema for the document's predicateType
     #[clap(value_parser)]
-    schema: PathBuf,
+    schema: Option<String>,
+
+    /// Expected SHA-256 of a schema fetched by URL
+    #[clap(long, value_name = "HEX")]
+    sha256: Option<String>,
 
-    /// Path to the file to validate
+    /// Directory to cache schemas fetched by URL in, defaults to ~/.cache/spector/schemas
+    #[clap(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// Check cached schemas with the server and download them again if they changed
+    #[clap(long)]
+    refresh: bool,
+
+    /// Path to the file to validate, or an s3:// or gs:// URI with the object-store feature
     // TODO(mlieberman85): Make this optional once we support stdin
     #[clap(value_parser)]
     #[clap(long, short, required = true)]
@@ -98,6 +792,10 @@ enum ValidateDocumentSubCommand {
     InTotoV1(ValidateInTotoV1),
     SPDXV23(ValidateSPDXV23),
     SPDXV22(ValidateSPDXV22),
+    /// A CycloneDX attestation (CDXA) document
+    Cdxa(ValidateCdxa),
+    /// A document of a type contributed by a plugin
+    Plugin(ValidatePlugin),
 }
 
 // The supported schema generate document types
@@ -106,6 +804,16 @@ enum GenerateDocumentSubCommand {
     InTotoV1(GenerateInTotoV1),
     SLSAProvenanceV01,
     SCAIV02,
+    /// An SPDX 2.3 document
+    SPDXV23,
+    /// An SPDX 2.2 document
+    SPDXV22,
+    All(GenerateAll),
+    /// A document of a type contributed by a plugin
+    Plugin(GeneratePlugin),
+    /// An OpenAPI 3.1 document with components for the statements, predicates and reports
+    #[command(name = "openapi")]
+    OpenAPI,
 }
 
 // The In-Toto v1 validate document subcommand
@@ -118,44 +826,298 @@ struct ValidateInTotoV1 {
     #[clap(long, short)]
     predicate: Option<PredicateOption>,
 
-    /// Path to the file to validate
+    /// Path to the statement, or a DSSE envelope wrapping it, to validate, or an s3:// or gs://
+    /// URI with the object-store feature
     #[clap(value_parser)]
     #[clap(long, short, required = true)]
     file: PathBuf,
+
+    /// Reject documents that the spec tolerates but are likely mistakes, e.g. an empty predicate
+    /// or a non-canonical predicateType URL
+    #[clap(long)]
+    strict: bool,
+
+    /// Which statement _type URIs to accept, defaults to v1 in strict mode and any otherwise
+    #[arg(value_enum)]
+    #[clap(long)]
+    statement_type: Option<StatementTypeOption>,
+
+    /// Lowercase hex digests and trim surrounding whitespace before validating
+    #[clap(long)]
+    normalize_digests: bool,
+
+    /// Path to a JSON policy file for the semantic checks
+    #[clap(value_parser)]
+    #[clap(long)]
+    policy: Option<PathBuf>,
+
+    /// Path to a JSON schema to check the predicate against, e.g. for a predicateType Spector
+    /// doesn't know
+    #[clap(value_parser)]
+    #[clap(long, value_name = "SCHEMA")]
+    predicate_schema: Option<PathBuf>,
+
+    /// Only accept provenance from builders whose id starts with this prefix, added to those of
+    /// the policy. Can be repeated
+    #[clap(long, value_name = "PREFIX")]
+    allow_builder: Vec<String>,
+
+    /// Only accept provenance with a buildType starting with this prefix, added to those of the
+    /// policy. Can be repeated
+    #[clap(long, value_name = "PREFIX")]
+    allow_build_type: Vec<String>,
+
+    /// Reject provenance whose build finished more than this many seconds ago, overriding the
+    /// policy
+    #[clap(long, value_name = "SECONDS")]
+    max_age: Option<u64>,
+
+    /// Reject attestations checked after this RFC 3339 time, overriding the policy
+    #[clap(long, value_name = "TIME")]
+    not_after: Option<DateTime<Utc>>,
+
+    /// Annotate the report with the license, latest version and OpenSSF Scorecard score of each
+    /// resolved dependency or material identified by a purl, from deps.dev
+    #[clap(long)]
+    deps_dev: bool,
+
+    /// URL of the deps.dev API
+    #[clap(long, default_value = depsdev::DEFAULT_API)]
+    deps_dev_api: String,
 }
 
 // The SPDX v2.3 validate document subcommand
 #[derive(Parser)]
 struct ValidateSPDXV23 {
-    /// Path to the file to validate
+    /// Path to the file to validate, or an s3:// or gs:// URI with the object-store feature
     #[clap(value_parser)]
     #[clap(long, short, required = true)]
     file: PathBuf,
+
+    /// Validate packages, files, snippets and relationships one at a time as the file is read, for
+    /// local SBOMs too large to load into memory
+    #[clap(long)]
+    stream: bool,
 }
 
 // The SPDX v2.2 validate document subcommand
 #[derive(Parser)]
 struct ValidateSPDXV22 {
-    /// Path to the file to validate
+    /// Path to the file to validate, or an s3:// or gs:// URI with the object-store feature
     #[clap(value_parser)]
     #[clap(long, short, required = true)]
     file: PathBuf,
+
+    /// Validate packages, files, snippets and relationships one at a time as the file is read, for
+    /// local SBOMs too large to load into memory
+    #[clap(long)]
+    stream: bool,
 }
 
-// The In-Toto v1 generate schema subcommand
+// The CycloneDX attestation validate document subcommand
 #[derive(Parser)]
-struct GenerateInTotoV1 {
-    /// Predicate type for In-Toto v1 documents
-    #[arg(value_enum)]
-    #[clap(long, short)]
-    predicate: Option<PredicateOption>,
+struct ValidateCdxa {
+    /// Path to the file to validate, or an s3:// or gs:// URI with the object-store feature
+    #[clap(value_parser)]
+    #[clap(long, short, required = true)]
+    file: PathBuf,
 }
 
-#[derive(Debug, Copy, Clone, ValueEnum)]
-enum PredicateOption {
-    SLSAProvenanceV1,
-    SLSAProvenanceV02,
-    SCAIV02Predicate,
+// The plugin validate document subcommand
+#[derive(Parser)]
+struct ValidatePlugin {
+    /// Name of the plugin's document type
+    #[clap(value_parser)]
+    name: String,
+
+    /// Path to the file to validate, or an s3:// or gs:// URI with the object-store feature
+    #[clap(value_parser)]
+    #[clap(long, short, required = true)]
+    file: PathBuf,
+
+    /// Path to a JSON policy file for the plugin's semantic checks
+    #[clap(long)]
+    policy: Option<PathBuf>,
+}
+
+// The plugin generate schema subcommand
+#[derive(Parser)]
+struct GeneratePlugin {
+    /// Name of the plugin's document type
+    #[clap(value_parser)]
+    name: String,
+}
+
+// The In-Toto v1 generate schema subcommand
+#[derive(Parser)]
+struct GenerateInTotoV1 {
+    /// Predicate type for In-Toto v1 documents
+    #[arg(value_enum)]
+    #[clap(long, short)]
+    predicate: Option<PredicateOption>,
+}
+
+// Generates the schemas for every supported document type
+#[derive(Parser)]
+struct GenerateAll {
+    /// Directory to write the schemas to, one `<name>.json` file per document type
+    #[clap(value_parser)]
+    #[clap(long, required = true)]
+    out_dir: PathBuf,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum ReportFormat {
+    Text,
+    PolicyReport,
+    Gatekeeper,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum LogFormat {
+    Text,
+    Json,
+}
+
+/// The log format, set once the arguments are parsed.
+static LOG_FORMAT: OnceLock<LogFormat> = OnceLock::new();
+
+/// The findings printed while processing a document, collected for its log record instead of
+/// being printed with --log-format json.
+static LOGGED_FINDINGS: Mutex<Vec<Finding>> = Mutex::new(Vec::new());
+
+fn json_log() -> bool {
+    LOG_FORMAT.get() == Some(&LogFormat::Json)
+}
+
+/// The log record of a document, written as a JSON line on stderr with --log-format json.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DocumentLog {
+    timestamp: DateTime<Utc>,
+    command: &'static str,
+    path: String,
+    /// The index of the attestation among those verified together.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
+    /// `pass` or `fail`.
+    result: &'static str,
+    errors: Vec<String>,
+    warnings: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    duration_ms: u64,
+}
+
+impl DocumentLog {
+    fn new(
+        command: &'static str,
+        path: String,
+        findings: &[Finding],
+        error: Option<String>,
+        duration: Duration,
+    ) -> Self {
+        let codes = |severity: Severity| {
+            findings
+                .iter()
+                .filter(|f| f.severity == severity)
+                .map(|f| f.code.clone())
+                .collect::<Vec<_>>()
+        };
+        let errors = codes(Severity::Error);
+        Self {
+            timestamp: Utc::now(),
+            command,
+            path,
+            index: None,
+            result: if error.is_none() && errors.is_empty() {
+                "pass"
+            } else {
+                "fail"
+            },
+            errors,
+            warnings: codes(Severity::Warning),
+            message: error,
+            duration_ms: duration.as_millis() as u64,
+        }
+    }
+
+    fn write(&self) {
+        if let Ok(line) = serde_json::to_string(self) {
+            eprintln!("{}", line);
+        }
+    }
+}
+
+/// Processes a document, writing its log record with --log-format json.
+fn log_document<T>(
+    command: &'static str,
+    path: &Path,
+    process: impl FnOnce() -> Result<T>,
+) -> Result<T> {
+    if !json_log() {
+        return process();
+    }
+    take_logged_findings();
+    let start = Instant::now();
+    let result = process();
+    let findings = take_logged_findings();
+    DocumentLog::new(
+        command,
+        path.display().to_string(),
+        &findings,
+        result.as_ref().err().map(|e| e.to_string()),
+        start.elapsed(),
+    )
+    .write();
+    result
+}
+
+fn take_logged_findings() -> Vec<Finding> {
+    LOGGED_FINDINGS
+        .lock()
+        .map(|mut findings| std::mem::take(&mut *findings))
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum PredicateOption {
+    SLSAProvenanceV1,
+    SLSAProvenanceV02,
+    SCAIV02Predicate,
+    SCAIV03Predicate,
+    ApkoBuildV1,
+    MelangeBuildV1,
+    JvmBuildInfoV1,
+    SLSAVerificationSummaryV1,
+    Link,
+    SpdxDocument,
+    #[value(name = "cyclonedx-bom")]
+    CycloneDxBom,
+    TestResultV01,
+    SarifV21,
+    CsafV2,
+    ReferenceV01,
+    LicenseScanV01,
+    CodeReviewV01,
+    DependencyReviewV01,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum StatementTypeOption {
+    Any,
+    V1,
+    V1OrV01,
+}
+
+impl From<StatementTypeOption> for StatementTypeCheck {
+    fn from(option: StatementTypeOption) -> Self {
+        match option {
+            StatementTypeOption::Any => StatementTypeCheck::Any,
+            StatementTypeOption::V1 => StatementTypeCheck::V1,
+            StatementTypeOption::V1OrV01 => StatementTypeCheck::V1OrV01,
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -165,12 +1127,893 @@ struct SLSAProvenanceV02 {}
 
 /// Validates the specified document.
 fn validate_cmd(validate: Validate) -> Result<()> {
-    //let file_str = std::fs::read_to_string(&validate.file)?;
-    match validate.document {
+    match (validate.document, validate.file) {
+        (Some(document), _) => {
+            let file = document.file().to_path_buf();
+            log_document("validate", &file, || validate_document_type(document)).map(|_| ())
+        }
+        (None, Some(file)) => log_document("validate", &file, || validate_auto(&file)).map(|_| ()),
+        (None, None) => Err(anyhow!(
+            "Either a document type or --auto --file is required"
+        )),
+    }
+}
+
+/// Validates a document in whichever format it is detected as.
+fn validate_auto(file: &Path) -> Result<ValidationReport> {
+    let document = serde_json::from_str::<Value>(&input::read_to_string(file)?)?;
+    let validation = detect::detect_and_validate(&document, &Policy::default())?;
+    print_findings(&validation.report);
+    if validation.report.has_errors() {
+        return Err(anyhow!(
+            "{} failed {} check(s)",
+            validation.format,
+            validation.report.errors().count()
+        ));
+    }
+    println!("Valid {}", validation.format);
+    Ok(validation.report)
+}
+
+impl ValidateDocumentSubCommand {
+    /// Returns the path of the document to validate.
+    fn file(&self) -> &Path {
+        match self {
+            ValidateDocumentSubCommand::InTotoV1(in_toto) => &in_toto.file,
+            ValidateDocumentSubCommand::SPDXV23(spdx) => &spdx.file,
+            ValidateDocumentSubCommand::SPDXV22(spdx) => &spdx.file,
+            ValidateDocumentSubCommand::Cdxa(cdxa) => &cdxa.file,
+            ValidateDocumentSubCommand::Plugin(plugin) => &plugin.file,
+        }
+    }
+}
+
+/// Validates the specified document, returning the report of its semantic checks.
+fn validate_document_type(document: ValidateDocumentSubCommand) -> Result<ValidationReport> {
+    match document {
         ValidateDocumentSubCommand::InTotoV1(in_toto) => validate_intoto_v1(in_toto),
+        ValidateDocumentSubCommand::SPDXV23(spdx) if spdx.stream => {
+            validate_stream("spdx-v23", &spdx.file)
+        }
         ValidateDocumentSubCommand::SPDXV23(spdx) => validate_document::<Spdx23>(spdx.file),
+        ValidateDocumentSubCommand::SPDXV22(spdx) if spdx.stream => {
+            validate_stream("spdx-v22", &spdx.file)
+        }
         ValidateDocumentSubCommand::SPDXV22(spdx) => validate_document::<Spdx22Document>(spdx.file),
+        ValidateDocumentSubCommand::Cdxa(cdxa) => validate_cdxa(&cdxa.file),
+        ValidateDocumentSubCommand::Plugin(plugin) => validate_plugin(&plugin),
+    }
+}
+
+/// Validates a document of a type contributed by a plugin.
+fn validate_plugin(validate_plugin: &ValidatePlugin) -> Result<ValidationReport> {
+    let plugin = PluginRegistry::global()
+        .get(&validate_plugin.name)
+        .ok_or_else(|| anyhow!("No plugin named {}", validate_plugin.name))?;
+    let document = serde_json::from_str::<Value>(&input::read_to_string(&validate_plugin.file)?)?;
+    let policy = match &validate_plugin.policy {
+        Some(path) => read_policy(path)?,
+        None => Policy::default(),
+    };
+    let report = plugin::validate(plugin.as_ref(), &document, &policy)?;
+    print_findings(&report);
+    if report.has_errors() {
+        return Err(anyhow!(
+            "Document failed {} check(s)",
+            report.errors().count()
+        ));
+    }
+    println!("Valid {} document", plugin.name());
+    Ok(report)
+}
+
+/// Validates an SPDX document one element at a time, without loading it into memory.
+fn validate_stream(schema_name: &str, file: &Path) -> Result<ValidationReport> {
+    let entry = SchemaRegistry::get(schema_name)
+        .ok_or_else(|| anyhow!("No schema named {}", schema_name))?;
+    let reader = input::open(file)?;
+    let validation = stream::validate_spdx(entry, reader)?;
+    print_findings(&validation.report);
+    if validation.report.has_errors() {
+        return Err(anyhow!(
+            "Document failed {} check(s)",
+            validation.report.errors().count()
+        ));
+    }
+    println!("Valid document with {} packages", validation.packages);
+    Ok(validation.report)
+}
+
+/// Fetches and validates the attestations published for a package.
+fn fetch_cmd(fetch: Fetch) -> Result<()> {
+    match fetch.registry {
+        FetchSubCommand::Npm(npm_package) => {
+            let package = npm_package.package.parse::<npm::PackageSpec>()?;
+            let policy = match &npm_package.policy {
+                Some(path) => read_policy(path)?,
+                None => Policy::default(),
+            };
+            let verification = npm::fetch_and_validate(&npm_package.registry, &package, &policy)?;
+            print_attestations(&verification.attestations);
+            print_findings(&verification.report);
+            if verification.has_errors() {
+                return Err(anyhow!("Attestations for {} are not valid", package));
+            }
+            println!(
+                "Valid attestations for {} with sha512 {}",
+                package, verification.tarball_sha512
+            );
+            Ok(())
+        }
+        FetchSubCommand::Pypi(pypi_file) => {
+            let file = pypi_file.file.parse::<pypi::DistributionFile>()?;
+            let policy = match &pypi_file.policy {
+                Some(path) => read_policy(path)?,
+                None => Policy::default(),
+            };
+            let trusted_root = pypi_file.trust.load()?.ok_or_else(|| {
+                anyhow!(
+                    "A trusted root is needed to verify the attestations, pass --trusted-root or \
+                     --tuf"
+                )
+            })?;
+            let verification =
+                pypi::fetch_and_validate(&pypi_file.index, &file, &policy, &trusted_root)?;
+            for publisher in &verification.publishers {
+                println!(
+                    "Published by {} {} with workflow {}",
+                    publisher.kind.as_deref().unwrap_or("(unknown)"),
+                    publisher.repository.as_deref().unwrap_or("(unknown)"),
+                    publisher.workflow.as_deref().unwrap_or("(unknown)")
+                );
+            }
+            print_attestations(&verification.attestations);
+            print_findings(&verification.report);
+            if verification.has_errors() {
+                return Err(anyhow!("Attestations for {} are not valid", file));
+            }
+            println!(
+                "Valid attestations for {} with sha256 {}",
+                file, verification.sha256
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Validates an SBOM and reports the known vulnerabilities of its packages, or fills in their
+/// licenses.
+fn sbom_cmd(sbom: Sbom) -> Result<()> {
+    match sbom.command {
+        SbomSubCommand::Scan(scan) => {
+            let document = read_sbom(&scan.file)?;
+            println!("Valid document");
+            let components = enrich::spdx_components(&document);
+            let report = osv::scan(&scan.osv_api, &components)?;
+            print_findings(&report);
+            let vulnerabilities = report.warnings().count();
+            println!(
+                "Found {} known vulnerabilities in {} packages",
+                vulnerabilities,
+                components.len()
+            );
+            if scan.fail_on_vulnerabilities && vulnerabilities > 0 {
+                return Err(anyhow!("{} has known vulnerabilities", scan.file.display()));
+            }
+            Ok(())
+        }
+        SbomSubCommand::FillLicenses(fill) => {
+            let mut document = read_sbom(&fill.file)?;
+            let report = clearlydefined::fill_licenses(&fill.clearlydefined_api, &mut document)?;
+            print_findings(&report);
+            let patched = serde_json::to_string_pretty(&document)?;
+            match &fill.output {
+                Some(path) => std::fs::write(path, patched + "\n")?,
+                None => println!("{}", patched),
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Checks statements about the same artifact against each other.
+fn consistency_cmd(consistency: Consistency) -> Result<()> {
+    let mut statements = Vec::new();
+    for (i, file) in consistency.files.iter().enumerate() {
+        let document = serde_json::from_str::<Value>(&input::read_to_string(file)?)?;
+        let statement = if document.get("payloadType").is_some() {
+            decode_dsse_statement(&document)?
+        } else {
+            document
+        };
+        println!("/{}: {}", i, file.display());
+        statements.push(NamedStatement {
+            name: file.display().to_string(),
+            statement,
+        });
+    }
+    if let (Some(location), Some(digest)) = (&consistency.store, &consistency.digest) {
+        for attestation in store::open(location)?.get(digest)? {
+            if statements
+                .iter()
+                .any(|statement| statement.name == attestation.location)
+            {
+                continue;
+            }
+            println!("/{}: {}", statements.len(), attestation.location);
+            statements.push(NamedStatement {
+                name: attestation.location,
+                statement: attestation.statement,
+            });
+        }
+    }
+    if statements.len() < 2 {
+        return Err(anyhow!(
+            "Expected at least two attestations to check, found {}",
+            statements.len()
+        ));
+    }
+    let report = consistency::check_consistency(&statements);
+    print_findings(&report);
+    if report.has_errors() {
+        return Err(anyhow!("Attestations are not about the same artifact"));
+    }
+    println!(
+        "Checked {} attestations with {} conflicts",
+        statements.len(),
+        report.warnings().count()
+    );
+    Ok(())
+}
+
+/// Verifies provenance against the expected source, ref, builder and workflow inputs.
+fn verify_cmd(verify_command: Verify) -> Result<()> {
+    match verify_command.document {
+        Some(VerifySubCommand::Provenance(provenance)) => verify_provenance_cmd(*provenance),
+        Some(VerifySubCommand::Linkage(linkage)) => verify_linkage_cmd(linkage),
+        None => match (&verify_command.bundle, &verify_command.policy) {
+            (Some(bundle), policy) => verify_bundle_cmd(bundle, policy.as_ref(), &verify_command),
+            (None, Some(policy)) => verify_policy_cmd(policy, &verify_command),
+            (None, None) => Err(anyhow!(
+                "Either a verify subcommand, --policy or --bundle is required"
+            )),
+        },
+    }
+}
+
+fn read_verification_policy(path: &PathBuf) -> Result<VerificationPolicy> {
+    VerificationPolicy::parse(&std::fs::read_to_string(path)?)
+        .map_err(|e| anyhow!("{}: {}", path.display(), e))
+}
+
+/// Evaluates attestations against a verification policy.
+fn verify_policy_cmd(policy_path: &PathBuf, verify_command: &Verify) -> Result<()> {
+    if verify_command.files.is_empty() {
+        return Err(anyhow!("No attestations to evaluate the policy against"));
+    }
+    let policy = read_verification_policy(policy_path)?;
+    let trusted_root = verify_command.trust.load()?.ok_or_else(|| {
+        anyhow!("A trusted root is needed to verify the attestations, pass --trusted-root or --tuf")
+    })?;
+    let pinned = read_pinned_keys(&verify_command.keys)?;
+    let start = Instant::now();
+    let mut attestations = Vec::new();
+    let mut files = Vec::new();
+    for file in &verify_command.files {
+        for signed in verify::read_signed_statements(&input::read_to_string(file)?)? {
+            if verify_command.format == ReportFormat::Text && !json_log() {
+                println!("/{}: {}", attestations.len(), file.display());
+            }
+            attestations.push(signed);
+            files.push(file);
+        }
+    }
+    let report = verify::policy::evaluate(&policy, &trusted_root, &attestations, &pinned);
+    if json_log() {
+        log_attestations(
+            &report,
+            &files,
+            &policy_path.display().to_string(),
+            start.elapsed(),
+        );
+    }
+    print_report(
+        &report,
+        verify_command.format,
+        &policy_path.display().to_string(),
+    )?;
+    if report.has_errors() {
+        return Err(anyhow!(
+            "Attestations do not meet the policy {}",
+            policy_path.display()
+        ));
+    }
+    if verify_command.format == ReportFormat::Text {
+        println!(
+            "{} attestation(s) meet the policy {}",
+            attestations.len(),
+            policy_path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Writes a log record for each attestation verified together, with the findings pointing into
+/// it, and one for the policy with the findings about its requirements. The attestations are
+/// verified together, so each record has the duration of the whole verification.
+fn log_attestations(
+    report: &ValidationReport,
+    files: &[&PathBuf],
+    policy: &str,
+    duration: Duration,
+) {
+    let mut rest = report.findings.clone();
+    for (i, file) in files.iter().enumerate() {
+        let prefix = format!("/{}", i);
+        let (findings, others): (Vec<_>, Vec<_>) = rest
+            .into_iter()
+            .partition(|f| f.path == prefix || f.path.starts_with(&format!("{}/", prefix)));
+        rest = others;
+        let mut log = DocumentLog::new(
+            "verify",
+            file.display().to_string(),
+            &findings,
+            None,
+            duration,
+        );
+        log.index = Some(i);
+        log.write();
+    }
+    let error = report
+        .has_errors()
+        .then(|| "The attestations do not meet the policy".to_string());
+    DocumentLog::new("verify", policy.to_string(), &rest, error, duration).write();
+}
+
+/// Verifies an offline bundle against the pinned trusted root and the given policy, or its own
+/// if its digest is pinned.
+fn verify_bundle_cmd(
+    bundle_path: &PathBuf,
+    policy_path: Option<&PathBuf>,
+    verify_command: &Verify,
+) -> Result<()> {
+    let format = verify_command.format;
+    let trusted_root = verify_command
+        .trust
+        .load()?
+        .ok_or_else(|| anyhow!("A trusted root is needed, pass --trusted-root or --tuf"))?;
+    let bundle = OfflineBundle::parse(&std::fs::read_to_string(bundle_path)?)
+        .map_err(|e| anyhow!("{}: {}", bundle_path.display(), e))?;
+    let policy = policy_path.map(read_verification_policy).transpose()?;
+    let pinned = read_pinned_keys(&verify_command.keys)?;
+    let start = Instant::now();
+    let report = bundle
+        .verify(
+            &trusted_root,
+            policy.as_ref(),
+            verify_command.policy_digest.as_deref(),
+            &pinned,
+        )
+        .map_err(|e| anyhow!("{}: {}", bundle_path.display(), e))?;
+    if json_log() {
+        let files = vec![bundle_path; bundle.attestations.len()];
+        log_attestations(
+            &report,
+            &files,
+            &policy_path.unwrap_or(bundle_path).display().to_string(),
+            start.elapsed(),
+        );
+    }
+    print_report(
+        &report,
+        format,
+        &policy_path.unwrap_or(bundle_path).display().to_string(),
+    )?;
+    if report.has_errors() {
+        return Err(anyhow!(
+            "The bundle {} does not verify",
+            bundle_path.display()
+        ));
+    }
+    if format == ReportFormat::Text {
+        println!(
+            "{} attestation(s) in {} verified offline",
+            bundle.attestations.len(),
+            bundle_path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Prints the result of a policy in a format: the findings on stderr, or the rendered report on
+/// stdout.
+fn print_report(report: &ValidationReport, format: ReportFormat, policy: &str) -> Result<()> {
+    match format {
+        ReportFormat::Text => print_findings(report),
+        ReportFormat::PolicyReport => println!(
+            "{}",
+            serde_json::to_string_pretty(&render::policy_report(report, policy, Utc::now()))?
+        ),
+        ReportFormat::Gatekeeper => println!(
+            "{}",
+            serde_json::to_string_pretty(&render::constraint(report, policy, Utc::now()))?
+        ),
+    }
+    Ok(())
+}
+
+/// Packages attestations with their trusted root, and optionally a policy, into an offline bundle.
+fn export_bundle_cmd(export: ExportBundle) -> Result<()> {
+    let trusted_root = export
+        .trust
+        .load()?
+        .ok_or_else(|| anyhow!("A trusted root is needed, pass --trusted-root or --tuf"))?;
+    let policy = export
+        .policy
+        .as_ref()
+        .map(read_verification_policy)
+        .transpose()?;
+    let mut attestations = Vec::new();
+    for file in &export.files {
+        attestations.extend(
+            verify::read_documents(&input::read_to_string(file)?)
+                .map_err(|e| anyhow!("{}: {}", file.display(), e))?,
+        );
+    }
+    let bundle = OfflineBundle::new(trusted_root, attestations, policy)?;
+    let text = serde_json::to_string_pretty(&bundle)?;
+    match &export.output {
+        Some(path) => {
+            std::fs::write(path, text + "\n")?;
+            println!(
+                "Exported {} attestation(s) to {}",
+                bundle.attestations.len(),
+                path.display()
+            );
+        }
+        None => println!("{}", text),
+    }
+    // The receiving side pins the policy's digest, so a swapped policy isn't trusted.
+    if let Some(digest) = bundle.policy_digest() {
+        eprintln!("Policy digest: {}", digest);
+    }
+    Ok(())
+}
+
+fn import_cmd(import: Import) -> Result<()> {
+    match import.command {
+        ImportSubCommand::BazelBep(bep) => {
+            let events = bazel::parse_events(&input::read_to_string(&bep.file)?)?;
+            let statement = bazel::import_bep(&events, &bep.builder_id)?;
+            let text = serde_json::to_string_pretty(&statement)?;
+            match &bep.output {
+                Some(path) => std::fs::write(path, text + "\n")?,
+                None => println!("{}", text),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn test_corpus_cmd(test_corpus: TestCorpus) -> Result<()> {
+    match test_corpus.command {
+        TestCorpusSubCommand::Generate(generate) => {
+            let cases = match &generate.model {
+                Some(name) => {
+                    let entry = SchemaRegistry::get(name)
+                        .ok_or_else(|| anyhow!("No schema named {}", name))?;
+                    corpus::generate(entry, generate.seeds)?
+                }
+                None => corpus::generate_all(generate.seeds)?,
+            };
+            corpus::write(&generate.out_dir, &cases)?;
+            let valid = cases
+                .iter()
+                .filter(|case| case.expected == corpus::Expectation::Valid)
+                .count();
+            println!(
+                "Generated {} valid and {} invalid documents in {}",
+                valid,
+                cases.len() - valid,
+                generate.out_dir.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Prints every finding of a document with the excerpt, constraint or requirement and fix for it.
+fn explain_cmd(explain_args: Explain) -> Result<()> {
+    let document = serde_json::from_str::<Value>(&input::read_to_string(&explain_args.file)?)?;
+    let policy = match &explain_args.policy {
+        Some(path) => read_policy(path)?,
+        None => Policy::default(),
+    };
+    let explanation = explain::explain(&document, &policy)?;
+    if explain_args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&explanation.explanations)?
+        );
+        return Ok(());
+    }
+    if explanation.explanations.is_empty() {
+        println!("No findings for the {}", explanation.format);
+        return Ok(());
+    }
+    for explained in &explanation.explanations {
+        println!("{}\n", explained);
+    }
+    let count = |severity| {
+        explanation
+            .explanations
+            .iter()
+            .filter(|explained| explained.finding.severity == severity)
+            .count()
+    };
+    println!(
+        "{}: {} error(s), {} warning(s)",
+        explanation.format,
+        count(Severity::Error),
+        count(Severity::Warning)
+    );
+    Ok(())
+}
+
+fn verify_provenance_cmd(provenance: VerifyProvenance) -> Result<()> {
+    let policy = match &provenance.policy {
+        Some(path) => read_policy(path)?,
+        None => Policy::default(),
+    };
+    let expected = ProvenanceExpectations {
+        source_uri: provenance.source_uri.clone(),
+        builder_id: provenance.builder_id.clone(),
+        tag: provenance.tag.clone(),
+        branch: provenance.branch.clone(),
+        workflow_inputs: provenance.workflow_inputs.iter().cloned().collect(),
+    };
+    let trusted_root = provenance.trust.load()?.ok_or_else(|| {
+        anyhow!("A trusted root is needed to verify the signature, pass --trusted-root or --tuf")
+    })?;
+    let pinned = read_pinned_keys(&provenance.keys)?;
+    let digest = format!("{:x}", Sha256::digest(std::fs::read(&provenance.artifact)?));
+    // Attestations are labelled by their index in the file, or their location in the store.
+    let (source, signed) = match (&provenance.file, &provenance.store) {
+        (Some(file), _) => {
+            let signed = verify::read_signed_statements(&input::read_to_string(file)?)?;
+            (
+                file.display().to_string(),
+                signed
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, signed)| (format!("/{}", i), signed))
+                    .collect::<Vec<_>>(),
+            )
+        }
+        (None, Some(location)) => {
+            let subject = SubjectDigest::new("sha256", &digest);
+            let attestations = store::open(location)?.get(&subject)?;
+            let required = provenance
+                .annotations
+                .iter()
+                .cloned()
+                .collect::<BTreeMap<_, _>>();
+            let found = attestations.len();
+            let attestations = attestations
+                .into_iter()
+                .filter(|attestation| attestation.has_annotations(&subject, &required))
+                .collect::<Vec<_>>();
+            if found > 0 && attestations.is_empty() {
+                return Err(anyhow!(
+                    "None of the {} attestation(s) in {} have the required annotations",
+                    found,
+                    location
+                ));
+            }
+            let signed = attestations
+                .into_iter()
+                .map(|attestation| {
+                    Ok((
+                        attestation.location.clone(),
+                        verify::signed_statement(&attestation.document)
+                            .map_err(|e| anyhow!("{}: {}", attestation.location, e))?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            (location.clone(), signed)
+        }
+        _ => return Err(anyhow!("Either a provenance file or --store is required")),
+    };
+
+    let (mut verified, mut failed) = (0, 0);
+    for (label, signed) in &signed {
+        let mut subject = ValidationReport::new();
+        attestations::check_subject_digest(&signed.statement, "sha256", &digest, &mut subject);
+        if subject.has_errors() {
+            continue;
+        }
+        // The signature is verified before the provenance's values are trusted.
+        let mut report = attestations::check_statement(&signed.statement, &policy);
+        report.extend(
+            verify::provenance::verify_signed_provenance(
+                &trusted_root,
+                signed,
+                &digest,
+                &expected,
+                &pinned,
+            )
+            .findings,
+        );
+        println!(
+            "{}: {}",
+            label,
+            signed
+                .statement
+                .get("predicateType")
+                .and_then(Value::as_str)
+                .unwrap_or("(no predicateType)")
+        );
+        print_findings(&report);
+        if report.has_errors() {
+            failed += 1;
+        } else {
+            verified += 1;
+        }
     }
+    if verified + failed == 0 {
+        return Err(anyhow!("No provenance in {} is about the artifact", source));
+    }
+    if failed > 0 {
+        return Err(anyhow!(
+            "{} provenance statement(s) failed verification",
+            failed
+        ));
+    }
+    println!(
+        "Verified {} provenance statement(s) for {}",
+        verified, provenance.source_uri
+    );
+    Ok(())
+}
+
+fn verify_linkage_cmd(linkage: VerifyLinkage) -> Result<()> {
+    let read_statement = |path: &PathBuf| -> Result<Value> {
+        let mut statements = verify::read_statements(&input::read_to_string(path)?)?;
+        match statements.len() {
+            1 => Ok(statements.remove(0)),
+            n => Err(anyhow!(
+                "Expected one statement in {}, found {}",
+                path.display(),
+                n
+            )),
+        }
+    };
+    let report = verify::linkage::check_linkage(
+        &read_statement(&linkage.sbom)?,
+        &read_statement(&linkage.provenance)?,
+    );
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    print_findings(&report.report);
+    if report.report.has_errors() {
+        return Err(anyhow!("The SBOM is not linked to the provenance"));
+    }
+    Ok(())
+}
+
+/// Patches statements and validates the results, writing only those that are valid.
+fn patch_cmd(patch_documents: PatchDocuments) -> Result<()> {
+    let patch_value =
+        serde_json::from_str::<Value>(&std::fs::read_to_string(&patch_documents.patch)?)?;
+    let patch = Patch::from_value(patch_value)?;
+    let policy = match &patch_documents.policy {
+        Some(path) => read_policy(path)?,
+        None => Policy::default(),
+    };
+    let mut failed = 0;
+    for file in &patch_documents.files {
+        if let Err(e) = log_document("patch", file, || {
+            patch_file(file, &patch, &policy, &patch_documents)
+        }) {
+            if !json_log() {
+                eprintln!("{}: {}", file.display(), e);
+            }
+            failed += 1;
+        }
+    }
+    if failed > 0 {
+        return Err(anyhow!(
+            "{} of {} statements could not be patched",
+            failed,
+            patch_documents.files.len()
+        ));
+    }
+    eprintln!("Patched {} statements", patch_documents.files.len());
+    Ok(())
+}
+
+/// Patches a statement and validates it, writing it where the options say.
+fn patch_file(file: &Path, patch: &Patch, policy: &Policy, options: &PatchDocuments) -> Result<()> {
+    let statement = serde_json::from_str::<Value>(&input::read_to_string(file)?)?;
+    let (patched, report) = patch::patch_statement(&statement, patch, policy)?;
+    print_findings(&report);
+    if report.has_errors() {
+        return Err(anyhow!("patched statement is not valid"));
+    }
+    let patched = serde_json::to_string_pretty(&patched)? + "\n";
+    if options.in_place {
+        std::fs::write(file, patched)?;
+    } else if let Some(dir) = &options.output_dir {
+        let name = file
+            .file_name()
+            .ok_or_else(|| anyhow!("{} has no file name", file.display()))?;
+        std::fs::write(dir.join(name), patched)?;
+    } else {
+        print!("{}", patched);
+    }
+    Ok(())
+}
+
+/// Reads an SPDX v2.3 or v2.2 document and validates it against its version's model.
+fn read_sbom(path: &Path) -> Result<Value> {
+    let document = serde_json::from_str::<Value>(&input::read_to_string(path)?)?;
+    match document.get("spdxVersion").and_then(Value::as_str) {
+        Some("SPDX-2.3") => GenericValidator::<Spdx23>::new()
+            .validate(&document)
+            .map(|_| ())?,
+        Some("SPDX-2.2") => GenericValidator::<Spdx22Document>::new()
+            .validate(&document)
+            .map(|_| ())?,
+        version => {
+            return Err(anyhow!(
+                "Unsupported spdxVersion {}, expected SPDX-2.3 or SPDX-2.2",
+                version.unwrap_or("(none)")
+            ))
+        }
+    }
+    Ok(document)
+}
+
+/// Prints whether each attestation is valid, followed by its findings.
+fn print_attestations(attestations: &[Attestation]) {
+    for attestation in attestations {
+        let status = if attestation.report.has_errors() {
+            "Invalid"
+        } else {
+            "Valid"
+        };
+        println!("{} attestation {}", status, attestation.predicate_type);
+        print_findings(&attestation.report);
+    }
+}
+
+/// Validates a document and pushes it with its validation report.
+fn push_cmd(push: Push) -> Result<()> {
+    let mut config = match push.config.clone().or_else(PushConfig::default_path) {
+        Some(path) if push.config.is_some() || path.exists() => PushConfig::from_file(&path)?,
+        _ => PushConfig::default(),
+    }
+    .with_env();
+    if let Some(endpoint) = push.endpoint {
+        config.endpoint = Some(endpoint);
+    }
+
+    let (document_type, file) = match &push.document {
+        ValidateDocumentSubCommand::InTotoV1(in_toto) => {
+            ("in-toto-v1".to_string(), in_toto.file.clone())
+        }
+        ValidateDocumentSubCommand::SPDXV23(spdx) => ("spdx-v23".to_string(), spdx.file.clone()),
+        ValidateDocumentSubCommand::SPDXV22(spdx) => ("spdx-v22".to_string(), spdx.file.clone()),
+        ValidateDocumentSubCommand::Cdxa(cdxa) => ("cdxa".to_string(), cdxa.file.clone()),
+        ValidateDocumentSubCommand::Plugin(plugin) => (plugin.name.clone(), plugin.file.clone()),
+    };
+    let report = validate_document_type(push.document)?;
+    let document = serde_json::from_str::<Value>(&input::read_to_string(&file)?)?;
+    push::push(
+        &config,
+        &Submission {
+            document_type: &document_type,
+            document: &document,
+            report: &report,
+        },
+    )?;
+    println!(
+        "Pushed {} to {}",
+        file.display(),
+        config.endpoint.unwrap_or_default()
+    );
+    Ok(())
+}
+
+/// Validates documents and pushes the valid ones into GUAC, as a collector would.
+fn guac_push_cmd(guac_push: GuacPush) -> Result<()> {
+    let endpoint = guac_push
+        .endpoint
+        .clone()
+        .or_else(|| std::env::var(guac::ENDPOINT_ENV).ok())
+        .ok_or_else(|| {
+            anyhow!(
+                "No GUAC endpoint to push to, set one with --endpoint or {}",
+                guac::ENDPOINT_ENV
+            )
+        })?;
+    let policy = match &guac_push.policy {
+        Some(path) => read_policy(path)?,
+        None => Policy::default(),
+    };
+
+    let mut documents = Vec::new();
+    let mut invalid = 0;
+    for file in &guac_push.files {
+        let statements = verify::read_statements(&input::read_to_string(file)?)?;
+        let several = statements.len() > 1;
+        for (i, document) in statements.into_iter().enumerate() {
+            let origin = if several {
+                format!("{}#{}", file.display(), i)
+            } else {
+                file.display().to_string()
+            };
+            let report = match document.get("spdxVersion").and_then(Value::as_str) {
+                Some(version) => {
+                    let result = match version {
+                        "SPDX-2.3" => GenericValidator::<Spdx23>::new()
+                            .validate(&document)
+                            .map(|_| ()),
+                        "SPDX-2.2" => GenericValidator::<Spdx22Document>::new()
+                            .validate(&document)
+                            .map(|_| ()),
+                        version => Err(anyhow!(
+                            "Unsupported spdxVersion {}, expected SPDX-2.3 or SPDX-2.2",
+                            version
+                        )),
+                    };
+                    let mut report = ValidationReport::new();
+                    if let Err(e) = result {
+                        report.push(Finding::error("invalid-document", "", e.to_string()));
+                    }
+                    report
+                }
+                None => attestations::check_statement(&document, &policy),
+            };
+            println!(
+                "{}: {}",
+                origin,
+                if report.has_errors() {
+                    "invalid"
+                } else {
+                    "valid"
+                }
+            );
+            print_findings(&report);
+            if report.has_errors() {
+                invalid += 1;
+            } else {
+                documents.push((origin, document));
+            }
+        }
+    }
+
+    let mut client = GuacClient::new(endpoint)
+        .with_batch_size(guac_push.batch_size)
+        .with_retries(guac_push.retries, Duration::from_secs(1));
+    if let Ok(token) = std::env::var(guac::TOKEN_ENV) {
+        client = client.with_token(token);
+    }
+    let documents = documents
+        .iter()
+        .map(|(origin, document)| GuacDocument { origin, document })
+        .collect::<Vec<_>>();
+    let summary = client.push(&documents)?;
+    println!(
+        "Pushed {} SLSA and {} SBOM relation(s) to GUAC in {} request(s)",
+        summary.slsa, summary.sboms, summary.requests
+    );
+    if invalid > 0 {
+        return Err(anyhow!(
+            "{} document(s) failed validation and were not pushed",
+            invalid
+        ));
+    }
+    Ok(())
 }
 
 /// Generates a schema for the specified type.
@@ -178,18 +2021,89 @@ fn generate_cmd(generate: SchemaGenerate) -> Result<()> {
     match generate.document {
         GenerateDocumentSubCommand::InTotoV1(in_toto) => generate_intoto_v1(in_toto),
         GenerateDocumentSubCommand::SLSAProvenanceV01 => generate_slsa_provenancev01(),
-        GenerateDocumentSubCommand::SCAIV02 => generate_scaiv02()
+        GenerateDocumentSubCommand::SCAIV02 => generate_scaiv02(),
+        GenerateDocumentSubCommand::SPDXV23 => print_schema::<Spdx23>(),
+        GenerateDocumentSubCommand::SPDXV22 => print_schema::<Spdx22Document>(),
+        GenerateDocumentSubCommand::All(all) => generate_all(all),
+        GenerateDocumentSubCommand::Plugin(generate_plugin) => {
+            let plugin = PluginRegistry::global()
+                .get(&generate_plugin.name)
+                .ok_or_else(|| anyhow!("No plugin named {}", generate_plugin.name))?;
+            println!("{}", serde_json::to_string_pretty(&plugin.schema())?);
+            Ok(())
+        }
+        GenerateDocumentSubCommand::OpenAPI => generate_openapi(),
     }
 }
 
 /// Handles validation for In-Toto v1 documents.
-fn validate_intoto_v1(in_toto: ValidateInTotoV1) -> Result<()> {
-    let file_str = std::fs::read_to_string(&in_toto.file)?;
-    let result = serde_json::from_str::<InTotoStatementV1>(&file_str);
+fn validate_intoto_v1(in_toto: ValidateInTotoV1) -> Result<ValidationReport> {
+    let file_str = input::read_to_string(&in_toto.file)?;
+    let mut options = if in_toto.strict {
+        ParseOptions::strict()
+    } else {
+        ParseOptions::default()
+    };
+    options.normalize_digests = in_toto.normalize_digests;
+    if let Some(statement_type) = in_toto.statement_type {
+        options.statement_type = statement_type.into();
+    }
+    let mut policy = match &in_toto.policy {
+        Some(path) => read_policy(path)?,
+        None => Policy::default(),
+    };
+    policy.strict |= in_toto.strict;
+    policy
+        .allowed_builders
+        .extend(in_toto.allow_builder.iter().cloned());
+    policy
+        .allowed_build_types
+        .extend(in_toto.allow_build_type.iter().cloned());
+    policy.max_age_seconds = in_toto.max_age.or(policy.max_age_seconds);
+    policy.not_after = in_toto.not_after.or(policy.not_after);
+    let predicate_schema = match &in_toto.predicate_schema {
+        Some(path) => Some(read_predicate_schema(path)?),
+        None => None,
+    };
+    let mut report = ValidationReport::new();
+    let result = serde_json::from_str::<Value>(&file_str)
+        .map_err(anyhow::Error::from)
+        .and_then(|value| {
+            // A DSSE envelope is validated by the statement it wraps.
+            let value = if value.get("payloadType").is_some() {
+                decode_dsse_statement(&value)?
+            } else {
+                value
+            };
+            Ok(InTotoStatementV1::from_value_with_options(
+                value,
+                &options,
+                &mut report,
+            )?)
+        });
 
-    match result {
+    let outcome: Result<()> = match result {
         Ok(statement) => {
             let pretty_json = serde_json::to_string_pretty(&statement)?;
+            report.extend(rules::check_statement(&statement, &policy).findings);
+            if let Some(rule) = &predicate_schema {
+                rule.check(&statement, &mut report);
+            }
+            print_findings(&report);
+            if report.has_errors() {
+                eprintln!("Document: {}", &pretty_json);
+                return Err(anyhow::anyhow!(
+                    "Document failed {} semantic check(s)",
+                    report.errors().count()
+                ));
+            }
+            if in_toto.deps_dev {
+                let document = serde_json::from_str::<Value>(&file_str)?;
+                let components = enrich::provenance_components(&document);
+                let annotations = depsdev::annotate(&in_toto.deps_dev_api, &components)?;
+                print_findings(&annotations);
+                report.extend(annotations.findings);
+            }
             match statement.predicate {
                 Predicate::SLSAProvenanceV1(_) => match in_toto.predicate {
                     Some(PredicateOption::SLSAProvenanceV1) => {
@@ -197,59 +2111,332 @@ fn validate_intoto_v1(in_toto: ValidateInTotoV1) -> Result<()> {
                         println!("Document: {}", &pretty_json);
                         Ok(())
                     }
-                    // TODO(mlieberman85): Uncomment below once additional predicate types are supported.
+                    // TODO(mlieberman85): Uncomment below once additional predicate types are supported.
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 SLSAProvenanceV1 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Invalid InTotoV1 SLSAProvenanceV1 document"
+                        ))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 SLSAProvenanceV1 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::SLSAProvenanceV02(_) => match in_toto.predicate {
+                    Some(PredicateOption::SLSAProvenanceV02) => {
+                        println!("Valid InTotoV1 SLSAProvenanceV02 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    // TODO(mlieberman85): Uncomment below once additional predicate types are supported.
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 SLSAProvenanceV02 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Invalid InTotoV1 SLSAProvenanceV02 document"
+                        ))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 SLSAProvenanceV02 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::SCAIV02(_) => match in_toto.predicate {
+                    Some(PredicateOption::SCAIV02Predicate) => {
+                        println!("Valid InTotoV1 SCAIV02Predicate document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 SCAIV02Predicate document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Invalid InTotoV1 SCAIV02Predicate document"
+                        ))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 SCAIV02Predicate document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                }
+                Predicate::SCAIV03(_) => match in_toto.predicate {
+                    Some(PredicateOption::SCAIV03Predicate) => {
+                        println!("Valid InTotoV1 SCAIV03Predicate document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 SCAIV03Predicate document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Invalid InTotoV1 SCAIV03Predicate document"
+                        ))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 SCAIV03Predicate document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::ApkoBuildV1(_) => {
+                    match in_toto.predicate {
+                        Some(PredicateOption::ApkoBuildV1) => {
+                            println!("Valid InTotoV1 ApkoBuildV1 document");
+                            println!("Document: {}", &pretty_json);
+                            Ok(())
+                        }
+                        Some(_) => {
+                            eprintln!("Invalid InTotoV1 ApkoBuildV1 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                            eprintln!("Document: {}", &pretty_json);
+                            Err(anyhow::anyhow!("Invalid InTotoV1 ApkoBuildV1 document"))
+                        }
+                        None => {
+                            println!("Valid InTotoV1 ApkoBuildV1 document");
+                            println!("Document: {}", &pretty_json);
+                            Ok(())
+                        }
+                    }
+                }
+                Predicate::MelangeBuildV1(_) => match in_toto.predicate {
+                    Some(PredicateOption::MelangeBuildV1) => {
+                        println!("Valid InTotoV1 MelangeBuildV1 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 MelangeBuildV1 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!("Invalid InTotoV1 MelangeBuildV1 document"))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 MelangeBuildV1 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::JvmBuildInfoV1(_) => match in_toto.predicate {
+                    Some(PredicateOption::JvmBuildInfoV1) => {
+                        println!("Valid InTotoV1 JvmBuildInfoV1 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 JvmBuildInfoV1 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!("Invalid InTotoV1 JvmBuildInfoV1 document"))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 JvmBuildInfoV1 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::VerificationSummaryV1(_) => match in_toto.predicate {
+                    Some(PredicateOption::SLSAVerificationSummaryV1) => {
+                        println!("Valid InTotoV1 SLSAVerificationSummaryV1 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 SLSAVerificationSummaryV1 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Invalid InTotoV1 SLSAVerificationSummaryV1 document"
+                        ))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 SLSAVerificationSummaryV1 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::Link(_) => match in_toto.predicate {
+                    Some(PredicateOption::Link) => {
+                        println!("Valid InTotoV1 Link document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!(
+                            "Invalid InTotoV1 Link document. Unexpected predicateType: {:?}",
+                            in_toto.predicate
+                        );
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!("Invalid InTotoV1 Link document"))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 Link document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::SpdxDocument(_) => match in_toto.predicate {
+                    Some(PredicateOption::SpdxDocument) => {
+                        println!("Valid InTotoV1 SpdxDocument document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
                     Some(_) => {
-                        eprintln!("Invalid InTotoV1 SLSAProvenanceV1 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Invalid InTotoV1 SpdxDocument document. Unexpected predicateType: {:?}", in_toto.predicate);
                         eprintln!("Document: {}", &pretty_json);
-                        Err(anyhow::anyhow!(
-                            "Invalid InTotoV1 SLSAProvenanceV1 document"
-                        ))
+                        Err(anyhow::anyhow!("Invalid InTotoV1 SpdxDocument document"))
                     }
                     None => {
-                        println!("Valid InTotoV1 SLSAProvenanceV1 document");
+                        println!("Valid InTotoV1 SpdxDocument document");
                         println!("Document: {}", &pretty_json);
                         Ok(())
                     }
                 },
-                Predicate::SLSAProvenanceV02(_) => match in_toto.predicate {
-                    Some(PredicateOption::SLSAProvenanceV02) => {
-                        println!("Valid InTotoV1 SLSAProvenanceV02 document");
+                Predicate::CycloneDX(_) => {
+                    match in_toto.predicate {
+                        Some(PredicateOption::CycloneDxBom) => {
+                            println!("Valid InTotoV1 CycloneDX document");
+                            println!("Document: {}", &pretty_json);
+                            Ok(())
+                        }
+                        Some(_) => {
+                            eprintln!("Invalid InTotoV1 CycloneDX document. Unexpected predicateType: {:?}", in_toto.predicate);
+                            eprintln!("Document: {}", &pretty_json);
+                            Err(anyhow::anyhow!("Invalid InTotoV1 CycloneDX document"))
+                        }
+                        None => {
+                            println!("Valid InTotoV1 CycloneDX document");
+                            println!("Document: {}", &pretty_json);
+                            Ok(())
+                        }
+                    }
+                }
+                Predicate::TestResultV01(_) => match in_toto.predicate {
+                    Some(PredicateOption::TestResultV01) => {
+                        println!("Valid InTotoV1 TestResultV01 document");
                         println!("Document: {}", &pretty_json);
                         Ok(())
                     }
-                    // TODO(mlieberman85): Uncomment below once additional predicate types are supported.
                     Some(_) => {
-                        eprintln!("Invalid InTotoV1 SLSAProvenanceV02 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Invalid InTotoV1 TestResultV01 document. Unexpected predicateType: {:?}", in_toto.predicate);
                         eprintln!("Document: {}", &pretty_json);
-                        Err(anyhow::anyhow!(
-                            "Invalid InTotoV1 SLSAProvenanceV02 document"
-                        ))
+                        Err(anyhow::anyhow!("Invalid InTotoV1 TestResultV01 document"))
                     }
                     None => {
-                        println!("Valid InTotoV1 SLSAProvenanceV02 document");
+                        println!("Valid InTotoV1 TestResultV01 document");
                         println!("Document: {}", &pretty_json);
                         Ok(())
                     }
                 },
-                Predicate::SCAIV02(_) => match in_toto.predicate {
-                    Some(PredicateOption::SCAIV02Predicate) => {
-                        println!("Valid InTotoV1 SCAIV02Predicate document");
+                Predicate::SarifV21(_) => {
+                    match in_toto.predicate {
+                        Some(PredicateOption::SarifV21) => {
+                            println!("Valid InTotoV1 SarifV21 document");
+                            println!("Document: {}", &pretty_json);
+                            Ok(())
+                        }
+                        Some(_) => {
+                            eprintln!("Invalid InTotoV1 SarifV21 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                            eprintln!("Document: {}", &pretty_json);
+                            Err(anyhow::anyhow!("Invalid InTotoV1 SarifV21 document"))
+                        }
+                        None => {
+                            println!("Valid InTotoV1 SarifV21 document");
+                            println!("Document: {}", &pretty_json);
+                            Ok(())
+                        }
+                    }
+                }
+                Predicate::CsafV2(_) => match in_toto.predicate {
+                    Some(PredicateOption::CsafV2) => {
+                        println!("Valid InTotoV1 CsafV2 document");
                         println!("Document: {}", &pretty_json);
                         Ok(())
                     }
                     Some(_) => {
-                        eprintln!("Invalid InTotoV1 SCAIV02Predicate document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!(
+                            "Invalid InTotoV1 CsafV2 document. Unexpected predicateType: {:?}",
+                            in_toto.predicate
+                        );
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!("Invalid InTotoV1 CsafV2 document"))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 CsafV2 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::ReferenceV01(_) => match in_toto.predicate {
+                    Some(PredicateOption::ReferenceV01) => {
+                        println!("Valid InTotoV1 ReferenceV01 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 ReferenceV01 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!("Invalid InTotoV1 ReferenceV01 document"))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 ReferenceV01 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::LicenseScanV01(_) => match in_toto.predicate {
+                    Some(PredicateOption::LicenseScanV01) => {
+                        println!("Valid InTotoV1 LicenseScanV01 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 LicenseScanV01 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!("Invalid InTotoV1 LicenseScanV01 document"))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 LicenseScanV01 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::CodeReviewV01(_) => match in_toto.predicate {
+                    Some(PredicateOption::CodeReviewV01) => {
+                        println!("Valid InTotoV1 CodeReviewV01 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 CodeReviewV01 document. Unexpected predicateType: {:?}", in_toto.predicate);
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!("Invalid InTotoV1 CodeReviewV01 document"))
+                    }
+                    None => {
+                        println!("Valid InTotoV1 CodeReviewV01 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                },
+                Predicate::DependencyReviewV01(_) => match in_toto.predicate {
+                    Some(PredicateOption::DependencyReviewV01) => {
+                        println!("Valid InTotoV1 DependencyReviewV01 document");
+                        println!("Document: {}", &pretty_json);
+                        Ok(())
+                    }
+                    Some(_) => {
+                        eprintln!("Invalid InTotoV1 DependencyReviewV01 document. Unexpected predicateType: {:?}", in_toto.predicate);
                         eprintln!("Document: {}", &pretty_json);
                         Err(anyhow::anyhow!(
-                            "Invalid InTotoV1 SCAIV02Predicate document"
+                            "Invalid InTotoV1 DependencyReviewV01 document"
                         ))
                     }
                     None => {
-                        println!("Valid InTotoV1 SCAIV02Predicate document");
+                        println!("Valid InTotoV1 DependencyReviewV01 document");
                         println!("Document: {}", &pretty_json);
                         Ok(())
                     }
-                }
+                },
                 _ => {
                     if let Some(PredicateOption::SLSAProvenanceV1) = in_toto.predicate {
                         eprintln!("Invalid InTotoV1 SLSAProvenanceV1 document");
@@ -273,6 +2460,113 @@ fn validate_intoto_v1(in_toto: ValidateInTotoV1) -> Result<()> {
                             "Unexpected predicateType: {:?}",
                             statement.predicate_type.as_str()
                         ))
+                    } else if let Some(PredicateOption::SCAIV03Predicate) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 SCAIV03Predicate document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::ApkoBuildV1) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 ApkoBuildV1 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::MelangeBuildV1) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 MelangeBuildV1 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::JvmBuildInfoV1) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 JvmBuildInfoV1 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::SLSAVerificationSummaryV1) =
+                        in_toto.predicate
+                    {
+                        eprintln!("Invalid InTotoV1 SLSAVerificationSummaryV1 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::Link) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 Link document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::SpdxDocument) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 SpdxDocument document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::CycloneDxBom) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 CycloneDX document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::TestResultV01) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 TestResultV01 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::SarifV21) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 SarifV21 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::CsafV2) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 CsafV2 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::ReferenceV01) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 ReferenceV01 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::LicenseScanV01) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 LicenseScanV01 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::CodeReviewV01) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 CodeReviewV01 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
+                    } else if let Some(PredicateOption::DependencyReviewV01) = in_toto.predicate {
+                        eprintln!("Invalid InTotoV1 DependencyReviewV01 document");
+                        eprintln!("Document: {}", &pretty_json);
+                        Err(anyhow::anyhow!(
+                            "Unexpected predicateType: {:?}",
+                            statement.predicate_type.as_str()
+                        ))
                     } else {
                         println!(
                             "Unknown predicateType: {:?}",
@@ -287,16 +2581,66 @@ fn validate_intoto_v1(in_toto: ValidateInTotoV1) -> Result<()> {
         Err(err) => {
             // TODO(mlieberman85): Figure out how to add all the fields that are incorrect between a valid SLSA statement and the one that is being validated.
             // Right now it only prints the first error.
+            print_findings(&report);
             eprintln!("Error parsing JSON: {}", err);
-            Err(err.into())
+            Err(err)
+        }
+    };
+    outcome.map(|_| report)
+}
+
+/// Reads a JSON schema file to check predicates against.
+fn read_predicate_schema(path: &PathBuf) -> Result<PredicateSchemaRule> {
+    let schema = serde_json::from_str::<Value>(&std::fs::read_to_string(path)?)
+        .map_err(|e| anyhow::anyhow!("Invalid predicate schema {}: {}", path.display(), e))?;
+    PredicateSchemaRule::new(schema)
+        .map_err(|e| anyhow::anyhow!("Invalid predicate schema {}: {}", path.display(), e))
+}
+
+/// Reads a JSON policy file.
+fn read_policy(path: &PathBuf) -> Result<Policy> {
+    let policy_str = std::fs::read_to_string(path)?;
+    serde_json::from_str::<Policy>(&policy_str)
+        .map_err(|e| anyhow::anyhow!("Invalid policy file {}: {}", path.display(), e))
+}
+
+/// Prints the findings of a semantic validation report to stderr.
+fn print_findings(report: &ValidationReport) {
+    if json_log() {
+        if let Ok(mut findings) = LOGGED_FINDINGS.lock() {
+            findings.extend(report.findings.iter().cloned());
         }
+        return;
+    }
+    for finding in &report.findings {
+        eprintln!("{}", finding);
+    }
+}
+
+/// Validates a CycloneDX attestation document and the references between its claims, evidence
+/// and attestations.
+fn validate_cdxa(file: &Path) -> Result<ValidationReport> {
+    let value = serde_json::from_str::<Value>(&input::read_to_string(file)?)?;
+    let document = GenericValidator::<CycloneDxAttestation>::new().validate(&value)?;
+    let report = rules::cdxa::check_cdxa(&document);
+    print_findings(&report);
+    let pretty_json = serde_json::to_string_pretty(&value)?;
+    if report.has_errors() {
+        eprintln!("Document: {}", &pretty_json);
+        return Err(anyhow!(
+            "Document failed {} semantic check(s)",
+            report.errors().count()
+        ));
     }
+    println!("Valid CycloneDX attestation document");
+    println!("Document: {}", &pretty_json);
+    Ok(report)
 }
 
 /// Handles simpler validation of documents.
 /// TODO(mlieberman85): Over time this should handle the logic for validation of all document types.
-fn validate_document<T: DeserializeOwned>(file_path: PathBuf) -> Result<()> {
-    let file_str = std::fs::read_to_string(&file_path)?;
+fn validate_document<T: DeserializeOwned>(file_path: PathBuf) -> Result<ValidationReport> {
+    let file_str = input::read_to_string(&file_path)?;
     let file_value = serde_json::from_str::<Value>(&file_str)?;
     let result = GenericValidator::<T>::new().validate(&file_value);
 
@@ -305,11 +2649,11 @@ fn validate_document<T: DeserializeOwned>(file_path: PathBuf) -> Result<()> {
             let pretty_json = serde_json::to_string_pretty(&file_value)?;
             println!("Valid document");
             println!("Document: {}", &pretty_json);
-            Ok(())
+            Ok(ValidationReport::new())
         }
         Err(err) => {
             eprintln!("Error parsing JSON: {}", err);
-            Err(err.into())
+            Err(err)
         }
     }
 }
@@ -320,6 +2664,23 @@ fn generate_intoto_v1(in_toto: GenerateInTotoV1) -> Result<()> {
         Some(PredicateOption::SLSAProvenanceV1) => print_schema::<SLSAProvenanceV1Predicate>(),
         Some(PredicateOption::SLSAProvenanceV02) => print_schema::<SLSAProvenanceV02Predicate>(),
         Some(PredicateOption::SCAIV02Predicate) => print_schema::<SCAIV02Predicate>(),
+        Some(PredicateOption::SCAIV03Predicate) => print_schema::<SCAIV03Predicate>(),
+        Some(PredicateOption::ApkoBuildV1) => print_schema::<ApkoBuildPredicate>(),
+        Some(PredicateOption::MelangeBuildV1) => print_schema::<MelangeBuildPredicate>(),
+        Some(PredicateOption::JvmBuildInfoV1) => print_schema::<JvmBuildInfoPredicate>(),
+        Some(PredicateOption::SLSAVerificationSummaryV1) => {
+            print_schema::<VerificationSummaryV1Predicate>()
+        }
+        Some(PredicateOption::Link) => print_schema::<LinkPredicate>(),
+        Some(PredicateOption::SpdxDocument) => print_schema::<SpdxDocumentPredicate>(),
+        Some(PredicateOption::CycloneDxBom) => print_schema::<CycloneDxBom>(),
+        Some(PredicateOption::TestResultV01) => print_schema::<TestResultPredicate>(),
+        Some(PredicateOption::SarifV21) => print_schema::<SarifLog>(),
+        Some(PredicateOption::CsafV2) => print_schema::<CsafDocument>(),
+        Some(PredicateOption::ReferenceV01) => print_schema::<ReferencePredicate>(),
+        Some(PredicateOption::LicenseScanV01) => print_schema::<LicenseScanPredicate>(),
+        Some(PredicateOption::CodeReviewV01) => print_schema::<CodeReviewPredicate>(),
+        Some(PredicateOption::DependencyReviewV01) => print_schema::<DependencyReviewPredicate>(),
         None => print_schema::<InTotoStatementV1>(),
     }
 }
@@ -332,39 +2693,114 @@ fn generate_slsa_provenancev01() -> Result<()> {
     print_schema::<InTotoStatementV1<SLSAProvenanceV1Predicate>>()
 }
 
-/// Generates Rust code from a JSON schema file.
+/// Writes the schema for every document type in the registry to a directory.
+fn generate_all(all: GenerateAll) -> Result<()> {
+    std::fs::create_dir_all(&all.out_dir)?;
+    for entry in SchemaRegistry::entries() {
+        let path = all.out_dir.join(format!("{}.json", entry.name));
+        let schema = serde_json::to_string_pretty(&entry.root_schema())?;
+        std::fs::write(&path, format!("{}\n", schema))?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// Generates Markdown field references for the document types in the registry.
+fn docs_generate_cmd(dg: DocsGenerate) -> Result<()> {
+    let entries = match &dg.name {
+        Some(name) => vec![SchemaRegistry::get(name).ok_or_else(|| {
+            let names = SchemaRegistry::entries()
+                .iter()
+                .map(|entry| entry.name)
+                .collect::<Vec<_>>();
+            anyhow!(
+                "Unknown document type {}, expected one of: {}",
+                name,
+                names.join(", ")
+            )
+        })?],
+        None => SchemaRegistry::entries().iter().collect(),
+    };
+
+    for entry in entries {
+        let markdown = docs::markdown_reference(&entry.schema());
+        match &dg.out_dir {
+            Some(out_dir) => {
+                std::fs::create_dir_all(out_dir)?;
+                let path = out_dir.join(format!("{}.md", entry.name));
+                std::fs::write(&path, markdown)?;
+                println!("Wrote {}", path.display());
+            }
+            None => print!("{}", markdown),
+        }
+    }
+    Ok(())
+}
+
+fn generate_openapi() -> Result<()> {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&openapi::openapi_document())?
+    );
+    Ok(())
+}
+
+/// Generates code from a JSON schema or protobuf descriptor file.
 fn code_generate_cmd(cg: CodeGenerate) -> Result<()> {
     match cg.codegen {
         CodeGenerateSubCommand::JsonSchema(json_schema) => {
             let schema_str = std::fs::read_to_string(&json_schema.file)?;
-            generate_rust_code(schema_str)
+            let schema = serde_json::from_str::<Value>(&schema_str)?;
+            generate_code(&schema, json_schema.output)
+        }
+        CodeGenerateSubCommand::Protobuf(protobuf) => {
+            let descriptor_set = std::fs::read(&protobuf.file)?;
+            let schema = codegen::protobuf::descriptor_set_to_schema(&descriptor_set)?;
+            generate_code(&schema, protobuf.output)
         }
     }
 }
 
-/// Generates Rust code from a JSON schema.
-fn generate_rust_code(schema_str: String) -> Result<()> {
-    let schema = serde_json::from_str::<schemars::schema::RootSchema>(&schema_str)?;
-    let mut type_space = TypeSpace::new(
-        TypeSpaceSettings::default()
-            // NOTE: Below allows us to also make the code be able to generate JSON schemas back from the Rust code.
-            .with_derive("schemars::JsonSchema".into())
-            .with_struct_builder(true),
-    );
-    type_space.add_root_schema(schema)?;
-
-    let contents = format!(
-        "{}\n{}\n{}\n{}\n{}",
-        "//! This file is generated by typify through Spector. Do not edit it directly.\n\
-        //! Exceptions to this rule are for cases where typify doesn't genrate the correct code.",
-        "#![allow(clippy::all)]",
-        "#![allow(warnings)]",
-        "use serde::{Deserialize, Serialize};",
-        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream())?)
-    );
-    println!("{}", contents);
+/// Generates code from a JSON schema, printing it or writing it to the output directory.
+fn generate_code(schema: &Value, output: CodeGenerateOutput) -> Result<()> {
+    let options = output.options();
+    match output.out_dir {
+        Some(out_dir) => {
+            let files = codegen::generate_files(schema, output.lang.into(), &options)?;
+            codegen::write_files(&out_dir, &files)
+        }
+        None => {
+            let contents = codegen::generate_with_options(schema, output.lang.into(), &options)?;
+            println!("{}", contents);
+            Ok(())
+        }
+    }
+}
 
-    Ok(())
+fn schema_cmd(schema: Schema) -> Result<()> {
+    match schema.command {
+        SchemaSubCommand::Sample(sample) => {
+            let schema_str = std::fs::read_to_string(&sample.file)?;
+            let schema = serde_json::from_str::<Value>(&schema_str)?;
+            let document = spector::schema::sample::sample(&schema, sample.seed)?;
+            println!("{}", serde_json::to_string_pretty(&document)?);
+            Ok(())
+        }
+        SchemaSubCommand::Compat(compat) => {
+            let old = serde_json::from_str::<Value>(&std::fs::read_to_string(&compat.old)?)?;
+            let new = serde_json::from_str::<Value>(&std::fs::read_to_string(&compat.new)?)?;
+            let report = compat::check_compatibility(&old, &new);
+            print_findings(&report);
+            if report.has_errors() {
+                return Err(anyhow!(
+                    "Schema has {} breaking change(s)",
+                    report.errors().count()
+                ));
+            }
+            println!("Schema changes are backward-compatible");
+            Ok(())
+        }
+    }
 }
 
 /// Prints a JSON schema for the given type T.
@@ -374,16 +2810,61 @@ fn print_schema<T: serde::Serialize + schemars::JsonSchema>() -> Result<()> {
     Ok(())
 }
 
+/// Returns the embedded schema for a document based on its predicateType.
+fn embedded_schema(document: &Value) -> Result<Value> {
+    let predicate_type = document
+        .get("predicateType")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("No schema file given and the document has no predicateType"))?;
+    let entry = SchemaRegistry::for_predicate_type(predicate_type).ok_or_else(|| {
+        anyhow!(
+            "No embedded schema for predicateType {}, a schema file must be given",
+            predicate_type
+        )
+    })?;
+    Ok(entry.schema())
+}
+
 /// Handles validation of documents to JSON schemas.
 ///
 /// Prints the document if valid, otherwise prints an error message
 fn schema_validate_cmd<T: DeserializeOwned>(sv: SchemaValidate) -> Result<()> {
-    let file_str = std::fs::read_to_string(&sv.file)?;
-    let schema_str = std::fs::read_to_string(&sv.schema)?;
-    let schema = serde_json::from_str::<serde_json::Value>(&schema_str)?;
-    let validator = validate::JSONSchemaValidator::<Value>::new(&schema);
+    let file = sv.file.clone();
+    log_document("schema-validate", &file, || {
+        schema_validate_document::<T>(sv)
+    })
+}
+
+fn schema_validate_document<T: DeserializeOwned>(sv: SchemaValidate) -> Result<()> {
+    let file_str = input::read_to_string(&sv.file)?;
     let document = serde_json::from_str::<serde_json::Value>(&file_str)?;
-    let result: std::result::Result<Value, anyhow::Error> = validator.validate(&document);
+    let options = FetchOptions {
+        cache_dir: sv.cache_dir.clone().or_else(fetch::default_cache_dir),
+        sha256: sv.sha256.clone(),
+        refresh: sv.refresh,
+    };
+    let schema_url = sv.schema.as_deref().filter(|schema| fetch::is_url(schema));
+    let schema = match &sv.schema {
+        Some(url) if fetch::is_url(url) => fetch::fetch_schema(url, &options)?,
+        Some(_) | None if sv.sha256.is_some() => {
+            return Err(anyhow!("--sha256 can only be used with a schema URL"));
+        }
+        Some(schema_path) => {
+            let schema_str = std::fs::read_to_string(schema_path)?;
+            serde_json::from_str::<serde_json::Value>(&schema_str)?
+        }
+        None => embedded_schema(&document)?,
+    };
+    let schema_report = meta_schema::check_schema(&schema);
+    print_findings(&schema_report);
+    if schema_report.has_errors() {
+        return Err(anyhow!("Schema is not valid against its meta-schema"));
+    }
+    // Only the schema check matters here, the document is deserialized into T below.
+    let documents = fetch::fetch_remote_refs(&schema, schema_url, &options)?;
+    let validator =
+        validate::JSONSchemaValidator::<IgnoredAny>::new(&schema).with_documents(documents);
+    let result = validator.validate(&document);
 
     match result {
         Ok(_) => {
@@ -401,13 +2882,30 @@ fn schema_validate_cmd<T: DeserializeOwned>(sv: SchemaValidate) -> Result<()> {
         }
         Err(err) => {
             eprintln!("Error validating document against JSON schema: {}", err);
-            Err(err.into())
+            Err(err)
         }
     }
 }
 
 fn main() {
+    // Traces of the library go to stderr when SPECTOR_LOG is set, e.g. to spector=debug.
+    if let Ok(filter) = tracing_subscriber::EnvFilter::try_from_env("SPECTOR_LOG") {
+        tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(std::io::stderr)
+            .with_ansi(std::io::IsTerminal::is_terminal(&std::io::stderr()))
+            .init();
+    }
     let opts: Spector = Spector::parse();
+    let _ = LOG_FORMAT.set(opts.log_format);
+    #[cfg(feature = "dynamic-plugins")]
+    for path in &opts.plugins {
+        // The libraries are the ones the user asked for, built as documented for PluginRegistry::load.
+        if let Err(e) = unsafe { PluginRegistry::global().load(path) } {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
     match opts.command {
         Command::Validate(validate) => {
             if let Err(e) = validate_cmd(validate) {
@@ -435,5 +2933,83 @@ fn main() {
                 process::exit(1);
             }
         }
+        Command::Schema(schema) => {
+            if let Err(e) = schema_cmd(schema) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::DocsGenerate(dg) => {
+            if let Err(e) = docs_generate_cmd(dg) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Push(push) => {
+            if let Err(e) = push_cmd(push) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::GuacPush(guac_push) => {
+            if let Err(e) = guac_push_cmd(guac_push) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Fetch(fetch) => {
+            if let Err(e) = fetch_cmd(fetch) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Sbom(sbom) => {
+            if let Err(e) = sbom_cmd(sbom) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Consistency(consistency) => {
+            if let Err(e) = consistency_cmd(consistency) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Patch(patch_documents) => {
+            if let Err(e) = patch_cmd(patch_documents) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Verify(verify_command) => {
+            if let Err(e) = verify_cmd(verify_command) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::ExportBundle(export) => {
+            if let Err(e) = export_bundle_cmd(export) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Import(import) => {
+            if let Err(e) = import_cmd(import) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::TestCorpus(test_corpus) => {
+            if let Err(e) = test_corpus_cmd(test_corpus) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Command::Explain(explain_args) => {
+            if let Err(e) = explain_cmd(explain_args) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
     }
 }
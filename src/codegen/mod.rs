@@ -0,0 +1,212 @@
+//! Code generation from JSON schemas.
+//!
+//! Rust code is generated with typify. The other languages are emitted directly from the
+//! schema and its `definitions` by the generators in this module, which support the subset of
+//! JSON Schema produced by schemars and used by the supply chain specs.
+
+pub mod protobuf;
+pub mod python;
+pub mod rust;
+pub mod typescript;
+
+use anyhow::{bail, Result};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// The languages code can be generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// Rust types with serde and schemars derives.
+    Rust,
+    /// TypeScript type definitions, suitable for a `.d.ts` file.
+    TypeScript,
+    /// TypeScript zod schemas with their inferred types.
+    Zod,
+    /// Python pydantic models.
+    Python,
+}
+
+/// Options for code generation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodegenOptions {
+    /// Generates a standalone crate with this name when generating Rust files.
+    pub crate_name: Option<String>,
+    /// Options for Rust code, which are ignored for the other languages.
+    pub rust: rust::RustOptions,
+}
+
+/// Generates code in the given language from a JSON schema.
+pub fn generate(schema: &Value, language: Language) -> Result<String> {
+    generate_with_options(schema, language, &CodegenOptions::default())
+}
+
+/// Generates code in the given language from a JSON schema with the given options.
+pub fn generate_with_options(
+    schema: &Value,
+    language: Language,
+    options: &CodegenOptions,
+) -> Result<String> {
+    match language {
+        Language::Rust => rust::generate_rust_with_options(schema, &options.rust),
+        Language::TypeScript => typescript::generate_typescript(schema),
+        Language::Zod => typescript::generate_zod(schema),
+        Language::Python => python::generate_python(schema),
+    }
+}
+
+/// A file produced by code generation, with a path relative to the output directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+/// Generates code in the given language from a JSON schema, laid out as a tree of files.
+///
+/// Rust code is split into a module with one file per top-level definition. If a crate name is
+/// given, the module becomes the root of a standalone crate with its own `Cargo.toml`. The
+/// other languages are generated as a single file.
+pub fn generate_files(
+    schema: &Value,
+    language: Language,
+    options: &CodegenOptions,
+) -> Result<Vec<GeneratedFile>> {
+    let crate_name = options.crate_name.as_deref();
+    let file_name = match language {
+        Language::Rust => return rust::generate_rust_module(schema, crate_name, &options.rust),
+        _ if crate_name.is_some() => {
+            bail!("A crate can only be generated for Rust code")
+        }
+        Language::TypeScript => "index.d.ts",
+        Language::Zod => "schemas.ts",
+        Language::Python => "models.py",
+    };
+    Ok(vec![GeneratedFile {
+        path: PathBuf::from(file_name),
+        contents: generate_with_options(schema, language, options)?,
+    }])
+}
+
+/// Writes generated files to a directory, creating it and any subdirectories as needed.
+pub fn write_files(dir: &Path, files: &[GeneratedFile]) -> Result<()> {
+    for file in files {
+        let path = dir.join(&file.path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, &file.contents)?;
+    }
+    Ok(())
+}
+
+/// Returns the type name for the root of a schema, taken from its title.
+///
+/// Like typify, the generators skip the root of schemas without a title, which only serve as a
+/// container for their definitions.
+pub(crate) fn root_type_name(schema: &Value) -> Option<String> {
+    schema.get("title").and_then(Value::as_str).map(type_name)
+}
+
+/// Returns the named definitions of a schema, sorted by name.
+pub(crate) fn definitions(schema: &Value) -> Vec<(&String, &Value)> {
+    let mut definitions = ["definitions", "$defs"]
+        .iter()
+        .filter_map(|key| schema.get(*key).and_then(Value::as_object))
+        .flat_map(|map| map.iter())
+        .collect::<Vec<_>>();
+    definitions.sort_by(|a, b| a.0.cmp(b.0));
+    definitions
+}
+
+/// Converts a schema title or definition name into a PascalCase type name.
+pub(crate) fn type_name(name: &str) -> String {
+    let mut result = String::new();
+    for part in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+            result.extend(chars);
+        }
+    }
+    if result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert(0, '_');
+    }
+    result
+}
+
+/// Converts a camelCase, PascalCase or kebab-case name into snake_case.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if previous_lower {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+            previous_lower = false;
+        } else if c.is_ascii_alphanumeric() {
+            result.push(c);
+            previous_lower = true;
+        } else {
+            if !result.is_empty() && !result.ends_with('_') {
+                result.push('_');
+            }
+            previous_lower = false;
+        }
+    }
+    result.trim_end_matches('_').to_string()
+}
+
+/// Returns the type name a local `$ref` such as `#/definitions/Subject` refers to.
+pub(crate) fn ref_type_name(reference: &str) -> String {
+    type_name(reference.rsplit('/').next().unwrap_or(reference))
+}
+
+/// Returns the schema's `type` keyword as a list of type names.
+pub(crate) fn schema_types(schema: &serde_json::Map<String, Value>) -> Vec<&str> {
+    match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns the names of the required properties of an object schema.
+pub(crate) fn required_properties(schema: &serde_json::Map<String, Value>) -> Vec<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// Returns the description of a schema, if any.
+pub(crate) fn description(schema: &Value) -> Option<&str> {
+    schema.get("description").and_then(Value::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!(
+            type_name("InTotoStatementV1_for_Predicate"),
+            "InTotoStatementV1ForPredicate"
+        );
+        assert_eq!(type_name("build-config"), "BuildConfig");
+        assert_eq!(ref_type_name("#/definitions/Subject"), "Subject");
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(
+            snake_case("InTotoStatementV1ForPredicate"),
+            "in_toto_statement_v1_for_predicate"
+        );
+        assert_eq!(snake_case("predicateType"), "predicate_type");
+        assert_eq!(snake_case("build-id"), "build_id");
+    }
+}
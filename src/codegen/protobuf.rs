@@ -0,0 +1,475 @@
+//! Conversion of protobuf descriptors into JSON schemas.
+//!
+//! Messages and enums from a `FileDescriptorSet` (as produced by `protoc --descriptor_set_out`
+//! or `buf build`) are converted to JSON schema definitions that follow the proto3 JSON mapping,
+//! so the types generated from them serialize the same way the protobuf JSON encoders do.
+
+use anyhow::Result;
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorProto,
+    FileDescriptorSet,
+};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+use super::type_name;
+
+// Field numbers from descriptor.proto, used to find comments in the source code info.
+const FILE_MESSAGE_TYPE: i32 = 4;
+const FILE_ENUM_TYPE: i32 = 5;
+const MESSAGE_FIELD: i32 = 2;
+const MESSAGE_NESTED_TYPE: i32 = 3;
+const MESSAGE_ENUM_TYPE: i32 = 4;
+
+/// Converts an encoded `FileDescriptorSet` into a JSON schema.
+///
+/// The schema has no root type, only a definition for every message and enum in the set.
+pub fn descriptor_set_to_schema(bytes: &[u8]) -> Result<Value> {
+    let descriptor_set = FileDescriptorSet::decode(bytes)?;
+    Ok(file_descriptor_set_to_schema(&descriptor_set))
+}
+
+/// Converts a `FileDescriptorSet` into a JSON schema.
+pub fn file_descriptor_set_to_schema(descriptor_set: &FileDescriptorSet) -> Value {
+    let mut converter = Converter::default();
+    for file in &descriptor_set.file {
+        converter.collect_file(file);
+    }
+    converter.assign_names();
+    for file in &descriptor_set.file {
+        converter.convert_file(file);
+    }
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "definitions": converter.definitions,
+    })
+}
+
+#[derive(Default)]
+struct Converter<'a> {
+    /// Messages and enums by their fully qualified name, e.g. `.in_toto.v1.Statement`, along
+    /// with their name relative to the package.
+    messages: HashMap<String, (&'a DescriptorProto, String)>,
+    enums: HashMap<String, String>,
+    /// Definition names by fully qualified name.
+    names: HashMap<String, String>,
+    definitions: Map<String, Value>,
+}
+
+impl<'a> Converter<'a> {
+    fn collect_file(&mut self, file: &'a FileDescriptorProto) {
+        let prefix = package_prefix(file);
+        for message in &file.message_type {
+            self.collect_message(message, &prefix, "");
+        }
+        for enumeration in &file.enum_type {
+            let name = enumeration.name().to_string();
+            self.enums.insert(format!("{}{}", prefix, name), name);
+        }
+    }
+
+    fn collect_message(&mut self, message: &'a DescriptorProto, prefix: &str, parent: &str) {
+        let relative_name = format!("{}{}", parent, message.name());
+        for nested in &message.nested_type {
+            self.collect_message(nested, prefix, &format!("{}.", relative_name));
+        }
+        for enumeration in &message.enum_type {
+            let name = format!("{}.{}", relative_name, enumeration.name());
+            self.enums.insert(format!("{}{}", prefix, name), name);
+        }
+        self.messages.insert(
+            format!("{}{}", prefix, relative_name),
+            (message, relative_name),
+        );
+    }
+
+    /// Names definitions after their name within their package, falling back to the fully
+    /// qualified name if that is ambiguous.
+    fn assign_names(&mut self) {
+        let relative_names = self
+            .messages
+            .iter()
+            .filter(|(_, (message, _))| !is_map_entry(message))
+            .map(|(full_name, (_, relative_name))| (full_name, relative_name))
+            .chain(self.enums.iter())
+            .collect::<Vec<_>>();
+
+        let mut counts = HashMap::<String, usize>::new();
+        for (_, relative_name) in &relative_names {
+            *counts.entry(type_name(relative_name)).or_default() += 1;
+        }
+        for (full_name, relative_name) in relative_names {
+            let name = type_name(relative_name);
+            let name = if counts[&name] > 1 {
+                type_name(full_name)
+            } else {
+                name
+            };
+            self.names.insert(full_name.clone(), name);
+        }
+    }
+
+    fn convert_file(&mut self, file: &'a FileDescriptorProto) {
+        let prefix = package_prefix(file);
+        let comments = comments(file);
+        for (i, message) in file.message_type.iter().enumerate() {
+            self.convert_message(
+                message,
+                &prefix,
+                "",
+                &[FILE_MESSAGE_TYPE, i as i32],
+                &comments,
+            );
+        }
+        for (i, enumeration) in file.enum_type.iter().enumerate() {
+            self.convert_enum(
+                enumeration,
+                &format!("{}{}", prefix, enumeration.name()),
+                comments.get(&vec![FILE_ENUM_TYPE, i as i32]),
+            );
+        }
+    }
+
+    fn convert_message(
+        &mut self,
+        message: &'a DescriptorProto,
+        prefix: &str,
+        parent: &str,
+        path: &[i32],
+        comments: &HashMap<Vec<i32>, String>,
+    ) {
+        let relative_name = format!("{}{}", parent, message.name());
+        for (i, nested) in message.nested_type.iter().enumerate() {
+            let nested_path = [path, &[MESSAGE_NESTED_TYPE, i as i32]].concat();
+            self.convert_message(
+                nested,
+                prefix,
+                &format!("{}.", relative_name),
+                &nested_path,
+                comments,
+            );
+        }
+        for (i, enumeration) in message.enum_type.iter().enumerate() {
+            let enum_path = [path, &[MESSAGE_ENUM_TYPE, i as i32]].concat();
+            self.convert_enum(
+                enumeration,
+                &format!("{}{}.{}", prefix, relative_name, enumeration.name()),
+                comments.get(&enum_path),
+            );
+        }
+        if is_map_entry(message) {
+            return;
+        }
+
+        let mut properties = Map::new();
+        for (i, field) in message.field.iter().enumerate() {
+            let mut schema = self.field_schema(field);
+            let field_path = [path, &[MESSAGE_FIELD, i as i32]].concat();
+            if let Some(comment) = comments.get(&field_path) {
+                schema = with_description(schema, comment);
+            }
+            properties.insert(json_name(field), schema);
+        }
+
+        let mut schema = json!({"type": "object"});
+        if !properties.is_empty() {
+            schema["properties"] = Value::Object(properties);
+        }
+        if let Some(comment) = comments.get(path) {
+            schema["description"] = Value::String(comment.clone());
+        }
+        let name = self.names[&format!("{}{}", prefix, relative_name)].clone();
+        self.definitions.insert(name, schema);
+    }
+
+    fn convert_enum(
+        &mut self,
+        enumeration: &EnumDescriptorProto,
+        full_name: &str,
+        comment: Option<&String>,
+    ) {
+        let values = enumeration
+            .value
+            .iter()
+            .map(|value| Value::String(value.name().to_string()))
+            .collect::<Vec<_>>();
+        let mut schema = json!({"type": "string", "enum": values});
+        if let Some(comment) = comment {
+            schema["description"] = Value::String(comment.clone());
+        }
+        let name = self.names[full_name].clone();
+        self.definitions.insert(name, schema);
+    }
+
+    fn field_schema(&self, field: &FieldDescriptorProto) -> Value {
+        if let Some((entry, _)) = self.messages.get(field.type_name()) {
+            if is_map_entry(entry) {
+                let value_schema = entry
+                    .field
+                    .iter()
+                    .find(|f| f.number() == 2)
+                    .map(|f| self.type_schema(f))
+                    .unwrap_or(Value::Bool(true));
+                return json!({"type": "object", "additionalProperties": value_schema});
+            }
+        }
+
+        let schema = self.type_schema(field);
+        if field.label() == Label::Repeated {
+            json!({"type": "array", "items": schema})
+        } else {
+            schema
+        }
+    }
+
+    /// Returns the schema for a single value of a field, following the proto3 JSON mapping.
+    fn type_schema(&self, field: &FieldDescriptorProto) -> Value {
+        match field.r#type() {
+            Type::Double | Type::Float => json!({"type": "number"}),
+            Type::Int32 | Type::Sint32 | Type::Sfixed32 => {
+                json!({"type": "integer", "format": "int32"})
+            }
+            Type::Uint32 | Type::Fixed32 => json!({"type": "integer", "format": "uint32"}),
+            // 64-bit integers are encoded as strings since JSON numbers can't represent them
+            // exactly.
+            Type::Int64 | Type::Sint64 | Type::Sfixed64 | Type::Uint64 | Type::Fixed64 => {
+                json!({"type": "string"})
+            }
+            Type::Bool => json!({"type": "boolean"}),
+            Type::String => json!({"type": "string"}),
+            Type::Bytes => json!({"type": "string", "contentEncoding": "base64"}),
+            Type::Enum | Type::Message | Type::Group => well_known_type_schema(field.type_name())
+                .unwrap_or_else(|| match self.names.get(field.type_name()) {
+                    Some(name) => json!({"$ref": format!("#/definitions/{}", name)}),
+                    None => Value::Bool(true),
+                }),
+        }
+    }
+}
+
+/// Returns the schema for the JSON mapping of a google.protobuf well-known type.
+fn well_known_type_schema(type_name: &str) -> Option<Value> {
+    let schema = match type_name.strip_prefix(".google.protobuf.")? {
+        "Struct" | "Any" | "Empty" => json!({"type": "object"}),
+        "Value" => Value::Bool(true),
+        "ListValue" => json!({"type": "array"}),
+        "Timestamp" => json!({"type": "string", "format": "date-time"}),
+        "Duration" | "FieldMask" | "StringValue" | "Int64Value" | "UInt64Value" => {
+            json!({"type": "string"})
+        }
+        "BytesValue" => json!({"type": "string", "contentEncoding": "base64"}),
+        "DoubleValue" | "FloatValue" => json!({"type": "number"}),
+        "Int32Value" | "UInt32Value" => json!({"type": "integer"}),
+        "BoolValue" => json!({"type": "boolean"}),
+        _ => return None,
+    };
+    Some(schema)
+}
+
+/// Adds a description to a schema, wrapping references in `allOf` since keywords next to
+/// `$ref` are ignored.
+fn with_description(schema: Value, description: &str) -> Value {
+    match schema {
+        Value::Object(mut object) if !object.contains_key("$ref") => {
+            object.insert(
+                "description".to_string(),
+                Value::String(description.to_string()),
+            );
+            Value::Object(object)
+        }
+        schema => json!({"description": description, "allOf": [schema]}),
+    }
+}
+
+fn package_prefix(file: &FileDescriptorProto) -> String {
+    match file.package() {
+        "" => ".".to_string(),
+        package => format!(".{}.", package),
+    }
+}
+
+fn is_map_entry(message: &DescriptorProto) -> bool {
+    message
+        .options
+        .as_ref()
+        .map(|options| options.map_entry())
+        .unwrap_or(false)
+}
+
+/// Returns the JSON name of a field, which protoc sets to the lowerCamelCase form of the field
+/// name unless overridden.
+fn json_name(field: &FieldDescriptorProto) -> String {
+    if let Some(json_name) = &field.json_name {
+        return json_name.clone();
+    }
+    let mut name = String::new();
+    let mut upper = false;
+    for c in field.name().chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            name.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+/// Returns the leading comments in a file by their path in the descriptor.
+fn comments(file: &FileDescriptorProto) -> HashMap<Vec<i32>, String> {
+    file.source_code_info
+        .iter()
+        .flat_map(|info| info.location.iter())
+        .filter_map(|location| {
+            let comment = location.leading_comments().trim();
+            (!comment.is_empty()).then(|| {
+                let comment = comment
+                    .lines()
+                    .map(str::trim)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                (location.path.clone(), comment)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost_types::{source_code_info::Location, MessageOptions, SourceCodeInfo};
+
+    fn field(
+        name: &str,
+        number: i32,
+        r#type: Type,
+        type_name: Option<&str>,
+    ) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            label: Some(Label::Optional as i32),
+            r#type: Some(r#type as i32),
+            type_name: type_name.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_file_descriptor_set_to_schema() {
+        let mut subject = field("subject", 1, Type::Message, Some(".example.v1.Subject"));
+        subject.label = Some(Label::Repeated as i32);
+        let mut type_field = field("type", 4, Type::String, None);
+        type_field.json_name = Some("_type".to_string());
+
+        let file = FileDescriptorProto {
+            name: Some("example.proto".to_string()),
+            package: Some("example.v1".to_string()),
+            message_type: vec![
+                DescriptorProto {
+                    name: Some("Statement".to_string()),
+                    field: vec![
+                        subject,
+                        field("predicate_type", 2, Type::String, None),
+                        field(
+                            "predicate",
+                            3,
+                            Type::Message,
+                            Some(".google.protobuf.Struct"),
+                        ),
+                        type_field,
+                        field("size", 5, Type::Uint64, None),
+                    ],
+                    ..Default::default()
+                },
+                DescriptorProto {
+                    name: Some("Subject".to_string()),
+                    field: vec![
+                        field(
+                            "digest",
+                            1,
+                            Type::Message,
+                            Some(".example.v1.Subject.DigestEntry"),
+                        ),
+                        field("kind", 2, Type::Enum, Some(".example.v1.Subject.Kind")),
+                    ],
+                    nested_type: vec![DescriptorProto {
+                        name: Some("DigestEntry".to_string()),
+                        field: vec![
+                            field("key", 1, Type::String, None),
+                            field("value", 2, Type::String, None),
+                        ],
+                        options: Some(MessageOptions {
+                            map_entry: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }],
+                    enum_type: vec![EnumDescriptorProto {
+                        name: Some("Kind".to_string()),
+                        value: vec![prost_types::EnumValueDescriptorProto {
+                            name: Some("KIND_UNSPECIFIED".to_string()),
+                            number: Some(0),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            ],
+            source_code_info: Some(SourceCodeInfo {
+                location: vec![
+                    Location {
+                        path: vec![FILE_MESSAGE_TYPE, 0],
+                        leading_comments: Some(" An attestation statement.\n".to_string()),
+                        ..Default::default()
+                    },
+                    Location {
+                        path: vec![FILE_MESSAGE_TYPE, 0, MESSAGE_FIELD, 0],
+                        leading_comments: Some(" The attested artifacts.\n".to_string()),
+                        ..Default::default()
+                    },
+                ],
+            }),
+            ..Default::default()
+        };
+        let bytes = FileDescriptorSet { file: vec![file] }.encode_to_vec();
+
+        let schema = descriptor_set_to_schema(&bytes).unwrap();
+        assert_eq!(
+            schema["definitions"]["Statement"],
+            json!({
+                "type": "object",
+                "description": "An attestation statement.",
+                "properties": {
+                    "subject": {
+                        "type": "array",
+                        "items": {"$ref": "#/definitions/Subject"},
+                        "description": "The attested artifacts."
+                    },
+                    "predicateType": {"type": "string"},
+                    "predicate": {"type": "object"},
+                    "_type": {"type": "string"},
+                    "size": {"type": "string"}
+                }
+            })
+        );
+        assert_eq!(
+            schema["definitions"]["Subject"]["properties"],
+            json!({
+                "digest": {"type": "object", "additionalProperties": {"type": "string"}},
+                "kind": {"$ref": "#/definitions/SubjectKind"}
+            })
+        );
+        assert_eq!(
+            schema["definitions"]["SubjectKind"],
+            json!({"type": "string", "enum": ["KIND_UNSPECIFIED"]})
+        );
+        assert!(schema["definitions"].get("SubjectDigestEntry").is_none());
+    }
+}
@@ -0,0 +1,300 @@
+//! Python code generation from JSON schemas.
+//!
+//! Definitions become pydantic v2 models. Properties are renamed to snake_case, with the
+//! original name kept as an alias so documents are parsed and serialized with the spec's names.
+
+use anyhow::Result;
+use serde_json::{Map, Value};
+use std::fmt::Write;
+
+use super::{
+    definitions, description, ref_type_name, required_properties, root_type_name, schema_types,
+    snake_case, type_name,
+};
+
+const HEADER: &str = "# This file is generated by Spector. Do not edit it directly.\n\n\
+    from __future__ import annotations\n\n\
+    from datetime import datetime\n\
+    from typing import Any, Dict, List, Literal, Optional, Tuple, Union\n\n\
+    from pydantic import BaseModel, ConfigDict, Field, RootModel\n";
+
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Generates pydantic models from a JSON schema.
+///
+/// Object definitions become `BaseModel` subclasses and everything else becomes a `RootModel`.
+/// The root schema is named after its title.
+pub fn generate_python(schema: &Value) -> Result<String> {
+    let mut out = HEADER.to_string();
+    let mut names = vec![];
+    if let Some(name) = root_type_name(schema) {
+        write_model(&mut out, &name, schema);
+        names.push(name);
+    }
+    for (name, definition) in definitions(schema) {
+        let name = type_name(name);
+        write_model(&mut out, &name, definition);
+        names.push(name);
+    }
+
+    // Models reference each other before they are defined, so their forward references can
+    // only be resolved once the whole module has been declared.
+    out.push_str("\n\n");
+    for name in names {
+        let _ = writeln!(out, "{}.model_rebuild()", name);
+    }
+    Ok(out)
+}
+
+fn write_model(out: &mut String, name: &str, schema: &Value) {
+    let object = schema.as_object();
+    let properties = object
+        .and_then(|o| o.get("properties"))
+        .and_then(Value::as_object)
+        .filter(|p| !p.is_empty());
+
+    match (object, properties) {
+        (Some(object), Some(properties)) if schema_types(object) == ["object"] => {
+            let _ = writeln!(out, "\n\nclass {}(BaseModel):", name);
+            write_docstring(out, schema);
+            write_fields(out, object, properties);
+        }
+        _ => {
+            let _ = writeln!(out, "\n\nclass {}(RootModel[{}]):", name, py_type(schema));
+            write_docstring(out, schema);
+            let _ = writeln!(out, "    root: {}", py_type(schema));
+        }
+    }
+}
+
+fn write_docstring(out: &mut String, schema: &Value) {
+    if let Some(description) = description(schema) {
+        let _ = writeln!(
+            out,
+            "    \"\"\"{}\"\"\"\n",
+            description
+                .replace('\\', "\\\\")
+                .replace("\"\"\"", "\\\"\\\"\\\"")
+        );
+    }
+}
+
+fn write_fields(out: &mut String, schema: &Map<String, Value>, properties: &Map<String, Value>) {
+    let mut config = vec!["populate_by_name=True"];
+    match schema.get("additionalProperties") {
+        Some(Value::Bool(false)) => config.push("extra=\"forbid\""),
+        Some(_) => config.push("extra=\"allow\""),
+        None => {}
+    }
+    let _ = writeln!(
+        out,
+        "    model_config = ConfigDict({})\n",
+        config.join(", ")
+    );
+
+    let required = required_properties(schema);
+    for (key, property) in properties {
+        let field = field_name(key);
+        let is_required = required.contains(&key.as_str());
+        let mut field_type = py_type(property);
+        if !is_required {
+            field_type = optional(field_type);
+        }
+
+        let mut args = vec![];
+        if !is_required {
+            args.push("None".to_string());
+        }
+        if &field != key {
+            args.push(format!("alias={}", Value::String(key.clone())));
+        }
+        if let Some(description) = description(property) {
+            args.push(format!(
+                "description={}",
+                Value::String(description.to_string())
+            ));
+        }
+
+        if args == ["None"] {
+            let _ = writeln!(out, "    {}: {} = None", field, field_type);
+        } else if args.is_empty() {
+            let _ = writeln!(out, "    {}: {}", field, field_type);
+        } else {
+            let _ = writeln!(
+                out,
+                "    {}: {} = Field({})",
+                field,
+                field_type,
+                args.join(", ")
+            );
+        }
+    }
+}
+
+/// Returns the Python type annotation for a schema.
+fn py_type(schema: &Value) -> String {
+    let object = match schema {
+        Value::Object(object) => object,
+        _ => return "Any".to_string(),
+    };
+
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        return ref_type_name(reference);
+    }
+    if let Some(constant) = object.get("const") {
+        return format!("Literal[{}]", py_literal(constant));
+    }
+    if let Some(values) = object.get("enum").and_then(Value::as_array) {
+        let values = values.iter().map(py_literal).collect::<Vec<_>>();
+        return format!("Literal[{}]", values.join(", "));
+    }
+    for key in ["anyOf", "oneOf"] {
+        if let Some(variants) = object.get(key).and_then(Value::as_array) {
+            return union(variants.iter().map(py_type).collect());
+        }
+    }
+    if let Some(parts) = object.get("allOf").and_then(Value::as_array) {
+        // Python has no intersection types, so only a single wrapped schema can be typed.
+        return match parts.as_slice() {
+            [part] => py_type(part),
+            _ => "Any".to_string(),
+        };
+    }
+
+    union(
+        schema_types(object)
+            .into_iter()
+            .map(|t| match t {
+                "string" => match object.get("format").and_then(Value::as_str) {
+                    Some("date-time") => "datetime".to_string(),
+                    _ => "str".to_string(),
+                },
+                "integer" => "int".to_string(),
+                "number" => "float".to_string(),
+                "boolean" => "bool".to_string(),
+                "null" => "None".to_string(),
+                "array" => match object.get("items") {
+                    Some(Value::Array(items)) => {
+                        let items = items.iter().map(py_type).collect::<Vec<_>>();
+                        format!("Tuple[{}]", items.join(", "))
+                    }
+                    Some(items) => format!("List[{}]", py_type(items)),
+                    None => "List[Any]".to_string(),
+                },
+                "object" => match object.get("additionalProperties") {
+                    Some(additional) if object.get("properties").is_none() => {
+                        format!("Dict[str, {}]", py_type(additional))
+                    }
+                    _ => "Dict[str, Any]".to_string(),
+                },
+                _ => "Any".to_string(),
+            })
+            .collect(),
+    )
+}
+
+/// Joins types into a union, removing duplicates and using `Optional` where possible.
+fn union(types: Vec<String>) -> String {
+    let mut unique = Vec::new();
+    for t in types {
+        if !unique.contains(&t) {
+            unique.push(t);
+        }
+    }
+    if unique.is_empty() || unique.iter().any(|t| t == "Any") {
+        return "Any".to_string();
+    }
+    match unique.as_slice() {
+        [single] => single.clone(),
+        [a, b] if b == "None" => format!("Optional[{}]", a),
+        [a, b] if a == "None" => format!("Optional[{}]", b),
+        _ => format!("Union[{}]", unique.join(", ")),
+    }
+}
+
+/// Makes a type optional unless it already accepts `None`.
+fn optional(py_type: String) -> String {
+    if py_type == "Any" || py_type == "None" || py_type.starts_with("Optional[") {
+        py_type
+    } else {
+        format!("Optional[{}]", py_type)
+    }
+}
+
+fn py_literal(value: &Value) -> String {
+    match value {
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Null => "None".to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// Converts a property name into a snake_case Python identifier.
+fn field_name(key: &str) -> String {
+    let mut name = snake_case(key);
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, 'f');
+    }
+    if KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_name() {
+        assert_eq!(field_name("predicateType"), "predicate_type");
+        assert_eq!(field_name("_type"), "type");
+        assert_eq!(field_name("build-id"), "build_id");
+        assert_eq!(field_name("from"), "from_");
+        assert_eq!(field_name("URI"), "uri");
+    }
+
+    #[test]
+    fn test_generate_python() {
+        let schema = json!({
+            "title": "Example_for_Thing",
+            "description": "An example.",
+            "type": "object",
+            "required": ["_type", "digest"],
+            "properties": {
+                "_type": {"type": "string"},
+                "digest": {"$ref": "#/definitions/DigestSet"},
+                "startedOn": {"type": ["string", "null"], "format": "date-time"},
+                "tags": {"type": "array", "items": {"type": "string"}}
+            },
+            "definitions": {
+                "DigestSet": {
+                    "type": "object",
+                    "additionalProperties": {"type": "string"}
+                },
+                "Kind": {"type": "string", "enum": ["a", "b"]}
+            }
+        });
+
+        let python = generate_python(&schema).unwrap();
+        assert!(python.contains(
+            "class ExampleForThing(BaseModel):\n    \"\"\"An example.\"\"\"\n\n    model_config = ConfigDict(populate_by_name=True)\n"
+        ));
+        assert!(python.contains("    type: str = Field(alias=\"_type\")\n"));
+        assert!(python.contains("    digest: DigestSet\n"));
+        assert!(python
+            .contains("    started_on: Optional[datetime] = Field(None, alias=\"startedOn\")\n"));
+        assert!(python.contains("    tags: Optional[List[str]] = None\n"));
+        assert!(python
+            .contains("class DigestSet(RootModel[Dict[str, str]]):\n    root: Dict[str, str]\n"));
+        assert!(python.contains("class Kind(RootModel[Literal[\"a\", \"b\"]]):\n"));
+        assert!(python.ends_with("DigestSet.model_rebuild()\nKind.model_rebuild()\n"));
+    }
+}
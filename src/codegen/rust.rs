@@ -0,0 +1,525 @@
+//! Rust code generation from JSON schemas using typify.
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use typify::{TypeSpace, TypeSpacePatch, TypeSpaceSettings};
+
+use super::{snake_case, GeneratedFile};
+use crate::schema::sample;
+
+/// The number of sample documents embedded in round-trip tests.
+const ROUND_TRIP_SAMPLES: u64 = 3;
+
+const HEADER: &str =
+    "//! This file is generated by typify through Spector. Do not edit it directly.\n\
+    //! Exceptions to this rule are for cases where typify doesn't genrate the correct code.";
+
+/// Options for the code typify generates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustOptions {
+    /// Extra derives for every generated type, in addition to serde's and `schemars::JsonSchema`.
+    pub derives: Vec<String>,
+    /// Generates a builder for every struct in a `builder` module.
+    pub struct_builder: bool,
+    /// Adds `#[serde(deny_unknown_fields)]` to every struct whose schema doesn't specify
+    /// `additionalProperties`.
+    pub deny_unknown_fields: bool,
+    /// New names for generated types, keyed by the name typify would give them.
+    pub renames: BTreeMap<String, String>,
+    /// Generates a test module that round-trips sample documents through the root type and
+    /// validates them against the schema, which needs `jsonschema` as a dev-dependency.
+    pub round_trip_tests: bool,
+}
+
+impl Default for RustOptions {
+    fn default() -> Self {
+        Self {
+            derives: vec![],
+            struct_builder: true,
+            deny_unknown_fields: false,
+            renames: BTreeMap::new(),
+            round_trip_tests: false,
+        }
+    }
+}
+
+/// Generates Rust code from a JSON schema.
+pub fn generate_rust(schema: &Value) -> Result<String> {
+    generate_rust_with_options(schema, &RustOptions::default())
+}
+
+/// Generates Rust code from a JSON schema with the given options.
+pub fn generate_rust_with_options(schema: &Value, options: &RustOptions) -> Result<String> {
+    let (type_space, root) = type_space(schema, options)?;
+
+    let mut contents = format!(
+        "{}\n{}\n{}\n{}\n{}",
+        HEADER,
+        "#![allow(clippy::all)]",
+        "#![allow(warnings)]",
+        "use serde::{Deserialize, Serialize};",
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream())?)
+    );
+    if options.round_trip_tests {
+        contents.push_str(&format!(
+            "\n#[cfg(test)]\nmod round_trip_tests {{\n{}}}\n",
+            indent(&round_trip_tests(schema, root.as_deref())?)
+        ));
+    }
+
+    Ok(contents)
+}
+
+/// Generates Rust code from a JSON schema as a module tree.
+///
+/// Each top-level type and its impls are written to their own file, and the modules typify
+/// generates (such as `builder`) are written to files of the same name. `mod.rs` declares them
+/// all and re-exports the types. If `crate_name` is given, the files are placed under `src/`
+/// with `lib.rs` in place of `mod.rs`, alongside a `Cargo.toml` for the crate.
+pub fn generate_rust_module(
+    schema: &Value,
+    crate_name: Option<&str>,
+    options: &RustOptions,
+) -> Result<Vec<GeneratedFile>> {
+    let (type_space, root_type) = type_space(schema, options)?;
+    let file = syn::parse2::<syn::File>(type_space.to_stream())?;
+
+    let type_names = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(s) => Some(s.ident.to_string()),
+            syn::Item::Enum(e) => Some(e.ident.to_string()),
+            syn::Item::Type(t) => Some(t.ident.to_string()),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+
+    let mut modules = Vec::new();
+    let mut types = BTreeMap::<String, Vec<syn::Item>>::new();
+    let mut root_items = Vec::new();
+    for item in file.items {
+        match item {
+            syn::Item::Mod(syn::ItemMod {
+                attrs,
+                vis,
+                ident,
+                content: Some((_, items)),
+                ..
+            }) => modules.push((attrs, vis, ident, items)),
+            item => match item_owner(&item, &type_names) {
+                Some(owner) => types.entry(owner).or_default().push(item),
+                None => root_items.push(item),
+            },
+        }
+    }
+
+    let mut files = Vec::new();
+    let mut root = format!(
+        "{}\n#![allow(clippy::all)]\n#![allow(warnings)]\n\n",
+        HEADER
+    );
+
+    let mut module_names = modules
+        .iter()
+        .map(|(_, _, ident, _)| ident.to_string())
+        .collect::<BTreeSet<_>>();
+    for (attrs, vis, ident, items) in modules {
+        let declaration = syn::ItemMod {
+            attrs,
+            vis,
+            unsafety: None,
+            mod_token: Default::default(),
+            ident: ident.clone(),
+            content: None,
+            semi: Some(Default::default()),
+        };
+        root.push_str(&unparse_items(vec![syn::Item::Mod(declaration)]));
+        files.push(GeneratedFile {
+            path: PathBuf::from(format!("{}.rs", ident)),
+            contents: unparse_items(items),
+        });
+    }
+    if !module_names.is_empty() {
+        root.push('\n');
+    }
+
+    for (type_name, items) in types {
+        let mut module = snake_case(&type_name);
+        // A type's module is private, so it can be renamed freely if its name is taken or
+        // isn't a valid identifier.
+        if module_names.contains(&module) || syn::parse_str::<syn::Ident>(&module).is_err() {
+            module.push_str("_type");
+        }
+        root.push_str(&format!("mod {};\npub use {}::*;\n", module, module));
+        files.push(GeneratedFile {
+            path: PathBuf::from(format!("{}.rs", module)),
+            contents: format!(
+                "use serde::{{Deserialize, Serialize}};\n\nuse super::*;\n\n{}",
+                unparse_items(items)
+            ),
+        });
+        module_names.insert(module);
+    }
+
+    if !root_items.is_empty() {
+        root.push('\n');
+        root.push_str(&unparse_items(root_items));
+    }
+
+    if options.round_trip_tests {
+        root.push_str("\n#[cfg(test)]\nmod round_trip_tests;\n");
+        files.push(GeneratedFile {
+            path: PathBuf::from("round_trip_tests.rs"),
+            contents: round_trip_tests(schema, root_type.as_deref())?,
+        });
+    }
+
+    match crate_name {
+        Some(crate_name) => {
+            for file in files.iter_mut() {
+                file.path = PathBuf::from("src").join(&file.path);
+            }
+            files.push(GeneratedFile {
+                path: PathBuf::from("src/lib.rs"),
+                contents: root,
+            });
+            files.push(GeneratedFile {
+                path: PathBuf::from("Cargo.toml"),
+                contents: cargo_toml(crate_name, &type_space, options),
+            });
+        }
+        None => files.push(GeneratedFile {
+            path: PathBuf::from("mod.rs"),
+            contents: root,
+        }),
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(files)
+}
+
+/// Adds the schema to a new type space, returning it with the name of the root schema's type.
+fn type_space(schema: &Value, options: &RustOptions) -> Result<(TypeSpace, Option<String>)> {
+    let mut schema = schema.clone();
+    if options.deny_unknown_fields {
+        deny_unknown_fields(&mut schema);
+    }
+    let schema = serde_json::from_value::<schemars::schema::RootSchema>(schema)?;
+
+    let mut settings = TypeSpaceSettings::default();
+    // NOTE: Below allows us to also make the code be able to generate JSON schemas back from the Rust code.
+    settings
+        .with_derive("schemars::JsonSchema".into())
+        .with_struct_builder(options.struct_builder);
+    for derive in &options.derives {
+        settings.with_derive(derive.clone());
+    }
+    for (type_name, rename) in &options.renames {
+        settings.with_patch(type_name, TypeSpacePatch::default().with_rename(rename));
+    }
+
+    let mut type_space = TypeSpace::new(&settings);
+    let root = match type_space.add_root_schema(schema)? {
+        Some(id) => Some(type_space.get_type(&id)?.name()),
+        None => None,
+    };
+    Ok((type_space, root))
+}
+
+/// Sets `additionalProperties` to false on every object schema with properties that doesn't
+/// set it, which typify turns into `#[serde(deny_unknown_fields)]`.
+fn deny_unknown_fields(schema: &mut Value) {
+    let object = match schema {
+        Value::Object(object) => object,
+        _ => return,
+    };
+    if object
+        .get("properties")
+        .map(Value::is_object)
+        .unwrap_or(false)
+        && !object.contains_key("additionalProperties")
+    {
+        object.insert("additionalProperties".to_string(), Value::Bool(false));
+    }
+
+    for (key, value) in object.iter_mut() {
+        match (key.as_str(), value) {
+            ("properties" | "definitions" | "$defs" | "patternProperties", Value::Object(map)) => {
+                map.values_mut().for_each(deny_unknown_fields)
+            }
+            ("anyOf" | "oneOf" | "allOf" | "items", Value::Array(schemas)) => {
+                schemas.iter_mut().for_each(deny_unknown_fields)
+            }
+            ("items" | "additionalProperties" | "not", value) => deny_unknown_fields(value),
+            _ => {}
+        }
+    }
+}
+
+fn unparse_items(items: Vec<syn::Item>) -> String {
+    prettyplease::unparse(&syn::File {
+        shebang: None,
+        attrs: vec![],
+        items,
+    })
+}
+
+/// Returns the name of the generated type an item belongs to.
+///
+/// Impls belong to their self type, or for conversions into foreign types such as
+/// `impl From<DigestSet> for HashMap<..>`, to the generated type named in the trait.
+fn item_owner(item: &syn::Item, type_names: &BTreeSet<String>) -> Option<String> {
+    let known = |name: String| type_names.contains(&name).then_some(name);
+    match item {
+        syn::Item::Struct(s) => known(s.ident.to_string()),
+        syn::Item::Enum(e) => known(e.ident.to_string()),
+        syn::Item::Type(t) => known(t.ident.to_string()),
+        syn::Item::Impl(i) => type_ident(&i.self_ty).and_then(known).or_else(|| {
+            let (_, path, _) = i.trait_.as_ref()?;
+            match &path.segments.last()?.arguments {
+                syn::PathArguments::AngleBracketed(args) => {
+                    args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => type_ident(ty).and_then(known),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        }),
+        _ => None,
+    }
+}
+
+fn type_ident(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        syn::Type::Reference(reference) => type_ident(&reference.elem),
+        _ => None,
+    }
+}
+
+/// Generates the body of a test module that round-trips sample documents through the root type.
+///
+/// The samples are generated from the schema now, so the test fails if the generated types
+/// drift from the schema they were generated from, e.g. after hand edits or a typify upgrade.
+fn round_trip_tests(schema: &Value, root: Option<&str>) -> Result<String> {
+    let root = root
+        .ok_or_else(|| anyhow!("Round-trip tests need a schema with a title for its root type"))?;
+
+    let samples = (0..ROUND_TRIP_SAMPLES)
+        .map(|seed| {
+            let document = sample::sample(schema, seed)?;
+            Ok(format!(
+                "    {},\n",
+                raw_string(&serde_json::to_string(&document)?)
+            ))
+        })
+        .collect::<Result<String>>()?;
+
+    Ok(format!(
+        "use super::*;
+
+const SCHEMA: &str = {schema};
+
+const SAMPLES: &[&str] = &[
+{samples}];
+
+#[test]
+fn test_round_trip_samples() {{
+    let schema = serde_json::from_str::<serde_json::Value>(SCHEMA).unwrap();
+    let validator = jsonschema::JSONSchema::compile(&schema).unwrap();
+    for sample in SAMPLES {{
+        let document = serde_json::from_str::<serde_json::Value>(sample).unwrap();
+        let parsed = serde_json::from_value::<{root}>(document.clone()).unwrap();
+        let round_tripped = serde_json::to_value(&parsed).unwrap();
+        assert!(validator.is_valid(&round_tripped), \"{{}}\", round_tripped);
+        assert_eq!(round_tripped, document);
+    }}
+}}
+",
+        schema = raw_string(&serde_json::to_string(schema)?),
+    ))
+}
+
+/// Returns a raw string literal for `s` with enough `#`s to delimit it.
+fn raw_string(s: &str) -> String {
+    let mut hashes = "#".to_string();
+    while s.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{}\"{}\"{}", hashes, s, hashes)
+}
+
+fn indent(code: &str) -> String {
+    code.lines()
+        .map(|line| match line {
+            "" => "\n".to_string(),
+            line => format!("    {}\n", line),
+        })
+        .collect()
+}
+
+fn cargo_toml(crate_name: &str, type_space: &TypeSpace, options: &RustOptions) -> String {
+    let mut dependencies = vec![
+        "schemars = \"0.8\"".to_string(),
+        "serde = { version = \"1.0\", features = [\"derive\"] }".to_string(),
+        "serde_json = \"1.0\"".to_string(),
+    ];
+    if type_space.uses_chrono() {
+        dependencies.push("chrono = { version = \"0.4\", features = [\"serde\"] }".to_string());
+        dependencies[0] = "schemars = { version = \"0.8\", features = [\"chrono\"] }".to_string();
+    }
+    if type_space.uses_regress() {
+        dependencies.push("regress = \"0.5\"".to_string());
+    }
+    if type_space.uses_uuid() {
+        dependencies.push("uuid = { version = \"1.0\", features = [\"serde\"] }".to_string());
+    }
+    dependencies.sort();
+
+    let mut manifest = format!(
+        "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n{}\n",
+        crate_name,
+        dependencies.join("\n")
+    );
+    if options.round_trip_tests {
+        manifest.push_str("\n[dev-dependencies]\njsonschema = \"0.17\"\n");
+    }
+    manifest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> Value {
+        let schema = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/in_toto_v1_schema.json"
+        ))
+        .unwrap();
+        serde_json::from_str(&schema).unwrap()
+    }
+
+    #[test]
+    fn test_generate_rust_module() {
+        let files = generate_rust_module(&schema(), None, &RustOptions::default()).unwrap();
+        let paths = files
+            .iter()
+            .map(|f| f.path.to_str().unwrap())
+            .collect::<Vec<_>>();
+        assert!(paths.contains(&"mod.rs"));
+        assert!(paths.contains(&"builder.rs"));
+        assert!(paths.contains(&"builder_type.rs"));
+        assert!(paths.contains(&"digest_set.rs"));
+        assert!(paths.contains(&"in_toto_statement_v1_for_predicate.rs"));
+
+        let root = &files
+            .iter()
+            .find(|f| f.path.to_str() == Some("mod.rs"))
+            .unwrap();
+        assert!(root.contents.contains("pub mod builder;\n"));
+        assert!(root
+            .contents
+            .contains("mod digest_set;\npub use digest_set::*;\n"));
+
+        let digest_set = &files
+            .iter()
+            .find(|f| f.path.to_str() == Some("digest_set.rs"))
+            .unwrap();
+        assert!(digest_set.contents.contains("pub struct DigestSet("));
+        assert!(digest_set
+            .contents
+            .contains("impl From<DigestSet> for std::collections::HashMap<String, String>"));
+    }
+
+    #[test]
+    fn test_generate_rust_with_options() {
+        let options = RustOptions {
+            derives: vec!["PartialEq".to_string()],
+            struct_builder: false,
+            deny_unknown_fields: true,
+            renames: [(
+                "InTotoStatementV1ForPredicate".to_string(),
+                "Statement".to_string(),
+            )]
+            .into_iter()
+            .collect(),
+            round_trip_tests: false,
+        };
+
+        let code = generate_rust_with_options(&schema(), &options).unwrap();
+        assert!(!code.contains("pub mod builder"));
+        assert!(code.contains("pub struct Statement {"));
+        assert!(!code.contains("InTotoStatementV1ForPredicate"));
+        assert!(code.contains("#[serde(deny_unknown_fields)]\npub struct Subject {"));
+        assert!(code.contains(
+            "#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, schemars::JsonSchema)]"
+        ));
+    }
+
+    #[test]
+    fn test_generate_rust_crate() {
+        let files = generate_rust_module(&schema(), Some("in-toto-types"), &RustOptions::default())
+            .unwrap();
+        let paths = files
+            .iter()
+            .map(|f| f.path.to_str().unwrap())
+            .collect::<Vec<_>>();
+        assert!(paths.contains(&"Cargo.toml"));
+        assert!(paths.contains(&"src/lib.rs"));
+        assert!(paths.contains(&"src/builder.rs"));
+        assert!(!paths.contains(&"src/mod.rs"));
+
+        let manifest = &files
+            .iter()
+            .find(|f| f.path.to_str() == Some("Cargo.toml"))
+            .unwrap();
+        assert!(manifest.contents.contains("name = \"in-toto-types\""));
+        assert!(manifest.contents.contains("chrono = "));
+    }
+
+    #[test]
+    fn test_generate_round_trip_tests() {
+        let options = RustOptions {
+            round_trip_tests: true,
+            ..RustOptions::default()
+        };
+
+        let files = generate_rust_module(&schema(), Some("in-toto-types"), &options).unwrap();
+        let lib = &files
+            .iter()
+            .find(|f| f.path.to_str() == Some("src/lib.rs"))
+            .unwrap();
+        assert!(lib
+            .contents
+            .contains("#[cfg(test)]\nmod round_trip_tests;\n"));
+        let tests = &files
+            .iter()
+            .find(|f| f.path.to_str() == Some("src/round_trip_tests.rs"))
+            .unwrap();
+        assert!(tests
+            .contents
+            .contains("serde_json::from_value::<InTotoStatementV1ForPredicate>"));
+        assert_eq!(tests.contents.matches("    r#\"{").count(), 3);
+        let manifest = &files
+            .iter()
+            .find(|f| f.path.to_str() == Some("Cargo.toml"))
+            .unwrap();
+        assert!(manifest
+            .contents
+            .ends_with("[dev-dependencies]\njsonschema = \"0.17\"\n"));
+
+        let untitled = serde_json::json!({"definitions": {"Name": {"type": "string"}}});
+        assert!(generate_rust_with_options(&untitled, &options).is_err());
+    }
+
+    #[test]
+    fn test_raw_string() {
+        assert_eq!(raw_string(r#"{"a":1}"#), r##"r#"{"a":1}"#"##);
+        assert_eq!(raw_string(r##"{"a":"#"}"##), r###"r##"{"a":"#"}"##"###);
+    }
+}
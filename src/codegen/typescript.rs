@@ -0,0 +1,423 @@
+//! TypeScript code generation from JSON schemas.
+//!
+//! Two flavors are supported: plain type definitions for `.d.ts` files, and zod schemas that
+//! also validate documents at runtime and infer the same types.
+
+use anyhow::Result;
+use serde_json::{Map, Value};
+use std::fmt::Write;
+
+use super::{
+    definitions, description, ref_type_name, required_properties, root_type_name, schema_types,
+    type_name,
+};
+
+const HEADER: &str = "// This file is generated by Spector. Do not edit it directly.\n";
+
+/// Generates TypeScript type definitions from a JSON schema.
+///
+/// Every definition becomes an exported interface, or a type alias if it isn't an object
+/// with properties. The root schema is named after its title.
+pub fn generate_typescript(schema: &Value) -> Result<String> {
+    let mut out = format!("{}\n", HEADER);
+    if let Some(name) = root_type_name(schema) {
+        write_type_definition(&mut out, &name, schema);
+    }
+    for (name, definition) in definitions(schema) {
+        write_type_definition(&mut out, &type_name(name), definition);
+    }
+    Ok(out)
+}
+
+/// Generates zod schemas, and the types inferred from them, from a JSON schema.
+///
+/// References between definitions use `z.lazy` so the declaration order doesn't matter.
+pub fn generate_zod(schema: &Value) -> Result<String> {
+    let mut out = format!("{}\nimport {{ z }} from \"zod\";\n\n", HEADER);
+    if let Some(name) = root_type_name(schema) {
+        write_zod_definition(&mut out, &name, schema);
+    }
+    for (name, definition) in definitions(schema) {
+        write_zod_definition(&mut out, &type_name(name), definition);
+    }
+    Ok(out)
+}
+
+fn write_doc(out: &mut String, schema: &Value, indent: &str) {
+    if let Some(description) = description(schema) {
+        let _ = writeln!(out, "{}/**", indent);
+        for line in description.lines() {
+            let _ = writeln!(
+                out,
+                "{} * {}",
+                indent,
+                line.replace("*/", "*\\/").trim_end()
+            );
+        }
+        let _ = writeln!(out, "{} */", indent);
+    }
+}
+
+fn write_type_definition(out: &mut String, name: &str, schema: &Value) {
+    write_doc(out, schema, "");
+    let has_properties = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|p| !p.is_empty())
+        .unwrap_or(false);
+    let is_object_only = schema
+        .as_object()
+        .map(|o| schema_types(o) == ["object"])
+        .unwrap_or(false);
+
+    if has_properties && is_object_only {
+        let _ = writeln!(out, "export interface {} {}\n", name, ts_type(schema, ""));
+    } else {
+        let _ = writeln!(out, "export type {} = {};\n", name, ts_type(schema, ""));
+    }
+}
+
+/// Returns the TypeScript type for a schema.
+fn ts_type(schema: &Value, indent: &str) -> String {
+    let object = match schema {
+        Value::Bool(true) => return "unknown".to_string(),
+        Value::Bool(false) => return "never".to_string(),
+        Value::Object(object) => object,
+        _ => return "unknown".to_string(),
+    };
+
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        return ref_type_name(reference);
+    }
+    if let Some(constant) = object.get("const") {
+        return constant.to_string();
+    }
+    if let Some(values) = object.get("enum").and_then(Value::as_array) {
+        return union(values.iter().map(Value::to_string).collect());
+    }
+    for key in ["anyOf", "oneOf"] {
+        if let Some(variants) = object.get(key).and_then(Value::as_array) {
+            return union(variants.iter().map(|v| ts_type(v, indent)).collect());
+        }
+    }
+    if let Some(parts) = object.get("allOf").and_then(Value::as_array) {
+        let parts = parts
+            .iter()
+            .map(|p| parenthesize(ts_type(p, indent)))
+            .collect::<Vec<_>>();
+        return parts.join(" & ");
+    }
+
+    let types = schema_types(object);
+    if types.is_empty() {
+        if object.contains_key("properties") {
+            return ts_object_type(object, indent);
+        }
+        return "unknown".to_string();
+    }
+    union(
+        types
+            .into_iter()
+            .map(|t| match t {
+                "string" => "string".to_string(),
+                "integer" | "number" => "number".to_string(),
+                "boolean" => "boolean".to_string(),
+                "null" => "null".to_string(),
+                "array" => ts_array_type(object, indent),
+                "object" => ts_object_type(object, indent),
+                _ => "unknown".to_string(),
+            })
+            .collect(),
+    )
+}
+
+fn ts_array_type(schema: &Map<String, Value>, indent: &str) -> String {
+    match schema.get("items") {
+        Some(Value::Array(items)) => {
+            let items = items.iter().map(|i| ts_type(i, indent)).collect::<Vec<_>>();
+            format!("[{}]", items.join(", "))
+        }
+        Some(items) => format!("{}[]", parenthesize(ts_type(items, indent))),
+        None => "unknown[]".to_string(),
+    }
+}
+
+fn ts_object_type(schema: &Map<String, Value>, indent: &str) -> String {
+    let inner_indent = format!("{}  ", indent);
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties");
+
+    let properties = match properties {
+        Some(properties) if !properties.is_empty() => properties,
+        _ => {
+            let value_type = match additional {
+                Some(Value::Bool(false)) => return "Record<string, never>".to_string(),
+                Some(additional) => ts_type(additional, indent),
+                None => "unknown".to_string(),
+            };
+            return format!("{{ [key: string]: {} }}", value_type);
+        }
+    };
+
+    let required = required_properties(schema);
+    let mut out = String::from("{\n");
+    for (key, property) in properties {
+        write_doc(&mut out, property, &inner_indent);
+        let optional = if required.contains(&key.as_str()) {
+            ""
+        } else {
+            "?"
+        };
+        let _ = writeln!(
+            out,
+            "{}{}{}: {};",
+            inner_indent,
+            property_key(key),
+            optional,
+            ts_type(property, &inner_indent)
+        );
+    }
+    if matches!(additional, Some(a) if a != &Value::Bool(false)) {
+        let _ = writeln!(out, "{}[key: string]: unknown;", inner_indent);
+    }
+    let _ = write!(out, "{}}}", indent);
+    out
+}
+
+fn write_zod_definition(out: &mut String, name: &str, schema: &Value) {
+    write_doc(out, schema, "");
+    let _ = writeln!(
+        out,
+        "export const {}Schema = {};",
+        name,
+        zod_type(schema, "")
+    );
+    let _ = writeln!(
+        out,
+        "export type {} = z.infer<typeof {}Schema>;\n",
+        name, name
+    );
+}
+
+/// Returns the zod schema expression for a schema.
+fn zod_type(schema: &Value, indent: &str) -> String {
+    let object = match schema {
+        Value::Bool(false) => return "z.never()".to_string(),
+        Value::Object(object) => object,
+        _ => return "z.unknown()".to_string(),
+    };
+
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        return format!("z.lazy(() => {}Schema)", ref_type_name(reference));
+    }
+    if let Some(constant) = object.get("const") {
+        return format!("z.literal({})", constant);
+    }
+    if let Some(values) = object.get("enum").and_then(Value::as_array) {
+        if values.iter().all(Value::is_string) {
+            let values = values.iter().map(Value::to_string).collect::<Vec<_>>();
+            return format!("z.enum([{}])", values.join(", "));
+        }
+        return zod_union(values.iter().map(|v| format!("z.literal({})", v)).collect());
+    }
+    for key in ["anyOf", "oneOf"] {
+        if let Some(variants) = object.get(key).and_then(Value::as_array) {
+            return zod_union(variants.iter().map(|v| zod_type(v, indent)).collect());
+        }
+    }
+    if let Some(parts) = object.get("allOf").and_then(Value::as_array) {
+        let mut parts = parts.iter().map(|p| zod_type(p, indent));
+        let first = parts.next().unwrap_or_else(|| "z.unknown()".to_string());
+        return parts.fold(first, |acc, p| format!("z.intersection({}, {})", acc, p));
+    }
+
+    let types = schema_types(object);
+    if types.is_empty() {
+        if object.contains_key("properties") {
+            return zod_object_type(object, indent);
+        }
+        return "z.unknown()".to_string();
+    }
+    zod_union(
+        types
+            .into_iter()
+            .map(|t| match t {
+                "string" => zod_string_type(object),
+                "integer" => "z.number().int()".to_string(),
+                "number" => "z.number()".to_string(),
+                "boolean" => "z.boolean()".to_string(),
+                "null" => "z.null()".to_string(),
+                "array" => match object.get("items") {
+                    Some(Value::Array(items)) => {
+                        let items = items
+                            .iter()
+                            .map(|i| zod_type(i, indent))
+                            .collect::<Vec<_>>();
+                        format!("z.tuple([{}])", items.join(", "))
+                    }
+                    Some(items) => format!("z.array({})", zod_type(items, indent)),
+                    None => "z.array(z.unknown())".to_string(),
+                },
+                "object" => zod_object_type(object, indent),
+                _ => "z.unknown()".to_string(),
+            })
+            .collect(),
+    )
+}
+
+fn zod_string_type(schema: &Map<String, Value>) -> String {
+    match schema.get("format").and_then(Value::as_str) {
+        Some("uri") => "z.string().url()".to_string(),
+        Some("date-time") => "z.string().datetime({ offset: true })".to_string(),
+        _ => "z.string()".to_string(),
+    }
+}
+
+fn zod_object_type(schema: &Map<String, Value>, indent: &str) -> String {
+    let inner_indent = format!("{}  ", indent);
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties");
+
+    let properties = match properties {
+        Some(properties) if !properties.is_empty() => properties,
+        _ => {
+            let value_type = match additional {
+                Some(Value::Bool(false)) => return "z.object({}).strict()".to_string(),
+                Some(additional) => zod_type(additional, indent),
+                None => "z.unknown()".to_string(),
+            };
+            return format!("z.record({})", value_type);
+        }
+    };
+
+    let required = required_properties(schema);
+    let mut out = String::from("z.object({\n");
+    for (key, property) in properties {
+        write_doc(&mut out, property, &inner_indent);
+        let optional = if required.contains(&key.as_str()) {
+            ""
+        } else {
+            ".optional()"
+        };
+        let _ = writeln!(
+            out,
+            "{}{}: {}{},",
+            inner_indent,
+            property_key(key),
+            zod_type(property, &inner_indent),
+            optional
+        );
+    }
+    let _ = write!(out, "{}}})", indent);
+    match additional {
+        Some(Value::Bool(false)) => out.push_str(".strict()"),
+        Some(_) => out.push_str(".passthrough()"),
+        None => {}
+    }
+    out
+}
+
+fn zod_union(mut variants: Vec<String>) -> String {
+    variants.dedup();
+    if variants.len() == 1 {
+        variants.remove(0)
+    } else {
+        format!("z.union([{}])", variants.join(", "))
+    }
+}
+
+/// Joins types into a TypeScript union, removing duplicates.
+fn union(types: Vec<String>) -> String {
+    let mut unique = Vec::new();
+    for t in types {
+        if !unique.contains(&t) {
+            unique.push(t);
+        }
+    }
+    let types = unique;
+    if types.iter().any(|t| t == "unknown") {
+        return "unknown".to_string();
+    }
+    types.join(" | ")
+}
+
+/// Wraps union and intersection types in parentheses so they can be used as array items.
+fn parenthesize(ts_type: String) -> String {
+    if ts_type.contains(" | ") || ts_type.contains(" & ") {
+        format!("({})", ts_type)
+    } else {
+        ts_type
+    }
+}
+
+/// Quotes a property key if it isn't a valid identifier.
+fn property_key(key: &str) -> String {
+    let is_identifier = key
+        .chars()
+        .next()
+        .map(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        .unwrap_or(false)
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn test_schema() -> Value {
+        json!({
+            "title": "Example_for_Thing",
+            "description": "An example.",
+            "type": "object",
+            "required": ["name", "digest"],
+            "properties": {
+                "name": {"type": "string"},
+                "digest": {"$ref": "#/definitions/DigestSet"},
+                "build-id": {"type": ["integer", "null"]},
+                "tags": {"type": "array", "items": {"anyOf": [{"type": "string"}, {"type": "null"}]}}
+            },
+            "definitions": {
+                "DigestSet": {
+                    "type": "object",
+                    "additionalProperties": {"type": "string"}
+                },
+                "Kind": {
+                    "type": "string",
+                    "enum": ["a", "b"]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_generate_typescript() {
+        let ts = generate_typescript(&test_schema()).unwrap();
+        assert!(ts.contains("/**\n * An example.\n */\nexport interface ExampleForThing {\n"));
+        assert!(ts.contains("  \"build-id\"?: number | null;\n"));
+        assert!(ts.contains("  digest: DigestSet;\n"));
+        assert!(ts.contains("  name: string;\n"));
+        assert!(ts.contains("  tags?: (string | null)[];\n"));
+        assert!(ts.contains("export type DigestSet = { [key: string]: string };\n"));
+        assert!(ts.contains("export type Kind = \"a\" | \"b\";\n"));
+    }
+
+    #[test]
+    fn test_generate_zod() {
+        let zod = generate_zod(&test_schema()).unwrap();
+        assert!(zod.contains("import { z } from \"zod\";\n"));
+        assert!(zod.contains("export const ExampleForThingSchema = z.object({\n"));
+        assert!(zod.contains("  digest: z.lazy(() => DigestSetSchema),\n"));
+        assert!(zod.contains("  \"build-id\": z.union([z.number().int(), z.null()]).optional(),\n"));
+        assert!(zod.contains("export const DigestSetSchema = z.record(z.string());\n"));
+        assert!(zod.contains("export const KindSchema = z.enum([\"a\", \"b\"]);\n"));
+        assert!(zod.contains("export type Kind = z.infer<typeof KindSchema>;\n"));
+    }
+}
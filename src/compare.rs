@@ -0,0 +1,155 @@
+//! Semantic comparison of documents.
+//!
+//! The same attestation can be serialized in many ways: keys in another order, digests in upper
+//! case, or URLs with a default port or an upper-case host. Comparing the bytes treats these as
+//! different attestations, which breaks deduplication and makes tests brittle. Documents are
+//! instead compared in a canonical form where those differences are removed.
+
+use serde_json::Value;
+use url::Url;
+
+use crate::models::intoto::digest::normalize_digest_value;
+use crate::visit::{walk_mut, VisitorMut};
+
+/// Returns true if two statements or SBOMs are the same once canonicalized.
+///
+/// Key order never matters, as JSON objects compare equal whatever the order of their keys.
+/// Digest algorithms and hex digests are compared case-insensitively, and URLs by their
+/// normalized form. Array order still matters.
+pub fn semantic_eq(a: &Value, b: &Value) -> bool {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    canonicalize(&mut a);
+    canonicalize(&mut b);
+    a == b
+}
+
+/// Rewrites a document into its canonical form: digest algorithms lowercased, digests
+/// normalized as by [`normalize_digest_value`], and URLs, including the statement's `_type` and
+/// `predicateType`, normalized by parsing them. URIs that aren't valid URLs are kept as is.
+pub fn canonicalize(document: &mut Value) {
+    for field in ["_type", "predicateType"] {
+        if let Some(Value::String(url)) = document.get_mut(field) {
+            normalize_url(url);
+        }
+    }
+    walk_mut(document, &mut Canonicalizer);
+}
+
+struct Canonicalizer;
+
+impl VisitorMut for Canonicalizer {
+    fn visit_subject(&mut self, _: &str, subject: &mut Value) {
+        canonicalize_digest_set(subject);
+    }
+
+    fn visit_resource_descriptor(&mut self, _: &str, descriptor: &mut Value) {
+        canonicalize_digest_set(descriptor);
+    }
+
+    fn visit_digest(&mut self, _: &str, _: &str, digest: &mut String) {
+        if let Some(normalized) = normalize_digest_value(digest) {
+            *digest = normalized;
+        }
+    }
+
+    fn visit_uri(&mut self, _: &str, uri: &mut String) {
+        normalize_url(uri);
+    }
+}
+
+/// Lowercases the algorithms of the `digest` of a subject or resource descriptor. The values are
+/// normalized here too, as those under a renamed algorithm aren't visited afterwards.
+fn canonicalize_digest_set(element: &mut Value) {
+    let Some(Value::Object(digests)) = element.get_mut("digest") else {
+        return;
+    };
+    *digests = std::mem::take(digests)
+        .into_iter()
+        .map(|(algorithm, digest)| {
+            let digest = match digest {
+                Value::String(digest) => {
+                    Value::String(normalize_digest_value(&digest).unwrap_or(digest))
+                }
+                digest => digest,
+            };
+            (algorithm.to_ascii_lowercase(), digest)
+        })
+        .collect();
+}
+
+fn normalize_url(uri: &mut String) {
+    if let Ok(url) = Url::parse(uri) {
+        *uri = url.into();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn statement() -> Value {
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        serde_json::from_str(&fixture).unwrap()
+    }
+
+    #[test]
+    fn test_semantic_eq() {
+        let original = statement();
+        let mut other = statement();
+        other["subject"][0]["digest"] = json!({
+            "SHA256": "FE4FE40AC7250263C5DBE1CF3138912F3F416140AA248637A60D65FE22C47DA4"
+        });
+        other["predicate"]["buildDefinition"]["resolvedDependencies"][1]["uri"] = json!(
+            "HTTPS://GitHub.com:443/actions/virtual-environments/releases/tag/ubuntu20/20220515.1"
+        );
+        other["predicateType"] = json!("https://SLSA.dev/provenance/v1");
+
+        assert_ne!(original, other);
+        assert!(semantic_eq(&original, &other));
+    }
+
+    #[test]
+    fn test_semantic_eq_differences() {
+        let original = statement();
+
+        let mut other = statement();
+        other["subject"][0]["digest"]["sha256"] = json!("0".repeat(64));
+        assert!(!semantic_eq(&original, &other));
+
+        // Paths are case-sensitive, unlike hosts.
+        let mut other = statement();
+        other["predicate"]["runDetails"]["builder"]["id"] = json!(
+            "https://github.com/SLSA-framework/slsa-github-generator/.github/workflows/builder_go_slsa3.yml@refs/tags/v0.0.1"
+        );
+        assert!(!semantic_eq(&original, &other));
+
+        let mut other = statement();
+        other["predicate"]["buildDefinition"]["resolvedDependencies"]
+            .as_array_mut()
+            .unwrap()
+            .reverse();
+        assert!(!semantic_eq(&original, &other));
+    }
+
+    #[test]
+    fn test_semantic_eq_spdx() {
+        let mut sbom: Value = serde_json::from_str(
+            &std::fs::read_to_string(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/spdx_v23_sbom.json"
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        sbom["packages"][0]["checksums"] =
+            json!([{"algorithm": "SHA256", "checksumValue": "abcdef"}]);
+        let mut other = sbom.clone();
+        other["packages"][0]["checksums"][0]["checksumValue"] = json!("ABCDEF");
+        assert!(semantic_eq(&sbom, &other));
+    }
+}
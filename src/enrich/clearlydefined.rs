@@ -0,0 +1,218 @@
+//! Licenses of SBOM packages, from ClearlyDefined.
+//!
+//! SBOM generators often can't tell a package's license and leave it out or write
+//! `NOASSERTION`. ClearlyDefined curates the declared licenses of packages across ecosystems, so
+//! those gaps can be filled in by looking packages up by their purls.
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::{percent_encode, post_json, PackageUrl};
+use crate::validate::report::{Finding, Severity, ValidationReport};
+
+/// The public ClearlyDefined API.
+pub const DEFAULT_API: &str = "https://api.clearlydefined.io";
+
+/// The SPDX package fields holding licenses.
+const LICENSE_FIELDS: [&str; 2] = ["licenseConcluded", "licenseDeclared"];
+
+/// Returns the ClearlyDefined coordinates of a purl, `type/provider/namespace/name/revision`, or
+/// `None` if its type isn't supported or it has no version.
+fn coordinates(purl: &str) -> Option<String> {
+    let purl = PackageUrl::parse(purl)?;
+    let (cd_type, provider) = match purl.purl_type.as_str() {
+        "npm" => ("npm", "npmjs"),
+        "pypi" => ("pypi", "pypi"),
+        "maven" => ("maven", "mavencentral"),
+        "gem" => ("gem", "rubygems"),
+        "cargo" => ("crate", "cratesio"),
+        "nuget" => ("nuget", "nuget"),
+        "golang" => ("go", "golang"),
+        _ => return None,
+    };
+    let namespace = if purl.namespace.is_empty() {
+        "-".to_string()
+    } else {
+        percent_encode(&purl.namespace.join("/"))
+    };
+    Some(format!(
+        "{}/{}/{}/{}/{}",
+        cd_type,
+        provider,
+        namespace,
+        percent_encode(&purl.name),
+        purl.version?
+    ))
+}
+
+/// Returns true if a license field is missing or `NOASSERTION`.
+fn is_unknown(license: Option<&Value>) -> bool {
+    match license {
+        None | Some(Value::Null) => true,
+        Some(license) => license.as_str() == Some("NOASSERTION"),
+    }
+}
+
+/// Fills missing or `NOASSERTION` license fields of the packages in an SPDX document with the
+/// licenses ClearlyDefined has declared for them.
+///
+/// Each field that is filled in is reported as a `license-filled` finding. Packages that still
+/// have unknown licenses, because they have no purl or ClearlyDefined doesn't know their license,
+/// get a `license-not-found` warning.
+pub fn fill_licenses(api: &str, document: &mut Value) -> Result<ValidationReport> {
+    let mut report = ValidationReport::new();
+    let packages = match document.get_mut("packages").and_then(Value::as_array_mut) {
+        Some(packages) => packages,
+        None => return Ok(report),
+    };
+
+    let mut lookups = Vec::new();
+    for (i, package) in packages.iter().enumerate() {
+        if !LICENSE_FIELDS.iter().any(|f| is_unknown(package.get(f))) {
+            continue;
+        }
+        let purl = package
+            .get("externalRefs")
+            .and_then(Value::as_array)
+            .and_then(|refs| {
+                refs.iter()
+                    .find(|r| r.get("referenceType").and_then(Value::as_str) == Some("purl"))
+            })
+            .and_then(|r| r.get("referenceLocator"))
+            .and_then(Value::as_str);
+        match purl.and_then(coordinates) {
+            Some(coordinates) => lookups.push((i, coordinates)),
+            None => report.push(Finding::warning(
+                "license-not-found",
+                format!("/packages/{}", i),
+                format!(
+                    "{} has no purl ClearlyDefined can look its license up by",
+                    package_name(package)
+                ),
+            )),
+        }
+    }
+    if lookups.is_empty() {
+        return Ok(report);
+    }
+
+    let coordinates = lookups.iter().map(|(_, c)| c).collect::<Vec<_>>();
+    let definitions = post_json(
+        &format!("{}/definitions", api.trim_end_matches('/')),
+        &json!(coordinates),
+    )?;
+    for (i, coordinates) in &lookups {
+        let package = &mut packages[*i];
+        let declared = definitions
+            .get(coordinates)
+            .and_then(|d| d.pointer("/licensed/declared"))
+            .and_then(Value::as_str)
+            .filter(|l| !matches!(*l, "" | "NOASSERTION" | "NONE" | "OTHER"));
+        let declared = match declared {
+            Some(declared) => declared,
+            None => {
+                report.push(Finding::warning(
+                    "license-not-found",
+                    format!("/packages/{}", i),
+                    format!(
+                        "ClearlyDefined has no declared license for {} ({})",
+                        package_name(package),
+                        coordinates
+                    ),
+                ));
+                continue;
+            }
+        };
+        for field in LICENSE_FIELDS {
+            if is_unknown(package.get(field)) {
+                report.push(Finding::new(
+                    "license-filled",
+                    Severity::Info,
+                    format!("/packages/{}/{}", i, field),
+                    format!(
+                        "Set {} of {} to {} from ClearlyDefined",
+                        field,
+                        package_name(package),
+                        declared
+                    ),
+                ));
+                package[field] = json!(declared);
+            }
+        }
+    }
+    Ok(report)
+}
+
+fn package_name(package: &Value) -> &str {
+    package
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or("(unnamed)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_coordinates() {
+        assert_eq!(
+            coordinates("pkg:npm/%40scope/example@1.0.0").as_deref(),
+            Some("npm/npmjs/%40scope/example/1.0.0")
+        );
+        assert_eq!(
+            coordinates("pkg:pypi/requests@2.31.0").as_deref(),
+            Some("pypi/pypi/-/requests/2.31.0")
+        );
+        assert_eq!(
+            coordinates("pkg:golang/github.com/pkg/errors@v0.9.1").as_deref(),
+            Some("go/golang/github.com%2Fpkg/errors/v0.9.1")
+        );
+        assert_eq!(coordinates("pkg:npm/lodash"), None);
+        assert_eq!(coordinates("pkg:deb/debian/curl@7.50.3"), None);
+    }
+
+    #[test]
+    fn test_fill_licenses() {
+        let (api, requests) = serve_json(HashMap::from([(
+            "/definitions",
+            json!({
+                "npm/npmjs/-/lodash/4.17.20": {"licensed": {"declared": "MIT"}},
+                "pypi/pypi/-/requests/2.31.0": {"licensed": {"declared": "NOASSERTION"}}
+            }),
+        )]));
+        let purl = |purl: &str| json!([{"referenceCategory": "PACKAGE-MANAGER", "referenceType": "purl", "referenceLocator": purl}]);
+        let mut document = json!({
+            "packages": [
+                {"name": "lodash", "licenseConcluded": "NOASSERTION", "licenseDeclared": "MIT OR ISC", "externalRefs": purl("pkg:npm/lodash@4.17.20")},
+                {"name": "requests", "externalRefs": purl("pkg:pypi/requests@2.31.0")},
+                {"name": "known", "licenseConcluded": "MIT", "licenseDeclared": "MIT"},
+                {"name": "vendored", "licenseConcluded": "NOASSERTION"}
+            ]
+        });
+
+        let report = fill_licenses(&api, &mut document).unwrap();
+        assert_eq!(document["packages"][0]["licenseConcluded"], "MIT");
+        assert_eq!(document["packages"][0]["licenseDeclared"], "MIT OR ISC");
+        assert_eq!(document["packages"][1].get("licenseConcluded"), None);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                ("license-not-found", "/packages/3"),
+                ("license-filled", "/packages/0/licenseConcluded"),
+                ("license-not-found", "/packages/1"),
+            ]
+        );
+        assert_eq!(
+            requests.recv().unwrap().json(),
+            json!(["npm/npmjs/-/lodash/4.17.20", "pypi/pypi/-/requests/2.31.0"])
+        );
+    }
+}
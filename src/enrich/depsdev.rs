@@ -0,0 +1,260 @@
+//! Package metadata from deps.dev.
+//!
+//! Each purl's package version is looked up for its licenses and source repository, the package
+//! for its latest version, and the source repository for its OpenSSF Scorecard score. Packages in
+//! ecosystems deps.dev doesn't cover are skipped.
+
+use anyhow::Result;
+use serde_json::Value;
+
+use super::{get_json, percent_encode, Component, PackageUrl};
+use crate::validate::report::{Finding, Severity, ValidationReport};
+
+/// The public deps.dev API.
+pub const DEFAULT_API: &str = "https://api.deps.dev";
+
+/// A package version in a deps.dev packaging system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PackageVersion {
+    system: &'static str,
+    name: String,
+    version: Option<String>,
+}
+
+impl PackageVersion {
+    /// Parses a purl, returning `None` if it isn't valid or its type has no deps.dev system.
+    fn from_purl(purl: &str) -> Option<Self> {
+        let purl = PackageUrl::parse(purl)?;
+        let (system, separator) = match purl.purl_type.as_str() {
+            "npm" => ("npm", "/"),
+            "pypi" => ("pypi", "/"),
+            "maven" => ("maven", ":"),
+            "golang" => ("go", "/"),
+            "cargo" => ("cargo", "/"),
+            "nuget" => ("nuget", "/"),
+            "gem" => ("rubygems", "/"),
+            _ => return None,
+        };
+        let mut parts = purl.namespace;
+        parts.push(purl.name);
+        Some(Self {
+            system,
+            name: parts.join(separator),
+            version: purl.version,
+        })
+    }
+
+    fn package_url(&self, api: &str) -> String {
+        format!(
+            "{}/v3/systems/{}/packages/{}",
+            api,
+            self.system,
+            percent_encode(&self.name)
+        )
+    }
+}
+
+/// Looks up components on deps.dev, annotating the report with their licenses, latest versions
+/// and the Scorecard scores of their source repositories.
+///
+/// The annotations are `Info` findings, except for an `unknown-dependency` warning for a
+/// component deps.dev doesn't know.
+pub fn annotate(api: &str, components: &[Component]) -> Result<ValidationReport> {
+    let api = api.trim_end_matches('/');
+    let mut report = ValidationReport::new();
+    for component in components {
+        let package = match PackageVersion::from_purl(&component.purl) {
+            Some(package) => package,
+            None => continue,
+        };
+        let info = |code: &str, message: String| {
+            Finding::new(code, Severity::Info, &component.path, message)
+        };
+
+        let versions = match get_json(&package.package_url(api))? {
+            Some(metadata) => metadata
+                .get("versions")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default(),
+            None => {
+                report.push(Finding::warning(
+                    "unknown-dependency",
+                    &component.path,
+                    format!("{} is not known to deps.dev", component.purl),
+                ));
+                continue;
+            }
+        };
+        let latest = versions
+            .iter()
+            .find(|v| v.get("isDefault").and_then(Value::as_bool) == Some(true))
+            .and_then(|v| v.pointer("/versionKey/version"))
+            .and_then(Value::as_str);
+        if let Some(latest) = latest {
+            if package.version.as_deref() != Some(latest) {
+                report.push(info(
+                    "dependency-outdated",
+                    format!("{} has a newer version {}", component.purl, latest),
+                ));
+            }
+        }
+
+        let version = match &package.version {
+            Some(version) => version,
+            None => continue,
+        };
+        let version_url = format!(
+            "{}/versions/{}",
+            package.package_url(api),
+            percent_encode(version)
+        );
+        let metadata = match get_json(&version_url)? {
+            Some(metadata) => metadata,
+            None => {
+                report.push(Finding::warning(
+                    "unknown-dependency",
+                    &component.path,
+                    format!("{} is not known to deps.dev", component.purl),
+                ));
+                continue;
+            }
+        };
+        let licenses = metadata
+            .get("licenses")
+            .and_then(Value::as_array)
+            .map(|l| l.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+            .unwrap_or_default();
+        report.push(info(
+            "dependency-license",
+            if licenses.is_empty() {
+                format!("{} has no known license", component.purl)
+            } else {
+                format!(
+                    "{} is licensed under {}",
+                    component.purl,
+                    licenses.join(" AND ")
+                )
+            },
+        ));
+
+        let project = metadata
+            .get("relatedProjects")
+            .and_then(Value::as_array)
+            .and_then(|projects| {
+                projects
+                    .iter()
+                    .find(|p| p.get("relationType").and_then(Value::as_str) == Some("SOURCE_REPO"))
+            })
+            .and_then(|p| p.pointer("/projectKey/id"))
+            .and_then(Value::as_str);
+        if let Some(project) = project {
+            let url = format!("{}/v3/projects/{}", api, percent_encode(project));
+            let score = get_json(&url)?
+                .as_ref()
+                .and_then(|p| p.pointer("/scorecard/overallScore"))
+                .and_then(Value::as_f64);
+            if let Some(score) = score {
+                report.push(info(
+                    "dependency-scorecard",
+                    format!(
+                        "{} is built from {}, which has an OpenSSF Scorecard score of {}",
+                        component.purl, project, score
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_json;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn component(purl: &str) -> Component {
+        Component {
+            path: "/predicate/materials/0/uri".to_string(),
+            purl: purl.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_package_version_from_purl() {
+        let package = PackageVersion::from_purl("pkg:npm/%40scope/example@1.0.0").unwrap();
+        assert_eq!(package.system, "npm");
+        assert_eq!(package.name, "@scope/example");
+        assert_eq!(package.version.as_deref(), Some("1.0.0"));
+
+        let package =
+            PackageVersion::from_purl("pkg:maven/org.apache/commons-io@2.6?type=jar").unwrap();
+        assert_eq!(package.name, "org.apache:commons-io");
+        assert_eq!(package.version.as_deref(), Some("2.6"));
+
+        let package = PackageVersion::from_purl("pkg:golang/github.com/pkg/errors").unwrap();
+        assert_eq!(package.system, "go");
+        assert_eq!(package.name, "github.com/pkg/errors");
+        assert_eq!(package.version, None);
+
+        assert_eq!(
+            PackageVersion::from_purl("pkg:deb/debian/curl@7.50.3"),
+            None
+        );
+        assert_eq!(PackageVersion::from_purl("https://example.com/x"), None);
+    }
+
+    #[test]
+    fn test_annotate() {
+        let (api, _requests) = serve_json(HashMap::from([
+            (
+                "/v3/systems/npm/packages/%40scope%2Fexample",
+                json!({"versions": [
+                    {"versionKey": {"version": "1.0.0"}, "isDefault": false},
+                    {"versionKey": {"version": "1.1.0"}, "isDefault": true}
+                ]}),
+            ),
+            (
+                "/v3/systems/npm/packages/%40scope%2Fexample/versions/1.0.0",
+                json!({
+                    "licenses": ["MIT", "Apache-2.0"],
+                    "relatedProjects": [{
+                        "projectKey": {"id": "github.com/scope/example"},
+                        "relationType": "SOURCE_REPO"
+                    }]
+                }),
+            ),
+            (
+                "/v3/projects/github.com%2Fscope%2Fexample",
+                json!({"scorecard": {"overallScore": 7.5}}),
+            ),
+        ]));
+
+        let report = annotate(
+            &api,
+            &[
+                component("pkg:npm/%40scope/example@1.0.0"),
+                component("pkg:npm/missing@1.0.0"),
+                component("pkg:deb/debian/curl@7.50.3"),
+            ],
+        )
+        .unwrap();
+        let messages = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.message.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                ("dependency-outdated", "pkg:npm/%40scope/example@1.0.0 has a newer version 1.1.0"),
+                ("dependency-license", "pkg:npm/%40scope/example@1.0.0 is licensed under MIT AND Apache-2.0"),
+                ("dependency-scorecard", "pkg:npm/%40scope/example@1.0.0 is built from github.com/scope/example, which has an OpenSSF Scorecard score of 7.5"),
+                ("unknown-dependency", "pkg:npm/missing@1.0.0 is not known to deps.dev"),
+            ]
+        );
+        assert!(!report.has_errors());
+    }
+}
@@ -0,0 +1,276 @@
+//! Enrichment of validated documents with data from external services.
+//!
+//! Enrichment passes look up the packages a document refers to, by their purls, and report what
+//! the services know about them as findings. They run after validation, so the findings add
+//! context for triage rather than deciding whether the document is valid.
+
+pub mod clearlydefined;
+pub mod depsdev;
+pub mod osv;
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+use std::time::Instant;
+use tracing::{debug, instrument};
+
+/// A package referred to by a document, identified by its purl.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    /// JSON pointer to the value holding the purl.
+    pub path: String,
+    pub purl: String,
+}
+
+/// The parts of a purl that packages are looked up by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PackageUrl {
+    /// The package type, lowercased, e.g. `npm`.
+    pub(crate) purl_type: String,
+    pub(crate) namespace: Vec<String>,
+    pub(crate) name: String,
+    pub(crate) version: Option<String>,
+}
+
+impl PackageUrl {
+    /// Parses a purl, ignoring its qualifiers and subpath. Returns `None` if it isn't a purl.
+    pub(crate) fn parse(purl: &str) -> Option<Self> {
+        let purl = purl.strip_prefix("pkg:")?;
+        let purl = purl.split(['?', '#']).next().unwrap_or_default();
+        let (path, version) = match purl.rsplit_once('@') {
+            // An `@` in the path is part of an npm scope, not a version separator.
+            Some((path, version)) if !version.contains('/') => {
+                (path, Some(percent_decode(version)))
+            }
+            _ => (purl, None),
+        };
+        let mut segments = path.split('/').filter(|s| !s.is_empty());
+        let purl_type = segments.next()?.to_ascii_lowercase();
+        let mut namespace = segments.map(percent_decode).collect::<Vec<_>>();
+        let name = namespace.pop()?;
+        Some(Self {
+            purl_type,
+            namespace,
+            name,
+            version: version.filter(|v| !v.is_empty()),
+        })
+    }
+}
+
+/// Returns the purls of the packages in an SPDX document, from their `purl` external references.
+pub fn spdx_components(document: &Value) -> Vec<Component> {
+    let mut components = Vec::new();
+    let packages = document
+        .get("packages")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for (i, package) in packages.iter().enumerate() {
+        let refs = package
+            .get("externalRefs")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for (j, external_ref) in refs.iter().enumerate() {
+            if external_ref.get("referenceType").and_then(Value::as_str) != Some("purl") {
+                continue;
+            }
+            if let Some(purl) = external_ref.get("referenceLocator").and_then(Value::as_str) {
+                components.push(Component {
+                    path: format!("/packages/{}/externalRefs/{}/referenceLocator", i, j),
+                    purl: purl.to_string(),
+                });
+            }
+        }
+    }
+    components
+}
+
+/// Returns the purls of the dependencies in SLSA provenance, from the `uri` of the v1
+/// `resolvedDependencies` and the v0.2 `materials`. Dependencies identified by other URIs, such
+/// as git repositories, are skipped.
+pub fn provenance_components(statement: &Value) -> Vec<Component> {
+    let mut components = Vec::new();
+    for pointer in [
+        "/predicate/buildDefinition/resolvedDependencies",
+        "/predicate/materials",
+    ] {
+        let dependencies = statement
+            .pointer(pointer)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for (i, dependency) in dependencies.iter().enumerate() {
+            if let Some(uri) = dependency.get("uri").and_then(Value::as_str) {
+                if uri.starts_with("pkg:") {
+                    components.push(Component {
+                        path: format!("{}/{}/uri", pointer, i),
+                        purl: uri.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    components
+}
+
+/// Fetches a URL and parses the response as JSON, returning `None` if it isn't found.
+#[instrument(level = "debug")]
+fn get_json(url: &str) -> Result<Option<Value>> {
+    let start = Instant::now();
+    let response =
+        reqwest::blocking::get(url).map_err(|e| anyhow!("Failed to query {}: {}", url, e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        bail!("Failed to query {}: {}", url, status);
+    }
+    let bytes = response.bytes()?;
+    debug!(
+        bytes = bytes.len(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Queried"
+    );
+    serde_json::from_slice(&bytes)
+        .map(Some)
+        .map_err(|e| anyhow!("Invalid JSON from {}: {}", url, e))
+}
+
+/// Posts a JSON body to a URL and parses the response as JSON, failing on error statuses.
+#[instrument(level = "debug", skip(body))]
+fn post_json(url: &str, body: &Value) -> Result<Value> {
+    let start = Instant::now();
+    let response = reqwest::blocking::Client::new()
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .map_err(|e| anyhow!("Failed to query {}: {}", url, e))?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!("Failed to query {}: {}", url, status);
+    }
+    let bytes = response.bytes()?;
+    debug!(
+        bytes = bytes.len(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Queried"
+    );
+    serde_json::from_slice(&bytes).map_err(|e| anyhow!("Invalid JSON from {}: {}", url, e))
+}
+
+/// Percent-encodes everything but unreserved characters, for use as a URL path segment.
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Decodes percent-encoded bytes, leaving invalid escapes as they are.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(b) => {
+                decoded.push(b);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_percent_encoding() {
+        assert_eq!(percent_encode("@scope/a b"), "%40scope%2Fa%20b");
+        assert_eq!(percent_decode("%40scope%2Fa%20b"), "@scope/a b");
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+
+    #[test]
+    fn test_package_url() {
+        assert_eq!(
+            PackageUrl::parse("pkg:NPM/%40scope/example@1.0.0?arch=x64#lib"),
+            Some(PackageUrl {
+                purl_type: "npm".to_string(),
+                namespace: vec!["@scope".to_string()],
+                name: "example".to_string(),
+                version: Some("1.0.0".to_string()),
+            })
+        );
+        assert_eq!(
+            PackageUrl::parse("pkg:npm/@scope/example").unwrap().version,
+            None
+        );
+        assert_eq!(PackageUrl::parse("pkg:npm"), None);
+        assert_eq!(PackageUrl::parse("https://example.com/x"), None);
+    }
+
+    #[test]
+    fn test_provenance_components() {
+        let statement = json!({
+            "predicate": {
+                "buildDefinition": {"resolvedDependencies": [
+                    {"uri": "git+https://github.com/octocat/hello-world@refs/heads/main"},
+                    {"uri": "pkg:npm/lodash@4.17.20"}
+                ]},
+                "materials": [{"uri": "pkg:pypi/requests@2.31.0"}]
+            }
+        });
+        assert_eq!(
+            provenance_components(&statement),
+            vec![
+                Component {
+                    path: "/predicate/buildDefinition/resolvedDependencies/1/uri".to_string(),
+                    purl: "pkg:npm/lodash@4.17.20".to_string(),
+                },
+                Component {
+                    path: "/predicate/materials/0/uri".to_string(),
+                    purl: "pkg:pypi/requests@2.31.0".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spdx_components() {
+        let document = json!({
+            "packages": [
+                {"externalRefs": [
+                    {"referenceType": "cpe23Type", "referenceLocator": "cpe:2.3:a:x:y:1:*:*:*:*:*:*:*"},
+                    {"referenceType": "purl", "referenceLocator": "pkg:npm/lodash@4.17.20"}
+                ]},
+                {"name": "no refs"}
+            ]
+        });
+        assert_eq!(
+            spdx_components(&document),
+            vec![Component {
+                path: "/packages/0/externalRefs/1/referenceLocator".to_string(),
+                purl: "pkg:npm/lodash@4.17.20".to_string(),
+            }]
+        );
+    }
+}
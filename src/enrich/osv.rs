@@ -0,0 +1,124 @@
+//! Known vulnerabilities of packages, from the OSV database.
+//!
+//! Purls are looked up with OSV's batch query API, which returns the IDs of the vulnerabilities
+//! affecting each package version. A purl without a version matches every version of the
+//! package, so such lookups report vulnerabilities that may not affect the version in use.
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+
+use super::{post_json, Component};
+use crate::validate::report::{Finding, ValidationReport};
+
+/// The public OSV API.
+pub const DEFAULT_API: &str = "https://api.osv.dev";
+
+/// The most queries OSV accepts in one batch.
+const BATCH_SIZE: usize = 1000;
+
+/// Looks up the known vulnerabilities of components, reporting a `known-vulnerability` warning
+/// for each vulnerability affecting a component.
+pub fn scan(api: &str, components: &[Component]) -> Result<ValidationReport> {
+    let url = format!("{}/v1/querybatch", api.trim_end_matches('/'));
+    let mut report = ValidationReport::new();
+    for batch in components.chunks(BATCH_SIZE) {
+        let queries = batch
+            .iter()
+            .map(|c| json!({"package": {"purl": c.purl}}))
+            .collect::<Vec<_>>();
+        let response = post_json(&url, &json!({ "queries": queries }))?;
+        let results = response
+            .get("results")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if results.len() != batch.len() {
+            bail!(
+                "OSV returned {} results for {} queries",
+                results.len(),
+                batch.len()
+            );
+        }
+        for (component, result) in batch.iter().zip(results) {
+            let vulns = result
+                .get("vulns")
+                .and_then(Value::as_array)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            for id in vulns
+                .iter()
+                .filter_map(|v| v.get("id").and_then(Value::as_str))
+            {
+                report.push(Finding::warning(
+                    "known-vulnerability",
+                    &component.path,
+                    format!("{} is affected by {}", component.purl, id),
+                ));
+            }
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_json;
+    use std::collections::HashMap;
+
+    fn component(i: usize, purl: &str) -> Component {
+        Component {
+            path: format!("/packages/{}/externalRefs/0/referenceLocator", i),
+            purl: purl.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_scan() {
+        let (api, requests) = serve_json(HashMap::from([(
+            "/v1/querybatch",
+            json!({
+                "results": [
+                    {"vulns": [{"id": "GHSA-35jh-r3h4-6jhm", "modified": "2024-01-01T00:00:00Z"}]},
+                    {}
+                ]
+            }),
+        )]));
+        let components = vec![
+            component(0, "pkg:npm/lodash@4.17.20"),
+            component(1, "pkg:pypi/requests@2.31.0"),
+        ];
+
+        let report = scan(&api, &components).unwrap();
+        assert_eq!(
+            report.findings,
+            vec![Finding::warning(
+                "known-vulnerability",
+                "/packages/0/externalRefs/0/referenceLocator",
+                "pkg:npm/lodash@4.17.20 is affected by GHSA-35jh-r3h4-6jhm"
+            )]
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("POST /v1/querybatch HTTP/1.1"));
+        assert_eq!(
+            request.json(),
+            json!({"queries": [
+                {"package": {"purl": "pkg:npm/lodash@4.17.20"}},
+                {"package": {"purl": "pkg:pypi/requests@2.31.0"}}
+            ]})
+        );
+    }
+
+    #[test]
+    fn test_scan_mismatched_results() {
+        let (api, _requests) =
+            serve_json(HashMap::from([("/v1/querybatch", json!({"results": []}))]));
+        let err = scan(&api, &[component(0, "pkg:npm/lodash@4.17.20")]).unwrap_err();
+        assert!(err.to_string().contains("0 results for 1 queries"));
+    }
+
+    #[test]
+    fn test_scan_no_components() {
+        assert!(scan("http://127.0.0.1:9", &[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,670 @@
+//! Pushing of validated documents into GUAC through its GraphQL API.
+//!
+//! GUAC stores documents as nodes and the relations between them. SLSA provenance becomes a
+//! `HasSLSA` relation from each artifact it is about to the artifacts it was built from and its
+//! builder, and an SBOM a `HasSBOM` relation on the artifacts or packages it describes. Documents
+//! are sent in batches, one GraphQL request each, and requests failing with a network or server
+//! error are retried, so spector can run as a validating collector in front of GUAC.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::Utc;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+use crate::enrich::PackageUrl;
+use crate::models::intoto::predicate::normalize_predicate_type;
+use crate::push::check_endpoint;
+use crate::validate::consistency::{builder_id, is_provenance};
+
+/// The environment variable with the URL of the GUAC GraphQL endpoint.
+pub const ENDPOINT_ENV: &str = "SPECTOR_GUAC_URL";
+
+/// The environment variable with a bearer token for the GUAC GraphQL endpoint.
+pub const TOKEN_ENV: &str = "SPECTOR_GUAC_TOKEN";
+
+/// The collector name recorded on the nodes spector ingests.
+pub const COLLECTOR: &str = "spector";
+
+const INGEST_SLSA: &str = "mutation IngestSLSAs($artifacts: [IDorArtifactInput!]!, \
+    $builders: [IDorBuilderInput!]!, $subjects: [IDorArtifactInput!]!, \
+    $builtFromList: [[IDorArtifactInput!]!]!, $builtByList: [IDorBuilderInput!]!, \
+    $slsaList: [SLSAInputSpec!]!) {
+  ingestArtifacts(artifacts: $artifacts)
+  ingestBuilders(builders: $builders)
+  ingestSLSAs(subjects: $subjects, builtFromList: $builtFromList, builtByList: $builtByList, slsaList: $slsaList)
+}";
+
+const INGEST_ARTIFACT_SBOMS: &str = "mutation IngestArtifactSBOMs(\
+    $artifacts: [IDorArtifactInput!]!, $hasSBOMs: [HasSBOMInputSpec!]!, \
+    $includes: [HasSBOMIncludesInputSpec!]!) {
+  ingestArtifacts(artifacts: $artifacts)
+  ingestHasSBOMs(subjects: {artifacts: $artifacts}, hasSBOMs: $hasSBOMs, includes: $includes)
+}";
+
+const INGEST_PACKAGE_SBOMS: &str = "mutation IngestPackageSBOMs($packages: [IDorPkgInput!]!, \
+    $hasSBOMs: [HasSBOMInputSpec!]!, $includes: [HasSBOMIncludesInputSpec!]!) {
+  ingestPackages(pkgs: $packages)
+  ingestHasSBOMs(subjects: {packages: $packages}, hasSBOMs: $hasSBOMs, includes: $includes)
+}";
+
+/// A document to push, with where it came from, which GUAC records as the origin of its nodes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GuacDocument<'a> {
+    pub origin: &'a str,
+    /// An In-Toto statement with SLSA provenance or an SPDX predicate, or an SPDX document.
+    pub document: &'a Value,
+}
+
+/// What a push ingested.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PushSummary {
+    /// The number of `HasSLSA` relations, one per provenance subject.
+    pub slsa: usize,
+    /// The number of `HasSBOM` relations, one per SBOM subject.
+    pub sboms: usize,
+    /// The number of GraphQL requests that succeeded.
+    pub requests: usize,
+}
+
+/// A client for the GUAC GraphQL API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuacClient {
+    endpoint: String,
+    token: Option<String>,
+    batch_size: usize,
+    retries: u32,
+    retry_delay: Duration,
+}
+
+impl GuacClient {
+    /// Creates a client for the GraphQL endpoint, e.g. `http://localhost:8080/query`, sending
+    /// batches of 100 relations and retrying failed requests 3 times.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            token: None,
+            batch_size: 100,
+            retries: 3,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Sends a bearer token with each request, for endpoints behind authentication.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Sets the number of relations sent in each request.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Sets how many times failed requests are retried, and the delay before the first retry,
+    /// which doubles with each further one.
+    pub fn with_retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.retries = retries;
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Pushes documents into GUAC.
+    ///
+    /// All documents are converted before anything is sent, so an unsupported document fails
+    /// the push without ingesting the others. A batch GUAC rejects fails the push, leaving the
+    /// batches before it ingested.
+    pub fn push(&self, documents: &[GuacDocument]) -> Result<PushSummary> {
+        let mut ingestion = Ingestion::default();
+        for document in documents {
+            ingestion
+                .add(document)
+                .map_err(|e| anyhow!("{}: {}", document.origin, e))?;
+        }
+
+        let mut summary = PushSummary::default();
+        for batch in ingestion.slsa.chunks(self.batch_size) {
+            let mut artifacts = Vec::new();
+            let mut builders = Vec::new();
+            for slsa in batch {
+                for artifact in std::iter::once(&slsa.subject).chain(&slsa.built_from) {
+                    if !artifacts.contains(artifact) {
+                        artifacts.push(artifact.clone());
+                    }
+                }
+                if !builders.contains(&slsa.built_by) {
+                    builders.push(slsa.built_by.clone());
+                }
+            }
+            self.send(
+                INGEST_SLSA,
+                json!({
+                    "artifacts": artifacts,
+                    "builders": builders,
+                    "subjects": batch.iter().map(|s| &s.subject).collect::<Vec<_>>(),
+                    "builtFromList": batch.iter().map(|s| &s.built_from).collect::<Vec<_>>(),
+                    "builtByList": batch.iter().map(|s| &s.built_by).collect::<Vec<_>>(),
+                    "slsaList": batch.iter().map(|s| &s.slsa).collect::<Vec<_>>(),
+                }),
+            )?;
+            summary.slsa += batch.len();
+            summary.requests += 1;
+        }
+        for (query, subjects, sboms) in [
+            (
+                INGEST_ARTIFACT_SBOMS,
+                "artifacts",
+                &ingestion.artifact_sboms,
+            ),
+            (INGEST_PACKAGE_SBOMS, "packages", &ingestion.package_sboms),
+        ] {
+            for batch in sboms.chunks(self.batch_size) {
+                self.send(
+                    query,
+                    json!({
+                        subjects: batch.iter().map(|(subject, _)| subject).collect::<Vec<_>>(),
+                        "hasSBOMs": batch.iter().map(|(_, sbom)| sbom).collect::<Vec<_>>(),
+                        "includes": batch.iter().map(|_| json!({
+                            "packages": [], "artifacts": [], "dependencies": [], "occurrences": []
+                        })).collect::<Vec<_>>(),
+                    }),
+                )?;
+                summary.sboms += batch.len();
+                summary.requests += 1;
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Sends a GraphQL request, retrying it on network errors, `429 Too Many Requests` and
+    /// server errors.
+    fn send(&self, query: &str, variables: Value) -> Result<()> {
+        let url = check_endpoint(&self.endpoint, self.token.is_some())?;
+        let body = json!({"query": query, "variables": variables});
+        let mut attempt = 0;
+        loop {
+            match self.try_send(url.clone(), &body) {
+                Ok(()) => return Ok(()),
+                Err(Failure::Transient(_)) if attempt < self.retries => {
+                    std::thread::sleep(self.retry_delay * 2u32.saturating_pow(attempt));
+                    attempt += 1;
+                }
+                Err(Failure::Transient(e)) | Err(Failure::Permanent(e)) => return Err(e),
+            }
+        }
+    }
+
+    fn try_send(&self, url: url::Url, body: &Value) -> std::result::Result<(), Failure> {
+        let mut request = reqwest::blocking::Client::new().post(url).json(body);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        let response = request.send().map_err(|e| {
+            Failure::Transient(anyhow!("Failed to push to {}: {}", self.endpoint, e))
+        })?;
+        let status = response.status();
+        if !status.is_success() {
+            let e = anyhow!("Failed to push to {}: {}", self.endpoint, status);
+            return Err(if status.is_server_error() || status.as_u16() == 429 {
+                Failure::Transient(e)
+            } else {
+                Failure::Permanent(e)
+            });
+        }
+        let response: Value = response.json().map_err(|e| {
+            Failure::Permanent(anyhow!("Invalid response from {}: {}", self.endpoint, e))
+        })?;
+        let errors = response
+            .get("errors")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if !errors.is_empty() {
+            let messages = errors
+                .iter()
+                .map(|e| {
+                    e.get("message")
+                        .and_then(Value::as_str)
+                        .unwrap_or("unknown error")
+                })
+                .collect::<Vec<_>>();
+            return Err(Failure::Permanent(anyhow!(
+                "GUAC rejected the batch: {}",
+                messages.join("; ")
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Why a request failed: transient failures are retried.
+enum Failure {
+    Transient(anyhow::Error),
+    Permanent(anyhow::Error),
+}
+
+/// A `HasSLSA` relation to ingest.
+#[derive(Clone)]
+struct Slsa {
+    subject: Value,
+    built_from: Vec<Value>,
+    built_by: Value,
+    slsa: Value,
+}
+
+/// The relations to ingest for a set of documents. GUAC takes the subjects of SBOMs about
+/// artifacts and about packages in separate lists, so they are sent separately.
+#[derive(Default)]
+struct Ingestion {
+    slsa: Vec<Slsa>,
+    artifact_sboms: Vec<(Value, Value)>,
+    package_sboms: Vec<(Value, Value)>,
+}
+
+impl Ingestion {
+    fn add(&mut self, document: &GuacDocument) -> Result<()> {
+        let value = document.document;
+        if value.get("spdxVersion").is_some() {
+            let sbom = has_sbom(document.origin, value);
+            for package in described_packages(value) {
+                self.package_sboms.push((package, sbom.clone()));
+            }
+            return Ok(());
+        }
+
+        let subjects = value
+            .get("subject")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|subject| subject.get("digest").and_then(artifact))
+            .collect::<Vec<_>>();
+        if subjects.is_empty() {
+            bail!("The statement has no subject with a digest");
+        }
+        if is_provenance(value) {
+            let slsa = slsa(document.origin, value)?;
+            for subject in subjects {
+                self.slsa.push(Slsa {
+                    subject,
+                    ..slsa.clone()
+                });
+            }
+        } else if let Some(predicate) = value
+            .get("predicate")
+            .filter(|predicate| predicate.get("spdxVersion").is_some())
+        {
+            let sbom = has_sbom(document.origin, predicate);
+            for subject in subjects {
+                self.artifact_sboms.push((subject, sbom.clone()));
+            }
+        } else {
+            bail!(
+                "Pushing {} attestations to GUAC isn't supported",
+                value
+                    .get("predicateType")
+                    .and_then(Value::as_str)
+                    .unwrap_or("untyped")
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Returns the GUAC artifact input for a digest set, preferring its SHA-256 digest, as GUAC
+/// identifies an artifact by a single digest.
+fn artifact(digests: &Value) -> Option<Value> {
+    let digests = digests.as_object()?;
+    let (algorithm, digest) = digests
+        .get_key_value("sha256")
+        .or_else(|| digests.iter().next())?;
+    Some(json!({"artifactInput": {
+        "algorithm": algorithm.to_ascii_lowercase(),
+        "digest": digest.as_str()?.trim().to_ascii_lowercase(),
+    }}))
+}
+
+/// Returns the `HasSLSA` relation of SLSA v1 or v0.2 provenance, with a null subject for each of
+/// the statement's subjects to be filled in.
+fn slsa(origin: &str, statement: &Value) -> Result<Slsa> {
+    let (_, builder) =
+        builder_id(statement).ok_or_else(|| anyhow!("The provenance has no builder id"))?;
+    let v1 = statement.pointer("/predicate/buildDefinition").is_some();
+    let (build_type, started_on, finished_on, dependencies) = if v1 {
+        (
+            "/predicate/buildDefinition/buildType",
+            "/predicate/runDetails/metadata/startedOn",
+            "/predicate/runDetails/metadata/finishedOn",
+            "/predicate/buildDefinition/resolvedDependencies",
+        )
+    } else {
+        (
+            "/predicate/buildType",
+            "/predicate/metadata/buildStartedOn",
+            "/predicate/metadata/buildFinishedOn",
+            "/predicate/materials",
+        )
+    };
+    let built_from = statement
+        .pointer(dependencies)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|dependency| dependency.get("digest").and_then(artifact))
+        .collect();
+
+    let mut predicate = Vec::new();
+    if let Some(value) = statement.get("predicate") {
+        flatten("slsa", value, &mut predicate);
+    }
+    let predicate_type = statement
+        .get("predicateType")
+        .and_then(Value::as_str)
+        .map(normalize_predicate_type)
+        .unwrap_or_default();
+    let mut slsa = Map::new();
+    slsa.insert(
+        "buildType".to_string(),
+        json!(statement
+            .pointer(build_type)
+            .and_then(Value::as_str)
+            .unwrap_or_default()),
+    );
+    slsa.insert("slsaPredicate".to_string(), json!(predicate));
+    slsa.insert("slsaVersion".to_string(), json!(predicate_type));
+    for (key, pointer) in [("startedOn", started_on), ("finishedOn", finished_on)] {
+        if let Some(time) = statement.pointer(pointer).and_then(Value::as_str) {
+            slsa.insert(key.to_string(), json!(time));
+        }
+    }
+    slsa.insert("origin".to_string(), json!(origin));
+    slsa.insert("collector".to_string(), json!(COLLECTOR));
+    slsa.insert("documentRef".to_string(), json!(""));
+
+    Ok(Slsa {
+        subject: Value::Null,
+        built_from,
+        built_by: json!({"builderInput": {"uri": builder}}),
+        slsa: Value::Object(slsa),
+    })
+}
+
+/// Flattens a predicate into the key and value pairs GUAC stores, with keys like
+/// `slsa.buildDefinition.buildType` and array indices as segments.
+fn flatten(prefix: &str, value: &Value, pairs: &mut Vec<Value>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                flatten(&format!("{}.{}", prefix, key), value, pairs);
+            }
+        }
+        Value::Array(array) => {
+            for (i, value) in array.iter().enumerate() {
+                flatten(&format!("{}.{}", prefix, i), value, pairs);
+            }
+        }
+        Value::Null => {}
+        Value::String(value) => pairs.push(json!({"key": prefix, "value": value})),
+        value => pairs.push(json!({"key": prefix, "value": value.to_string()})),
+    }
+}
+
+/// Returns the `HasSBOM` input for an SPDX document, identified by its namespace and the
+/// SHA-256 digest of its JSON.
+fn has_sbom(origin: &str, document: &Value) -> Value {
+    let digest = Sha256::digest(document.to_string().as_bytes());
+    let known_since = document
+        .pointer("/creationInfo/created")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| Utc::now().to_rfc3339());
+    json!({
+        "uri": document.get("documentNamespace").and_then(Value::as_str).unwrap_or(origin),
+        "algorithm": "sha256",
+        "digest": format!("{:x}", digest),
+        "downloadLocation": origin,
+        "knownSince": known_since,
+        "origin": origin,
+        "collector": COLLECTOR,
+        "documentRef": "",
+    })
+}
+
+/// Returns the GUAC package inputs of the packages an SPDX document describes, from their
+/// purls. A document describing no package with a purl is about itself, which GUAC names
+/// `pkg:guac/spdx/<document name>`.
+fn described_packages(document: &Value) -> Vec<Value> {
+    let mut described = document
+        .get("documentDescribes")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Value::as_str)
+        .collect::<Vec<_>>();
+    let relationships = document
+        .get("relationships")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for relationship in relationships {
+        let field = |name| relationship.get(name).and_then(Value::as_str);
+        if field("relationshipType") == Some("DESCRIBES") {
+            described.extend(field("relatedSpdxElement"));
+        }
+    }
+
+    let packages = document
+        .get("packages")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let inputs = packages
+        .iter()
+        .filter(|package| {
+            package
+                .get("SPDXID")
+                .and_then(Value::as_str)
+                .is_some_and(|id| described.contains(&id))
+        })
+        .flat_map(|package| {
+            package
+                .get("externalRefs")
+                .and_then(Value::as_array)
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+        })
+        .filter(|external_ref| {
+            external_ref.get("referenceType").and_then(Value::as_str) == Some("purl")
+        })
+        .filter_map(|external_ref| external_ref.get("referenceLocator")?.as_str())
+        .filter_map(PackageUrl::parse)
+        .map(|purl| {
+            json!({"packageInput": {
+                "type": purl.purl_type,
+                "namespace": purl.namespace.join("/"),
+                "name": purl.name,
+                "version": purl.version.unwrap_or_default(),
+                "qualifiers": [],
+                "subpath": "",
+            }})
+        })
+        .collect::<Vec<_>>();
+    if !inputs.is_empty() {
+        return inputs;
+    }
+    let name = document
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    vec![json!({"packageInput": {
+        "type": "guac",
+        "namespace": "spdx",
+        "name": name,
+        "version": "",
+        "qualifiers": [],
+        "subpath": "",
+    }})]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{serve, Request, Response};
+    use std::sync::mpsc;
+
+    fn fixture(name: &str) -> Value {
+        let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    /// Answers requests with each of the responses in turn, returning the endpoint URL and a
+    /// receiver for the requests.
+    fn serve_in_turn(
+        responses: Vec<(&'static str, &'static str)>,
+    ) -> (String, mpsc::Receiver<Request>) {
+        let mut responses = responses.into_iter();
+        let (url, requests) = serve(move |_| match responses.next() {
+            Some((status, body)) => Response::new(status).body(body),
+            None => Response::new("500 Internal Server Error"),
+        });
+        (format!("{}/query", url), requests)
+    }
+
+    #[test]
+    fn test_ingestion() {
+        let provenance = fixture("slsa_provenance_v1.json");
+        let sbom_attestation = fixture("spdx_v23_sbom_attestation.json");
+        let mut sbom = fixture("spdx_v23_sbom.json");
+        let mut ingestion = Ingestion::default();
+        for (origin, document) in [
+            ("provenance.json", &provenance),
+            ("sbom.intoto.json", &sbom_attestation),
+            ("sbom.spdx.json", &sbom),
+        ] {
+            ingestion.add(&GuacDocument { origin, document }).unwrap();
+        }
+
+        let slsa = &ingestion.slsa[0];
+        assert_eq!(
+            slsa.subject,
+            json!({"artifactInput": {
+                "algorithm": "sha256",
+                "digest": "fe4fe40ac7250263c5dbe1cf3138912f3f416140aa248637a60d65fe22c47da4"
+            }})
+        );
+        assert_eq!(
+            slsa.built_by["builderInput"]["uri"],
+            provenance["predicate"]["runDetails"]["builder"]["id"]
+        );
+        assert_eq!(slsa.slsa["slsaVersion"], "https://slsa.dev/provenance/v1");
+        assert_eq!(slsa.slsa["origin"], "provenance.json");
+        assert!(slsa.slsa["slsaPredicate"]
+            .as_array()
+            .unwrap()
+            .contains(&json!({
+                "key": "slsa.buildDefinition.buildType",
+                "value": provenance["predicate"]["buildDefinition"]["buildType"]
+            })));
+
+        assert_eq!(ingestion.artifact_sboms.len(), 1);
+        assert_eq!(
+            ingestion.artifact_sboms[0].1["uri"],
+            "https://example.com/hello-world"
+        );
+        assert_eq!(ingestion.package_sboms[0].0["packageInput"]["type"], "guac");
+
+        sbom["documentDescribes"] = json!(["SPDXRef-Package-lodash"]);
+        assert_eq!(
+            described_packages(&sbom),
+            vec![json!({"packageInput": {
+                "type": "npm",
+                "namespace": "",
+                "name": "lodash",
+                "version": "4.17.20",
+                "qualifiers": [],
+                "subpath": ""
+            }})]
+        );
+
+        let statement = json!({"subject": [{"name": "a", "digest": {"sha256": "ab"}}], "predicateType": "https://example.com/custom"});
+        let err = ingestion
+            .add(&GuacDocument {
+                origin: "custom.json",
+                document: &statement,
+            })
+            .unwrap_err();
+        assert!(err.to_string().contains("https://example.com/custom"));
+    }
+
+    #[test]
+    fn test_push_batches_and_retries() {
+        let (endpoint, requests) = serve_in_turn(vec![
+            ("503 Service Unavailable", ""),
+            ("200 OK", r#"{"data": {}}"#),
+            ("200 OK", r#"{"data": {}}"#),
+        ]);
+        let provenance = fixture("slsa_provenance_v1.json");
+        let sbom = fixture("spdx_v23_sbom_attestation.json");
+        let client = GuacClient::new(endpoint).with_retries(2, Duration::ZERO);
+        let summary = client
+            .push(&[
+                GuacDocument {
+                    origin: "a.json",
+                    document: &provenance,
+                },
+                GuacDocument {
+                    origin: "b.json",
+                    document: &provenance,
+                },
+                GuacDocument {
+                    origin: "c.json",
+                    document: &sbom,
+                },
+            ])
+            .unwrap();
+        assert_eq!(
+            summary,
+            PushSummary {
+                slsa: 2,
+                sboms: 1,
+                requests: 2
+            }
+        );
+
+        let retried = requests.recv().unwrap().json();
+        assert_eq!(requests.recv().unwrap().json(), retried);
+        assert!(retried["query"].as_str().unwrap().contains("ingestSLSAs"));
+        assert_eq!(
+            retried["variables"]["slsaList"].as_array().unwrap().len(),
+            2
+        );
+        assert_eq!(
+            retried["variables"]["artifacts"].as_array().unwrap().len(),
+            2
+        );
+        let sboms = requests.recv().unwrap().json();
+        assert!(sboms["query"].as_str().unwrap().contains("ingestHasSBOMs"));
+        assert_eq!(sboms["variables"]["hasSBOMs"][0]["collector"], "spector");
+    }
+
+    #[test]
+    fn test_push_graphql_errors() {
+        let (endpoint, _requests) = serve_in_turn(vec![(
+            "200 OK",
+            r#"{"errors": [{"message": "invalid digest"}]}"#,
+        )]);
+        let provenance = fixture("slsa_provenance_v1.json");
+        let client = GuacClient::new(endpoint).with_batch_size(1);
+        let err = client
+            .push(&[GuacDocument {
+                origin: "a.json",
+                document: &provenance,
+            }])
+            .unwrap_err();
+        assert_eq!(err.to_string(), "GUAC rejected the batch: invalid digest");
+    }
+}
@@ -0,0 +1,348 @@
+//! Imports Bazel builds from their Build Event Protocol (BEP) output.
+//!
+//! `bazel build --build_event_json_file=bep.json` writes the build events as JSON Lines, which
+//! are mapped to a SLSA v1 provenance skeleton:
+//!
+//! | Provenance | Build events |
+//! |---|---|
+//! | `subject` | The files of the output groups of successful `targetCompleted` events |
+//! | `externalParameters` | The `command`, the target `pattern`s and the explicit options |
+//! | `internalParameters` | The Bazel version |
+//! | `resolvedDependencies` | The URLs of successful `fetch` events |
+//! | `runDetails.metadata` | The invocation id and the start and finish times |
+//!
+//! Output groups whose name starts with `_`, such as `_validation`, are internal to Bazel and
+//! aren't subjects. Fetch events don't record digests, so the dependencies only have URIs.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+use url::Url;
+
+use crate::models::intoto::predicate::{Predicate, SLSA_PROVENANCE_V1};
+use crate::models::intoto::provenancev1::{
+    BuildDefinition, BuildMetadata, Builder, ResourceDescriptor, RunDetails,
+    SLSAProvenanceV1Predicate,
+};
+use crate::models::intoto::statement::{Algorithm, InTotoStatementV1, Subject, STATEMENT_TYPE_V1};
+use crate::models::intoto::uri::UriReference;
+
+/// The buildType of the imported provenance.
+pub const BAZEL_BUILD_TYPE: &str = "https://bazel.build/slsa/build-types/bep/v1";
+
+/// What the build events say about the build.
+#[derive(Default)]
+struct Build<'a> {
+    invocation_id: Option<&'a str>,
+    started_on: Option<DateTime<Utc>>,
+    finished_on: Option<DateTime<Utc>>,
+    success: Option<bool>,
+    command: Option<&'a str>,
+    bazel_version: Option<&'a str>,
+    patterns: Vec<&'a str>,
+    options: Vec<&'a str>,
+    startup_options: Vec<&'a str>,
+    named_sets: HashMap<&'a str, &'a Value>,
+    output_sets: Vec<&'a str>,
+    fetches: Vec<&'a str>,
+}
+
+/// Parses build events written as JSON Lines, skipping blank lines.
+pub fn parse_events(text: &str) -> Result<Vec<Value>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| anyhow!("Invalid build event on line {}: {}", i + 1, e))
+        })
+        .collect()
+}
+
+/// Maps the build events of a successful build to a SLSA v1 provenance skeleton, with the
+/// given builder id.
+///
+/// Fails if the build didn't finish successfully, or none of its outputs have a digest.
+pub fn import_bep(events: &[Value], builder_id: &Url) -> Result<InTotoStatementV1> {
+    let build = read_events(events);
+    match build.success {
+        Some(true) => {}
+        Some(false) => return Err(anyhow!("The build did not succeed")),
+        None => {
+            return Err(anyhow!(
+                "The build events have no buildFinished event, the stream may be incomplete"
+            ))
+        }
+    }
+    let algorithm = digest_algorithm(&build.startup_options)?;
+
+    let mut subject: Vec<Subject> = vec![];
+    let mut visited = HashSet::new();
+    let mut pending = build.output_sets.clone();
+    pending.reverse();
+    while let Some(id) = pending.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+        let Some(set) = build.named_sets.get(id) else {
+            continue;
+        };
+        for file in array(set, "files") {
+            let (Some(name), Some(digest)) = (str_field(file, "name"), str_field(file, "digest"))
+            else {
+                continue;
+            };
+            if subject.iter().all(|subject| subject.name != name) {
+                subject.push(Subject {
+                    name: name.to_string(),
+                    digest: [(algorithm, digest.to_lowercase())].into_iter().collect(),
+                });
+            }
+        }
+        let mut children = array(set, "fileSets")
+            .filter_map(|set| str_field(set, "id"))
+            .collect::<Vec<_>>();
+        children.reverse();
+        pending.extend(children);
+    }
+    if subject.is_empty() {
+        return Err(anyhow!(
+            "None of the outputs in the build events have a digest"
+        ));
+    }
+
+    let mut fetched = HashSet::new();
+    let resolved_dependencies = build
+        .fetches
+        .iter()
+        .filter(|url| fetched.insert(**url))
+        .map(|url| {
+            let uri = UriReference::parse(url)
+                .map_err(|e| anyhow!("Invalid fetch URL {}: {}", url, e))?;
+            Ok(ResourceDescriptor {
+                uri: Some(uri),
+                ..ResourceDescriptor::default()
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut external_parameters = Map::new();
+    if let Some(command) = build.command {
+        external_parameters.insert("command".to_string(), json!(command));
+    }
+    external_parameters.insert("targets".to_string(), json!(build.patterns));
+    if !build.options.is_empty() {
+        external_parameters.insert("options".to_string(), json!(build.options));
+    }
+    let internal_parameters = build
+        .bazel_version
+        .map(|version| Map::from_iter([("bazelVersion".to_string(), json!(version))]));
+
+    let predicate = SLSAProvenanceV1Predicate {
+        build_definition: BuildDefinition {
+            build_type: Url::parse(BAZEL_BUILD_TYPE).expect("the build type is a valid URL"),
+            external_parameters: Value::Object(external_parameters),
+            internal_parameters,
+            resolved_dependencies: Some(resolved_dependencies),
+        },
+        run_details: RunDetails {
+            builder: Builder {
+                id: builder_id.clone(),
+                builder_dependencies: None,
+                version: None,
+            },
+            metadata: Some(BuildMetadata {
+                invocation_id: build.invocation_id.map(str::to_string),
+                started_on: build.started_on,
+                finished_on: build.finished_on,
+            }),
+            byproducts: None,
+        },
+    };
+    Ok(InTotoStatementV1 {
+        _type: Url::parse(STATEMENT_TYPE_V1).expect("the statement type is a valid URL"),
+        subject,
+        predicate_type: Url::parse(SLSA_PROVENANCE_V1).expect("the predicate type is a valid URL"),
+        predicate: Predicate::SLSAProvenanceV1(predicate),
+    })
+}
+
+fn read_events(events: &[Value]) -> Build<'_> {
+    let mut build = Build::default();
+    for event in events {
+        let Some(id) = event.get("id").and_then(Value::as_object) else {
+            continue;
+        };
+        if let Some(started) = event.get("started") {
+            build.invocation_id = str_field(started, "uuid");
+            build.started_on = timestamp(started, "startTimeMillis", "startTime");
+            build.command = str_field(started, "command");
+            build.bazel_version = str_field(started, "buildToolVersion");
+        }
+        if let Some(options) = event.get("optionsParsed") {
+            build.options = array(options, "explicitCmdLine")
+                .filter_map(Value::as_str)
+                .collect();
+            build.startup_options = array(options, "startupOptions")
+                .filter_map(Value::as_str)
+                .collect();
+        }
+        if let Some(pattern) = id.get("pattern").filter(|_| event.get("aborted").is_none()) {
+            build
+                .patterns
+                .extend(array(pattern, "pattern").filter_map(Value::as_str));
+        }
+        if let (Some(named_set), Some(files)) = (id.get("namedSet"), event.get("namedSetOfFiles")) {
+            if let Some(set_id) = str_field(named_set, "id") {
+                build.named_sets.insert(set_id, files);
+            }
+        }
+        if let Some(completed) = event
+            .get("completed")
+            .filter(|completed| completed.get("success") == Some(&Value::Bool(true)))
+        {
+            for group in array(completed, "outputGroup") {
+                if str_field(group, "name").is_some_and(|name| name.starts_with('_')) {
+                    continue;
+                }
+                build
+                    .output_sets
+                    .extend(array(group, "fileSets").filter_map(|set| str_field(set, "id")));
+            }
+        }
+        if let (Some(fetch), Some(result)) = (id.get("fetch"), event.get("fetch")) {
+            if result.get("success") == Some(&Value::Bool(true)) {
+                build.fetches.extend(str_field(fetch, "url"));
+            }
+        }
+        if let Some(finished) = event.get("finished") {
+            build.success = Some(finished.get("overallSuccess") == Some(&Value::Bool(true)));
+            build.finished_on = timestamp(finished, "finishTimeMillis", "finishTime");
+        }
+    }
+    build
+}
+
+/// Returns the algorithm of the output digests, from the `--digest_function` startup option.
+fn digest_algorithm(startup_options: &[&str]) -> Result<Algorithm> {
+    let function = startup_options
+        .iter()
+        .rev()
+        .find_map(|option| option.strip_prefix("--digest_function="))
+        .unwrap_or("sha256");
+    match function.to_lowercase().replace('-', "").as_str() {
+        "sha256" => Ok(Algorithm::Sha256),
+        "sha1" => Ok(Algorithm::Sha1),
+        _ => Err(anyhow!(
+            "Unsupported digest function {}, the outputs can't be subjects",
+            function
+        )),
+    }
+}
+
+fn array<'a>(value: &'a Value, field: &str) -> impl Iterator<Item = &'a Value> {
+    value
+        .get(field)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn str_field<'a>(value: &'a Value, field: &str) -> Option<&'a str> {
+    value.get(field).and_then(Value::as_str)
+}
+
+/// Reads a time, either as milliseconds since the epoch, which the protobuf JSON encoding
+/// writes as a string, or as an RFC 3339 timestamp in the newer field.
+fn timestamp(value: &Value, millis_field: &str, field: &str) -> Option<DateTime<Utc>> {
+    let millis = match value.get(millis_field) {
+        Some(Value::String(millis)) => millis.parse().ok(),
+        Some(Value::Number(millis)) => millis.as_i64(),
+        _ => None,
+    };
+    match millis {
+        Some(millis) => DateTime::from_timestamp_millis(millis),
+        None => str_field(value, field)
+            .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+            .map(Into::into),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events() -> Vec<Value> {
+        let text = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/bazel_bep.json"
+        ))
+        .unwrap();
+        parse_events(&text).unwrap()
+    }
+
+    fn builder_id() -> Url {
+        Url::parse("https://ci.example.com/bazel").unwrap()
+    }
+
+    #[test]
+    fn test_import_bep() {
+        let statement = import_bep(&events(), &builder_id()).unwrap();
+        let value = serde_json::to_value(&statement).unwrap();
+        assert_eq!(
+            value["subject"],
+            json!([
+                {"name": "hello", "digest": {"sha256": "a7f2b6f2d4d0c6f6bbd0ac66b38c3c91ad8ab3e6d0a0b5a1fd42ef2d0b3a4f1c"}},
+                {"name": "hello.runfiles_manifest", "digest": {"sha256": "0b5a1fd42ef2d0b3a4f1ca7f2b6f2d4d0c6f6bbd0ac66b38c3c91ad8ab3e6d0a"}}
+            ])
+        );
+        let definition = &value["predicate"]["buildDefinition"];
+        assert_eq!(
+            definition["externalParameters"],
+            json!({"command": "build", "targets": ["//:hello"], "options": ["--compilation_mode=opt"]})
+        );
+        assert_eq!(
+            definition["internalParameters"],
+            json!({"bazelVersion": "7.1.1"})
+        );
+        assert_eq!(
+            definition["resolvedDependencies"],
+            json!([{"uri": "https://github.com/bazelbuild/rules_cc/releases/download/0.0.9/rules_cc-0.0.9.tar.gz"}])
+        );
+        assert_eq!(
+            value["predicate"]["runDetails"]["metadata"],
+            json!({
+                "invocationId": "5f3c9e8e-6b4b-4a9b-9d55-2a3d1f0c7b21",
+                "startedOn": "2024-05-01T12:00:00Z",
+                "finishedOn": "2024-05-01T12:01:03Z"
+            })
+        );
+
+        // The skeleton is a valid statement.
+        let round_trip: InTotoStatementV1 = serde_json::from_value(value).unwrap();
+        assert_eq!(round_trip, statement);
+    }
+
+    #[test]
+    fn test_import_bep_errors() {
+        let mut events = events();
+        events.pop();
+        let err = import_bep(&events, &builder_id()).unwrap_err();
+        assert!(err.to_string().contains("no buildFinished event"));
+
+        events.push(json!({"id": {"buildFinished": {}}, "finished": {"overallSuccess": false}}));
+        let err = import_bep(&events, &builder_id()).unwrap_err();
+        assert_eq!(err.to_string(), "The build did not succeed");
+
+        events.pop();
+        events.push(json!({"id": {"buildFinished": {}}, "finished": {"overallSuccess": true}}));
+        events[1]["optionsParsed"]["startupOptions"] = json!(["--digest_function=blake3"]);
+        let err = import_bep(&events, &builder_id()).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unsupported digest function blake3"));
+
+        assert!(parse_events("{}\n\nnot json").is_err());
+    }
+}
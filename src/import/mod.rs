@@ -0,0 +1,7 @@
+//! Importers that turn the output of build tools into attestations.
+//!
+//! Build tools that don't generate provenance often record enough about the build to write a
+//! skeleton of it: what was built, from what and when. The skeletons still need to be reviewed,
+//! completed and signed by the build platform.
+
+pub mod bazel;
@@ -0,0 +1,223 @@
+//! Reading of input documents from local paths or remote object stores.
+//!
+//! With the `object-store` feature, documents can be read straight from `s3://` and `gs://`
+//! URIs. Credentials come from the standard chains of each provider: the `AWS_*` environment
+//! variables, web identity and instance metadata for S3, and `GOOGLE_APPLICATION_CREDENTIALS` or
+//! the application default credentials for GCS.
+//!
+//! Documents compressed with gzip or zstd, such as `.json.gz` and `.json.zst` archives, are
+//! decompressed transparently. The compression is detected from the magic bytes at the start of
+//! the input rather than the file extension, so compressed objects are read whatever their name.
+
+use anyhow::{anyhow, Result};
+use flate2::read::MultiGzDecoder;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use tracing::{debug, instrument};
+
+/// URI schemes of the object stores documents can be read from.
+const OBJECT_STORE_SCHEMES: [&str; 2] = ["s3://", "gs://"];
+
+/// Returns true if an input refers to an object in a remote object store.
+pub fn is_object_store_uri(input: &str) -> bool {
+    OBJECT_STORE_SCHEMES
+        .iter()
+        .any(|scheme| input.starts_with(scheme))
+}
+
+/// The magic bytes gzip streams start with.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// The magic bytes zstd frames start with.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// The compression of an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// Detects the compression from the first bytes of an input.
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&GZIP_MAGIC) {
+            Compression::Gzip
+        } else if bytes.starts_with(&ZSTD_MAGIC) {
+            Compression::Zstd
+        } else {
+            Compression::None
+        }
+    }
+}
+
+/// Wraps a reader so that gzip or zstd compressed input is decompressed while reading it.
+/// Uncompressed input is read as is.
+pub fn decompress<'a, R: Read + 'a>(reader: R) -> std::io::Result<Box<dyn Read + 'a>> {
+    let mut reader = BufReader::new(reader);
+    // A short first read is fine, as neither magic can be split by the buffer's capacity.
+    let compression = Compression::detect(reader.fill_buf()?);
+    Ok(match compression {
+        Compression::None => Box::new(reader),
+        Compression::Gzip => Box::new(MultiGzDecoder::new(reader)),
+        Compression::Zstd => Box::new(zstd::Decoder::with_buffer(reader)?),
+    })
+}
+
+/// Opens a local file for reading, decompressing it if it is compressed.
+pub fn open(input: &Path) -> Result<Box<dyn Read>> {
+    let file = std::fs::File::open(input)
+        .map_err(|e| anyhow!("Failed to open {}: {}", input.display(), e))?;
+    decompress(file).map_err(|e| anyhow!("Failed to read {}: {}", input.display(), e))
+}
+
+/// Reads a document from a local path, or an `s3://` or `gs://` URI, decompressing it if it is
+/// compressed.
+#[instrument(skip_all, fields(input = %input.display()))]
+pub fn read_to_string(input: &Path) -> Result<String> {
+    let (name, bytes) = match input.to_str() {
+        Some(uri) if is_object_store_uri(uri) => (uri.to_string(), remote::get(uri)?),
+        _ => (
+            input.display().to_string(),
+            std::fs::read(input)
+                .map_err(|e| anyhow!("Failed to read {}: {}", input.display(), e))?,
+        ),
+    };
+    let mut document = String::new();
+    decompress(&bytes[..])
+        .and_then(|mut reader| reader.read_to_string(&mut document))
+        .map_err(|e| anyhow!("Failed to read {}: {}", name, e))?;
+    debug!(
+        bytes = bytes.len(),
+        decompressed_bytes = document.len(),
+        compression = ?Compression::detect(&bytes),
+        "Read document"
+    );
+    Ok(document)
+}
+
+#[cfg(feature = "object-store")]
+mod remote {
+    use anyhow::{anyhow, Result};
+    use object_store::{aws::AmazonS3Builder, gcp::GoogleCloudStorageBuilder, ObjectStore};
+    use url::Url;
+
+    /// Fetches an object from S3 or GCS.
+    pub(super) fn get(uri: &str) -> Result<Vec<u8>> {
+        let url = Url::parse(uri).map_err(|e| anyhow!("Invalid URI {}: {}", uri, e))?;
+        let store: Box<dyn ObjectStore> = match url.scheme() {
+            "s3" => Box::new(AmazonS3Builder::from_env().with_url(uri).build()?),
+            _ => Box::new(
+                GoogleCloudStorageBuilder::from_env()
+                    .with_url(uri)
+                    .build()?,
+            ),
+        };
+        let path = object_store::path::Path::from_url_path(url.path())?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        runtime
+            .block_on(async { store.get(&path).await?.bytes().await })
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| anyhow!("Failed to fetch {}: {}", uri, e))
+    }
+}
+
+#[cfg(not(feature = "object-store"))]
+mod remote {
+    use anyhow::{bail, Result};
+
+    pub(super) fn get(uri: &str) -> Result<Vec<u8>> {
+        bail!(
+            "Reading {} requires spector to be built with the object-store feature",
+            uri
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_object_store_uri() {
+        assert!(is_object_store_uri("s3://bucket/attestations/a.json"));
+        assert!(is_object_store_uri("gs://bucket/a.json"));
+        assert!(!is_object_store_uri(
+            "tests/fixtures/slsa_provenance_v1.json"
+        ));
+        assert!(!is_object_store_uri("https://example.com/a.json"));
+    }
+
+    #[test]
+    fn test_read_to_string_local() {
+        let document = read_to_string(Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        )))
+        .unwrap();
+        assert!(document.contains("https://slsa.dev/provenance/v1"));
+        assert!(read_to_string(Path::new("does/not/exist.json")).is_err());
+    }
+
+    fn compressed_fixture(compression: Compression) -> Vec<u8> {
+        let fixture = std::fs::read(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        match compression {
+            Compression::None => fixture,
+            Compression::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                std::io::Write::write_all(&mut encoder, &fixture).unwrap();
+                encoder.finish().unwrap()
+            }
+            Compression::Zstd => zstd::encode_all(&fixture[..], 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_decompress() {
+        let expected = compressed_fixture(Compression::None);
+        for compression in [Compression::None, Compression::Gzip, Compression::Zstd] {
+            let compressed = compressed_fixture(compression);
+            assert_eq!(Compression::detect(&compressed), compression);
+            let mut decompressed = Vec::new();
+            decompress(&compressed[..])
+                .unwrap()
+                .read_to_end(&mut decompressed)
+                .unwrap();
+            assert_eq!(decompressed, expected, "{:?}", compression);
+        }
+    }
+
+    #[test]
+    fn test_read_to_string_compressed() {
+        let path =
+            std::env::temp_dir().join(format!("spector-input-{}.json.zst", std::process::id()));
+        std::fs::write(&path, compressed_fixture(Compression::Zstd)).unwrap();
+        let document = read_to_string(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(document.unwrap().contains("https://slsa.dev/provenance/v1"));
+
+        // Truncated input fails rather than returning part of the document.
+        let mut truncated = compressed_fixture(Compression::Gzip);
+        truncated.truncate(truncated.len() / 2);
+        let mut document = String::new();
+        assert!(decompress(&truncated[..])
+            .unwrap()
+            .read_to_string(&mut document)
+            .is_err());
+    }
+
+    #[cfg(not(feature = "object-store"))]
+    #[test]
+    fn test_read_to_string_without_feature() {
+        let err = read_to_string(Path::new("s3://bucket/a.json")).unwrap_err();
+        assert!(err.to_string().contains("object-store feature"));
+    }
+}
@@ -1,2 +1,35 @@
+#[cfg(feature = "validation")]
+pub mod attestations;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+pub mod compare;
+#[cfg(feature = "validation")]
+pub mod enrich;
+#[cfg(feature = "validation")]
+pub mod guac;
+#[cfg(feature = "validation")]
+pub mod import;
+#[cfg(feature = "validation")]
+pub mod input;
 pub mod models;
+#[cfg(feature = "validation")]
+pub mod patch;
+#[cfg(feature = "validation")]
+pub mod plugin;
+#[cfg(feature = "validation")]
+pub mod push;
+pub mod redact;
+#[cfg(feature = "validation")]
+pub mod schema;
+#[cfg(feature = "validation")]
+pub mod store;
+#[cfg(feature = "testing")]
+pub mod test_server;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "validation")]
+pub mod trust;
 pub mod validate;
+#[cfg(feature = "validation")]
+pub mod verify;
+pub mod visit;
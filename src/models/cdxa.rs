@@ -0,0 +1,349 @@
+//! CycloneDX Attestations (CDXA) model and associated structures.
+//!
+//! See: https://cyclonedx.org/capabilities/attestations/
+//!
+//! A CDXA document is a CycloneDX 1.6+ BOM whose `declarations` attest to conformance with the
+//! requirements of standards: claims about targets, backed by evidence, and mapped to the
+//! requirements by attestations. The standards and their requirements can be defined in the
+//! document's `definitions`. BOM fields other than those are not modelled.
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The `bomFormat` of CycloneDX documents.
+pub const BOM_FORMAT: &str = "CycloneDX";
+
+/// The first CycloneDX spec version with declarations.
+pub const MIN_SPEC_VERSION: (u32, u32) = (1, 6);
+
+/// A struct representing a standalone CycloneDX attestation document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CycloneDxAttestation {
+    /// Always `CycloneDX`.
+    pub bom_format: String,
+    /// The version of the CycloneDX spec, at least 1.6.
+    pub spec_version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+    /// The BOM metadata, such as its timestamp and authors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+    /// The conformance claims, evidence and attestations.
+    pub declarations: Declarations,
+    /// The standards the attestations map their claims to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definitions: Option<Definitions>,
+}
+
+/// The declarations of conformance to standards.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Declarations {
+    /// The parties assessing the conformance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assessors: Option<Vec<Assessor>>,
+    /// The attestations of the assessors, mapping requirements to claims.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attestations: Option<Vec<Attestation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claims: Option<Vec<Claim>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<Vec<Evidence>>,
+    /// The organizations, components and services the claims are about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Targets>,
+    /// The affirmation of the declarations by authorized signatories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affirmation: Option<Affirmation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Value>,
+}
+
+/// A party assessing the conformance, or the organization itself for self-assessments.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Assessor {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    /// Whether the assessor is a third party rather than the organization itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub third_party: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Value>,
+}
+
+/// An attestation by an assessor, mapping the requirements of standards to claims.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Attestation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// The `bom-ref` of the assessor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assessor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map: Option<Vec<AttestationMap>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Value>,
+}
+
+/// The claims and counter claims about a requirement, and how well they conform to it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationMap {
+    /// The `bom-ref` of the requirement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirement: Option<String>,
+    /// The `bom-ref`s of the claims supporting conformance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claims: Option<Vec<String>>,
+    /// The `bom-ref`s of the claims against conformance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counter_claims: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conformance: Option<Conformance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<Confidence>,
+}
+
+/// How well the claims conform to a requirement.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Conformance {
+    /// From 0 for no conformance to 1 for full conformance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<String>,
+    /// The `bom-ref`s of the mitigation strategies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mitigation_strategies: Option<Vec<String>>,
+}
+
+/// How confident the assessor is in the conformance.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Confidence {
+    /// From 0 for no confidence to 1 for full confidence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<String>,
+}
+
+/// A claim about a target, backed by evidence.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Claim {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    /// The `bom-ref` of the target the claim is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// What is claimed about the target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mitigation_strategies: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<String>,
+    /// The `bom-ref`s of the evidence supporting the claim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<Vec<String>>,
+    /// The `bom-ref`s of the evidence against the claim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counter_evidence: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_references: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Value>,
+}
+
+/// Evidence for or against claims.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Evidence {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    /// The property the evidence is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub property_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<EvidenceData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+    /// When the evidence stops being valid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewer: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Value>,
+}
+
+/// A piece of the data of evidence.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EvidenceData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contents: Option<Value>,
+    /// The data classification, e.g. `public` or `confidential`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitive_data: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub governance: Option<Value>,
+}
+
+/// The targets of claims, each with a `bom-ref`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Targets {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organizations: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<Vec<Value>>,
+}
+
+/// The affirmation of the declarations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Affirmation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signatories: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Value>,
+}
+
+/// The definitions of the standards attestations refer to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Definitions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standards: Option<Vec<Standard>>,
+}
+
+/// A standard, such as a regulation or framework, and its requirements.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Standard {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<Vec<Requirement>>,
+    /// The levels of conformance, each a set of requirements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub levels: Option<Vec<Level>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_references: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Value>,
+}
+
+/// A requirement of a standard.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Requirement {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    /// The identifier of the requirement in the standard, e.g. `V1.1.1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptions: Option<Vec<String>>,
+    /// The OWASP Common Requirement Enumeration identifiers of the requirement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_cre: Option<Vec<String>>,
+    /// The `bom-ref` of the requirement this one is part of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_references: Option<Vec<Value>>,
+}
+
+/// A level of conformance to a standard.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Level {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The `bom-ref`s of the requirements of the level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<Vec<String>>,
+}
+
+impl CycloneDxAttestation {
+    /// Returns the spec version as a major and minor version, if it is one.
+    pub fn spec_version(&self) -> Option<(u32, u32)> {
+        let (major, minor) = self.spec_version.split_once('.')?;
+        Some((major.parse().ok()?, minor.parse().ok()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Value {
+        serde_json::from_str(
+            &std::fs::read_to_string(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/cdxa.json"
+            ))
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cdxa_roundtrip() {
+        let value = fixture();
+        let document: CycloneDxAttestation = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(document.spec_version(), Some((1, 6)));
+
+        let attestation = &document.declarations.attestations.as_ref().unwrap()[0];
+        let map = &attestation.map.as_ref().unwrap()[0];
+        assert_eq!(map.requirement.as_deref(), Some("requirement-1"));
+        assert_eq!(map.conformance.as_ref().unwrap().score, Some(1.0));
+        let claim = &document.declarations.claims.as_ref().unwrap()[0];
+        assert_eq!(claim.evidence, Some(vec!["evidence-1".to_string()]));
+
+        assert_eq!(serde_json::to_value(&document).unwrap(), value);
+    }
+}
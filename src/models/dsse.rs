@@ -0,0 +1,109 @@
+//! DSSE envelope model.
+//!
+//! Attestations are usually distributed signed, as a [DSSE](https://github.com/secure-systems-lab/dsse)
+//! envelope wrapping the In-Toto statement as a base64-encoded payload:
+//!
+//! ```json
+//! {
+//!   "payloadType": "application/vnd.in-toto+json",
+//!   "payload": "eyJfdHlwZSI6Imh0dHBzOi8vaW4tdG90by5pby9TdGF0ZW1lbnQvdjEiLC4uLn0=",
+//!   "signatures": [{"keyid": "", "sig": "MEUCIQD..."}]
+//! }
+//! ```
+//!
+//! The payload is decoded when the envelope is deserialized. Signatures are modelled but not
+//! verified here, see `verify` for that.
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::models::helpers::b64_serde;
+
+/// The DSSE payload type of In-Toto statements.
+pub const IN_TOTO_PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+
+/// A DSSE envelope.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Envelope {
+    /// The payload, decoded from base64.
+    #[serde(with = "b64_serde")]
+    #[schemars(with = "String")]
+    pub payload: Vec<u8>,
+    /// The media type of the payload, `application/vnd.in-toto+json` for In-Toto statements.
+    pub payload_type: String,
+    /// The signatures over the payload. Unsigned envelopes, which some tools emit before
+    /// signing, have none.
+    #[serde(default)]
+    pub signatures: Vec<Signature>,
+}
+
+/// A signature over the pre-authentication encoding of an envelope's payload.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Signature {
+    /// A hint of the key the signature was made with. Often empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyid: Option<String>,
+    /// The signature, decoded from base64.
+    #[serde(with = "b64_serde")]
+    #[schemars(with = "String")]
+    pub sig: Vec<u8>,
+}
+
+impl Envelope {
+    /// Returns true if the payload is an In-Toto statement, by its payload type.
+    pub fn is_in_toto(&self) -> bool {
+        self.payload_type == IN_TOTO_PAYLOAD_TYPE
+    }
+
+    /// Deserializes the payload as JSON, e.g. into a `serde_json::Value` or an
+    /// `InTotoStatementV1`.
+    pub fn decode_payload<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.payload)
+    }
+
+    /// Returns the pre-authentication encoding of the payload, which the signatures are over.
+    pub fn pae(&self) -> Vec<u8> {
+        let mut pae = format!(
+            "DSSEv1 {} {} {} ",
+            self.payload_type.len(),
+            self.payload_type,
+            self.payload.len()
+        )
+        .into_bytes();
+        pae.extend_from_slice(&self.payload);
+        pae
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_envelope() {
+        // `{"_type":"https://in-toto.io/Statement/v1"}`
+        let value = json!({
+            "payload": "eyJfdHlwZSI6Imh0dHBzOi8vaW4tdG90by5pby9TdGF0ZW1lbnQvdjEifQ==",
+            "payloadType": "application/vnd.in-toto+json",
+            "signatures": [{"keyid": "", "sig": "c2lnbmF0dXJl"}]
+        });
+        let envelope: Envelope = serde_json::from_value(value.clone()).unwrap();
+        assert!(envelope.is_in_toto());
+        assert_eq!(envelope.signatures[0].sig, b"signature");
+        assert_eq!(
+            envelope.decode_payload::<Value>().unwrap(),
+            json!({"_type": "https://in-toto.io/Statement/v1"})
+        );
+        assert!(envelope
+            .pae()
+            .starts_with(b"DSSEv1 28 application/vnd.in-toto+json 43 {\"_type\""));
+        assert_eq!(serde_json::to_value(&envelope).unwrap(), value);
+
+        let mut invalid = value;
+        invalid["payload"] = json!("not base64!");
+        assert!(serde_json::from_value::<Envelope>(invalid).is_err());
+    }
+}
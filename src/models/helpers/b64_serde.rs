@@ -0,0 +1,25 @@
+//! Custom (de)serialization functions for base64-encoded byte arrays.
+//!
+//! The same as [`super::b64_option_serde`], for `Vec<u8>` fields that are required.
+
+use base64::{engine::general_purpose, Engine};
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serializes a `Vec<u8>` as a base64-encoded string.
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(general_purpose::STANDARD.encode(bytes).as_str())
+}
+
+/// Deserializes a base64-encoded string into a `Vec<u8>`.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bytes = String::deserialize(deserializer)?;
+    general_purpose::STANDARD
+        .decode(bytes)
+        .map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,25 @@
+//! Hashing of maps whose iteration order isn't stable.
+//!
+//! `HashMap` doesn't implement `Hash`, as two equal maps can iterate in different orders. These
+//! functions hash the entries sorted by key instead, so equal maps always hash the same.
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Hashes the entries of a map in key order.
+pub fn hash_map<K: Hash + Ord, V: Hash, H: Hasher>(map: &HashMap<K, V>, state: &mut H) {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    entries.hash(state);
+}
+
+/// Hashes an optional map like `Option::hash`, with the entries in key order.
+pub fn hash_option_map<K: Hash + Ord, V: Hash, H: Hasher>(
+    map: &Option<HashMap<K, V>>,
+    state: &mut H,
+) {
+    map.is_some().hash(state);
+    if let Some(map) = map {
+        hash_map(map, state);
+    }
+}
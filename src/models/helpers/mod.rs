@@ -1 +1,3 @@
-pub mod b64_option_serde;
\ No newline at end of file
+pub mod b64_option_serde;
+pub mod b64_serde;
+pub mod hash;
@@ -0,0 +1,176 @@
+//! apko build predicate model and associated structures.
+//!
+//! apko assembles container images from APK packages. Its build attestations record the image
+//! configuration the image was built from, the packages that were resolved and installed, and
+//! the SBOMs generated for the image:
+//!
+//! ```json
+//! {
+//!   "config": {
+//!     "contents": {
+//!       "repositories": ["https://packages.wolfi.dev/os"],
+//!       "keyring": ["https://packages.wolfi.dev/os/wolfi-signing.rsa.pub"],
+//!       "packages": ["wolfi-base"]
+//!     },
+//!     "entrypoint": {"command": "/bin/sh -l"},
+//!     "archs": ["x86_64"]
+//!   },
+//!   "packages": [{"name": "wolfi-base", "version": "1-r5", "arch": "x86_64"}],
+//!   "sboms": [{"uri": "sbom-x86_64.spdx.json", "digest": {"sha256": "..."}}]
+//! }
+//! ```
+//!
+//! The package and contents structures are shared with melange, which builds the packages.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::resource_descriptor::ResourceDescriptor;
+
+/// A struct representing the apko build predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ApkoBuildPredicate {
+    /// The image configuration the image was built from.
+    pub config: ImageConfiguration,
+    /// The packages installed in the image, as resolved at build time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<ApkPackage>,
+    /// The SBOMs generated for the image.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sboms: Vec<ResourceDescriptor>,
+}
+
+/// An apko image configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct ImageConfiguration {
+    pub contents: ApkContents,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<Entrypoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_dir: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accounts: Option<Accounts>,
+    /// The architectures the image is built for, e.g. `x86_64` and `aarch64`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub archs: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub environment: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+}
+
+/// The APK repositories, keys and packages an image or build environment is made of.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ApkContents {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repositories: Vec<String>,
+    /// The URLs or paths of the keys the repositories are signed with.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keyring: Vec<String>,
+    /// The packages to install, optionally with a version constraint, e.g. `busybox>=1.36`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<String>,
+}
+
+/// The entrypoint of an image.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Entrypoint {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+}
+
+/// The users and groups of an image.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct Accounts {
+    /// The user the entrypoint runs as.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_as: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<User>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<Group>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct User {
+    pub username: String,
+    pub uid: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gid: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Group {
+    pub groupname: String,
+    pub gid: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
+}
+
+/// An APK package, as installed by apko or built by melange.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ApkPackage {
+    pub name: String,
+    /// The full version, including the epoch as the `-r` suffix, e.g. `1.36.1-r2`.
+    pub version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    /// The repository the package was installed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    /// The APK checksum of the package control section, e.g. `Q1...=`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apko_build_predicate() {
+        let value = json!({
+            "config": {
+                "contents": {
+                    "repositories": ["https://packages.wolfi.dev/os"],
+                    "keyring": ["https://packages.wolfi.dev/os/wolfi-signing.rsa.pub"],
+                    "packages": ["wolfi-base"]
+                },
+                "entrypoint": {"command": "/bin/sh -l"},
+                "work-dir": "/home/nonroot",
+                "accounts": {
+                    "run-as": "nonroot",
+                    "users": [{"username": "nonroot", "uid": 65532}],
+                    "groups": [{"groupname": "nonroot", "gid": 65532}]
+                },
+                "archs": ["x86_64", "aarch64"],
+                "environment": {"PATH": "/usr/bin:/bin"}
+            },
+            "packages": [{
+                "name": "wolfi-base",
+                "version": "1-r5",
+                "arch": "x86_64",
+                "repository": "https://packages.wolfi.dev/os",
+                "checksum": "Q1dGBpOK0Xb/7tIKGhrQbK0SBWFLw="
+            }],
+            "sboms": [{
+                "uri": "sbom-x86_64.spdx.json",
+                "digest": {"sha256": "a7f2b6f2d4d0c6f6bbd0ac66b38c3c91ad8ab3e6d0a0b5a1fd42ef2d0b3a4f1c"},
+                "mediaType": "application/spdx+json"
+            }]
+        });
+        let predicate: ApkoBuildPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            predicate.config.accounts.as_ref().unwrap().users[0].uid,
+            65532
+        );
+        assert_eq!(predicate.sboms.len(), 1);
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+    }
+}
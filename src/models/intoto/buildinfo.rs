@@ -0,0 +1,212 @@
+//! JVM build-info predicate model and associated structures.
+//!
+//! Gradle and Maven builds publish build-info: the modules the build produced, identified by
+//! their coordinates, with the checksums of their artifacts and of the dependencies they were
+//! built with, and the versions of the build plugins that were applied:
+//!
+//! ```json
+//! {
+//!   "name": "hello-world",
+//!   "number": "42",
+//!   "buildAgent": {"name": "Gradle", "version": "8.5"},
+//!   "modules": [{
+//!     "type": "gradle",
+//!     "id": "com.example:hello-world:1.2.3",
+//!     "artifacts": [{"name": "hello-world-1.2.3.jar", "type": "jar", "sha256": "..."}],
+//!     "dependencies": [{"id": "com.google.guava:guava:33.0.0-jre", "sha256": "...", "scopes": ["compile"]}]
+//!   }],
+//!   "plugins": [{"id": "org.springframework.boot", "version": "3.2.0"}]
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A struct representing the JVM build-info predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JvmBuildInfoPredicate {
+    /// The name of the build.
+    pub name: String,
+    /// The number of the build, as set by the CI system.
+    pub number: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started: Option<DateTime<Utc>>,
+    /// The build tool, e.g. Gradle or Maven.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_agent: Option<Agent>,
+    /// The CI system the build tool ran in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<Agent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<Module>,
+    /// The build plugins that were applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugins: Vec<Plugin>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Agent {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// A module the build produced.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Module {
+    /// The build tool of the module, e.g. `gradle` or `maven`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub module_type: Option<String>,
+    /// The coordinates of the module, `group:artifact:version`.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<Artifact>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<Dependency>,
+}
+
+/// A file a module published.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Artifact {
+    pub name: String,
+    /// The type of the file, e.g. `jar` or `pom`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub artifact_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(flatten)]
+    pub checksums: Checksums,
+}
+
+/// A dependency a module was built with.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Dependency {
+    /// The coordinates of the dependency, `group:artifact:version`.
+    pub id: String,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub dependency_type: Option<String>,
+    /// The configurations or scopes the dependency is in, e.g. `compile` or `runtimeClasspath`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    #[serde(flatten)]
+    pub checksums: Checksums,
+}
+
+/// The checksums of a file, as hex.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Checksums {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub md5: Option<String>,
+}
+
+/// A build plugin, identified by its plugin id or coordinates.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Plugin {
+    pub id: String,
+    pub version: String,
+}
+
+/// Maven coordinates, as written `group:artifact[:packaging[:classifier]]:version`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coordinates<'a> {
+    pub group: &'a str,
+    pub artifact: &'a str,
+    pub packaging: Option<&'a str>,
+    pub classifier: Option<&'a str>,
+    pub version: &'a str,
+}
+
+impl<'a> Coordinates<'a> {
+    /// Parses coordinates, returning None if they don't have 3 to 5 non-empty parts.
+    pub fn parse(id: &'a str) -> Option<Self> {
+        let parts = id.split(':').collect::<Vec<_>>();
+        if parts.iter().any(|part| part.trim().is_empty()) {
+            return None;
+        }
+        let (packaging, classifier) = match parts.len() {
+            3 => (None, None),
+            4 => (Some(parts[2]), None),
+            5 => (Some(parts[2]), Some(parts[3])),
+            _ => return None,
+        };
+        Some(Coordinates {
+            group: parts[0],
+            artifact: parts[1],
+            packaging,
+            classifier,
+            version: parts[parts.len() - 1],
+        })
+    }
+}
+
+/// Returns true if a version resolves to different releases over time: a Maven range, a Gradle
+/// dynamic version or one of the `LATEST` and `RELEASE` meta-versions.
+pub fn is_dynamic_version(version: &str) -> bool {
+    version.ends_with('+')
+        || version.starts_with('[')
+        || version.starts_with('(')
+        || version.starts_with("latest.")
+        || matches!(version, "LATEST" | "RELEASE")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_jvm_build_info_predicate() {
+        let value = json!({
+            "name": "hello-world",
+            "number": "42",
+            "started": "2024-05-01T12:00:00Z",
+            "buildAgent": {"name": "Gradle", "version": "8.5"},
+            "modules": [{
+                "type": "gradle",
+                "id": "com.example:hello-world:1.2.3",
+                "artifacts": [{
+                    "name": "hello-world-1.2.3.jar",
+                    "type": "jar",
+                    "sha256": "a7f2b6f2d4d0c6f6bbd0ac66b38c3c91ad8ab3e6d0a0b5a1fd42ef2d0b3a4f1c",
+                    "sha1": "b1946ac92492d2347c6235b4d2611184b3a8e1e0"
+                }],
+                "dependencies": [{
+                    "id": "com.google.guava:guava:33.0.0-jre",
+                    "scopes": ["compileClasspath"],
+                    "md5": "5d41402abc4b2a76b9719d911017c592"
+                }]
+            }],
+            "plugins": [{"id": "org.springframework.boot", "version": "3.2.0"}]
+        });
+        let predicate: JvmBuildInfoPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            predicate.modules[0].dependencies[0]
+                .checksums
+                .md5
+                .as_deref(),
+            Some("5d41402abc4b2a76b9719d911017c592")
+        );
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let coordinates = Coordinates::parse("com.example:hello-world:jar:sources:1.2.3").unwrap();
+        assert_eq!(coordinates.group, "com.example");
+        assert_eq!(coordinates.classifier, Some("sources"));
+        assert_eq!(coordinates.version, "1.2.3");
+        assert!(Coordinates::parse("com.example:hello-world").is_none());
+        assert!(Coordinates::parse("com.example::1.2.3").is_none());
+
+        assert!(is_dynamic_version("1.+"));
+        assert!(is_dynamic_version("[1.0,2.0)"));
+        assert!(is_dynamic_version("latest.release"));
+        assert!(!is_dynamic_version("33.0.0-jre"));
+    }
+}
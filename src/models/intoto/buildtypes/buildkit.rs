@@ -0,0 +1,174 @@
+//! The buildType of SLSA provenance generated by Docker BuildKit.
+//!
+//! BuildKit records the request it was sent, with the frontend and its arguments, the local
+//! sources, secrets and SSH sockets the build was given:
+//!
+//! ```json
+//! {
+//!   "frontend": "dockerfile.v0",
+//!   "args": {"build-arg:VERSION": "1.2.3", "platform": "linux/amd64"},
+//!   "locals": [{"name": "context"}, {"name": "dockerfile"}],
+//!   "secrets": [{"id": "GIT_AUTH_TOKEN", "optional": true}],
+//!   "ssh": [{"id": "default"}]
+//! }
+//! ```
+//!
+//! Where the request and the rest of the build are recorded depends on the SLSA version:
+//!
+//! | | v1 | v0.2 |
+//! |---|---|---|
+//! | Request | `externalParameters.request` | `invocation.parameters` |
+//! | Config source | `externalParameters.configSource` | `invocation.configSource` |
+//! | Build platform | `internalParameters.builderPlatform` | `invocation.environment.platform` |
+//! | LLB definition | `internalParameters.buildConfig` | `buildConfig` |
+//!
+//! How much is recorded depends on the provenance mode the build was run with. In `min` mode,
+//! the default, the build args, the LLB definition and the layer metadata are omitted. In `max`
+//! mode they are included.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// The buildType URI of BuildKit.
+pub const BUILDKIT_BUILD_TYPE: &str = "https://mobyproject.org/buildkit@v1";
+
+/// The frontend of builds from Dockerfiles.
+pub const DOCKERFILE_FRONTEND: &str = "dockerfile.v0";
+
+/// The frontend of builds whose frontend is an image, set by the `source` arg.
+pub const GATEWAY_FRONTEND: &str = "gateway.v0";
+
+/// The provenance mode of a build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildkitMode {
+    Min,
+    Max,
+}
+
+impl BuildkitMode {
+    /// Returns the mode of a provenance from its build config, which only `max` mode records.
+    pub fn detect(build_config: Option<&Value>) -> Self {
+        match build_config {
+            Some(Value::Null) | None => BuildkitMode::Min,
+            Some(_) => BuildkitMode::Max,
+        }
+    }
+}
+
+/// The request a BuildKit build was run with.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildkitRequest {
+    /// The frontend that converted the build definition to LLB, e.g. `dockerfile.v0`.
+    pub frontend: String,
+    /// The frontend options, including the build args as `build-arg:<name>`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub args: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locals: Vec<BuildkitLocal>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secrets: Vec<BuildkitSecret>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ssh: Vec<BuildkitSsh>,
+}
+
+/// A local source of a build, such as the context or the Dockerfile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildkitLocal {
+    pub name: String,
+}
+
+/// A secret a build was given. Only the id is recorded, never the value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildkitSecret {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+}
+
+/// An SSH agent socket or key a build was given.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildkitSsh {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+}
+
+/// The external parameters of SLSA v1 BuildKit provenance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildkitExternalParameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_source: Option<BuildkitConfigSource>,
+    pub request: BuildkitRequest,
+}
+
+/// Where the build definition came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildkitConfigSource {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<HashMap<String, String>>,
+    /// The path of the build definition, e.g. `Dockerfile`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// The build config recorded in `max` mode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildkitBuildConfig {
+    /// The LLB steps of the build.
+    pub llb_definition: Vec<Value>,
+    /// The digests of the LLB steps, by their ids in the definition.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub digest_mapping: BTreeMap<String, String>,
+}
+
+impl BuildkitRequest {
+    /// Returns the build args, the `build-arg:` args without their prefix.
+    pub fn build_args(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.args.iter().filter_map(|(name, value)| {
+            name.strip_prefix("build-arg:")
+                .map(|name| (name, value.as_str()))
+        })
+    }
+
+    /// Returns the image of the frontend of gateway builds, the `source` arg.
+    pub fn frontend_image(&self) -> Option<&str> {
+        self.args.get("source").map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_request() {
+        let value = json!({
+            "frontend": "gateway.v0",
+            "args": {"build-arg:VERSION": "1.2.3", "source": "docker/dockerfile:1"},
+            "locals": [{"name": "context"}],
+            "secrets": [{"id": "GIT_AUTH_TOKEN", "optional": true}],
+            "ssh": [{"id": "default"}]
+        });
+        let request: BuildkitRequest = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            request.build_args().collect::<Vec<_>>(),
+            [("VERSION", "1.2.3")]
+        );
+        assert_eq!(request.frontend_image(), Some("docker/dockerfile:1"));
+        assert_eq!(serde_json::to_value(&request).unwrap(), value);
+
+        assert_eq!(BuildkitMode::detect(None), BuildkitMode::Min);
+        assert_eq!(
+            BuildkitMode::detect(Some(&json!({"llbDefinition": []}))),
+            BuildkitMode::Max
+        );
+    }
+}
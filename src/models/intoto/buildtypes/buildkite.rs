@@ -0,0 +1,124 @@
+//! The buildType of SLSA provenance generated for Buildkite builds.
+//!
+//! The generator records the pipeline and the build in `externalParameters`, and the agent the
+//! job ran on in `internalParameters`:
+//!
+//! ```json
+//! {
+//!   "buildType": "https://buildkite.com/slsa/build-types/pipeline/v1",
+//!   "externalParameters": {
+//!     "organization": "octocat",
+//!     "pipeline": "hello-world",
+//!     "buildId": "0190046e-e199-453b-a302-a21a4d649d31",
+//!     "buildNumber": 42,
+//!     "branch": "main"
+//!   },
+//!   "internalParameters": {
+//!     "agent": {"name": "linux-agent-1", "queue": "release"}
+//!   }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::is_uuid;
+
+/// The buildType URI of Buildkite pipelines.
+pub const BUILDKITE_BUILD_TYPE: &str = "https://buildkite.com/slsa/build-types/pipeline/v1";
+
+/// The parameters of a Buildkite build that are under external control.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildkiteExternalParameters {
+    /// The slug of the organization, e.g. `octocat`.
+    pub organization: String,
+    /// The slug of the pipeline, e.g. `hello-world`.
+    pub pipeline: String,
+    /// The UUID of the build.
+    pub build_id: String,
+    /// The number of the build of the pipeline, starting from 1.
+    pub build_number: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
+/// The parameters of a Buildkite build that are set by Buildkite.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildkiteInternalParameters {
+    /// The agent the job ran on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<BuildkiteAgent>,
+}
+
+/// A Buildkite agent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildkiteAgent {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+}
+
+impl BuildkiteExternalParameters {
+    /// Returns true if the organization and pipeline are Buildkite slugs: lowercase
+    /// alphanumerics and dashes.
+    pub fn has_slugs(&self) -> bool {
+        let is_slug = |slug: &str| {
+            !slug.is_empty()
+                && slug
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        };
+        is_slug(&self.organization) && is_slug(&self.pipeline)
+    }
+
+    /// Returns true if the build id is a UUID.
+    pub fn has_build_id(&self) -> bool {
+        is_uuid(&self.build_id)
+    }
+
+    /// Returns the URL of the build, which Buildkite generators also record as the
+    /// `invocationId`.
+    pub fn build_url(&self) -> String {
+        format!(
+            "https://buildkite.com/{}/{}/builds/{}",
+            self.organization, self.pipeline, self.build_number
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parameters() {
+        let external = json!({
+            "organization": "octocat",
+            "pipeline": "hello-world",
+            "buildId": "0190046e-e199-453b-a302-a21a4d649d31",
+            "buildNumber": 42,
+            "branch": "main"
+        });
+        let mut parameters: BuildkiteExternalParameters =
+            serde_json::from_value(external.clone()).unwrap();
+        assert!(parameters.has_slugs());
+        assert!(parameters.has_build_id());
+        assert_eq!(
+            parameters.build_url(),
+            "https://buildkite.com/octocat/hello-world/builds/42"
+        );
+        assert_eq!(serde_json::to_value(&parameters).unwrap(), external);
+
+        parameters.pipeline = "Hello World".to_string();
+        parameters.build_id = "42".to_string();
+        assert!(!parameters.has_slugs());
+        assert!(!parameters.has_build_id());
+
+        let internal: BuildkiteInternalParameters =
+            serde_json::from_value(json!({"agent": {"name": "linux-agent-1"}})).unwrap();
+        assert_eq!(internal.agent.unwrap().queue, None);
+    }
+}
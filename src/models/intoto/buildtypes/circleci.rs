@@ -0,0 +1,113 @@
+//! The buildType of SLSA provenance generated for CircleCI workflows.
+//!
+//! The generator records the pipeline and the workflow of the job in `externalParameters`:
+//!
+//! ```json
+//! {
+//!   "buildType": "https://circleci.com/slsa/build-types/workflow/v1",
+//!   "externalParameters": {
+//!     "projectSlug": "gh/octocat/hello-world",
+//!     "pipelineId": "5034460f-c7c4-4c43-9457-de07e2029e7b",
+//!     "pipelineNumber": 42,
+//!     "workflow": {"id": "fda08377-fe7e-46b1-8992-3a7aaecac9c3", "name": "release"},
+//!     "parameters": {"deploy": true}
+//!   }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::is_uuid;
+
+/// The buildType URI of CircleCI workflows.
+pub const CIRCLECI_BUILD_TYPE: &str = "https://circleci.com/slsa/build-types/workflow/v1";
+
+/// The version control systems of CircleCI project slugs.
+pub const PROJECT_SLUG_VCS: &[&str] = &["gh", "github", "bb", "bitbucket", "circleci"];
+
+/// The parameters of a CircleCI workflow that are under external control.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircleCiExternalParameters {
+    /// The project, as `<vcs>/<org>/<repo>`, e.g. `gh/octocat/hello-world`.
+    pub project_slug: String,
+    /// The UUID of the pipeline.
+    pub pipeline_id: String,
+    /// The number of the pipeline in the project, starting from 1.
+    pub pipeline_number: u64,
+    pub workflow: CircleCiWorkflow,
+    /// The pipeline parameters.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameters: BTreeMap<String, Value>,
+}
+
+/// A workflow of a CircleCI pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircleCiWorkflow {
+    /// The UUID of the workflow.
+    pub id: String,
+    /// The name of the workflow in the project's config.
+    pub name: String,
+}
+
+impl CircleCiExternalParameters {
+    /// Returns true if the project slug has a known version control system, an organization and
+    /// a repository.
+    pub fn has_project_slug(&self) -> bool {
+        let segments = self.project_slug.split('/').collect::<Vec<_>>();
+        matches!(segments.as_slice(), [vcs, org, repo]
+            if PROJECT_SLUG_VCS.contains(vcs) && !org.is_empty() && !repo.is_empty())
+    }
+
+    /// Returns true if the pipeline id is a UUID.
+    pub fn has_pipeline_id(&self) -> bool {
+        is_uuid(&self.pipeline_id)
+    }
+
+    /// Returns true if the workflow has a UUID and a name.
+    pub fn has_workflow(&self) -> bool {
+        is_uuid(&self.workflow.id) && !self.workflow.name.trim().is_empty()
+    }
+
+    /// Returns the URL of the workflow, which CircleCI generators also record as the
+    /// `invocationId`.
+    pub fn workflow_url(&self) -> String {
+        format!(
+            "https://app.circleci.com/pipelines/{}/{}/workflows/{}",
+            self.project_slug, self.pipeline_number, self.workflow.id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parameters() {
+        let external = json!({
+            "projectSlug": "gh/octocat/hello-world",
+            "pipelineId": "5034460f-c7c4-4c43-9457-de07e2029e7b",
+            "pipelineNumber": 42,
+            "workflow": {"id": "fda08377-fe7e-46b1-8992-3a7aaecac9c3", "name": "release"}
+        });
+        let mut parameters: CircleCiExternalParameters =
+            serde_json::from_value(external.clone()).unwrap();
+        assert!(parameters.has_project_slug());
+        assert!(parameters.has_pipeline_id());
+        assert!(parameters.has_workflow());
+        assert_eq!(
+            parameters.workflow_url(),
+            "https://app.circleci.com/pipelines/gh/octocat/hello-world/42/workflows/fda08377-fe7e-46b1-8992-3a7aaecac9c3"
+        );
+        assert_eq!(serde_json::to_value(&parameters).unwrap(), external);
+
+        parameters.project_slug = "gitlab/octocat/hello-world".to_string();
+        parameters.workflow.name = " ".to_string();
+        assert!(!parameters.has_project_slug());
+        assert!(!parameters.has_workflow());
+    }
+}
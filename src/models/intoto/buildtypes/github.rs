@@ -0,0 +1,171 @@
+//! The buildType of SLSA provenance generated for GitHub Actions workflows, as in GitHub Artifact
+//! Attestations made with `actions/attest-build-provenance` and checked with `gh attestation`.
+//!
+//! The workflow that ran is recorded in `externalParameters`, and the GitHub context of the run in
+//! `internalParameters`:
+//!
+//! ```json
+//! {
+//!   "buildType": "https://actions.github.io/buildtypes/workflow/v1",
+//!   "externalParameters": {
+//!     "workflow": {
+//!       "ref": "refs/heads/main",
+//!       "repository": "https://github.com/octo-org/octo-repo",
+//!       "path": ".github/workflows/release.yml"
+//!     }
+//!   },
+//!   "internalParameters": {
+//!     "github": {
+//!       "event_name": "push",
+//!       "repository_id": "123456789",
+//!       "repository_owner_id": "987654321",
+//!       "runner_environment": "github-hosted"
+//!     }
+//!   }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use url::Url;
+
+/// The buildType URI of GitHub Actions workflows.
+pub const GITHUB_ACTIONS_BUILD_TYPE: &str = "https://actions.github.io/buildtypes/workflow/v1";
+
+/// The directory of a repository GitHub Actions runs workflows from.
+pub const WORKFLOWS_DIRECTORY: &str = ".github/workflows/";
+
+/// The parameters of a GitHub Actions workflow run that are under external control.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitHubActionsExternalParameters {
+    pub workflow: GitHubWorkflow,
+    /// The inputs of `workflow_dispatch` runs.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub inputs: BTreeMap<String, Value>,
+}
+
+/// The workflow file that ran.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitHubWorkflow {
+    /// The git ref the workflow ran at, e.g. `refs/heads/main`.
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// The URL of the repository, e.g. `https://github.com/octo-org/octo-repo`.
+    pub repository: Url,
+    /// The path of the workflow file in the repository, e.g. `.github/workflows/release.yml`.
+    pub path: String,
+}
+
+/// The parameters of a GitHub Actions workflow run that are set by GitHub.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitHubActionsInternalParameters {
+    pub github: GitHubContext,
+}
+
+/// The parts of the `github` context of the run that GitHub records, with its snake_case keys.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitHubContext {
+    /// The event that triggered the run, e.g. `push` or `workflow_dispatch`.
+    pub event_name: String,
+    /// The numeric id of the repository, which unlike its name can't be reused.
+    pub repository_id: String,
+    /// The numeric id of the organization or user owning the repository.
+    pub repository_owner_id: String,
+    /// `github-hosted` or `self-hosted`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runner_environment: Option<String>,
+}
+
+impl GitHubWorkflow {
+    /// Returns true if the ref is a full git ref, e.g. `refs/heads/main` rather than `main`.
+    pub fn has_ref(&self) -> bool {
+        self.git_ref
+            .strip_prefix("refs/")
+            .is_some_and(|name| name.contains('/') && !name.ends_with('/'))
+    }
+
+    /// Returns true if the path is a YAML file in the workflows directory.
+    pub fn has_path(&self) -> bool {
+        self.path.starts_with(WORKFLOWS_DIRECTORY)
+            && (self.path.ends_with(".yml") || self.path.ends_with(".yaml"))
+    }
+
+    /// Returns true if the repository is the https URL of an owner's repository.
+    pub fn has_repository(&self) -> bool {
+        let segments = self
+            .repository
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).count());
+        self.repository.scheme() == "https" && segments == Some(2)
+    }
+
+    /// Returns the URL the runs of the repository's workflows are under, which the
+    /// `invocationId` is the URL of an attempt of a run in.
+    pub fn runs_url(&self) -> String {
+        format!(
+            "{}/actions/runs/",
+            self.repository.as_str().trim_end_matches('/')
+        )
+    }
+}
+
+impl GitHubContext {
+    /// Returns true if the repository and owner ids are numeric.
+    pub fn has_ids(&self) -> bool {
+        [&self.repository_id, &self.repository_owner_id]
+            .iter()
+            .all(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+    }
+
+    /// Returns true if the run was on a self-hosted runner, which GitHub doesn't vouch for.
+    pub fn is_self_hosted(&self) -> bool {
+        self.runner_environment.as_deref() == Some("self-hosted")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parameters() {
+        let external = json!({
+            "workflow": {
+                "ref": "refs/heads/main",
+                "repository": "https://github.com/octo-org/octo-repo",
+                "path": ".github/workflows/release.yml"
+            }
+        });
+        let mut parameters: GitHubActionsExternalParameters =
+            serde_json::from_value(external.clone()).unwrap();
+        assert!(parameters.workflow.has_ref());
+        assert!(parameters.workflow.has_path());
+        assert!(parameters.workflow.has_repository());
+        assert_eq!(
+            parameters.workflow.runs_url(),
+            "https://github.com/octo-org/octo-repo/actions/runs/"
+        );
+        assert_eq!(serde_json::to_value(&parameters).unwrap(), external);
+
+        parameters.workflow.git_ref = "main".to_string();
+        parameters.workflow.path = "release.yml".to_string();
+        parameters.workflow.repository = Url::parse("https://github.com/octo-org").unwrap();
+        assert!(!parameters.workflow.has_ref());
+        assert!(!parameters.workflow.has_path());
+        assert!(!parameters.workflow.has_repository());
+
+        let internal: GitHubActionsInternalParameters = serde_json::from_value(json!({
+            "github": {
+                "event_name": "push",
+                "repository_id": "123456789",
+                "repository_owner_id": "octo-org",
+                "runner_environment": "self-hosted"
+            }
+        }))
+        .unwrap();
+        assert!(!internal.github.has_ids());
+        assert!(internal.github.is_self_hosted());
+    }
+}
@@ -0,0 +1,131 @@
+//! The buildType of SLSA provenance generated for Jenkins jobs.
+//!
+//! The generator records the job and the build number it was run as in `externalParameters`,
+//! and the agent the build ran on in `internalParameters`:
+//!
+//! ```json
+//! {
+//!   "buildType": "https://jenkins.io/slsa/build-types/job/v1",
+//!   "externalParameters": {
+//!     "jobUrl": "https://ci.example.com/job/release/",
+//!     "buildNumber": 42,
+//!     "parameters": {"VERSION": "1.2.3"}
+//!   },
+//!   "internalParameters": {
+//!     "node": {"name": "linux-agent-1", "labels": ["linux", "docker"]}
+//!   }
+//! }
+//! ```
+//!
+//! SLSA v0.2 provenance records the same values in `invocation.parameters` and
+//! `invocation.environment`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use url::Url;
+
+/// The buildType URI of Jenkins jobs.
+pub const JENKINS_BUILD_TYPE: &str = "https://jenkins.io/slsa/build-types/job/v1";
+
+/// The names Jenkins gives the controller when it runs builds itself, rather than on an agent.
+pub const CONTROLLER_NODE_NAMES: &[&str] = &["built-in", "master"];
+
+/// The parameters of a Jenkins build that are under external control.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JenkinsExternalParameters {
+    /// The URL of the job, e.g. `https://ci.example.com/job/folder/job/release/`.
+    pub job_url: Url,
+    /// The number of the build of the job, starting from 1.
+    pub build_number: u64,
+    /// The parameters the job was run with.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameters: BTreeMap<String, Value>,
+}
+
+/// The parameters of a Jenkins build that are set by the Jenkins instance.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JenkinsInternalParameters {
+    /// The node the build ran on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<JenkinsNode>,
+}
+
+/// A Jenkins controller or agent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JenkinsNode {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+}
+
+impl JenkinsExternalParameters {
+    /// Returns true if the job URL is an http or https URL with a `/job/` path segment.
+    pub fn has_job_url(&self) -> bool {
+        matches!(self.job_url.scheme(), "http" | "https")
+            && self.job_url.host().is_some()
+            && self
+                .job_url
+                .path_segments()
+                .is_some_and(|mut segments| segments.any(|segment| segment == "job"))
+    }
+
+    /// Returns the URL of the build, which Jenkins generators also record as the
+    /// `invocationId`.
+    pub fn build_url(&self) -> String {
+        format!(
+            "{}/{}/",
+            self.job_url.as_str().trim_end_matches('/'),
+            self.build_number
+        )
+    }
+}
+
+impl JenkinsNode {
+    /// Returns true if the node is the controller rather than an agent.
+    pub fn is_controller(&self) -> bool {
+        CONTROLLER_NODE_NAMES.contains(&self.name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parameters() {
+        let external = json!({
+            "jobUrl": "https://ci.example.com/job/folder/job/release/",
+            "buildNumber": 42,
+            "parameters": {"VERSION": "1.2.3"}
+        });
+        let parameters: JenkinsExternalParameters =
+            serde_json::from_value(external.clone()).unwrap();
+        assert!(parameters.has_job_url());
+        assert_eq!(
+            parameters.build_url(),
+            "https://ci.example.com/job/folder/job/release/42/"
+        );
+        assert_eq!(serde_json::to_value(&parameters).unwrap(), external);
+
+        let parameters: JenkinsExternalParameters = serde_json::from_value(json!({
+            "jobUrl": "ftp://ci.example.com/release",
+            "buildNumber": 1
+        }))
+        .unwrap();
+        assert!(!parameters.has_job_url());
+        assert!(serde_json::from_value::<JenkinsExternalParameters>(json!({
+            "jobUrl": "https://ci.example.com/job/release/",
+            "buildNumber": -1
+        }))
+        .is_err());
+
+        let internal: JenkinsInternalParameters =
+            serde_json::from_value(json!({"node": {"name": "built-in"}})).unwrap();
+        assert!(internal.node.unwrap().is_controller());
+    }
+}
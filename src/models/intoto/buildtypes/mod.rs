@@ -0,0 +1,166 @@
+//! Profiles of the `buildType`s of known SLSA provenance generators.
+//!
+//! The buildType of a provenance defines what its parameters mean. For buildTypes with a
+//! profile, the parameters can be read as typed structures and checked against what the
+//! generator is known to record. The profile is selected from the buildType URI with
+//! [`BuildTypeProfile::detect`].
+
+pub mod buildkit;
+pub mod buildkite;
+pub mod circleci;
+pub mod github;
+pub mod jenkins;
+pub mod tekton;
+
+/// A buildType with typed parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuildTypeProfile {
+    /// Jenkins jobs, see [`jenkins`].
+    Jenkins,
+    /// CircleCI workflows, see [`circleci`].
+    CircleCi,
+    /// Buildkite pipelines, see [`buildkite`].
+    Buildkite,
+    /// Docker BuildKit builds, see [`buildkit`].
+    Buildkit,
+    /// GitHub Actions workflows, see [`github`].
+    GitHubActions,
+    /// Tekton Chains TaskRuns, in SLSA v0.2 provenance, see [`tekton`].
+    TektonTaskRun,
+    /// Tekton Chains PipelineRuns, in SLSA v0.2 provenance, see [`tekton`].
+    TektonPipelineRun,
+    /// Tekton Chains runs, in SLSA v1 provenance, see [`tekton`].
+    TektonChains,
+}
+
+impl BuildTypeProfile {
+    /// All the profiles.
+    pub const ALL: &'static [BuildTypeProfile] = &[
+        BuildTypeProfile::Jenkins,
+        BuildTypeProfile::CircleCi,
+        BuildTypeProfile::Buildkite,
+        BuildTypeProfile::Buildkit,
+        BuildTypeProfile::GitHubActions,
+        BuildTypeProfile::TektonTaskRun,
+        BuildTypeProfile::TektonPipelineRun,
+        BuildTypeProfile::TektonChains,
+    ];
+
+    /// Returns the profile of a buildType URI, if it has one. Any version of a buildType
+    /// selects its profile, and for Tekton's `tekton.dev/<version>/<kind>` buildTypes any API
+    /// version.
+    pub fn detect(build_type: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|profile| profile.matches(build_type))
+    }
+
+    fn matches(&self, build_type: &str) -> bool {
+        match self {
+            BuildTypeProfile::TektonTaskRun => {
+                tekton::TektonRunKind::from_build_type(build_type)
+                    == Some(tekton::TektonRunKind::TaskRun)
+            }
+            BuildTypeProfile::TektonPipelineRun => {
+                tekton::TektonRunKind::from_build_type(build_type)
+                    == Some(tekton::TektonRunKind::PipelineRun)
+            }
+            BuildTypeProfile::TektonChains => tekton::is_chains_build_type(build_type),
+            _ => unversioned(self.build_type()) == unversioned(build_type),
+        }
+    }
+
+    /// Returns the buildType URI of the current version of the profile.
+    pub fn build_type(&self) -> &'static str {
+        match self {
+            BuildTypeProfile::Jenkins => jenkins::JENKINS_BUILD_TYPE,
+            BuildTypeProfile::CircleCi => circleci::CIRCLECI_BUILD_TYPE,
+            BuildTypeProfile::Buildkite => buildkite::BUILDKITE_BUILD_TYPE,
+            BuildTypeProfile::Buildkit => buildkit::BUILDKIT_BUILD_TYPE,
+            BuildTypeProfile::GitHubActions => github::GITHUB_ACTIONS_BUILD_TYPE,
+            BuildTypeProfile::TektonTaskRun => tekton::TEKTON_TASK_RUN_BUILD_TYPE,
+            BuildTypeProfile::TektonPipelineRun => tekton::TEKTON_PIPELINE_RUN_BUILD_TYPE,
+            BuildTypeProfile::TektonChains => tekton::TEKTON_CHAINS_BUILD_TYPE,
+        }
+    }
+}
+
+/// Returns a buildType URI without its version: an `@` suffix, e.g. `@v1`, or else the last
+/// path segment.
+fn unversioned(build_type: &str) -> &str {
+    let last = build_type.rsplit('/').next().unwrap_or(build_type);
+    match last.split_once('@') {
+        Some((name, _)) => &build_type[..build_type.len() - last.len() + name.len()],
+        None => build_type
+            .rsplit_once('/')
+            .map_or(build_type, |(base, _)| base),
+    }
+}
+
+/// Returns true if an id is a hyphenated UUID.
+fn is_uuid(id: &str) -> bool {
+    let groups = id.split('-').collect::<Vec<_>>();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(group, len)| group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        for profile in BuildTypeProfile::ALL {
+            assert_eq!(
+                BuildTypeProfile::detect(profile.build_type()),
+                Some(*profile)
+            );
+        }
+        assert_eq!(
+            BuildTypeProfile::detect("https://jenkins.io/slsa/build-types/job/v2"),
+            Some(BuildTypeProfile::Jenkins)
+        );
+        assert_eq!(
+            BuildTypeProfile::detect("https://mobyproject.org/buildkit@v2"),
+            Some(BuildTypeProfile::Buildkit)
+        );
+        assert_eq!(
+            BuildTypeProfile::detect("https://mobyproject.org/other@v1"),
+            None
+        );
+        assert_eq!(
+            BuildTypeProfile::detect("https://actions.github.io/buildtypes/workflow/v2"),
+            Some(BuildTypeProfile::GitHubActions)
+        );
+        assert_eq!(
+            BuildTypeProfile::detect(
+                "https://slsa-framework.github.io/github-actions-buildtypes/workflow/v1"
+            ),
+            None
+        );
+        assert_eq!(
+            BuildTypeProfile::detect("tekton.dev/v1/TaskRun"),
+            Some(BuildTypeProfile::TektonTaskRun)
+        );
+        assert_eq!(
+            BuildTypeProfile::detect("tekton.dev/v1beta1/CustomRun"),
+            None
+        );
+        assert_eq!(
+            BuildTypeProfile::detect("https://tekton.dev/chains/v2/slsa-tekton"),
+            Some(BuildTypeProfile::TektonChains)
+        );
+    }
+
+    #[test]
+    fn test_is_uuid() {
+        assert!(is_uuid("5034460f-c7c4-4c43-9457-de07e2029e7b"));
+        assert!(!is_uuid("5034460f-c7c4-4c43-9457"));
+        assert!(!is_uuid("5034460f-c7c4-4c43-9457-de07e2029e7g"));
+    }
+}
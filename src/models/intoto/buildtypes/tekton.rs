@@ -0,0 +1,279 @@
+//! The buildTypes of SLSA provenance generated by Tekton Chains for TaskRuns and PipelineRuns.
+//!
+//! Chains' SLSA v0.2 provenance has a buildType per kind of run, e.g. `tekton.dev/v1beta1/TaskRun`,
+//! and records what ran in `buildConfig`: the steps of a TaskRun, or the tasks of a PipelineRun
+//! with their steps:
+//!
+//! ```json
+//! {
+//!   "buildType": "tekton.dev/v1beta1/PipelineRun",
+//!   "buildConfig": {
+//!     "tasks": [{
+//!       "name": "build",
+//!       "after": ["git-clone"],
+//!       "ref": {"name": "buildah", "kind": "Task"},
+//!       "status": "Succeeded",
+//!       "steps": [{
+//!         "entryPoint": "buildah bud -t $(params.IMAGE) .",
+//!         "environment": {"container": "build", "image": "quay.io/buildah/stable@sha256:..."}
+//!       }]
+//!     }]
+//!   }
+//! }
+//! ```
+//!
+//! Its SLSA v1 provenance has the `https://tekton.dev/chains/v2/slsa` buildType, or
+//! `https://tekton.dev/chains/v2/slsa-tekton` with the labels and annotations of the run, and
+//! records the spec of the run in `externalParameters` and the Tekton Pipelines feature flags in
+//! `internalParameters`:
+//!
+//! ```json
+//! {
+//!   "buildType": "https://tekton.dev/chains/v2/slsa",
+//!   "externalParameters": {"runSpec": {"pipelineRef": {"name": "release"}, "params": []}},
+//!   "internalParameters": {"tekton-pipelines-feature-flags": {"EnableAPIFields": "beta"}}
+//! }
+//! ```
+//!
+//! Both record the UID of the run as the invocation id.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashSet};
+
+use super::is_uuid;
+
+/// The buildType of Chains' SLSA v0.2 provenance for TaskRuns.
+pub const TEKTON_TASK_RUN_BUILD_TYPE: &str = "tekton.dev/v1beta1/TaskRun";
+
+/// The buildType of Chains' SLSA v0.2 provenance for PipelineRuns.
+pub const TEKTON_PIPELINE_RUN_BUILD_TYPE: &str = "tekton.dev/v1beta1/PipelineRun";
+
+/// The buildType of Chains' SLSA v1 provenance.
+pub const TEKTON_CHAINS_BUILD_TYPE: &str = "https://tekton.dev/chains/v2/slsa";
+
+/// The buildType of Chains' SLSA v1 provenance that also records the labels and annotations of
+/// the run.
+pub const TEKTON_CHAINS_TEKTON_BUILD_TYPE: &str = "https://tekton.dev/chains/v2/slsa-tekton";
+
+/// The fields of a run spec, one of which says what ran.
+pub const RUN_SPEC_REFERENCES: &[&str] = &["taskRef", "taskSpec", "pipelineRef", "pipelineSpec"];
+
+/// The kind of run of a SLSA v0.2 buildType.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TektonRunKind {
+    TaskRun,
+    PipelineRun,
+}
+
+impl TektonRunKind {
+    /// Returns the kind of run of a `tekton.dev/<version>/<kind>` buildType, in any API version.
+    pub fn from_build_type(build_type: &str) -> Option<Self> {
+        let rest = build_type.strip_prefix("tekton.dev/")?;
+        match rest.split_once('/')? {
+            (version, "TaskRun") if version.starts_with('v') => Some(TektonRunKind::TaskRun),
+            (version, "PipelineRun") if version.starts_with('v') => {
+                Some(TektonRunKind::PipelineRun)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns true if a buildType is one of Chains' SLSA v1 buildTypes, in any version.
+pub fn is_chains_build_type(build_type: &str) -> bool {
+    build_type
+        .strip_prefix("https://tekton.dev/chains/")
+        .and_then(|rest| rest.split_once('/'))
+        .is_some_and(|(version, name)| {
+            version.starts_with('v') && matches!(name, "slsa" | "slsa-tekton")
+        })
+}
+
+/// Returns true if an invocation id is the UID of a run, which Chains records.
+pub fn is_run_uid(invocation_id: &str) -> bool {
+    is_uuid(invocation_id)
+}
+
+/// The build config of a TaskRun.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TektonTaskRunBuildConfig {
+    pub steps: Vec<TektonStep>,
+}
+
+/// The build config of a PipelineRun.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TektonPipelineRunBuildConfig {
+    pub tasks: Vec<TektonTask>,
+}
+
+/// A task of a PipelineRun.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TektonTask {
+    /// The name of the task in the pipeline.
+    pub name: String,
+    /// The names of the tasks that ran before it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<Vec<String>>,
+    /// The reference to the Task that ran, if it wasn't embedded.
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub task_ref: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_on: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_on: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_account_name: Option<String>,
+    /// The reason of the TaskRun's condition, e.g. `Succeeded` or `Failed`.
+    pub status: String,
+    #[serde(default)]
+    pub steps: Vec<TektonStep>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invocation: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Value>,
+}
+
+/// A step of a TaskRun.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TektonStep {
+    /// The script or command of the step.
+    pub entry_point: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<Value>,
+    pub environment: TektonStepEnvironment,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Value>,
+}
+
+/// The container a step ran in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TektonStepEnvironment {
+    /// The name of the step's container.
+    pub container: String,
+    /// The image the step ran, as resolved by the Tekton controller.
+    pub image: String,
+}
+
+/// The parameters of Chains' SLSA v1 provenance that are under external control.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TektonChainsExternalParameters {
+    /// The spec of the TaskRun or PipelineRun.
+    pub run_spec: Map<String, Value>,
+    /// Where the Task or Pipeline came from, if it was resolved remotely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_config_source: Option<Value>,
+}
+
+/// The parameters of Chains' SLSA v1 provenance that are set by Tekton.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TektonChainsInternalParameters {
+    #[serde(
+        rename = "tekton-pipelines-feature-flags",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub feature_flags: Option<Map<String, Value>>,
+    /// The labels of the run, recorded with the `slsa-tekton` buildType.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<BTreeMap<String, String>>,
+    /// The annotations of the run, recorded with the `slsa-tekton` buildType.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<BTreeMap<String, String>>,
+}
+
+impl TektonStep {
+    /// Returns true if the step's image is pinned by a digest.
+    pub fn has_pinned_image(&self) -> bool {
+        self.environment
+            .image
+            .split_once('@')
+            .is_some_and(|(_, digest)| digest.contains(':'))
+    }
+}
+
+impl TektonTask {
+    /// Returns true if the task's TaskRun succeeded.
+    pub fn succeeded(&self) -> bool {
+        self.status == "Succeeded"
+    }
+}
+
+impl TektonPipelineRunBuildConfig {
+    /// Returns the indexes and names of the tasks listed after a task that isn't in the
+    /// pipeline, with the unknown task.
+    pub fn unknown_dependencies(&self) -> Vec<(usize, &str)> {
+        let names = self
+            .tasks
+            .iter()
+            .map(|task| task.name.as_str())
+            .collect::<HashSet<_>>();
+        self.tasks
+            .iter()
+            .enumerate()
+            .flat_map(|(i, task)| {
+                task.after
+                    .iter()
+                    .flatten()
+                    .filter(|after| !names.contains(after.as_str()))
+                    .map(move |after| (i, after.as_str()))
+            })
+            .collect()
+    }
+}
+
+impl TektonChainsExternalParameters {
+    /// Returns true if the run spec says which Task or Pipeline ran.
+    pub fn has_run_reference(&self) -> bool {
+        RUN_SPEC_REFERENCES
+            .iter()
+            .any(|field| self.run_spec.contains_key(*field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_types() {
+        assert_eq!(
+            TektonRunKind::from_build_type(TEKTON_TASK_RUN_BUILD_TYPE),
+            Some(TektonRunKind::TaskRun)
+        );
+        assert_eq!(
+            TektonRunKind::from_build_type("tekton.dev/v1/PipelineRun"),
+            Some(TektonRunKind::PipelineRun)
+        );
+        assert_eq!(TektonRunKind::from_build_type("tekton.dev/v1/Run"), None);
+        assert!(is_chains_build_type(TEKTON_CHAINS_BUILD_TYPE));
+        assert!(is_chains_build_type(TEKTON_CHAINS_TEKTON_BUILD_TYPE));
+        assert!(!is_chains_build_type("https://tekton.dev/chains/v2/other"));
+    }
+
+    #[test]
+    fn test_build_config() {
+        let value = json!({
+            "tasks": [{
+                "name": "build",
+                "after": ["git-clone"],
+                "ref": {"name": "buildah", "kind": "Task"},
+                "status": "Succeeded",
+                "steps": [{
+                    "entryPoint": "buildah bud .",
+                    "environment": {"container": "build", "image": "quay.io/buildah/stable:v1"}
+                }]
+            }]
+        });
+        let build_config: TektonPipelineRunBuildConfig =
+            serde_json::from_value(value.clone()).unwrap();
+        assert!(build_config.tasks[0].succeeded());
+        assert!(!build_config.tasks[0].steps[0].has_pinned_image());
+        assert_eq!(build_config.unknown_dependencies(), vec![(0, "git-clone")]);
+        assert_eq!(serde_json::to_value(&build_config).unwrap(), value);
+    }
+}
@@ -0,0 +1,128 @@
+//! Code review predicate model and associated structures.
+//!
+//! The SLSA source track asks for changes to be reviewed by someone other than their author
+//! before they are merged. A code review attestation records who reviewed a change, the range of
+//! commits it covers and who approved which commit, as reported by the review tool:
+//!
+//! ```json
+//! {
+//!   "tool": {"name": "GitHub", "uri": "https://github.com"},
+//!   "changeUri": "https://github.com/octocat/hello-world/pull/42",
+//!   "author": "octocat",
+//!   "commitRange": {
+//!     "repository": "https://github.com/octocat/hello-world",
+//!     "base": "c27d339ee6075c1f744c5d4b200f7901aad2c369",
+//!     "head": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d"
+//!   },
+//!   "reviewers": [{"id": "hubot"}, {"id": "monalisa"}],
+//!   "approvals": [{"reviewer": "hubot", "commit": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d", "timeApproved": "2024-05-01T12:00:00Z"}]
+//! }
+//! ```
+//!
+//! See: https://slsa.dev/spec/draft/source-requirements
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::models::intoto::uri::UriReference;
+
+/// A struct representing the Code Review v0.1 Predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeReviewPredicate {
+    pub tool: ReviewTool,
+    /// The URI of the reviewed change, e.g. a pull request or a merge request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_uri: Option<UriReference>,
+    /// The id of the author of the change in the review tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    pub commit_range: CommitRange,
+    /// Everyone asked to or who did review the change.
+    pub reviewers: Vec<Reviewer>,
+    pub approvals: Vec<Approval>,
+    /// When the change was merged, if it was.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_merged: Option<DateTime<Utc>>,
+}
+
+/// The tool a review took place in.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ReviewTool {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<UriReference>,
+}
+
+/// The commits of a change, after `base` up to and including `head`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct CommitRange {
+    pub repository: UriReference,
+    pub base: String,
+    pub head: String,
+}
+
+/// A reviewer of a change.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Reviewer {
+    /// The id of the reviewer in the review tool.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The approval of a commit of a change by a reviewer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Approval {
+    /// The id of the approving reviewer.
+    pub reviewer: String,
+    /// The commit that was approved. Approvals of commits before the head don't cover the
+    /// commits after them.
+    pub commit: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_approved: Option<DateTime<Utc>>,
+}
+
+impl CodeReviewPredicate {
+    /// Returns the approvals of the head commit by reviewers other than the author.
+    pub fn independent_approvals(&self) -> impl Iterator<Item = &Approval> {
+        self.approvals.iter().filter(|approval| {
+            approval.commit == self.commit_range.head
+                && self.author.as_deref() != Some(approval.reviewer.as_str())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_code_review_predicate() {
+        let value = json!({
+            "tool": {"name": "GitHub", "uri": "https://github.com"},
+            "changeUri": "https://github.com/octocat/hello-world/pull/42",
+            "author": "octocat",
+            "commitRange": {
+                "repository": "https://github.com/octocat/hello-world",
+                "base": "c27d339ee6075c1f744c5d4b200f7901aad2c369",
+                "head": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d"
+            },
+            "reviewers": [{"id": "hubot"}, {"id": "octocat"}],
+            "approvals": [
+                {"reviewer": "hubot", "commit": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d", "timeApproved": "2024-05-01T12:00:00Z"},
+                {"reviewer": "hubot", "commit": "c27d339ee6075c1f744c5d4b200f7901aad2c369"},
+                {"reviewer": "octocat", "commit": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d"}
+            ],
+            "timeMerged": "2024-05-01T13:00:00Z"
+        });
+        let predicate: CodeReviewPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(predicate.independent_approvals().count(), 1);
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+    }
+}
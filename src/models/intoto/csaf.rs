@@ -0,0 +1,402 @@
+//! CSAF 2.0 VEX predicate model and associated structures.
+//!
+//! Vendors publish the exploitability of vulnerabilities in their products as CSAF documents of
+//! the `csaf_vex` category, which are attested as the predicate with the products as subjects:
+//!
+//! ```json
+//! {
+//!   "document": {
+//!     "category": "csaf_vex",
+//!     "csaf_version": "2.0",
+//!     "publisher": {"category": "vendor", "name": "Example", "namespace": "https://example.com"},
+//!     "title": "Example VEX",
+//!     "tracking": {
+//!       "id": "EX-2024-0001",
+//!       "status": "final",
+//!       "version": "1",
+//!       "initial_release_date": "2024-05-01T12:00:00Z",
+//!       "current_release_date": "2024-05-01T12:00:00Z",
+//!       "revision_history": [{"date": "2024-05-01T12:00:00Z", "number": "1", "summary": "Initial"}]
+//!     }
+//!   },
+//!   "product_tree": {"full_product_names": [{"name": "Example 1.2.3", "product_id": "EX-123"}]},
+//!   "vulnerabilities": [{
+//!     "cve": "CVE-2024-3094",
+//!     "product_status": {"known_not_affected": ["EX-123"]},
+//!     "flags": [{"label": "vulnerable_code_not_present", "product_ids": ["EX-123"]}]
+//!   }]
+//! }
+//! ```
+//!
+//! Only the parts of a document a VEX statement is made of are modelled: the document metadata
+//! and tracking, the product tree and the vulnerabilities with their product status, flags,
+//! threats and remediations. Other fields, e.g. notes, references or scores, are accepted but
+//! not kept.
+//!
+//! See: https://docs.oasis-open.org/csaf/csaf/v2.0/os/csaf-v2.0-os.html
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The version of CSAF modelled.
+pub const CSAF_VERSION: &str = "2.0";
+
+/// The document category of the CSAF VEX profile.
+pub const CSAF_VEX_CATEGORY: &str = "csaf_vex";
+
+/// A struct representing a CSAF document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct CsafDocument {
+    pub document: DocumentMetadata,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_tree: Option<ProductTree>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+/// The metadata of a CSAF document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct DocumentMetadata {
+    /// The profile of the document, `csaf_vex` for VEX documents.
+    pub category: String,
+    /// The version of CSAF the document conforms to, `2.0`.
+    pub csaf_version: String,
+    pub publisher: Publisher,
+    pub title: String,
+    pub tracking: Tracking,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub references: Option<Value>,
+}
+
+/// The organization that published the document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Publisher {
+    /// The kind of publisher, e.g. `vendor` or `coordinator`.
+    pub category: String,
+    pub name: String,
+    /// The URL of the publisher's namespace, which with the tracking id identifies the document.
+    pub namespace: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_details: Option<String>,
+}
+
+/// The identity and release history of a document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Tracking {
+    /// The id of the document, unique within the publisher's namespace.
+    pub id: String,
+    pub status: TrackingStatus,
+    /// The version of the document, which is the number of its latest revision.
+    pub version: String,
+    pub initial_release_date: DateTime<Utc>,
+    pub current_release_date: DateTime<Utc>,
+    pub revision_history: Vec<Revision>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generator: Option<Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+/// The status of a document.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackingStatus {
+    Draft,
+    Interim,
+    Final,
+}
+
+/// A revision of a document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Revision {
+    pub date: DateTime<Utc>,
+    pub number: String,
+    pub summary: String,
+}
+
+/// The products a document is about, which the vulnerabilities refer to by product id.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ProductTree {
+    /// The products, grouped by vendor, product name and version.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<Branch>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub full_product_names: Vec<FullProductName>,
+    /// Products made of other products, e.g. a component installed on a platform.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<Relationship>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_groups: Vec<ProductGroup>,
+}
+
+/// A node of the product tree, e.g. a vendor, product name or version.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Branch {
+    /// The kind of node, e.g. `vendor`, `product_name` or `product_version`.
+    pub category: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<Branch>,
+    /// The product at a leaf of the tree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product: Option<FullProductName>,
+}
+
+/// A product, with the id the rest of the document refers to it by.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct FullProductName {
+    pub name: String,
+    pub product_id: String,
+    /// Identifiers of the product outside of the document, e.g. its package URL or CPE.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_identification_helper: Option<Value>,
+}
+
+/// A product made of two other products.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Relationship {
+    /// How the products relate, e.g. `default_component_of` or `installed_on`.
+    pub category: String,
+    pub full_product_name: FullProductName,
+    pub product_reference: String,
+    pub relates_to_product_reference: String,
+}
+
+/// A group of products, that flags, threats and remediations can refer to at once.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ProductGroup {
+    pub group_id: String,
+    pub product_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+/// A vulnerability and how it affects the products.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Vulnerability {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cve: Option<String>,
+    /// Ids of the vulnerability in other systems, e.g. GHSA ids.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ids: Vec<VulnerabilityId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_status: Option<ProductStatus>,
+    /// Machine-readable justifications of why products are not affected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<Flag>,
+    /// Free-form statements, including the impact statements of products that are not affected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub threats: Vec<Threat>,
+    /// What to do about affected products.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remediations: Vec<Remediation>,
+}
+
+/// An id of a vulnerability in a tracking system.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct VulnerabilityId {
+    pub system_name: String,
+    pub text: String,
+}
+
+/// The products in each status towards a vulnerability, by product id.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ProductStatus {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub first_affected: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub first_fixed: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixed: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub known_affected: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub known_not_affected: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub last_affected: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recommended: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub under_investigation: Vec<String>,
+}
+
+/// A justification of why products are not affected.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Flag {
+    pub label: FlagLabel,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_ids: Vec<String>,
+}
+
+/// The justifications of the VEX spec for a product not being affected.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FlagLabel {
+    ComponentNotPresent,
+    InlineMitigationsAlreadyExist,
+    VulnerableCodeCannotBeControlledByAdversary,
+    VulnerableCodeNotInExecutePath,
+    VulnerableCodeNotPresent,
+}
+
+/// A statement about the vulnerability's threat to products.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Threat {
+    /// The kind of statement, e.g. `impact` or `exploit_status`.
+    pub category: String,
+    pub details: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_ids: Vec<String>,
+}
+
+/// A remediation of the vulnerability in products.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Remediation {
+    /// The kind of remediation, e.g. `vendor_fix`, `workaround` or `none_available`.
+    pub category: String,
+    pub details: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_ids: Vec<String>,
+}
+
+impl ProductTree {
+    /// Returns the ids of the products the tree defines, with the JSON pointer of each
+    /// definition relative to the tree, in document order.
+    pub fn product_ids(&self) -> Vec<(&str, String)> {
+        fn walk<'a>(branches: &'a [Branch], path: &str, ids: &mut Vec<(&'a str, String)>) {
+            for (i, branch) in branches.iter().enumerate() {
+                let path = format!("{}/branches/{}", path, i);
+                if let Some(product) = &branch.product {
+                    ids.push((
+                        product.product_id.as_str(),
+                        format!("{}/product/product_id", path),
+                    ));
+                }
+                walk(&branch.branches, &path, ids);
+            }
+        }
+
+        let mut ids = Vec::new();
+        walk(&self.branches, "", &mut ids);
+        for (i, product) in self.full_product_names.iter().enumerate() {
+            ids.push((
+                product.product_id.as_str(),
+                format!("/full_product_names/{}/product_id", i),
+            ));
+        }
+        for (i, relationship) in self.relationships.iter().enumerate() {
+            ids.push((
+                relationship.full_product_name.product_id.as_str(),
+                format!("/relationships/{}/full_product_name/product_id", i),
+            ));
+        }
+        ids
+    }
+}
+
+impl ProductStatus {
+    /// Returns the product ids of each status, by the name of its field.
+    pub fn statuses(&self) -> [(&'static str, &[String]); 8] {
+        [
+            ("first_affected", &self.first_affected),
+            ("first_fixed", &self.first_fixed),
+            ("fixed", &self.fixed),
+            ("known_affected", &self.known_affected),
+            ("known_not_affected", &self.known_not_affected),
+            ("last_affected", &self.last_affected),
+            ("recommended", &self.recommended),
+            ("under_investigation", &self.under_investigation),
+        ]
+    }
+}
+
+impl Vulnerability {
+    /// Returns true if the vulnerability has a CVE or another id.
+    pub fn is_identified(&self) -> bool {
+        self.cve.is_some() || !self.ids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_csaf_document() {
+        let value = json!({
+            "document": {
+                "category": "csaf_vex",
+                "csaf_version": "2.0",
+                "publisher": {"category": "vendor", "name": "Example", "namespace": "https://example.com"},
+                "title": "Example VEX",
+                "tracking": {
+                    "id": "EX-2024-0001",
+                    "status": "final",
+                    "version": "1",
+                    "initial_release_date": "2024-05-01T12:00:00Z",
+                    "current_release_date": "2024-05-01T12:00:00Z",
+                    "revision_history": [{"date": "2024-05-01T12:00:00Z", "number": "1", "summary": "Initial"}]
+                }
+            },
+            "product_tree": {
+                "branches": [{
+                    "category": "vendor",
+                    "name": "Example",
+                    "branches": [{
+                        "category": "product_version",
+                        "name": "1.2.3",
+                        "product": {"name": "Example 1.2.3", "product_id": "EX-123"}
+                    }]
+                }],
+                "full_product_names": [{"name": "Example 2.0.0", "product_id": "EX-200"}]
+            },
+            "vulnerabilities": [{
+                "cve": "CVE-2024-3094",
+                "product_status": {"known_not_affected": ["EX-123", "EX-200"]},
+                "flags": [{"label": "vulnerable_code_not_present", "product_ids": ["EX-123", "EX-200"]}]
+            }]
+        });
+        let csaf: CsafDocument = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(csaf.document.tracking.status, TrackingStatus::Final);
+        assert_eq!(
+            csaf.product_tree.as_ref().unwrap().product_ids(),
+            vec![
+                (
+                    "EX-123",
+                    "/branches/0/branches/0/product/product_id".to_string()
+                ),
+                ("EX-200", "/full_product_names/0/product_id".to_string())
+            ]
+        );
+        assert_eq!(serde_json::to_value(&csaf).unwrap(), value);
+
+        let mut unknown = value;
+        unknown["vulnerabilities"][0]["flags"][0]["label"] = json!("not_affected");
+        assert!(serde_json::from_value::<CsafDocument>(unknown).is_err());
+    }
+}
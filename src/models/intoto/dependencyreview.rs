@@ -0,0 +1,139 @@
+//! Dependency review predicate model and associated structures.
+//!
+//! CI gates such as GitHub's dependency review action compare the dependencies of a change with
+//! those of its base, and fail it if it adds vulnerable packages. A dependency review attestation
+//! records the packages the change added and removed, by their purls, with the advisories known
+//! against them:
+//!
+//! ```json
+//! {
+//!   "tool": {"name": "dependency-review-action", "version": "4.3.2"},
+//!   "base": {"uri": "git+https://github.com/octocat/hello-world", "digest": {"gitCommit": "c27d339ee6075c1f744c5d4b200f7901aad2c369"}},
+//!   "head": {"uri": "git+https://github.com/octocat/hello-world", "digest": {"gitCommit": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d"}},
+//!   "changes": [
+//!     {"changeType": "removed", "purl": "pkg:npm/lodash@4.17.20", "manifest": "package-lock.json"},
+//!     {
+//!       "changeType": "added",
+//!       "purl": "pkg:npm/lodash@4.17.21",
+//!       "manifest": "package-lock.json",
+//!       "scope": "runtime",
+//!       "advisories": []
+//!     }
+//!   ]
+//! }
+//! ```
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::models::intoto::codereview::ReviewTool;
+use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+use crate::models::intoto::uri::UriReference;
+
+/// A struct representing the Dependency Review v0.1 Predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct DependencyReviewPredicate {
+    /// The tool that reviewed the dependencies.
+    pub tool: ReviewTool,
+    /// The revision the dependencies were compared against, e.g. the base of a pull request.
+    pub base: ResourceDescriptor,
+    /// The revision whose dependencies were reviewed.
+    pub head: ResourceDescriptor,
+    /// The packages added and removed between the base and the head. Empty if the dependencies
+    /// didn't change.
+    pub changes: Vec<DependencyChange>,
+}
+
+/// A package added or removed by the change.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyChange {
+    pub change_type: ChangeType,
+    /// The purl of the package, with its version, e.g. `pkg:npm/lodash@4.17.21`.
+    pub purl: String,
+    /// The path of the manifest or lockfile declaring the package.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<String>,
+    /// The scope of the dependency, e.g. `runtime` or `development`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// The SPDX license expression of the package.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    /// The advisories known against the package when it was reviewed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub advisories: Vec<Advisory>,
+}
+
+/// Whether a package was added or removed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeType {
+    Added,
+    Removed,
+}
+
+/// A security advisory against a package.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Advisory {
+    /// The id of the advisory, e.g. `GHSA-35jh-r3h4-6jhm` or `CVE-2021-23337`.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<AdvisorySeverity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<UriReference>,
+}
+
+/// The severity of an advisory, as GitHub rates them.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AdvisorySeverity {
+    Low,
+    Moderate,
+    High,
+    Critical,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_dependency_review_predicate() {
+        let value = json!({
+            "tool": {"name": "dependency-review-action", "version": "4.3.2"},
+            "base": {"uri": "git+https://github.com/octocat/hello-world", "digest": {"gitCommit": "c27d339ee6075c1f744c5d4b200f7901aad2c369"}},
+            "head": {"uri": "git+https://github.com/octocat/hello-world", "digest": {"gitCommit": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d"}},
+            "changes": [
+                {"changeType": "removed", "purl": "pkg:npm/lodash@4.17.21"},
+                {
+                    "changeType": "added",
+                    "purl": "pkg:npm/lodash@4.17.20",
+                    "manifest": "package-lock.json",
+                    "scope": "runtime",
+                    "license": "MIT",
+                    "advisories": [{
+                        "id": "GHSA-35jh-r3h4-6jhm",
+                        "severity": "high",
+                        "summary": "Command Injection in lodash",
+                        "url": "https://github.com/advisories/GHSA-35jh-r3h4-6jhm"
+                    }]
+                }
+            ]
+        });
+        let predicate: DependencyReviewPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(predicate.changes[1].change_type, ChangeType::Added);
+        assert_eq!(
+            predicate.changes[1].advisories[0].severity,
+            Some(AdvisorySeverity::High)
+        );
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+
+        let mut unknown = value;
+        unknown["changes"][0]["changeType"] = json!("updated");
+        assert!(serde_json::from_value::<DependencyReviewPredicate>(unknown).is_err());
+    }
+}
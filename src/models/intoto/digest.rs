@@ -0,0 +1,127 @@
+//! Helpers for digest values in In-Toto statements and their predicates.
+//!
+//! Digests are compared byte-for-byte further down the pipeline, so values that differ only in
+//! case or surrounding whitespace are a common source of spurious mismatches.
+
+use serde_json::Value;
+
+use crate::validate::report::{join_pointer, Finding, ValidationReport};
+
+/// Returns the normalized form of a digest value if it differs from the input.
+///
+/// Surrounding whitespace is trimmed, and hex values are lowercased. Values that aren't hex,
+/// such as base64-encoded digests, are only trimmed since their case is significant.
+pub fn normalize_digest_value(digest: &str) -> Option<String> {
+    let trimmed = digest.trim();
+    let normalized = if !trimmed.is_empty() && trimmed.bytes().all(|b| b.is_ascii_hexdigit()) {
+        trimmed.to_ascii_lowercase()
+    } else {
+        trimmed.to_string()
+    };
+
+    if normalized == digest {
+        None
+    } else {
+        Some(normalized)
+    }
+}
+
+/// Normalizes the values of every `digest` object found in the given JSON value, recursively.
+///
+/// A warning is added to the report for every value that was changed. `path` is the JSON
+/// pointer of `value` within the document.
+pub fn normalize_digests(value: &mut Value, path: &str, report: &mut ValidationReport) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                let child_path = join_pointer(path, key);
+                if key == "digest" {
+                    if let Value::Object(digests) = child {
+                        normalize_digest_set(digests, &child_path, report);
+                        continue;
+                    }
+                }
+                normalize_digests(child, &child_path, report);
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter_mut().enumerate() {
+                normalize_digests(child, &join_pointer(path, &i.to_string()), report);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn normalize_digest_set(
+    digests: &mut serde_json::Map<String, Value>,
+    path: &str,
+    report: &mut ValidationReport,
+) {
+    for (algorithm, digest) in digests.iter_mut() {
+        if let Value::String(digest) = digest {
+            if let Some(normalized) = normalize_digest_value(digest) {
+                report.push(Finding::warning(
+                    "normalized-digest",
+                    join_pointer(path, algorithm),
+                    format!("digest {:?} was normalized to {:?}", digest, normalized),
+                ));
+                *digest = normalized;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_normalize_digest_value() {
+        assert_eq!(
+            normalize_digest_value(" ABCD1234\n"),
+            Some("abcd1234".to_string())
+        );
+        assert_eq!(normalize_digest_value("abcd1234"), None);
+        assert_eq!(
+            normalize_digest_value(" h1:AbC+/= "),
+            Some("h1:AbC+/=".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_digests() {
+        let mut value = json!({
+            "subject": [
+                {"name": "example", "digest": {"sha256": "ABCD1234"}}
+            ],
+            "predicate": {
+                "materials": [
+                    {"uri": "https://example.com", "digest": {"sha1": "abcd1234 "}},
+                    {"uri": "https://example.com/other", "digest": {"sha1": "abcd1234"}}
+                ]
+            }
+        });
+
+        let mut report = ValidationReport::new();
+        normalize_digests(&mut value, "", &mut report);
+
+        assert_eq!(value["subject"][0]["digest"]["sha256"], "abcd1234");
+        assert_eq!(
+            value["predicate"]["materials"][0]["digest"]["sha1"],
+            "abcd1234"
+        );
+        let paths = report
+            .warnings()
+            .map(|f| f.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/predicate/materials/0/digest/sha1",
+                "/subject/0/digest/sha256"
+            ]
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! License scan predicate model and associated structures.
+//!
+//! A license scanner, e.g. ScanCode or Trivy, attests the licenses it detected in a target, such
+//! as a source tree or a container image, by their SPDX identifiers:
+//!
+//! ```json
+//! {
+//!   "scanner": {"name": "scancode-toolkit", "version": "32.2.0", "licenseListVersion": "3.25"},
+//!   "target": {"uri": "git+https://github.com/octocat/hello-world@refs/heads/main", "digest": {"gitCommit": "c27d339ee6075c1f744c5d4b200f7901aad2c369"}},
+//!   "timeScanned": "2024-05-01T12:00:00Z",
+//!   "licenses": [
+//!     {"spdxId": "Apache-2.0", "files": ["LICENSE", "src/main.rs"]},
+//!     {"spdxId": "LicenseRef-scancode-proprietary", "name": "Proprietary", "files": ["vendor/blob.c"]}
+//!   ]
+//! }
+//! ```
+//!
+//! Licenses that aren't on the SPDX License List are named by `LicenseRef-` identifiers.
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+use crate::models::intoto::uri::UriReference;
+
+/// A struct representing the License Scan v0.1 Predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LicenseScanPredicate {
+    pub scanner: Scanner,
+    /// What was scanned, e.g. the source tree the subjects were built from.
+    pub target: ResourceDescriptor,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_scanned: Option<DateTime<Utc>>,
+    /// The licenses detected in the target. Empty if none were found.
+    pub licenses: Vec<DetectedLicense>,
+}
+
+/// The license scanner that ran.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Scanner {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<UriReference>,
+    /// The version of the SPDX License List the scanner's identifiers are from, e.g. `3.25`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license_list_version: Option<String>,
+}
+
+/// A license detected in the target.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedLicense {
+    /// The SPDX short identifier of the license, e.g. `Apache-2.0`, or a `LicenseRef-`
+    /// identifier for licenses that aren't on the SPDX License List.
+    pub spdx_id: String,
+    /// The name of the license, for `LicenseRef-` identifiers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The paths in the target the license was detected in.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_license_scan_predicate() {
+        let value = json!({
+            "scanner": {"name": "scancode-toolkit", "version": "32.2.0", "licenseListVersion": "3.25"},
+            "target": {
+                "uri": "git+https://github.com/octocat/hello-world@refs/heads/main",
+                "digest": {"gitCommit": "c27d339ee6075c1f744c5d4b200f7901aad2c369"}
+            },
+            "timeScanned": "2024-05-01T12:00:00Z",
+            "licenses": [
+                {"spdxId": "Apache-2.0", "files": ["LICENSE"]},
+                {"spdxId": "LicenseRef-scancode-proprietary", "name": "Proprietary"}
+            ]
+        });
+        let predicate: LicenseScanPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(predicate.licenses[0].spdx_id, "Apache-2.0");
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+
+        let mut missing = value;
+        missing.as_object_mut().unwrap().remove("scanner");
+        assert!(serde_json::from_value::<LicenseScanPredicate>(missing).is_err());
+    }
+}
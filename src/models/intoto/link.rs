@@ -0,0 +1,93 @@
+//! In-Toto Link predicate model and associated structures.
+//!
+//! Links are the classic in-toto metadata recorded by `in-toto-run` for a step of a supply chain:
+//! the command that was run, the digests of the files it read and wrote, and what it printed.
+//! Pipelines that predate the attestation framework still emit them, wrapped in v1 statements:
+//!
+//! ```json
+//! {
+//!   "name": "build",
+//!   "command": ["make", "release"],
+//!   "materials": {"src/main.c": {"sha256": "..."}},
+//!   "products": {"hello-world": {"sha256": "..."}},
+//!   "byproducts": {"stdout": "", "stderr": "", "return-value": 0},
+//!   "environment": {}
+//! }
+//! ```
+//!
+//! See: https://github.com/in-toto/docs/blob/master/in-toto-spec.md#44-file-formats-namekeyid-prefixlink
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::models::intoto::statement::DigestSet;
+
+/// A struct representing the In-Toto Link predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct LinkPredicate {
+    /// The name of the step the link was recorded for.
+    pub name: String,
+    /// The command that was run, as its arguments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command: Vec<String>,
+    /// The digests of the files the step read, by path.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub materials: BTreeMap<String, DigestSet>,
+    /// The digests of the files the step wrote, by path.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub products: BTreeMap<String, DigestSet>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub byproducts: Option<Byproducts>,
+    /// Information about the environment the step ran in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// What the command printed and returned.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Byproducts {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+    #[serde(
+        rename = "return-value",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub return_value: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::intoto::statement::Algorithm;
+    use serde_json::json;
+
+    #[test]
+    fn test_link_predicate() {
+        let value = json!({
+            "name": "build",
+            "command": ["make", "release"],
+            "materials": {
+                "src/main.c": {"sha256": "a7f2b6f2d4d0c6f6bbd0ac66b38c3c91ad8ab3e6d0a0b5a1fd42ef2d0b3a4f1c"}
+            },
+            "products": {
+                "hello-world": {"sha256": "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c"}
+            },
+            "byproducts": {"stdout": "", "stderr": "", "return-value": 0},
+            "environment": {"workdir": "/src"}
+        });
+        let predicate: LinkPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(predicate.command, vec!["make", "release"]);
+        assert_eq!(
+            predicate.products["hello-world"]
+                .get(&Algorithm::Sha256)
+                .map(String::as_str),
+            Some("b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c")
+        );
+        assert_eq!(predicate.byproducts.as_ref().unwrap().return_value, Some(0));
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+    }
+}
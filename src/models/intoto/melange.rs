@@ -0,0 +1,155 @@
+//! melange build predicate model and associated structures.
+//!
+//! melange builds APK packages from a declarative pipeline. Its build attestations record the
+//! configuration the packages were built from, the packages that were built and the SBOMs
+//! generated for them:
+//!
+//! ```json
+//! {
+//!   "config": {
+//!     "package": {"name": "hello", "version": "2.12", "epoch": 1},
+//!     "environment": {"contents": {"packages": ["build-base"]}},
+//!     "pipeline": [{"uses": "autoconf/make"}]
+//!   },
+//!   "packages": [{"name": "hello", "version": "2.12-r1", "arch": "x86_64"}],
+//!   "sboms": [{"uri": "hello-2.12-r1.spdx.json", "digest": {"sha256": "..."}}]
+//! }
+//! ```
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::apko::{ApkContents, ApkPackage};
+use super::resource_descriptor::ResourceDescriptor;
+
+/// A struct representing the melange build predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct MelangeBuildPredicate {
+    /// The build configuration the packages were built from.
+    pub config: MelangeConfiguration,
+    /// The packages that were built, the main package and its subpackages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<ApkPackage>,
+    /// The SBOMs generated for the packages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sboms: Vec<ResourceDescriptor>,
+}
+
+/// A melange build configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct MelangeConfiguration {
+    pub package: MelangePackage,
+    /// The build environment, which is assembled like an apko image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<BuildEnvironment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pipeline: Vec<PipelineStep>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subpackages: Vec<Subpackage>,
+}
+
+/// The package a melange configuration builds.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct MelangePackage {
+    pub name: String,
+    pub version: String,
+    /// The number of the build of the version, the `-r` suffix of the full version.
+    pub epoch: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub copyright: Vec<Copyright>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Copyright {
+    /// An SPDX license expression.
+    pub license: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct BuildEnvironment {
+    #[serde(default)]
+    pub contents: ApkContents,
+}
+
+/// A step of a melange pipeline, which either uses a built-in pipeline or runs a script.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct PipelineStep {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The built-in pipeline, e.g. `git-checkout` or `autoconf/make`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uses: Option<String>,
+    /// The inputs of the built-in pipeline.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub with: BTreeMap<String, Value>,
+    /// The script the step runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Subpackage {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl MelangeConfiguration {
+    /// Returns the full version of the package, e.g. `2.12-r1`.
+    pub fn full_version(&self) -> String {
+        format!("{}-r{}", self.package.version, self.package.epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_melange_build_predicate() {
+        let value = json!({
+            "config": {
+                "package": {
+                    "name": "hello",
+                    "version": "2.12",
+                    "epoch": 1,
+                    "copyright": [{"license": "GPL-3.0-or-later"}]
+                },
+                "environment": {
+                    "contents": {
+                        "repositories": ["https://packages.wolfi.dev/os"],
+                        "packages": ["build-base"]
+                    }
+                },
+                "pipeline": [
+                    {
+                        "uses": "fetch",
+                        "with": {
+                            "uri": "https://ftp.gnu.org/gnu/hello/hello-2.12.tar.gz",
+                            "expected-sha256": "cf04af86dc085268c5f4470fbae49b18afbc221b78096aab842d934a76bad0ab"
+                        }
+                    },
+                    {"uses": "autoconf/make"},
+                    {"name": "strip", "runs": "strip ${{targets.destdir}}/usr/bin/hello"}
+                ],
+                "subpackages": [{"name": "hello-doc"}]
+            },
+            "packages": [
+                {"name": "hello", "version": "2.12-r1", "arch": "x86_64"},
+                {"name": "hello-doc", "version": "2.12-r1", "arch": "x86_64"}
+            ],
+            "sboms": [{"uri": "hello-2.12-r1.spdx.json", "digest": {"sha256": "aa"}}]
+        });
+        let predicate: MelangeBuildPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(predicate.config.full_version(), "2.12-r1");
+        assert_eq!(predicate.config.pipeline.len(), 3);
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+    }
+}
@@ -1,8 +1,27 @@
+pub mod apko;
+pub mod buildinfo;
+pub mod buildtypes;
+pub mod codereview;
+pub mod csaf;
+pub mod dependencyreview;
+pub mod digest;
+pub mod licensescan;
+pub mod link;
+pub mod melange;
+pub mod options;
 pub mod predicate;
-pub mod provenancev1;
 pub mod provenancev02;
-pub mod statement;
+pub mod provenancev1;
+pub mod reference;
+pub mod registry;
+pub mod resource_descriptor;
+pub mod sarif;
 pub mod scai;
+pub mod spdx;
+pub mod statement;
+pub mod testresult;
+pub mod uri;
+pub mod vsa;
 
 // NOTE(mlieberman85): Many of the models include additional schemars attributes, e.g. "with".
 // See: https://github.com/GREsau/schemars/issues/89 for more info.
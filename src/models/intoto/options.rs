@@ -0,0 +1,66 @@
+//! Options for controlling In-Toto statement deserialization.
+//!
+//! The plain `Deserialize` implementations use `ParseOptions::default()`, which follows the
+//! attestation spec as leniently as possible. Callers that want to reject documents the spec
+//! merely tolerates can opt into stricter behavior here.
+
+/// Options controlling how strictly In-Toto statements are deserialized.
+///
+/// New options are added with a default that keeps the current behavior, so start from
+/// `ParseOptions::default()` or `ParseOptions::strict()` and set the fields to change.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Rejects statements whose predicate is null or missing, even for predicate types where
+    /// the spec permits an empty predicate.
+    pub strict: bool,
+    /// How the predicateType URL is matched against the known predicate types.
+    pub predicate_type_matching: PredicateTypeMatching,
+    /// Lowercases hex digest values and trims surrounding whitespace from all digests before
+    /// deserializing, with a warning for each value that was changed.
+    pub normalize_digests: bool,
+    /// Which statement `_type` URIs are accepted.
+    pub statement_type: StatementTypeCheck,
+}
+
+impl ParseOptions {
+    /// Returns options with strict mode enabled, exact predicateType matching and only the
+    /// v1 statement `_type` accepted.
+    pub fn strict() -> Self {
+        Self {
+            strict: true,
+            predicate_type_matching: PredicateTypeMatching::Exact,
+            statement_type: StatementTypeCheck::V1,
+            ..Self::default()
+        }
+    }
+}
+
+/// How predicateType URLs are matched against the known predicate types.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PredicateTypeMatching {
+    /// Only the canonical URL of a predicate type is recognized.
+    Exact,
+    /// Non-canonical forms such as a trailing slash or a `.0` version suffix (e.g.
+    /// `https://slsa.dev/provenance/v1.0/`) are recognized, with a warning.
+    Normalized,
+    /// Like `Normalized`, and other versions of a known predicate type are recognized as the
+    /// type whose model implements the highest compatible version, with a warning (e.g.
+    /// `https://slsa.dev/provenance/v1.1` as SLSA Provenance v1).
+    #[default]
+    Compatible,
+}
+
+/// Which statement `_type` URIs are accepted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StatementTypeCheck {
+    /// Any URL is accepted.
+    #[default]
+    Any,
+    /// Only the In-Toto v1 statement URI is accepted.
+    V1,
+    /// The In-Toto v1 and v0.1 statement URIs are accepted.
+    V1OrV01,
+}
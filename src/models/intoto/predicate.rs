@@ -2,29 +2,139 @@
 //!
 //! This module provides an enum `Predicate` and a custom deserialization function
 //! to handle different predicate types, including known types such as `SLSAProvenanceV1`
-//! and generic `Other` variants.
+//! and generic `Other` variants, as well as the `Empty` variant for predicates that were
+//! null or omitted.
+//!
+//! `Predicate` is non-exhaustive: supporting a new predicate type adds a variant, and predicates
+//! of that type that used to deserialize as `Other` get it instead.
 
-use super::provenancev1::SLSAProvenanceV1Predicate;
+use super::apko::ApkoBuildPredicate;
+use super::buildinfo::JvmBuildInfoPredicate;
+use super::codereview::CodeReviewPredicate;
+use super::csaf::CsafDocument;
+use super::dependencyreview::DependencyReviewPredicate;
+use super::licensescan::LicenseScanPredicate;
+use super::link::LinkPredicate;
+use super::melange::MelangeBuildPredicate;
+use super::options::{ParseOptions, PredicateTypeMatching};
 use super::provenancev02::SLSAProvenanceV02Predicate;
-use super::scai::SCAIV02Predicate;
-use schemars::JsonSchema;
+use super::provenancev1::SLSAProvenanceV1Predicate;
+use super::reference::ReferencePredicate;
+use super::registry::{PredicateRegistry, RegisteredPredicate};
+use super::sarif::SarifLog;
+use super::scai::{SCAIV02Predicate, SCAIV03Predicate};
+use super::spdx::SpdxDocumentPredicate;
+use super::statement::InTotoStatementV1;
+use super::testresult::TestResultPredicate;
+use super::vsa::VerificationSummaryV1Predicate;
+use crate::models::sbom::cyclonedx::CycloneDxBom;
+use crate::validate::report::{Finding, ValidationReport};
+use schemars::{schema_for, JsonSchema};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
+/// The canonical predicateType URL for SLSA Provenance v1.
+pub const SLSA_PROVENANCE_V1: &str = "https://slsa.dev/provenance/v1";
+/// The canonical predicateType URL for SLSA Provenance v0.2.
+pub const SLSA_PROVENANCE_V02: &str = "https://slsa.dev/provenance/v0.2";
+/// The canonical predicateType URL for SCAI attribute reports.
+pub const SCAI_ATTRIBUTE_REPORT: &str = "https://in-toto.io/attestation/scai/attribute-report";
+/// The canonical predicateType URL for SCAI v0.3 attribute reports.
+pub const SCAI_V03: &str = "https://in-toto.io/attestation/scai/v0.3";
+/// The canonical predicateType URL for apko image build attestations.
+pub const APKO_BUILD_V1: &str = "https://apko.dev/build/v1";
+/// The canonical predicateType URL for melange package build attestations.
+pub const MELANGE_BUILD_V1: &str = "https://melange.dev/build/v1";
+/// The canonical predicateType URL for JVM build-info attestations from Gradle and Maven.
+pub const JVM_BUILD_INFO_V1: &str = "https://jfrog.com/build-info/v1";
+/// The canonical predicateType URL for SLSA Verification Summary Attestations (VSAs) v1.
+pub const SLSA_VERIFICATION_SUMMARY_V1: &str = "https://slsa.dev/verification_summary/v1";
+/// The canonical predicateType URL for classic In-Toto link metadata.
+pub const IN_TOTO_LINK_V1: &str = "https://in-toto.io/Link/v1";
+/// The canonical predicateType URL for SPDX SBOM attestations.
+pub const SPDX_DOCUMENT: &str = "https://spdx.dev/Document";
+/// The canonical predicateType URL for CycloneDX SBOM attestations.
+pub const CYCLONEDX_BOM: &str = "https://cyclonedx.org/bom";
+/// The canonical predicateType URL for In-Toto test result attestations v0.1.
+pub const IN_TOTO_TEST_RESULT_V01: &str = "https://in-toto.io/attestation/test-result/v0.1";
+/// The canonical predicateType URL for SARIF 2.1 scan result attestations.
+pub const SARIF_V21: &str = "https://docs.oasis-open.org/sarif/sarif/v2.1";
+/// The canonical predicateType URL for CSAF 2.0 VEX attestations.
+pub const CSAF_V2: &str = "https://docs.oasis-open.org/csaf/csaf/v2";
+/// The canonical predicateType URL for references to externally stored documents, e.g. SBOMs.
+pub const REFERENCE_V01: &str = "https://in-toto.io/attestation/reference/v0.1";
+/// The canonical predicateType URL for license scan results.
+pub const LICENSE_SCAN_V01: &str = "https://kusari.dev/attestation/license-scan/v0.1";
+/// The canonical predicateType URL for code review attestations.
+pub const CODE_REVIEW_V01: &str = "https://kusari.dev/attestation/code-review/v0.1";
+/// The canonical predicateType URL for dependency review results.
+pub const DEPENDENCY_REVIEW_V01: &str = "https://kusari.dev/attestation/dependency-review/v0.1";
+
+/// The canonical predicateType URLs of all known predicate types.
+pub const KNOWN_PREDICATE_TYPES: &[&str] = &[
+    SLSA_PROVENANCE_V1,
+    SLSA_PROVENANCE_V02,
+    SCAI_ATTRIBUTE_REPORT,
+    SCAI_V03,
+    APKO_BUILD_V1,
+    MELANGE_BUILD_V1,
+    JVM_BUILD_INFO_V1,
+    SLSA_VERIFICATION_SUMMARY_V1,
+    IN_TOTO_LINK_V1,
+    SPDX_DOCUMENT,
+    CYCLONEDX_BOM,
+    IN_TOTO_TEST_RESULT_V01,
+    SARIF_V21,
+    CSAF_V2,
+    REFERENCE_V01,
+    LICENSE_SCAN_V01,
+    CODE_REVIEW_V01,
+    DEPENDENCY_REVIEW_V01,
+];
+
 /// An enum representing different predicate types.
 ///
 /// Known predicate types have their own variants, while unknown types are represented
-/// by the `Other` variant, which stores the raw JSON value.
+/// by the `Other` variant, which stores the raw JSON value. The attestation spec allows
+/// the predicate to be unset when the predicateType fully describes it; this is represented
+/// by the `Empty` variant.
 ///
 /// TODO(mlieberman85): Support (de)serializing the predicates based on the
 /// predicateType URL in the statement.
-#[derive(Debug, Serialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(untagged)]
+#[non_exhaustive]
 pub enum Predicate {
     SLSAProvenanceV1(SLSAProvenanceV1Predicate),
     SLSAProvenanceV02(SLSAProvenanceV02Predicate),
     SCAIV02(SCAIV02Predicate),
+    SCAIV03(SCAIV03Predicate),
+    ApkoBuildV1(ApkoBuildPredicate),
+    MelangeBuildV1(MelangeBuildPredicate),
+    JvmBuildInfoV1(JvmBuildInfoPredicate),
+    VerificationSummaryV1(VerificationSummaryV1Predicate),
+    Link(LinkPredicate),
+    SpdxDocument(SpdxDocumentPredicate),
+    CycloneDX(CycloneDxBom),
+    TestResultV01(TestResultPredicate),
+    SarifV21(SarifLog),
+    CsafV2(CsafDocument),
+    ReferenceV01(ReferencePredicate),
+    LicenseScanV01(LicenseScanPredicate),
+    CodeReviewV01(CodeReviewPredicate),
+    DependencyReviewV01(DependencyReviewPredicate),
+    /// A predicate of a type registered with [`PredicateRegistry`].
+    #[schemars(skip)]
+    Registered(RegisteredPredicate),
     Other(Value),
+    Empty,
+}
+
+impl Predicate {
+    /// Returns true if the predicate was null or omitted.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Predicate::Empty)
+    }
 }
 
 // Helper function to deserialize a JSON value into the specified type `T`.
@@ -32,29 +142,288 @@ fn deserialize_helper<T: DeserializeOwned>(predicate: &Value) -> Result<T, serde
     serde_json::from_value::<T>(predicate.clone())
 }
 
+/// Normalizes a predicateType URL by removing a trailing slash and any trailing `.0`
+/// components from a version path segment, e.g. `https://slsa.dev/provenance/v1.0/`
+/// becomes `https://slsa.dev/provenance/v1`.
+pub fn normalize_predicate_type(predicate_type: &str) -> String {
+    let trimmed = predicate_type.trim_end_matches('/');
+    let (prefix, last_segment) = match trimmed.rsplit_once('/') {
+        Some(parts) => parts,
+        None => return trimmed.to_string(),
+    };
+
+    let is_version = last_segment
+        .strip_prefix('v')
+        .map(|v| {
+            v.split('.')
+                .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .unwrap_or(false);
+    if !is_version {
+        return trimmed.to_string();
+    }
+
+    let mut version = last_segment;
+    while let Some(stripped) = version.strip_suffix(".0") {
+        version = stripped;
+    }
+    format!("{}/{}", prefix, version)
+}
+
+/// Returns the canonical URL of the known predicate type matching `predicate_type`, if any.
+///
+/// With `PredicateTypeMatching::Exact` only the canonical URL itself matches. With
+/// `PredicateTypeMatching::Normalized` the URL is normalized with `normalize_predicate_type`
+/// before comparison, and with `PredicateTypeMatching::Compatible` it is matched by
+/// `compatible_predicate_type`.
+pub fn canonical_predicate_type(
+    predicate_type: &str,
+    matching: PredicateTypeMatching,
+) -> Option<&'static str> {
+    match matching {
+        PredicateTypeMatching::Exact => KNOWN_PREDICATE_TYPES
+            .iter()
+            .find(|known| **known == predicate_type)
+            .copied(),
+        PredicateTypeMatching::Normalized => {
+            let normalized = normalize_predicate_type(predicate_type);
+            KNOWN_PREDICATE_TYPES
+                .iter()
+                .find(|known| **known == normalized)
+                .copied()
+        }
+        PredicateTypeMatching::Compatible => compatible_predicate_type(predicate_type),
+    }
+}
+
+/// Splits a predicateType URL into the URL of the predicate type and the `major.minor` version
+/// of its last path segment, e.g. `https://slsa.dev/provenance/v1.1` into
+/// `https://slsa.dev/provenance` and `(1, 1)`.
+///
+/// URLs without a version segment, like `https://spdx.dev/Document`, are returned whole with no
+/// version. A missing minor version is 0, and patch versions are ignored.
+pub fn split_predicate_type_version(predicate_type: &str) -> (&str, Option<(u64, u64)>) {
+    let trimmed = predicate_type.trim_end_matches('/');
+    let Some((prefix, last_segment)) = trimmed.rsplit_once('/') else {
+        return (trimmed, None);
+    };
+    let version = last_segment.strip_prefix('v').and_then(|version| {
+        let parts = version
+            .split('.')
+            .map(|part| part.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        Some((*parts.first()?, parts.get(1).copied().unwrap_or(0)))
+    });
+    match version {
+        Some(version) => (prefix, Some(version)),
+        None => (trimmed, None),
+    }
+}
+
+// Returns the version of the spec a known predicate type's model implements. It is the version
+// in the canonical URL, except for predicate types whose canonical URL is unversioned but whose
+// model implements a specific version.
+fn model_version(canonical: &str) -> Option<(u64, u64)> {
+    match canonical {
+        SCAI_ATTRIBUTE_REPORT => Some((0, 2)),
+        _ => split_predicate_type_version(canonical).1,
+    }
+}
+
+// Returns true if a model implementing the given version can deserialize predicates of the
+// requested version: versions are compatible within a major version, or within a minor version
+// before 1.0. Unversioned predicate types and models are compatible with any version.
+fn is_compatible_version(requested: Option<(u64, u64)>, model: Option<(u64, u64)>) -> bool {
+    match (requested, model) {
+        (Some((major, minor)), Some((model_major, model_minor))) => {
+            major == model_major && (major > 0 || minor == model_minor)
+        }
+        _ => true,
+    }
+}
+
+/// Returns the canonical URL of the known predicate type whose model can deserialize predicates
+/// of `predicate_type`, matching its URL without the version and comparing versions.
+///
+/// Of the known predicate types with the same URL, those whose model implements a compatible
+/// version are candidates, and the one implementing the highest version is returned. For
+/// example, `https://slsa.dev/provenance/v1.1` matches SLSA Provenance v1, and
+/// `https://in-toto.io/attestation/scai/attribute-report/v0.2` matches SCAI, whose canonical URL
+/// is unversioned but whose model implements v0.2.
+pub fn compatible_predicate_type(predicate_type: &str) -> Option<&'static str> {
+    let (base, requested) = split_predicate_type_version(predicate_type);
+    KNOWN_PREDICATE_TYPES
+        .iter()
+        .copied()
+        .filter(|known| split_predicate_type_version(known).0 == base)
+        .filter(|known| is_compatible_version(requested, model_version(known)))
+        .max_by_key(|known| model_version(known))
+}
+
 /// Deserializes a predicate based on the provided predicate_type.
 ///
 /// If the predicate_type matches a known type, it will deserialize
 /// the predicate to the corresponding struct, otherwise, it will
 /// deserialize the predicate to the generic `Other` variant.
-/// Update the match for any new predicate types.
+/// A null predicate for an unknown type is deserialized to `Empty`, while
+/// known types still require their predicate to be present.
+/// Non-canonical forms of known predicate types are matched, see `canonical_predicate_type`.
 pub fn deserialize_predicate(
     predicate_type: &str,
     predicate_json: &Value,
 ) -> Result<Predicate, serde_json::Error> {
-    match predicate_type {
-        "https://slsa.dev/provenance/v1" => {
+    deserialize_predicate_with_options(
+        predicate_type,
+        predicate_json,
+        &ParseOptions::default(),
+        &mut ValidationReport::new(),
+    )
+}
+
+/// Deserializes a predicate based on the provided predicate_type using the given options.
+///
+/// Predicates of types registered with [`PredicateRegistry::global`] are deserialized into
+/// [`Predicate::Registered`] first, and never compared against the known predicate types.
+///
+/// A warning is added to the report when a known predicate type is matched through a
+/// non-canonical URL. When the predicate doesn't match its predicateType but looks like
+/// another known predicate type, a `predicate-type-mismatch` finding suggesting the correct
+/// predicateType is added to the report.
+pub fn deserialize_predicate_with_options(
+    predicate_type: &str,
+    predicate_json: &Value,
+    options: &ParseOptions,
+    report: &mut ValidationReport,
+) -> Result<Predicate, serde_json::Error> {
+    let canonical = canonical_predicate_type(predicate_type, options.predicate_type_matching);
+    if let Some(canonical) = canonical {
+        if canonical != predicate_type {
+            report.push(Finding::warning(
+                "non-canonical-predicate-type",
+                "/predicateType",
+                format!(
+                    "predicateType {} is not canonical, use {} instead",
+                    predicate_type, canonical
+                ),
+            ));
+        }
+    }
+
+    if let Some(result) = PredicateRegistry::global().deserialize(predicate_type, predicate_json) {
+        return result;
+    }
+
+    let result = dispatch_predicate(canonical, predicate_json);
+    let detected = detect_predicate_type(predicate_json).filter(|d| Some(*d) != canonical);
+    match (detected, canonical, result) {
+        (Some(detected), Some(_), Err(err)) => {
+            let message = format!(
+                "predicate looks like {} but predicateType is {}, did you mean predicateType {}?",
+                detected, predicate_type, detected
+            );
+            report.push(Finding::error(
+                "predicate-type-mismatch",
+                "/predicateType",
+                message.clone(),
+            ));
+            Err(serde::de::Error::custom(format!("{} ({})", message, err)))
+        }
+        (Some(detected), None, Ok(predicate)) => {
+            report.push(Finding::warning(
+                "predicate-type-mismatch",
+                "/predicateType",
+                format!(
+                    "predicate looks like {} but predicateType is the unknown type {}",
+                    detected, predicate_type
+                ),
+            ));
+            Ok(predicate)
+        }
+        (_, _, result) => result,
+    }
+}
+
+// Deserializes the predicate into the model for the given canonical predicate type.
+// Update the match for any new predicate types.
+fn dispatch_predicate(
+    canonical: Option<&str>,
+    predicate_json: &Value,
+) -> Result<Predicate, serde_json::Error> {
+    match canonical {
+        Some(SLSA_PROVENANCE_V1) => {
             let slsa_provenance = deserialize_helper::<SLSAProvenanceV1Predicate>(predicate_json)?;
             Ok(Predicate::SLSAProvenanceV1(slsa_provenance))
         }
-        "https://slsa.dev/provenance/v0.2" => {
+        Some(SLSA_PROVENANCE_V02) => {
             let slsa_provenance: SLSAProvenanceV02Predicate = deserialize_helper::<SLSAProvenanceV02Predicate>(predicate_json)?;
             Ok(Predicate::SLSAProvenanceV02(slsa_provenance))
         }
-        "https://in-toto.io/attestation/scai/attribute-report" => {
+        Some(SCAI_ATTRIBUTE_REPORT) => {
             let scai_v02 = deserialize_helper::<SCAIV02Predicate>(predicate_json)?;
             Ok(Predicate::SCAIV02(scai_v02))
         }
+        Some(SCAI_V03) => {
+            let scai_v03 = deserialize_helper::<SCAIV03Predicate>(predicate_json)?;
+            Ok(Predicate::SCAIV03(scai_v03))
+        }
+        Some(APKO_BUILD_V1) => {
+            let apko_build = deserialize_helper::<ApkoBuildPredicate>(predicate_json)?;
+            Ok(Predicate::ApkoBuildV1(apko_build))
+        }
+        Some(MELANGE_BUILD_V1) => {
+            let melange_build = deserialize_helper::<MelangeBuildPredicate>(predicate_json)?;
+            Ok(Predicate::MelangeBuildV1(melange_build))
+        }
+        Some(JVM_BUILD_INFO_V1) => {
+            let build_info = deserialize_helper::<JvmBuildInfoPredicate>(predicate_json)?;
+            Ok(Predicate::JvmBuildInfoV1(build_info))
+        }
+        Some(SLSA_VERIFICATION_SUMMARY_V1) => {
+            let summary = deserialize_helper::<VerificationSummaryV1Predicate>(predicate_json)?;
+            Ok(Predicate::VerificationSummaryV1(summary))
+        }
+        Some(IN_TOTO_LINK_V1) => {
+            let link = deserialize_helper::<LinkPredicate>(predicate_json)?;
+            Ok(Predicate::Link(link))
+        }
+        Some(SPDX_DOCUMENT) => {
+            let spdx_document = deserialize_helper::<SpdxDocumentPredicate>(predicate_json)?;
+            Ok(Predicate::SpdxDocument(spdx_document))
+        }
+        Some(CYCLONEDX_BOM) => {
+            let bom = deserialize_helper::<CycloneDxBom>(predicate_json)?;
+            Ok(Predicate::CycloneDX(bom))
+        }
+        Some(IN_TOTO_TEST_RESULT_V01) => {
+            let test_result = deserialize_helper::<TestResultPredicate>(predicate_json)?;
+            Ok(Predicate::TestResultV01(test_result))
+        }
+        Some(SARIF_V21) => {
+            let sarif_log = deserialize_helper::<SarifLog>(predicate_json)?;
+            Ok(Predicate::SarifV21(sarif_log))
+        }
+        Some(CSAF_V2) => {
+            let csaf = deserialize_helper::<CsafDocument>(predicate_json)?;
+            Ok(Predicate::CsafV2(csaf))
+        }
+        Some(REFERENCE_V01) => {
+            let reference = deserialize_helper::<ReferencePredicate>(predicate_json)?;
+            Ok(Predicate::ReferenceV01(reference))
+        }
+        Some(LICENSE_SCAN_V01) => {
+            let license_scan = deserialize_helper::<LicenseScanPredicate>(predicate_json)?;
+            Ok(Predicate::LicenseScanV01(license_scan))
+        }
+        Some(CODE_REVIEW_V01) => {
+            let code_review = deserialize_helper::<CodeReviewPredicate>(predicate_json)?;
+            Ok(Predicate::CodeReviewV01(code_review))
+        }
+        Some(DEPENDENCY_REVIEW_V01) => {
+            let dependency_review =
+                deserialize_helper::<DependencyReviewPredicate>(predicate_json)?;
+            Ok(Predicate::DependencyReviewV01(dependency_review))
+        }
+        _ if predicate_json.is_null() => Ok(Predicate::Empty),
         _ => {
             let other_predicate = deserialize_helper::<Value>(predicate_json)?;
             Ok(Predicate::Other(other_predicate))
@@ -62,6 +431,96 @@ pub fn deserialize_predicate(
     }
 }
 
+// Returns the JSON schema of a statement with the predicate model for the given canonical
+// predicate type, used to find unknown properties in strict mode.
+// Update the match for any new predicate types.
+pub(crate) fn statement_schema(canonical: Option<&str>) -> Value {
+    let schema = match canonical {
+        Some(SLSA_PROVENANCE_V1) => schema_for!(InTotoStatementV1<SLSAProvenanceV1Predicate>),
+        Some(SLSA_PROVENANCE_V02) => schema_for!(InTotoStatementV1<SLSAProvenanceV02Predicate>),
+        Some(SCAI_ATTRIBUTE_REPORT) => schema_for!(InTotoStatementV1<SCAIV02Predicate>),
+        Some(SCAI_V03) => schema_for!(InTotoStatementV1<SCAIV03Predicate>),
+        Some(APKO_BUILD_V1) => schema_for!(InTotoStatementV1<ApkoBuildPredicate>),
+        Some(MELANGE_BUILD_V1) => schema_for!(InTotoStatementV1<MelangeBuildPredicate>),
+        Some(JVM_BUILD_INFO_V1) => schema_for!(InTotoStatementV1<JvmBuildInfoPredicate>),
+        Some(SLSA_VERIFICATION_SUMMARY_V1) => {
+            schema_for!(InTotoStatementV1<VerificationSummaryV1Predicate>)
+        }
+        Some(IN_TOTO_LINK_V1) => schema_for!(InTotoStatementV1<LinkPredicate>),
+        Some(SPDX_DOCUMENT) => schema_for!(InTotoStatementV1<SpdxDocumentPredicate>),
+        Some(CYCLONEDX_BOM) => schema_for!(InTotoStatementV1<CycloneDxBom>),
+        Some(IN_TOTO_TEST_RESULT_V01) => schema_for!(InTotoStatementV1<TestResultPredicate>),
+        Some(SARIF_V21) => schema_for!(InTotoStatementV1<SarifLog>),
+        Some(CSAF_V2) => schema_for!(InTotoStatementV1<CsafDocument>),
+        Some(REFERENCE_V01) => schema_for!(InTotoStatementV1<ReferencePredicate>),
+        Some(LICENSE_SCAN_V01) => schema_for!(InTotoStatementV1<LicenseScanPredicate>),
+        Some(CODE_REVIEW_V01) => schema_for!(InTotoStatementV1<CodeReviewPredicate>),
+        Some(DEPENDENCY_REVIEW_V01) => schema_for!(InTotoStatementV1<DependencyReviewPredicate>),
+        _ => schema_for!(InTotoStatementV1<Value>),
+    };
+    serde_json::to_value(schema).unwrap_or_default()
+}
+
+/// Guesses the known predicate type a predicate body belongs to from its top-level fields.
+///
+/// This is a heuristic used to give better error messages, not a substitute for validation.
+pub fn detect_predicate_type(predicate_json: &Value) -> Option<&'static str> {
+    let object = predicate_json.as_object()?;
+    let has = |field: &str| object.contains_key(field);
+    let config = |field: &str| {
+        object
+            .get("config")
+            .and_then(Value::as_object)
+            .is_some_and(|config| config.contains_key(field))
+    };
+
+    if has("buildDefinition") && has("runDetails") {
+        Some(SLSA_PROVENANCE_V1)
+    } else if has("builder") && (has("materials") || has("buildType") || has("invocation")) {
+        Some(SLSA_PROVENANCE_V02)
+    } else if object
+        .get("attributes")
+        .map(Value::is_array)
+        .unwrap_or(false)
+    {
+        Some(SCAI_ATTRIBUTE_REPORT)
+    } else if config("package") && config("pipeline") {
+        Some(MELANGE_BUILD_V1)
+    } else if config("contents") {
+        Some(APKO_BUILD_V1)
+    } else if has("modules") && has("number") {
+        Some(JVM_BUILD_INFO_V1)
+    } else if has("verifier") && has("verificationResult") {
+        Some(SLSA_VERIFICATION_SUMMARY_V1)
+    } else if has("command") && (has("materials") || has("products")) {
+        Some(IN_TOTO_LINK_V1)
+    } else if has("spdxVersion") {
+        Some(SPDX_DOCUMENT)
+    } else if has("bomFormat") {
+        Some(CYCLONEDX_BOM)
+    } else if has("result") && has("configuration") {
+        Some(IN_TOTO_TEST_RESULT_V01)
+    } else if has("runs") && has("version") {
+        Some(SARIF_V21)
+    } else if object
+        .get("document")
+        .and_then(Value::as_object)
+        .is_some_and(|document| document.contains_key("csaf_version"))
+    {
+        Some(CSAF_V2)
+    } else if has("attester") && has("references") {
+        Some(REFERENCE_V01)
+    } else if has("scanner") && has("licenses") {
+        Some(LICENSE_SCAN_V01)
+    } else if has("commitRange") && has("approvals") {
+        Some(CODE_REVIEW_V01)
+    } else if has("changes") && has("base") && has("head") {
+        Some(DEPENDENCY_REVIEW_V01)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +574,232 @@ mod tests {
         assert!(matches!(result, Ok(Predicate::SLSAProvenanceV02(_))));
     }
 
+    #[test]
+    fn test_deserialize_apko_and_melange_predicates() {
+        let apko = json!({
+            "config": {"contents": {"packages": ["wolfi-base"]}},
+            "packages": [{"name": "wolfi-base", "version": "1-r5"}]
+        });
+        let result = deserialize_predicate("https://apko.dev/build/v1.0", &apko);
+        assert!(matches!(result, Ok(Predicate::ApkoBuildV1(_))));
+        assert_eq!(detect_predicate_type(&apko), Some(APKO_BUILD_V1));
+
+        let melange = json!({
+            "config": {
+                "package": {"name": "hello", "version": "2.12", "epoch": 1},
+                "pipeline": [{"uses": "autoconf/make"}]
+            }
+        });
+        let result = deserialize_predicate(MELANGE_BUILD_V1, &melange);
+        assert!(matches!(result, Ok(Predicate::MelangeBuildV1(_))));
+        assert_eq!(detect_predicate_type(&melange), Some(MELANGE_BUILD_V1));
+
+        let mut report = ValidationReport::new();
+        let result = deserialize_predicate_with_options(
+            APKO_BUILD_V1,
+            &melange,
+            &ParseOptions::default(),
+            &mut report,
+        );
+        assert!(result.is_err());
+        assert_eq!(report.findings[0].code, "predicate-type-mismatch");
+    }
+
+    #[test]
+    fn test_deserialize_jvm_build_info_predicate() {
+        let build_info = json!({
+            "name": "hello-world",
+            "number": "42",
+            "modules": [{"id": "com.example:hello-world:1.2.3"}]
+        });
+        let result = deserialize_predicate(JVM_BUILD_INFO_V1, &build_info);
+        assert!(matches!(result, Ok(Predicate::JvmBuildInfoV1(_))));
+        assert_eq!(detect_predicate_type(&build_info), Some(JVM_BUILD_INFO_V1));
+    }
+
+    #[test]
+    fn test_deserialize_verification_summary_v1_predicate() {
+        let summary = json!({
+            "verifier": {"id": "https://github.com/slsa-framework/slsa-verifier"},
+            "resourceUri": "pkg:npm/hello-world@1.2.3",
+            "policy": {"uri": "https://example.com/slsa-policy/hello-world"},
+            "verificationResult": "PASSED",
+            "verifiedLevels": ["SLSA_BUILD_LEVEL_3"]
+        });
+        let result = deserialize_predicate(SLSA_VERIFICATION_SUMMARY_V1, &summary);
+        assert!(matches!(result, Ok(Predicate::VerificationSummaryV1(_))));
+        assert_eq!(
+            detect_predicate_type(&summary),
+            Some(SLSA_VERIFICATION_SUMMARY_V1)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_link_predicate() {
+        let link = json!({
+            "name": "build",
+            "command": ["make", "release"],
+            "products": {"hello-world": {"sha256": "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c"}}
+        });
+        let result = deserialize_predicate(IN_TOTO_LINK_V1, &link);
+        assert!(matches!(result, Ok(Predicate::Link(_))));
+        assert_eq!(detect_predicate_type(&link), Some(IN_TOTO_LINK_V1));
+    }
+
+    #[test]
+    fn test_deserialize_spdx_document_predicate() {
+        let statement: Value = serde_json::from_str(
+            &std::fs::read_to_string(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/spdx_v23_sbom_attestation.json"
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        let sbom = &statement["predicate"];
+        let result = deserialize_predicate(SPDX_DOCUMENT, sbom);
+        assert!(matches!(result, Ok(Predicate::SpdxDocument(_))));
+        assert_eq!(detect_predicate_type(sbom), Some(SPDX_DOCUMENT));
+
+        let mut invalid = sbom.clone();
+        invalid.as_object_mut().unwrap().remove("SPDXID");
+        assert!(deserialize_predicate(SPDX_DOCUMENT, &invalid).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_cyclonedx_predicate() {
+        let bom = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "components": [{"type": "library", "name": "lodash", "version": "4.17.21"}]
+        });
+        let result = deserialize_predicate(CYCLONEDX_BOM, &bom);
+        assert!(matches!(result, Ok(Predicate::CycloneDX(_))));
+        assert_eq!(detect_predicate_type(&bom), Some(CYCLONEDX_BOM));
+    }
+
+    #[test]
+    fn test_deserialize_test_result_predicate() {
+        let test_result = json!({
+            "result": "PASSED",
+            "configuration": [{"uri": "https://example.com/ci.yml"}],
+            "passedTests": ["test_parse"]
+        });
+        let result = deserialize_predicate(IN_TOTO_TEST_RESULT_V01, &test_result);
+        assert!(matches!(result, Ok(Predicate::TestResultV01(_))));
+        assert_eq!(
+            detect_predicate_type(&test_result),
+            Some(IN_TOTO_TEST_RESULT_V01)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_sarif_predicate() {
+        let sarif_log = json!({
+            "version": "2.1.0",
+            "runs": [{"tool": {"driver": {"name": "CodeQL"}}, "results": []}]
+        });
+        let result =
+            deserialize_predicate("https://docs.oasis-open.org/sarif/sarif/v2.1.0", &sarif_log);
+        assert!(matches!(result, Ok(Predicate::SarifV21(_))));
+        assert_eq!(detect_predicate_type(&sarif_log), Some(SARIF_V21));
+    }
+
+    #[test]
+    fn test_deserialize_csaf_predicate() {
+        let csaf = json!({
+            "document": {
+                "category": "csaf_vex",
+                "csaf_version": "2.0",
+                "publisher": {"category": "vendor", "name": "Example", "namespace": "https://example.com"},
+                "title": "Example VEX",
+                "tracking": {
+                    "id": "EX-2024-0001",
+                    "status": "draft",
+                    "version": "0.1",
+                    "initial_release_date": "2024-05-01T12:00:00Z",
+                    "current_release_date": "2024-05-01T12:00:00Z",
+                    "revision_history": []
+                }
+            }
+        });
+        let result = deserialize_predicate("https://docs.oasis-open.org/csaf/csaf/v2.0", &csaf);
+        assert!(matches!(result, Ok(Predicate::CsafV2(_))));
+        assert_eq!(detect_predicate_type(&csaf), Some(CSAF_V2));
+    }
+
+    #[test]
+    fn test_deserialize_dependency_review_predicate() {
+        let dependency_review = json!({
+            "tool": {"name": "dependency-review-action"},
+            "base": {"digest": {"gitCommit": "1234abcd"}},
+            "head": {"digest": {"gitCommit": "abcd1234"}},
+            "changes": [{"changeType": "added", "purl": "pkg:npm/lodash@4.17.21"}]
+        });
+        let result = deserialize_predicate(DEPENDENCY_REVIEW_V01, &dependency_review);
+        assert!(matches!(result, Ok(Predicate::DependencyReviewV01(_))));
+        assert_eq!(
+            detect_predicate_type(&dependency_review),
+            Some(DEPENDENCY_REVIEW_V01)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_code_review_predicate() {
+        let code_review = json!({
+            "tool": {"name": "GitHub"},
+            "commitRange": {
+                "repository": "https://github.com/octocat/hello-world",
+                "base": "1234abcd",
+                "head": "abcd1234"
+            },
+            "reviewers": [{"id": "hubot"}],
+            "approvals": [{"reviewer": "hubot", "commit": "abcd1234"}]
+        });
+        let result = deserialize_predicate(CODE_REVIEW_V01, &code_review);
+        assert!(matches!(result, Ok(Predicate::CodeReviewV01(_))));
+        assert_eq!(detect_predicate_type(&code_review), Some(CODE_REVIEW_V01));
+    }
+
+    #[test]
+    fn test_deserialize_license_scan_predicate() {
+        let license_scan = json!({
+            "scanner": {"name": "scancode-toolkit"},
+            "target": {"uri": "https://example.com/source.tar.gz"},
+            "licenses": [{"spdxId": "Apache-2.0"}]
+        });
+        let result = deserialize_predicate(LICENSE_SCAN_V01, &license_scan);
+        assert!(matches!(result, Ok(Predicate::LicenseScanV01(_))));
+        assert_eq!(detect_predicate_type(&license_scan), Some(LICENSE_SCAN_V01));
+    }
+
+    #[test]
+    fn test_deserialize_reference_predicate() {
+        let reference = json!({
+            "attester": {"id": "https://example.com/attester"},
+            "references": [{
+                "uri": "https://example.com/sbom.spdx.json",
+                "digest": {"sha256": "abcd1234"}
+            }]
+        });
+        let result = deserialize_predicate(REFERENCE_V01, &reference);
+        assert!(matches!(result, Ok(Predicate::ReferenceV01(_))));
+        assert_eq!(detect_predicate_type(&reference), Some(REFERENCE_V01));
+    }
+
+    #[test]
+    fn test_deserialize_scai_v03_predicate() {
+        let scai = json!({
+            "attributes": [{
+                "attribute": "WITH_STACK_PROTECTION",
+                "conditions": {"flags": ["-fstack-protector-strong"]}
+            }]
+        });
+        let result = deserialize_predicate(SCAI_V03, &scai);
+        assert!(matches!(result, Ok(Predicate::SCAIV03(_))));
+        assert!(deserialize_predicate(SCAI_ATTRIBUTE_REPORT, &scai).is_err());
+    }
+
     #[test]
     fn test_deserialize_other_predicate() {
         let predicate_type = "https://unknown.example.com";
@@ -130,6 +815,163 @@ mod tests {
         assert!(matches!(result, Ok(Predicate::Other(_))));
     }
 
+    #[test]
+    fn test_deserialize_empty_predicate() {
+        let predicate_type = "https://unknown.example.com";
+
+        let result = deserialize_predicate(predicate_type, &Value::Null);
+        assert!(matches!(result, Ok(Predicate::Empty)));
+    }
+
+    #[test]
+    fn test_deserialize_empty_known_predicate() {
+        let predicate_type = "https://slsa.dev/provenance/v1";
+
+        let result = deserialize_predicate(predicate_type, &Value::Null);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_normalize_predicate_type() {
+        assert_eq!(
+            normalize_predicate_type("https://slsa.dev/provenance/v1.0"),
+            "https://slsa.dev/provenance/v1"
+        );
+        assert_eq!(
+            normalize_predicate_type("https://slsa.dev/provenance/v0.2/"),
+            "https://slsa.dev/provenance/v0.2"
+        );
+        assert_eq!(
+            normalize_predicate_type("https://slsa.dev/provenance/v12"),
+            "https://slsa.dev/provenance/v12"
+        );
+        assert_eq!(
+            normalize_predicate_type("https://example.com/predicate/v1.0.0"),
+            "https://example.com/predicate/v1"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_non_canonical_predicate_type() {
+        let predicate_json = json!({
+            "buildDefinition": {
+                "buildType": "https://example.com/buildType/v1",
+                "externalParameters": {}
+            },
+            "runDetails": {
+                "builder": {
+                    "id": "https://example.com/builder"
+                }
+            }
+        });
+
+        let mut report = ValidationReport::new();
+        let result = deserialize_predicate_with_options(
+            "https://slsa.dev/provenance/v1.0/",
+            &predicate_json,
+            &ParseOptions::default(),
+            &mut report,
+        );
+        assert!(matches!(result, Ok(Predicate::SLSAProvenanceV1(_))));
+        assert_eq!(report.warnings().count(), 1);
+        assert_eq!(report.findings[0].code, "non-canonical-predicate-type");
+
+        let mut report = ValidationReport::new();
+        let result = deserialize_predicate_with_options(
+            "https://slsa.dev/provenance/v1.0/",
+            &predicate_json,
+            &ParseOptions::strict(),
+            &mut report,
+        );
+        assert!(matches!(result, Ok(Predicate::Other(_))));
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].code, "predicate-type-mismatch");
+    }
+
+    #[test]
+    fn test_deserialize_mismatched_predicate_type() {
+        let predicate_json = json!({
+            "builder": {
+                "id": "https://example.com/builder"
+            },
+            "buildType": "https://example.com/buildType/v1",
+            "materials": []
+        });
+
+        let mut report = ValidationReport::new();
+        let result = deserialize_predicate_with_options(
+            SLSA_PROVENANCE_V1,
+            &predicate_json,
+            &ParseOptions::default(),
+            &mut report,
+        );
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("did you mean predicateType https://slsa.dev/provenance/v0.2?"));
+        assert_eq!(report.errors().count(), 1);
+        assert_eq!(report.findings[0].code, "predicate-type-mismatch");
+
+        let mut report = ValidationReport::new();
+        let result = deserialize_predicate_with_options(
+            "https://slsa.dev/provenance/v12",
+            &predicate_json,
+            &ParseOptions::default(),
+            &mut report,
+        );
+        assert!(matches!(result, Ok(Predicate::Other(_))));
+        assert_eq!(report.warnings().count(), 1);
+    }
+
+    #[test]
+    fn test_compatible_predicate_type() {
+        assert_eq!(
+            split_predicate_type_version("https://slsa.dev/provenance/v1.1/"),
+            ("https://slsa.dev/provenance", Some((1, 1)))
+        );
+        assert_eq!(
+            split_predicate_type_version(SPDX_DOCUMENT),
+            (SPDX_DOCUMENT, None)
+        );
+
+        for (predicate_type, expected) in [
+            (SLSA_PROVENANCE_V1, Some(SLSA_PROVENANCE_V1)),
+            ("https://slsa.dev/provenance/v1.1", Some(SLSA_PROVENANCE_V1)),
+            (
+                "https://slsa.dev/provenance/v0.2.1",
+                Some(SLSA_PROVENANCE_V02),
+            ),
+            ("https://slsa.dev/provenance", Some(SLSA_PROVENANCE_V1)),
+            ("https://slsa.dev/provenance/v0.1", None),
+            ("https://slsa.dev/provenance/v2", None),
+            (
+                "https://in-toto.io/attestation/scai/attribute-report/v0.2",
+                Some(SCAI_ATTRIBUTE_REPORT),
+            ),
+            (
+                "https://in-toto.io/attestation/scai/attribute-report/v0.3",
+                None,
+            ),
+            ("https://in-toto.io/attestation/scai/v0.3.1", Some(SCAI_V03)),
+            ("https://in-toto.io/attestation/scai/v0.2", None),
+            ("https://spdx.dev/Document/v2.3", Some(SPDX_DOCUMENT)),
+            ("https://cyclonedx.org/bom/v1.5", Some(CYCLONEDX_BOM)),
+            ("https://example.com/predicate/v1", None),
+        ] {
+            assert_eq!(
+                canonical_predicate_type(predicate_type, PredicateTypeMatching::Compatible),
+                expected,
+                "{}",
+                predicate_type
+            );
+        }
+        assert_eq!(
+            canonical_predicate_type(
+                "https://in-toto.io/attestation/scai/attribute-report/v0.2",
+                PredicateTypeMatching::Normalized
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_deserialize_invalid_predicate() {
         let predicate_type = "https://slsa.dev/provenance/v1";
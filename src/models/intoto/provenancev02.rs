@@ -7,22 +7,30 @@ use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use url::Url;
 
+use crate::models::helpers::hash::hash_option_map;
+use crate::models::intoto::uri::UriReference;
+
+pub use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+
 /// A structure representing the SLSA Provenance v0.2 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct SLSAProvenanceV02Predicate {
     /// The entity that executed the invocation, which is trusted to have correctly performed the operation and populated this provenance.
     pub builder: Builder,
     #[serde(rename = "buildType")]
     /// The type of build that was performed.
-    pub build_type: Url,
+    // Kept as a URI-reference, as Tekton Chains emits relative ones like `tekton.dev/v1beta1/TaskRun`.
+    pub build_type: UriReference,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// The event that kicked off the build.
     pub invocation: Option<Invocation>,
     #[serde(rename = "buildConfig", skip_serializing_if = "Option::is_none")]
     /// The steps in the build. If invocation.configSource is not available, buildConfig can be used to verify information about the build.
-    pub build_config: Option<serde_json::Map<String, serde_json::Value>>,
+    // Some builders emit an array of steps rather than an object, so any JSON value is accepted.
+    pub build_config: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Metadata about this particular execution of the build.
     pub metadata: Option<BuildMetadata>,
@@ -32,13 +40,13 @@ pub struct SLSAProvenanceV02Predicate {
 }
 
 /// A structure representing the builder information of the SLSA Provenance v0.2 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct Builder {
     pub id: Url
 }
 
 /// A structure identifying the event that kicked off the build in the SLSA Provenance v0.2 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct Invocation {
     #[serde(rename = "configSource", skip_serializing_if = "Option::is_none")]
     /// Description of where the config file that kicked off the build came from. This is effectively a pointer to the source where buildConfig came from.
@@ -53,11 +61,11 @@ pub struct Invocation {
 }
 
 /// A structure representing the description of where the config file that kicked off the build came from in the SLSA Provenance v0.2 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct ConfigSource {
     /// The identity of the source of the config.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub uri: Option<Url>,
+    pub uri: Option<UriReference>,
     /// A set of cryptographic digests of the contents of the resource or artifact.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub digest: Option<HashMap<String, String>>,
@@ -67,7 +75,7 @@ pub struct ConfigSource {
 }
 
 /// A structure representing the metadata of the SLSA Provenance v0.2 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct BuildMetadata {
     #[serde(rename = "buildInvocationId", skip_serializing_if = "Option::is_none")]
     /// Identifies this particular build invocation, which can be useful for finding associated logs or other ad-hoc analysis. The exact meaning and format is defined by builder.id; by default it is treated as opaque and case-sensitive. The value SHOULD be globally unique.
@@ -87,7 +95,7 @@ pub struct BuildMetadata {
  }
 
 /// A structure representing the completeness claims of the SLSA Provenance v0.2 Predicate.
- #[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+ #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
  pub struct Completeness {
     #[serde(rename = "parameters", skip_serializing_if = "Option::is_none")]
     /// Whether the builder claims that nvocation.parameters is complete, meaning that all external inputs are properly captured in invocation.parameters.
@@ -100,15 +108,12 @@ pub struct BuildMetadata {
     pub materials: Option<bool>,
 }
 
-/// A size-efficient description of any software artifact or resource (mutable or immutable).
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct ResourceDescriptor {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    /// A URI used to identify the resource or artifact globally. This field is REQUIRED unless digest is set.
-    pub uri: Option<Url>,
-    /// A set of cryptographic digests of the contents of the resource or artifact. This field is REQUIRED unless uri is set.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub digest: Option<HashMap<String, String>>,
+impl Hash for ConfigSource {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uri.hash(state);
+        hash_option_map(&self.digest, state);
+        self.entry_point.hash(state);
+    }
 }
 
 #[cfg(test)]
@@ -122,17 +127,17 @@ mod tests {
             builder: Builder {
                 id: Url::parse("https://example.com/builder/v1").unwrap(),
             },
-            build_type: Url::parse("https://example.com/buildType/v1").unwrap(),
+            build_type: UriReference::parse("https://example.com/buildType/v1").unwrap(),
             invocation: Some(Invocation {
                 config_source: Some(ConfigSource {
-                    uri: Some(Url::parse("https://example.com/source1").unwrap()),
+                    uri: Some(UriReference::parse("https://example.com/source1").unwrap()),
                     digest: Some(hashmap! {"algorithm1".to_string() => "digest1".to_string()}),
                     entry_point: Some("myentrypoint".to_string()),
                 }),
                 parameters: Some(json!({"key": "value"}).as_object().unwrap().clone()),
                 environment: Some(json!({"key": "value"}).as_object().unwrap().clone()),
             }),
-            build_config: Some(json!({"key": "value"}).as_object().unwrap().clone()),
+            build_config: Some(json!({"key": "value"})),
             metadata: Some(BuildMetadata {
                 invocation_id: Some("invocation1".to_string()),
                 started_on: Some(DateTime::parse_from_rfc3339("2023-01-01T12:34:56Z")
@@ -151,8 +156,9 @@ mod tests {
                 reproducible: Some(false),
             }),
             materials: Some(vec![ResourceDescriptor {
-                uri: Some(Url::parse("https://example.com/material1").unwrap()),
+                uri: Some(UriReference::parse("https://example.com/material1").unwrap()),
                 digest: Some(hashmap! {"algorithm1".to_string() => "digest1".to_string()}),
+                ..ResourceDescriptor::default()
             }]),
         }
     }
@@ -221,4 +227,25 @@ mod tests {
 
         assert_eq!(serialized_provenance, expected_json_data);
     }
+
+    #[test]
+    fn config_source_uri_is_preserved() {
+        let mut json_data = get_test_slsa_provenance_json();
+        json_data["invocation"]["configSource"]["uri"] =
+            json!("git+https://github.com/org/repo@refs/heads/main");
+
+        let provenance: SLSAProvenanceV02Predicate =
+            serde_json::from_value(json_data.clone()).unwrap();
+        let config_source = provenance
+            .invocation
+            .as_ref()
+            .unwrap()
+            .config_source
+            .as_ref();
+        assert_eq!(
+            config_source.unwrap().uri.as_ref().unwrap(),
+            "git+https://github.com/org/repo@refs/heads/main"
+        );
+        assert_eq!(serde_json::to_value(provenance).unwrap(), json_data);
+    }
 }
@@ -3,15 +3,15 @@
 //! This module provides structs for the SLSAProvenanceV1Predicate and its related structures.
 //! It also includes the necessary (de)serialization code for handling SLSA provenance predicates.
 
-use crate::models::helpers::b64_option_serde;
 use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use url::Url;
 
+pub use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+
 /// A structure representing the SLSA Provenance v1 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct SLSAProvenanceV1Predicate {
     #[serde(rename = "buildDefinition")]
     pub build_definition: BuildDefinition,
@@ -20,14 +20,15 @@ pub struct SLSAProvenanceV1Predicate {
 }
 
 /// A structure representing the build definition of the SLSA Provenance v1 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct BuildDefinition {
     #[serde(rename = "buildType")]
     #[schemars(with = "Url")]
     pub build_type: Url,
     #[serde(rename = "externalParameters")]
     /// The parameters that are under external control, such as those set by a user or tenant of the build platform. They MUST be complete at SLSA Build L3, meaning that there is no additional mechanism for an external party to influence the build. (At lower SLSA Build levels, the completeness MAY be best effort.)\nThe build platform SHOULD be designed to minimize the size and complexity of externalParameters, in order to reduce fragility and ease verification. Consumers SHOULD have an expectation of what “good” looks like; the more information that they need to check, the harder that task becomes.\nVerifiers SHOULD reject unrecognized or unexpected fields within externalParameters.
-    pub external_parameters:  serde_json::Map<String, serde_json::Value>,
+    // Real-world builders don't always emit an object here, so any JSON value is accepted.
+    pub external_parameters: serde_json::Value,
     #[serde(rename = "internalParameters")]
     /// Unordered collection of artifacts needed at build time. Completeness is best effort, at least through SLSA Build L3. For example, if the build script fetches and executes “example.com/foo.sh”, which in turn fetches “example.com/bar.tar.gz”, then both “foo.sh” and “bar.tar.gz” SHOULD be listed here.
     pub internal_parameters: Option<serde_json::Map<String, serde_json::Value>>,
@@ -38,7 +39,7 @@ pub struct BuildDefinition {
 }
 
 /// A structure representing the run details of the SLSA Provenance v1 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct RunDetails {
     /// Identifies the build platform that executed the invocation, which is trusted to have correctly performed the operation and populated this provenance.
     pub builder: Builder,
@@ -50,7 +51,7 @@ pub struct RunDetails {
 }
 
 /// A structure representing the builder information of the SLSA Provenance v1 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct Builder {
     #[schemars(with = "Url")]
     pub id: Url,
@@ -64,7 +65,7 @@ pub struct Builder {
 }
 
 /// A structure representing the metadata of the SLSA Provenance v1 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct BuildMetadata {
     #[serde(rename = "invocationId")]
     /// Identifies this particular build invocation, which can be useful for finding associated logs or other ad-hoc analysis. The exact meaning and format is defined by builder.id; by default it is treated as opaque and case-sensitive. The value SHOULD be globally unique.
@@ -77,49 +78,10 @@ pub struct BuildMetadata {
     pub finished_on: Option<DateTime<Utc>>,
 }
 
-/// A size-efficient description of any software artifact or resource (mutable or immutable).
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct ResourceDescriptor {
-    #[schemars(with = "Url")]
-    /// A URI used to identify the resource or artifact globally. This field is REQUIRED unless either digest or content is set.
-    pub uri: Url,
-    /// A set of cryptographic digests of the contents of the resource or artifact. This field is REQUIRED unless either uri or content is set.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub digest: Option<HashMap<String, String>>,
-    /// Machine-readable identifier for distinguishing between descriptors.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    #[serde(
-        rename = "downloadLocation",
-        default,
-        skip_serializing_if = "Option::is_none"
-    )]
-    #[schemars(with = "Url")]
-    /// The location of the described resource or artifact, if different from the uri.
-    pub download_location: Option<Url>,
-    #[serde(rename = "mediaType", skip_serializing_if = "Option::is_none")]
-    /// The MIME Type (i.e., media type) of the described resource or artifact.
-    pub media_type: Option<String>,
-    // TODO(mlieberman85): Fix below. Serde was erroring without the default attribute.
-    // I think we can probably use a crate with base64 decoding already built in.
-    #[serde(
-        with = "b64_option_serde",
-        default,
-        skip_serializing_if = "Option::is_none"
-    )]
-    // TODO(mlieberman85): Use a base64 type when this issue is resolved:
-    // https://github.com/GREsau/schemars/issues/160
-    /// The contents of the resource or artifact. This field is REQUIRED unless either uri or digest is set.
-    #[schemars(with = "String")]
-    pub content: Option<Vec<u8>>,
-    /// This field MAY be used to provide additional information or metadata about the resource or artifact that may be useful to the consumer when evaluating the attestation against a policy.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub annotations: Option<serde_json::Map<String, serde_json::Value>>,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::intoto::uri::UriReference;
     use maplit::hashmap;
     use serde_json::json;
 
@@ -127,13 +89,15 @@ mod tests {
         SLSAProvenanceV1Predicate {
             build_definition: BuildDefinition {
                 build_type: Url::parse("https://example.com/buildType/v1").unwrap(),
-                external_parameters: json!({"key": "value"}).as_object().unwrap().clone(),
+                external_parameters: json!({"key": "value"}),
                 internal_parameters: Some(json!({"key": "value"}).as_object().unwrap().clone()),
                 resolved_dependencies: Some(vec![ResourceDescriptor {
-                    uri: Url::parse("https://example.com/dependency1").unwrap(),
+                    uri: Some(UriReference::parse("https://example.com/dependency1").unwrap()),
                     digest: Some(hashmap! {"algorithm1".to_string() => "digest1".to_string()}),
                     name: Some("dependency1".to_string()),
-                    download_location: Some(Url::parse("https://example.com/download1").unwrap()),
+                    download_location: Some(
+                        UriReference::parse("https://example.com/download1").unwrap(),
+                    ),
                     media_type: Some("media/type1".to_string()),
                     content: Some(b"content1".to_vec()),
                     annotations: Some(json!({"key": "value"}).as_object().unwrap().clone()),
@@ -143,11 +107,13 @@ mod tests {
                 builder: Builder {
                     id: Url::parse("https://example.com/builder/v1").unwrap(),
                     builder_dependencies: Some(vec![ResourceDescriptor {
-                        uri: Url::parse("https://example.com/builder/dependency1").unwrap(),
+                        uri: Some(
+                            UriReference::parse("https://example.com/builder/dependency1").unwrap(),
+                        ),
                         digest: Some(hashmap! {"algorithm1".to_string() => "digest1".to_string()}),
                         name: Some("builder_dependency1".to_string()),
                         download_location: Some(
-                            Url::parse("https://example.com/builder/download1").unwrap(),
+                            UriReference::parse("https://example.com/builder/download1").unwrap(),
                         ),
                         media_type: Some("media/type1".to_string()),
                         content: Some(b"content1".to_vec()),
@@ -167,11 +133,11 @@ mod tests {
                     ),
                 }),
                 byproducts: Some(vec![ResourceDescriptor {
-                    uri: Url::parse("https://example.com/byproduct1").unwrap(),
+                    uri: Some(UriReference::parse("https://example.com/byproduct1").unwrap()),
                     digest: Some(hashmap! {"algorithm1".to_string() => "digest1".to_string()}),
                     name: Some("byproduct1".to_string()),
                     download_location: Some(
-                        Url::parse("https://example.com/byproduct/download1").unwrap(),
+                        UriReference::parse("https://example.com/byproduct/download1").unwrap(),
                     ),
                     media_type: Some("media/type1".to_string()),
                     content: Some(b"content1".to_vec()),
@@ -269,4 +235,28 @@ mod tests {
 
         assert_eq!(serialized_provenance, expected_json_data);
     }
+
+    #[test]
+    fn uri_references_are_preserved() {
+        let mut json_data = get_test_slsa_provenance_json();
+        let dependency = &mut json_data["buildDefinition"]["resolvedDependencies"][0];
+        dependency["uri"] = json!("git+https://github.com/org/repo@refs/heads/main");
+        dependency["downloadLocation"] = json!("pkg:npm/%40scope/name@1.0.0");
+
+        let provenance: SLSAProvenanceV1Predicate =
+            serde_json::from_value(json_data.clone()).unwrap();
+        let dependency = &provenance
+            .build_definition
+            .resolved_dependencies
+            .as_ref()
+            .unwrap()[0];
+        assert_eq!(
+            dependency.uri.as_ref().unwrap(),
+            "git+https://github.com/org/repo@refs/heads/main"
+        );
+        assert_eq!(serde_json::to_value(provenance).unwrap(), json_data);
+
+        json_data["buildDefinition"]["resolvedDependencies"][0]["uri"] = json!("not a uri");
+        assert!(serde_json::from_value::<SLSAProvenanceV1Predicate>(json_data).is_err());
+    }
 }
@@ -0,0 +1,67 @@
+//! Reference predicate model and associated structures.
+//!
+//! Rather than embedding an SBOM as the predicate, an attester can point to one stored
+//! elsewhere, e.g. next to a release or in an OCI registry, by its location and digest:
+//!
+//! ```json
+//! {
+//!   "attester": {"id": "https://github.com/actions/attest-sbom"},
+//!   "references": [{
+//!     "downloadLocation": "https://example.com/releases/hello-world-1.2.3.spdx.json",
+//!     "digest": {"sha256": "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c"},
+//!     "mediaType": "application/spdx+json"
+//!   }]
+//! }
+//! ```
+//!
+//! See: https://github.com/in-toto/attestation/blob/main/spec/predicates/reference.md
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+use crate::models::intoto::uri::UriReference;
+
+/// A struct representing the Reference v0.1 Predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ReferencePredicate {
+    pub attester: Attester,
+    /// The documents the subjects refer to, e.g. their SBOMs. Each is located by its `uri` or
+    /// `downloadLocation` and pinned by its `digest`.
+    pub references: Vec<ResourceDescriptor>,
+}
+
+/// The entity that attests to the references.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Attester {
+    pub id: UriReference,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_reference_predicate() {
+        let value = json!({
+            "attester": {"id": "https://github.com/actions/attest-sbom"},
+            "references": [{
+                "uri": "oci://registry.example.com/hello-world:sbom",
+                "downloadLocation": "https://example.com/hello-world.spdx.json",
+                "digest": {"sha256": "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c"},
+                "mediaType": "application/spdx+json"
+            }]
+        });
+        let predicate: ReferencePredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            predicate.references[0].locator().map(UriReference::as_str),
+            Some("https://example.com/hello-world.spdx.json")
+        );
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+
+        let mut missing = value;
+        missing.as_object_mut().unwrap().remove("attester");
+        assert!(serde_json::from_value::<ReferencePredicate>(missing).is_err());
+    }
+}
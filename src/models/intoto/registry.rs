@@ -0,0 +1,258 @@
+//! Predicate types registered at runtime, for predicate types Spector doesn't know.
+//!
+//! Library users register a predicate type URL with the model its predicates deserialize into
+//! and the semantic checks to run on them, typically at the start of `main`:
+//!
+//! ```
+//! use serde::Deserialize;
+//! use spector::models::intoto::registry::PredicateRegistry;
+//! use spector::validate::report::Finding;
+//!
+//! #[derive(Deserialize)]
+//! struct ReleaseApproval {
+//!     approver: String,
+//! }
+//!
+//! PredicateRegistry::global()
+//!     .register("https://example.com/release-approval/v1", |approval: &ReleaseApproval, report| {
+//!         if approval.approver.is_empty() {
+//!             report.push(Finding::error("empty-approver", "/predicate/approver", "approver is empty"));
+//!         }
+//!     })
+//!     .unwrap();
+//! ```
+//!
+//! Statements with a registered predicateType then deserialize their predicate into
+//! [`Predicate::Registered`], failing like built-in predicate types when it doesn't match the
+//! model, and the checks run with the other statement rules. Predicate types are matched after
+//! normalizing them with [`normalize_predicate_type`].
+
+use anyhow::{bail, Result};
+use serde::de::DeserializeOwned;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::sync::{Arc, OnceLock, RwLock};
+use tracing::debug;
+
+use super::options::PredicateTypeMatching;
+use super::predicate::{canonical_predicate_type, normalize_predicate_type, Predicate};
+use crate::validate::report::ValidationReport;
+
+type Parse = dyn Fn(&Value) -> Result<(), serde_json::Error> + Send + Sync;
+type Check = dyn Fn(&Value, &mut ValidationReport) + Send + Sync;
+
+struct RegisteredType {
+    predicate_type: String,
+    parse: Box<Parse>,
+    check: Box<Check>,
+}
+
+/// A predicate of a registered predicate type, which deserialized into the registered model.
+///
+/// It is kept as JSON, and serializes as the predicate itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RegisteredPredicate {
+    /// The predicateType of the statement, as written.
+    pub predicate_type: String,
+    pub value: Value,
+}
+
+impl RegisteredPredicate {
+    /// Deserializes the predicate into a model, usually the one its type was registered with.
+    pub fn to_model<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_value(self.value.clone())
+    }
+}
+
+impl Serialize for RegisteredPredicate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+/// The registered predicate types.
+#[derive(Default)]
+pub struct PredicateRegistry {
+    types: RwLock<Vec<Arc<RegisteredType>>>,
+}
+
+impl PredicateRegistry {
+    /// Returns an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the registry shared by the whole process, which statements are deserialized and
+    /// validated with.
+    pub fn global() -> &'static PredicateRegistry {
+        static GLOBAL: OnceLock<PredicateRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(PredicateRegistry::new)
+    }
+
+    /// Registers a predicate type, whose predicates must deserialize into `T` and are checked by
+    /// `check`, which adds its findings to the report with paths from the statement root, e.g.
+    /// `/predicate/approver`.
+    ///
+    /// Fails if the predicate type is already registered or is, in any compatible version, a
+    /// predicate type Spector knows.
+    pub fn register<T, F>(&self, predicate_type: &str, check: F) -> Result<()>
+    where
+        T: DeserializeOwned + 'static,
+        F: Fn(&T, &mut ValidationReport) + Send + Sync + 'static,
+    {
+        if let Some(known) =
+            canonical_predicate_type(predicate_type, PredicateTypeMatching::Compatible)
+        {
+            bail!(
+                "Predicate type {} is the built-in predicate type {}",
+                predicate_type,
+                known
+            );
+        }
+        let normalized = normalize_predicate_type(predicate_type);
+        let mut types = self.types.write().unwrap_or_else(|e| e.into_inner());
+        if types
+            .iter()
+            .any(|registered| registered.predicate_type == normalized)
+        {
+            bail!("Predicate type {} is already registered", predicate_type);
+        }
+        types.push(Arc::new(RegisteredType {
+            predicate_type: normalized,
+            parse: Box::new(|value| serde_json::from_value::<T>(value.clone()).map(|_| ())),
+            check: Box::new(move |value, report| {
+                if let Ok(model) = serde_json::from_value::<T>(value.clone()) {
+                    check(&model, report);
+                }
+            }),
+        }));
+        debug!(predicate_type, "Registered predicate type");
+        Ok(())
+    }
+
+    /// Returns the normalized URLs of the registered predicate types.
+    pub fn predicate_types(&self) -> Vec<String> {
+        self.types
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|registered| registered.predicate_type.clone())
+            .collect()
+    }
+
+    /// Returns true if the predicate type is registered.
+    pub fn contains(&self, predicate_type: &str) -> bool {
+        self.get(predicate_type).is_some()
+    }
+
+    fn get(&self, predicate_type: &str) -> Option<Arc<RegisteredType>> {
+        let normalized = normalize_predicate_type(predicate_type);
+        self.types
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|registered| registered.predicate_type == normalized)
+            .cloned()
+    }
+
+    /// Deserializes a predicate of a registered type into [`Predicate::Registered`], or returns
+    /// None if the type isn't registered.
+    pub fn deserialize(
+        &self,
+        predicate_type: &str,
+        predicate_json: &Value,
+    ) -> Option<Result<Predicate, serde_json::Error>> {
+        let registered = self.get(predicate_type)?;
+        Some((registered.parse)(predicate_json).map(|_| {
+            Predicate::Registered(RegisteredPredicate {
+                predicate_type: predicate_type.to_string(),
+                value: predicate_json.clone(),
+            })
+        }))
+    }
+
+    /// Runs the checks of the predicate's type, if it is still registered.
+    pub fn check(&self, predicate: &RegisteredPredicate, report: &mut ValidationReport) {
+        if let Some(registered) = self.get(&predicate.predicate_type) {
+            (registered.check)(&predicate.value, report);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::report::Finding;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Deserialize)]
+    struct ReleaseApproval {
+        approver: String,
+    }
+
+    fn check_approval(approval: &ReleaseApproval, report: &mut ValidationReport) {
+        if approval.approver.is_empty() {
+            report.push(Finding::error(
+                "empty-approver",
+                "/predicate/approver",
+                "approver is empty",
+            ));
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = PredicateRegistry::new();
+        registry
+            .register("https://example.com/release-approval/v1", check_approval)
+            .unwrap();
+        assert!(registry
+            .register("https://example.com/release-approval/v1.0/", check_approval)
+            .unwrap_err()
+            .to_string()
+            .contains("already registered"));
+        assert!(registry
+            .register("https://slsa.dev/provenance/v1.1", check_approval)
+            .unwrap_err()
+            .to_string()
+            .contains("built-in predicate type https://slsa.dev/provenance/v1"));
+        assert_eq!(
+            registry.predicate_types(),
+            vec!["https://example.com/release-approval/v1"]
+        );
+        assert!(registry.contains("https://example.com/release-approval/v1.0"));
+        assert!(!registry.contains("https://example.com/other/v1"));
+    }
+
+    #[test]
+    fn test_deserialize_and_check() {
+        let registry = PredicateRegistry::new();
+        registry
+            .register("https://example.com/release-approval/v1", check_approval)
+            .unwrap();
+
+        let approval = json!({"approver": ""});
+        let Some(Ok(Predicate::Registered(predicate))) =
+            registry.deserialize("https://example.com/release-approval/v1", &approval)
+        else {
+            panic!("expected a registered predicate");
+        };
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), approval);
+        assert_eq!(
+            predicate.to_model::<ReleaseApproval>().unwrap().approver,
+            ""
+        );
+        let mut report = ValidationReport::new();
+        registry.check(&predicate, &mut report);
+        assert_eq!(report.findings[0].code, "empty-approver");
+
+        assert!(matches!(
+            registry.deserialize("https://example.com/release-approval/v1", &json!({})),
+            Some(Err(_))
+        ));
+        assert!(registry
+            .deserialize("https://example.com/other/v1", &approval)
+            .is_none());
+    }
+}
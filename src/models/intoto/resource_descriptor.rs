@@ -0,0 +1,111 @@
+//! The In-Toto resource descriptor, shared by the predicates that describe artifacts.
+//!
+//! See: https://github.com/in-toto/attestation/blob/main/spec/v1/resource_descriptor.md
+//!
+//! SLSA Provenance v1 uses it for dependencies and byproducts, SLSA Provenance v0.2 for
+//! materials, which only set `uri` and `digest`, and SCAI for targets, evidence and producers.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::models::helpers::{b64_option_serde, hash::hash_option_map};
+use crate::models::intoto::uri::UriReference;
+
+/// A size-efficient description of any software artifact or resource (mutable or immutable).
+///
+/// The spec requires at least one of `uri`, `digest` or `content` to be set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct ResourceDescriptor {
+    /// A URI used to identify the resource or artifact globally. This field is REQUIRED unless either digest or content is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<UriReference>,
+    /// A set of cryptographic digests of the contents of the resource or artifact. This field is REQUIRED unless either uri or content is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<HashMap<String, String>>,
+    /// Machine-readable identifier for distinguishing between descriptors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        rename = "downloadLocation",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(with = "UriReference")]
+    /// The location of the described resource or artifact, if different from the uri.
+    pub download_location: Option<UriReference>,
+    #[serde(rename = "mediaType", skip_serializing_if = "Option::is_none")]
+    /// The MIME Type (i.e., media type) of the described resource or artifact.
+    pub media_type: Option<String>,
+    #[serde(
+        with = "b64_option_serde",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    // TODO(mlieberman85): Use a base64 type when this issue is resolved:
+    // https://github.com/GREsau/schemars/issues/160
+    /// The contents of the resource or artifact. This field is REQUIRED unless either uri or digest is set.
+    #[schemars(with = "String")]
+    pub content: Option<Vec<u8>>,
+    /// This field MAY be used to provide additional information or metadata about the resource or artifact that may be useful to the consumer when evaluating the attestation against a policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl ResourceDescriptor {
+    /// Returns true if none of `uri`, `digest` and `content`, one of which the spec requires, is
+    /// set.
+    pub fn is_empty(&self) -> bool {
+        self.uri.is_none() && self.digest.is_none() && self.content.is_none()
+    }
+
+    /// Returns the location of the described resource, its `downloadLocation` if it has one and
+    /// else its `uri`.
+    pub fn locator(&self) -> Option<&UriReference> {
+        self.download_location.as_ref().or(self.uri.as_ref())
+    }
+}
+
+impl Hash for ResourceDescriptor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uri.hash(state);
+        hash_option_map(&self.digest, state);
+        self.name.hash(state);
+        self.download_location.hash(state);
+        self.media_type.hash(state);
+        self.content.hash(state);
+        self.annotations.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_resource_descriptor() {
+        let value = json!({
+            "uri": "git+https://github.com/octocat/hello-world@refs/tags/v1.2.3",
+            "digest": {"gitCommit": "c27d339ee6075c1f744c5d4b200f7901aad2c369"},
+            "name": "source",
+            "downloadLocation": "https://github.com/octocat/hello-world/archive/v1.2.3.tar.gz",
+            "mediaType": "application/x-git",
+            "content": "aGVsbG8=",
+            "annotations": {"reviewed": true}
+        });
+        let descriptor: ResourceDescriptor = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(descriptor.content.as_deref(), Some(&b"hello"[..]));
+        assert!(!descriptor.is_empty());
+        assert_eq!(serde_json::to_value(&descriptor).unwrap(), value);
+
+        // v0.2 materials, and descriptors identified by their digest alone.
+        let material = json!({"digest": {"sha256": "aa"}});
+        let descriptor: ResourceDescriptor = serde_json::from_value(material.clone()).unwrap();
+        assert_eq!(descriptor.uri, None);
+        assert_eq!(serde_json::to_value(&descriptor).unwrap(), material);
+
+        assert!(ResourceDescriptor::default().is_empty());
+    }
+}
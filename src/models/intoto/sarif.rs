@@ -0,0 +1,266 @@
+//! SARIF predicate model and associated structures.
+//!
+//! Some scanners attest their findings by embedding a SARIF 2.1.0 log as the predicate, with the
+//! scanned artifacts as subjects:
+//!
+//! ```json
+//! {
+//!   "version": "2.1.0",
+//!   "runs": [{
+//!     "tool": {"driver": {"name": "CodeQL", "version": "2.17.0", "rules": [{"id": "js/xss"}]}},
+//!     "results": [{
+//!       "ruleId": "js/xss",
+//!       "ruleIndex": 0,
+//!       "level": "error",
+//!       "message": {"text": "Cross-site scripting vulnerability due to user-provided value."},
+//!       "locations": [{"physicalLocation": {"artifactLocation": {"uri": "src/index.js"}}}]
+//!     }]
+//!   }]
+//! }
+//! ```
+//!
+//! Only the tool and the results of each run are modelled. Other parts of the log, e.g. the
+//! invocations, artifacts or taxonomies of a run, are accepted but not kept.
+//!
+//! See: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The version of SARIF modelled.
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// A struct representing a SARIF log.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct SarifLog {
+    /// The URI of the SARIF JSON schema the log conforms to.
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    /// The version of SARIF the log conforms to, `2.1.0`.
+    pub version: String,
+    /// The runs of analysis tools the log contains.
+    pub runs: Vec<Run>,
+}
+
+/// A run of a single analysis tool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Run {
+    pub tool: Tool,
+    /// The results of the run. Absent if the tool failed to run, as opposed to empty if it found
+    /// nothing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<SarifResult>>,
+}
+
+/// The analysis tool of a run.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Tool {
+    /// The tool's primary executable.
+    pub driver: ToolComponent,
+    /// The plugins and extensions of the tool that ran.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<ToolComponent>,
+}
+
+/// The driver or an extension of an analysis tool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolComponent {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub semantic_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub information_uri: Option<String>,
+    /// The rules the component checks, which results refer to by `ruleIndex` or `ruleId`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<ReportingDescriptor>,
+}
+
+/// A rule of an analysis tool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingDescriptor {
+    /// A stable identifier of the rule, e.g. `js/xss` or `CVE-2024-3094`.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<MultiformatMessage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help_uri: Option<String>,
+    /// The level and other settings results of the rule have unless they say otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_configuration: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Value>,
+}
+
+/// A message with a plain text and optionally a Markdown form.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct MultiformatMessage {
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<String>,
+}
+
+/// A result found by an analysis tool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    /// The id of the rule the result was found by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    /// The index of the rule in the driver's `rules`, or -1 if it isn't known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_index: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<Level>,
+    /// The kind of result, e.g. `fail` or `pass`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    pub message: Message,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<Location>,
+    /// Identifiers of the result that are stable across runs, by the algorithm that computed them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprints: Option<BTreeMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial_fingerprints: Option<BTreeMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Value>,
+}
+
+/// The message of a result, given by its `text` or by the `id` of a message of its rule.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Message {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<String>,
+}
+
+/// The severity of a result.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    None,
+    Note,
+    Warning,
+    Error,
+}
+
+/// Where a result was found.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub physical_location: Option<PhysicalLocation>,
+    /// Locations by the names of the functions, classes or namespaces they are in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logical_locations: Option<Value>,
+}
+
+/// A location in an artifact, e.g. a range of lines of a file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact_location: Option<ArtifactLocation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<Value>,
+}
+
+/// The location of an artifact, relative to the base URI `uriBaseId` names if it has one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactLocation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<i64>,
+}
+
+impl Run {
+    /// Returns the rule of the driver a result refers to, by its `ruleIndex` or else its `ruleId`.
+    pub fn rule(&self, result: &SarifResult) -> Option<&ReportingDescriptor> {
+        match result.rule_index {
+            Some(index) if index >= 0 => usize::try_from(index)
+                .ok()
+                .and_then(|index| self.tool.driver.rules.get(index)),
+            _ => {
+                let rule_id = result.rule_id.as_deref()?;
+                self.tool
+                    .driver
+                    .rules
+                    .iter()
+                    .find(|rule| rule.id == rule_id)
+            }
+        }
+    }
+}
+
+impl Message {
+    /// Returns true if the message has neither a text nor the id of a message of its rule.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none() && self.id.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_sarif_log() {
+        let value = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "CodeQL",
+                        "version": "2.17.0",
+                        "rules": [{"id": "js/xss", "shortDescription": {"text": "Cross-site scripting"}}]
+                    }
+                },
+                "results": [{
+                    "ruleId": "js/xss",
+                    "ruleIndex": 0,
+                    "level": "error",
+                    "message": {"text": "Cross-site scripting vulnerability."},
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": {"uri": "src/index.js", "uriBaseId": "%SRCROOT%"},
+                            "region": {"startLine": 12}
+                        }
+                    }],
+                    "partialFingerprints": {"primaryLocationLineHash": "39fa2ee980eb94b0:1"}
+                }]
+            }]
+        });
+        let log: SarifLog = serde_json::from_value(value.clone()).unwrap();
+        let run = &log.runs[0];
+        let result = &run.results.as_ref().unwrap()[0];
+        assert_eq!(result.level, Some(Level::Error));
+        assert_eq!(
+            run.rule(result).map(|rule| rule.id.as_str()),
+            Some("js/xss")
+        );
+        assert_eq!(serde_json::to_value(&log).unwrap(), value);
+
+        let mut unknown = value;
+        unknown["runs"][0]["results"][0]["level"] = json!("critical");
+        assert!(serde_json::from_value::<SarifLog>(unknown).is_err());
+    }
+}
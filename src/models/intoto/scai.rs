@@ -1,13 +1,16 @@
 //! SCAI predicate model and associated structures.
 //!
-//! This module provides structs for the SCAIV02Predicate.
+//! This module provides structs for the SCAIV02Predicate and SCAIV03Predicate.
 //! It also includes the necessary (de)serialization code for handling the SCAI predicate.
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 
-use super::provenancev1::ResourceDescriptor;
+use super::resource_descriptor::ResourceDescriptor;
+use crate::models::helpers::hash::hash_option_map;
 
 /// This is based on the model in: 
 /// {
@@ -22,9 +25,9 @@ use super::provenancev1::ResourceDescriptor;
 ///         "producer": { [ResourceDescriptor] } // optional
 ///     }
 /// }
-
+///
 /// A struct representing the SCAI V0.2 Predicate.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct SCAIV02Predicate {
     pub attributes: Vec<Attribute>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -32,7 +35,7 @@ pub struct SCAIV02Predicate {
 }
 
 /// A struct 
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct Attribute {
     pub attribute: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,15 +46,62 @@ pub struct Attribute {
     pub evidence: Option<ResourceDescriptor>,
 }
 
+impl Hash for Attribute {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.attribute.hash(state);
+        self.target.hash(state);
+        hash_option_map(&self.conditions, state);
+        self.evidence.hash(state);
+    }
+}
+
+/// This is based on the model in:
+/// {
+///     "predicateType": "https://in-toto.io/attestation/scai/v0.3",
+///     "predicate": {
+///         "attributes": [{
+///             "attribute": "<ATTRIBUTE>",
+///             "target": { [ResourceDescriptor] }, // optional
+///             "conditions": { /* object */ }, // optional
+///             "evidence": { [ResourceDescriptor] } // optional
+///         }],
+///         "producer": { [ResourceDescriptor] } // optional
+///     }
+/// }
+///
+/// A struct representing the SCAI V0.3 Predicate. v0.3 dropped `attribute-report` from the
+/// predicateType, and the conditions of an attribute assertion can be any JSON object.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct SCAIV03Predicate {
+    pub attributes: Vec<AttributeAssertion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub producer: Option<ResourceDescriptor>,
+}
+
+/// An assertion that a target, or the producer if there is none, has an attribute.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct AttributeAssertion {
+    /// The attribute, e.g. `WITH_STACK_PROTECTION` or `HAS_SLSA_PROVENANCE`.
+    pub attribute: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<ResourceDescriptor>,
+    /// The conditions under which the attribute holds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Map<String, Value>>,
+    /// The evidence the attribute holds, e.g. an attestation or a test report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<ResourceDescriptor>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use url::Url;
+    use crate::models::intoto::uri::UriReference;
 
     #[test]
     fn test_scaiv02_predicate_serialization() {
         let target_resource_descriptor = ResourceDescriptor {
-            uri: Url::parse("http://target.example.com/").unwrap(),
+            uri: Some(UriReference::parse("http://target.example.com/").unwrap()),
             digest: None,
             name: Some("TargetResource".into()),
             download_location: None,
@@ -61,7 +111,7 @@ mod tests {
         };
 
         let evidence_resource_descriptor = ResourceDescriptor {
-            uri: Url::parse("http://evidence.example.com/").unwrap(),
+            uri: Some(UriReference::parse("http://evidence.example.com/").unwrap()),
             digest: None,
             name: Some("EvidenceResource".into()),
             download_location: None,
@@ -71,7 +121,7 @@ mod tests {
         };
 
         let producer_resource_descriptor = ResourceDescriptor {
-            uri: Url::parse("http://producer.example.com/").unwrap(),
+            uri: Some(UriReference::parse("http://producer.example.com/").unwrap()),
             digest: None,
             name: Some("ProducerResource".into()),
             download_location: None,
@@ -132,4 +182,24 @@ mod tests {
         assert_eq!(deserialized.attributes[0].evidence.as_ref().unwrap().name, Some("EvidenceResource".into()));
         assert_eq!(deserialized.producer.as_ref().unwrap().name, Some("ProducerResource".into()));
     }
+
+    #[test]
+    fn test_scaiv03_predicate() {
+        let value = serde_json::json!({
+            "attributes": [{
+                "attribute": "WITH_STACK_PROTECTION",
+                "target": {"name": "hello-world", "digest": {"sha256": "abcd1234"}},
+                "conditions": {"flags": ["-fstack-protector-strong"], "inlined": true},
+                "evidence": {"uri": "https://example.com/build.log", "mediaType": "text/plain"}
+            }],
+            "producer": {"uri": "https://example.com/builder", "name": "gcc"}
+        });
+        let predicate: SCAIV03Predicate = serde_json::from_value(value.clone()).unwrap();
+        let conditions = predicate.attributes[0].conditions.as_ref().unwrap();
+        assert_eq!(conditions.get("inlined"), Some(&Value::Bool(true)));
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+
+        // v0.2 only allows string conditions.
+        assert!(serde_json::from_value::<SCAIV02Predicate>(value).is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,97 @@
+//! SPDX SBOM predicate model.
+//!
+//! SBOM attestations carry an SPDX document as their predicate, with the
+//! `https://spdx.dev/Document` predicateType. The document is deserialized into the SPDX 2.3 or
+//! 2.2 model from [`crate::models::sbom`], as selected by its `spdxVersion`.
+//!
+//! See: https://github.com/in-toto/attestation/blob/main/spec/predicates/spdx.md
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::hash::{Hash, Hasher};
+
+use crate::models::sbom::{spdx22::Spdx22Document, spdx23::Spdx23};
+
+/// An SPDX document used as an In-Toto predicate, in the version given by its `spdxVersion`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SpdxDocumentPredicate {
+    V23(Box<Spdx23>),
+    V22(Box<Spdx22Document>),
+}
+
+impl<'de> Deserialize<'de> for SpdxDocumentPredicate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        match value.get("spdxVersion").and_then(Value::as_str) {
+            Some("SPDX-2.3") => serde_json::from_value(value)
+                .map(SpdxDocumentPredicate::V23)
+                .map_err(serde::de::Error::custom),
+            Some("SPDX-2.2") => serde_json::from_value(value)
+                .map(SpdxDocumentPredicate::V22)
+                .map_err(serde::de::Error::custom),
+            other => Err(serde::de::Error::custom(format!(
+                "unsupported spdxVersion {}, expected SPDX-2.3 or SPDX-2.2",
+                other.unwrap_or("(none)")
+            ))),
+        }
+    }
+}
+
+// The generated SPDX models don't implement comparison or hashing, so documents are compared
+// and hashed through their JSON representation.
+impl SpdxDocumentPredicate {
+    fn to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl PartialEq for SpdxDocumentPredicate {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_value() == other.to_value()
+    }
+}
+
+impl Eq for SpdxDocumentPredicate {}
+
+impl Hash for SpdxDocumentPredicate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_value().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sbom() -> Value {
+        let text = std::fs::read_to_string(format!(
+            "{}/tests/fixtures/spdx_v23_sbom.json",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+        serde_json::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn test_spdx_document_predicate() {
+        let value = sbom();
+        let predicate: SpdxDocumentPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert!(matches!(predicate, SpdxDocumentPredicate::V23(_)));
+        assert_eq!(
+            serde_json::from_value::<SpdxDocumentPredicate>(
+                serde_json::to_value(&predicate).unwrap()
+            )
+            .unwrap(),
+            predicate
+        );
+
+        let mut unsupported = value;
+        unsupported["spdxVersion"] = "SPDX-3.0".into();
+        assert!(serde_json::from_value::<SpdxDocumentPredicate>(unsupported)
+            .unwrap_err()
+            .to_string()
+            .contains("unsupported spdxVersion SPDX-3.0"));
+    }
+}
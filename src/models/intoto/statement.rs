@@ -5,16 +5,32 @@
 //! code for handling In-Toto v1 statements.
 
 use schemars::JsonSchema;
+use serde::de::{MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use tracing::instrument;
 use url::Url;
-use std::fmt::Debug;
 
-use crate::models::intoto::predicate::{deserialize_predicate, Predicate};
+use crate::models::helpers::hash::hash_map;
+use crate::models::intoto::digest::normalize_digests;
+use crate::models::intoto::options::{ParseOptions, StatementTypeCheck};
+use crate::models::intoto::predicate::{
+    canonical_predicate_type, deserialize_predicate_with_options, statement_schema, Predicate,
+};
+use crate::validate::report::{Finding, ValidationReport};
+use crate::validate::suggest::{did_you_mean, hint, unknown_properties};
+
+/// The `_type` URI of In-Toto v1 statements.
+pub const STATEMENT_TYPE_V1: &str = "https://in-toto.io/Statement/v1";
+/// The `_type` URI of In-Toto v0.1 statements.
+pub const STATEMENT_TYPE_V01: &str = "https://in-toto.io/Statement/v0.1";
 
 /// Represents an In-Toto v1 statement.
-#[derive(Debug, Serialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct InTotoStatementV1<T: Debug + Serialize + PartialEq + JsonSchema = Predicate> {
     #[serde(rename = "_type")]
     #[schemars(with = "Url")]
@@ -27,8 +43,13 @@ pub struct InTotoStatementV1<T: Debug + Serialize + PartialEq + JsonSchema = Pre
 }
 
 /// Enum for the supported hashing algorithms.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+///
+/// Algorithm names are matched case-insensitively when deserializing, and serialized using
+/// the canonical names from the in-toto DigestSet spec. More algorithms are added as the spec
+/// lists them.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, JsonSchema)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum Algorithm {
     // TODO(mlieberman85): Add validation for the length/encoding of the digest string.
     Sha224,
@@ -50,51 +71,352 @@ pub enum Algorithm {
     Gost,
     Sha1,
     Md5,
+    #[serde(rename = "gitBlob")]
+    GitBlob,
+    #[serde(rename = "gitCommit")]
+    GitCommit,
+    #[serde(rename = "gitTag")]
+    GitTag,
+    #[serde(rename = "gitTree")]
+    GitTree,
+    #[serde(rename = "dirHash")]
+    DirHash,
+}
+
+impl Algorithm {
+    /// All supported algorithms.
+    pub const ALL: &'static [Algorithm] = &[
+        Algorithm::Sha224,
+        Algorithm::Sha256,
+        Algorithm::Sha384,
+        Algorithm::Sha512,
+        Algorithm::Sha512_224,
+        Algorithm::Sha512_256,
+        Algorithm::Sha3_224,
+        Algorithm::Sha3_256,
+        Algorithm::Sha3_384,
+        Algorithm::Sha3_512,
+        Algorithm::Shake128,
+        Algorithm::Shake256,
+        Algorithm::Blake2b,
+        Algorithm::Blake2s,
+        Algorithm::Ripemd160,
+        Algorithm::Sm3,
+        Algorithm::Gost,
+        Algorithm::Sha1,
+        Algorithm::Md5,
+        Algorithm::GitBlob,
+        Algorithm::GitCommit,
+        Algorithm::GitTag,
+        Algorithm::GitTree,
+        Algorithm::DirHash,
+    ];
+
+    /// Returns the canonical name of the algorithm.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Algorithm::Sha224 => "sha224",
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Sha384 => "sha384",
+            Algorithm::Sha512 => "sha512",
+            Algorithm::Sha512_224 => "sha512_224",
+            Algorithm::Sha512_256 => "sha512_256",
+            Algorithm::Sha3_224 => "sha3_224",
+            Algorithm::Sha3_256 => "sha3_256",
+            Algorithm::Sha3_384 => "sha3_384",
+            Algorithm::Sha3_512 => "sha3_512",
+            Algorithm::Shake128 => "shake128",
+            Algorithm::Shake256 => "shake256",
+            Algorithm::Blake2b => "blake2b",
+            Algorithm::Blake2s => "blake2s",
+            Algorithm::Ripemd160 => "ripemd160",
+            Algorithm::Sm3 => "sm3",
+            Algorithm::Gost => "gost",
+            Algorithm::Sha1 => "sha1",
+            Algorithm::Md5 => "md5",
+            Algorithm::GitBlob => "gitBlob",
+            Algorithm::GitCommit => "gitCommit",
+            Algorithm::GitTag => "gitTag",
+            Algorithm::GitTree => "gitTree",
+            Algorithm::DirHash => "dirHash",
+        }
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = String;
+
+    /// Parses an algorithm name case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Algorithm::ALL
+            .iter()
+            .find(|algorithm| algorithm.as_str().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| format!("unknown digest algorithm `{}`", s))
+    }
+}
+
+impl<'de> Deserialize<'de> for Algorithm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
 }
 
 /// Represents a set of digests, mapping algorithms to their respective digest strings.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, JsonSchema)]
 pub struct DigestSet(HashMap<Algorithm, String>);
 
-/// Represents a subject in an In-Toto v1 statement.
-#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct Subject {
-    pub name: String,
-    pub digest: DigestSet,
+impl DigestSet {
+    /// Returns the digest for the given algorithm, if present.
+    pub fn get(&self, algorithm: &Algorithm) -> Option<&String> {
+        self.0.get(algorithm)
+    }
+
+    /// Returns an iterator over the algorithms and their digests.
+    pub fn iter(&self) -> impl Iterator<Item = (&Algorithm, &String)> {
+        self.0.iter()
+    }
 }
 
-// Custom deserialization for InTotoStatementV1.
-impl<'de> Deserialize<'de> for InTotoStatementV1 {
+/// Values may be prefixed with their algorithm, as OCI digests are, e.g. `sha256:abcd`, and are
+/// kept without the prefix. A prefix naming another algorithm than the key, and keys naming the
+/// same algorithm, such as `sha256` and `SHA256`, are errors rather than one digest silently
+/// replacing the other.
+impl<'de> Deserialize<'de> for DigestSet {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        // Helper struct to deserialize the JSON before constructing the InTotoStatementV1.
-        #[derive(Deserialize)]
-        struct Helper {
-            #[serde(rename = "_type")]
-            _type: Url,
-            subject: Vec<Subject>,
-            #[serde(rename = "predicateType")]
-            predicate_type: Url,
-            predicate: Value,
+        deserializer.deserialize_map(DigestSetVisitor)
+    }
+}
+
+struct DigestSetVisitor;
+
+impl<'de> Visitor<'de> for DigestSetVisitor {
+    type Value = DigestSet;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of digest algorithms to digests")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<DigestSet, A::Error> {
+        let mut digests = HashMap::new();
+        while let Some((name, digest)) = map.next_entry::<String, String>()? {
+            let algorithm: Algorithm = name.parse().map_err(serde::de::Error::custom)?;
+            let digest = match digest.split_once(':') {
+                Some((prefix, value)) => match prefix.parse::<Algorithm>() {
+                    Ok(prefixed) if prefixed == algorithm => value.to_string(),
+                    Ok(prefixed) => {
+                        return Err(serde::de::Error::custom(format!(
+                            "{} digest is prefixed with another algorithm, {}",
+                            name, prefixed
+                        )))
+                    }
+                    // Other prefixes are part of the value, such as the `h1:` of a dirHash.
+                    Err(_) => digest,
+                },
+                None => digest,
+            };
+            if digests.insert(algorithm, digest).is_some() {
+                return Err(serde::de::Error::custom(format!(
+                    "duplicate digest algorithm `{}`, given as `{}`",
+                    algorithm, name
+                )));
+            }
         }
+        Ok(DigestSet(digests))
+    }
+}
+
+impl FromIterator<(Algorithm, String)> for DigestSet {
+    fn from_iter<I: IntoIterator<Item = (Algorithm, String)>>(digests: I) -> Self {
+        Self(digests.into_iter().collect())
+    }
+}
 
-        let helper = Helper::deserialize(deserializer)?;
+impl Hash for DigestSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_map(&self.0, state);
+    }
+}
+
+/// Represents a subject in an In-Toto v1 statement.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Subject {
+    pub name: String,
+    pub digest: DigestSet,
+}
+
+// Helper struct to deserialize the JSON before constructing the InTotoStatementV1.
+#[derive(Deserialize)]
+struct StatementHelper {
+    #[serde(rename = "_type")]
+    _type: Url,
+    subject: Vec<Subject>,
+    #[serde(rename = "predicateType")]
+    predicate_type: Url,
+    // A missing predicate is treated the same as a null one, per the attestation spec.
+    #[serde(default)]
+    predicate: Value,
+}
+
+impl StatementHelper {
+    fn into_statement(
+        self,
+        options: &ParseOptions,
+        report: &mut ValidationReport,
+    ) -> Result<InTotoStatementV1, String> {
+        check_statement_type(&self._type, options.statement_type)?;
+
+        if options.strict && self.predicate.is_null() {
+            return Err(format!(
+                "predicate must not be null or missing in strict mode (predicateType: {})",
+                self.predicate_type
+            ));
+        }
 
         // Deserialize the predicate based on the predicate type.
-        let predicate = deserialize_predicate(&helper.predicate_type.as_str(), &helper.predicate)
-            .map_err(serde::de::Error::custom)?;
+        let predicate = deserialize_predicate_with_options(
+            self.predicate_type.as_str(),
+            &self.predicate,
+            options,
+            report,
+        )
+        .map_err(|e| e.to_string())?;
 
         Ok(InTotoStatementV1 {
-            _type: helper._type,
-            subject: helper.subject,
-            predicate_type: helper.predicate_type,
+            _type: self._type,
+            subject: self.subject,
+            predicate_type: self.predicate_type,
             predicate,
         })
     }
 }
 
+/// Checks the statement `_type` against the accepted URIs.
+fn check_statement_type(statement_type: &Url, check: StatementTypeCheck) -> Result<(), String> {
+    let accepted: &[&str] = match check {
+        StatementTypeCheck::Any => return Ok(()),
+        StatementTypeCheck::V1 => &[STATEMENT_TYPE_V1],
+        StatementTypeCheck::V1OrV01 => &[STATEMENT_TYPE_V1, STATEMENT_TYPE_V01],
+    };
+
+    if accepted.contains(&statement_type.as_str()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "unexpected _type {}, expected {}",
+            statement_type,
+            accepted.join(" or ")
+        ))
+    }
+}
+
+/// Reports the properties of the statement that its model doesn't define and that look like a
+/// misspelling of one it does, such as `buildtype` for `buildType`, as `unknown-property` errors.
+///
+/// Other unknown properties are left alone, as the spec allows extension fields in several
+/// places the models don't cover.
+fn check_unknown_properties(value: &Value, options: &ParseOptions, report: &mut ValidationReport) {
+    let canonical = value
+        .get("predicateType")
+        .and_then(Value::as_str)
+        .and_then(|predicate_type| {
+            canonical_predicate_type(predicate_type, options.predicate_type_matching)
+        });
+    for unknown in unknown_properties(value, &statement_schema(canonical)) {
+        if unknown.suggestion.is_some() {
+            report.push(Finding::error(
+                "unknown-property",
+                unknown.path.clone(),
+                unknown.message(),
+            ));
+        }
+    }
+}
+
+/// Adds a hint to a missing field error when the statement has a property that looks like a
+/// misspelling of the field, such as `predicatetype` for `predicateType`.
+fn with_field_hint(err: serde_json::Error, value: &Value) -> serde_json::Error {
+    let message = err.to_string();
+    let missing = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split_once('`'))
+        .map(|(field, _)| field);
+    let found = missing.zip(value.as_object()).and_then(|(field, object)| {
+        object
+            .keys()
+            .find(|key| did_you_mean(key, [field]).is_some())
+            .map(|key| hint(key, field))
+    });
+    match found {
+        Some(found) => serde::de::Error::custom(format!("{} ({})", message, found)),
+        None => err,
+    }
+}
+
+impl InTotoStatementV1 {
+    /// Deserializes an In-Toto v1 statement from a JSON value using the given options.
+    ///
+    /// This behaves like the `Deserialize` implementation, which uses the default options,
+    /// except that any warnings raised while deserializing are added to the report.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(predicate_type = value.get("predicateType").and_then(serde_json::Value::as_str))
+    )]
+    pub fn from_value_with_options(
+        mut value: Value,
+        options: &ParseOptions,
+        report: &mut ValidationReport,
+    ) -> Result<Self, serde_json::Error> {
+        if options.normalize_digests {
+            normalize_digests(&mut value, "", report);
+        }
+        if options.strict {
+            check_unknown_properties(&value, options, report);
+        }
+        let helper =
+            StatementHelper::deserialize(&value).map_err(|err| with_field_hint(err, &value))?;
+        helper
+            .into_statement(options, report)
+            .map_err(serde::de::Error::custom)
+    }
+
+    /// Returns true if both statements are the same attestation, ignoring differences in digest
+    /// case and URL normalization. See [`crate::compare::semantic_eq`].
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(a), Ok(b)) => crate::compare::semantic_eq(&a, &b),
+            _ => false,
+        }
+    }
+}
+
+// Custom deserialization for InTotoStatementV1.
+impl<'de> Deserialize<'de> for InTotoStatementV1 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let helper = StatementHelper::deserialize(deserializer)?;
+        helper
+            .into_statement(&ParseOptions::default(), &mut ValidationReport::new())
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,4 +572,268 @@ mod tests {
             "Deserialization should fail due to invalid digest in the subject"
         );
     }
+
+    #[test]
+    fn deserialize_intoto_statement_missing_unknown_predicate() {
+        let json_data = r#"{
+            "_type": "https://in-toto.io/Statement/v1",
+            "predicateType": "https://random.type/predicate/v1",
+            "subject": [
+                {
+                    "name": "example",
+                    "digest": {
+                        "sha256": "abcd1234"
+                    }
+                }
+            ]
+        }"#;
+
+        let statement: InTotoStatementV1 = serde_json::from_str(json_data).unwrap();
+        assert_eq!(statement.predicate, Predicate::Empty);
+    }
+
+    #[test]
+    fn deserialize_intoto_statement_null_predicate_strict() {
+        let json_data = serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "predicateType": "https://random.type/predicate/v1",
+            "predicate": null,
+            "subject": [
+                {
+                    "name": "example",
+                    "digest": {
+                        "sha256": "abcd1234"
+                    }
+                }
+            ]
+        });
+
+        let lenient = InTotoStatementV1::from_value_with_options(
+            json_data.clone(),
+            &ParseOptions::default(),
+            &mut ValidationReport::new(),
+        )
+        .unwrap();
+        assert!(lenient.predicate.is_empty());
+
+        let result = InTotoStatementV1::from_value_with_options(
+            json_data,
+            &ParseOptions::strict(),
+            &mut ValidationReport::new(),
+        );
+        assert!(
+            result.is_err(),
+            "Deserialization should fail in strict mode due to null predicate"
+        );
+    }
+
+    #[test]
+    fn deserialize_intoto_statement_normalize_digests() {
+        let json_data = serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "predicateType": "https://random.type/predicate/v1",
+            "predicate": {},
+            "subject": [
+                {
+                    "name": "example",
+                    "digest": {
+                        "sha256": " ABCD1234 "
+                    }
+                }
+            ]
+        });
+        let options = ParseOptions {
+            normalize_digests: true,
+            ..ParseOptions::default()
+        };
+
+        let mut report = ValidationReport::new();
+        let statement =
+            InTotoStatementV1::from_value_with_options(json_data, &options, &mut report).unwrap();
+        let expected: DigestSet =
+            serde_json::from_value(serde_json::json!({"sha256": "abcd1234"})).unwrap();
+        assert_eq!(statement.subject[0].digest, expected);
+        assert_eq!(report.findings[0].code, "normalized-digest");
+    }
+
+    #[test]
+    fn deserialize_algorithms_case_insensitive() {
+        let digests: DigestSet = serde_json::from_value(serde_json::json!({
+            "SHA256": "abcd1234",
+            "gitcommit": "c27d339ee6075c1f744c5d4b200f7901aad2c369",
+            "dirHash": "abcd1234"
+        }))
+        .unwrap();
+
+        let serialized = serde_json::to_value(&digests).unwrap();
+        assert_eq!(
+            serialized,
+            serde_json::json!({
+                "sha256": "abcd1234",
+                "gitCommit": "c27d339ee6075c1f744c5d4b200f7901aad2c369",
+                "dirHash": "abcd1234"
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_prefixed_digests() {
+        let digests: DigestSet = serde_json::from_value(serde_json::json!({
+            "sha256": "sha256:abcd1234",
+            "SHA512": "sha512:ef567890",
+            "dirHash": "h1:6qJ6aBjS5wYc8Ks+UedWjt1dHrSRl9cTVBHBIf7yfJs="
+        }))
+        .unwrap();
+        assert_eq!(
+            digests.get(&Algorithm::Sha256).map(String::as_str),
+            Some("abcd1234")
+        );
+        assert_eq!(
+            digests.get(&Algorithm::Sha512).map(String::as_str),
+            Some("ef567890")
+        );
+        assert_eq!(
+            digests.get(&Algorithm::DirHash).map(String::as_str),
+            Some("h1:6qJ6aBjS5wYc8Ks+UedWjt1dHrSRl9cTVBHBIf7yfJs=")
+        );
+
+        let err = serde_json::from_value::<DigestSet>(serde_json::json!({
+            "sha256": "sha512:abcd1234"
+        }))
+        .unwrap_err();
+        assert!(err.to_string().contains("prefixed with another algorithm"));
+    }
+
+    #[test]
+    fn deserialize_duplicate_algorithms() {
+        let err = serde_json::from_value::<DigestSet>(serde_json::json!({
+            "sha256": "abcd1234",
+            "SHA256": "ef567890"
+        }))
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("duplicate digest algorithm `sha256`"));
+    }
+
+    #[test]
+    fn deserialize_unknown_algorithm() {
+        let result = serde_json::from_value::<DigestSet>(serde_json::json!({
+            "crc32": "abcd1234"
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_intoto_statement_type_check() {
+        let json_data = serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v0.1",
+            "predicateType": "https://random.type/predicate/v1",
+            "predicate": {},
+            "subject": [
+                {
+                    "name": "example",
+                    "digest": {
+                        "sha256": "abcd1234"
+                    }
+                }
+            ]
+        });
+
+        let parse = |statement_type| {
+            let options = ParseOptions {
+                statement_type,
+                ..ParseOptions::default()
+            };
+            InTotoStatementV1::from_value_with_options(
+                json_data.clone(),
+                &options,
+                &mut ValidationReport::new(),
+            )
+        };
+
+        assert!(parse(StatementTypeCheck::Any).is_ok());
+        assert!(parse(StatementTypeCheck::V1OrV01).is_ok());
+        let err = parse(StatementTypeCheck::V1).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected https://in-toto.io/Statement/v1"));
+    }
+
+    #[test]
+    fn test_statements_in_sets() {
+        use std::collections::HashSet;
+
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        let statement: InTotoStatementV1 = serde_json::from_str(&fixture).unwrap();
+
+        let mut statements = HashSet::new();
+        statements.insert(statement.clone());
+        // A separately deserialized copy, whose maps may iterate in another order, is the same.
+        assert!(!statements.insert(serde_json::from_str(&fixture).unwrap()));
+        assert!(statements.contains(&statement));
+        assert_eq!(statements.len(), 1);
+    }
+
+    #[test]
+    fn test_misspelled_properties() {
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        let mut json_data: Value = serde_json::from_str(&fixture).unwrap();
+        let build_definition = json_data["predicate"]["buildDefinition"]
+            .as_object_mut()
+            .unwrap();
+        let build_type = build_definition.remove("buildType").unwrap();
+        build_definition.insert("buildtype".to_string(), build_type);
+
+        let mut report = ValidationReport::new();
+        let lenient = InTotoStatementV1::from_value_with_options(
+            json_data.clone(),
+            &ParseOptions::default(),
+            &mut report,
+        );
+        assert!(lenient.is_err());
+        assert!(report.findings.is_empty());
+
+        let mut report = ValidationReport::new();
+        let strict = InTotoStatementV1::from_value_with_options(
+            json_data,
+            &ParseOptions::strict(),
+            &mut report,
+        );
+        assert!(strict.is_err());
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].code, "unknown-property");
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/buildDefinition/buildtype"
+        );
+        assert!(report.findings[0]
+            .message
+            .contains("did you mean `buildType` instead of `buildtype`?"));
+
+        let misspelled = serde_json::json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "predicatetype": "https://random.type/predicate/v1",
+            "subject": []
+        });
+        let err = InTotoStatementV1::from_value_with_options(
+            misspelled,
+            &ParseOptions::default(),
+            &mut ValidationReport::new(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "missing field `predicateType` (did you mean `predicateType` instead of \
+             `predicatetype`?)"
+        );
+    }
 }
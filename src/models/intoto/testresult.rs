@@ -0,0 +1,78 @@
+//! In-Toto test result predicate model and associated structures.
+//!
+//! A test result attestation records the outcome of running a test suite, e.g. in CI, against
+//! its subjects, with the tests that passed, warned and failed:
+//!
+//! ```json
+//! {
+//!   "result": "FAILED",
+//!   "configuration": [{"name": ".github/workflows/ci.yml", "digest": {"gitBlob": "..."}}],
+//!   "url": "https://github.com/example/hello-world/actions/runs/1234",
+//!   "passedTests": ["test_parse"],
+//!   "failedTests": ["test_render"]
+//! }
+//! ```
+//!
+//! See: https://github.com/in-toto/attestation/blob/main/spec/predicates/test-result.md
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+use crate::models::intoto::uri::UriReference;
+
+/// A struct representing the In-Toto test result v0.1 predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TestResultPredicate {
+    pub result: TestResult,
+    /// The configuration the tests ran with, e.g. the CI workflow.
+    pub configuration: Vec<ResourceDescriptor>,
+    /// Where the test run can be looked at, e.g. the CI job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<UriReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub passed_tests: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warned_tests: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_tests: Vec<String>,
+}
+
+/// The overall outcome of the test run.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TestResult {
+    Passed,
+    Warned,
+    Failed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_result_predicate() {
+        let value = json!({
+            "result": "FAILED",
+            "configuration": [{
+                "name": ".github/workflows/ci.yml",
+                "digest": {"gitBlob": "ebf8d4f1a4a6b1b0e5c5c5e1b0a7d0b6a9c3e2f1"}
+            }],
+            "url": "https://github.com/example/hello-world/actions/runs/1234",
+            "passedTests": ["test_parse"],
+            "warnedTests": ["test_deprecated"],
+            "failedTests": ["test_render"]
+        });
+        let predicate: TestResultPredicate = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(predicate.result, TestResult::Failed);
+        assert_eq!(predicate.failed_tests, vec!["test_render"]);
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+
+        let mut unknown = value;
+        unknown["result"] = json!("SKIPPED");
+        assert!(serde_json::from_value::<TestResultPredicate>(unknown).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! URI-references, as used by the provenance fields that identify artifacts.
+//!
+//! Fields like `configSource.uri` and the `uri` of resolved dependencies carry values such as
+//! `git+https://github.com/org/repo@refs/heads/main` or package URLs. Parsing them as a `Url`
+//! normalizes them, so they no longer match the digests and policies that refer to them, and
+//! rejects relative references outright. `UriReference` checks the syntax of RFC 3986
+//! URI-references but keeps the original string.
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Schema, SchemaObject};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use url::Url;
+
+/// Characters allowed in a URI-reference besides ASCII letters, digits and percent-encodings.
+const ALLOWED_CHARACTERS: &str = "-._~:/?#[]@!$&'()*+,;=";
+
+/// A URI or relative reference, kept exactly as written.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct UriReference(String);
+
+impl UriReference {
+    /// Checks the syntax of a URI-reference.
+    ///
+    /// Non-ASCII characters are accepted, as IRIs are common in practice, but whitespace,
+    /// control characters and the ASCII characters that must always be percent-encoded aren't.
+    pub fn parse(reference: &str) -> Result<Self, String> {
+        let invalid =
+            |reason: &str| Err(format!("invalid URI-reference {:?}: {}", reference, reason));
+        if reference.is_empty() {
+            return invalid("it is empty");
+        }
+        if reference
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control())
+        {
+            return invalid("it contains whitespace or control characters");
+        }
+        if let Some(c) = reference.chars().find(|c| {
+            c.is_ascii()
+                && !c.is_ascii_alphanumeric()
+                && *c != '%'
+                && !ALLOWED_CHARACTERS.contains(*c)
+        }) {
+            return invalid(&format!("{:?} must be percent-encoded", c));
+        }
+        let bytes = reference.as_bytes();
+        for (i, _) in reference.match_indices('%') {
+            if !matches!(bytes.get(i + 1..i + 3), Some([a, b]) if a.is_ascii_hexdigit() && b.is_ascii_hexdigit())
+            {
+                return invalid("% must be followed by two hex digits");
+            }
+        }
+        if reference.matches('#').count() > 1 {
+            return invalid("it has more than one fragment");
+        }
+
+        // A colon before the first slash, question mark or hash ends the scheme, which relative
+        // references can't have.
+        let first_part = reference.split(['/', '?', '#']).next().unwrap_or_default();
+        if let Some((scheme, _)) = first_part.split_once(':') {
+            let valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+            if !valid_scheme {
+                return invalid("the scheme is not valid");
+            }
+        }
+        Ok(Self(reference.to_string()))
+    }
+
+    /// Returns the reference as written.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the scheme, or `None` for a relative reference.
+    pub fn scheme(&self) -> Option<&str> {
+        let first_part = self.0.split(['/', '?', '#']).next().unwrap_or_default();
+        first_part.split_once(':').map(|(scheme, _)| scheme)
+    }
+
+    /// Returns true if the reference has a scheme, rather than being relative.
+    pub fn is_absolute(&self) -> bool {
+        self.scheme().is_some()
+    }
+
+    /// Parses the reference as a `Url`, which may normalize it. Returns `None` for relative
+    /// references and those `Url` can't represent.
+    pub fn to_url(&self) -> Option<Url> {
+        Url::parse(&self.0).ok()
+    }
+}
+
+impl fmt::Display for UriReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for UriReference {
+    type Err = String;
+
+    fn from_str(reference: &str) -> Result<Self, Self::Err> {
+        Self::parse(reference)
+    }
+}
+
+impl TryFrom<String> for UriReference {
+    type Error = String;
+
+    fn try_from(reference: String) -> Result<Self, Self::Error> {
+        Self::parse(&reference)
+    }
+}
+
+impl From<UriReference> for String {
+    fn from(reference: UriReference) -> Self {
+        reference.0
+    }
+}
+
+impl From<Url> for UriReference {
+    fn from(url: Url) -> Self {
+        Self(url.into())
+    }
+}
+
+impl AsRef<str> for UriReference {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for UriReference {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for UriReference {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl JsonSchema for UriReference {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "UriReference".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some("uri-reference".to_string()),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keeps_original() {
+        for reference in [
+            "git+https://github.com/org/repo@refs/heads/main",
+            "pkg:npm/%40angular/core@16.0.0",
+            "https://Example.com/a/../b",
+            "../relative/path.yaml",
+            "//example.com/no-scheme",
+            "main.yaml#L10",
+            "urn:uuid:6e8bc430-9c3a-11d9-9669-0800200c9a66",
+            "https://example.com/ünïcode",
+        ] {
+            let parsed = UriReference::parse(reference).unwrap();
+            assert_eq!(parsed.as_str(), reference);
+            assert_eq!(
+                serde_json::to_value(&parsed).unwrap(),
+                serde_json::json!(reference)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for reference in [
+            "",
+            "has space",
+            "tab\tinside",
+            "https://example.com/{template}",
+            "100%",
+            "%zz",
+            "1abc:def",
+            "a#b#c",
+            "<https://example.com>",
+        ] {
+            assert!(
+                UriReference::parse(reference).is_err(),
+                "{} should be rejected",
+                reference
+            );
+        }
+        assert!(serde_json::from_str::<UriReference>("\"not valid\"").is_err());
+    }
+
+    #[test]
+    fn test_scheme() {
+        let absolute = UriReference::parse("git+https://github.com/org/repo").unwrap();
+        assert_eq!(absolute.scheme(), Some("git+https"));
+        assert!(absolute.is_absolute());
+        assert_eq!(absolute.to_url().unwrap().host_str(), Some("github.com"));
+
+        let relative = UriReference::parse("./path/to:file").unwrap();
+        assert_eq!(relative.scheme(), None);
+        assert!(relative.to_url().is_none());
+    }
+}
@@ -0,0 +1,110 @@
+//! SLSA Verification Summary Attestation (VSA) predicate model and associated structures.
+//!
+//! A VSA records that a verifier, e.g. slsa-verifier, checked an artifact's attestations against
+//! a policy, and the SLSA levels it found the artifact to meet:
+//!
+//! ```json
+//! {
+//!   "verifier": {"id": "https://github.com/slsa-framework/slsa-verifier", "version": {"slsa-verifier": "2.5.1"}},
+//!   "timeVerified": "2024-05-01T12:00:00Z",
+//!   "resourceUri": "pkg:npm/hello-world@1.2.3",
+//!   "policy": {"uri": "https://example.com/slsa-policy/hello-world"},
+//!   "verificationResult": "PASSED",
+//!   "verifiedLevels": ["SLSA_BUILD_LEVEL_3"],
+//!   "slsaVersion": "1.0"
+//! }
+//! ```
+//!
+//! See: https://slsa.dev/spec/v1.0/verification_summary
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use url::Url;
+
+use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+use crate::models::intoto::uri::UriReference;
+
+/// A structure representing the SLSA Verification Summary v1 Predicate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationSummaryV1Predicate {
+    pub verifier: Verifier,
+    /// When the verification took place.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_verified: Option<DateTime<Utc>>,
+    /// The URI of the artifact the verification was requested for.
+    pub resource_uri: UriReference,
+    /// The policy the subject was verified against. Its `uri` identifies the policy.
+    pub policy: ResourceDescriptor,
+    /// The attestations the verification was based on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_attestations: Option<Vec<ResourceDescriptor>>,
+    pub verification_result: VerificationResult,
+    /// The levels the subject was verified to meet, e.g. `SLSA_BUILD_LEVEL_3`.
+    pub verified_levels: Vec<String>,
+    /// The number of the subject's transitive dependencies verified at each level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency_levels: Option<BTreeMap<String, u64>>,
+    /// The version of the SLSA spec used for the verification, `MAJOR.MINOR`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slsa_version: Option<String>,
+}
+
+/// The entity that performed the verification.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Verifier {
+    #[schemars(with = "Url")]
+    pub id: Url,
+    /// The versions of the verifier's components, by component name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<BTreeMap<String, String>>,
+}
+
+/// Whether the subject passed verification.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VerificationResult {
+    Passed,
+    Failed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_verification_summary_v1_predicate() {
+        let value = json!({
+            "verifier": {
+                "id": "https://github.com/slsa-framework/slsa-verifier",
+                "version": {"slsa-verifier": "2.5.1"}
+            },
+            "timeVerified": "2024-05-01T12:00:00Z",
+            "resourceUri": "pkg:npm/hello-world@1.2.3",
+            "policy": {
+                "uri": "https://example.com/slsa-policy/hello-world",
+                "digest": {"sha256": "a7f2b6f2d4d0c6f6bbd0ac66b38c3c91ad8ab3e6d0a0b5a1fd42ef2d0b3a4f1c"}
+            },
+            "inputAttestations": [{
+                "uri": "https://example.com/attestations/hello-world.intoto.jsonl",
+                "digest": {"sha256": "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c"}
+            }],
+            "verificationResult": "PASSED",
+            "verifiedLevels": ["SLSA_BUILD_LEVEL_3"],
+            "dependencyLevels": {"SLSA_BUILD_LEVEL_3": 5, "SLSA_BUILD_LEVEL_1": 2},
+            "slsaVersion": "1.0"
+        });
+        let predicate: VerificationSummaryV1Predicate =
+            serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(predicate.verification_result, VerificationResult::Passed);
+        assert_eq!(predicate.verified_levels, vec!["SLSA_BUILD_LEVEL_3"]);
+        assert_eq!(serde_json::to_value(&predicate).unwrap(), value);
+
+        let mut unknown = value;
+        unknown["verificationResult"] = json!("SKIPPED");
+        assert!(serde_json::from_value::<VerificationSummaryV1Predicate>(unknown).is_err());
+    }
+}
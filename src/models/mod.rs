@@ -1,5 +1,8 @@
 mod helpers;
+pub mod cdxa;
+pub mod dsse;
 pub mod intoto;
+pub mod rekor;
 pub mod sbom;
 
 // NOTE: Throughout the models, several of the Options have a serde attribute of `skip_serializing_if = "Option::is_none"`.
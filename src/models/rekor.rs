@@ -0,0 +1,384 @@
+//! Rekor transparency log entry models.
+//!
+//! [Rekor](https://github.com/sigstore/rekor) returns the entries of its log keyed by their
+//! UUID, as from `/api/v1/log/entries/{uuid}`:
+//!
+//! ```json
+//! {
+//!   "24296fb24b8ad77a...": {
+//!     "body": "eyJhcGlWZXJzaW9uIjoiMC4wLjEiLCJraW5kIjoiZHNzZSIsLi4ufQ==",
+//!     "integratedTime": 1700000000,
+//!     "logID": "c0d23d6ad406973f9559f3ba2d1ca01f84147d8ffc5b8445c224f98b9591801d",
+//!     "logIndex": 25915956,
+//!     "verification": {
+//!       "inclusionProof": {"logIndex": 21752525, "rootHash": "...", "treeSize": 21752530, "hashes": ["..."], "checkpoint": "..."},
+//!       "signedEntryTimestamp": "MEUCIQD..."
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! which deserializes into a `BTreeMap<String, RekorEntry>`. The body is what was logged, one of
+//! the [`EntryBody`] kinds, and is the leaf of the log's Merkle tree the inclusion proof is for.
+//! The proof and signed entry timestamp are modelled but not verified here, see `trust` for that.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::models::helpers::{b64_option_serde, b64_serde};
+
+/// An entry of a Rekor log.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RekorEntry {
+    /// The canonicalized body of the entry, decoded from base64.
+    #[serde(with = "b64_serde")]
+    #[schemars(with = "String")]
+    pub body: Vec<u8>,
+    /// When the entry was integrated into the log, in seconds since the epoch.
+    pub integrated_time: i64,
+    /// The hex SHA-256 digest of the log's key.
+    #[serde(rename = "logID")]
+    pub log_id: String,
+    /// The index of the entry across all the shards of the log.
+    pub log_index: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+    /// The attestation Rekor kept for the entry, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attestation: Option<Attestation>,
+}
+
+/// The log's evidence an entry is included in it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Verification {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inclusion_proof: Option<InclusionProof>,
+    /// The log's base64 signature over the entry's body, integrated time, log id and index: its
+    /// promise to include the entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_entry_timestamp: Option<String>,
+}
+
+/// A proof an entry is included in the Merkle tree of the log's current shard.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionProof {
+    /// The index of the entry in the shard's tree.
+    pub log_index: u64,
+    /// The hex root hash of the tree.
+    pub root_hash: String,
+    pub tree_size: u64,
+    /// The hex hashes of the proof, from the leaf up.
+    pub hashes: Vec<String>,
+    /// The log's signed note committing to the root hash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<String>,
+}
+
+/// An attestation kept with an entry.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct Attestation {
+    /// The attestation, decoded from base64: a DSSE envelope or the statement it wrapped.
+    #[serde(default, with = "b64_option_serde")]
+    #[schemars(with = "Option<String>")]
+    pub data: Option<Vec<u8>>,
+}
+
+/// The body of an entry, by its kind.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum EntryBody {
+    /// A DSSE envelope of an In-Toto statement, logged by `cosign attest`.
+    Intoto(IntotoEntry),
+    /// A DSSE envelope of any payload type.
+    Dsse(DsseEntry),
+    /// A signature over the digest of an artifact, logged by `cosign sign-blob`.
+    Hashedrekord(HashedRekordEntry),
+}
+
+/// An `intoto` entry, v0.0.1 or v0.0.2.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IntotoEntry {
+    pub api_version: String,
+    pub spec: IntotoSpec,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IntotoSpec {
+    pub content: IntotoContent,
+    /// The base64 PEM key or certificate the envelope was signed with, in v0.0.1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IntotoContent {
+    /// The envelope without its payload, in v0.0.2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub envelope: Option<IntotoEnvelope>,
+    /// The digest of the envelope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<Hash>,
+    /// The digest of the envelope's payload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_hash: Option<Hash>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IntotoEnvelope {
+    pub payload_type: String,
+    #[serde(default)]
+    pub signatures: Vec<IntotoSignature>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IntotoSignature {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyid: Option<String>,
+    /// The signature, base64 encoded twice.
+    pub sig: String,
+    /// The base64 PEM key or certificate the signature was made with.
+    pub public_key: String,
+}
+
+/// A `dsse` entry, v0.0.1.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DsseEntry {
+    pub api_version: String,
+    pub spec: DsseSpec,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DsseSpec {
+    /// The digest of the envelope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub envelope_hash: Option<Hash>,
+    /// The digest of the envelope's payload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_hash: Option<Hash>,
+    #[serde(default)]
+    pub signatures: Vec<DsseSignature>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DsseSignature {
+    /// The base64 signature.
+    pub signature: String,
+    /// The base64 PEM key or certificate the signature was made with.
+    pub verifier: String,
+}
+
+/// A `hashedrekord` entry, v0.0.1.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HashedRekordEntry {
+    pub api_version: String,
+    pub spec: HashedRekordSpec,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HashedRekordSpec {
+    pub data: HashedRekordData,
+    pub signature: HashedRekordSignature,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HashedRekordData {
+    /// The digest of the signed artifact.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<Hash>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HashedRekordSignature {
+    /// The base64 signature.
+    pub content: String,
+    pub public_key: HashedRekordPublicKey,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HashedRekordPublicKey {
+    /// The base64 PEM key or certificate the signature was made with.
+    pub content: String,
+}
+
+/// A digest, such as `sha256`, in hex.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct Hash {
+    pub algorithm: String,
+    pub value: String,
+}
+
+impl RekorEntry {
+    /// Parses the body of the entry, failing on kinds other than `intoto`, `dsse` and
+    /// `hashedrekord`.
+    pub fn decode_body(&self) -> Result<EntryBody, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+
+    /// Returns the inclusion proof of the entry, if the log returned one.
+    pub fn inclusion_proof(&self) -> Option<&InclusionProof> {
+        self.verification.as_ref()?.inclusion_proof.as_ref()
+    }
+
+    /// Returns the signed entry timestamp of the entry, if the log returned one.
+    pub fn signed_entry_timestamp(&self) -> Option<&str> {
+        self.verification
+            .as_ref()?
+            .signed_entry_timestamp
+            .as_deref()
+    }
+}
+
+impl EntryBody {
+    /// Returns the kind of the entry, as Rekor names it.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EntryBody::Intoto(_) => "intoto",
+            EntryBody::Dsse(_) => "dsse",
+            EntryBody::Hashedrekord(_) => "hashedrekord",
+        }
+    }
+
+    /// Returns the digest of what was signed: the payload of the envelope, or the artifact of a
+    /// `hashedrekord` entry.
+    pub fn payload_hash(&self) -> Option<&Hash> {
+        match self {
+            EntryBody::Intoto(entry) => entry.spec.content.payload_hash.as_ref(),
+            EntryBody::Dsse(entry) => entry.spec.payload_hash.as_ref(),
+            EntryBody::Hashedrekord(entry) => entry.spec.data.hash.as_ref(),
+        }
+    }
+
+    /// Returns the digest of the envelope of a `dsse` entry, as it was submitted to the log.
+    pub fn envelope_hash(&self) -> Option<&Hash> {
+        match self {
+            EntryBody::Dsse(entry) => entry.spec.envelope_hash.as_ref(),
+            EntryBody::Intoto(_) | EntryBody::Hashedrekord(_) => None,
+        }
+    }
+
+    /// Returns the base64 PEM keys or certificates the logged signatures were made with.
+    pub fn verifiers(&self) -> Vec<&str> {
+        match self {
+            EntryBody::Intoto(entry) => match &entry.spec.content.envelope {
+                Some(envelope) => envelope
+                    .signatures
+                    .iter()
+                    .map(|signature| signature.public_key.as_str())
+                    .collect(),
+                None => entry.spec.public_key.iter().map(String::as_str).collect(),
+            },
+            EntryBody::Dsse(entry) => entry
+                .spec
+                .signatures
+                .iter()
+                .map(|signature| signature.verifier.as_str())
+                .collect(),
+            EntryBody::Hashedrekord(entry) => {
+                vec![entry.spec.signature.public_key.content.as_str()]
+            }
+        }
+    }
+
+    /// Returns the number of signatures logged.
+    pub fn signatures(&self) -> usize {
+        match self {
+            EntryBody::Intoto(entry) => match &entry.spec.content.envelope {
+                Some(envelope) => envelope.signatures.len(),
+                None => usize::from(entry.spec.public_key.is_some()),
+            },
+            EntryBody::Dsse(entry) => entry.spec.signatures.len(),
+            EntryBody::Hashedrekord(_) => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose, Engine};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_rekor_entry() {
+        let body = json!({
+            "apiVersion": "0.0.1",
+            "kind": "dsse",
+            "spec": {
+                "envelopeHash": {"algorithm": "sha256", "value": "ab"},
+                "payloadHash": {"algorithm": "sha256", "value": "cd"},
+                "signatures": [{"signature": "c2ln", "verifier": "a2V5"}]
+            }
+        });
+        let response = json!({
+            "24296fb24b8ad77a": {
+                "body": general_purpose::STANDARD.encode(body.to_string()),
+                "integratedTime": 1_700_000_000,
+                "logID": "c0d23d6ad406973f9559f3ba2d1ca01f84147d8ffc5b8445c224f98b9591801d",
+                "logIndex": 25915956,
+                "verification": {"signedEntryTimestamp": "MEUC"}
+            }
+        });
+        let entries: BTreeMap<String, RekorEntry> =
+            serde_json::from_value(response.clone()).unwrap();
+        let entry = &entries["24296fb24b8ad77a"];
+        assert_eq!(entry.signed_entry_timestamp(), Some("MEUC"));
+        assert!(entry.inclusion_proof().is_none());
+        assert!(entry.attestation.is_none());
+        assert_eq!(serde_json::to_value(&entries).unwrap(), response);
+
+        let body = entry.decode_body().unwrap();
+        assert_eq!(body.kind(), "dsse");
+        assert_eq!(body.payload_hash().unwrap().value, "cd");
+        assert_eq!(body.envelope_hash().unwrap().value, "ab");
+        assert_eq!(body.verifiers(), vec!["a2V5"]);
+        assert_eq!(body.signatures(), 1);
+
+        let hashedrekord: EntryBody = serde_json::from_value(json!({
+            "apiVersion": "0.0.1",
+            "kind": "hashedrekord",
+            "spec": {
+                "data": {"hash": {"algorithm": "sha256", "value": "ef"}},
+                "signature": {"content": "c2ln", "publicKey": {"content": "a2V5"}}
+            }
+        }))
+        .unwrap();
+        assert_eq!(hashedrekord.payload_hash().unwrap().value, "ef");
+        assert_eq!(hashedrekord.verifiers(), vec!["a2V5"]);
+
+        let intoto: EntryBody = serde_json::from_value(json!({
+            "apiVersion": "0.0.2",
+            "kind": "intoto",
+            "spec": {"content": {
+                "envelope": {
+                    "payloadType": "application/vnd.in-toto+json",
+                    "signatures": [{"sig": "YzJsbg==", "publicKey": "a2V5"}]
+                },
+                "hash": {"algorithm": "sha256", "value": "01"},
+                "payloadHash": {"algorithm": "sha256", "value": "23"}
+            }}
+        }))
+        .unwrap();
+        assert_eq!(intoto.kind(), "intoto");
+        assert_eq!(intoto.payload_hash().unwrap().value, "23");
+
+        let rekord = json!({"apiVersion": "0.0.1", "kind": "rekord", "spec": {}});
+        assert!(serde_json::from_value::<EntryBody>(rekord).is_err());
+    }
+}
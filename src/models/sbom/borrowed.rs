@@ -0,0 +1,189 @@
+//! A zero-copy view of SPDX documents for reading huge SBOMs already in memory.
+//!
+//! The generated models own every string, so deserializing an SBOM copies each license, path and
+//! locator into a fresh allocation on top of the buffer it was read from. The models here borrow
+//! their strings from that buffer instead, as `Cow<str>`s that are only owned when the JSON string
+//! has escapes and has to be unescaped. They keep the same fields as the
+//! [interned models](super::interned), which suit reading many documents rather than one large
+//! one.
+//!
+//! Both SPDX 2.2 and 2.3 documents can be read. The documents aren't validated, so validate them
+//! first if that matters.
+
+use serde::{Deserialize, Deserializer};
+use std::borrow::Cow;
+
+/// An SPDX document, keeping its packages and relationships, borrowing from the JSON it was
+/// read from.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct BorrowedSbom<'a> {
+    #[serde(rename = "spdxVersion", borrow)]
+    pub spdx_version: Cow<'a, str>,
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(rename = "documentNamespace", borrow)]
+    pub document_namespace: Cow<'a, str>,
+    #[serde(default, borrow)]
+    pub packages: Vec<BorrowedPackage<'a>>,
+    #[serde(default, borrow)]
+    pub relationships: Vec<BorrowedRelationship<'a>>,
+}
+
+impl<'a> BorrowedSbom<'a> {
+    /// Reads a document from a string, borrowing its strings from it.
+    pub fn from_json(json: &'a str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Reads a document from bytes, borrowing its strings from them.
+    pub fn from_slice(json: &'a [u8]) -> serde_json::Result<Self> {
+        serde_json::from_slice(json)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct BorrowedPackage<'a> {
+    #[serde(rename = "SPDXID", borrow)]
+    pub spdxid: Cow<'a, str>,
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(rename = "versionInfo", default, deserialize_with = "borrow_optional")]
+    pub version_info: Option<Cow<'a, str>>,
+    #[serde(rename = "downloadLocation", borrow)]
+    pub download_location: Cow<'a, str>,
+    #[serde(
+        rename = "licenseConcluded",
+        default,
+        deserialize_with = "borrow_optional"
+    )]
+    pub license_concluded: Option<Cow<'a, str>>,
+    #[serde(
+        rename = "licenseDeclared",
+        default,
+        deserialize_with = "borrow_optional"
+    )]
+    pub license_declared: Option<Cow<'a, str>>,
+    #[serde(default, deserialize_with = "borrow_optional")]
+    pub supplier: Option<Cow<'a, str>>,
+    #[serde(default, borrow)]
+    pub checksums: Vec<BorrowedChecksum<'a>>,
+    #[serde(rename = "externalRefs", default, borrow)]
+    pub external_refs: Vec<BorrowedExternalRef<'a>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct BorrowedChecksum<'a> {
+    #[serde(borrow)]
+    pub algorithm: Cow<'a, str>,
+    #[serde(rename = "checksumValue", borrow)]
+    pub checksum_value: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct BorrowedExternalRef<'a> {
+    #[serde(rename = "referenceCategory", borrow)]
+    pub reference_category: Cow<'a, str>,
+    #[serde(rename = "referenceType", borrow)]
+    pub reference_type: Cow<'a, str>,
+    #[serde(rename = "referenceLocator", borrow)]
+    pub reference_locator: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct BorrowedRelationship<'a> {
+    #[serde(rename = "spdxElementId", borrow)]
+    pub spdx_element_id: Cow<'a, str>,
+    #[serde(rename = "relationshipType", borrow)]
+    pub relationship_type: Cow<'a, str>,
+    #[serde(rename = "relatedSpdxElement", borrow)]
+    pub related_spdx_element: Cow<'a, str>,
+}
+
+/// Deserializes an optional string, borrowing it as the other fields do. serde's `borrow` only
+/// applies to a `Cow` directly in a field, so one in an `Option` would always be owned.
+fn borrow_optional<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    Ok(Option::<Borrowed>::deserialize(deserializer)?.map(|borrowed| borrowed.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_json() {
+        let json = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/spdx_v23_sbom.json"
+        ))
+        .unwrap();
+        let sbom = BorrowedSbom::from_json(&json).unwrap();
+
+        assert_eq!(sbom.spdx_version, "SPDX-2.3");
+        assert_eq!(sbom.packages.len(), 2);
+        let lodash = &sbom.packages[0];
+        assert_eq!(lodash.name, "lodash");
+        assert!(matches!(lodash.name, Cow::Borrowed(_)));
+        assert!(matches!(
+            lodash.version_info,
+            Some(Cow::Borrowed("4.17.20"))
+        ));
+        assert!(matches!(
+            lodash.license_concluded,
+            Some(Cow::Borrowed("MIT"))
+        ));
+        assert_eq!(
+            lodash.external_refs[0].reference_locator,
+            "pkg:npm/lodash@4.17.20"
+        );
+    }
+
+    #[test]
+    fn test_escaped_strings_are_owned() {
+        let json = br#"{
+            "spdxVersion": "SPDX-2.3",
+            "name": "quoted \"name\"",
+            "documentNamespace": "https://example.com/sbom",
+            "packages": [{
+                "SPDXID": "SPDXRef-a",
+                "name": "a",
+                "versionInfo": "1.0.0",
+                "downloadLocation": "NOASSERTION",
+                "licenseConcluded": "MIT",
+                "licenseDeclared": "MIT",
+                "supplier": "Organization: Example"
+            }, {
+                "SPDXID": "SPDXRef-b",
+                "name": "b",
+                "versionInfo": "1.0.0\u002b1",
+                "downloadLocation": "NOASSERTION",
+                "licenseConcluded": "MIT\n",
+                "licenseDeclared": "\"MIT\"",
+                "supplier": "Organization: \"Example\""
+            }]
+        }"#;
+        let sbom = BorrowedSbom::from_slice(json).unwrap();
+
+        assert_eq!(sbom.name, "quoted \"name\"");
+        assert!(matches!(sbom.name, Cow::Owned(_)));
+        assert!(matches!(sbom.document_namespace, Cow::Borrowed(_)));
+        let (a, b) = (&sbom.packages[0], &sbom.packages[1]);
+        assert_eq!(a.license_concluded.as_deref(), Some("MIT"));
+        assert!(matches!(a.version_info, Some(Cow::Borrowed(_))));
+        assert!(matches!(a.license_concluded, Some(Cow::Borrowed(_))));
+        assert!(matches!(a.license_declared, Some(Cow::Borrowed(_))));
+        assert!(matches!(a.supplier, Some(Cow::Borrowed(_))));
+        assert_eq!(b.version_info.as_deref(), Some("1.0.0+1"));
+        assert!(matches!(b.version_info, Some(Cow::Owned(_))));
+        assert!(matches!(b.license_concluded, Some(Cow::Owned(_))));
+        assert!(matches!(b.license_declared, Some(Cow::Owned(_))));
+        assert!(matches!(b.supplier, Some(Cow::Owned(_))));
+        assert!(sbom.relationships.is_empty());
+        assert!(BorrowedSbom::from_slice(b"{\"packages\": 1}").is_err());
+    }
+}
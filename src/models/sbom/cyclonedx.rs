@@ -0,0 +1,150 @@
+//! CycloneDX SBOM model.
+//!
+//! See: https://cyclonedx.org/docs/1.6/json/
+//!
+//! Only the parts of a BOM that describe its software are modelled: the metadata, the components,
+//! with their identities and hashes, and the dependency graph between them. Other BOM fields,
+//! e.g. services or vulnerabilities, are accepted but not kept. Attestations, the `declarations`
+//! of CycloneDX 1.6, are modelled by [`crate::models::cdxa`].
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A struct representing a CycloneDX BOM.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CycloneDxBom {
+    /// Always `CycloneDX`.
+    pub bom_format: String,
+    /// The version of the CycloneDX spec, e.g. `1.5`.
+    pub spec_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<BomMetadata>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    /// The dependency graph of the components, by `bom-ref`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<Dependency>,
+}
+
+/// When, by what and for what the BOM was created.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct BomMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    /// The tools that created the BOM, as a list of tools (before 1.5) or an object of components
+    /// and services.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Value>,
+    /// The component the BOM describes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<Box<Component>>,
+}
+
+/// A software or hardware component.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct Component {
+    /// The type of component, e.g. `library`, `application` or `container`.
+    #[serde(rename = "type")]
+    pub component_type: String,
+    /// An identifier of the component, unique within the BOM, that other elements refer to it by.
+    #[serde(rename = "bom-ref", default, skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The package URL of the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpe: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hashes: Vec<ComponentHash>,
+    /// The license choices of the component, as license objects or SPDX expressions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub licenses: Vec<Value>,
+    /// The components this component contains.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+}
+
+/// A hash of a component.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+pub struct ComponentHash {
+    /// The algorithm, e.g. `SHA-256`.
+    pub alg: String,
+    /// The hash, as hex.
+    pub content: String,
+}
+
+/// The components a component depends on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Dependency {
+    /// The `bom-ref` of the depending component.
+    #[serde(rename = "ref")]
+    pub dependency_ref: String,
+    /// The `bom-ref`s of the components it depends on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_cyclonedx_bom() {
+        let value = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "version": 1,
+            "metadata": {
+                "timestamp": "2024-05-01T12:00:00Z",
+                "component": {"type": "application", "name": "hello-world", "version": "1.2.3"}
+            },
+            "components": [{
+                "type": "library",
+                "bom-ref": "pkg:npm/lodash@4.17.21",
+                "name": "lodash",
+                "version": "4.17.21",
+                "purl": "pkg:npm/lodash@4.17.21",
+                "hashes": [{
+                    "alg": "SHA-256",
+                    "content": "a7f2b6f2d4d0c6f6bbd0ac66b38c3c91ad8ab3e6d0a0b5a1fd42ef2d0b3a4f1c"
+                }],
+                "licenses": [{"license": {"id": "MIT"}}]
+            }],
+            "dependencies": [{"ref": "pkg:npm/lodash@4.17.21"}]
+        });
+        let bom: CycloneDxBom = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(bom.components[0].hashes[0].alg, "SHA-256");
+        assert_eq!(
+            bom.metadata
+                .as_ref()
+                .unwrap()
+                .component
+                .as_ref()
+                .unwrap()
+                .name,
+            "hello-world"
+        );
+        assert_eq!(serde_json::to_value(&bom).unwrap(), value);
+
+        let mut nameless = value;
+        nameless["components"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("name");
+        assert!(serde_json::from_value::<CycloneDxBom>(nameless).is_err());
+    }
+}
@@ -0,0 +1,319 @@
+//! A compact, interned view of SPDX documents for analysing many SBOMs at once.
+//!
+//! Large SBOMs repeat the same license IDs, checksum algorithms, relationship types and URIs
+//! thousands of times, and a corpus of them repeats them again across documents. The generated
+//! models keep a `String` for every occurrence, which dominates memory when many documents are
+//! loaded. The models here keep only the fields analyses usually need, as `Arc<str>`s shared
+//! through an [`Interner`], so each distinct string is allocated once however often it appears.
+//!
+//! Both SPDX 2.2 and 2.3 documents can be read. The documents aren't validated, so validate them
+//! first if that matters.
+
+use serde::{Deserialize, Deserializer};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::io::Read;
+use std::sync::Arc;
+
+/// A set of strings shared between the documents read with it.
+#[derive(Debug, Default)]
+pub struct Interner {
+    strings: HashSet<Arc<str>>,
+}
+
+impl Interner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared copy of a string, adding it if it isn't interned yet.
+    pub fn intern(&mut self, string: &str) -> Arc<str> {
+        if let Some(interned) = self.strings.get(string) {
+            return Arc::clone(interned);
+        }
+        let interned: Arc<str> = Arc::from(string);
+        self.strings.insert(Arc::clone(&interned));
+        interned
+    }
+
+    /// Returns the number of distinct strings interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if no strings are interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+/// An SPDX document, keeping its packages and relationships.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InternedSbom {
+    pub spdx_version: Arc<str>,
+    pub name: Arc<str>,
+    pub document_namespace: Arc<str>,
+    pub packages: Vec<InternedPackage>,
+    pub relationships: Vec<InternedRelationship>,
+}
+
+impl InternedSbom {
+    /// Reads a document, sharing its strings with the other documents read with `interner`.
+    /// Gzip or zstd compressed documents are decompressed while reading, with the `validation`
+    /// feature.
+    pub fn from_reader<R: Read>(reader: R, interner: &mut Interner) -> serde_json::Result<Self> {
+        #[cfg(feature = "validation")]
+        let reader = crate::input::decompress(reader).map_err(serde_json::Error::io)?;
+        // Deserialize impls can't be handed state, so the interner is lent to this thread
+        // while reading and taken back afterwards.
+        CURRENT.with(|current| std::mem::swap(&mut *current.borrow_mut(), interner));
+        let sbom = serde_json::from_reader::<_, Sbom>(std::io::BufReader::new(reader));
+        CURRENT.with(|current| std::mem::swap(&mut *current.borrow_mut(), interner));
+        sbom.map(Self::from)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InternedPackage {
+    pub spdxid: Arc<str>,
+    pub name: Arc<str>,
+    pub version_info: Option<Arc<str>>,
+    pub download_location: Arc<str>,
+    pub license_concluded: Option<Arc<str>>,
+    pub license_declared: Option<Arc<str>>,
+    pub supplier: Option<Arc<str>>,
+    pub checksums: Vec<InternedChecksum>,
+    pub external_refs: Vec<InternedExternalRef>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InternedChecksum {
+    pub algorithm: Arc<str>,
+    pub checksum_value: Arc<str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InternedExternalRef {
+    pub reference_category: Arc<str>,
+    pub reference_type: Arc<str>,
+    pub reference_locator: Arc<str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InternedRelationship {
+    pub spdx_element_id: Arc<str>,
+    pub relationship_type: Arc<str>,
+    pub related_spdx_element: Arc<str>,
+}
+
+thread_local! {
+    /// The interner lent to this thread by [`InternedSbom::from_reader`] while it reads.
+    static CURRENT: RefCell<Interner> = RefCell::new(Interner::new());
+}
+
+// The documents as read, interning their strings into the interner lent to the thread. Only
+// these private types can be deserialized, so only `from_reader` interns into it, while it has
+// the interner lent.
+
+#[derive(Deserialize)]
+struct Sbom {
+    #[serde(rename = "spdxVersion", deserialize_with = "intern")]
+    spdx_version: Arc<str>,
+    #[serde(deserialize_with = "intern")]
+    name: Arc<str>,
+    #[serde(rename = "documentNamespace", deserialize_with = "intern")]
+    document_namespace: Arc<str>,
+    #[serde(default)]
+    packages: Vec<Package>,
+    #[serde(default)]
+    relationships: Vec<Relationship>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    #[serde(rename = "SPDXID", deserialize_with = "intern")]
+    spdxid: Arc<str>,
+    #[serde(deserialize_with = "intern")]
+    name: Arc<str>,
+    #[serde(rename = "versionInfo", default, deserialize_with = "intern_option")]
+    version_info: Option<Arc<str>>,
+    #[serde(rename = "downloadLocation", deserialize_with = "intern")]
+    download_location: Arc<str>,
+    #[serde(
+        rename = "licenseConcluded",
+        default,
+        deserialize_with = "intern_option"
+    )]
+    license_concluded: Option<Arc<str>>,
+    #[serde(
+        rename = "licenseDeclared",
+        default,
+        deserialize_with = "intern_option"
+    )]
+    license_declared: Option<Arc<str>>,
+    #[serde(default, deserialize_with = "intern_option")]
+    supplier: Option<Arc<str>>,
+    #[serde(default)]
+    checksums: Vec<Checksum>,
+    #[serde(rename = "externalRefs", default)]
+    external_refs: Vec<ExternalRef>,
+}
+
+#[derive(Deserialize)]
+struct Checksum {
+    #[serde(deserialize_with = "intern")]
+    algorithm: Arc<str>,
+    #[serde(rename = "checksumValue", deserialize_with = "intern")]
+    checksum_value: Arc<str>,
+}
+
+#[derive(Deserialize)]
+struct ExternalRef {
+    #[serde(rename = "referenceCategory", deserialize_with = "intern")]
+    reference_category: Arc<str>,
+    #[serde(rename = "referenceType", deserialize_with = "intern")]
+    reference_type: Arc<str>,
+    #[serde(rename = "referenceLocator", deserialize_with = "intern")]
+    reference_locator: Arc<str>,
+}
+
+#[derive(Deserialize)]
+struct Relationship {
+    #[serde(rename = "spdxElementId", deserialize_with = "intern")]
+    spdx_element_id: Arc<str>,
+    #[serde(rename = "relationshipType", deserialize_with = "intern")]
+    relationship_type: Arc<str>,
+    #[serde(rename = "relatedSpdxElement", deserialize_with = "intern")]
+    related_spdx_element: Arc<str>,
+}
+
+impl From<Sbom> for InternedSbom {
+    fn from(sbom: Sbom) -> Self {
+        Self {
+            spdx_version: sbom.spdx_version,
+            name: sbom.name,
+            document_namespace: sbom.document_namespace,
+            packages: sbom.packages.into_iter().map(From::from).collect(),
+            relationships: sbom.relationships.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+impl From<Package> for InternedPackage {
+    fn from(package: Package) -> Self {
+        Self {
+            spdxid: package.spdxid,
+            name: package.name,
+            version_info: package.version_info,
+            download_location: package.download_location,
+            license_concluded: package.license_concluded,
+            license_declared: package.license_declared,
+            supplier: package.supplier,
+            checksums: package.checksums.into_iter().map(From::from).collect(),
+            external_refs: package.external_refs.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+impl From<Checksum> for InternedChecksum {
+    fn from(checksum: Checksum) -> Self {
+        Self {
+            algorithm: checksum.algorithm,
+            checksum_value: checksum.checksum_value,
+        }
+    }
+}
+
+impl From<ExternalRef> for InternedExternalRef {
+    fn from(external_ref: ExternalRef) -> Self {
+        Self {
+            reference_category: external_ref.reference_category,
+            reference_type: external_ref.reference_type,
+            reference_locator: external_ref.reference_locator,
+        }
+    }
+}
+
+impl From<Relationship> for InternedRelationship {
+    fn from(relationship: Relationship) -> Self {
+        Self {
+            spdx_element_id: relationship.spdx_element_id,
+            relationship_type: relationship.relationship_type,
+            related_spdx_element: relationship.related_spdx_element,
+        }
+    }
+}
+
+fn intern<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Arc<str>, D::Error> {
+    let string = std::borrow::Cow::<str>::deserialize(deserializer)?;
+    Ok(CURRENT.with(|current| current.borrow_mut().intern(&string)))
+}
+
+fn intern_option<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Arc<str>>, D::Error> {
+    let string = Option::<std::borrow::Cow<str>>::deserialize(deserializer)?;
+    Ok(string.map(|string| CURRENT.with(|current| current.borrow_mut().intern(&string))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        std::fs::read(format!(
+            "{}/tests/fixtures/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let mut interner = Interner::new();
+        let sbom =
+            InternedSbom::from_reader(&fixture("spdx_v23_sbom.json")[..], &mut interner).unwrap();
+
+        assert_eq!(&*sbom.spdx_version, "SPDX-2.3");
+        assert_eq!(sbom.packages.len(), 2);
+        let lodash = &sbom.packages[0];
+        assert_eq!(&*lodash.name, "lodash");
+        assert_eq!(
+            &*lodash.external_refs[0].reference_locator,
+            "pkg:npm/lodash@4.17.20"
+        );
+        assert!(!interner.is_empty());
+        // The thread's interner is only lent while reading.
+        assert!(CURRENT.with(|current| current.borrow().is_empty()));
+    }
+
+    #[test]
+    fn test_strings_are_shared() {
+        let mut interner = Interner::new();
+        let first =
+            InternedSbom::from_reader(&fixture("spdx_v23_sbom.json")[..], &mut interner).unwrap();
+        let interned = interner.len();
+        let second =
+            InternedSbom::from_reader(&fixture("spdx_v23_sbom.json")[..], &mut interner).unwrap();
+
+        // Reading the same document again adds no strings and shares those of the first.
+        assert_eq!(interner.len(), interned);
+        assert!(Arc::ptr_eq(
+            &first.packages[0].name,
+            &second.packages[0].name
+        ));
+        assert!(Arc::ptr_eq(
+            &first.packages[0].external_refs[0].reference_type,
+            &first.packages[1].external_refs[0].reference_type
+        ));
+    }
+
+    #[test]
+    fn test_from_reader_invalid() {
+        let mut interner = Interner::new();
+        interner.intern("MIT");
+        assert!(InternedSbom::from_reader(&b"{\"packages\": 1}"[..], &mut interner).is_err());
+        // The interner is given back even when reading fails.
+        assert_eq!(interner.len(), 1);
+    }
+}
@@ -3,5 +3,9 @@
 
 pub mod spdx23;
 pub mod spdx22;
+pub mod borrowed;
+pub mod cyclonedx;
+pub mod interned;
+pub mod licenses;
 mod spdx23_test;
 mod spdx22_test;
\ No newline at end of file
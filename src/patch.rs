@@ -0,0 +1,141 @@
+//! Applying JSON patches to documents.
+//!
+//! Attestations that are slightly off, such as a misspelled field or a digest under the wrong
+//! algorithm, can be fixed in bulk with a single patch instead of regenerating them. Both
+//! JSON Patch (RFC 6902), a list of operations, and JSON Merge Patch (RFC 7386), an object
+//! merged into the document, are supported.
+//!
+//! Patching doesn't re-sign anything, so patch statements before they are signed, or sign them
+//! again afterwards.
+
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+
+use crate::validate::{policy::Policy, report::ValidationReport};
+
+/// A JSON Patch or JSON Merge Patch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Patch {
+    /// An RFC 6902 JSON Patch, applied atomically.
+    Json(json_patch::Patch),
+    /// An RFC 7386 JSON Merge Patch.
+    Merge(Value),
+}
+
+impl Patch {
+    /// Reads a patch from JSON: an array is a JSON Patch, and an object a JSON Merge Patch.
+    pub fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::Array(_) => serde_json::from_value(value)
+                .map(Patch::Json)
+                .map_err(|e| anyhow!("Invalid JSON Patch: {}", e)),
+            Value::Object(_) => Ok(Patch::Merge(value)),
+            _ => Err(anyhow!(
+                "A patch must be an array of JSON Patch operations or a JSON Merge Patch object"
+            )),
+        }
+    }
+}
+
+/// Applies a patch to a document in place.
+///
+/// If any operation of a JSON Patch fails, including a failed `test`, the document is left
+/// unchanged.
+pub fn apply_patch(document: &mut Value, patch: &Patch) -> Result<()> {
+    match patch {
+        Patch::Json(patch) => {
+            json_patch::patch(document, patch).map_err(|e| anyhow!("Failed to apply patch: {}", e))
+        }
+        Patch::Merge(patch) => {
+            json_patch::merge(document, patch);
+            Ok(())
+        }
+    }
+}
+
+/// Applies a patch to a copy of an In-Toto statement and validates the result with the policy,
+/// returning the patched statement and the findings.
+pub fn patch_statement(
+    statement: &Value,
+    patch: &Patch,
+    policy: &Policy,
+) -> Result<(Value, ValidationReport)> {
+    let mut patched = statement.clone();
+    apply_patch(&mut patched, patch)?;
+    let report = crate::attestations::check_statement(&patched, policy);
+    Ok((patched, report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn statement() -> Value {
+        json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha-256": "abcd1234"}}],
+            "predicateType": "https://example.com/predicate/v1",
+            "predicat": {"key": "value"}
+        })
+    }
+
+    #[test]
+    fn test_apply_json_patch() {
+        let patch = Patch::from_value(json!([
+            {"op": "test", "path": "/subject/0/name", "value": "example"},
+            {"op": "move", "from": "/predicat", "path": "/predicate"}
+        ]))
+        .unwrap();
+
+        let mut document = statement();
+        apply_patch(&mut document, &patch).unwrap();
+        assert_eq!(document["predicate"], json!({"key": "value"}));
+        assert!(document.get("predicat").is_none());
+    }
+
+    #[test]
+    fn test_apply_json_patch_atomic() {
+        let patch = Patch::from_value(json!([
+            {"op": "remove", "path": "/predicat"},
+            {"op": "test", "path": "/subject/0/name", "value": "other"}
+        ]))
+        .unwrap();
+
+        let mut document = statement();
+        assert!(apply_patch(&mut document, &patch).is_err());
+        assert_eq!(document, statement());
+    }
+
+    #[test]
+    fn test_apply_merge_patch() {
+        let patch = Patch::from_value(json!({
+            "predicat": null,
+            "predicate": {"key": "value"},
+            "subject": [{"name": "example", "digest": {"sha256": "ef567890"}}]
+        }))
+        .unwrap();
+
+        let mut document = statement();
+        apply_patch(&mut document, &patch).unwrap();
+        assert!(document.get("predicat").is_none());
+        assert_eq!(document["subject"][0]["digest"]["sha256"], "ef567890");
+        assert!(Patch::from_value(json!("not a patch")).is_err());
+        assert!(Patch::from_value(json!([{"op": "unknown"}])).is_err());
+    }
+
+    #[test]
+    fn test_patch_statement() {
+        let policy = Policy::default();
+        let (_, report) = patch_statement(&statement(), &Patch::Merge(json!({})), &policy).unwrap();
+        assert!(report.has_errors());
+
+        let fix = Patch::from_value(json!([
+            {"op": "move", "from": "/subject/0/digest/sha-256", "path": "/subject/0/digest/sha256"}
+        ]))
+        .unwrap();
+        let (patched, report) = patch_statement(&statement(), &fix, &policy).unwrap();
+        assert!(!report.has_errors(), "{:?}", report);
+        assert_eq!(patched["subject"][0]["digest"]["sha256"], "abcd1234");
+    }
+}
@@ -0,0 +1,288 @@
+//! Plugins contributing document types Spector doesn't support itself.
+//!
+//! A plugin implements [`DocumentPlugin`] for its document type: how to recognize a document, its
+//! JSON schema, its semantic checks and the name it goes by on the command line. Plugins are
+//! registered with [`PluginRegistry::register`] on the [global](PluginRegistry::global) registry,
+//! typically at the start of `main` of a binary built against Spector, so organizations can ship
+//! proprietary attestation formats without forking it.
+//!
+//! With the `dynamic-plugins` feature, plugins can also be loaded from shared libraries with
+//! [`PluginRegistry::load`]. The library exports a [`RegisterPlugins`] function named
+//! `spector_register_plugins`, which registers its plugins on the registry it is given:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! pub fn spector_register_plugins(registry: &PluginRegistry) -> anyhow::Result<()> {
+//!     registry.register(AcmeBuildRecord)
+//! }
+//! ```
+//!
+//! Registered plugins are used by [`crate::validate::detect`] for documents no built-in format
+//! matches, and by `spector validate plugin`.
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+use std::sync::{Arc, OnceLock, RwLock};
+use tracing::{debug, instrument};
+
+use crate::schema::{cache::SchemaCache, registry::SchemaRegistry};
+use crate::validate::policy::Policy;
+use crate::validate::report::{Finding, ValidationReport};
+use crate::validate::suggest::describe_schema_error;
+
+/// The name of the function shared libraries export to register their plugins.
+#[cfg(feature = "dynamic-plugins")]
+pub const REGISTER_SYMBOL: &str = "spector_register_plugins";
+
+/// The signature of the function shared libraries export to register their plugins.
+#[cfg(feature = "dynamic-plugins")]
+pub type RegisterPlugins = fn(&PluginRegistry) -> Result<()>;
+
+/// A document type contributed by a plugin.
+pub trait DocumentPlugin: Send + Sync {
+    /// A stable, kebab-case name for the document type, e.g. `acme-build-record`, used to select
+    /// it on the command line. It must not be the name of a built-in schema.
+    fn name(&self) -> &'static str;
+
+    /// Returns true if the document is of this type, from the fields that identify it.
+    fn detect(&self, document: &Value) -> bool;
+
+    /// Returns the JSON schema of the document type.
+    fn schema(&self) -> Value;
+
+    /// Runs the semantic checks of the document type on a document that is valid against its
+    /// schema.
+    fn validate(&self, document: &Value, policy: &Policy) -> ValidationReport;
+}
+
+/// The registered plugins, in registration order.
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: RwLock<Vec<Arc<dyn DocumentPlugin>>>,
+    // Libraries are never unloaded, as their plugins can be in use anywhere.
+    #[cfg(feature = "dynamic-plugins")]
+    libraries: std::sync::Mutex<Vec<libloading::Library>>,
+}
+
+impl PluginRegistry {
+    /// Returns an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the registry shared by the whole process, which Spector looks plugins up in.
+    pub fn global() -> &'static PluginRegistry {
+        static GLOBAL: OnceLock<PluginRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(PluginRegistry::new)
+    }
+
+    /// Registers a plugin, failing if its name is taken by another plugin or a built-in schema.
+    pub fn register(&self, plugin: impl DocumentPlugin + 'static) -> Result<()> {
+        let name = plugin.name();
+        if SchemaRegistry::get(name).is_some() {
+            bail!("Plugin {} has the name of a built-in schema", name);
+        }
+        let mut plugins = self.plugins.write().unwrap_or_else(|e| e.into_inner());
+        if plugins.iter().any(|registered| registered.name() == name) {
+            bail!("A plugin named {} is already registered", name);
+        }
+        plugins.push(Arc::new(plugin));
+        debug!(name, "Registered plugin");
+        Ok(())
+    }
+
+    /// Returns every registered plugin.
+    pub fn plugins(&self) -> Vec<Arc<dyn DocumentPlugin>> {
+        self.plugins
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Returns the plugin with the given name.
+    pub fn get(&self, name: &str) -> Option<Arc<dyn DocumentPlugin>> {
+        self.plugins()
+            .into_iter()
+            .find(|plugin| plugin.name() == name)
+    }
+
+    /// Returns the first plugin, in registration order, that detects the document as its type.
+    pub fn detect(&self, document: &Value) -> Option<Arc<dyn DocumentPlugin>> {
+        self.plugins()
+            .into_iter()
+            .find(|plugin| plugin.detect(document))
+    }
+
+    /// Loads a shared library and calls its `spector_register_plugins` function with this
+    /// registry.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialization code, and its plugins are called through the
+    /// unstable Rust ABI, so the library must be built with the same compiler, and the same
+    /// version and features of Spector, as the program loading it.
+    #[cfg(feature = "dynamic-plugins")]
+    pub unsafe fn load(&self, path: &std::path::Path) -> Result<()> {
+        let library = libloading::Library::new(path)
+            .map_err(|e| anyhow!("Failed to load plugin {}: {}", path.display(), e))?;
+        let result = match library.get::<RegisterPlugins>(REGISTER_SYMBOL.as_bytes()) {
+            Ok(register) => register(self),
+            Err(e) => Err(anyhow!(
+                "Plugin {} has no {} function: {}",
+                path.display(),
+                REGISTER_SYMBOL,
+                e
+            )),
+        };
+        // Plugins registered before a failure still refer to the library.
+        self.libraries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(library);
+        result
+    }
+}
+
+/// Validates a document against the plugin's schema, then runs its semantic checks if it is
+/// valid.
+///
+/// Schema violations are reported as `schema-violation` errors. Only a schema that doesn't
+/// compile fails the whole validation.
+#[instrument(skip_all, fields(plugin = plugin.name()))]
+pub fn validate(
+    plugin: &dyn DocumentPlugin,
+    document: &Value,
+    policy: &Policy,
+) -> Result<ValidationReport> {
+    let schema = plugin.schema();
+    let compiled = SchemaCache::global()
+        .get_or_compile(&schema)
+        .map_err(|e| anyhow!("Invalid schema for plugin {}: {}", plugin.name(), e))?;
+
+    let mut report = ValidationReport::new();
+    if let Err(errors) = compiled.validate(document) {
+        for error in errors {
+            report.push(Finding::error(
+                "schema-violation",
+                error.instance_path.to_string(),
+                describe_schema_error(&schema, &error),
+            ));
+        }
+    }
+    if !report.has_errors() {
+        report.extend(plugin.validate(document, policy).findings);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct BuildRecord;
+
+    impl DocumentPlugin for BuildRecord {
+        fn name(&self) -> &'static str {
+            "test-build-record"
+        }
+
+        fn detect(&self, document: &Value) -> bool {
+            document.get("recordType") == Some(&json!("https://example.com/build-record/v1"))
+        }
+
+        fn schema(&self) -> Value {
+            json!({
+                "type": "object",
+                "properties": {
+                    "recordType": {"type": "string"},
+                    "builder": {"type": "string"}
+                },
+                "required": ["recordType", "builder"],
+                "additionalProperties": false
+            })
+        }
+
+        fn validate(&self, document: &Value, _policy: &Policy) -> ValidationReport {
+            let mut report = ValidationReport::new();
+            if document["builder"].as_str() == Some("") {
+                report.push(Finding::error(
+                    "empty-builder",
+                    "/builder",
+                    "builder is empty",
+                ));
+            }
+            report
+        }
+    }
+
+    struct Named(&'static str);
+
+    impl DocumentPlugin for Named {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn detect(&self, _document: &Value) -> bool {
+            false
+        }
+
+        fn schema(&self) -> Value {
+            json!({})
+        }
+
+        fn validate(&self, _document: &Value, _policy: &Policy) -> ValidationReport {
+            ValidationReport::new()
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = PluginRegistry::new();
+        registry.register(BuildRecord).unwrap();
+        registry.register(Named("other")).unwrap();
+        assert!(registry
+            .register(Named("test-build-record"))
+            .unwrap_err()
+            .to_string()
+            .contains("already registered"));
+        assert!(registry
+            .register(Named("spdx-v23"))
+            .unwrap_err()
+            .to_string()
+            .contains("built-in schema"));
+
+        assert_eq!(registry.plugins().len(), 2);
+        assert_eq!(registry.get("other").unwrap().name(), "other");
+        let record = json!({"recordType": "https://example.com/build-record/v1", "builder": "ci"});
+        assert_eq!(
+            registry.detect(&record).unwrap().name(),
+            "test-build-record"
+        );
+        assert!(registry.detect(&json!({"builder": "ci"})).is_none());
+    }
+
+    #[test]
+    fn test_validate() {
+        let policy = Policy::default();
+        let valid = json!({"recordType": "https://example.com/build-record/v1", "builder": "ci"});
+        assert!(!validate(&BuildRecord, &valid, &policy)
+            .unwrap()
+            .has_errors());
+
+        let empty = json!({"recordType": "https://example.com/build-record/v1", "builder": ""});
+        let report = validate(&BuildRecord, &empty, &policy).unwrap();
+        assert_eq!(report.findings[0].code, "empty-builder");
+
+        let misspelled =
+            json!({"recordType": "https://example.com/build-record/v1", "Builder": ""});
+        let report = validate(&BuildRecord, &misspelled, &policy).unwrap();
+        assert!(report
+            .findings
+            .iter()
+            .all(|finding| finding.code == "schema-violation"));
+        assert!(report.findings[0]
+            .message
+            .contains("did you mean `builder` instead of `Builder`?"));
+    }
+}
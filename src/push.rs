@@ -0,0 +1,203 @@
+//! Uploading of validated documents to an ingestion endpoint.
+//!
+//! Documents are posted as JSON together with their validation report, so validation and
+//! ingestion into the Kusari platform, or any other HTTPS endpoint accepting the same payload,
+//! happen in one step.
+
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+use crate::validate::report::ValidationReport;
+
+/// The environment variable overriding the configured endpoint.
+pub const ENDPOINT_ENV: &str = "SPECTOR_PUSH_URL";
+
+/// The environment variable overriding the configured auth token.
+pub const TOKEN_ENV: &str = "SPECTOR_PUSH_TOKEN";
+
+/// Where to push documents to and how to authenticate.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PushConfig {
+    /// The URL documents are posted to.
+    pub endpoint: Option<String>,
+    /// A token sent as a bearer token in the `Authorization` header.
+    pub token: Option<String>,
+}
+
+impl PushConfig {
+    /// Reads a JSON config file.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let config_str = std::fs::read_to_string(path)?;
+        serde_json::from_str(&config_str)
+            .map_err(|e| anyhow!("Invalid config file {}: {}", path.display(), e))
+    }
+
+    /// Returns the default config file path, `$XDG_CONFIG_HOME/spector/config.json` or
+    /// `~/.config/spector/config.json`.
+    pub fn default_path() -> Option<PathBuf> {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .map(|dir| dir.join("spector").join("config.json"))
+    }
+
+    /// Overrides the config with the `SPECTOR_PUSH_URL` and `SPECTOR_PUSH_TOKEN` environment
+    /// variables, if they are set.
+    pub fn with_env(mut self) -> Self {
+        if let Ok(endpoint) = std::env::var(ENDPOINT_ENV) {
+            self.endpoint = Some(endpoint);
+        }
+        if let Ok(token) = std::env::var(TOKEN_ENV) {
+            self.token = Some(token);
+        }
+        self
+    }
+}
+
+/// The payload posted for a document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Submission<'a> {
+    /// The kind of document, named as in the schema registry, e.g. `in-toto-v1`.
+    pub document_type: &'a str,
+    pub document: &'a Value,
+    pub report: &'a ValidationReport,
+}
+
+/// Posts a submission to the configured endpoint.
+///
+/// Tokens are only sent over HTTPS, or plain HTTP to the local machine, so they can't leak to
+/// the network through a mistyped URL.
+pub fn push(config: &PushConfig, submission: &Submission) -> Result<()> {
+    let endpoint = config.endpoint.as_deref().ok_or_else(|| {
+        anyhow!(
+            "No endpoint to push to, set one with --endpoint, {} or the config file",
+            ENDPOINT_ENV
+        )
+    })?;
+    let url = check_endpoint(endpoint, config.token.is_some())?;
+
+    let mut request = reqwest::blocking::Client::new()
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(serde_json::to_vec(submission)?);
+    if let Some(token) = &config.token {
+        request = request.bearer_auth(token);
+    }
+    let response = request
+        .send()
+        .map_err(|e| anyhow!("Failed to push document to {}: {}", endpoint, e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().unwrap_or_default();
+        bail!(
+            "Failed to push document to {}: {} {}",
+            endpoint,
+            status,
+            body.trim()
+        );
+    }
+    Ok(())
+}
+
+/// Parses an endpoint, refusing to send a token anywhere but over HTTPS or to the local machine.
+pub(crate) fn check_endpoint(endpoint: &str, has_token: bool) -> Result<Url> {
+    let url = Url::parse(endpoint).map_err(|e| anyhow!("Invalid endpoint {}: {}", endpoint, e))?;
+    let is_local = matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+    match url.scheme() {
+        "https" => {}
+        "http" if is_local || !has_token => {}
+        "http" => bail!("Refusing to send a token to {} over plain HTTP", endpoint),
+        scheme => bail!("Unsupported endpoint scheme {}", scheme),
+    }
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{serve, Response};
+    use crate::validate::report::Finding;
+    use serde_json::json;
+
+    fn report() -> ValidationReport {
+        ValidationReport {
+            findings: vec![Finding::warning("empty-predicate", "/predicate", "empty")],
+        }
+    }
+
+    #[test]
+    fn test_push() {
+        let (url, requests) = serve(|_| Response::new("202 Accepted"));
+        let config = PushConfig {
+            endpoint: Some(format!("{}/ingest", url)),
+            token: Some("secret".to_string()),
+        };
+        let document = json!({"_type": "https://in-toto.io/Statement/v1"});
+        let report = report();
+        let submission = Submission {
+            document_type: "in-toto-v1",
+            document: &document,
+            report: &report,
+        };
+
+        push(&config, &submission).unwrap();
+        let request = requests.recv().unwrap();
+        assert_eq!((request.method(), request.path()), ("POST", "/ingest"));
+        assert_eq!(request.header("authorization"), Some("Bearer secret"));
+        assert_eq!(
+            request.json(),
+            json!({
+                "documentType": "in-toto-v1",
+                "document": {"_type": "https://in-toto.io/Statement/v1"},
+                "report": {"findings": [{
+                    "code": "empty-predicate",
+                    "severity": "warning",
+                    "path": "/predicate",
+                    "message": "empty"
+                }]}
+            })
+        );
+    }
+
+    #[test]
+    fn test_push_error_status() {
+        let (url, _requests) = serve(|_| Response::new("401 Unauthorized"));
+        let config = PushConfig {
+            endpoint: Some(format!("{}/ingest", url)),
+            token: None,
+        };
+        let document = json!({});
+        let report = ValidationReport::new();
+        let submission = Submission {
+            document_type: "spdx-v23",
+            document: &document,
+            report: &report,
+        };
+
+        let err = push(&config, &submission).unwrap_err();
+        assert!(err.to_string().contains("401 Unauthorized"));
+    }
+
+    #[test]
+    fn test_push_refuses_token_over_http() {
+        let config = PushConfig {
+            endpoint: Some("http://ingest.example.com/".to_string()),
+            token: Some("secret".to_string()),
+        };
+        let document = json!({});
+        let report = ValidationReport::new();
+        let submission = Submission {
+            document_type: "spdx-v23",
+            document: &document,
+            report: &report,
+        };
+
+        let err = push(&config, &submission).unwrap_err();
+        assert!(err.to_string().contains("over plain HTTP"));
+    }
+}
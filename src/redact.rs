@@ -0,0 +1,252 @@
+//! Redaction of sensitive fields before documents leave the host.
+//!
+//! Provenance often records more of the build environment than should be shared, such as
+//! environment variables, internal hostnames or tokens passed as parameters. A
+//! [`RedactionPolicy`] lists the fields to strip as JSON Pointer patterns, and whether each is
+//! replaced with a placeholder, keeping the shape of the document, or dropped entirely.
+//!
+//! Policies are deserialized from JSON, like the validation [`Policy`](crate::validate::policy::Policy):
+//!
+//! ```json
+//! {
+//!   "rules": [
+//!     {"pointer": "/predicate/invocation/environment"},
+//!     {"pointer": "/predicate/buildDefinition/internalParameters/*/token", "action": "drop"}
+//!   ]
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::validate::report::join_pointer;
+
+/// The value redacted fields are replaced with by default.
+pub const DEFAULT_REPLACEMENT: &str = "[REDACTED]";
+
+/// The fields to redact from documents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct RedactionPolicy {
+    /// The rules to apply, in order.
+    pub rules: Vec<RedactionRule>,
+    /// The value replaced fields get.
+    pub replacement: Value,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            replacement: Value::String(DEFAULT_REPLACEMENT.to_string()),
+        }
+    }
+}
+
+/// Fields to redact and how.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RedactionRule {
+    /// A JSON Pointer to the fields, where a `*` segment matches any key of an object or index
+    /// of an array, e.g. `/predicate/runDetails/byproducts/*/content`.
+    pub pointer: String,
+    /// What to do with the fields.
+    #[serde(default)]
+    pub action: RedactionAction,
+}
+
+/// What to do with a redacted field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RedactionAction {
+    /// Replace the value, keeping the field.
+    #[default]
+    Replace,
+    /// Remove the field, or the element from its array.
+    Drop,
+}
+
+impl RedactionPolicy {
+    /// Redacts the fields matching the rules in place, returning the pointers to those redacted.
+    ///
+    /// Pointers that match nothing are ignored. The document itself can be replaced but not
+    /// dropped, so a rule dropping the empty pointer does nothing.
+    pub fn redact(&self, document: &mut Value) -> Vec<String> {
+        let mut redacted = Vec::new();
+        for rule in &self.rules {
+            let mut matches = Vec::new();
+            collect_matches(document, &segments(&rule.pointer), "", &mut matches);
+            // Drop later array elements first, so the indices of earlier matches stay valid.
+            for pointer in matches.into_iter().rev() {
+                let done = match rule.action {
+                    RedactionAction::Replace => document
+                        .pointer_mut(&pointer)
+                        .map(|value| *value = self.replacement.clone())
+                        .is_some(),
+                    RedactionAction::Drop => remove(document, &pointer),
+                };
+                if done {
+                    redacted.push(pointer);
+                }
+            }
+        }
+        redacted
+    }
+}
+
+/// Splits a JSON Pointer into its unescaped reference tokens.
+fn segments(pointer: &str) -> Vec<String> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+/// Collects the pointers to the values under `value` matching the pattern segments.
+fn collect_matches(value: &Value, pattern: &[String], path: &str, matches: &mut Vec<String>) {
+    let Some((segment, rest)) = pattern.split_first() else {
+        matches.push(path.to_string());
+        return;
+    };
+    match value {
+        Value::Object(object) if segment == "*" => {
+            for (key, child) in object {
+                collect_matches(child, rest, &join_pointer(path, key), matches);
+            }
+        }
+        Value::Object(object) => {
+            if let Some(child) = object.get(segment) {
+                collect_matches(child, rest, &join_pointer(path, segment), matches);
+            }
+        }
+        Value::Array(array) => {
+            let indices = if segment == "*" {
+                0..array.len()
+            } else {
+                match segment.parse::<usize>() {
+                    Ok(i) if i < array.len() => i..i + 1,
+                    _ => return,
+                }
+            };
+            for i in indices {
+                collect_matches(&array[i], rest, &format!("{}/{}", path, i), matches);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Removes the value at a pointer from its parent, returning true if there was one.
+fn remove(document: &mut Value, pointer: &str) -> bool {
+    let Some((parent, token)) = pointer.rsplit_once('/') else {
+        return false;
+    };
+    let token = token.replace("~1", "/").replace("~0", "~");
+    match document.pointer_mut(parent) {
+        Some(Value::Object(object)) => object.remove(&token).is_some(),
+        Some(Value::Array(array)) => match token.parse::<usize>() {
+            Ok(i) if i < array.len() => {
+                array.remove(i);
+                true
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn provenance() -> Value {
+        json!({
+            "predicate": {
+                "invocation": {
+                    "environment": {"HOSTNAME": "build-7.internal", "TOKEN": "secret"}
+                },
+                "materials": [
+                    {"uri": "https://internal.example.com/a", "digest": {"sha256": "aa"}},
+                    {"uri": "https://github.com/org/repo", "digest": {"sha256": "bb"}},
+                    {"uri": "https://internal.example.com/b", "digest": {"sha256": "cc"}}
+                ],
+                "a/b": "escaped"
+            }
+        })
+    }
+
+    #[test]
+    fn test_redact_replace() {
+        let policy: RedactionPolicy = serde_json::from_value(json!({
+            "rules": [
+                {"pointer": "/predicate/invocation/environment/*"},
+                {"pointer": "/predicate/materials/*/uri"},
+                {"pointer": "/predicate/a~1b"},
+                {"pointer": "/predicate/missing"}
+            ]
+        }))
+        .unwrap();
+
+        let mut document = provenance();
+        let redacted = policy.redact(&mut document);
+        assert_eq!(redacted.len(), 6);
+        assert_eq!(
+            document["predicate"]["invocation"]["environment"],
+            json!({"HOSTNAME": "[REDACTED]", "TOKEN": "[REDACTED]"})
+        );
+        assert_eq!(document["predicate"]["materials"][1]["uri"], "[REDACTED]");
+        assert_eq!(
+            document["predicate"]["materials"][1]["digest"]["sha256"],
+            "bb"
+        );
+        assert_eq!(document["predicate"]["a/b"], "[REDACTED]");
+    }
+
+    #[test]
+    fn test_redact_drop() {
+        let policy = RedactionPolicy {
+            rules: vec![
+                RedactionRule {
+                    pointer: "/predicate/invocation/environment/TOKEN".to_string(),
+                    action: RedactionAction::Drop,
+                },
+                RedactionRule {
+                    pointer: "/predicate/materials/*".to_string(),
+                    action: RedactionAction::Drop,
+                },
+                RedactionRule {
+                    pointer: "".to_string(),
+                    action: RedactionAction::Drop,
+                },
+            ],
+            replacement: Value::Null,
+        };
+
+        let mut document = provenance();
+        let redacted = policy.redact(&mut document);
+        assert_eq!(
+            redacted,
+            vec![
+                "/predicate/invocation/environment/TOKEN",
+                "/predicate/materials/2",
+                "/predicate/materials/1",
+                "/predicate/materials/0",
+            ]
+        );
+        assert_eq!(
+            document["predicate"]["invocation"]["environment"],
+            json!({"HOSTNAME": "build-7.internal"})
+        );
+        assert_eq!(document["predicate"]["materials"], json!([]));
+    }
+
+    #[test]
+    fn test_deserialize_unknown_action() {
+        assert!(serde_json::from_value::<RedactionPolicy>(json!({
+            "rules": [{"pointer": "/a", "action": "hash"}]
+        }))
+        .is_err());
+    }
+}
@@ -0,0 +1,225 @@
+//! A cache of compiled JSON schemas.
+//!
+//! Compiling a schema resolves its references and builds a validator for every keyword, which
+//! for the larger schemas, like SPDX, costs far more than validating a document against it.
+//! Schemas are keyed by the SHA-256 hash of their JSON, so the same schema is only compiled
+//! once per process however it was loaded.
+//!
+//! Schemas whose local references loop back on themselves without descending into the instance,
+//! like `{"$ref": "#"}`, are rejected before compiling, as validating against them would recurse
+//! until the stack overflows.
+
+use anyhow::{anyhow, Result};
+use jsonschema::JSONSchema;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Compiled schemas keyed by the hash of their JSON.
+#[derive(Default)]
+pub struct SchemaCache {
+    schemas: Mutex<HashMap<[u8; 32], Arc<JSONSchema>>>,
+}
+
+impl SchemaCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cache shared by the whole process.
+    pub fn global() -> &'static SchemaCache {
+        static GLOBAL: OnceLock<SchemaCache> = OnceLock::new();
+        GLOBAL.get_or_init(SchemaCache::new)
+    }
+
+    /// Returns the compiled schema, compiling and caching it if it isn't cached yet.
+    pub fn get_or_compile(&self, schema: &Value) -> Result<Arc<JSONSchema>> {
+        self.get_or_compile_with_documents(schema, &BTreeMap::new())
+    }
+
+    /// Returns the compiled schema, resolving remote references to the given documents, keyed by
+    /// URL, instead of fetching them. The documents are part of the cache key, so the same schema
+    /// compiled with other documents is cached separately.
+    pub fn get_or_compile_with_documents(
+        &self,
+        schema: &Value,
+        documents: &BTreeMap<String, Value>,
+    ) -> Result<Arc<JSONSchema>> {
+        // Objects serialize with sorted keys, so equal schemas always hash the same.
+        let mut hasher = Sha256::new();
+        hasher.update(schema.to_string());
+        for (url, document) in documents {
+            hasher.update(url);
+            hasher.update(document.to_string());
+        }
+        let key: [u8; 32] = hasher.finalize().into();
+        if let Some(compiled) = self.lock().get(&key) {
+            return Ok(Arc::clone(compiled));
+        }
+
+        if let Some(pointer) = find_reference_cycle(schema) {
+            return Err(anyhow!(
+                "Failed to compile schema: reference cycle at #{}",
+                pointer
+            ));
+        }
+
+        // Compile without holding the lock, so other schemas can be looked up meanwhile.
+        let mut options = JSONSchema::options();
+        for (url, document) in documents {
+            options.with_document(url.clone(), document.clone());
+        }
+        let compiled = Arc::new(
+            options
+                .compile(schema)
+                .map_err(|e| anyhow!("Failed to compile schema: {}", e))?,
+        );
+        Ok(Arc::clone(self.lock().entry(key).or_insert(compiled)))
+    }
+
+    /// Returns the number of cached schemas.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if no schemas are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<[u8; 32], Arc<JSONSchema>>> {
+        // A panic while holding the lock can't leave the map inconsistent, so recover from it.
+        self.schemas.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Keywords whose subschemas apply to the same instance as the schema containing them.
+const IN_PLACE_KEYWORDS: [&str; 7] = ["allOf", "anyOf", "oneOf", "not", "if", "then", "else"];
+
+/// Returns the JSON pointer of a subschema that reaches itself through local references and
+/// in-place applicators only, if there is one.
+fn find_reference_cycle(schema: &Value) -> Option<String> {
+    let mut acyclic = HashSet::new();
+    let mut pointers = Vec::new();
+    collect_pointers(schema, String::new(), &mut pointers);
+    pointers
+        .into_iter()
+        .find_map(|pointer| visit(schema, pointer, &mut Vec::new(), &mut acyclic))
+}
+
+fn collect_pointers(value: &Value, pointer: String, pointers: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let escaped = key.replace('~', "~0").replace('/', "~1");
+                collect_pointers(child, format!("{}/{}", pointer, escaped), pointers);
+            }
+            pointers.push(pointer);
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter().enumerate() {
+                collect_pointers(child, format!("{}/{}", pointer, i), pointers);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn visit(
+    root: &Value,
+    pointer: String,
+    stack: &mut Vec<String>,
+    acyclic: &mut HashSet<String>,
+) -> Option<String> {
+    if acyclic.contains(&pointer) {
+        return None;
+    }
+    if stack.contains(&pointer) {
+        return Some(pointer);
+    }
+    let Some(Value::Object(map)) = root.pointer(&pointer) else {
+        acyclic.insert(pointer);
+        return None;
+    };
+
+    let mut next = Vec::new();
+    if let Some(Value::String(reference)) = map.get("$ref") {
+        // Only references within the schema itself can be followed without resolving it.
+        if reference == "#" || reference.starts_with("#/") {
+            next.push(reference[1..].to_string());
+        }
+    }
+    for keyword in IN_PLACE_KEYWORDS {
+        match map.get(keyword) {
+            Some(Value::Array(items)) => {
+                next.extend((0..items.len()).map(|i| format!("{}/{}/{}", pointer, keyword, i)))
+            }
+            Some(Value::Object(_)) => next.push(format!("{}/{}", pointer, keyword)),
+            _ => {}
+        }
+    }
+
+    stack.push(pointer);
+    for child in next {
+        if let Some(cycle) = visit(root, child, stack, acyclic) {
+            return Some(cycle);
+        }
+    }
+    let pointer = stack.pop().unwrap_or_default();
+    acyclic.insert(pointer);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_get_or_compile() {
+        let cache = SchemaCache::new();
+        let schema = json!({"type": "object", "required": ["name"]});
+
+        let compiled = cache.get_or_compile(&schema).unwrap();
+        assert!(compiled.is_valid(&json!({"name": "a"})));
+        assert!(!compiled.is_valid(&json!({})));
+
+        // The same schema, with its keys in another order, is served from the cache.
+        let reordered = json!({"required": ["name"], "type": "object"});
+        assert!(Arc::ptr_eq(
+            &compiled,
+            &cache.get_or_compile(&reordered).unwrap()
+        ));
+        assert_eq!(cache.len(), 1);
+
+        cache.get_or_compile(&json!({"type": "string"})).unwrap();
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_get_or_compile_invalid_schema() {
+        let cache = SchemaCache::new();
+        assert!(cache.get_or_compile(&json!({"type": 1})).is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_get_or_compile_reference_cycle() {
+        let cache = SchemaCache::new();
+        for schema in [
+            json!({"$ref": "#"}),
+            json!({"definitions": {"a": {"$ref": "#/definitions/b"}, "b": {"allOf": [{"$ref": "#/definitions/a"}]}}, "$ref": "#/definitions/a"}),
+        ] {
+            let err = cache.get_or_compile(&schema).unwrap_err();
+            assert!(err.to_string().contains("reference cycle"), "{}", err);
+        }
+        assert!(cache.is_empty());
+
+        // Recursing through a property descends into the instance, so it terminates.
+        let tree = json!({"type": "object", "properties": {"child": {"$ref": "#"}}});
+        let compiled = cache.get_or_compile(&tree).unwrap();
+        assert!(compiled.is_valid(&json!({"child": {"child": {}}})));
+    }
+}
@@ -0,0 +1,585 @@
+//! Compatibility checks between two versions of a schema.
+//!
+//! A change is breaking if a document that was valid against the old schema may be invalid
+//! against the new one, or if it removes a property that consumers of the documents may rely on.
+//! Breaking changes are reported as errors and backward-compatible changes as info findings,
+//! each at the path of the changed keyword, with references followed from the root schema.
+
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+use crate::validate::report::{Finding, Severity, ValidationReport};
+
+/// Keywords whose value may only grow in a backward-compatible change.
+const LOWER_BOUNDS: &[&str] = &["minimum", "exclusiveMinimum", "minLength", "minItems"];
+
+/// Keywords whose value may only shrink in a backward-compatible change.
+const UPPER_BOUNDS: &[&str] = &["maximum", "exclusiveMaximum", "maxLength", "maxItems"];
+
+/// Compares two versions of a schema, reporting every change between them.
+///
+/// Local references (`#/definitions/...`) are followed in both schemas, so changes to a
+/// definition are reported at every path it is used from. `anyOf` and `oneOf` variants are
+/// compared by position.
+pub fn check_compatibility(old: &Value, new: &Value) -> ValidationReport {
+    let mut checker = Checker {
+        old_root: old,
+        new_root: new,
+        visited: BTreeSet::new(),
+        report: ValidationReport::new(),
+    };
+    checker.compare(old, new, "");
+    checker.report
+}
+
+struct Checker<'a> {
+    old_root: &'a Value,
+    new_root: &'a Value,
+    /// The pairs of references being compared, so recursive schemas terminate.
+    visited: BTreeSet<(String, String)>,
+    report: ValidationReport,
+}
+
+impl<'a> Checker<'a> {
+    fn breaking(&mut self, code: &str, path: String, message: String) {
+        self.report.push(Finding::error(code, path, message));
+    }
+
+    fn compatible(&mut self, code: &str, path: String, message: String) {
+        self.report
+            .push(Finding::new(code, Severity::Info, path, message));
+    }
+
+    fn compare(&mut self, old: &'a Value, new: &'a Value, path: &str) {
+        let old_ref = old.get("$ref").and_then(Value::as_str);
+        let new_ref = new.get("$ref").and_then(Value::as_str);
+        if old_ref.is_none() && new_ref.is_none() {
+            return self.compare_resolved(old, new, path);
+        }
+
+        let key = (
+            old_ref.unwrap_or_default().to_string(),
+            new_ref.unwrap_or_default().to_string(),
+        );
+        if self.visited.insert(key.clone()) {
+            self.compare_resolved(old, new, path);
+            self.visited.remove(&key);
+        }
+    }
+
+    fn compare_resolved(&mut self, old: &'a Value, new: &'a Value, path: &str) {
+        let old = resolve(self.old_root, old);
+        let new = resolve(self.new_root, new);
+
+        let (old, new) = match (old, new) {
+            (Value::Bool(false), _) | (_, Value::Bool(true)) => return,
+            (_, Value::Bool(false)) => {
+                self.breaking(
+                    "schema-removed",
+                    display_path(path),
+                    "No value is valid against the new schema".to_string(),
+                );
+                return;
+            }
+            (Value::Object(old), Value::Object(new)) => (old, new),
+            (Value::Bool(true), Value::Object(new)) => {
+                if !new.is_empty() {
+                    self.breaking(
+                        "schema-restricted",
+                        display_path(path),
+                        "Constraints were added to a schema that accepted any value".to_string(),
+                    );
+                }
+                return;
+            }
+            _ => return,
+        };
+
+        self.compare_types(old, new, path);
+        self.compare_values(old, new, path);
+        self.compare_bounds(old, new, path);
+        self.compare_properties(old, new, path);
+        self.compare_subschemas(old, new, path);
+    }
+
+    fn compare_types(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        let old_types = types(old);
+        let new_types = types(new);
+        let path = format!("{}/type", path);
+        let removed = old_types
+            .iter()
+            .filter(|t| !accepts(&new_types, t))
+            .cloned()
+            .collect::<Vec<_>>();
+        let added = new_types
+            .iter()
+            .filter(|t| !accepts(&old_types, t))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !removed.is_empty() {
+            self.breaking(
+                "type-narrowed",
+                path,
+                format!(
+                    "Type narrowed from {} to {}",
+                    describe_types(&old_types),
+                    describe_types(&new_types)
+                ),
+            );
+        } else if !added.is_empty() {
+            self.compatible(
+                "type-widened",
+                path,
+                format!(
+                    "Type widened from {} to {}",
+                    describe_types(&old_types),
+                    describe_types(&new_types)
+                ),
+            );
+        }
+    }
+
+    fn compare_values(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        match (old.get("const"), new.get("const")) {
+            (Some(old), Some(new)) if old != new => self.breaking(
+                "const-changed",
+                format!("{}/const", path),
+                format!("Constant changed from {} to {}", old, new),
+            ),
+            (None, Some(new)) => self.breaking(
+                "const-added",
+                format!("{}/const", path),
+                format!("Value is now constrained to {}", new),
+            ),
+            (Some(_), None) => self.compatible(
+                "const-removed",
+                format!("{}/const", path),
+                "Value is no longer constrained to a constant".to_string(),
+            ),
+            _ => {}
+        }
+
+        let path = format!("{}/enum", path);
+        match (
+            old.get("enum").and_then(Value::as_array),
+            new.get("enum").and_then(Value::as_array),
+        ) {
+            (Some(old), Some(new)) => {
+                for value in old.iter().filter(|v| !new.contains(v)) {
+                    self.breaking(
+                        "enum-value-removed",
+                        path.clone(),
+                        format!("Value {} was removed", value),
+                    );
+                }
+                for value in new.iter().filter(|v| !old.contains(v)) {
+                    self.compatible(
+                        "enum-value-added",
+                        path.clone(),
+                        format!("Value {} was added", value),
+                    );
+                }
+            }
+            (None, Some(_)) => self.breaking(
+                "enum-added",
+                path,
+                "Values are now restricted to an enum".to_string(),
+            ),
+            (Some(_), None) => self.compatible(
+                "enum-removed",
+                path,
+                "Values are no longer restricted to an enum".to_string(),
+            ),
+            (None, None) => {}
+        }
+    }
+
+    fn compare_bounds(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        let bounds = LOWER_BOUNDS
+            .iter()
+            .map(|keyword| (keyword, true))
+            .chain(UPPER_BOUNDS.iter().map(|keyword| (keyword, false)));
+        for (keyword, is_lower) in bounds {
+            let path = format!("{}/{}", path, keyword);
+            match (
+                old.get(*keyword).and_then(Value::as_f64),
+                new.get(*keyword).and_then(Value::as_f64),
+            ) {
+                (Some(old), Some(new)) if old != new && (new > old) == is_lower => self.breaking(
+                    "constraint-narrowed",
+                    path,
+                    format!("{} narrowed from {} to {}", keyword, old, new),
+                ),
+                (Some(old), Some(new)) if old != new => self.compatible(
+                    "constraint-widened",
+                    path,
+                    format!("{} widened from {} to {}", keyword, old, new),
+                ),
+                (None, Some(new)) => self.breaking(
+                    "constraint-added",
+                    path,
+                    format!("{} of {} was added", keyword, new),
+                ),
+                (Some(old), None) => self.compatible(
+                    "constraint-removed",
+                    path,
+                    format!("{} of {} was removed", keyword, old),
+                ),
+                _ => {}
+            }
+        }
+
+        for keyword in ["pattern", "format"] {
+            let path = format!("{}/{}", path, keyword);
+            match (old.get(keyword), new.get(keyword)) {
+                (Some(old), Some(new)) if old != new => self.breaking(
+                    "constraint-changed",
+                    path,
+                    format!("{} changed from {} to {}", keyword, old, new),
+                ),
+                (None, Some(new)) => self.breaking(
+                    "constraint-added",
+                    path,
+                    format!("{} {} was added", keyword, new),
+                ),
+                (Some(old), None) => self.compatible(
+                    "constraint-removed",
+                    path,
+                    format!("{} {} was removed", keyword, old),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    fn compare_properties(
+        &mut self,
+        old: &'a Map<String, Value>,
+        new: &'a Map<String, Value>,
+        path: &str,
+    ) {
+        let old_required = required(old);
+        let new_required = required(new);
+        for name in new_required.difference(&old_required) {
+            self.breaking(
+                "required-added",
+                format!("{}/required", path),
+                format!("Property {} is now required", name),
+            );
+        }
+        for name in old_required.difference(&new_required) {
+            self.compatible(
+                "required-removed",
+                format!("{}/required", path),
+                format!("Property {} is no longer required", name),
+            );
+        }
+
+        let old_properties = old.get("properties").and_then(Value::as_object);
+        let new_properties = new.get("properties").and_then(Value::as_object);
+        for (name, old_property) in old_properties.into_iter().flatten() {
+            let property_path = format!("{}/properties/{}", path, escape(name));
+            match new_properties.and_then(|p| p.get(name)) {
+                Some(new_property) => self.compare(old_property, new_property, &property_path),
+                None => self.breaking(
+                    "property-removed",
+                    property_path,
+                    format!("Property {} was removed", name),
+                ),
+            }
+        }
+        for name in new_properties.into_iter().flat_map(Map::keys) {
+            if !old_properties
+                .map(|p| p.contains_key(name))
+                .unwrap_or(false)
+            {
+                self.compatible(
+                    "property-added",
+                    format!("{}/properties/{}", path, escape(name)),
+                    format!("Property {} was added", name),
+                );
+            }
+        }
+
+        let path = format!("{}/additionalProperties", path);
+        match (
+            old.get("additionalProperties"),
+            new.get("additionalProperties"),
+        ) {
+            (Some(old), Some(new)) => self.compare(old, new, &path),
+            (None, Some(Value::Bool(true))) | (None, None) => {}
+            (None, Some(new)) => self.compare(&Value::Bool(true), new, &path),
+            (Some(_), None) => self.compatible(
+                "constraint-removed",
+                path,
+                "Additional properties are no longer restricted".to_string(),
+            ),
+        }
+    }
+
+    fn compare_subschemas(
+        &mut self,
+        old: &'a Map<String, Value>,
+        new: &'a Map<String, Value>,
+        path: &str,
+    ) {
+        match (old.get("items"), new.get("items")) {
+            (Some(old_items), Some(new_items)) => {
+                self.compare(old_items, new_items, &format!("{}/items", path))
+            }
+            (None, Some(new_items)) => {
+                self.compare(&Value::Bool(true), new_items, &format!("{}/items", path))
+            }
+            _ => {}
+        }
+
+        for keyword in ["anyOf", "oneOf", "allOf"] {
+            let old_variants = old.get(keyword).and_then(Value::as_array);
+            let new_variants = new.get(keyword).and_then(Value::as_array);
+            let (old_variants, new_variants) = match (old_variants, new_variants) {
+                (Some(old), Some(new)) => (old, new),
+                (None, Some(_)) => {
+                    self.breaking(
+                        "constraint-added",
+                        format!("{}/{}", path, keyword),
+                        format!("{} was added", keyword),
+                    );
+                    continue;
+                }
+                (Some(_), None) => {
+                    self.compatible(
+                        "constraint-removed",
+                        format!("{}/{}", path, keyword),
+                        format!("{} was removed", keyword),
+                    );
+                    continue;
+                }
+                (None, None) => continue,
+            };
+
+            for (i, (old_variant, new_variant)) in old_variants.iter().zip(new_variants).enumerate()
+            {
+                self.compare(
+                    old_variant,
+                    new_variant,
+                    &format!("{}/{}/{}", path, keyword, i),
+                );
+            }
+            // Every allOf schema must match, so adding one narrows the schema, whereas adding an
+            // anyOf or oneOf variant widens it.
+            for i in old_variants.len()..new_variants.len() {
+                let path = format!("{}/{}/{}", path, keyword, i);
+                match keyword {
+                    "allOf" => self.breaking(
+                        "constraint-added",
+                        path,
+                        "An allOf schema was added".to_string(),
+                    ),
+                    _ => self.compatible(
+                        "variant-added",
+                        path,
+                        format!("An {} variant was added", keyword),
+                    ),
+                }
+            }
+            for i in new_variants.len()..old_variants.len() {
+                let path = format!("{}/{}/{}", path, keyword, i);
+                match keyword {
+                    "allOf" => self.compatible(
+                        "constraint-removed",
+                        path,
+                        "An allOf schema was removed".to_string(),
+                    ),
+                    _ => self.breaking(
+                        "variant-removed",
+                        path,
+                        format!("An {} variant was removed", keyword),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Follows a local reference, returning the schema itself if it isn't one or can't be resolved.
+fn resolve<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    let mut schema = schema;
+    // Chains of references are followed a bounded number of times in case they form a cycle.
+    for _ in 0..32 {
+        match schema
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|r| r.strip_prefix('#'))
+            .and_then(|pointer| root.pointer(pointer))
+        {
+            Some(target) => schema = target,
+            None => break,
+        }
+    }
+    schema
+}
+
+/// Returns the types a schema accepts, or every type if it doesn't restrict them.
+fn types(schema: &Map<String, Value>) -> BTreeSet<String> {
+    match schema.get("type") {
+        Some(Value::String(t)) => [t.clone()].into(),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        _ => BTreeSet::new(),
+    }
+}
+
+/// Returns true if a set of types from `types` accepts every value of type `t`.
+fn accepts(types: &BTreeSet<String>, t: &str) -> bool {
+    types.is_empty() || types.contains(t) || (t == "integer" && types.contains("number"))
+}
+
+fn describe_types(types: &BTreeSet<String>) -> String {
+    if types.is_empty() {
+        "any".to_string()
+    } else {
+        types.iter().cloned().collect::<Vec<_>>().join(" | ")
+    }
+}
+
+fn required(schema: &Map<String, Value>) -> BTreeSet<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// Escapes a property name for use in a JSON pointer.
+fn escape(name: &str) -> String {
+    name.replace('~', "~0").replace('/', "~1")
+}
+
+fn display_path(path: &str) -> String {
+    if path.is_empty() {
+        "/".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn codes(report: &ValidationReport, severity: Severity) -> Vec<(&str, &str)> {
+        report
+            .findings
+            .iter()
+            .filter(|f| f.severity == severity)
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_schemas_are_compatible() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"name": {"$ref": "#/definitions/Name"}},
+            "definitions": {"Name": {"type": "string"}}
+        });
+        assert!(check_compatibility(&schema, &schema).findings.is_empty());
+    }
+
+    #[test]
+    fn test_breaking_changes() {
+        let old = json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": {"type": ["string", "null"]},
+                "kind": {"$ref": "#/definitions/Kind"},
+                "removed": {"type": "string"},
+                "count": {"type": "integer", "minimum": 0}
+            },
+            "definitions": {"Kind": {"enum": ["a", "b"]}}
+        });
+        let new = json!({
+            "type": "object",
+            "required": ["name", "kind"],
+            "properties": {
+                "name": {"type": "string"},
+                "kind": {"$ref": "#/definitions/Kind"},
+                "count": {"type": "integer", "minimum": 1}
+            },
+            "definitions": {"Kind": {"enum": ["a"]}}
+        });
+
+        let report = check_compatibility(&old, &new);
+        assert_eq!(
+            codes(&report, Severity::Error),
+            vec![
+                ("required-added", "/required"),
+                ("constraint-narrowed", "/properties/count/minimum"),
+                ("enum-value-removed", "/properties/kind/enum"),
+                ("type-narrowed", "/properties/name/type"),
+                ("property-removed", "/properties/removed"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compatible_changes() {
+        let old = json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": {"type": "string", "enum": ["a"]},
+                "count": {"type": "integer"}
+            },
+            "additionalProperties": false
+        });
+        let new = json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "enum": ["a", "b"]},
+                "count": {"type": "number"},
+                "added": {"type": "string"}
+            }
+        });
+
+        let report = check_compatibility(&old, &new);
+        assert!(!report.has_errors(), "{:?}", report);
+        assert_eq!(
+            codes(&report, Severity::Info),
+            vec![
+                ("required-removed", "/required"),
+                ("type-widened", "/properties/count/type"),
+                ("enum-value-added", "/properties/name/enum"),
+                ("property-added", "/properties/added"),
+                ("constraint-removed", "/additionalProperties"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recursive_schemas_terminate() {
+        let schema = json!({
+            "$ref": "#/definitions/Node",
+            "definitions": {
+                "Node": {
+                    "type": "object",
+                    "properties": {"children": {"type": "array", "items": {"$ref": "#/definitions/Node"}}}
+                }
+            }
+        });
+        let mut new = schema.clone();
+        new["definitions"]["Node"]["required"] = json!(["children"]);
+
+        let report = check_compatibility(&schema, &new);
+        assert_eq!(
+            codes(&report, Severity::Error),
+            vec![("required-added", "/required")]
+        );
+    }
+}
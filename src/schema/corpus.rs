@@ -0,0 +1,471 @@
+//! Generation of a conformance corpus of valid and systematically broken documents.
+//!
+//! Every model in the schema registry gets valid documents, sampled from its schema and filled in
+//! with the values its semantic rules expect, and broken copies of them: each with a required
+//! field removed, a value replaced by one of the wrong type, or a malformed digest in an In-Toto
+//! digest set. A broken copy is only kept if Spector rejects it, so the expectations always match
+//! what this version enforces, and the errors it reports are recorded alongside.
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::path::Path;
+
+use super::registry::{SchemaEntry, SchemaRegistry};
+use super::sample::sample;
+use crate::attestations::check_statement;
+use crate::models::cdxa::{CycloneDxAttestation, BOM_FORMAT, MIN_SPEC_VERSION};
+use crate::models::intoto::csaf::{CSAF_VERSION, CSAF_VEX_CATEGORY};
+use crate::models::intoto::sarif::SARIF_VERSION;
+use crate::models::intoto::statement::STATEMENT_TYPE_V1;
+use crate::models::sbom::{spdx22::Spdx22Document, spdx23::Spdx23};
+use crate::validate::policy::Policy;
+use crate::validate::report::{join_pointer, Finding, ValidationReport};
+use crate::validate::rules::cdxa::check_cdxa;
+use crate::validate::suggest::describe_schema_error;
+use crate::validate::{GenericValidator, Validator};
+
+/// Whether a verifier should accept a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Expectation {
+    Valid,
+    Invalid,
+}
+
+/// How a document was derived from a valid one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CaseKind {
+    /// A valid document, unchanged.
+    Valid,
+    /// A field the schema requires was removed.
+    MissingRequired,
+    /// A value was replaced with one of another JSON type.
+    WrongType,
+    /// A digest value was replaced with a malformed one.
+    BadDigest,
+}
+
+impl CaseKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CaseKind::Valid => "valid",
+            CaseKind::MissingRequired => "missing-required",
+            CaseKind::WrongType => "wrong-type",
+            CaseKind::BadDigest => "bad-digest",
+        }
+    }
+}
+
+/// A document in the corpus, with what a verifier is expected to make of it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorpusCase {
+    /// The path of the document within the corpus, e.g. `cdxa/wrong-type-3.json`.
+    pub file: String,
+    /// The name of the document's model in the schema registry.
+    pub model: &'static str,
+    pub expected: Expectation,
+    pub kind: CaseKind,
+    /// The JSON pointer of the value that was removed or replaced, empty for valid documents.
+    pub path: String,
+    /// The codes of the errors Spector reports for the document.
+    pub errors: Vec<String>,
+    #[serde(skip)]
+    pub document: Value,
+}
+
+/// Generates the corpus for every model in the schema registry, from `seeds` valid documents per
+/// model.
+pub fn generate_all(seeds: u64) -> Result<Vec<CorpusCase>> {
+    let mut cases = Vec::new();
+    for entry in SchemaRegistry::entries() {
+        cases.extend(generate(entry, seeds)?);
+    }
+    Ok(cases)
+}
+
+/// Generates the corpus for one model, from `seeds` valid documents.
+pub fn generate(entry: &SchemaEntry, seeds: u64) -> Result<Vec<CorpusCase>> {
+    let mut corpus = Corpus {
+        entry,
+        cases: Vec::new(),
+    };
+    for seed in 0..seeds {
+        let document = valid_document(entry, seed)?;
+        let errors = error_codes(&check(entry, &document));
+        if !errors.is_empty() {
+            bail!(
+                "Sampled {} document is not valid: {}",
+                entry.name,
+                errors.join(", ")
+            );
+        }
+        corpus.push(CaseKind::Valid, String::new(), document.clone());
+
+        for path in value_paths(&document, "") {
+            let (parent, key) = path.rsplit_once('/').unwrap_or_default();
+            if let Some(Value::Object(_)) = document.pointer(parent) {
+                let mut missing = document.clone();
+                if let Some(Value::Object(map)) = missing.pointer_mut(parent) {
+                    map.remove(&key.replace("~1", "/").replace("~0", "~"));
+                }
+                corpus.push(CaseKind::MissingRequired, path.clone(), missing);
+            }
+
+            let mut wrong = document.clone();
+            if let Some(value) = wrong.pointer_mut(&path) {
+                *value = wrong_type(value);
+            }
+            corpus.push(CaseKind::WrongType, path, wrong);
+        }
+
+        for (path, digest) in bad_digests(&document, "") {
+            let mut bad = document.clone();
+            if let Some(value) = bad.pointer_mut(&path) {
+                *value = digest;
+            }
+            corpus.push(CaseKind::BadDigest, path, bad);
+        }
+    }
+    Ok(corpus.cases)
+}
+
+/// Writes every document of the corpus under `dir`, with a `manifest.json` listing them.
+pub fn write(dir: &Path, cases: &[CorpusCase]) -> Result<()> {
+    for case in cases {
+        let path = dir.join(&case.file);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&case.document)? + "\n")?;
+    }
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(
+        dir.join("manifest.json"),
+        serde_json::to_string_pretty(cases)? + "\n",
+    )?;
+    Ok(())
+}
+
+/// Checks a document of the given model the way `spector validate` does: against its schema, then
+/// with the model's types and semantic rules.
+pub fn check(entry: &SchemaEntry, document: &Value) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    match entry.compiled() {
+        Ok(compiled) => {
+            if let Err(errors) = compiled.validate(document) {
+                let schema = entry.schema();
+                for error in errors {
+                    report.push(Finding::error(
+                        "schema-violation",
+                        error.instance_path.to_string(),
+                        describe_schema_error(&schema, &error),
+                    ));
+                }
+            }
+        }
+        Err(e) => report.push(Finding::error("invalid-schema", "", e.to_string())),
+    }
+
+    let typed = match entry.name {
+        "spdx-v23" => GenericValidator::<Spdx23>::new()
+            .validate(document)
+            .map(|_| ()),
+        "spdx-v22" => GenericValidator::<Spdx22Document>::new()
+            .validate(document)
+            .map(|_| ()),
+        "cdxa" => GenericValidator::<CycloneDxAttestation>::new()
+            .validate(document)
+            .map(|cdxa| report.extend(check_cdxa(&cdxa).findings)),
+        _ => {
+            report.extend(check_statement(document, &Policy::default()).findings);
+            Ok(())
+        }
+    };
+    if let Err(e) = typed {
+        report.push(Finding::error("invalid-document", "", e.to_string()));
+    }
+    report
+}
+
+struct Corpus<'a> {
+    entry: &'a SchemaEntry,
+    cases: Vec<CorpusCase>,
+}
+
+impl Corpus<'_> {
+    /// Adds the document if it is valid, or if it was broken and Spector rejects it.
+    fn push(&mut self, kind: CaseKind, path: String, document: Value) {
+        let errors = error_codes(&check(self.entry, &document));
+        let expected = if errors.is_empty() {
+            Expectation::Valid
+        } else {
+            Expectation::Invalid
+        };
+        if (kind == CaseKind::Valid) != (expected == Expectation::Valid) {
+            return;
+        }
+
+        let index = self.cases.iter().filter(|case| case.kind == kind).count();
+        self.cases.push(CorpusCase {
+            file: format!("{}/{}-{}.json", self.entry.name, kind.as_str(), index),
+            model: self.entry.name,
+            expected,
+            kind,
+            path,
+            errors,
+            document,
+        });
+    }
+}
+
+/// Returns the distinct codes of the report's errors, in the order they were first reported.
+fn error_codes(report: &ValidationReport) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    for finding in report.errors() {
+        if !codes.contains(&finding.code) {
+            codes.push(finding.code.clone());
+        }
+    }
+    codes
+}
+
+/// Samples a document from the model's schema, with the fields its semantic rules check, like the
+/// statement and predicate types, set to values they accept.
+fn valid_document(entry: &SchemaEntry, seed: u64) -> Result<Value> {
+    let mut document = sample(&entry.schema(), seed)?;
+    let overrides = match entry.name {
+        "spdx-v23" | "spdx-v22" => spdx_document(entry.name),
+        "cdxa" => json!({
+            "bomFormat": BOM_FORMAT,
+            "specVersion": format!("{}.{}", MIN_SPEC_VERSION.0, MIN_SPEC_VERSION.1),
+        }),
+        _ => json!({
+            "_type": STATEMENT_TYPE_V1,
+            "predicateType": entry.predicate_type.unwrap_or(STATEMENT_TYPE_V1),
+        }),
+    };
+    json_patch::merge(&mut document, &overrides);
+    match entry.name {
+        // The sampled predicate can mix fields of either SPDX version, so it is replaced.
+        "spdx-document" => document["predicate"] = spdx_document("spdx-v23"),
+        // The sampled results can refer to rules the tool doesn't have, so they are left out.
+        "sarif-v21" => document["predicate"] = sarif_log(),
+        // The sampled product ids and statuses don't refer to each other, so they are replaced.
+        "csaf-v2" => document["predicate"] = csaf_vex_document(),
+        // The sampled references can have neither a locator nor a digest, so they are replaced.
+        "reference-v01" => document["predicate"] = sbom_reference(),
+        // The sampled license identifiers aren't on the SPDX License List, so they are replaced.
+        "license-scan-v01" => document["predicate"]["licenses"] = json!([{"spdxId": "Apache-2.0"}]),
+        // The sampled approvals don't refer to the reviewers or the head, so they are replaced.
+        "code-review-v01" => {
+            let head = document["predicate"]["commitRange"]["head"].clone();
+            document["predicate"]["author"] = json!("author");
+            document["predicate"]["reviewers"] = json!([{"id": "reviewer"}]);
+            document["predicate"]["approvals"] = json!([{"reviewer": "reviewer", "commit": head}]);
+        }
+        // The sampled purls aren't purls, so they are replaced.
+        "dependency-review-v01" => {
+            document["predicate"]["changes"] =
+                json!([{"changeType": "added", "purl": "pkg:npm/lodash@4.17.21"}])
+        }
+        _ => {}
+    }
+    Ok(document)
+}
+
+/// Returns a SARIF log with one run that found nothing.
+fn sarif_log() -> Value {
+    json!({
+        "version": SARIF_VERSION,
+        "runs": [{"tool": {"driver": {"name": "scanner"}}, "results": []}]
+    })
+}
+
+/// Returns a CSAF VEX document with one product, not affected by one vulnerability.
+fn csaf_vex_document() -> Value {
+    json!({
+        "document": {
+            "category": CSAF_VEX_CATEGORY,
+            "csaf_version": CSAF_VERSION,
+            "publisher": {"category": "vendor", "name": "Example", "namespace": "https://example.com"},
+            "title": "Example VEX",
+            "tracking": {
+                "id": "EX-2024-0001",
+                "status": "final",
+                "version": "1",
+                "initial_release_date": "2024-05-01T12:00:00Z",
+                "current_release_date": "2024-05-01T12:00:00Z",
+                "revision_history": [{"date": "2024-05-01T12:00:00Z", "number": "1", "summary": "Initial"}]
+            }
+        },
+        "product_tree": {"full_product_names": [{"name": "Example 1.2.3", "product_id": "EX-123"}]},
+        "vulnerabilities": [{
+            "cve": "CVE-2024-3094",
+            "product_status": {"known_not_affected": ["EX-123"]},
+            "flags": [{"label": "component_not_present", "product_ids": ["EX-123"]}]
+        }]
+    })
+}
+
+/// Returns a reference predicate pointing to one SPDX document.
+fn sbom_reference() -> Value {
+    json!({
+        "attester": {"id": "https://example.com/attester"},
+        "references": [{
+            "downloadLocation": "https://example.com/sbom.spdx.json",
+            "digest": {"sha256": "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c"},
+            "mediaType": "application/spdx+json"
+        }]
+    })
+}
+
+/// Returns the fields of a minimal SPDX document with one package, as the sampled document has
+/// none.
+fn spdx_document(name: &str) -> Value {
+    let version = if name == "spdx-v22" {
+        "SPDX-2.2"
+    } else {
+        "SPDX-2.3"
+    };
+    json!({
+        "spdxVersion": version,
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": "corpus",
+        "documentNamespace": "https://example.com/spdx/corpus",
+        "creationInfo": {
+            "created": "2023-01-01T00:00:00Z",
+            "creators": ["Tool: spector"]
+        },
+        "packages": [{
+            "SPDXID": "SPDXRef-Package",
+            "name": "alpha",
+            "downloadLocation": "NOASSERTION",
+            "checksums": [{
+                "algorithm": "SHA256",
+                "checksumValue": "a1c3696bf9b564c7dac9b0062f5097331f9ae8787d91a167145186fb0dbe329c"
+            }]
+        }]
+    })
+}
+
+/// Returns the JSON pointer of every value in the document, other than the document itself.
+fn value_paths(value: &Value, path: &str) -> Vec<String> {
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, child)| (join_pointer(path, key), child))
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, child)| (join_pointer(path, &i.to_string()), child))
+            .collect(),
+        _ => vec![],
+    };
+    let mut paths = Vec::new();
+    for (child_path, child) in children {
+        paths.push(child_path.clone());
+        paths.extend(value_paths(child, &child_path));
+    }
+    paths
+}
+
+/// Returns a value of another JSON type than the given one.
+fn wrong_type(value: &Value) -> Value {
+    match value {
+        Value::String(_) => json!(1),
+        Value::Number(_) | Value::Null => json!("1"),
+        Value::Bool(_) => json!("true"),
+        Value::Object(_) => json!("object"),
+        Value::Array(_) => json!({}),
+    }
+}
+
+/// Returns malformed replacements for the digest sets in the document, keyed by their JSON
+/// pointer. Only the algorithms with a format Spector checks are used, as a malformed value of
+/// any other algorithm is accepted.
+fn bad_digests(value: &Value, path: &str) -> Vec<(String, Value)> {
+    let mut digests = Vec::new();
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_path = join_pointer(path, key);
+                if key == "digest" && child.is_object() {
+                    digests.push((child_path.clone(), json!({"gitCommit": "not-a-commit"})));
+                    digests.push((child_path, json!({"dirHash": "not-a-dirhash"})));
+                } else {
+                    digests.extend(bad_digests(child, &child_path));
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter().enumerate() {
+                digests.extend(bad_digests(child, &join_pointer(path, &i.to_string())));
+            }
+        }
+        _ => {}
+    }
+    digests
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_all() {
+        let cases = generate_all(1).unwrap();
+        for entry in SchemaRegistry::entries() {
+            let kinds = cases
+                .iter()
+                .filter(|case| case.model == entry.name)
+                .map(|case| case.kind)
+                .collect::<Vec<_>>();
+            assert!(kinds.contains(&CaseKind::Valid), "{}", entry.name);
+            assert!(kinds.contains(&CaseKind::WrongType), "{}", entry.name);
+        }
+
+        // Every case is checked against the validators, so the expectations hold by construction;
+        // this guards against the file names colliding instead.
+        let mut files = cases.iter().map(|case| &case.file).collect::<Vec<_>>();
+        files.sort();
+        files.dedup();
+        assert_eq!(files.len(), cases.len());
+    }
+
+    #[test]
+    fn test_generate_bad_digest() {
+        let entry = SchemaRegistry::get("slsa-provenance-v1").unwrap();
+        let cases = generate(entry, 1).unwrap();
+        let bad_digest = cases
+            .iter()
+            .find(|case| case.document["subject"][0]["digest"]["gitCommit"] == "not-a-commit")
+            .unwrap();
+        assert_eq!(bad_digest.kind, CaseKind::BadDigest);
+        assert_eq!(bad_digest.expected, Expectation::Invalid);
+        assert_eq!(bad_digest.path, "/subject/0/digest");
+        assert_eq!(bad_digest.errors, vec!["malformed-digest"]);
+        assert!(bad_digest
+            .file
+            .starts_with("slsa-provenance-v1/bad-digest-"));
+    }
+
+    #[test]
+    fn test_check_valid_and_missing_required() {
+        let entry = SchemaRegistry::get("cdxa").unwrap();
+        let mut document = valid_document(entry, 0).unwrap();
+        assert!(!check(entry, &document).has_errors());
+
+        document.as_object_mut().unwrap().remove("bomFormat");
+        let errors = error_codes(&check(entry, &document));
+        assert!(
+            errors.contains(&"schema-violation".to_string()),
+            "{:?}",
+            errors
+        );
+    }
+}
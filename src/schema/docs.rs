@@ -0,0 +1,234 @@
+//! Markdown field references generated from JSON schemas.
+//!
+//! Each type in a schema, starting with the root and followed by its definitions, gets a section
+//! with its description and a table of its fields. References between types become links to
+//! their sections, so the reference can be read like the model source.
+
+use serde_json::{Map, Value};
+use std::fmt::Write;
+
+use crate::codegen::{definitions, description, required_properties, schema_types};
+
+/// Generates a Markdown field reference for a schema.
+///
+/// Object types are documented with a table of their fields, giving each field's type, whether
+/// it is required and its description. Other types, such as enums, are documented with their
+/// type instead.
+pub fn markdown_reference(schema: &Value) -> String {
+    let mut out = String::new();
+    let title = schema
+        .get("title")
+        .and_then(Value::as_str)
+        .unwrap_or("Schema");
+    write_type(&mut out, "#", title, schema);
+    for (name, definition) in definitions(schema) {
+        out.push('\n');
+        write_type(&mut out, "##", name, definition);
+    }
+    out
+}
+
+fn write_type(out: &mut String, heading: &str, name: &str, schema: &Value) {
+    let _ = writeln!(out, "{} {}\n", heading, name);
+    if let Some(description) = description(schema) {
+        let _ = writeln!(out, "{}\n", description);
+    }
+
+    let object = match schema.as_object() {
+        Some(object) => object,
+        None => {
+            let _ = writeln!(out, "**Type:** {}", md_type(schema));
+            return;
+        }
+    };
+    let properties = object
+        .get("properties")
+        .and_then(Value::as_object)
+        .filter(|p| !p.is_empty());
+    match properties {
+        Some(properties) => write_fields(out, object, properties),
+        None => {
+            let _ = writeln!(out, "**Type:** {}", md_type(schema));
+        }
+    }
+}
+
+fn write_fields(out: &mut String, schema: &Map<String, Value>, properties: &Map<String, Value>) {
+    let required = required_properties(schema);
+    out.push_str("| Field | Type | Required | Description |\n");
+    out.push_str("| --- | --- | --- | --- |\n");
+    for (name, property) in properties {
+        let _ = writeln!(
+            out,
+            "| `{}` | {} | {} | {} |",
+            name,
+            md_type(property),
+            if required.contains(&name.as_str()) {
+                "yes"
+            } else {
+                "no"
+            },
+            table_cell(description(property).unwrap_or_default())
+        );
+    }
+}
+
+/// Returns a short Markdown description of the values a schema accepts.
+fn md_type(schema: &Value) -> String {
+    let object = match schema {
+        Value::Object(object) => object,
+        Value::Bool(false) => return "nothing".to_string(),
+        _ => return "any".to_string(),
+    };
+
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        let name = reference.rsplit('/').next().unwrap_or(reference);
+        return format!("[{}](#{})", name, anchor(name));
+    }
+    if let Some(constant) = object.get("const") {
+        return format!("`{}`", table_cell(&constant.to_string()));
+    }
+    if let Some(values) = object.get("enum").and_then(Value::as_array) {
+        let values = values
+            .iter()
+            .map(|v| format!("`{}`", table_cell(&v.to_string())))
+            .collect::<Vec<_>>();
+        return format!("one of {}", values.join(", "));
+    }
+    for key in ["anyOf", "oneOf"] {
+        if let Some(variants) = object.get(key).and_then(Value::as_array) {
+            return alternatives(variants.iter().map(md_type).collect());
+        }
+    }
+    if let Some(parts) = object.get("allOf").and_then(Value::as_array) {
+        let parts = parts.iter().map(md_type).collect::<Vec<_>>();
+        return parts.join(" and ");
+    }
+
+    let types = schema_types(object);
+    if types.is_empty() {
+        return if object.contains_key("properties") {
+            "object".to_string()
+        } else {
+            "any".to_string()
+        };
+    }
+    alternatives(
+        types
+            .into_iter()
+            .map(|t| match t {
+                "string" => match object.get("format").and_then(Value::as_str) {
+                    Some(format) => format!("string ({})", format),
+                    None => "string".to_string(),
+                },
+                "array" => match object.get("items") {
+                    Some(Value::Array(items)) => {
+                        let items = items.iter().map(md_type).collect::<Vec<_>>();
+                        format!("tuple of {}", items.join(", "))
+                    }
+                    Some(items) => format!("array of {}", md_type(items)),
+                    None => "array".to_string(),
+                },
+                "object" => match object.get("additionalProperties") {
+                    Some(additional @ Value::Object(_)) if !object.contains_key("properties") => {
+                        format!("map of {}", md_type(additional))
+                    }
+                    _ => "object".to_string(),
+                },
+                t => t.to_string(),
+            })
+            .collect(),
+    )
+}
+
+/// Joins alternative types, removing duplicates.
+fn alternatives(types: Vec<String>) -> String {
+    let mut unique = Vec::new();
+    for t in types {
+        if !unique.contains(&t) {
+            unique.push(t);
+        }
+    }
+    unique.join(" or ")
+}
+
+/// Returns the anchor GitHub generates for a heading.
+fn anchor(heading: &str) -> String {
+    heading
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c.to_ascii_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Escapes text for use in a table cell, which can't contain line breaks or unescaped pipes.
+fn table_cell(text: &str) -> String {
+    text.trim().replace('|', "\\|").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::registry::SchemaRegistry;
+    use serde_json::json;
+
+    #[test]
+    fn test_markdown_reference() {
+        let schema = json!({
+            "title": "Statement",
+            "description": "A statement.",
+            "type": "object",
+            "required": ["subject"],
+            "properties": {
+                "subject": {"type": "array", "items": {"$ref": "#/definitions/Subject"}},
+                "kind": {"anyOf": [{"$ref": "#/definitions/Kind"}, {"type": "null"}]},
+                "time": {
+                    "description": "When it happened.\nIn UTC | local time.",
+                    "type": ["string", "null"],
+                    "format": "date-time"
+                }
+            },
+            "definitions": {
+                "Kind": {"type": "string", "enum": ["a", "b"]},
+                "Subject": {
+                    "type": "object",
+                    "properties": {
+                        "digest": {"type": "object", "additionalProperties": {"type": "string"}}
+                    }
+                }
+            }
+        });
+
+        let markdown = markdown_reference(&schema);
+        assert!(markdown.starts_with("# Statement\n\nA statement.\n\n| Field | Type |"));
+        assert!(markdown.contains("| `subject` | array of [Subject](#subject) | yes |  |\n"));
+        assert!(markdown.contains("| `kind` | [Kind](#kind) or null | no |  |\n"));
+        assert!(markdown.contains(
+            "| `time` | string (date-time) or null | no | When it happened.<br>In UTC \\| local time. |\n"
+        ));
+        assert!(markdown.contains("\n## Kind\n\n**Type:** one of `\"a\"`, `\"b\"`\n"));
+        assert!(markdown.contains("\n## Subject\n\n| Field |"));
+        assert!(markdown.contains("| `digest` | map of string | no |  |\n"));
+    }
+
+    #[test]
+    fn test_markdown_reference_for_registry_schemas() {
+        for entry in SchemaRegistry::entries() {
+            let markdown = markdown_reference(&entry.schema());
+            assert!(markdown.starts_with("# "), "{}", entry.name);
+            assert!(markdown.contains("| Field | Type | Required | Description |"));
+        }
+    }
+
+    #[test]
+    fn test_anchor() {
+        assert_eq!(anchor("DigestSet"), "digestset");
+        assert_eq!(
+            anchor("InTotoStatementV1_for_SLSAProvenanceV1Predicate"),
+            "intotostatementv1_for_slsaprovenancev1predicate"
+        );
+    }
+}
@@ -0,0 +1,392 @@
+//! Fetching of published schemas by URL.
+//!
+//! Downloaded schemas are cached on disk by URL, and can be pinned to a SHA-256 checksum so a
+//! schema that changes upstream is rejected instead of silently validating against it. The
+//! remote schemas a schema refers to with `$ref` can be fetched ahead of compiling it, all at
+//! once, instead of one by one as the compiler comes across them.
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Instant;
+use tracing::{debug, instrument};
+use url::Url;
+
+/// The version of the cache layout, bumped whenever it changes so old entries are ignored.
+const CACHE_VERSION: &str = "v1";
+
+/// The most schemas fetched at the same time.
+const MAX_CONCURRENT_FETCHES: usize = 8;
+
+/// Options for fetching a schema.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FetchOptions {
+    /// Directory to cache downloaded schemas in, or `None` to always download them.
+    pub cache_dir: Option<PathBuf>,
+    /// The expected hex-encoded SHA-256 of the schema document.
+    pub sha256: Option<String>,
+    /// Checks cached schemas with the server, downloading them again if they changed.
+    pub refresh: bool,
+}
+
+/// Returns true if the argument looks like an HTTP(S) URL rather than a file path.
+pub fn is_url(location: &str) -> bool {
+    location.starts_with("https://") || location.starts_with("http://")
+}
+
+/// Returns the default cache directory, `$XDG_CACHE_HOME/spector/schemas` or
+/// `~/.cache/spector/schemas`.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .map(|dir| dir.join("spector").join("schemas"))
+}
+
+/// Fetches the schema at a URL, using the cache if possible.
+///
+/// A cached copy that doesn't match the pinned checksum is downloaded again, and a downloaded
+/// schema that doesn't match is an error. When refreshing, a cached copy is revalidated with the
+/// ETag it was served with, so it is only downloaded again if it changed.
+#[instrument(skip(options), fields(refresh = options.refresh))]
+pub fn fetch_schema(url: &str, options: &FetchOptions) -> Result<Value> {
+    let cache_path = options.cache_dir.as_ref().map(|dir| {
+        dir.join(CACHE_VERSION)
+            .join(format!("{}.json", sha256_hex(url.as_bytes())))
+    });
+    let etag_path = cache_path.as_ref().map(|path| path.with_extension("etag"));
+
+    let cached = cache_path
+        .as_ref()
+        .and_then(|path| std::fs::read(path).ok())
+        .filter(|bytes| checksum_matches(bytes, options.sha256.as_deref()));
+    let etag = etag_path
+        .as_ref()
+        .and_then(|path| std::fs::read_to_string(path).ok());
+    match (cached, etag) {
+        (Some(bytes), _) if !options.refresh => {
+            debug!(bytes = bytes.len(), "Using the cached schema");
+            return Ok(serde_json::from_slice(&bytes)?);
+        }
+        (Some(bytes), Some(etag)) => {
+            let response = reqwest::blocking::Client::new()
+                .get(url)
+                .header(reqwest::header::IF_NONE_MATCH, etag.trim())
+                .send()
+                .map_err(|e| anyhow!("Failed to fetch schema from {}: {}", url, e))?;
+            if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                debug!(bytes = bytes.len(), "The cached schema is up to date");
+                return Ok(serde_json::from_slice(&bytes)?);
+            }
+            let response = response
+                .error_for_status()
+                .map_err(|e| anyhow!("Failed to fetch schema from {}: {}", url, e))?;
+            return store(url, response, options, cache_path, etag_path);
+        }
+        _ => {}
+    }
+
+    let response = reqwest::blocking::get(url)
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| anyhow!("Failed to fetch schema from {}: {}", url, e))?;
+    store(url, response, options, cache_path, etag_path)
+}
+
+/// Checks a downloaded schema and writes it and its ETag to the cache.
+fn store(
+    url: &str,
+    response: reqwest::blocking::Response,
+    options: &FetchOptions,
+    cache_path: Option<PathBuf>,
+    etag_path: Option<PathBuf>,
+) -> Result<Value> {
+    let start = Instant::now();
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(str::to_string);
+    let bytes = response.bytes()?.to_vec();
+    debug!(
+        bytes = bytes.len(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Downloaded schema"
+    );
+    if !checksum_matches(&bytes, options.sha256.as_deref()) {
+        bail!(
+            "Schema from {} has SHA-256 {}, expected {}",
+            url,
+            sha256_hex(&bytes),
+            options.sha256.as_deref().unwrap_or_default()
+        );
+    }
+    let schema = serde_json::from_slice(&bytes)
+        .map_err(|e| anyhow!("Schema from {} is not valid JSON: {}", url, e))?;
+
+    if let (Some(cache_path), Some(etag_path)) = (cache_path, etag_path) {
+        if let Some(parent) = cache_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(cache_path, &bytes)?;
+        match etag {
+            Some(etag) => std::fs::write(etag_path, etag)?,
+            None => {
+                let _ = std::fs::remove_file(etag_path);
+            }
+        }
+    }
+    Ok(schema)
+}
+
+/// Fetches every remote schema a schema refers to with `$ref`, directly or through other remote
+/// schemas, returning them by URL.
+///
+/// References are resolved against `base_url`, or the schema's `$id` if it has none. Each URL is
+/// fetched once, and the schemas found at each level of references are fetched concurrently.
+/// The checksum in the options only applies to the schema itself, so it isn't checked for the
+/// schemas it refers to.
+pub fn fetch_remote_refs(
+    schema: &Value,
+    base_url: Option<&str>,
+    options: &FetchOptions,
+) -> Result<BTreeMap<String, Value>> {
+    let options = FetchOptions {
+        sha256: None,
+        ..options.clone()
+    };
+    let base = base_url
+        .or_else(|| schema.get("$id").and_then(Value::as_str))
+        .and_then(|base| Url::parse(base).ok())
+        .map(|mut base| {
+            base.set_fragment(None);
+            base
+        });
+    let mut documents = BTreeMap::new();
+    let mut pending = Vec::new();
+    remote_refs(schema, base.as_ref(), &mut pending);
+
+    while !pending.is_empty() {
+        pending.sort();
+        pending.dedup();
+        pending.retain(|url| !documents.contains_key(url) && Some(url) != base.as_ref());
+        let mut fetched = Vec::new();
+        for batch in pending.chunks(MAX_CONCURRENT_FETCHES) {
+            let results = std::thread::scope(|scope| {
+                let handles = batch
+                    .iter()
+                    .map(|url| scope.spawn(|| fetch_schema(url.as_str(), &options)))
+                    .collect::<Vec<_>>();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("schema fetches don't panic"))
+                    .collect::<Vec<_>>()
+            });
+            for (url, result) in batch.iter().zip(results) {
+                fetched.push((url.clone(), result?));
+            }
+        }
+
+        pending = Vec::new();
+        for (url, document) in fetched {
+            remote_refs(&document, Some(&url), &mut pending);
+            documents.insert(url, document);
+        }
+    }
+    Ok(documents
+        .into_iter()
+        .map(|(url, document)| (url.to_string(), document))
+        .collect())
+}
+
+/// Collects the URLs of the remote documents referred to by `$ref`s in a schema, without their
+/// fragments.
+fn remote_refs(schema: &Value, base: Option<&Url>, refs: &mut Vec<Url>) {
+    match schema {
+        Value::Object(object) => {
+            if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+                let url = match base {
+                    Some(base) => base.join(reference).ok(),
+                    None => Url::parse(reference).ok(),
+                };
+                if let Some(mut url) = url.filter(|url| matches!(url.scheme(), "http" | "https")) {
+                    url.set_fragment(None);
+                    if Some(&url) != base {
+                        refs.push(url);
+                    }
+                }
+            }
+            for value in object.values() {
+                remote_refs(value, base, refs);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                remote_refs(value, base, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn checksum_matches(bytes: &[u8], sha256: Option<&str>) -> bool {
+    match sha256 {
+        Some(expected) => sha256_hex(bytes).eq_ignore_ascii_case(expected.trim()),
+        None => true,
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{serve, serve_with, Request, Response};
+    use serde_json::json;
+    use std::sync::mpsc;
+
+    const SCHEMA: &str = r#"{"type": "string"}"#;
+
+    /// Serves the documents, made from the server's URL, by path with an ETag of their checksum,
+    /// answering requests whose `If-None-Match` matches with 304. Returns the server's URL and
+    /// a receiver for the requests.
+    fn serve_documents(
+        documents: impl FnOnce(&str) -> Vec<(&'static str, String)>,
+    ) -> (String, mpsc::Receiver<Request>) {
+        serve_with(|url| {
+            let documents = documents(url);
+            move |request: &Request| {
+                let Some((_, body)) = documents.iter().find(|(path, _)| *path == request.path())
+                else {
+                    return Response::not_found();
+                };
+                let etag = format!("\"{}\"", sha256_hex(body.as_bytes()));
+                if request.header("if-none-match") == Some(etag.as_str()) {
+                    Response::new("304 Not Modified")
+                } else {
+                    Response::ok(body.clone()).header("ETag", &etag)
+                }
+            }
+        })
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("spector-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_schema_with_cache() {
+        let (url, requests) = serve(|_| Response::ok(SCHEMA));
+        let options = FetchOptions {
+            cache_dir: Some(cache_dir("cache")),
+            sha256: Some(sha256_hex(SCHEMA.as_bytes()).to_uppercase()),
+            refresh: false,
+        };
+
+        assert_eq!(fetch_schema(&url, &options).unwrap()["type"], "string");
+        assert_eq!(fetch_schema(&url, &options).unwrap()["type"], "string");
+        // The second fetch comes from the cache.
+        assert_eq!(requests.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn test_fetch_schema_checksum_mismatch() {
+        let (url, _requests) = serve(|_| Response::ok(SCHEMA));
+        let options = FetchOptions {
+            cache_dir: Some(cache_dir("mismatch")),
+            sha256: Some("00".repeat(32)),
+            refresh: false,
+        };
+
+        let err = fetch_schema(&url, &options).unwrap_err();
+        assert!(err.to_string().contains("expected 0000"));
+        assert!(!options.cache_dir.unwrap().exists());
+    }
+
+    #[test]
+    fn test_fetch_schema_revalidates_with_etag() {
+        let (url, requests) = serve_documents(|_| vec![("/schema.json", SCHEMA.to_string())]);
+        let url = format!("{}/schema.json", url);
+        let mut options = FetchOptions {
+            cache_dir: Some(cache_dir("etag")),
+            sha256: None,
+            refresh: false,
+        };
+
+        fetch_schema(&url, &options).unwrap();
+        options.refresh = true;
+        assert_eq!(fetch_schema(&url, &options).unwrap()["type"], "string");
+        assert_eq!(
+            requests
+                .try_iter()
+                .map(|request| (
+                    request.path().to_string(),
+                    request.header("if-none-match").is_some()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("/schema.json".to_string(), false),
+                ("/schema.json".to_string(), true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fetch_remote_refs() {
+        let (url, requests) = serve_documents(|url| {
+            vec![
+                (
+                    "/a.json",
+                    json!({"$ref": format!("{}/c.json#/definitions/c", url)}).to_string(),
+                ),
+                ("/b.json", json!({"$ref": "c.json"}).to_string()),
+                (
+                    "/c.json",
+                    json!({"definitions": {"c": {"type": "string"}}}).to_string(),
+                ),
+            ]
+        });
+        let schema = json!({
+            "$id": format!("{}/schemas/root.json", url),
+            "properties": {
+                "a": {"$ref": "/a.json"},
+                "b": {"$ref": format!("{}/b.json#", url)},
+                "c": {"$ref": "#/definitions/local"},
+                "d": {"$ref": "urn:example:d"}
+            },
+            "definitions": {"local": {"type": "string"}}
+        });
+
+        let documents = fetch_remote_refs(&schema, None, &FetchOptions::default()).unwrap();
+        assert_eq!(
+            documents.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                format!("{}/a.json", url),
+                format!("{}/b.json", url),
+                format!("{}/c.json", url)
+            ]
+        );
+        // c.json is referred to by both a.json and b.json, but only fetched once.
+        let mut paths = requests
+            .try_iter()
+            .map(|request| request.path().to_string())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec!["/a.json", "/b.json", "/c.json"]);
+    }
+
+    #[test]
+    fn test_is_url() {
+        assert!(is_url("https://spdx.org/schema.json"));
+        assert!(!is_url("tests/fixtures/in_toto_v1_schema.json"));
+    }
+}
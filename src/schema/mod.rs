@@ -0,0 +1,14 @@
+//! JSON schemas for the supported models.
+//!
+//! The schemas are generated from the model types with schemars, so they are always in sync with
+//! what the library deserializes and are available without any schema files on disk.
+
+pub mod cache;
+pub mod compat;
+pub mod corpus;
+#[cfg(feature = "codegen")]
+pub mod docs;
+pub mod fetch;
+pub mod openapi;
+pub mod registry;
+pub mod sample;
@@ -0,0 +1,138 @@
+//! OpenAPI document generation for the models.
+//!
+//! Services that accept or return Spector documents can reference these components from their
+//! own API definitions instead of duplicating the schemas.
+
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use serde_json::{json, Value};
+
+use crate::models::intoto::apko::ApkoBuildPredicate;
+use crate::models::intoto::buildinfo::JvmBuildInfoPredicate;
+use crate::models::intoto::codereview::CodeReviewPredicate;
+use crate::models::intoto::csaf::CsafDocument;
+use crate::models::intoto::dependencyreview::DependencyReviewPredicate;
+use crate::models::intoto::licensescan::LicenseScanPredicate;
+use crate::models::intoto::link::LinkPredicate;
+use crate::models::intoto::melange::MelangeBuildPredicate;
+use crate::models::intoto::provenancev02::SLSAProvenanceV02Predicate;
+use crate::models::intoto::provenancev1::SLSAProvenanceV1Predicate;
+use crate::models::intoto::reference::ReferencePredicate;
+use crate::models::intoto::sarif::SarifLog;
+use crate::models::intoto::scai::{SCAIV02Predicate, SCAIV03Predicate};
+use crate::models::intoto::spdx::SpdxDocumentPredicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::models::intoto::testresult::TestResultPredicate;
+use crate::models::intoto::vsa::VerificationSummaryV1Predicate;
+use crate::models::sbom::cyclonedx::CycloneDxBom;
+use crate::validate::report::ValidationReport;
+
+/// The OpenAPI version of the generated documents.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Returns an OpenAPI 3.1 document whose components cover the statements, predicates and
+/// validation reports.
+///
+/// The document has no paths. Schemas reference each other under `#/components/schemas/`, and
+/// since OpenAPI 3.1 schemas are JSON Schema 2020-12, they are otherwise identical to the
+/// JSON schemas for the models.
+pub fn openapi_document() -> Value {
+    let mut settings = SchemaSettings::draft2019_09();
+    settings.definitions_path = "#/components/schemas/".to_string();
+    settings.meta_schema = None;
+    let mut gen = SchemaGenerator::new(settings);
+
+    gen.subschema_for::<InTotoStatementV1>();
+    gen.subschema_for::<InTotoStatementV1<SLSAProvenanceV1Predicate>>();
+    gen.subschema_for::<InTotoStatementV1<SLSAProvenanceV02Predicate>>();
+    gen.subschema_for::<InTotoStatementV1<SCAIV02Predicate>>();
+    gen.subschema_for::<InTotoStatementV1<SCAIV03Predicate>>();
+    gen.subschema_for::<InTotoStatementV1<ApkoBuildPredicate>>();
+    gen.subschema_for::<InTotoStatementV1<MelangeBuildPredicate>>();
+    gen.subschema_for::<InTotoStatementV1<JvmBuildInfoPredicate>>();
+    gen.subschema_for::<InTotoStatementV1<VerificationSummaryV1Predicate>>();
+    gen.subschema_for::<InTotoStatementV1<LinkPredicate>>();
+    gen.subschema_for::<InTotoStatementV1<SpdxDocumentPredicate>>();
+    gen.subschema_for::<InTotoStatementV1<CycloneDxBom>>();
+    gen.subschema_for::<InTotoStatementV1<TestResultPredicate>>();
+    gen.subschema_for::<InTotoStatementV1<SarifLog>>();
+    gen.subschema_for::<InTotoStatementV1<CsafDocument>>();
+    gen.subschema_for::<InTotoStatementV1<ReferencePredicate>>();
+    gen.subschema_for::<InTotoStatementV1<LicenseScanPredicate>>();
+    gen.subschema_for::<InTotoStatementV1<CodeReviewPredicate>>();
+    gen.subschema_for::<InTotoStatementV1<DependencyReviewPredicate>>();
+    gen.subschema_for::<ValidationReport>();
+
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": "Spector",
+            "description": "Schemas for supply chain metadata documents and their validation reports.",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": {},
+        "components": {
+            "schemas": gen.take_definitions(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_refs<'a>(value: &'a Value, refs: &mut Vec<&'a str>) {
+        match value {
+            Value::Object(object) => {
+                if let Some(Value::String(reference)) = object.get("$ref") {
+                    refs.push(reference);
+                }
+                object.values().for_each(|v| collect_refs(v, refs));
+            }
+            Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_openapi_document() {
+        let document = openapi_document();
+        assert_eq!(document["openapi"], "3.1.0");
+
+        let schemas = document["components"]["schemas"].as_object().unwrap();
+        for name in [
+            "InTotoStatementV1_for_Predicate",
+            "InTotoStatementV1_for_SLSAProvenanceV1Predicate",
+            "SLSAProvenanceV02Predicate",
+            "SCAIV02Predicate",
+            "SCAIV03Predicate",
+            "ApkoBuildPredicate",
+            "MelangeBuildPredicate",
+            "JvmBuildInfoPredicate",
+            "VerificationSummaryV1Predicate",
+            "LinkPredicate",
+            "SpdxDocumentPredicate",
+            "CycloneDxBom",
+            "TestResultPredicate",
+            "SarifLog",
+            "CsafDocument",
+            "ReferencePredicate",
+            "LicenseScanPredicate",
+            "CodeReviewPredicate",
+            "DependencyReviewPredicate",
+            "ValidationReport",
+            "Finding",
+        ] {
+            assert!(schemas.contains_key(name), "missing schema {}", name);
+        }
+
+        let mut refs = vec![];
+        collect_refs(&document, &mut refs);
+        assert!(!refs.is_empty());
+        for reference in refs {
+            let name = reference
+                .strip_prefix("#/components/schemas/")
+                .unwrap_or_else(|| panic!("unexpected $ref {}", reference));
+            assert!(schemas.contains_key(name), "dangling $ref {}", reference);
+        }
+    }
+}
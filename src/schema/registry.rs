@@ -0,0 +1,244 @@
+//! A registry of the JSON schemas embedded in Spector, keyed by name and predicateType.
+
+use anyhow::Result;
+use jsonschema::JSONSchema;
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+use serde_json::Value;
+use std::sync::Arc;
+
+use super::cache::SchemaCache;
+use crate::models::cdxa::CycloneDxAttestation;
+use crate::models::intoto::apko::ApkoBuildPredicate;
+use crate::models::intoto::buildinfo::JvmBuildInfoPredicate;
+use crate::models::intoto::codereview::CodeReviewPredicate;
+use crate::models::intoto::csaf::CsafDocument;
+use crate::models::intoto::dependencyreview::DependencyReviewPredicate;
+use crate::models::intoto::licensescan::LicenseScanPredicate;
+use crate::models::intoto::link::LinkPredicate;
+use crate::models::intoto::melange::MelangeBuildPredicate;
+use crate::models::intoto::options::PredicateTypeMatching;
+use crate::models::intoto::predicate::{
+    canonical_predicate_type, APKO_BUILD_V1, CODE_REVIEW_V01, CSAF_V2, CYCLONEDX_BOM,
+    DEPENDENCY_REVIEW_V01, IN_TOTO_LINK_V1, IN_TOTO_TEST_RESULT_V01, JVM_BUILD_INFO_V1,
+    LICENSE_SCAN_V01, MELANGE_BUILD_V1, REFERENCE_V01, SARIF_V21, SCAI_ATTRIBUTE_REPORT, SCAI_V03,
+    SLSA_PROVENANCE_V02, SLSA_PROVENANCE_V1, SLSA_VERIFICATION_SUMMARY_V1, SPDX_DOCUMENT,
+};
+use crate::models::intoto::provenancev02::SLSAProvenanceV02Predicate;
+use crate::models::intoto::provenancev1::SLSAProvenanceV1Predicate;
+use crate::models::intoto::reference::ReferencePredicate;
+use crate::models::intoto::sarif::SarifLog;
+use crate::models::intoto::scai::{SCAIV02Predicate, SCAIV03Predicate};
+use crate::models::intoto::spdx::SpdxDocumentPredicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::models::intoto::testresult::TestResultPredicate;
+use crate::models::intoto::vsa::VerificationSummaryV1Predicate;
+use crate::models::sbom::{cyclonedx::CycloneDxBom, spdx22::Spdx22Document, spdx23::Spdx23};
+
+/// A schema for one of the supported document types.
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaEntry {
+    /// A stable, kebab-case name for the document type, e.g. `slsa-provenance-v1`.
+    pub name: &'static str,
+    /// The predicateType of In-Toto statements this schema describes, if any.
+    pub predicate_type: Option<&'static str>,
+    generate: fn() -> RootSchema,
+}
+
+impl SchemaEntry {
+    /// Returns the schema as a schemars `RootSchema`.
+    pub fn root_schema(&self) -> RootSchema {
+        (self.generate)()
+    }
+
+    /// Returns the schema as a JSON value.
+    pub fn schema(&self) -> Value {
+        serde_json::to_value(self.root_schema()).expect("schemas are always valid JSON")
+    }
+
+    /// Returns the compiled schema, from the process-wide schema cache.
+    pub fn compiled(&self) -> Result<Arc<JSONSchema>> {
+        SchemaCache::global().get_or_compile(&self.schema())
+    }
+}
+
+const ENTRIES: &[SchemaEntry] = &[
+    SchemaEntry {
+        name: "in-toto-v1",
+        predicate_type: None,
+        generate: || schema_for!(InTotoStatementV1),
+    },
+    SchemaEntry {
+        name: "slsa-provenance-v1",
+        predicate_type: Some(SLSA_PROVENANCE_V1),
+        generate: || schema_for!(InTotoStatementV1<SLSAProvenanceV1Predicate>),
+    },
+    SchemaEntry {
+        name: "slsa-provenance-v02",
+        predicate_type: Some(SLSA_PROVENANCE_V02),
+        generate: || schema_for!(InTotoStatementV1<SLSAProvenanceV02Predicate>),
+    },
+    SchemaEntry {
+        name: "scai-v02",
+        predicate_type: Some(SCAI_ATTRIBUTE_REPORT),
+        generate: || schema_for!(InTotoStatementV1<SCAIV02Predicate>),
+    },
+    SchemaEntry {
+        name: "scai-v03",
+        predicate_type: Some(SCAI_V03),
+        generate: || schema_for!(InTotoStatementV1<SCAIV03Predicate>),
+    },
+    SchemaEntry {
+        name: "apko-build-v1",
+        predicate_type: Some(APKO_BUILD_V1),
+        generate: || schema_for!(InTotoStatementV1<ApkoBuildPredicate>),
+    },
+    SchemaEntry {
+        name: "melange-build-v1",
+        predicate_type: Some(MELANGE_BUILD_V1),
+        generate: || schema_for!(InTotoStatementV1<MelangeBuildPredicate>),
+    },
+    SchemaEntry {
+        name: "jvm-build-info-v1",
+        predicate_type: Some(JVM_BUILD_INFO_V1),
+        generate: || schema_for!(InTotoStatementV1<JvmBuildInfoPredicate>),
+    },
+    SchemaEntry {
+        name: "slsa-verification-summary-v1",
+        predicate_type: Some(SLSA_VERIFICATION_SUMMARY_V1),
+        generate: || schema_for!(InTotoStatementV1<VerificationSummaryV1Predicate>),
+    },
+    SchemaEntry {
+        name: "link",
+        predicate_type: Some(IN_TOTO_LINK_V1),
+        generate: || schema_for!(InTotoStatementV1<LinkPredicate>),
+    },
+    SchemaEntry {
+        name: "spdx-document",
+        predicate_type: Some(SPDX_DOCUMENT),
+        generate: || schema_for!(InTotoStatementV1<SpdxDocumentPredicate>),
+    },
+    SchemaEntry {
+        name: "cyclonedx-bom",
+        predicate_type: Some(CYCLONEDX_BOM),
+        generate: || schema_for!(InTotoStatementV1<CycloneDxBom>),
+    },
+    SchemaEntry {
+        name: "test-result-v01",
+        predicate_type: Some(IN_TOTO_TEST_RESULT_V01),
+        generate: || schema_for!(InTotoStatementV1<TestResultPredicate>),
+    },
+    SchemaEntry {
+        name: "sarif-v21",
+        predicate_type: Some(SARIF_V21),
+        generate: || schema_for!(InTotoStatementV1<SarifLog>),
+    },
+    SchemaEntry {
+        name: "csaf-v2",
+        predicate_type: Some(CSAF_V2),
+        generate: || schema_for!(InTotoStatementV1<CsafDocument>),
+    },
+    SchemaEntry {
+        name: "reference-v01",
+        predicate_type: Some(REFERENCE_V01),
+        generate: || schema_for!(InTotoStatementV1<ReferencePredicate>),
+    },
+    SchemaEntry {
+        name: "license-scan-v01",
+        predicate_type: Some(LICENSE_SCAN_V01),
+        generate: || schema_for!(InTotoStatementV1<LicenseScanPredicate>),
+    },
+    SchemaEntry {
+        name: "code-review-v01",
+        predicate_type: Some(CODE_REVIEW_V01),
+        generate: || schema_for!(InTotoStatementV1<CodeReviewPredicate>),
+    },
+    SchemaEntry {
+        name: "dependency-review-v01",
+        predicate_type: Some(DEPENDENCY_REVIEW_V01),
+        generate: || schema_for!(InTotoStatementV1<DependencyReviewPredicate>),
+    },
+    SchemaEntry {
+        name: "spdx-v23",
+        predicate_type: None,
+        generate: || schema_for!(Spdx23),
+    },
+    SchemaEntry {
+        name: "spdx-v22",
+        predicate_type: None,
+        generate: || schema_for!(Spdx22Document),
+    },
+    SchemaEntry {
+        name: "cdxa",
+        predicate_type: None,
+        generate: || schema_for!(CycloneDxAttestation),
+    },
+];
+
+/// The JSON schemas for every supported document type.
+pub struct SchemaRegistry;
+
+impl SchemaRegistry {
+    /// Returns every schema in the registry.
+    pub fn entries() -> &'static [SchemaEntry] {
+        ENTRIES
+    }
+
+    /// Returns the schema with the given name.
+    pub fn get(name: &str) -> Option<&'static SchemaEntry> {
+        ENTRIES.iter().find(|entry| entry.name == name)
+    }
+
+    /// Returns the schema for In-Toto statements with the given predicateType.
+    ///
+    /// Non-canonical forms of the URL, such as a trailing slash or another compatible version, are
+    /// matched the same way they are when parsing statements.
+    pub fn for_predicate_type(url: &str) -> Option<&'static SchemaEntry> {
+        let canonical = canonical_predicate_type(url, PredicateTypeMatching::Compatible)?;
+        ENTRIES
+            .iter()
+            .find(|entry| entry.predicate_type == Some(canonical))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_predicate_type() {
+        let entry = SchemaRegistry::for_predicate_type("https://slsa.dev/provenance/v1/").unwrap();
+        assert_eq!(entry.name, "slsa-provenance-v1");
+        assert_eq!(
+            entry.schema()["title"],
+            "InTotoStatementV1_for_SLSAProvenanceV1Predicate"
+        );
+        assert!(SchemaRegistry::for_predicate_type("https://example.com/unknown/v1").is_none());
+    }
+
+    #[test]
+    fn test_entries_have_unique_names() {
+        let mut names = SchemaRegistry::entries()
+            .iter()
+            .map(|entry| entry.name)
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), SchemaRegistry::entries().len());
+        assert!(SchemaRegistry::get("spdx-v23").is_some());
+    }
+
+    #[test]
+    fn test_compiled() {
+        let entry = SchemaRegistry::get("slsa-provenance-v1").unwrap();
+        let compiled = entry.compiled().unwrap();
+        assert!(Arc::ptr_eq(&compiled, &entry.compiled().unwrap()));
+
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        assert!(compiled.is_valid(&serde_json::from_str(&fixture).unwrap()));
+    }
+}
@@ -0,0 +1,136 @@
+//! Attestations stored as files in a directory.
+
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::{AttestationStore, StoredAttestation, SubjectDigest};
+use crate::input;
+use crate::verify::{read_documents, signed_statement};
+
+/// A directory of attestation files, searched recursively.
+///
+/// Files ending in `.json` or `.jsonl`, optionally compressed with gzip or zstd, are read as
+/// statements, DSSE envelopes or Sigstore bundles, one per line for JSON Lines. Attestations in
+/// files with several of them are located as `<path>#<index>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilesystemStore {
+    root: PathBuf,
+}
+
+impl FilesystemStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl AttestationStore for FilesystemStore {
+    fn list(&self, subject: &SubjectDigest) -> Result<Vec<String>> {
+        Ok(self
+            .get(subject)?
+            .into_iter()
+            .map(|attestation| attestation.location)
+            .collect())
+    }
+
+    fn get(&self, subject: &SubjectDigest) -> Result<Vec<StoredAttestation>> {
+        let mut files = Vec::new();
+        collect_files(&self.root, &mut files)?;
+        files.sort();
+
+        let mut attestations = Vec::new();
+        for file in files {
+            let documents = read_documents(&input::read_to_string(&file)?)
+                .and_then(|documents| {
+                    documents
+                        .into_iter()
+                        .map(|document| Ok((signed_statement(&document)?.statement, document)))
+                        .collect::<Result<Vec<_>>>()
+                })
+                .map_err(|e| anyhow!("Failed to read {}: {}", file.display(), e))?;
+            let several = documents.len() > 1;
+            for (i, (statement, document)) in documents.into_iter().enumerate() {
+                if !subject.is_subject_of(&statement) {
+                    continue;
+                }
+                let location = if several {
+                    format!("{}#{}", file.display(), i)
+                } else {
+                    file.display().to_string()
+                };
+                attestations.push(StoredAttestation {
+                    location,
+                    statement,
+                    document,
+                    annotations: BTreeMap::new(),
+                });
+            }
+        }
+        Ok(attestations)
+    }
+}
+
+/// Collects the attestation files under a directory.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+            continue;
+        }
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        let name = [".gz", ".zst"]
+            .iter()
+            .find_map(|suffix| name.strip_suffix(suffix))
+            .unwrap_or(name);
+        if name.ends_with(".json") || name.ends_with(".jsonl") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_filesystem_store() {
+        let root = std::env::temp_dir().join(format!("spector-store-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("nested")).unwrap();
+        let statement = |digest: &str| json!({"_type": "https://in-toto.io/Statement/v1", "subject": [{"name": "a", "digest": {"sha256": digest}}]});
+        std::fs::write(root.join("a.json"), statement("aa").to_string()).unwrap();
+        std::fs::write(
+            root.join("nested/b.intoto.jsonl"),
+            format!("{}\n{}\n", statement("bb"), statement("aa")),
+        )
+        .unwrap();
+        std::fs::write(root.join("notes.txt"), "not an attestation").unwrap();
+
+        let store = FilesystemStore::new(&root);
+        let subject = SubjectDigest::new("sha256", "AA");
+        let attestations = store.get(&subject).unwrap();
+        assert_eq!(
+            store.list(&subject).unwrap(),
+            vec![
+                root.join("a.json").display().to_string(),
+                format!("{}#1", root.join("nested/b.intoto.jsonl").display()),
+            ]
+        );
+        assert_eq!(attestations[0].statement, statement("aa"));
+        assert_eq!(attestations[0].document, statement("aa"));
+        assert!(store
+            .get(&SubjectDigest::new("sha256", "cc"))
+            .unwrap()
+            .is_empty());
+
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(store.get(&subject).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+//! Access to attestations by the digest of their subject, wherever they are stored.
+//!
+//! Attestations live in different places depending on who produced them: files next to a
+//! release, layers attached to a container image in an OCI registry, or entries in the Rekor
+//! transparency log. An [`AttestationStore`] finds those about an artifact by its digest, so the
+//! checks built on top, such as provenance verification and consistency checks, don't depend on
+//! where the attestations come from. Other stores can be plugged in by implementing the trait.
+
+pub mod fs;
+pub mod oci;
+pub mod rekor;
+
+use anyhow::{anyhow, Result};
+use reqwest::blocking::RequestBuilder;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Instant;
+use tracing::{debug, instrument};
+
+pub use fs::FilesystemStore;
+pub use oci::OciStore;
+pub use rekor::RekorStore;
+
+/// The environment variable with a bearer token for OCI registries opened with [`open`].
+pub const OCI_TOKEN_ENV: &str = "SPECTOR_OCI_TOKEN";
+
+/// The digest of an artifact, written as `algorithm:digest`, e.g. `sha256:abcd…`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SubjectDigest {
+    /// The In-Toto name of the algorithm, e.g. `sha256`.
+    pub algorithm: String,
+    /// The digest, with hex digests in lower case.
+    pub digest: String,
+}
+
+impl SubjectDigest {
+    /// Creates a subject digest, lowercasing the algorithm and digest.
+    pub fn new(algorithm: &str, digest: &str) -> Self {
+        Self {
+            algorithm: algorithm.to_ascii_lowercase(),
+            digest: digest.trim().to_ascii_lowercase(),
+        }
+    }
+
+    /// Returns true if a subject of the statement has this digest.
+    pub fn is_subject_of(&self, statement: &Value) -> bool {
+        subjects(statement)
+            .iter()
+            .any(|subject| self.is_digest_of(subject))
+    }
+
+    /// Returns true if the subject has this digest.
+    fn is_digest_of(&self, subject: &Value) -> bool {
+        subject
+            .get("digest")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .any(|(algorithm, digest)| {
+                algorithm.eq_ignore_ascii_case(&self.algorithm)
+                    && digest
+                        .as_str()
+                        .is_some_and(|d| d.trim().eq_ignore_ascii_case(&self.digest))
+            })
+    }
+}
+
+/// Returns the subjects of a statement.
+fn subjects(statement: &Value) -> &[Value] {
+    statement
+        .get("subject")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+impl FromStr for SubjectDigest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once(':') {
+            Some((algorithm, digest)) if !algorithm.is_empty() && !digest.is_empty() => {
+                Ok(Self::new(algorithm, digest))
+            }
+            _ => Err(anyhow!(
+                "Invalid digest {}, expected algorithm:digest such as sha256:abcd",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SubjectDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.algorithm, self.digest)
+    }
+}
+
+/// An attestation found in a store.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredAttestation {
+    /// Where the attestation is in the store, such as a file path, blob URL or Rekor UUID.
+    pub location: String,
+    /// The In-Toto statement, unwrapped from its envelope.
+    pub statement: Value,
+    /// The attestation as stored, with its signatures: a statement, DSSE envelope, Sigstore
+    /// bundle or Rekor log entry, as read by [`signed_statement`](crate::verify::signed_statement).
+    pub document: Value,
+    /// The annotations the attestation was stored with, such as those cosign puts on
+    /// attestation layers. Empty for stores without annotations. They aren't signed, so anyone
+    /// who can write to the store can change them, and they must not be relied on.
+    pub annotations: BTreeMap<String, String>,
+}
+
+impl StoredAttestation {
+    /// Returns true if the statement's subject with the digest has all the annotations with the
+    /// same values. Other annotations are ignored.
+    ///
+    /// Only the annotations in the statement are read, as they are signed with it, and not the
+    /// ones the attestation was stored with.
+    pub fn has_annotations(
+        &self,
+        subject: &SubjectDigest,
+        required: &BTreeMap<String, String>,
+    ) -> bool {
+        required.is_empty()
+            || subjects(&self.statement)
+                .iter()
+                .filter(|annotated| subject.is_digest_of(annotated))
+                .any(|annotated| {
+                    required.iter().all(|(key, value)| {
+                        annotated
+                            .get("annotations")
+                            .and_then(|annotations| annotations.get(key))
+                            .and_then(Value::as_str)
+                            == Some(value)
+                    })
+                })
+    }
+}
+
+/// A place attestations can be looked up in by the digest of their subject.
+pub trait AttestationStore {
+    /// Lists where the attestations about the subject are in the store.
+    fn list(&self, subject: &SubjectDigest) -> Result<Vec<String>>;
+
+    /// Returns the attestations about the subject.
+    fn get(&self, subject: &SubjectDigest) -> Result<Vec<StoredAttestation>>;
+}
+
+/// Opens a store from its location:
+///
+/// - `oci://<registry>/<repository>` for the attestations cosign attached to images in a
+///   registry, sending the token in `SPECTOR_OCI_TOKEN` if it is set,
+/// - `rekor` for the public Rekor instance, or `rekor+<url>` for another one,
+/// - anything else as a directory of attestation files.
+pub fn open(location: &str) -> Result<Box<dyn AttestationStore>> {
+    if let Some(reference) = location.strip_prefix("oci://") {
+        let (registry, repository) = reference.split_once('/').ok_or_else(|| {
+            anyhow!(
+                "Invalid OCI store {}, expected oci://<registry>/<repository>",
+                location
+            )
+        })?;
+        let mut store = OciStore::new(format!("https://{}", registry), repository);
+        if let Ok(token) = std::env::var(OCI_TOKEN_ENV) {
+            store = store.with_token(token);
+        }
+        Ok(Box::new(store))
+    } else if location == "rekor" {
+        Ok(Box::new(RekorStore::new(rekor::DEFAULT_URL)))
+    } else if let Some(url) = location.strip_prefix("rekor+") {
+        Ok(Box::new(RekorStore::new(url)))
+    } else {
+        Ok(Box::new(FilesystemStore::new(location)))
+    }
+}
+
+/// Sends a request, returning `None` for a 404 and failing on other error statuses.
+#[instrument(level = "debug", skip(request))]
+fn send(request: RequestBuilder, url: &str) -> Result<Option<Vec<u8>>> {
+    let start = Instant::now();
+    let response = request
+        .send()
+        .map_err(|e| anyhow!("Failed to fetch {}: {}", url, e))?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    let response = response
+        .error_for_status()
+        .map_err(|e| anyhow!("Failed to fetch {}: {}", url, e))?;
+    let bytes = response.bytes()?.to_vec();
+    debug!(
+        bytes = bytes.len(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Fetched"
+    );
+    Ok(Some(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_subject_digest() {
+        let digest = "SHA256:ABCD".parse::<SubjectDigest>().unwrap();
+        assert_eq!(digest.to_string(), "sha256:abcd");
+        assert!(digest.is_subject_of(&json!({
+            "subject": [{"name": "a", "digest": {"sha512": "ef"}}, {"name": "b", "digest": {"sha256": "abcd"}}]
+        })));
+        assert!(!digest.is_subject_of(&json!({"subject": [{"digest": {"sha512": "abcd"}}]})));
+        assert!("abcd".parse::<SubjectDigest>().is_err());
+        assert!("sha256:".parse::<SubjectDigest>().is_err());
+    }
+
+    #[test]
+    fn test_has_annotations() {
+        let subject = "sha256:01".parse::<SubjectDigest>().unwrap();
+        let attestation = StoredAttestation {
+            location: "sha256:01".to_string(),
+            statement: json!({"subject": [
+                {"digest": {"sha256": "02"}, "annotations": {"owner": "a"}},
+                {"digest": {"sha256": "01"}, "annotations": {"env": "prod", "team": "payments"}}
+            ]}),
+            document: json!({}),
+            // Annotations of the store only, which aren't signed.
+            annotations: BTreeMap::from([("owner".to_string(), "a".to_string())]),
+        };
+        let required = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert!(attestation.has_annotations(&subject, &required(&[])));
+        assert!(attestation.has_annotations(&subject, &required(&[("env", "prod")])));
+        assert!(!attestation.has_annotations(&subject, &required(&[("env", "staging")])));
+        assert!(!attestation.has_annotations(&subject, &required(&[("owner", "a")])));
+        assert!(
+            !attestation.has_annotations(&subject, &required(&[("env", "prod"), ("owner", "a")]))
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! Attestations attached to container images in an OCI registry.
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose, Engine};
+use reqwest::blocking::Client;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+use super::{send, AttestationStore, StoredAttestation, SubjectDigest};
+use crate::attestations::decode_dsse_statement;
+use crate::trust::tuf::decode_hex;
+
+/// The annotations cosign puts on attestation layers with the signing certificate, as PEM, and
+/// the Rekor entry of the signature.
+const CERTIFICATE_ANNOTATION: &str = "dev.sigstore.cosign/certificate";
+const BUNDLE_ANNOTATION: &str = "dev.sigstore.cosign/bundle";
+
+/// The manifest media types asked for when fetching the attestation image.
+const MANIFEST_TYPES: &str =
+    "application/vnd.oci.image.manifest.v1+json, application/vnd.docker.distribution.manifest.v2+json";
+
+/// The attestations cosign attached to the images of a repository in an OCI registry.
+///
+/// `cosign attest` stores the attestations about an image as the layers of another image in
+/// the same repository, tagged `sha256-<digest>.att` after the image digest. Each layer is a
+/// DSSE envelope. Only `sha256` subjects can be looked up, as images are addressed by them.
+///
+/// The annotations of each layer in the manifest, such as the `predicateType` cosign adds, are
+/// kept with its attestation, though they aren't signed. The signing certificate and Rekor entry cosign annotates the layer with are kept with the
+/// envelope as a Sigstore bundle, so its signature can be verified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OciStore {
+    registry: String,
+    repository: String,
+    token: Option<String>,
+}
+
+impl OciStore {
+    /// Creates a store for a repository, e.g. `org/app`, in the registry at a base URL, e.g.
+    /// `https://ghcr.io`.
+    pub fn new(registry: impl Into<String>, repository: impl Into<String>) -> Self {
+        Self {
+            registry: registry.into().trim_end_matches('/').to_string(),
+            repository: repository.into(),
+            token: None,
+        }
+    }
+
+    /// Sends a bearer token with each request, for registries that require authentication.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    fn fetch(&self, url: &str, accept: &str) -> Result<Option<Vec<u8>>> {
+        let mut request = Client::new()
+            .get(url)
+            .header(reqwest::header::ACCEPT, accept);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        send(request, url)
+    }
+
+    /// Returns the URLs and annotations of the layers of the attestation image for the subject.
+    fn layers(&self, subject: &SubjectDigest) -> Result<Vec<(String, BTreeMap<String, String>)>> {
+        if subject.algorithm != "sha256" {
+            bail!(
+                "OCI images are addressed by sha256 digests, not {}",
+                subject.algorithm
+            );
+        }
+        let url = format!(
+            "{}/v2/{}/manifests/sha256-{}.att",
+            self.registry, self.repository, subject.digest
+        );
+        let Some(manifest) = self.fetch(&url, MANIFEST_TYPES)? else {
+            return Ok(Vec::new());
+        };
+        let manifest: Value = serde_json::from_slice(&manifest)
+            .map_err(|e| anyhow!("Invalid manifest from {}: {}", url, e))?;
+        let layers = manifest
+            .get("layers")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        Ok(layers
+            .iter()
+            .filter_map(|layer| {
+                let digest = layer.get("digest").and_then(Value::as_str)?;
+                let url = format!("{}/v2/{}/blobs/{}", self.registry, self.repository, digest);
+                let annotations = layer
+                    .get("annotations")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                    .collect();
+                Some((url, annotations))
+            })
+            .collect())
+    }
+}
+
+impl AttestationStore for OciStore {
+    fn list(&self, subject: &SubjectDigest) -> Result<Vec<String>> {
+        Ok(self
+            .layers(subject)?
+            .into_iter()
+            .map(|(url, _)| url)
+            .collect())
+    }
+
+    fn get(&self, subject: &SubjectDigest) -> Result<Vec<StoredAttestation>> {
+        let mut attestations = Vec::new();
+        for (url, annotations) in self.layers(subject)? {
+            let envelope = self
+                .fetch(&url, "*/*")?
+                .ok_or_else(|| anyhow!("Attestation layer {} not found", url))?;
+            let envelope: Value = serde_json::from_slice(&envelope)
+                .map_err(|e| anyhow!("Invalid DSSE envelope at {}: {}", url, e))?;
+            attestations.push(StoredAttestation {
+                statement: decode_dsse_statement(&envelope)?,
+                document: layer_document(envelope, &annotations),
+                location: url,
+                annotations,
+            });
+        }
+        Ok(attestations)
+    }
+}
+
+/// Returns the envelope of a layer as a Sigstore bundle with the signing certificate and Rekor
+/// entry cosign annotated the layer with, or as it is if cosign didn't.
+fn layer_document(envelope: Value, annotations: &BTreeMap<String, String>) -> Value {
+    let mut material = Map::new();
+    if let Some(pem) = annotations.get(CERTIFICATE_ANNOTATION) {
+        let der = pem
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .collect::<String>();
+        material.insert(
+            "x509CertificateChain".to_string(),
+            json!({"certificates": [{"rawBytes": der}]}),
+        );
+    }
+    let bundle = annotations
+        .get(BUNDLE_ANNOTATION)
+        .and_then(|bundle| serde_json::from_str::<Value>(bundle).ok());
+    if let Some(bundle) = bundle {
+        let payload = &bundle["Payload"];
+        // cosign gives the log id in hex, bundles in base64.
+        let log_id = payload["logID"]
+            .as_str()
+            .and_then(decode_hex)
+            .map(|id| general_purpose::STANDARD.encode(id));
+        material.insert(
+            "tlogEntries".to_string(),
+            json!([{
+                "logIndex": payload["logIndex"],
+                "logId": {"keyId": log_id},
+                "integratedTime": payload["integratedTime"],
+                "canonicalizedBody": payload["body"],
+                "inclusionPromise": {"signedEntryTimestamp": bundle["SignedEntryTimestamp"]}
+            }]),
+        );
+    }
+    if material.is_empty() {
+        return envelope;
+    }
+    json!({
+        "mediaType": "application/vnd.dev.sigstore.bundle+json;version=0.1",
+        "verificationMaterial": material,
+        "dsseEnvelope": envelope
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestations::tests::envelope;
+    use crate::test_server::serve_routes;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_oci_store() {
+        let digest = "a".repeat(64);
+        let statement = json!({"_type": "https://in-toto.io/Statement/v1", "subject": []});
+        let registry = serve_routes(|_| {
+            HashMap::from([
+                (
+                    format!("/v2/org/app/manifests/sha256-{}.att", digest),
+                    json!({"layers": [
+                        {"digest": "sha256:01"},
+                        {"digest": "sha256:02", "annotations": {"env": "prod"}}
+                    ]})
+                    .to_string()
+                    .into_bytes(),
+                ),
+                (
+                    "/v2/org/app/blobs/sha256:01".to_string(),
+                    envelope(&statement).to_string().into_bytes(),
+                ),
+                (
+                    "/v2/org/app/blobs/sha256:02".to_string(),
+                    envelope(&statement).to_string().into_bytes(),
+                ),
+            ])
+        })
+        .0;
+
+        let store = OciStore::new(&registry, "org/app").with_token("token");
+        let subject = SubjectDigest::new("sha256", &digest);
+        assert_eq!(
+            store.list(&subject).unwrap(),
+            vec![
+                format!("{}/v2/org/app/blobs/sha256:01", registry),
+                format!("{}/v2/org/app/blobs/sha256:02", registry),
+            ]
+        );
+        let attestations = store.get(&subject).unwrap();
+        assert_eq!(attestations.len(), 2);
+        assert_eq!(attestations[1].statement, statement);
+        assert!(attestations[0].annotations.is_empty());
+        assert_eq!(
+            attestations[1].annotations,
+            BTreeMap::from([("env".to_string(), "prod".to_string())])
+        );
+
+        assert!(store
+            .get(&SubjectDigest::new("sha256", "bb"))
+            .unwrap()
+            .is_empty());
+        assert!(store.get(&SubjectDigest::new("sha512", "bb")).is_err());
+        assert_eq!(attestations[0].document, envelope(&statement));
+    }
+
+    #[test]
+    fn test_layer_document() {
+        let statement = json!({"_type": "https://in-toto.io/Statement/v1", "subject": []});
+        let cosign_bundle = json!({
+            "SignedEntryTimestamp": "TUVVQw==",
+            "Payload": {
+                "body": "e30=",
+                "integratedTime": 1_700_000_000,
+                "logIndex": 25915956,
+                "logID": "c0d23d6ad406973f9559f3ba2d1ca01f84147d8ffc5b8445c224f98b9591801d"
+            }
+        });
+        let annotations = BTreeMap::from([
+            (
+                CERTIFICATE_ANNOTATION.to_string(),
+                "-----BEGIN CERTIFICATE-----\nMIIB\nAQID\n-----END CERTIFICATE-----\n".to_string(),
+            ),
+            (BUNDLE_ANNOTATION.to_string(), cosign_bundle.to_string()),
+        ]);
+        let document = layer_document(envelope(&statement), &annotations);
+        assert_eq!(
+            document["verificationMaterial"],
+            json!({
+                "x509CertificateChain": {"certificates": [{"rawBytes": "MIIBAQID"}]},
+                "tlogEntries": [{
+                    "logIndex": 25915956,
+                    "logId": {"keyId": "wNI9atQGlz+VWfO6LRygH4QUfY/8W4RFwiT5i5WRgB0="},
+                    "integratedTime": 1_700_000_000,
+                    "canonicalizedBody": "e30=",
+                    "inclusionPromise": {"signedEntryTimestamp": "TUVVQw=="}
+                }]
+            })
+        );
+        assert_eq!(document["dsseEnvelope"], envelope(&statement));
+        assert_eq!(
+            layer_document(envelope(&statement), &BTreeMap::new()),
+            envelope(&statement)
+        );
+    }
+}
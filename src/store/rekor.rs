@@ -0,0 +1,149 @@
+//! Attestations recorded in a Rekor transparency log.
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine};
+use reqwest::blocking::Client;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use super::{send, AttestationStore, StoredAttestation, SubjectDigest};
+use crate::attestations::decode_dsse_statement;
+
+/// The public Rekor instance run by Sigstore.
+pub const DEFAULT_URL: &str = "https://rekor.sigstore.dev";
+
+/// The attestations in a Rekor log, found through its index of subject digests.
+///
+/// Rekor indexes the `intoto` and `dsse` entries it records by the digests of their subjects.
+/// Only entries Rekor kept the attestation of can be returned; others are listed but skipped by
+/// [`get`](AttestationStore::get).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RekorStore {
+    url: String,
+}
+
+impl RekorStore {
+    /// Creates a store for the Rekor instance at a base URL, e.g. [`DEFAULT_URL`].
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn fetch_json(&self, url: &str, body: Option<&Value>) -> Result<Option<Value>> {
+        let request = match body {
+            Some(body) => Client::new().post(url).json(body),
+            None => Client::new().get(url),
+        };
+        send(request, url)?
+            .map(|bytes| {
+                serde_json::from_slice(&bytes)
+                    .map_err(|e| anyhow!("Invalid JSON from {}: {}", url, e))
+            })
+            .transpose()
+    }
+}
+
+impl AttestationStore for RekorStore {
+    fn list(&self, subject: &SubjectDigest) -> Result<Vec<String>> {
+        let url = format!("{}/api/v1/index/retrieve", self.url);
+        let uuids = self
+            .fetch_json(&url, Some(&json!({"hash": subject.to_string()})))?
+            .unwrap_or_default();
+        Ok(uuids
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|uuid| uuid.as_str().map(str::to_string))
+            .collect())
+    }
+
+    fn get(&self, subject: &SubjectDigest) -> Result<Vec<StoredAttestation>> {
+        let mut attestations = Vec::new();
+        for uuid in self.list(subject)? {
+            let url = format!("{}/api/v1/log/entries/{}", self.url, uuid);
+            let Some(entries) = self.fetch_json(&url, None)? else {
+                continue;
+            };
+            let data = entries
+                .as_object()
+                .into_iter()
+                .flat_map(|entries| entries.values())
+                .find_map(|entry| entry.pointer("/attestation/data").and_then(Value::as_str));
+            let Some(data) = data else {
+                continue;
+            };
+            let data = general_purpose::STANDARD
+                .decode(data)
+                .map_err(|e| anyhow!("Attestation of entry {} is not valid base64: {}", uuid, e))?;
+            let attestation: Value = serde_json::from_slice(&data)
+                .map_err(|e| anyhow!("Attestation of entry {} is not JSON: {}", uuid, e))?;
+            let statement = if attestation.get("payloadType").is_some() {
+                decode_dsse_statement(&attestation)?
+            } else {
+                attestation
+            };
+            attestations.push(StoredAttestation {
+                location: uuid,
+                statement,
+                document: entries,
+                annotations: BTreeMap::new(),
+            });
+        }
+        Ok(attestations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestations::tests::envelope;
+    use crate::test_server::serve_routes;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_rekor_store() {
+        let statement = json!({"_type": "https://in-toto.io/Statement/v1", "subject": []});
+        let entry = |attestation: Option<Value>| {
+            let mut entry = json!({"body": "", "logIndex": 1});
+            if let Some(attestation) = attestation {
+                entry["attestation"] = json!({
+                    "data": general_purpose::STANDARD.encode(attestation.to_string())
+                });
+            }
+            json!({"uuid": entry}).to_string().into_bytes()
+        };
+        let rekor = serve_routes(|_| {
+            HashMap::from([
+                (
+                    "/api/v1/index/retrieve".to_string(),
+                    json!(["u1", "u2", "u3"]).to_string().into_bytes(),
+                ),
+                (
+                    "/api/v1/log/entries/u1".to_string(),
+                    entry(Some(statement.clone())),
+                ),
+                (
+                    "/api/v1/log/entries/u2".to_string(),
+                    entry(Some(envelope(&statement))),
+                ),
+                ("/api/v1/log/entries/u3".to_string(), entry(None)),
+            ])
+        })
+        .0;
+
+        let store = RekorStore::new(format!("{}/", rekor));
+        let subject = SubjectDigest::new("sha256", "ab");
+        assert_eq!(store.list(&subject).unwrap(), vec!["u1", "u2", "u3"]);
+        let attestations = store.get(&subject).unwrap();
+        assert_eq!(
+            attestations
+                .iter()
+                .map(|a| a.location.as_str())
+                .collect::<Vec<_>>(),
+            vec!["u1", "u2"]
+        );
+        assert!(attestations.iter().all(|a| a.statement == statement));
+        assert!(attestations[1].document["uuid"]["attestation"].is_object());
+    }
+}
@@ -0,0 +1,178 @@
+//! A local HTTP server for tests, to stand in for registries, transparency logs and the other
+//! services spector talks to.
+//!
+//! Enabled with the `testing` feature. Each connection carries a single request, read up to the
+//! end of its headers and then the body given by its Content-Length, so answering doesn't depend
+//! on how fast the client sends.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+
+/// A request received by the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// The request line and headers, separated by CRLF.
+    pub head: String,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn method(&self) -> &str {
+        self.head.split(' ').next().unwrap_or_default()
+    }
+
+    /// Returns the path of the request, with its query.
+    pub fn path(&self) -> &str {
+        self.head.split(' ').nth(1).unwrap_or_default()
+    }
+
+    /// Returns the value of a header, whose name is matched case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.head.lines().skip(1).find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+
+    /// Parses the body as JSON.
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).unwrap()
+    }
+}
+
+/// A response for the server to send.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    status: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Response {
+    /// Creates an empty response with a status, such as `404 Not Found`.
+    pub fn new(status: &str) -> Self {
+        Self {
+            status: status.to_string(),
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Creates a `200 OK` response with a body.
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self::new("200 OK").body(body)
+    }
+
+    pub fn not_found() -> Self {
+        Self::new("404 Not Found")
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+/// Answers requests with a handler until the test exits, returning the server's base URL and a
+/// receiver for the requests. Each request is sent to the receiver before it is answered.
+pub fn serve(
+    handler: impl FnMut(&Request) -> Response + Send + 'static,
+) -> (String, mpsc::Receiver<Request>) {
+    serve_with(|_| handler)
+}
+
+/// Answers requests as [`serve`] does, with a handler made from the server's base URL.
+pub fn serve_with<H>(handler: impl FnOnce(&str) -> H) -> (String, mpsc::Receiver<Request>)
+where
+    H: FnMut(&Request) -> Response + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let mut handler = handler(&url);
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            let Some(request) = read_request(&stream) else {
+                continue;
+            };
+            let response = handler(&request);
+            let _ = sender.send(request);
+            let _ = write_response(&mut stream, &response);
+        }
+    });
+    (url, receiver)
+}
+
+/// Serves bodies by path, answering other paths with 404. The routes are built from the
+/// server's base URL, so bodies can link to each other.
+pub fn serve_routes(
+    routes: impl FnOnce(&str) -> HashMap<String, Vec<u8>>,
+) -> (String, mpsc::Receiver<Request>) {
+    serve_with(|url| {
+        let routes = routes(url);
+        move |request: &Request| match routes.get(request.path()) {
+            Some(body) => Response::ok(body.clone()),
+            None => Response::not_found(),
+        }
+    })
+}
+
+/// Serves JSON bodies by path, answering other paths with 404.
+pub fn serve_json(routes: HashMap<&str, serde_json::Value>) -> (String, mpsc::Receiver<Request>) {
+    let routes = routes
+        .into_iter()
+        .map(|(path, body)| (path.to_string(), body.to_string().into_bytes()))
+        .collect();
+    serve_routes(|_| routes)
+}
+
+/// Reads a request up to the end of its headers, then the body given by its Content-Length.
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut head = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            break;
+        }
+        head.push(line.to_string());
+    }
+    let mut request = Request {
+        head: head.join("\r\n"),
+        body: Vec::new(),
+    };
+    let length = request
+        .header("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    request.body = vec![0; length];
+    reader.read_exact(&mut request.body).ok()?;
+    Some(request)
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> std::io::Result<()> {
+    write!(stream, "HTTP/1.1 {}\r\n", response.status)?;
+    for (name, value) in &response.headers {
+        write!(stream, "{}: {}\r\n", name, value)?;
+    }
+    write!(
+        stream,
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        response.body.len()
+    )?;
+    stream.write_all(&response.body)
+}
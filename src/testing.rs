@@ -0,0 +1,338 @@
+//! Proptest strategies for generating realistic attestations.
+//!
+//! Enabled with the `testing` feature. The strategies generate statements that pass validation:
+//! digests have the length and encoding of their algorithm, URIs are absolute, and provenance
+//! timestamps are in order. Pipelines can then be property-tested against a wide range of
+//! attestations rather than a handful of fixtures, and the generated values can be tweaked with
+//! `prop_map` to exercise invalid input.
+//!
+//! The main models also implement [`Arbitrary`], so `any::<InTotoStatementV1>()` works.
+
+use base64::{engine::general_purpose, Engine};
+use chrono::{DateTime, Utc};
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::{btree_map, vec};
+use proptest::option;
+use proptest::prelude::*;
+use proptest::sample::select;
+use serde_json::{Map, Value};
+use url::Url;
+
+use crate::models::intoto::predicate::{Predicate, SLSA_PROVENANCE_V02, SLSA_PROVENANCE_V1};
+use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+use crate::models::intoto::statement::{
+    Algorithm, DigestSet, InTotoStatementV1, Subject, STATEMENT_TYPE_V1,
+};
+use crate::models::intoto::uri::UriReference;
+use crate::models::intoto::{provenancev02, provenancev1};
+
+/// Generates any supported digest algorithm.
+pub fn algorithm() -> impl Strategy<Value = Algorithm> {
+    select(Algorithm::ALL)
+}
+
+/// Generates a well-formed digest for the algorithm.
+pub fn digest(algorithm: Algorithm) -> BoxedStrategy<String> {
+    let hex = |len: usize| proptest::string::string_regex(&format!("[0-9a-f]{{{}}}", len)).unwrap();
+    match algorithm {
+        Algorithm::Md5 => hex(32).boxed(),
+        Algorithm::Sha1 | Algorithm::Ripemd160 => hex(40).boxed(),
+        Algorithm::Sha224 | Algorithm::Sha512_224 | Algorithm::Sha3_224 => hex(56).boxed(),
+        Algorithm::Sha384 | Algorithm::Sha3_384 => hex(96).boxed(),
+        Algorithm::Sha512 | Algorithm::Sha3_512 | Algorithm::Blake2b => hex(128).boxed(),
+        Algorithm::GitBlob | Algorithm::GitCommit | Algorithm::GitTag | Algorithm::GitTree => {
+            prop_oneof![hex(40), hex(64)].boxed()
+        }
+        Algorithm::DirHash => any::<[u8; 32]>()
+            .prop_map(|hash| format!("h1:{}", general_purpose::STANDARD.encode(hash)))
+            .boxed(),
+        _ => hex(64).boxed(),
+    }
+}
+
+/// Generates a digest set with one to three algorithms.
+pub fn digest_set() -> impl Strategy<Value = DigestSet> {
+    vec(algorithm(), 1..=3)
+        .prop_flat_map(|algorithms| {
+            algorithms
+                .into_iter()
+                .map(|algorithm| (Just(algorithm), digest(algorithm)))
+                .collect::<Vec<_>>()
+        })
+        .prop_map(|digests| digests.into_iter().collect())
+}
+
+/// Generates a digest map, as used by resource descriptors, with the canonical algorithm names.
+fn digest_map() -> impl Strategy<Value = std::collections::HashMap<String, String>> {
+    digest_set().prop_map(|digests| {
+        digests
+            .iter()
+            .map(|(algorithm, digest)| (algorithm.to_string(), digest.clone()))
+            .collect()
+    })
+}
+
+/// Generates an HTTPS URL under `example.com`.
+pub fn url() -> impl Strategy<Value = Url> {
+    ("[a-z]{1,10}", vec("[a-z0-9-]{1,12}", 0..4)).prop_map(|(host, segments)| {
+        Url::parse(&format!(
+            "https://{}.example.com/{}",
+            host,
+            segments.join("/")
+        ))
+        .unwrap()
+    })
+}
+
+/// Generates a URI reference of the kinds found in provenance: URLs, git URIs and package URLs.
+pub fn uri_reference() -> impl Strategy<Value = UriReference> {
+    prop_oneof![
+        url().prop_map(UriReference::from),
+        ("[a-z]{1,10}", "[a-z0-9-]{1,12}", "[a-z]{1,8}").prop_map(|(org, repo, branch)| {
+            UriReference::parse(&format!(
+                "git+https://github.com/{}/{}@refs/heads/{}",
+                org, repo, branch
+            ))
+            .unwrap()
+        }),
+        ("[a-z][a-z0-9-]{0,12}", 0..20u32, 0..20u32, 0..20u32).prop_map(
+            |(name, major, minor, patch)| {
+                UriReference::parse(&format!("pkg:npm/{}@{}.{}.{}", name, major, minor, patch))
+                    .unwrap()
+            }
+        ),
+    ]
+}
+
+/// Generates a subject named after a file or package.
+pub fn subject() -> impl Strategy<Value = Subject> {
+    (
+        prop_oneof![
+            "[a-z][a-z0-9_-]{0,15}(\\.tar\\.gz|\\.whl|\\.jar)?",
+            uri_reference().prop_map(String::from),
+        ],
+        digest_set(),
+    )
+        .prop_map(|(name, digest)| Subject { name, digest })
+}
+
+/// Generates a small JSON object of string, number and boolean values.
+pub fn parameters() -> impl Strategy<Value = Map<String, Value>> {
+    let leaf = prop_oneof![
+        "[a-zA-Z0-9 ._/-]{0,20}".prop_map(Value::from),
+        any::<i32>().prop_map(Value::from),
+        any::<bool>().prop_map(Value::from),
+    ];
+    btree_map("[a-z_]{1,12}", leaf, 0..5).prop_map(|parameters| parameters.into_iter().collect())
+}
+
+/// Generates a start and finish time, in whole seconds and in order.
+fn build_times() -> impl Strategy<Value = (DateTime<Utc>, DateTime<Utc>)> {
+    (1_500_000_000..2_000_000_000i64, 0..86_400i64).prop_map(|(started, duration)| {
+        (
+            DateTime::from_timestamp(started, 0).unwrap(),
+            DateTime::from_timestamp(started + duration, 0).unwrap(),
+        )
+    })
+}
+
+/// Generates a SLSA Provenance v1 resource descriptor with a URI and digests.
+pub fn resource_descriptor_v1() -> impl Strategy<Value = ResourceDescriptor> {
+    (
+        uri_reference(),
+        option::of(digest_map()),
+        option::of("[a-z][a-z0-9_-]{0,15}"),
+    )
+        .prop_map(|(uri, digest, name)| ResourceDescriptor {
+            uri: Some(uri),
+            digest,
+            name,
+            ..ResourceDescriptor::default()
+        })
+}
+
+/// Generates a SLSA Provenance v1 predicate.
+pub fn slsa_provenance_v1() -> impl Strategy<Value = provenancev1::SLSAProvenanceV1Predicate> {
+    (
+        url(),
+        parameters(),
+        option::of(parameters()),
+        option::of(vec(resource_descriptor_v1(), 0..4)),
+        url(),
+        option::of(("[a-z0-9-]{1,20}", build_times())),
+    )
+        .prop_map(
+            |(build_type, external, internal, dependencies, builder_id, metadata)| {
+                provenancev1::SLSAProvenanceV1Predicate {
+                    build_definition: provenancev1::BuildDefinition {
+                        build_type,
+                        external_parameters: Value::Object(external),
+                        internal_parameters: internal,
+                        resolved_dependencies: dependencies,
+                    },
+                    run_details: provenancev1::RunDetails {
+                        builder: provenancev1::Builder {
+                            id: builder_id,
+                            builder_dependencies: None,
+                            version: None,
+                        },
+                        metadata: metadata.map(|(invocation_id, (started_on, finished_on))| {
+                            provenancev1::BuildMetadata {
+                                invocation_id: Some(invocation_id),
+                                started_on: Some(started_on),
+                                finished_on: Some(finished_on),
+                            }
+                        }),
+                        byproducts: None,
+                    },
+                }
+            },
+        )
+}
+
+/// Generates a SLSA Provenance v0.2 predicate.
+pub fn slsa_provenance_v02() -> impl Strategy<Value = provenancev02::SLSAProvenanceV02Predicate> {
+    let material =
+        (option::of(uri_reference()), digest_map()).prop_map(|(uri, digest)| ResourceDescriptor {
+            uri,
+            digest: Some(digest),
+            ..ResourceDescriptor::default()
+        });
+    (
+        url(),
+        url().prop_map(UriReference::from),
+        option::of(parameters()),
+        option::of(vec(material, 0..4)),
+        option::of(("[a-z0-9-]{1,20}", build_times(), any::<bool>())),
+    )
+        .prop_map(
+            |(builder_id, build_type, parameters, materials, metadata)| {
+                provenancev02::SLSAProvenanceV02Predicate {
+                    builder: provenancev02::Builder { id: builder_id },
+                    build_type,
+                    invocation: parameters.map(|parameters| provenancev02::Invocation {
+                        config_source: None,
+                        parameters: Some(parameters),
+                        environment: None,
+                    }),
+                    build_config: None,
+                    metadata: metadata.map(
+                        |(invocation_id, (started_on, finished_on), reproducible)| {
+                            provenancev02::BuildMetadata {
+                                invocation_id: Some(invocation_id),
+                                started_on: Some(started_on),
+                                finished_on: Some(finished_on),
+                                completeness: None,
+                                reproducible: Some(reproducible),
+                            }
+                        },
+                    ),
+                    materials,
+                }
+            },
+        )
+}
+
+/// Generates a statement with one to three subjects and a SLSA provenance or unknown predicate,
+/// with the matching `predicateType`.
+pub fn statement() -> impl Strategy<Value = InTotoStatementV1> {
+    let predicate = prop_oneof![
+        slsa_provenance_v1().prop_map(|predicate| (
+            Url::parse(SLSA_PROVENANCE_V1).unwrap(),
+            Predicate::SLSAProvenanceV1(predicate)
+        )),
+        slsa_provenance_v02().prop_map(|predicate| (
+            Url::parse(SLSA_PROVENANCE_V02).unwrap(),
+            Predicate::SLSAProvenanceV02(predicate)
+        )),
+        (url(), parameters()).prop_map(|(predicate_type, predicate)| (
+            predicate_type,
+            Predicate::Other(Value::Object(predicate))
+        )),
+    ];
+    (vec(subject(), 1..=3), predicate).prop_map(|(subject, (predicate_type, predicate))| {
+        InTotoStatementV1 {
+            _type: Url::parse(STATEMENT_TYPE_V1).unwrap(),
+            subject,
+            predicate_type,
+            predicate,
+        }
+    })
+}
+
+impl Arbitrary for Algorithm {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        algorithm().boxed()
+    }
+}
+
+impl Arbitrary for DigestSet {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        digest_set().boxed()
+    }
+}
+
+impl Arbitrary for Subject {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        subject().boxed()
+    }
+}
+
+impl Arbitrary for provenancev1::SLSAProvenanceV1Predicate {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        slsa_provenance_v1().boxed()
+    }
+}
+
+impl Arbitrary for provenancev02::SLSAProvenanceV02Predicate {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        slsa_provenance_v02().boxed()
+    }
+}
+
+impl Arbitrary for InTotoStatementV1 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        statement().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "validation")]
+    use crate::validate::{policy::Policy, rules};
+
+    proptest! {
+        #[test]
+        fn test_statement_round_trips(statement in any::<InTotoStatementV1>()) {
+            let json = serde_json::to_value(&statement).unwrap();
+            let parsed: InTotoStatementV1 = serde_json::from_value(json).unwrap();
+            prop_assert_eq!(parsed, statement);
+        }
+
+        #[test]
+        #[cfg(feature = "validation")]
+        fn test_statement_is_valid(statement in statement()) {
+            let report = rules::check_statement(&statement, &Policy::default());
+            prop_assert!(!report.has_errors(), "{:?}", report);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Fulcio signing certificates: their chain up to the trusted root and the identity they certify.
+//!
+//! Fulcio issues a short-lived certificate for the key a signer generated, certifying the
+//! identity of its OIDC token: the workflow of a GitHub Actions build, or an email address. The
+//! certificate is only trusted if its chain leads up to a certificate authority of the trusted
+//! root. Its identity is in the subject alternative names and the Fulcio extensions under
+//! 1.3.6.1.4.1.57264.1, which record the build that requested it, such as its source repository.
+//!
+//! Only ECDSA signatures over P-256 and P-384 keys are supported, which is what Fulcio uses.
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose, Engine};
+use der::{Encode, Tag, TagNumber, Tagged};
+use p256::pkcs8::DecodePublicKey;
+use sha2::{Digest, Sha256, Sha384};
+
+use super::sct::{certificate_validity, invalid, sequence, single, Certificate};
+use super::TrustedRoot;
+
+/// The DER encodings of the OIDs of ecdsa-with-SHA256 and ecdsa-with-SHA384.
+const ECDSA_WITH_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+const ECDSA_WITH_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+
+/// The DER encoding of the OID of the subject alternative name extension, 2.5.29.17.
+pub(super) const SUBJECT_ALT_NAME_OID: &[u8] = &[0x55, 0x1d, 0x11];
+
+/// The DER encoding of the OID of the Fulcio extensions, 1.3.6.1.4.1.57264.1, which the number
+/// of each extension follows.
+pub(super) const FULCIO_OID: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0x83, 0xbf, 0x30, 0x01];
+
+/// The Fulcio extensions read, whose values are raw strings before 8 and DER UTF8Strings from 8.
+const ISSUER_V1: u8 = 1;
+const GITHUB_WORKFLOW_REPOSITORY: u8 = 5;
+const GITHUB_WORKFLOW_REF: u8 = 6;
+const ISSUER: u8 = 8;
+const BUILD_SIGNER_URI: u8 = 9;
+const SOURCE_REPOSITORY_URI: u8 = 12;
+const SOURCE_REPOSITORY_REF: u8 = 14;
+
+/// The most certificates a chain can have between the signing certificate and the trusted root.
+const MAX_CHAIN_LENGTH: usize = 8;
+
+/// The identity Fulcio certified in a signing certificate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CertificateIdentity {
+    /// The URIs and email addresses of the subject alternative name extension.
+    pub subject_alternative_names: Vec<String>,
+    /// The OIDC issuer of the token the certificate was issued for, e.g.
+    /// `https://token.actions.githubusercontent.com`.
+    pub issuer: Option<String>,
+    /// The reference of the workflow that signed, with its ref, e.g.
+    /// `https://github.com/org/repo/.github/workflows/release.yml@refs/tags/v1`.
+    pub build_signer_uri: Option<String>,
+    /// The repository the build was run from, e.g. `https://github.com/org/repo`.
+    pub source_repository_uri: Option<String>,
+    /// The ref of the source repository the build was run at, e.g. `refs/tags/v1`.
+    pub source_repository_ref: Option<String>,
+}
+
+impl CertificateIdentity {
+    /// Returns the builder that signed: the build signer URI, or for certificates from before it
+    /// was recorded, the first URI subject alternative name.
+    pub fn builder(&self) -> Option<&str> {
+        self.build_signer_uri.as_deref().or_else(|| {
+            self.subject_alternative_names
+                .iter()
+                .find(|name| name.contains("://"))
+                .map(String::as_str)
+        })
+    }
+}
+
+/// Returns the identity certified in a DER certificate.
+pub fn certificate_identity(certificate: &[u8]) -> Result<CertificateIdentity> {
+    let certificate = Certificate::parse(certificate)?;
+    let mut identity = CertificateIdentity::default();
+    let (mut workflow_repository, mut workflow_ref) = (None, None);
+    for extension in certificate.extensions()? {
+        let (Some(oid), Some(value)) = (extension.first(), extension.last()) else {
+            continue;
+        };
+        if oid.tag() != Tag::ObjectIdentifier || value.tag() != Tag::OctetString {
+            continue;
+        }
+        if oid.value() == SUBJECT_ALT_NAME_OID {
+            identity.subject_alternative_names = subject_alternative_names(value.value())?;
+            continue;
+        }
+        let Some(&[number]) = oid.value().strip_prefix(FULCIO_OID) else {
+            continue;
+        };
+        let text = if number < ISSUER {
+            String::from_utf8(value.value().to_vec()).ok()
+        } else {
+            let value = single(value.value())?;
+            (value.tag() == Tag::Utf8String)
+                .then(|| String::from_utf8(value.value().to_vec()).ok())
+                .flatten()
+        };
+        let text = text.ok_or_else(|| anyhow!("Invalid Fulcio extension {}", number))?;
+        match number {
+            ISSUER_V1 => identity.issuer = identity.issuer.or(Some(text)),
+            ISSUER => identity.issuer = Some(text),
+            GITHUB_WORKFLOW_REPOSITORY => workflow_repository = Some(text),
+            GITHUB_WORKFLOW_REF => workflow_ref = Some(text),
+            BUILD_SIGNER_URI => identity.build_signer_uri = Some(text),
+            SOURCE_REPOSITORY_URI => identity.source_repository_uri = Some(text),
+            SOURCE_REPOSITORY_REF => identity.source_repository_ref = Some(text),
+            _ => {}
+        }
+    }
+    // Certificates from before the source was recorded only name the GitHub repository.
+    identity.source_repository_uri = identity
+        .source_repository_uri
+        .or(workflow_repository.map(|repository| format!("https://github.com/{}", repository)));
+    identity.source_repository_ref = identity.source_repository_ref.or(workflow_ref);
+    Ok(identity)
+}
+
+/// Verifies a DER certificate was issued by a certificate authority of a trusted root, through
+/// the given DER certificates, such as the rest of a bundle's chain.
+///
+/// Each certificate must be signed by the next, up to one of the certificates of an authority,
+/// and the signing certificate must have been issued while that authority was in use. The
+/// authorities' certificates are trusted as they are, so their own signatures aren't verified.
+pub fn verify_chain(root: &TrustedRoot, certificate: &[u8], chain: &[Vec<u8>]) -> Result<()> {
+    let authorities = root
+        .certificate_authorities
+        .iter()
+        .flat_map(|authority| {
+            authority
+                .cert_chain
+                .certificates
+                .iter()
+                .filter_map(|certificate| {
+                    general_purpose::STANDARD
+                        .decode(&certificate.raw_bytes)
+                        .ok()
+                })
+                .map(move |der| (der, authority))
+        })
+        .collect::<Vec<_>>();
+    let (issued, _) = certificate_validity(certificate)?;
+    let mut current = certificate.to_vec();
+    for _ in 0..MAX_CHAIN_LENGTH {
+        let parsed = Certificate::parse(&current)?;
+        if let Some((_, authority)) = authorities
+            .iter()
+            .find(|(issuer, _)| is_issued_by(&parsed, issuer))
+        {
+            if let Some(valid_for) = &authority.valid_for {
+                if !valid_for.contains(issued) {
+                    bail!(
+                        "Issued by {} at {}, when it wasn't in use",
+                        authority.uri,
+                        issued
+                    );
+                }
+            }
+            return Ok(());
+        }
+        current = chain
+            .iter()
+            .find(|issuer| is_issued_by(&parsed, issuer))
+            .ok_or_else(|| {
+                anyhow!(
+                    "The certificate is not issued by a certificate authority of the trusted root"
+                )
+            })?
+            .clone();
+    }
+    bail!(
+        "The certificate chain is longer than {} certificates",
+        MAX_CHAIN_LENGTH
+    )
+}
+
+/// Returns true if a certificate was issued by the DER issuer: the issuer's subject is the
+/// certificate's issuer, and its key verifies the certificate's signature.
+fn is_issued_by(certificate: &Certificate<'_>, issuer: &[u8]) -> bool {
+    let Ok(issuer) = Certificate::parse(issuer) else {
+        return false;
+    };
+    issuer.subject() == certificate.issuer() && verify_signature(certificate, &issuer).is_ok()
+}
+
+/// Verifies the issuer's signature over a certificate.
+fn verify_signature(certificate: &Certificate<'_>, issuer: &Certificate<'_>) -> Result<()> {
+    use p256::ecdsa::signature::hazmat::PrehashVerifier;
+
+    let tbs = certificate.tbs.to_der().map_err(invalid)?;
+    let digest = match certificate.signature_algorithm.value() {
+        ECDSA_WITH_SHA256 => Sha256::digest(&tbs).to_vec(),
+        ECDSA_WITH_SHA384 => Sha384::digest(&tbs).to_vec(),
+        _ => bail!("Unsupported certificate signature algorithm"),
+    };
+    let key = issuer.public_key().to_der().map_err(invalid)?;
+    let verified = if let Ok(key) = p256::ecdsa::VerifyingKey::from_public_key_der(&key) {
+        p256::ecdsa::Signature::from_der(certificate.signature)
+            .is_ok_and(|signature| key.verify_prehash(&digest, &signature).is_ok())
+    } else if let Ok(key) = p384::ecdsa::VerifyingKey::from_public_key_der(&key) {
+        p384::ecdsa::Signature::from_der(certificate.signature)
+            .is_ok_and(|signature| key.verify_prehash(&digest, &signature).is_ok())
+    } else {
+        bail!("The issuer's key is not an ECDSA P-256 or P-384 key");
+    };
+    if !verified {
+        bail!("The certificate's signature doesn't verify with its issuer's key");
+    }
+    Ok(())
+}
+
+/// Returns the URIs and email addresses of a DER GeneralNames sequence.
+fn subject_alternative_names(names: &[u8]) -> Result<Vec<String>> {
+    let email = context_tag(1);
+    let uri = context_tag(6);
+    Ok(sequence(single(names)?)?
+        .into_iter()
+        .filter(|name| name.tag() == email || name.tag() == uri)
+        .filter_map(|name| String::from_utf8(name.value().to_vec()).ok())
+        .collect())
+}
+
+/// Returns the tag of an implicitly tagged, primitive GeneralName.
+fn context_tag(number: u8) -> Tag {
+    Tag::ContextSpecific {
+        constructed: false,
+        number: TagNumber::new(number),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trust::sct::tests::{fixture, fixture_with_ca, BUILDER, SOURCE};
+
+    #[test]
+    fn test_certificate_identity() {
+        let (issuer, leaf, _) = fixture("leaf");
+        let identity = certificate_identity(&leaf).unwrap();
+        let builder = format!("{}@refs/tags/v1.9.0", BUILDER);
+        assert_eq!(identity.subject_alternative_names, vec![builder.clone()]);
+        assert_eq!(
+            identity.issuer.as_deref(),
+            Some("https://token.actions.githubusercontent.com")
+        );
+        assert_eq!(identity.builder(), Some(builder.as_str()));
+        assert_eq!(identity.source_repository_uri.as_deref(), Some(SOURCE));
+        assert_eq!(
+            identity.source_repository_ref.as_deref(),
+            Some("refs/tags/v1.2.3")
+        );
+
+        let identity = certificate_identity(&issuer).unwrap();
+        assert_eq!(identity, CertificateIdentity::default());
+        assert_eq!(identity.builder(), None);
+        assert!(certificate_identity(b"not a certificate").is_err());
+    }
+
+    #[test]
+    fn test_verify_chain() {
+        let (issuer, leaf, root) = fixture("leaf");
+        assert!(verify_chain(&root, &leaf, &[]).is_ok());
+
+        // The issuer of the bundle's chain must itself lead up to the trusted root.
+        let mut untrusted = root.clone();
+        untrusted.certificate_authorities.clear();
+        assert_eq!(
+            verify_chain(&untrusted, &leaf, std::slice::from_ref(&issuer))
+                .unwrap_err()
+                .to_string(),
+            "The certificate is not issued by a certificate authority of the trusted root"
+        );
+
+        // A certificate with the issuer's name, signed with another key.
+        let (_, _, other_root) = fixture_with_ca("leaf", 9);
+        assert!(verify_chain(&other_root, &leaf, &[]).is_err());
+
+        let mut tampered = leaf.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        assert!(verify_chain(&root, &tampered, &[]).is_err());
+
+        let mut retired = root;
+        retired.certificate_authorities[0].valid_for = Some(crate::trust::TimeRange {
+            start: "2020-01-01T00:00:00Z".parse().unwrap(),
+            end: Some("2024-01-01T00:00:00Z".parse().unwrap()),
+        });
+        assert!(verify_chain(&retired, &leaf, &[])
+            .unwrap_err()
+            .to_string()
+            .contains("when it wasn't in use"));
+    }
+}
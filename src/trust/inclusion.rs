@@ -0,0 +1,398 @@
+//! Offline verification of transparency log inclusion proofs.
+//!
+//! A Sigstore bundle can carry a proof its entry is included in the log's Merkle tree, and a
+//! checkpoint: a note signed by the log committing to the tree's size and root hash. Together
+//! they show the entry was logged without asking the log, as described in RFC 9162. The proof
+//! shows the entry's body is in the tree, but not that the body is about the bundle's
+//! attestation.
+//!
+//! Bundles and Rekor entries can also carry a signed entry timestamp (SET), the log's signed
+//! promise to include the entry, made when it was submitted. It is weaker than a proof, but is
+//! all older bundles have.
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose, Engine};
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use p256::pkcs8::DecodePublicKey;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use super::{normalize_log_id, tuf, TransparencyLogInstance};
+use crate::verify::{InclusionProof, LogEntry};
+
+/// Returns the hash of a leaf of a log's Merkle tree.
+pub fn leaf_hash(body: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([0])
+        .chain_update(body)
+        .finalize()
+        .into()
+}
+
+fn node_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([1])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Returns the root hash of a tree of a size an inclusion proof for the leaf at an index leads
+/// to, or `None` if the proof doesn't fit the tree.
+pub fn root_from_inclusion_proof(
+    index: u64,
+    size: u64,
+    leaf_hash: [u8; 32],
+    proof: &[[u8; 32]],
+) -> Option<[u8; 32]> {
+    if index >= size {
+        return None;
+    }
+    let (mut index, mut last) = (index, size - 1);
+    let mut hash = leaf_hash;
+    for sibling in proof {
+        if last == 0 {
+            return None;
+        }
+        if index & 1 == 1 || index == last {
+            hash = node_hash(sibling, &hash);
+            while index & 1 == 0 && index != 0 {
+                index >>= 1;
+                last >>= 1;
+            }
+        } else {
+            hash = node_hash(&hash, sibling);
+        }
+        index >>= 1;
+        last >>= 1;
+    }
+    (last == 0).then_some(hash)
+}
+
+/// Verifies the inclusion proof of a log entry, and that its checkpoint is signed by the log.
+pub fn verify_inclusion_proof(tlog: &TransparencyLogInstance, entry: &LogEntry) -> Result<()> {
+    let proof = entry
+        .inclusion_proof
+        .as_ref()
+        .ok_or_else(|| anyhow!("The entry has no inclusion proof"))?;
+    let body = entry
+        .canonicalized_body
+        .as_deref()
+        .ok_or_else(|| anyhow!("The entry has no body to prove the inclusion of"))?;
+    let body = decode(body, "body")?;
+    let hashes = proof
+        .hashes
+        .iter()
+        .map(|hash| {
+            decode(hash, "proof hash")?
+                .try_into()
+                .map_err(|_| anyhow!("Invalid proof hash {}", hash))
+        })
+        .collect::<Result<Vec<[u8; 32]>>>()?;
+    let root =
+        root_from_inclusion_proof(proof.log_index, proof.tree_size, leaf_hash(&body), &hashes)
+            .ok_or_else(|| {
+                anyhow!(
+                    "The proof doesn't fit entry {} of a tree of {}",
+                    proof.log_index,
+                    proof.tree_size
+                )
+            })?;
+    if root.as_slice() != decode(&proof.root_hash, "root hash")? {
+        bail!("The proof leads to another root hash than the proof's");
+    }
+    verify_checkpoint(tlog, proof)
+}
+
+/// Verifies the checkpoint of a proof is for its tree, and signed with the key of the log.
+fn verify_checkpoint(tlog: &TransparencyLogInstance, proof: &InclusionProof) -> Result<()> {
+    let checkpoint = proof
+        .checkpoint
+        .as_deref()
+        .ok_or_else(|| anyhow!("The proof has no checkpoint signed by the log"))?;
+    // A signed note is its text, a blank line and a line per signature.
+    let (text, signatures) = checkpoint
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow!("The checkpoint is not signed"))?;
+    let mut lines = text.lines().skip(1);
+    let tree_size = lines.next().and_then(|size| size.parse::<u64>().ok());
+    let root_hash = lines.next();
+    if tree_size != Some(proof.tree_size) || root_hash != Some(proof.root_hash.as_str()) {
+        bail!("The checkpoint is for another tree than the proof");
+    }
+
+    let key = verifying_key(tlog)?;
+    let message = format!("{}\n", text);
+    let signed = signatures
+        .lines()
+        .filter_map(|line| line.strip_prefix("\u{2014} "))
+        .filter_map(|line| line.rsplit_once(' '))
+        .filter_map(|(_, signature)| general_purpose::STANDARD.decode(signature).ok())
+        // Each signature starts with a 4-byte hint of the key.
+        .filter_map(|signature| Signature::from_der(signature.get(4..)?).ok())
+        .any(|signature| key.verify(message.as_bytes(), &signature).is_ok());
+    if !signed {
+        bail!("The checkpoint is not signed by {}", tlog.base_url);
+    }
+    Ok(())
+}
+
+/// Verifies the signed entry timestamp of a log entry is signed by the log, over the entry's
+/// body, integrated time, log id and index.
+pub fn verify_signed_entry_timestamp(
+    tlog: &TransparencyLogInstance,
+    entry: &LogEntry,
+) -> Result<()> {
+    let timestamp = entry
+        .signed_entry_timestamp
+        .as_deref()
+        .ok_or_else(|| anyhow!("The entry has no signed entry timestamp"))?;
+    let (Some(body), Some(integrated_time), Some(log_index)) = (
+        entry.canonicalized_body.as_deref(),
+        entry.integrated_time,
+        entry.log_index,
+    ) else {
+        bail!("The entry lacks the body, integrated time or index its timestamp is signed over");
+    };
+    let log_id = normalize_log_id(&entry.log_id)
+        .ok_or_else(|| anyhow!("Invalid log id {}", entry.log_id))?;
+    // Rekor signs the canonical JSON of these, with the log id in hex.
+    let message = tuf::canonical(&json!({
+        "body": body,
+        "integratedTime": integrated_time,
+        "logID": tuf::encode_hex(&log_id),
+        "logIndex": log_index,
+    }))?;
+    let signature = Signature::from_der(&decode(timestamp, "signed entry timestamp")?)
+        .map_err(|e| anyhow!("Invalid signed entry timestamp: {}", e))?;
+    verifying_key(tlog)?
+        .verify(&message, &signature)
+        .map_err(|_| {
+            anyhow!(
+                "The signed entry timestamp is not signed by {}",
+                tlog.base_url
+            )
+        })
+}
+
+fn verifying_key(tlog: &TransparencyLogInstance) -> Result<VerifyingKey> {
+    general_purpose::STANDARD
+        .decode(&tlog.public_key.raw_bytes)
+        .ok()
+        .and_then(|der| VerifyingKey::from_public_key_der(&der).ok())
+        .ok_or_else(|| anyhow!("The key of {} is not an ECDSA P-256 key", tlog.base_url))
+}
+
+fn decode(value: &str, what: &str) -> Result<Vec<u8>> {
+    general_purpose::STANDARD
+        .decode(value)
+        .map_err(|e| anyhow!("Invalid {} {}: {}", what, value, e))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use p256::ecdsa::{signature::Signer, SigningKey};
+    use p256::pkcs8::EncodePublicKey;
+
+    /// Returns the root hash of a tree of leaves, as defined in RFC 9162.
+    fn tree_hash(leaves: &[[u8; 32]]) -> [u8; 32] {
+        if leaves.len() == 1 {
+            return leaves[0];
+        }
+        let split = split(leaves.len());
+        node_hash(&tree_hash(&leaves[..split]), &tree_hash(&leaves[split..]))
+    }
+
+    /// Returns the inclusion proof of a leaf, as defined in RFC 9162.
+    fn path(index: usize, leaves: &[[u8; 32]]) -> Vec<[u8; 32]> {
+        if leaves.len() == 1 {
+            return Vec::new();
+        }
+        let split = split(leaves.len());
+        if index < split {
+            let mut path = path(index, &leaves[..split]);
+            path.push(tree_hash(&leaves[split..]));
+            path
+        } else {
+            let mut path = path(index - split, &leaves[split..]);
+            path.push(tree_hash(&leaves[..split]));
+            path
+        }
+    }
+
+    /// Returns the largest power of two smaller than a size.
+    fn split(size: usize) -> usize {
+        1 << (usize::BITS - (size - 1).leading_zeros() - 1)
+    }
+
+    /// Returns a log signing with a key, and an entry of it for a body with a proof of its
+    /// inclusion in a tree of 7 entries and a signed entry timestamp.
+    pub(crate) fn logged_entry(body: &[u8]) -> (TransparencyLogInstance, LogEntry) {
+        let key = SigningKey::from_bytes(&[6; 32].into()).unwrap();
+        let spki = key.verifying_key().to_public_key_der().unwrap();
+        let mut leaves = (0..7u8).map(|i| leaf_hash(&[i])).collect::<Vec<_>>();
+        leaves[5] = leaf_hash(body);
+        let root_hash = general_purpose::STANDARD.encode(tree_hash(&leaves));
+
+        let text = format!("rekor.example.com - 1\n7\n{}\n", root_hash);
+        let signature: Signature = key.sign(text.as_bytes());
+        let mut note_signature = vec![0xde, 0xad, 0xbe, 0xef];
+        note_signature.extend(signature.to_der().as_bytes());
+        let checkpoint = format!(
+            "{}\n\u{2014} rekor.example.com {}\n",
+            text,
+            general_purpose::STANDARD.encode(note_signature)
+        );
+
+        let tlog: TransparencyLogInstance = serde_json::from_value(json!({
+            "baseUrl": "https://rekor.example.com",
+            "hashAlgorithm": "SHA2_256",
+            "publicKey": {
+                "rawBytes": general_purpose::STANDARD.encode(spki.as_bytes()),
+                "keyDetails": "PKIX_ECDSA_P256_SHA_256"
+            },
+            "logId": {"keyId": general_purpose::STANDARD.encode(Sha256::digest(spki.as_bytes()))}
+        }))
+        .unwrap();
+        let mut entry = LogEntry {
+            log_id: tlog.log_id.key_id.clone(),
+            log_index: Some(25_915_956),
+            integrated_time: Some(1_700_000_000),
+            canonicalized_body: Some(general_purpose::STANDARD.encode(body)),
+            inclusion_proof: Some(InclusionProof {
+                log_index: 5,
+                root_hash,
+                tree_size: 7,
+                hashes: path(5, &leaves)
+                    .iter()
+                    .map(|hash| general_purpose::STANDARD.encode(hash))
+                    .collect(),
+                checkpoint: Some(checkpoint),
+            }),
+            signed_entry_timestamp: None,
+        };
+        sign_entry_timestamp(&mut entry, 1_700_000_000);
+        (tlog, entry)
+    }
+
+    /// Sets the integrated time of an entry of the log of [`logged_entry`], signing a new
+    /// signed entry timestamp for it.
+    pub(crate) fn sign_entry_timestamp(entry: &mut LogEntry, integrated_time: i64) {
+        let key = SigningKey::from_bytes(&[6; 32].into()).unwrap();
+        let spki = key.verifying_key().to_public_key_der().unwrap();
+        let message = tuf::canonical(&json!({
+            "body": entry.canonicalized_body,
+            "integratedTime": integrated_time,
+            "logID": tuf::encode_hex(&Sha256::digest(spki.as_bytes())),
+            "logIndex": entry.log_index,
+        }))
+        .unwrap();
+        let signature: Signature = key.sign(&message);
+        entry.integrated_time = Some(integrated_time);
+        entry.signed_entry_timestamp =
+            Some(general_purpose::STANDARD.encode(signature.to_der().as_bytes()));
+    }
+
+    #[test]
+    fn test_root_from_inclusion_proof() {
+        for size in 1..=9 {
+            let leaves = (0..size as u8).map(|i| leaf_hash(&[i])).collect::<Vec<_>>();
+            let root = tree_hash(&leaves);
+            for index in 0..size {
+                let proof = path(index, &leaves);
+                assert_eq!(
+                    root_from_inclusion_proof(index as u64, size as u64, leaves[index], &proof),
+                    Some(root),
+                    "leaf {} of {}",
+                    index,
+                    size
+                );
+            }
+            assert_eq!(
+                root_from_inclusion_proof(size as u64, size as u64, leaves[0], &[]),
+                None
+            );
+        }
+        let leaf = leaf_hash(&[0]);
+        assert_eq!(root_from_inclusion_proof(0, 1, leaf, &[leaf]), None);
+        assert_eq!(root_from_inclusion_proof(0, 4, leaf, &[leaf]), None);
+    }
+
+    #[test]
+    fn test_verify_inclusion_proof() {
+        let (tlog, entry) = logged_entry(b"{\"kind\":\"dsse\"}");
+        verify_inclusion_proof(&tlog, &entry).unwrap();
+
+        let mut other_body = entry.clone();
+        other_body.canonicalized_body = Some(general_purpose::STANDARD.encode("{}"));
+        assert_eq!(
+            verify_inclusion_proof(&tlog, &other_body)
+                .unwrap_err()
+                .to_string(),
+            "The proof leads to another root hash than the proof's"
+        );
+
+        let mut other_log = tlog.clone();
+        let other_key = SigningKey::from_bytes(&[7; 32].into()).unwrap();
+        let spki = other_key.verifying_key().to_public_key_der().unwrap();
+        other_log.public_key.raw_bytes = general_purpose::STANDARD.encode(spki.as_bytes());
+        assert_eq!(
+            verify_inclusion_proof(&other_log, &entry)
+                .unwrap_err()
+                .to_string(),
+            "The checkpoint is not signed by https://rekor.example.com"
+        );
+
+        let mut unsigned = entry.clone();
+        if let Some(proof) = unsigned.inclusion_proof.as_mut() {
+            proof.checkpoint = None;
+        }
+        assert!(verify_inclusion_proof(&tlog, &unsigned).is_err());
+        assert!(verify_inclusion_proof(
+            &tlog,
+            &LogEntry {
+                inclusion_proof: None,
+                ..entry
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_signed_entry_timestamp() {
+        let (tlog, entry) = logged_entry(b"{\"kind\":\"hashedrekord\"}");
+        verify_signed_entry_timestamp(&tlog, &entry).unwrap();
+
+        let mut hex = entry.clone();
+        let log_id = normalize_log_id(&entry.log_id).unwrap();
+        hex.log_id = tuf::encode_hex(&log_id);
+        verify_signed_entry_timestamp(&tlog, &hex).unwrap();
+
+        let mut other_index = entry.clone();
+        other_index.log_index = Some(1);
+        assert_eq!(
+            verify_signed_entry_timestamp(&tlog, &other_index)
+                .unwrap_err()
+                .to_string(),
+            "The signed entry timestamp is not signed by https://rekor.example.com"
+        );
+        assert!(verify_signed_entry_timestamp(
+            &tlog,
+            &LogEntry {
+                log_index: None,
+                ..entry.clone()
+            }
+        )
+        .is_err());
+        assert!(verify_signed_entry_timestamp(
+            &tlog,
+            &LogEntry {
+                signed_entry_timestamp: None,
+                ..entry
+            }
+        )
+        .is_err());
+    }
+}
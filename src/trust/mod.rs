@@ -0,0 +1,863 @@
+//! Sigstore trust roots: the keys of the transparency logs and the certificate and timestamp
+//! authorities attestations are checked against.
+//!
+//! Sigstore publishes its trust root as the `trusted_root.json` target of a TUF repository, so
+//! it can be rotated without users passing keys around. [`tuf::TufClient`] fetches and verifies
+//! it, starting from a pinned TUF root, and [`TrustedRoot::from_file`] reads a pinned copy for
+//! offline use.
+//!
+//! The transparency log entries of bundles and Rekor, their [inclusion proofs](inclusion) and
+//! signed entry timestamps, and the [SCTs](sct) of their signing certificates are checked
+//! against the trust root, all without network access. The entries must also have been logged
+//! while the signing certificate was valid, as their signed entry timestamps show. The
+//! [signatures](signature) of the envelopes are verified with a signing certificate whose
+//! [chain](certificate) leads up to the trust root, or with a key the verifier pinned. The
+//! signatures of timestamp authorities aren't verified yet.
+
+pub mod certificate;
+pub mod inclusion;
+pub mod sct;
+pub mod signature;
+pub mod tuf;
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine};
+use chrono::{DateTime, Utc};
+use p256::ecdsa::VerifyingKey;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::{debug, instrument};
+
+pub use signature::Signer;
+
+use crate::validate::report::{Finding, ValidationReport};
+use crate::verify::{LogEntry, SignedStatement};
+
+/// The media types of the trusted root versions this crate reads.
+pub const TRUSTED_ROOT_MEDIA_TYPES: &[&str] = &[
+    "application/vnd.dev.sigstore.trustedroot+json;version=0.1",
+    "application/vnd.dev.sigstore.trustedroot.v0.2+json",
+];
+
+/// A Sigstore trusted root, as published in `trusted_root.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TrustedRoot {
+    pub media_type: String,
+    /// The transparency logs, such as Rekor, attestations are recorded in.
+    pub tlogs: Vec<TransparencyLogInstance>,
+    /// The certificate authorities, such as Fulcio, issuing signing certificates.
+    pub certificate_authorities: Vec<CertificateAuthority>,
+    /// The certificate transparency logs signing certificates are recorded in.
+    pub ctlogs: Vec<TransparencyLogInstance>,
+    /// The authorities countersigning signatures with a trusted time.
+    pub timestamp_authorities: Vec<CertificateAuthority>,
+}
+
+/// A transparency log and the key it signs entries with.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TransparencyLogInstance {
+    pub base_url: String,
+    pub hash_algorithm: String,
+    pub public_key: PublicKey,
+    pub log_id: LogId,
+}
+
+/// A public key and when it was in use.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PublicKey {
+    /// The base64 DER encoding of the key.
+    pub raw_bytes: String,
+    /// The kind of key, e.g. `PKIX_ECDSA_P256_SHA_256`.
+    pub key_details: String,
+    pub valid_for: Option<TimeRange>,
+}
+
+/// The id of a log, the SHA-256 digest of its key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LogId {
+    /// The base64 id.
+    pub key_id: String,
+}
+
+/// An authority issuing certificates or timestamps.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CertificateAuthority {
+    pub subject: DistinguishedName,
+    pub uri: String,
+    pub cert_chain: CertificateChain,
+    pub valid_for: Option<TimeRange>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DistinguishedName {
+    pub organization: String,
+    pub common_name: String,
+}
+
+/// A certificate chain, starting with the leaf.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CertificateChain {
+    pub certificates: Vec<Certificate>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Certificate {
+    /// The base64 DER encoding of the certificate.
+    pub raw_bytes: String,
+}
+
+/// When a key or authority was in use. Without an end, it still is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeRange {
+    pub start: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl TimeRange {
+    /// Returns true if the time is within the range.
+    pub fn contains(&self, time: DateTime<Utc>) -> bool {
+        time >= self.start && self.end.is_none_or(|end| time <= end)
+    }
+}
+
+impl TrustedRoot {
+    /// Parses a trusted root, failing on media types other than those in
+    /// [`TRUSTED_ROOT_MEDIA_TYPES`].
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        let root: Self =
+            serde_json::from_slice(bytes).map_err(|e| anyhow!("Invalid trusted root: {}", e))?;
+        if !TRUSTED_ROOT_MEDIA_TYPES.contains(&root.media_type.as_str()) {
+            return Err(anyhow!(
+                "Unsupported trusted root media type {}",
+                root.media_type
+            ));
+        }
+        Ok(root)
+    }
+
+    /// Reads a pinned trusted root.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        Self::from_slice(&std::fs::read(path)?).map_err(|e| anyhow!("{}: {}", path.display(), e))
+    }
+
+    /// Returns the transparency log with the id, given in base64 or hex.
+    pub fn tlog(&self, log_id: &str) -> Option<&TransparencyLogInstance> {
+        let log_id = normalize_log_id(log_id)?;
+        self.tlogs
+            .iter()
+            .find(|tlog| normalize_log_id(&tlog.log_id.key_id).as_ref() == Some(&log_id))
+    }
+}
+
+/// Decodes a log id given in base64, as in trusted roots and bundles, or in hex, as Rekor
+/// returns it.
+fn normalize_log_id(log_id: &str) -> Option<Vec<u8>> {
+    if log_id.len() == 64 && log_id.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return tuf::decode_hex(log_id);
+    }
+    general_purpose::STANDARD.decode(log_id).ok()
+}
+
+/// Runs all the checks of the attestations against a trusted root and the pinned keys:
+/// [transparency logs](check_transparency_logs), [inclusion proofs](check_inclusion_proofs),
+/// [certificates](check_certificates), [signing times](check_signing_times) and
+/// [signatures](check_signatures). Attestations signed with a key rather than a certificate have
+/// no validity window to check, so they fail unless the key is pinned.
+#[instrument(skip_all, fields(attestations = attestations.len()))]
+pub fn check(
+    root: &TrustedRoot,
+    attestations: &[SignedStatement],
+    pinned: &[VerifyingKey],
+) -> ValidationReport {
+    let mut report = check_transparency_logs(root, attestations);
+    report.extend(check_inclusion_proofs(root, attestations).findings);
+    report.extend(check_certificates(root, attestations).findings);
+    report.extend(check_signing_times(root, attestations).findings);
+    report.extend(check_signatures(root, attestations, pinned).findings);
+    debug!(
+        errors = report.errors().count(),
+        "Checked the attestations against the trusted root"
+    );
+    report
+}
+
+/// Checks the transparency log entries of the attestations against a trusted root.
+///
+/// Entries in a log the root doesn't know get an `unknown-transparency-log` error, and entries
+/// integrated while the log's key wasn't in use a `log-key-not-valid` error. Findings point into
+/// the attestations with their index as the first segment of the path.
+pub fn check_transparency_logs(
+    root: &TrustedRoot,
+    attestations: &[SignedStatement],
+) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    for (i, signed) in attestations.iter().enumerate() {
+        for (j, entry) in signed.log_entries.iter().enumerate() {
+            let path = format!("/{}/tlogEntries/{}", i, j);
+            let Some(tlog) = root.tlog(&entry.log_id) else {
+                report.push(Finding::error(
+                    "unknown-transparency-log",
+                    format!("{}/logId", path),
+                    format!("Log {} is not in the trusted root", entry.log_id),
+                ));
+                continue;
+            };
+            let integrated = entry
+                .integrated_time
+                .and_then(|time| DateTime::from_timestamp(time, 0));
+            if let (Some(valid_for), Some(integrated)) = (&tlog.public_key.valid_for, integrated) {
+                if !valid_for.contains(integrated) {
+                    report.push(Finding::error(
+                        "log-key-not-valid",
+                        format!("{}/integratedTime", path),
+                        format!(
+                            "Integrated into {} at {}, when its key wasn't in use",
+                            tlog.base_url, integrated
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+    report
+}
+
+/// Checks the inclusion proofs of the transparency log entries of the attestations, and that
+/// their checkpoints and signed entry timestamps are signed by the logs of a trusted root.
+///
+/// Proofs that don't verify get an `invalid-inclusion-proof` error, and entries without one a
+/// `no-inclusion-proof` warning, as older bundles only carry the log's promise to include the
+/// entry. Signed entry timestamps that don't verify get an `invalid-signed-entry-timestamp`
+/// error, and entries with neither a proof nor a signed entry timestamp a `no-log-evidence`
+/// error, as nothing shows the log integrated them. Entries in logs the root doesn't know are left to [`check_transparency_logs`].
+pub fn check_inclusion_proofs(
+    root: &TrustedRoot,
+    attestations: &[SignedStatement],
+) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    for (i, signed) in attestations.iter().enumerate() {
+        for (j, entry) in signed.log_entries.iter().enumerate() {
+            let Some(tlog) = root.tlog(&entry.log_id) else {
+                continue;
+            };
+            let path = format!("/{}/tlogEntries/{}", i, j);
+            if entry.inclusion_proof.is_none() && entry.signed_entry_timestamp.is_none() {
+                report.push(Finding::error(
+                    "no-log-evidence",
+                    path.clone(),
+                    format!(
+                        "The entry has neither a proof of its inclusion in {} nor a signed entry \
+                         timestamp",
+                        tlog.base_url
+                    ),
+                ));
+            } else if entry.inclusion_proof.is_none() {
+                report.push(Finding::warning(
+                    "no-inclusion-proof",
+                    path.clone(),
+                    format!(
+                        "The entry has no proof of its inclusion in {}",
+                        tlog.base_url
+                    ),
+                ));
+            } else if let Err(e) = inclusion::verify_inclusion_proof(tlog, entry) {
+                report.push(Finding::error(
+                    "invalid-inclusion-proof",
+                    format!("{}/inclusionProof", path),
+                    e.to_string(),
+                ));
+            }
+            if entry.signed_entry_timestamp.is_some() {
+                if let Err(e) = inclusion::verify_signed_entry_timestamp(tlog, entry) {
+                    report.push(Finding::error(
+                        "invalid-signed-entry-timestamp",
+                        format!("{}/inclusionPromise", path),
+                        e.to_string(),
+                    ));
+                }
+            }
+        }
+    }
+    report
+}
+
+/// Verifies the envelope signature of an attestation, returning who signed it: the signing
+/// certificate of a Sigstore bundle, whose chain must lead up to a certificate authority of a
+/// trusted root, or otherwise one of the pinned keys. The keys its transparency log entries
+/// record aren't trusted, as anyone can log an entry signed with their own key.
+///
+/// Bare statements fail with an `unsigned` error, certificates that don't chain up to the root
+/// with an `untrusted-certificate` error, attestations signed with a key when none is pinned
+/// with a `no-signing-key` error, and those none of whose signatures verify with an
+/// `invalid-signature` error. The paths of the findings are relative to the attestation.
+pub fn verify_signature(
+    root: &TrustedRoot,
+    signed: &SignedStatement,
+    pinned: &[VerifyingKey],
+) -> Result<Signer, Finding> {
+    let Some(envelope) = &signed.envelope else {
+        return Err(Finding::error(
+            "unsigned",
+            "",
+            "The statement is not in a signed envelope",
+        ));
+    };
+    let invalid =
+        |e: anyhow::Error| Finding::error("invalid-signature", "/signatures", e.to_string());
+    if let Some(certificate) = signed.certificates.first() {
+        let (key, signer) =
+            signature::certificate_signer(root, &signed.certificates).map_err(|e| {
+                Finding::error(
+                    "untrusted-certificate",
+                    "/certificates/0",
+                    format!("{}: {}", truncate(certificate), e),
+                )
+            })?;
+        signature::verify_envelope(envelope, &[key]).map_err(invalid)?;
+        return Ok(signer);
+    }
+    if pinned.is_empty() {
+        return Err(Finding::error(
+            "no-signing-key",
+            "",
+            "The attestation is signed with a key rather than a certificate, and no keys are \
+             pinned to verify it with",
+        ));
+    }
+    let key = signature::verify_envelope(envelope, pinned).map_err(invalid)?;
+    Ok(Signer::Key(signature::key_fingerprint(&key)))
+}
+
+/// Checks the envelope signatures of the attestations, as [`verify_signature`] does. Findings
+/// point into the attestations with their index as the first segment of the path.
+pub fn check_signatures(
+    root: &TrustedRoot,
+    attestations: &[SignedStatement],
+    pinned: &[VerifyingKey],
+) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    for (i, signed) in attestations.iter().enumerate() {
+        if let Err(mut finding) = verify_signature(root, signed, pinned) {
+            finding.path = format!("/{}{}", i, finding.path);
+            report.push(finding);
+        }
+    }
+    report
+}
+
+/// Checks the signing certificates of the attestations were logged in a certificate
+/// transparency log of a trusted root, by verifying the SCTs embedded in them.
+///
+/// Certificates whose SCTs can't be verified get a `certificate-not-logged` error pointing at
+/// the certificate, with the index of the attestation as the first segment of the path.
+/// Attestations signed with a key rather than a certificate aren't checked here, as
+/// [`check_signatures`] only accepts them when the key is pinned.
+pub fn check_certificates(
+    root: &TrustedRoot,
+    attestations: &[SignedStatement],
+) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    for (i, signed) in attestations.iter().enumerate() {
+        let Some(certificate) = signed.certificates.first() else {
+            continue;
+        };
+        let decoded = signed
+            .certificates
+            .iter()
+            .map(|certificate| general_purpose::STANDARD.decode(certificate))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("Invalid base64: {}", e));
+        let verified =
+            decoded.and_then(|chain| sct::verify_embedded_scts(root, &chain[0], &chain[1..]));
+        if let Err(e) = verified {
+            report.push(Finding::error(
+                "certificate-not-logged",
+                format!("/{}/certificates/0", i),
+                format!("{}: {}", truncate(certificate), e),
+            ));
+        }
+    }
+    report
+}
+
+/// Returns when a transparency log entry was integrated, if a log of a trusted root vouches for
+/// it with a signed entry timestamp. An inclusion proof doesn't cover the integrated time, so
+/// without a signed entry timestamp that verifies it is only what the bundle claims.
+pub fn verified_integrated_time(root: &TrustedRoot, entry: &LogEntry) -> Option<DateTime<Utc>> {
+    let tlog = root.tlog(&entry.log_id)?;
+    inclusion::verify_signed_entry_timestamp(tlog, entry).ok()?;
+    DateTime::from_timestamp(entry.integrated_time?, 0)
+}
+
+/// Checks the transparency log entries of the attestations were integrated while their signing
+/// certificate was valid, by the time their [signed entry timestamps](verified_integrated_time)
+/// vouch for.
+///
+/// As the log's time is when the signature was made, an entry outside the certificate's
+/// validity gets a `signed-outside-certificate-validity` error: the signature was backdated, or
+/// made with an expired certificate. Entries without a verified time, and certificates without
+/// any entries, get a `no-signing-time` error, as nothing shows the certificate was used while
+/// valid. Certificates that can't be read get an `invalid-certificate` error. Attestations
+/// signed with a key rather than a certificate have no validity window, so they aren't checked
+/// here, and [`check_signatures`] only accepts them when the key is pinned.
+pub fn check_signing_times(
+    root: &TrustedRoot,
+    attestations: &[SignedStatement],
+) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    for (i, signed) in attestations.iter().enumerate() {
+        let Some(certificate) = signed.certificates.first() else {
+            continue;
+        };
+        let validity = general_purpose::STANDARD
+            .decode(certificate)
+            .map_err(|e| anyhow!("Invalid base64: {}", e))
+            .and_then(|der| sct::certificate_validity(&der));
+        let (not_before, not_after) = match validity {
+            Ok(validity) => validity,
+            Err(e) => {
+                report.push(Finding::error(
+                    "invalid-certificate",
+                    format!("/{}/certificates/0", i),
+                    format!("{}: {}", truncate(certificate), e),
+                ));
+                continue;
+            }
+        };
+        if signed.log_entries.is_empty() {
+            report.push(Finding::error(
+                "no-signing-time",
+                format!("/{}", i),
+                "The attestation has no transparency log entry to show when it was signed",
+            ));
+        }
+        for (j, entry) in signed.log_entries.iter().enumerate() {
+            let path = format!("/{}/tlogEntries/{}", i, j);
+            let Some(integrated) = verified_integrated_time(root, entry) else {
+                report.push(Finding::error(
+                    "no-signing-time",
+                    path,
+                    "The entry has no signed entry timestamp from a trusted log to show when it \
+                     was integrated",
+                ));
+                continue;
+            };
+            if integrated < not_before || integrated > not_after {
+                report.push(Finding::error(
+                    "signed-outside-certificate-validity",
+                    format!("{}/integratedTime", path),
+                    format!(
+                        "Logged at {}, but the signing certificate is only valid from {} to {}",
+                        integrated, not_before, not_after
+                    ),
+                ));
+            }
+        }
+    }
+    report
+}
+
+fn truncate(certificate: &str) -> String {
+    match certificate.get(..16) {
+        Some(start) if start.len() < certificate.len() => format!("{}...", start),
+        _ => certificate.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::report::Severity;
+    use serde_json::json;
+
+    pub(crate) fn trusted_root() -> serde_json::Value {
+        json!({
+            "mediaType": "application/vnd.dev.sigstore.trustedroot+json;version=0.1",
+            "tlogs": [{
+                "baseUrl": "https://rekor.sigstore.dev",
+                "hashAlgorithm": "SHA2_256",
+                "publicKey": {
+                    "rawBytes": "MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE",
+                    "keyDetails": "PKIX_ECDSA_P256_SHA_256",
+                    "validFor": {"start": "2021-01-12T11:53:27.000Z"}
+                },
+                "logId": {"keyId": "wNI9atQGlz+VWfO6LRygH4QUfY/8W4RFwiT5i5WRgB0="}
+            }],
+            "certificateAuthorities": [{
+                "subject": {"organization": "sigstore.dev", "commonName": "sigstore"},
+                "uri": "https://fulcio.sigstore.dev",
+                "certChain": {"certificates": [{"rawBytes": "MIIB"}]},
+                "validFor": {"start": "2022-04-13T20:06:15.000Z"}
+            }],
+            "ctlogs": [],
+            "timestampAuthorities": []
+        })
+    }
+
+    #[test]
+    fn test_trusted_root() {
+        let root = TrustedRoot::from_slice(trusted_root().to_string().as_bytes()).unwrap();
+        assert_eq!(
+            root.certificate_authorities[0].uri,
+            "https://fulcio.sigstore.dev"
+        );
+        let hex = "c0d23d6ad406973f9559f3ba2d1ca01f84147d8ffc5b8445c224f98b9591801d";
+        assert_eq!(
+            root.tlog(hex).map(|tlog| tlog.base_url.as_str()),
+            Some("https://rekor.sigstore.dev")
+        );
+        assert!(root.tlog("AAAA").is_none());
+
+        let mut other = trusted_root();
+        other["mediaType"] = json!("application/json");
+        assert!(TrustedRoot::from_slice(other.to_string().as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_check_transparency_logs() {
+        let root = TrustedRoot::from_slice(trusted_root().to_string().as_bytes()).unwrap();
+        let signed = SignedStatement {
+            statement: json!({}),
+            key_ids: Vec::new(),
+            signatures: 1,
+            certificates: Vec::new(),
+            envelope: None,
+            log_entries: vec![
+                LogEntry {
+                    log_id: "wNI9atQGlz+VWfO6LRygH4QUfY/8W4RFwiT5i5WRgB0=".to_string(),
+                    integrated_time: Some(1_700_000_000),
+                    ..LogEntry::default()
+                },
+                LogEntry {
+                    log_id: "wNI9atQGlz+VWfO6LRygH4QUfY/8W4RFwiT5i5WRgB0=".to_string(),
+                    integrated_time: Some(1_500_000_000),
+                    ..LogEntry::default()
+                },
+                LogEntry {
+                    log_id: "AAAA".to_string(),
+                    integrated_time: None,
+                    ..LogEntry::default()
+                },
+            ],
+        };
+        let report = check_transparency_logs(&root, &[signed]);
+        let codes = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                ("log-key-not-valid", "/0/tlogEntries/1/integratedTime"),
+                ("unknown-transparency-log", "/0/tlogEntries/2/logId"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_certificates() {
+        let (issuer, leaf, root) = sct::tests::fixture("leaf");
+        let (_, other, _) = sct::tests::fixture("other");
+        let signed = |chain: &[&Vec<u8>]| SignedStatement {
+            statement: json!({}),
+            key_ids: Vec::new(),
+            signatures: 1,
+            log_entries: Vec::new(),
+            certificates: chain
+                .iter()
+                .map(|der| general_purpose::STANDARD.encode(der))
+                .collect(),
+            envelope: None,
+        };
+        let report = check_certificates(
+            &root,
+            &[
+                signed(&[]),
+                signed(&[&leaf, &issuer]),
+                signed(&[&other]),
+                signed(&[&issuer]),
+            ],
+        );
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                ("certificate-not-logged", "/2/certificates/0"),
+                ("certificate-not-logged", "/3/certificates/0"),
+            ]
+        );
+        assert!(report.findings[1]
+            .message
+            .ends_with("The certificate has no SCTs"));
+    }
+
+    #[test]
+    fn test_check_signing_times() {
+        let (_, leaf, _) = sct::tests::fixture("leaf");
+        let (tlog, logged) = inclusion::tests::logged_entry(b"{}");
+        let root = TrustedRoot {
+            tlogs: vec![tlog],
+            ..TrustedRoot::default()
+        };
+        let entry = |time| {
+            let mut entry = logged.clone();
+            inclusion::tests::sign_entry_timestamp(&mut entry, time);
+            entry
+        };
+        let signed = |certificates: Vec<String>| SignedStatement {
+            statement: json!({}),
+            key_ids: Vec::new(),
+            signatures: 1,
+            // 2030, 2024 and 2036, against a certificate valid from 2025 to 2035, then 2030
+            // with a time the log didn't sign.
+            log_entries: vec![
+                entry(1_900_000_000),
+                entry(1_700_000_000),
+                entry(2_100_000_000),
+                LogEntry {
+                    integrated_time: Some(1_900_000_000),
+                    ..entry(1_700_000_000)
+                },
+                LogEntry {
+                    signed_entry_timestamp: None,
+                    ..entry(1_900_000_000)
+                },
+            ],
+            certificates,
+            envelope: None,
+        };
+        let report = check_signing_times(
+            &root,
+            &[
+                signed(vec![general_purpose::STANDARD.encode(&leaf)]),
+                signed(Vec::new()),
+                signed(vec!["MIIB".to_string()]),
+                SignedStatement {
+                    log_entries: Vec::new(),
+                    ..signed(vec![general_purpose::STANDARD.encode(&leaf)])
+                },
+            ],
+        );
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                (
+                    "signed-outside-certificate-validity",
+                    "/0/tlogEntries/1/integratedTime"
+                ),
+                (
+                    "signed-outside-certificate-validity",
+                    "/0/tlogEntries/2/integratedTime"
+                ),
+                ("no-signing-time", "/0/tlogEntries/3"),
+                ("no-signing-time", "/0/tlogEntries/4"),
+                ("invalid-certificate", "/2/certificates/0"),
+                ("no-signing-time", "/3"),
+            ]
+        );
+        assert!(report.findings[0]
+            .message
+            .starts_with("Logged at 2023-11-14 22:13:20 UTC"));
+    }
+
+    #[test]
+    fn test_check_inclusion_proofs() {
+        let (tlog, entry) = inclusion::tests::logged_entry(b"{}");
+        let root = TrustedRoot {
+            tlogs: vec![tlog],
+            ..TrustedRoot::default()
+        };
+        let mut tampered = entry.clone();
+        tampered.canonicalized_body = Some("e30K".to_string());
+        let signed = SignedStatement {
+            statement: json!({}),
+            key_ids: Vec::new(),
+            signatures: 1,
+            log_entries: vec![
+                entry.clone(),
+                tampered,
+                LogEntry {
+                    inclusion_proof: None,
+                    ..entry.clone()
+                },
+                LogEntry {
+                    inclusion_proof: None,
+                    signed_entry_timestamp: None,
+                    ..entry
+                },
+            ],
+            certificates: Vec::new(),
+            envelope: None,
+        };
+        let report = check_inclusion_proofs(&root, &[signed]);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                ("invalid-inclusion-proof", "/0/tlogEntries/1/inclusionProof"),
+                (
+                    "invalid-signed-entry-timestamp",
+                    "/0/tlogEntries/1/inclusionPromise"
+                ),
+                ("no-inclusion-proof", "/0/tlogEntries/2"),
+                ("no-log-evidence", "/0/tlogEntries/3"),
+            ]
+        );
+        assert_eq!(report.findings[2].severity, Severity::Warning);
+        assert_eq!(report.findings[3].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_check_signatures() {
+        let key = p256::ecdsa::SigningKey::from_bytes(&[7; 32].into()).unwrap();
+        let (envelope, body) = signature::tests::signed_envelope(&key, &json!({}));
+        let signed = SignedStatement {
+            statement: json!({}),
+            key_ids: Vec::new(),
+            signatures: 1,
+            log_entries: vec![LogEntry {
+                canonicalized_body: Some(body),
+                ..LogEntry::default()
+            }],
+            certificates: Vec::new(),
+            envelope: Some(envelope.clone()),
+        };
+        let mut tampered = envelope;
+        tampered.payload = b"[]".to_vec();
+
+        // A keyless attestation with a certificate chained up to the root, and one from an
+        // authority the root doesn't have.
+        let (_, leaf, root) = sct::tests::fixture("leaf");
+        let leaf_key = p256::ecdsa::SigningKey::from_bytes(&[5; 32].into()).unwrap();
+        let (_, other, _) = sct::tests::fixture_with_ca("leaf", 9);
+        let keyless = SignedStatement {
+            certificates: vec![general_purpose::STANDARD.encode(&leaf)],
+            envelope: Some(signature::tests::signed_envelope(&leaf_key, &json!({})).0),
+            ..signed.clone()
+        };
+        let attestations = [
+            signed.clone(),
+            SignedStatement {
+                envelope: None,
+                ..signed.clone()
+            },
+            SignedStatement {
+                envelope: Some(tampered),
+                ..signed.clone()
+            },
+            keyless.clone(),
+            SignedStatement {
+                certificates: vec![general_purpose::STANDARD.encode(&other)],
+                ..keyless.clone()
+            },
+            // Signed with the key the log entry records rather than the certificate's.
+            SignedStatement {
+                envelope: signed.envelope.clone(),
+                ..keyless
+            },
+        ];
+        let pinned = [*key.verifying_key()];
+        let codes = |report: &ValidationReport| {
+            report
+                .findings
+                .iter()
+                .map(|f| format!("{} {}", f.code, f.path))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            codes(&check_signatures(&root, &attestations, &pinned)),
+            vec![
+                "unsigned /1",
+                "invalid-signature /2/signatures",
+                "untrusted-certificate /4/certificates/0",
+                "invalid-signature /5/signatures",
+            ]
+        );
+
+        // Without a pinned key, the key the log entry records isn't trusted.
+        assert_eq!(
+            codes(&check_signatures(&root, &attestations, &[])),
+            vec![
+                "no-signing-key /0",
+                "unsigned /1",
+                "no-signing-key /2",
+                "untrusted-certificate /4/certificates/0",
+                "invalid-signature /5/signatures",
+            ]
+        );
+
+        assert_eq!(
+            verify_signature(&root, &attestations[0], &pinned),
+            Ok(Signer::Key(signature::key_fingerprint(&pinned[0])))
+        );
+        let signer = verify_signature(&root, &attestations[3], &[]).unwrap();
+        assert_eq!(signer.fingerprint(), signature::fingerprint(&leaf));
+        assert!(signer.identity().is_some());
+    }
+
+    #[test]
+    fn test_check() {
+        let key = p256::ecdsa::SigningKey::from_bytes(&[7; 32].into()).unwrap();
+        let (envelope, body) = signature::tests::signed_envelope(&key, &json!({}));
+        let body = general_purpose::STANDARD.decode(body).unwrap();
+        let (tlog, entry) = inclusion::tests::logged_entry(&body);
+        let key_signed = SignedStatement {
+            statement: json!({}),
+            key_ids: Vec::new(),
+            signatures: 1,
+            log_entries: vec![entry],
+            certificates: Vec::new(),
+            envelope: Some(envelope),
+        };
+        let leaf_key = p256::ecdsa::SigningKey::from_bytes(&[5; 32].into()).unwrap();
+        let (envelope, body) = signature::tests::signed_envelope(&leaf_key, &json!({}));
+        let body = general_purpose::STANDARD.decode(body).unwrap();
+        let (_, mut entry) = inclusion::tests::logged_entry(&body);
+        inclusion::tests::sign_entry_timestamp(&mut entry, 1_900_000_000);
+        let (_, leaf, mut root) = sct::tests::fixture("leaf");
+        root.tlogs = vec![tlog];
+        let keyless = SignedStatement {
+            log_entries: vec![entry],
+            certificates: vec![general_purpose::STANDARD.encode(leaf)],
+            envelope: Some(envelope),
+            ..key_signed.clone()
+        };
+        let attestations = [key_signed, keyless];
+        let report = check(&root, &attestations, &[*key.verifying_key()]);
+        assert!(report.is_empty(), "{:?}", report);
+
+        // Nothing shows when an attestation signed with a key was signed, so the key must be
+        // pinned.
+        let report = check(&root, &attestations, &[]);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(findings, vec![("no-signing-key", "/0")]);
+    }
+}
@@ -0,0 +1,635 @@
+//! Signed certificate timestamps (SCTs) embedded in Fulcio certificates.
+//!
+//! Fulcio records each certificate it issues in a certificate transparency log before issuing
+//! it, and embeds the log's signed promise to publish it, the SCT, in the certificate. Verifying
+//! the SCT against the CT log keys of the trusted root shows the certificate can be found in the
+//! log, where the owner of the identity can notice it.
+//!
+//! The SCT is signed over the precertificate: the certificate without the SCT extension, along
+//! with a hash of the issuer's key, as described in RFC 6962. Only ECDSA P-256 CT log keys are
+//! supported, which is what Sigstore uses.
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose, Engine};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use der::{asn1::AnyRef, Decode, Encode, Reader, SliceReader, Tag, TagNumber, Tagged};
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use p256::pkcs8::DecodePublicKey;
+use sha2::{Digest, Sha256};
+
+use super::TrustedRoot;
+
+/// The DER encoding of the OID of the embedded SCT list extension, 1.3.6.1.4.1.11129.2.4.2.
+const SCT_LIST_OID: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0xd6, 0x79, 0x02, 0x04, 0x02];
+
+/// The TLS hash and signature algorithm codes for SHA-256 and ECDSA.
+const SHA256: u8 = 4;
+const ECDSA: u8 = 3;
+
+/// A signed certificate timestamp, as embedded in a certificate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedCertificateTimestamp {
+    /// The id of the CT log, the SHA-256 digest of its key.
+    pub log_id: [u8; 32],
+    /// When the log saw the certificate, in milliseconds since the epoch.
+    pub timestamp: u64,
+    pub extensions: Vec<u8>,
+    pub hash_algorithm: u8,
+    pub signature_algorithm: u8,
+    pub signature: Vec<u8>,
+}
+
+/// The parts of a certificate needed to verify its SCTs and its issuer's signature.
+pub(super) struct Certificate<'a> {
+    /// The TBS certificate, which the issuer signs.
+    pub(super) tbs: AnyRef<'a>,
+    /// The fields of the TBS certificate.
+    fields: Vec<AnyRef<'a>>,
+    /// The index of the first of version, serial and signature algorithm, 1 if the version is
+    /// given and 0 otherwise.
+    offset: usize,
+    /// The OID of the algorithm of the issuer's signature.
+    pub(super) signature_algorithm: AnyRef<'a>,
+    /// The issuer's signature over the TBS certificate.
+    pub(super) signature: &'a [u8],
+}
+
+impl<'a> Certificate<'a> {
+    pub(super) fn parse(der: &'a [u8]) -> Result<Self> {
+        let certificate = single(der)?;
+        let [tbs, algorithm, signature] = sequence(certificate)?[..] else {
+            bail!("Invalid certificate: expected a TBS certificate, algorithm and signature");
+        };
+        let signature_algorithm = *sequence(algorithm)?
+            .first()
+            .ok_or_else(|| anyhow!("Invalid certificate: no signature algorithm"))?;
+        // A signature is a whole number of bytes, so no bits of the BIT STRING are unused.
+        let signature = match signature.value().split_first() {
+            Some((0, bits)) if signature.tag() == Tag::BitString => bits,
+            _ => bail!("Invalid certificate: invalid signature"),
+        };
+        let fields = sequence(tbs)?;
+        let offset = match fields.first() {
+            Some(field) if field.tag() == context_tag(0) => 1,
+            _ => 0,
+        };
+        if fields.len() < offset + 6 {
+            bail!("Invalid certificate: missing TBS certificate fields");
+        }
+        Ok(Self {
+            tbs,
+            fields,
+            offset,
+            signature_algorithm,
+            signature,
+        })
+    }
+
+    pub(super) fn issuer(&self) -> AnyRef<'a> {
+        self.fields[self.offset + 2]
+    }
+
+    /// Returns the notBefore and notAfter times of the certificate.
+    pub(super) fn validity(&self) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+        let times = sequence(self.fields[self.offset + 3])?;
+        let [not_before, not_after] = times.as_slice() else {
+            bail!("Invalid certificate: expected notBefore and notAfter");
+        };
+        Ok((time(*not_before)?, time(*not_after)?))
+    }
+
+    pub(super) fn subject(&self) -> AnyRef<'a> {
+        self.fields[self.offset + 4]
+    }
+
+    pub(super) fn public_key(&self) -> AnyRef<'a> {
+        self.fields[self.offset + 5]
+    }
+
+    /// Returns the extensions, each the items of its sequence.
+    pub(super) fn extensions(&self) -> Result<Vec<Vec<AnyRef<'a>>>> {
+        let Some(extensions) = self.fields.iter().find(|f| f.tag() == context_tag(3)) else {
+            return Ok(Vec::new());
+        };
+        sequence(single(extensions.value())?)?
+            .into_iter()
+            .map(sequence)
+            .collect()
+    }
+
+    /// Returns the DER TBS certificate without the SCT list extension.
+    fn precertificate_tbs(&self) -> Result<Vec<u8>> {
+        let mut fields = Vec::new();
+        for field in &self.fields {
+            if field.tag() != context_tag(3) {
+                fields.extend(field.to_der().map_err(invalid)?);
+                continue;
+            }
+            let mut extensions = Vec::new();
+            for extension in sequence(single(field.value())?)? {
+                if !is_sct_list(&sequence(extension)?) {
+                    extensions.extend(extension.to_der().map_err(invalid)?);
+                }
+            }
+            let extensions = encode(Tag::Sequence, &extensions)?;
+            fields.extend(encode(context_tag(3), &extensions)?);
+        }
+        encode(Tag::Sequence, &fields)
+    }
+}
+
+/// Returns the SCTs embedded in a DER certificate.
+pub fn embedded_scts(certificate: &[u8]) -> Result<Vec<SignedCertificateTimestamp>> {
+    let certificate = Certificate::parse(certificate)?;
+    let Some(extension) = certificate
+        .extensions()?
+        .into_iter()
+        .find(|extension| is_sct_list(extension))
+    else {
+        return Ok(Vec::new());
+    };
+    // The extension value is an OCTET STRING holding an OCTET STRING of the TLS encoded list.
+    let value = extension
+        .last()
+        .filter(|value| value.tag() == Tag::OctetString)
+        .ok_or_else(|| anyhow!("Invalid SCT list extension"))?;
+    let list = single(value.value())?;
+    if list.tag() != Tag::OctetString {
+        bail!("Invalid SCT list extension");
+    }
+
+    let mut list = TlsReader(list.value());
+    let mut scts = TlsReader(list.vec16()?);
+    if !list.0.is_empty() {
+        bail!("Invalid SCT list: trailing data");
+    }
+    let mut timestamps = Vec::new();
+    while !scts.0.is_empty() {
+        let mut sct = TlsReader(scts.vec16()?);
+        let version = sct.u8()?;
+        if version != 0 {
+            bail!("Unsupported SCT version {}", version);
+        }
+        timestamps.push(SignedCertificateTimestamp {
+            log_id: sct.take(32)?.try_into()?,
+            timestamp: u64::from_be_bytes(sct.take(8)?.try_into()?),
+            extensions: sct.vec16()?.to_vec(),
+            hash_algorithm: sct.u8()?,
+            signature_algorithm: sct.u8()?,
+            signature: sct.vec16()?.to_vec(),
+        });
+    }
+    Ok(timestamps)
+}
+
+/// Returns when a DER certificate is valid, from its notBefore to its notAfter time.
+///
+/// Fulcio certificates are only valid for a few minutes, so a signature made with one must have
+/// been logged within that window.
+pub fn certificate_validity(certificate: &[u8]) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    Certificate::parse(certificate)?.validity()
+}
+
+/// Returns the DER SubjectPublicKeyInfo of a DER certificate, the key signatures made with the
+/// certificate verify with.
+pub fn certificate_public_key(certificate: &[u8]) -> Result<Vec<u8>> {
+    Certificate::parse(certificate)?
+        .public_key()
+        .to_der()
+        .map_err(invalid)
+}
+
+/// Verifies the SCTs embedded in a DER certificate against the CT logs of a trusted root,
+/// returning how many were verified.
+///
+/// The issuer of the certificate is looked for among the given DER certificates, such as the
+/// rest of a bundle's chain, and the certificate authorities of the trusted root. The
+/// certificate must have at least one SCT, and every SCT must verify.
+pub fn verify_embedded_scts(
+    root: &TrustedRoot,
+    certificate: &[u8],
+    chain: &[Vec<u8>],
+) -> Result<usize> {
+    let scts = embedded_scts(certificate)?;
+    if scts.is_empty() {
+        bail!("The certificate has no SCTs");
+    }
+    let parsed = Certificate::parse(certificate)?;
+    let authorities = root
+        .certificate_authorities
+        .iter()
+        .flat_map(|authority| &authority.cert_chain.certificates)
+        .filter_map(|certificate| {
+            general_purpose::STANDARD
+                .decode(&certificate.raw_bytes)
+                .ok()
+        });
+    let issuer_key = chain
+        .iter()
+        .cloned()
+        .chain(authorities)
+        .find_map(|issuer| {
+            let issuer = Certificate::parse(&issuer).ok()?;
+            (issuer.subject() == parsed.issuer())
+                .then(|| issuer.public_key().to_der().ok())
+                .flatten()
+        })
+        .ok_or_else(|| anyhow!("The issuer of the certificate is not in the trusted root"))?;
+    let issuer_key_hash = Sha256::digest(issuer_key);
+    let tbs = parsed.precertificate_tbs()?;
+
+    for sct in &scts {
+        let log_id = general_purpose::STANDARD.encode(sct.log_id);
+        let ctlog = root
+            .ctlogs
+            .iter()
+            .find(|ctlog| {
+                general_purpose::STANDARD
+                    .decode(&ctlog.log_id.key_id)
+                    .is_ok_and(|id| id == sct.log_id)
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "SCT from CT log {}, which is not in the trusted root",
+                    log_id
+                )
+            })?;
+        let time = i64::try_from(sct.timestamp / 1000)
+            .ok()
+            .and_then(|time| DateTime::from_timestamp(time, 0))
+            .ok_or_else(|| anyhow!("Invalid SCT timestamp {}", sct.timestamp))?;
+        if let Some(valid_for) = &ctlog.public_key.valid_for {
+            if !valid_for.contains(time) {
+                bail!(
+                    "SCT from {} at {}, when its key wasn't in use",
+                    ctlog.base_url,
+                    time
+                );
+            }
+        }
+        if (sct.hash_algorithm, sct.signature_algorithm) != (SHA256, ECDSA) {
+            bail!(
+                "Unsupported SCT signature algorithm {}/{}",
+                sct.hash_algorithm,
+                sct.signature_algorithm
+            );
+        }
+        let key = general_purpose::STANDARD
+            .decode(&ctlog.public_key.raw_bytes)
+            .ok()
+            .and_then(|der| VerifyingKey::from_public_key_der(&der).ok())
+            .ok_or_else(|| anyhow!("The key of CT log {} is not an ECDSA P-256 key", log_id))?;
+        let signature = Signature::from_der(&sct.signature)
+            .map_err(|e| anyhow!("Invalid SCT signature: {}", e))?;
+        let message = signed_data(sct, &issuer_key_hash, &tbs)?;
+        key.verify(&message, &signature)
+            .map_err(|_| anyhow!("The SCT from {} doesn't verify", ctlog.base_url))?;
+    }
+    Ok(scts.len())
+}
+
+/// Returns the data an SCT for a precertificate is signed over.
+fn signed_data(
+    sct: &SignedCertificateTimestamp,
+    issuer_key_hash: &[u8],
+    tbs: &[u8],
+) -> Result<Vec<u8>> {
+    let tbs_length = u32::try_from(tbs.len())
+        .ok()
+        .filter(|length| *length < 1 << 24)
+        .ok_or_else(|| anyhow!("The certificate is too large"))?;
+    let extensions_length = u16::try_from(sct.extensions.len())?;
+    let mut data = vec![0, 0];
+    data.extend(sct.timestamp.to_be_bytes());
+    // The entry type of precertificates.
+    data.extend([0, 1]);
+    data.extend(issuer_key_hash);
+    data.extend(&tbs_length.to_be_bytes()[1..]);
+    data.extend(tbs);
+    data.extend(extensions_length.to_be_bytes());
+    data.extend(&sct.extensions);
+    Ok(data)
+}
+
+fn is_sct_list(extension: &[AnyRef<'_>]) -> bool {
+    extension
+        .first()
+        .is_some_and(|oid| oid.tag() == Tag::ObjectIdentifier && oid.value() == SCT_LIST_OID)
+}
+
+/// Decodes a UTCTime or GeneralizedTime, which certificates write in UTC to the second.
+fn time(value: AnyRef<'_>) -> Result<DateTime<Utc>> {
+    let text = std::str::from_utf8(value.value())
+        .map_err(|_| anyhow!("Invalid certificate: invalid time"))?;
+    let text = match value.tag() {
+        // Two digit years from 50 are in the 20th century, as RFC 5280 says.
+        Tag::UtcTime if text.get(..2).is_some_and(|year| year >= "50") => format!("19{}", text),
+        Tag::UtcTime => format!("20{}", text),
+        Tag::GeneralizedTime => text.to_string(),
+        tag => bail!("Invalid certificate: expected a time, found {}", tag),
+    };
+    NaiveDateTime::parse_from_str(&text, "%Y%m%d%H%M%SZ")
+        .map(|time| time.and_utc())
+        .map_err(|_| anyhow!("Invalid certificate: invalid time {}", text))
+}
+
+fn context_tag(number: u8) -> Tag {
+    Tag::ContextSpecific {
+        constructed: true,
+        number: TagNumber::new(number),
+    }
+}
+
+/// Decodes a DER value that must be the only one in the bytes.
+pub(super) fn single(bytes: &[u8]) -> Result<AnyRef<'_>> {
+    AnyRef::from_der(bytes).map_err(invalid)
+}
+
+/// Returns the items of a DER sequence.
+pub(super) fn sequence(value: AnyRef<'_>) -> Result<Vec<AnyRef<'_>>> {
+    if value.tag() != Tag::Sequence {
+        bail!(
+            "Invalid certificate: expected a sequence, found {}",
+            value.tag()
+        );
+    }
+    let mut reader = SliceReader::new(value.value()).map_err(invalid)?;
+    let mut items = Vec::new();
+    while !reader.is_finished() {
+        items.push(AnyRef::decode(&mut reader).map_err(invalid)?);
+    }
+    Ok(items)
+}
+
+fn encode(tag: Tag, value: &[u8]) -> Result<Vec<u8>> {
+    AnyRef::new(tag, value)
+        .and_then(|value| value.to_der())
+        .map_err(invalid)
+}
+
+pub(super) fn invalid(e: der::Error) -> anyhow::Error {
+    anyhow!("Invalid certificate: {}", e)
+}
+
+/// Reads TLS encoded structures.
+struct TlsReader<'a>(&'a [u8]);
+
+impl<'a> TlsReader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+        if self.0.len() < length {
+            bail!("Invalid SCT list: truncated");
+        }
+        let (taken, rest) = self.0.split_at(length);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Reads bytes prefixed with a 16-bit length.
+    fn vec16(&mut self) -> Result<&'a [u8]> {
+        let length = u16::from_be_bytes(self.take(2)?.try_into()?);
+        self.take(length.into())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::trust::certificate::{FULCIO_OID, SUBJECT_ALT_NAME_OID};
+    use p256::ecdsa::{signature::Signer, SigningKey};
+    use p256::pkcs8::EncodePublicKey;
+    use serde_json::json;
+
+    const ECDSA_WITH_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+    const COMMON_NAME: &[u8] = &[0x55, 0x04, 0x03];
+    const KEY_USAGE: &[u8] = &[0x55, 0x1d, 0x0f];
+
+    fn tlv(tag: Tag, value: &[u8]) -> Vec<u8> {
+        encode(tag, value).unwrap()
+    }
+
+    fn seq(items: &[Vec<u8>]) -> Vec<u8> {
+        tlv(Tag::Sequence, &items.concat())
+    }
+
+    fn name(common_name: &str) -> Vec<u8> {
+        let attribute = seq(&[
+            tlv(Tag::ObjectIdentifier, COMMON_NAME),
+            tlv(Tag::Utf8String, common_name.as_bytes()),
+        ]);
+        seq(&[tlv(Tag::Set, &attribute)])
+    }
+
+    fn extension(oid: &[u8], value: &[u8]) -> Vec<u8> {
+        seq(&[
+            tlv(Tag::ObjectIdentifier, oid),
+            tlv(Tag::OctetString, value),
+        ])
+    }
+
+    fn key_der(key: &SigningKey) -> Vec<u8> {
+        key.verifying_key()
+            .to_public_key_der()
+            .unwrap()
+            .as_bytes()
+            .to_vec()
+    }
+
+    fn tbs(issuer: &str, subject: &str, key: &SigningKey, extensions: &[Vec<u8>]) -> Vec<u8> {
+        seq(&[
+            tlv(context_tag(0), &tlv(Tag::Integer, &[2])),
+            tlv(Tag::Integer, &[1]),
+            seq(&[tlv(Tag::ObjectIdentifier, ECDSA_WITH_SHA256)]),
+            name(issuer),
+            seq(&[
+                tlv(Tag::UtcTime, b"250101000000Z"),
+                tlv(Tag::UtcTime, b"350101000000Z"),
+            ]),
+            name(subject),
+            key_der(key),
+            tlv(context_tag(3), &seq(extensions)),
+        ])
+    }
+
+    /// Wraps a TBS certificate into a certificate signed by the issuer's key.
+    fn certificate(tbs: Vec<u8>, issuer: &SigningKey) -> Vec<u8> {
+        let signature: Signature = issuer.sign(&tbs);
+        let mut bits = vec![0];
+        bits.extend(signature.to_der().as_bytes());
+        seq(&[
+            tbs,
+            seq(&[tlv(Tag::ObjectIdentifier, ECDSA_WITH_SHA256)]),
+            tlv(Tag::BitString, &bits),
+        ])
+    }
+
+    fn key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32].into()).unwrap()
+    }
+
+    /// The builder and source repository the leaf certificates of [`fixture`] certify.
+    pub(crate) const BUILDER: &str =
+        "https://github.com/slsa-framework/slsa-github-generator/.github/workflows/builder_go_slsa3.yml";
+    pub(crate) const SOURCE: &str = "https://github.com/octocat/hello-world";
+
+    /// Returns the extensions of the identity of a GitHub Actions build of [`SOURCE`] at
+    /// `v1.2.3` by [`BUILDER`].
+    fn identity_extensions() -> Vec<Vec<u8>> {
+        let builder = format!("{}@refs/tags/v1.9.0", BUILDER);
+        let uri = Tag::ContextSpecific {
+            constructed: false,
+            number: TagNumber::new(6),
+        };
+        let fulcio = |number: u8, value: &str| {
+            let mut oid = FULCIO_OID.to_vec();
+            oid.push(number);
+            extension(&oid, &tlv(Tag::Utf8String, value.as_bytes()))
+        };
+        vec![
+            extension(SUBJECT_ALT_NAME_OID, &seq(&[tlv(uri, builder.as_bytes())])),
+            fulcio(8, "https://token.actions.githubusercontent.com"),
+            fulcio(9, &builder),
+            fulcio(12, SOURCE),
+            fulcio(14, "refs/tags/v1.2.3"),
+        ]
+    }
+
+    /// Returns a Fulcio-like intermediate, a leaf certificate it issued with an SCT signed by a
+    /// CT log, and a trusted root with both.
+    pub(crate) fn fixture(subject: &str) -> (Vec<u8>, Vec<u8>, TrustedRoot) {
+        fixture_with_ca(subject, 3)
+    }
+
+    /// Returns a fixture as [`fixture`] does, with the key of the certificate authority made
+    /// from a seed.
+    pub(crate) fn fixture_with_ca(subject: &str, seed: u8) -> (Vec<u8>, Vec<u8>, TrustedRoot) {
+        let (ca, ctlog, leaf) = (key(seed), key(4), key(5));
+        let issuer = certificate(tbs("sigstore", "sigstore-intermediate", &ca, &[]), &ca);
+        let mut extensions = vec![extension(KEY_USAGE, &[0x03, 0x02, 0x07, 0x80])];
+        extensions.extend(identity_extensions());
+        let log_id: [u8; 32] = Sha256::digest(key_der(&ctlog)).into();
+        let mut sct = SignedCertificateTimestamp {
+            log_id,
+            timestamp: 1_750_000_000_000,
+            extensions: Vec::new(),
+            hash_algorithm: SHA256,
+            signature_algorithm: ECDSA,
+            signature: Vec::new(),
+        };
+        let precertificate = tbs("sigstore-intermediate", "leaf", &leaf, &extensions);
+        let issuer_key_hash = Sha256::digest(key_der(&ca));
+        let signature: Signature =
+            ctlog.sign(&signed_data(&sct, &issuer_key_hash, &precertificate).unwrap());
+        sct.signature = signature.to_der().as_bytes().to_vec();
+
+        let mut encoded = vec![0];
+        encoded.extend(sct.log_id);
+        encoded.extend(sct.timestamp.to_be_bytes());
+        encoded.extend([0, 0, SHA256, ECDSA]);
+        encoded.extend((sct.signature.len() as u16).to_be_bytes());
+        encoded.extend(&sct.signature);
+        let mut list = ((encoded.len() + 2) as u16).to_be_bytes().to_vec();
+        list.extend((encoded.len() as u16).to_be_bytes());
+        list.extend(encoded);
+        extensions.push(extension(SCT_LIST_OID, &tlv(Tag::OctetString, &list)));
+        let leaf = certificate(
+            tbs("sigstore-intermediate", subject, &leaf, &extensions),
+            &ca,
+        );
+
+        let root = serde_json::from_value(json!({
+            "mediaType": "application/vnd.dev.sigstore.trustedroot+json;version=0.1",
+            "ctlogs": [{
+                "baseUrl": "https://ctfe.sigstore.dev/test",
+                "hashAlgorithm": "SHA2_256",
+                "publicKey": {
+                    "rawBytes": general_purpose::STANDARD.encode(key_der(&ctlog)),
+                    "keyDetails": "PKIX_ECDSA_P256_SHA_256",
+                    "validFor": {"start": "2021-03-14T00:00:00.000Z"}
+                },
+                "logId": {"keyId": general_purpose::STANDARD.encode(log_id)}
+            }],
+            "certificateAuthorities": [{
+                "uri": "https://fulcio.sigstore.dev",
+                "certChain": {"certificates": [{"rawBytes": general_purpose::STANDARD.encode(&issuer)}]}
+            }]
+        }))
+        .unwrap();
+        (issuer, leaf, root)
+    }
+
+    #[test]
+    fn test_embedded_scts() {
+        let (issuer, leaf, _) = fixture("leaf");
+        let scts = embedded_scts(&leaf).unwrap();
+        assert_eq!(scts.len(), 1);
+        assert_eq!(scts[0].timestamp, 1_750_000_000_000);
+        assert!(embedded_scts(&issuer).unwrap().is_empty());
+        assert!(embedded_scts(b"not a certificate").is_err());
+    }
+
+    #[test]
+    fn test_certificate_validity() {
+        let (_, leaf, _) = fixture("leaf");
+        let (not_before, not_after) = certificate_validity(&leaf).unwrap();
+        assert_eq!(not_before.to_rfc3339(), "2025-01-01T00:00:00+00:00");
+        assert_eq!(not_after.to_rfc3339(), "2035-01-01T00:00:00+00:00");
+
+        let utc = |text: &[u8]| time(AnyRef::new(Tag::UtcTime, text).unwrap());
+        assert_eq!(
+            utc(b"991231235959Z").unwrap().to_rfc3339(),
+            "1999-12-31T23:59:59+00:00"
+        );
+        assert!(utc(b"9912312359Z").is_err());
+        let generalized = AnyRef::new(Tag::GeneralizedTime, b"20500101000000Z").unwrap();
+        assert_eq!(
+            time(generalized).unwrap().to_rfc3339(),
+            "2050-01-01T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_verify_embedded_scts() {
+        let (issuer, leaf, root) = fixture("leaf");
+        assert_eq!(verify_embedded_scts(&root, &leaf, &[]).unwrap(), 1);
+
+        // The issuer can come from the bundle's chain instead of the trusted root.
+        let mut without_authorities = root.clone();
+        without_authorities.certificate_authorities.clear();
+        assert!(verify_embedded_scts(&without_authorities, &leaf, &[]).is_err());
+        assert_eq!(
+            verify_embedded_scts(&without_authorities, &leaf, std::slice::from_ref(&issuer))
+                .unwrap(),
+            1
+        );
+
+        let mut without_ctlogs = root.clone();
+        without_ctlogs.ctlogs.clear();
+        assert!(verify_embedded_scts(&without_ctlogs, &leaf, &[])
+            .unwrap_err()
+            .to_string()
+            .contains("which is not in the trusted root"));
+
+        // The SCT was issued for a certificate with another subject.
+        let (_, other, _) = fixture("other");
+        let (_, _, root) = fixture("leaf");
+        let scts = embedded_scts(&other).unwrap();
+        assert_eq!(scts, embedded_scts(&leaf).unwrap());
+        assert_eq!(
+            verify_embedded_scts(&root, &other, &[])
+                .unwrap_err()
+                .to_string(),
+            "The SCT from https://ctfe.sigstore.dev/test doesn't verify"
+        );
+
+        assert_eq!(
+            verify_embedded_scts(&root, &issuer, &[])
+                .unwrap_err()
+                .to_string(),
+            "The certificate has no SCTs"
+        );
+    }
+}
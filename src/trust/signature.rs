@@ -0,0 +1,228 @@
+//! Verification of the signatures of DSSE envelopes.
+//!
+//! An envelope's signatures are over the pre-authentication encoding of its payload. The key
+//! they are verified with is that of a keyless bundle's signing certificate, once its chain is
+//! verified up to a certificate authority of the trusted root, or otherwise one of the keys the
+//! verifier pinned. The keys a transparency log entry records aren't trusted on their own, as
+//! anyone can log an entry signed with their own key. Only ECDSA P-256 signing keys are
+//! supported, which is what cosign and Fulcio use by default.
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose, Engine};
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use p256::pkcs8::{DecodePublicKey, EncodePublicKey};
+use sha2::{Digest, Sha256};
+
+use super::certificate::{self, CertificateIdentity};
+use super::tuf::encode_hex;
+use super::{sct, TrustedRoot};
+use crate::models::dsse::Envelope;
+
+/// Who signed an attestation, once the signature verified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Signer {
+    /// A key the verifier pinned, by its [fingerprint](key_fingerprint).
+    Key(String),
+    /// A Fulcio certificate chained up to the trusted root, by its [fingerprint], with the
+    /// identity it certifies.
+    Certificate {
+        fingerprint: String,
+        identity: CertificateIdentity,
+    },
+}
+
+impl Signer {
+    /// Returns the fingerprint of the key or certificate.
+    pub fn fingerprint(&self) -> &str {
+        match self {
+            Self::Key(fingerprint) | Self::Certificate { fingerprint, .. } => fingerprint,
+        }
+    }
+
+    /// Returns the identity certified by the signing certificate, if signed with one.
+    pub fn identity(&self) -> Option<&CertificateIdentity> {
+        match self {
+            Self::Key(_) => None,
+            Self::Certificate { identity, .. } => Some(identity),
+        }
+    }
+}
+
+/// Returns the fingerprint of a DER certificate or public key: `sha256:` and its hex SHA-256
+/// digest, as policies name trusted keys.
+pub fn fingerprint(der: &[u8]) -> String {
+    format!("sha256:{}", encode_hex(&Sha256::digest(der)))
+}
+
+/// Returns the fingerprint of a key, that of its DER SubjectPublicKeyInfo.
+pub fn key_fingerprint(key: &VerifyingKey) -> String {
+    let der = key
+        .to_public_key_der()
+        .expect("P-256 keys always encode as DER");
+    fingerprint(der.as_bytes())
+}
+
+/// Returns the key of a signing certificate and who it certifies, after verifying its chain up
+/// to a certificate authority of the trusted root. The certificates are base64 DER, the signing
+/// certificate first and then the rest of its chain, as in a Sigstore bundle.
+pub fn certificate_signer(
+    root: &TrustedRoot,
+    certificates: &[String],
+) -> Result<(VerifyingKey, Signer)> {
+    let chain = certificates
+        .iter()
+        .map(|certificate| general_purpose::STANDARD.decode(certificate))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| anyhow!("Invalid base64 certificate: {}", e))?;
+    let Some((der, chain)) = chain.split_first() else {
+        bail!("No signing certificate");
+    };
+    certificate::verify_chain(root, der, chain)?;
+    let signer = Signer::Certificate {
+        fingerprint: fingerprint(der),
+        identity: certificate::certificate_identity(der)?,
+    };
+    Ok((certificate_key(der)?, signer))
+}
+
+/// Verifies at least one of the signatures of an envelope with one of the keys, returning the
+/// key it verified with.
+pub fn verify_envelope(envelope: &Envelope, keys: &[VerifyingKey]) -> Result<VerifyingKey> {
+    if envelope.signatures.is_empty() {
+        bail!("The envelope has no signatures");
+    }
+    let pae = envelope.pae();
+    envelope
+        .signatures
+        .iter()
+        .filter_map(|signature| {
+            Signature::from_der(&signature.sig)
+                .or_else(|_| Signature::from_slice(&signature.sig))
+                .ok()
+        })
+        .find_map(|signature| {
+            keys.iter()
+                .find(|key| key.verify(&pae, &signature).is_ok())
+                .copied()
+        })
+        .ok_or_else(|| anyhow!("None of the envelope's signatures verify with a trusted key"))
+}
+
+/// Reads an ECDSA P-256 key from a PEM public key or certificate, such as a key to pin.
+pub fn pem_key(pem: &str) -> Result<VerifyingKey> {
+    if !pem.contains("-----BEGIN CERTIFICATE-----") {
+        return VerifyingKey::from_public_key_pem(pem)
+            .map_err(|_| anyhow!("The key is not an ECDSA P-256 key"));
+    }
+    let base64 = pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect::<String>();
+    let der = general_purpose::STANDARD
+        .decode(base64.trim())
+        .map_err(|e| anyhow!("Invalid PEM certificate: {}", e))?;
+    certificate_key(&der)
+}
+
+fn certificate_key(der: &[u8]) -> Result<VerifyingKey> {
+    VerifyingKey::from_public_key_der(&sct::certificate_public_key(der)?)
+        .map_err(|_| anyhow!("The key of the certificate is not an ECDSA P-256 key"))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::models::dsse::Signature as EnvelopeSignature;
+    use p256::ecdsa::{signature::Signer as _, SigningKey};
+    use p256::pkcs8::LineEnding;
+    use serde_json::{json, Value};
+
+    /// Returns an envelope of a statement signed with a key, and the base64 body of a `dsse` log
+    /// entry recording it.
+    pub(crate) fn signed_envelope(key: &SigningKey, statement: &Value) -> (Envelope, String) {
+        let mut envelope = Envelope {
+            payload: statement.to_string().into_bytes(),
+            payload_type: "application/vnd.in-toto+json".to_string(),
+            signatures: Vec::new(),
+        };
+        let signature: Signature = key.sign(&envelope.pae());
+        envelope.signatures.push(EnvelopeSignature {
+            keyid: None,
+            sig: signature.to_der().as_bytes().to_vec(),
+        });
+        let pem = key
+            .verifying_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+        let body = json!({
+            "apiVersion": "0.0.1",
+            "kind": "dsse",
+            "spec": {
+                "payloadHash": {
+                    "algorithm": "sha256",
+                    "value": encode_hex(&Sha256::digest(&envelope.payload))
+                },
+                "signatures": [{
+                    "signature": general_purpose::STANDARD.encode(&envelope.signatures[0].sig),
+                    "verifier": general_purpose::STANDARD.encode(pem)
+                }]
+            }
+        });
+        (envelope, general_purpose::STANDARD.encode(body.to_string()))
+    }
+
+    #[test]
+    fn test_verify_envelope() {
+        let key = SigningKey::from_bytes(&[7; 32].into()).unwrap();
+        let statement = json!({"_type": "https://in-toto.io/Statement/v1"});
+        let (mut envelope, _) = signed_envelope(&key, &statement);
+        let other = *SigningKey::from_bytes(&[8; 32].into())
+            .unwrap()
+            .verifying_key();
+        let pinned = [other, *key.verifying_key()];
+        assert_eq!(verify_envelope(&envelope, &pinned).unwrap(), pinned[1]);
+        assert!(verify_envelope(&envelope, &[other]).is_err());
+        envelope.payload = b"{}".to_vec();
+        assert!(verify_envelope(&envelope, &pinned).is_err());
+        envelope.signatures.clear();
+        assert!(verify_envelope(&envelope, &pinned).is_err());
+    }
+
+    #[test]
+    fn test_certificate_signer() {
+        let (_, leaf, root) = sct::tests::fixture("leaf");
+        let (key, signer) =
+            certificate_signer(&root, &[general_purpose::STANDARD.encode(&leaf)]).unwrap();
+        assert_eq!(
+            key,
+            *SigningKey::from_bytes(&[5; 32].into())
+                .unwrap()
+                .verifying_key()
+        );
+        assert_eq!(signer.fingerprint(), fingerprint(&leaf));
+        assert_eq!(
+            signer.identity().unwrap().source_repository_uri.as_deref(),
+            Some(sct::tests::SOURCE)
+        );
+
+        // A certificate from an authority with the same name but another key, along with it.
+        let (issuer, leaf, _) = sct::tests::fixture_with_ca("leaf", 9);
+        let chain = [leaf, issuer].map(|der| general_purpose::STANDARD.encode(der));
+        assert!(certificate_signer(&root, &chain).is_err());
+        assert!(certificate_signer(&root, &[]).is_err());
+    }
+
+    #[test]
+    fn test_key_fingerprint() {
+        let key = *SigningKey::from_bytes(&[7; 32].into())
+            .unwrap()
+            .verifying_key();
+        let pem = key.to_public_key_pem(LineEnding::LF).unwrap();
+        assert_eq!(pem_key(&pem).unwrap(), key);
+        assert!(pem_key("not a key").is_err());
+        let fingerprint = key_fingerprint(&key);
+        assert!(fingerprint.starts_with("sha256:"));
+        assert_eq!(fingerprint.len(), 71);
+        assert_eq!(Signer::Key(fingerprint.clone()).fingerprint(), fingerprint);
+    }
+}
@@ -0,0 +1,672 @@
+//! A TUF client for fetching the Sigstore trusted root.
+//!
+//! The client follows the TUF client workflow from a pinned root: it walks the root rotations
+//! the repository publishes, then verifies the timestamp, snapshot and targets metadata in turn,
+//! and finally the `trusted_root.json` target against the length and hashes the targets
+//! metadata gives for it. Each piece of metadata must be signed by a threshold of the keys of
+//! its role, and must not have expired.
+//!
+//! Metadata with a lower version than the cached metadata of its role is rejected as a rollback.
+//! The verified metadata and target are cached, and used without fetching anything until the
+//! cached metadata expires; the cached metadata is verified again each time it is used. The
+//! latest verified root is also cached, so later runs start from it rather than from the pinned
+//! one. Only ECDSA P-256 keys are supported, which is what Sigstore
+//! uses; signatures by other keys don't count towards thresholds.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use olpc_cjson::CanonicalFormatter;
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use p256::pkcs8::DecodePublicKey;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256, Sha512};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tracing::{debug, instrument};
+
+use super::TrustedRoot;
+
+/// The TUF repository Sigstore publishes its public-good trusted root in.
+pub const SIGSTORE_TUF_URL: &str = "https://tuf-repo-cdn.sigstore.dev";
+
+/// The name of the trusted root target.
+pub const TRUSTED_ROOT_TARGET: &str = "trusted_root.json";
+
+/// The most root rotations walked in one update, so a repository can't keep a client busy.
+const MAX_ROOT_ROTATIONS: u64 = 1024;
+
+/// A client for a TUF repository publishing a Sigstore trusted root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TufClient {
+    url: String,
+    cache_dir: PathBuf,
+    pinned_root: Option<Vec<u8>>,
+}
+
+impl TufClient {
+    /// Creates a client for the repository at a URL, caching metadata in a directory.
+    pub fn new(url: impl Into<String>, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            url: url.into().trim_end_matches('/').to_string(),
+            cache_dir: cache_dir.into(),
+            pinned_root: None,
+        }
+    }
+
+    /// Returns the default cache directory for a repository,
+    /// `$XDG_CACHE_HOME/spector/tuf/<host>` or `~/.cache/spector/tuf/<host>`.
+    pub fn default_cache_dir(url: &str) -> Option<PathBuf> {
+        let host = url::Url::parse(url).ok()?.host_str()?.to_string();
+        std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .map(|dir| dir.join("spector").join("tuf").join(host))
+    }
+
+    /// Pins the `root.json` to start from when no root has been cached yet. It is trusted as
+    /// given, so it must come from a trusted source, such as the Sigstore root signing ceremony.
+    pub fn with_pinned_root(mut self, root: Vec<u8>) -> Self {
+        self.pinned_root = Some(root);
+        self
+    }
+
+    /// Returns the trusted root, from the cache while its metadata hasn't expired, or else
+    /// fetched from the repository.
+    pub fn trusted_root(&self) -> Result<TrustedRoot> {
+        match self.cached_trusted_root() {
+            Some(root) => Ok(root),
+            None => self.refresh(),
+        }
+    }
+
+    /// Fetches and verifies the trusted root from the repository, updating the cache.
+    pub fn refresh(&self) -> Result<TrustedRoot> {
+        let mut root = self.load_root()?;
+        for _ in 0..MAX_ROOT_ROTATIONS {
+            let version = version(&root)? + 1;
+            let name = format!("{}.root.json", version);
+            let Some(bytes) = self.fetch_optional(&name)? else {
+                break;
+            };
+            let new_root = parse_metadata(&bytes, &name)?;
+            verify_role(&new_root, &root, "root")?;
+            verify_role(&new_root, &new_root, "root")?;
+            if self::version(&new_root)? != version {
+                bail!("{} has version {}", name, self::version(&new_root)?);
+            }
+            self.write_cache("root.json", &bytes)?;
+            root = new_root;
+        }
+        check_expiry(&root, "root")?;
+        let consistent = root
+            .pointer("/signed/consistent_snapshot")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let timestamp_bytes = self.fetch("timestamp.json")?;
+        let timestamp = parse_metadata(&timestamp_bytes, "timestamp.json")?;
+        verify_role(&timestamp, &root, "timestamp")?;
+        self.check_rollback(&timestamp, "timestamp")?;
+        check_expiry(&timestamp, "timestamp")?;
+
+        let (snapshot_bytes, snapshot) =
+            self.fetch_role(&timestamp, "snapshot", consistent, &root)?;
+        let (targets_bytes, targets) = self.fetch_role(&snapshot, "targets", consistent, &root)?;
+
+        let target = targets
+            .pointer(&format!("/signed/targets/{}", TRUSTED_ROOT_TARGET))
+            .ok_or_else(|| anyhow!("{} has no {} target", self.url, TRUSTED_ROOT_TARGET))?;
+        let sha256 = target
+            .pointer("/hashes/sha256")
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("The {} target has no sha256 hash", TRUSTED_ROOT_TARGET))?;
+        let name = if consistent {
+            format!("targets/{}.{}", sha256, TRUSTED_ROOT_TARGET)
+        } else {
+            format!("targets/{}", TRUSTED_ROOT_TARGET)
+        };
+        let trusted_root_bytes = self.fetch(&name)?;
+        check_meta(&trusted_root_bytes, target, TRUSTED_ROOT_TARGET)?;
+        let trusted_root = TrustedRoot::from_slice(&trusted_root_bytes)?;
+
+        self.write_cache("timestamp.json", &timestamp_bytes)?;
+        self.write_cache("snapshot.json", &snapshot_bytes)?;
+        self.write_cache("targets.json", &targets_bytes)?;
+        self.write_cache(TRUSTED_ROOT_TARGET, &trusted_root_bytes)?;
+        Ok(trusted_root)
+    }
+
+    /// Returns the cached trusted root if it and the metadata it was verified with are all
+    /// cached, and the metadata still verifies: the root is signed by its own keys, the other
+    /// roles by the keys the cached root gives them, each role matches the version and hashes its
+    /// parent lists for it, and none of it has expired.
+    fn cached_trusted_root(&self) -> Option<TrustedRoot> {
+        let root = parse_metadata(&self.read_cache("root.json")?, "root.json").ok()?;
+        verify_role(&root, &root, "root").ok()?;
+        check_expiry(&root, "root").ok()?;
+
+        let mut parent: Option<Value> = None;
+        for role in ["timestamp", "snapshot", "targets"] {
+            let name = format!("{}.json", role);
+            let bytes = self.read_cache(&name)?;
+            let metadata = parse_metadata(&bytes, &name).ok()?;
+            verify_role(&metadata, &root, role).ok()?;
+            check_expiry(&metadata, role).ok()?;
+            if let Some(parent) = &parent {
+                let meta = parent.pointer(&format!("/signed/meta/{}", name))?;
+                check_meta(&bytes, meta, &name).ok()?;
+                if meta.get("version").and_then(Value::as_u64) != version(&metadata).ok() {
+                    return None;
+                }
+            }
+            parent = Some(metadata);
+        }
+        let target = parent?
+            .pointer(&format!("/signed/targets/{}", TRUSTED_ROOT_TARGET))?
+            .clone();
+        let bytes = self.read_cache(TRUSTED_ROOT_TARGET)?;
+        check_meta(&bytes, &target, TRUSTED_ROOT_TARGET).ok()?;
+        TrustedRoot::from_slice(&bytes).ok()
+    }
+
+    /// Loads the cached root, or else the pinned one, checking it is signed by its own keys.
+    fn load_root(&self) -> Result<Value> {
+        let bytes = match self.read_cache("root.json") {
+            Some(bytes) => bytes,
+            None => self.pinned_root.clone().ok_or_else(|| {
+                anyhow!(
+                    "No TUF root is pinned for {}, pass the root.json to start from",
+                    self.url
+                )
+            })?,
+        };
+        let root = parse_metadata(&bytes, "root.json")?;
+        verify_role(&root, &root, "root")?;
+        if self.read_cache("root.json").is_none() {
+            self.write_cache("root.json", &bytes)?;
+        }
+        Ok(root)
+    }
+
+    /// Fetches the metadata of a role listed in the `meta` of another, checking its length,
+    /// hashes, signatures, version and expiry.
+    fn fetch_role(
+        &self,
+        parent: &Value,
+        role: &str,
+        consistent: bool,
+        root: &Value,
+    ) -> Result<(Vec<u8>, Value)> {
+        let file = format!("{}.json", role);
+        let meta = parent
+            .pointer(&format!("/signed/meta/{}", file.replace('/', "~1")))
+            .ok_or_else(|| anyhow!("The metadata of {} doesn't list {}", self.url, file))?;
+        let expected_version = meta
+            .get("version")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| anyhow!("The metadata of {} has no version for {}", self.url, file))?;
+        let name = if consistent {
+            format!("{}.{}", expected_version, file)
+        } else {
+            file.clone()
+        };
+        let bytes = self.fetch(&name)?;
+        check_meta(&bytes, meta, &file)?;
+        let metadata = parse_metadata(&bytes, &name)?;
+        verify_role(&metadata, root, role)?;
+        if version(&metadata)? != expected_version {
+            bail!(
+                "{} has version {}, expected {}",
+                name,
+                version(&metadata)?,
+                expected_version
+            );
+        }
+        self.check_rollback(&metadata, role)?;
+        check_expiry(&metadata, role)?;
+        Ok((bytes, metadata))
+    }
+
+    /// Fails if the metadata of a role has a lower version than the cached metadata of it.
+    fn check_rollback(&self, metadata: &Value, role: &str) -> Result<()> {
+        let name = format!("{}.json", role);
+        let Some(cached) = self.read_cache(&name) else {
+            return Ok(());
+        };
+        let cached_version = parse_metadata(&cached, &name).and_then(|m| version(&m));
+        if cached_version.is_ok_and(|cached| version(metadata).is_ok_and(|v| v < cached)) {
+            bail!("The {} metadata of {} was rolled back", role, self.url);
+        }
+        Ok(())
+    }
+
+    fn fetch(&self, name: &str) -> Result<Vec<u8>> {
+        self.fetch_optional(name)?
+            .ok_or_else(|| anyhow!("{}/{} not found", self.url, name))
+    }
+
+    /// Fetches a file, returning `None` if it doesn't exist. Repositories served from object
+    /// storage answer 403 rather than 404 for missing files.
+    #[instrument(level = "debug", skip(self), fields(repository = %self.url))]
+    fn fetch_optional(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        let start = Instant::now();
+        let url = format!("{}/{}", self.url, name);
+        let response =
+            reqwest::blocking::get(&url).map_err(|e| anyhow!("Failed to fetch {}: {}", url, e))?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND || status == reqwest::StatusCode::FORBIDDEN {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            bail!("Failed to fetch {}: {}", url, status);
+        }
+        let bytes = response.bytes()?.to_vec();
+        debug!(
+            bytes = bytes.len(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Fetched TUF metadata"
+        );
+        Ok(Some(bytes))
+    }
+
+    fn read_cache(&self, name: &str) -> Option<Vec<u8>> {
+        std::fs::read(self.cache_dir.join(name)).ok()
+    }
+
+    fn write_cache(&self, name: &str, bytes: &[u8]) -> Result<()> {
+        std::fs::create_dir_all(&self.cache_dir)?;
+        let path: &Path = &self.cache_dir.join(name);
+        std::fs::write(path, bytes)
+            .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+fn parse_metadata(bytes: &[u8], name: &str) -> Result<Value> {
+    let metadata: Value = serde_json::from_slice(bytes)
+        .map_err(|e| anyhow!("Invalid TUF metadata {}: {}", name, e))?;
+    if !metadata.get("signed").is_some_and(Value::is_object) {
+        bail!("Invalid TUF metadata {}: no signed object", name);
+    }
+    Ok(metadata)
+}
+
+fn version(metadata: &Value) -> Result<u64> {
+    metadata
+        .pointer("/signed/version")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| anyhow!("TUF metadata has no version"))
+}
+
+/// Fails if the metadata has expired or has no expiry.
+fn check_expiry(metadata: &Value, role: &str) -> Result<()> {
+    let expires = metadata
+        .pointer("/signed/expires")
+        .and_then(Value::as_str)
+        .and_then(|expires| DateTime::parse_from_rfc3339(expires).ok())
+        .ok_or_else(|| anyhow!("The {} metadata has no valid expiry", role))?;
+    if expires < Utc::now() {
+        bail!("The {} metadata expired at {}", role, expires);
+    }
+    Ok(())
+}
+
+/// Checks the length and hashes listed for a file, where they are given. At least one hash
+/// must match for targets, which always list them.
+fn check_meta(bytes: &[u8], meta: &Value, name: &str) -> Result<()> {
+    if let Some(length) = meta.get("length").and_then(Value::as_u64) {
+        if bytes.len() as u64 != length {
+            bail!("{} is {} bytes, expected {}", name, bytes.len(), length);
+        }
+    }
+    let mut checked = false;
+    let hashes = meta.get("hashes").and_then(Value::as_object);
+    for (algorithm, expected) in hashes.into_iter().flatten() {
+        let actual = match algorithm.as_str() {
+            "sha256" => encode_hex(&Sha256::digest(bytes)),
+            "sha512" => encode_hex(&Sha512::digest(bytes)),
+            _ => continue,
+        };
+        if Some(actual.as_str()) != expected.as_str() {
+            bail!("The {} hash of {} doesn't match", algorithm, name);
+        }
+        checked = true;
+    }
+    if !checked && name == TRUSTED_ROOT_TARGET {
+        bail!("{} has no sha256 or sha512 hash to check", name);
+    }
+    Ok(())
+}
+
+/// Checks the metadata is of the role, and signed by a threshold of the keys the root gives it.
+fn verify_role(metadata: &Value, root: &Value, role: &str) -> Result<()> {
+    let kind = metadata.pointer("/signed/_type").and_then(Value::as_str);
+    if kind != Some(role) {
+        bail!(
+            "Expected {} metadata, got {}",
+            role,
+            kind.unwrap_or("metadata with no _type")
+        );
+    }
+    let spec = root
+        .pointer(&format!("/signed/roles/{}", role))
+        .ok_or_else(|| anyhow!("The TUF root has no {} role", role))?;
+    let key_ids = spec
+        .get("keyids")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Value::as_str)
+        .collect::<HashSet<_>>();
+    let threshold = spec
+        .get("threshold")
+        .and_then(Value::as_u64)
+        .filter(|threshold| *threshold > 0)
+        .ok_or_else(|| anyhow!("The {} role has no valid threshold", role))?;
+    let keys = root.pointer("/signed/keys").and_then(Value::as_object);
+    let message = canonical(&metadata["signed"])?;
+
+    let mut verified = HashSet::new();
+    let signatures = metadata
+        .get("signatures")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for signature in signatures {
+        let (Some(key_id), Some(sig)) = (
+            signature.get("keyid").and_then(Value::as_str),
+            signature.get("sig").and_then(Value::as_str),
+        ) else {
+            continue;
+        };
+        if !key_ids.contains(key_id) || verified.contains(key_id) {
+            continue;
+        }
+        let Some(key) = keys.and_then(|keys| keys.get(key_id)) else {
+            continue;
+        };
+        if verify_signature(key, &message, sig) {
+            verified.insert(key_id);
+        }
+    }
+    if (verified.len() as u64) < threshold {
+        bail!(
+            "The {} metadata has {} valid signature(s) from the role's keys, {} required",
+            role,
+            verified.len(),
+            threshold
+        );
+    }
+    Ok(())
+}
+
+/// Verifies an ECDSA P-256 signature, given in hex DER, with a key in PEM or hex SEC1.
+fn verify_signature(key: &Value, message: &[u8], signature: &str) -> bool {
+    let scheme = key.get("scheme").and_then(Value::as_str);
+    if scheme != Some("ecdsa-sha2-nistp256") {
+        return false;
+    }
+    let Some(public) = key.pointer("/keyval/public").and_then(Value::as_str) else {
+        return false;
+    };
+    let key = if public.starts_with("-----BEGIN") {
+        VerifyingKey::from_public_key_pem(public).ok()
+    } else {
+        decode_hex(public).and_then(|bytes| VerifyingKey::from_sec1_bytes(&bytes).ok())
+    };
+    let signature = decode_hex(signature).and_then(|der| Signature::from_der(&der).ok());
+    match (key, signature) {
+        (Some(key), Some(signature)) => key.verify(message, &signature).is_ok(),
+        _ => false,
+    }
+}
+
+/// Serializes a value as OLPC canonical JSON, which TUF signatures are made over.
+pub(crate) fn canonical(value: &Value) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut serializer =
+        serde_json::Serializer::with_formatter(&mut bytes, CanonicalFormatter::new());
+    value.serialize(&mut serializer)?;
+    Ok(bytes)
+}
+
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub(crate) fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_routes;
+    use crate::trust::tests::trusted_root;
+    use chrono::Duration;
+    use p256::ecdsa::{signature::Signer, SigningKey};
+    use p256::pkcs8::{EncodePublicKey, LineEnding};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32].into()).unwrap()
+    }
+
+    fn sign(signed: Value, keys: &[(&str, &SigningKey)]) -> Vec<u8> {
+        let message = canonical(&signed).unwrap();
+        let signatures = keys
+            .iter()
+            .map(|(id, key)| {
+                let signature: Signature = key.sign(&message);
+                json!({"keyid": id, "sig": encode_hex(signature.to_der().as_bytes())})
+            })
+            .collect::<Vec<_>>();
+        json!({"signed": signed, "signatures": signatures})
+            .to_string()
+            .into_bytes()
+    }
+
+    fn expires(days: i64) -> String {
+        (Utc::now() + Duration::days(days)).to_rfc3339()
+    }
+
+    /// Returns the signed part of a root trusting one key for every role.
+    fn root(version: u64, id: &str, key: &SigningKey) -> Value {
+        let public = key
+            .verifying_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+        let role = json!({"keyids": [id], "threshold": 1});
+        json!({
+            "_type": "root",
+            "spec_version": "1.0",
+            "version": version,
+            "expires": expires(365),
+            "consistent_snapshot": true,
+            "keys": {id: {"keytype": "ecdsa", "scheme": "ecdsa-sha2-nistp256", "keyval": {"public": public}}},
+            "roles": {"root": role, "timestamp": role, "snapshot": role, "targets": role}
+        })
+    }
+
+    /// Serves a repository whose root rotates from key `a` to key `b`, with the timestamp
+    /// signed by `timestamp_key` and expiring in `timestamp_days`.
+    fn repository(timestamp_key: &SigningKey, timestamp_days: i64) -> (String, Vec<u8>) {
+        let (a, b) = (key(1), key(2));
+        let pinned = sign(root(1, "a", &a), &[("a", &a)]);
+        let rotated = sign(root(2, "b", &b), &[("a", &a), ("b", &b)]);
+        let trusted_root = trusted_root().to_string().into_bytes();
+        let trusted_root_hash = encode_hex(&Sha256::digest(&trusted_root));
+        let targets = sign(
+            json!({"_type": "targets", "version": 3, "expires": expires(30), "targets": {
+                TRUSTED_ROOT_TARGET: {"length": trusted_root.len(), "hashes": {"sha256": trusted_root_hash}}
+            }}),
+            &[("b", &b)],
+        );
+        let snapshot = sign(
+            json!({"_type": "snapshot", "version": 2, "expires": expires(30), "meta": {
+                "targets.json": {"version": 3}
+            }}),
+            &[("b", &b)],
+        );
+        let timestamp = sign(
+            json!({"_type": "timestamp", "version": 4, "expires": expires(timestamp_days), "meta": {
+                "snapshot.json": {
+                    "version": 2,
+                    "length": snapshot.len(),
+                    "hashes": {"sha256": encode_hex(&Sha256::digest(&snapshot))}
+                }
+            }}),
+            &[("b", timestamp_key)],
+        );
+        let url = serve_routes(|_| {
+            HashMap::from([
+                ("/2.root.json".to_string(), rotated),
+                ("/timestamp.json".to_string(), timestamp),
+                ("/2.snapshot.json".to_string(), snapshot),
+                ("/3.targets.json".to_string(), targets),
+                (
+                    format!("/targets/{}.{}", trusted_root_hash, TRUSTED_ROOT_TARGET),
+                    trusted_root,
+                ),
+            ])
+        })
+        .0;
+        (url, pinned)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("spector-tuf-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_trusted_root() {
+        let (url, pinned) = repository(&key(2), 7);
+        let cache = cache_dir("fetch");
+        let client = TufClient::new(&url, &cache);
+        assert!(client
+            .trusted_root()
+            .unwrap_err()
+            .to_string()
+            .contains("No TUF root is pinned"));
+
+        let root = client
+            .clone()
+            .with_pinned_root(pinned)
+            .trusted_root()
+            .unwrap();
+        assert_eq!(root.tlogs[0].base_url, "https://rekor.sigstore.dev");
+        let cached_root = std::fs::read(cache.join("root.json")).unwrap();
+        assert_eq!(
+            version(&parse_metadata(&cached_root, "root.json").unwrap()).unwrap(),
+            2
+        );
+
+        // The cached metadata hasn't expired, so nothing is fetched.
+        let offline = TufClient::new("http://127.0.0.1:1", &cache);
+        assert_eq!(offline.trusted_root().unwrap(), root);
+        assert!(offline.refresh().is_err());
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_rolled_back_snapshot() {
+        let (url, pinned) = repository(&key(2), 7);
+        let cache = cache_dir("rollback");
+        let client = TufClient::new(&url, &cache).with_pinned_root(pinned);
+        client.trusted_root().unwrap();
+
+        // A snapshot newer than the one the repository now serves was seen before.
+        let b = key(2);
+        let snapshot = sign(
+            json!({"_type": "snapshot", "version": 5, "expires": expires(30), "meta": {
+                "targets.json": {"version": 3}
+            }}),
+            &[("b", &b)],
+        );
+        std::fs::write(cache.join("snapshot.json"), snapshot).unwrap();
+        let err = client.trusted_root().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("The snapshot metadata of {} was rolled back", url)
+        );
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_tampered_cache() {
+        let (url, pinned) = repository(&key(2), 7);
+        let cache = cache_dir("tampered");
+        TufClient::new(&url, &cache)
+            .with_pinned_root(pinned)
+            .trusted_root()
+            .unwrap();
+
+        // Cached metadata that isn't signed by the role's keys isn't used offline.
+        let targets = std::fs::read(cache.join("targets.json")).unwrap();
+        let mut targets: Value = serde_json::from_slice(&targets).unwrap();
+        targets["signed"]["expires"] = json!(expires(3650));
+        std::fs::write(cache.join("targets.json"), targets.to_string()).unwrap();
+        let offline = TufClient::new("http://127.0.0.1:1", &cache);
+        assert!(offline.cached_trusted_root().is_none());
+        assert!(offline.trusted_root().is_err());
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_metadata_type() {
+        let a = key(1);
+        let root = sign(root(1, "a", &a), &[("a", &a)]);
+        let root = parse_metadata(&root, "root.json").unwrap();
+        let timestamp = sign(
+            json!({"_type": "timestamp", "version": 1, "expires": expires(1), "meta": {}}),
+            &[("a", &a)],
+        );
+        let timestamp = parse_metadata(&timestamp, "timestamp.json").unwrap();
+        verify_role(&timestamp, &root, "timestamp").unwrap();
+        assert_eq!(
+            verify_role(&timestamp, &root, "snapshot")
+                .unwrap_err()
+                .to_string(),
+            "Expected snapshot metadata, got timestamp"
+        );
+    }
+
+    #[test]
+    fn test_expired_timestamp() {
+        let (url, pinned) = repository(&key(2), -1);
+        let cache = cache_dir("expired");
+        let err = TufClient::new(&url, &cache)
+            .with_pinned_root(pinned)
+            .trusted_root()
+            .unwrap_err();
+        assert!(err.to_string().contains("The timestamp metadata expired"));
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_untrusted_signature() {
+        // After the rotation, timestamps must be signed with key b.
+        let (url, pinned) = repository(&key(1), 7);
+        let cache = cache_dir("untrusted");
+        let err = TufClient::new(&url, &cache)
+            .with_pinned_root(pinned)
+            .trusted_root()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The timestamp metadata has 0 valid signature(s) from the role's keys, 1 required"
+        );
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+}
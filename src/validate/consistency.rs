@@ -0,0 +1,431 @@
+//! Consistency checks across several attestations about the same artifact.
+//!
+//! Each attestation can be valid on its own while contradicting the others: provenance listing
+//! dependencies the SBOM doesn't have, rebuilds attributed to different builders, or a
+//! vulnerability scan of an artifact that hadn't been built yet. These checks take every
+//! attestation for a subject at once and report such conflicts as findings.
+//!
+//! Findings point into the attestation they are about, with its index in the list as the first
+//! segment of the path, e.g. `/1/predicate/runDetails/builder/id`.
+
+use chrono::{DateTime, FixedOffset};
+use serde_json::Value;
+use std::collections::HashSet;
+
+use crate::enrich::{self, PackageUrl};
+use crate::models::intoto::predicate::normalize_predicate_type;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// An attestation to check, with a name to refer to it by in messages, such as its path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedStatement {
+    pub name: String,
+    pub statement: Value,
+}
+
+/// Checks a set of statements about the same artifact against each other.
+///
+/// Statements sharing no subject digest with the first are reported as `subject-mismatch`
+/// errors. Among the rest, provenance from different builders is reported as
+/// `builder-mismatch`, provenance dependencies missing from the SPDX SBOMs as
+/// `material-not-in-sbom`, and timestamps out of order between the build, the SBOM and
+/// vulnerability scans as `timestamp-order`, all as warnings.
+pub fn check_consistency(statements: &[NamedStatement]) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    let Some(first) = statements.first() else {
+        return report;
+    };
+    let first_subjects = subject_digests(&first.statement);
+    let mut related = vec![(0, first)];
+    for (i, named) in statements.iter().enumerate().skip(1) {
+        if subject_digests(&named.statement).is_disjoint(&first_subjects) {
+            report.push(Finding::error(
+                "subject-mismatch",
+                format!("/{}/subject", i),
+                format!(
+                    "{} has no subject digest in common with {}, so it isn't about the same artifact",
+                    named.name, first.name
+                ),
+            ));
+        } else {
+            related.push((i, named));
+        }
+    }
+
+    let provenance = related
+        .iter()
+        .filter(|(_, named)| is_provenance(&named.statement))
+        .copied()
+        .collect::<Vec<_>>();
+    let sboms = related
+        .iter()
+        .filter(|(_, named)| named.statement.pointer("/predicate/spdxVersion").is_some())
+        .copied()
+        .collect::<Vec<_>>();
+    let scans = related
+        .iter()
+        .filter(|(_, named)| named.statement.pointer("/predicate/scanner").is_some())
+        .copied()
+        .collect::<Vec<_>>();
+
+    check_builders(&provenance, &mut report);
+    if !sboms.is_empty() {
+        check_materials(&provenance, &sboms, &mut report);
+    }
+    check_timestamps(&provenance, &sboms, &scans, &mut report);
+    report
+}
+
+/// Returns the `algorithm:digest` pairs of a statement's subjects, with hex digests lowercased.
+pub(crate) fn subject_digests(statement: &Value) -> HashSet<String> {
+    let subjects = statement
+        .get("subject")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    subjects
+        .iter()
+        .filter_map(|subject| subject.get("digest").and_then(Value::as_object))
+        .flatten()
+        .filter_map(|(algorithm, digest)| {
+            let digest = digest.as_str()?.trim();
+            Some(format!(
+                "{}:{}",
+                algorithm.to_ascii_lowercase(),
+                digest.to_ascii_lowercase()
+            ))
+        })
+        .collect()
+}
+
+pub(crate) fn is_provenance(statement: &Value) -> bool {
+    statement
+        .get("predicateType")
+        .and_then(Value::as_str)
+        .map(|predicate_type| {
+            normalize_predicate_type(predicate_type).starts_with("https://slsa.dev/provenance/")
+        })
+        .unwrap_or(false)
+}
+
+/// Returns the pointer to the builder id of SLSA v1 or v0.2 provenance, and the id.
+pub(crate) fn builder_id(statement: &Value) -> Option<(&'static str, &str)> {
+    ["/predicate/runDetails/builder/id", "/predicate/builder/id"]
+        .into_iter()
+        .find_map(|pointer| Some((pointer, statement.pointer(pointer)?.as_str()?)))
+}
+
+/// Returns the pointer to the time SLSA v1 or v0.2 provenance says the build finished, and the
+/// time.
+pub(crate) fn finished_on(statement: &Value) -> Option<(DateTime<FixedOffset>, &'static str)> {
+    timestamp(
+        statement,
+        &[
+            "/predicate/runDetails/metadata/finishedOn",
+            "/predicate/metadata/buildFinishedOn",
+        ],
+    )
+}
+
+/// Reports provenance whose builder differs from that of the first provenance.
+fn check_builders(provenance: &[(usize, &NamedStatement)], report: &mut ValidationReport) {
+    let mut builders = provenance
+        .iter()
+        .filter_map(|(i, named)| Some((i, named, builder_id(&named.statement)?)));
+    let Some((_, first, (_, expected))) = builders.next() else {
+        return;
+    };
+    for (i, named, (pointer, builder)) in builders {
+        if builder != expected {
+            report.push(Finding::warning(
+                "builder-mismatch",
+                format!("/{}{}", i, pointer),
+                format!(
+                    "{} was built by {}, but {} by {}",
+                    named.name, builder, first.name, expected
+                ),
+            ));
+        }
+    }
+}
+
+/// Reports provenance dependencies, identified by purl, that no SBOM lists as a package.
+fn check_materials(
+    provenance: &[(usize, &NamedStatement)],
+    sboms: &[(usize, &NamedStatement)],
+    report: &mut ValidationReport,
+) {
+    let packages = sboms
+        .iter()
+        .filter_map(|(_, named)| named.statement.get("predicate"))
+        .flat_map(enrich::spdx_components)
+        .filter_map(|component| PackageUrl::parse(&component.purl))
+        .collect::<Vec<_>>();
+    for (i, named) in provenance {
+        for component in enrich::provenance_components(&named.statement) {
+            let listed = PackageUrl::parse(&component.purl)
+                .map(|purl| packages.contains(&purl))
+                .unwrap_or(true);
+            if !listed {
+                report.push(Finding::warning(
+                    "material-not-in-sbom",
+                    format!("/{}{}", i, component.path),
+                    format!(
+                        "{} lists dependency {}, which no SBOM has as a package",
+                        named.name, component.purl
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Reports timestamps that contradict the order of the steps: each build starts before it
+/// finishes, the SBOMs aren't created before the build starts, and the vulnerability scans
+/// don't start before the build finishes.
+fn check_timestamps(
+    provenance: &[(usize, &NamedStatement)],
+    sboms: &[(usize, &NamedStatement)],
+    scans: &[(usize, &NamedStatement)],
+    report: &mut ValidationReport,
+) {
+    let mut started: Option<(DateTime<FixedOffset>, &str)> = None;
+    let mut finished: Option<(DateTime<FixedOffset>, &str)> = None;
+    for (i, named) in provenance {
+        let start = timestamp(
+            &named.statement,
+            &[
+                "/predicate/runDetails/metadata/startedOn",
+                "/predicate/metadata/buildStartedOn",
+            ],
+        );
+        let finish = finished_on(&named.statement);
+        if let (Some((start, _)), Some((finish, pointer))) = (start, finish) {
+            if finish < start {
+                report.push(Finding::warning(
+                    "timestamp-order",
+                    format!("/{}{}", i, pointer),
+                    format!(
+                        "{} finished building at {}, before it started at {}",
+                        named.name, finish, start
+                    ),
+                ));
+            }
+        }
+        // The earliest start and latest finish bound the build across rebuilds.
+        if let Some((start, _)) = start {
+            if started.is_none_or(|(earliest, _)| start < earliest) {
+                started = Some((start, named.name.as_str()));
+            }
+        }
+        if let Some((finish, _)) = finish {
+            if finished.is_none_or(|(latest, _)| finish > latest) {
+                finished = Some((finish, named.name.as_str()));
+            }
+        }
+    }
+
+    let steps = [
+        (sboms, "/predicate/creationInfo/created", started, "created"),
+        (
+            scans,
+            "/predicate/metadata/scanStartedOn",
+            finished,
+            "scanned",
+        ),
+    ];
+    for (statements, pointer, bound, verb) in steps {
+        let Some((bound, build)) = bound else {
+            continue;
+        };
+        for (i, named) in statements {
+            if let Some((time, pointer)) = timestamp(&named.statement, &[pointer]) {
+                if time < bound {
+                    report.push(Finding::warning(
+                        "timestamp-order",
+                        format!("/{}{}", i, pointer),
+                        format!(
+                            "{} was {} at {}, before the build of {} at {}",
+                            named.name, verb, time, build, bound
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Returns the first of the RFC 3339 timestamps at the pointers that is set, with its pointer.
+fn timestamp<'a>(
+    statement: &Value,
+    pointers: &[&'a str],
+) -> Option<(DateTime<FixedOffset>, &'a str)> {
+    pointers.iter().find_map(|pointer| {
+        let time = statement.pointer(pointer)?.as_str()?;
+        Some((DateTime::parse_from_rfc3339(time).ok()?, *pointer))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const DIGEST: &str = "c0ffee00c0ffee00c0ffee00c0ffee00c0ffee00c0ffee00c0ffee00c0ffee00";
+
+    fn named(name: &str, predicate_type: &str, predicate: Value) -> NamedStatement {
+        NamedStatement {
+            name: name.to_string(),
+            statement: json!({
+                "_type": "https://in-toto.io/Statement/v1",
+                "subject": [{"name": "app.tar.gz", "digest": {"sha256": DIGEST}}],
+                "predicateType": predicate_type,
+                "predicate": predicate
+            }),
+        }
+    }
+
+    fn provenance(builder: &str, started: &str, finished: &str) -> Value {
+        json!({
+            "buildDefinition": {
+                "buildType": "https://example.com/buildType/v1",
+                "externalParameters": {},
+                "resolvedDependencies": [
+                    {"uri": "pkg:npm/lodash@4.17.20"},
+                    {"uri": "pkg:pypi/requests@2.31.0"},
+                    {"uri": "git+https://github.com/org/app@refs/heads/main"}
+                ]
+            },
+            "runDetails": {
+                "builder": {"id": builder},
+                "metadata": {"startedOn": started, "finishedOn": finished}
+            }
+        })
+    }
+
+    fn sbom(created: &str) -> Value {
+        let mut sbom: Value = serde_json::from_str(
+            &std::fs::read_to_string(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/spdx_v23_sbom.json"
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        sbom["creationInfo"]["created"] = json!(created);
+        sbom
+    }
+
+    fn scan(started: &str) -> Value {
+        json!({
+            "scanner": {"uri": "pkg:github/aquasecurity/trivy@0.45.0"},
+            "metadata": {"scanStartedOn": started, "scanFinishedOn": started}
+        })
+    }
+
+    fn codes(report: &ValidationReport) -> Vec<(&str, &str)> {
+        report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_consistent() {
+        let statements = [
+            named(
+                "provenance.json",
+                "https://slsa.dev/provenance/v1",
+                provenance(
+                    "https://example.com/builder",
+                    "2024-01-01T00:00:00Z",
+                    "2024-01-01T00:10:00Z",
+                ),
+            ),
+            named(
+                "sbom.json",
+                "https://spdx.dev/Document",
+                sbom("2024-01-01T00:05:00Z"),
+            ),
+            named(
+                "scan.json",
+                "https://cosign.sigstore.dev/attestation/vuln/v1",
+                scan("2024-01-02T00:00:00Z"),
+            ),
+        ];
+        let report = check_consistency(&statements);
+        assert!(report.is_empty(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let mut other_artifact = named(
+            "other.json",
+            "https://spdx.dev/Document",
+            sbom("2024-01-01T00:05:00Z"),
+        );
+        other_artifact.statement["subject"][0]["digest"]["sha256"] = json!("00");
+        let mut provenance_v1 = provenance(
+            "https://example.com/builder",
+            "2024-01-01T00:00:00Z",
+            "2024-01-01T00:10:00Z",
+        );
+        provenance_v1["buildDefinition"]["resolvedDependencies"][0]["uri"] =
+            json!("pkg:npm/lodash@4.17.21");
+        let statements = [
+            named(
+                "provenance.json",
+                "https://slsa.dev/provenance/v1",
+                provenance_v1,
+            ),
+            named(
+                "rebuild.json",
+                "https://slsa.dev/provenance/v1.0",
+                provenance(
+                    "https://other.example.com/builder",
+                    "2024-01-03T00:10:00Z",
+                    "2024-01-03T00:00:00Z",
+                ),
+            ),
+            other_artifact,
+            named(
+                "sbom.json",
+                "https://spdx.dev/Document",
+                sbom("2023-12-31T00:00:00Z"),
+            ),
+            named(
+                "scan.json",
+                "https://cosign.sigstore.dev/attestation/vuln/v1",
+                scan("2024-01-02T00:00:00Z"),
+            ),
+        ];
+
+        let report = check_consistency(&statements);
+        assert_eq!(
+            codes(&report),
+            vec![
+                ("subject-mismatch", "/2/subject"),
+                ("builder-mismatch", "/1/predicate/runDetails/builder/id"),
+                (
+                    "material-not-in-sbom",
+                    "/0/predicate/buildDefinition/resolvedDependencies/0/uri"
+                ),
+                (
+                    "timestamp-order",
+                    "/1/predicate/runDetails/metadata/finishedOn"
+                ),
+                ("timestamp-order", "/3/predicate/creationInfo/created"),
+                ("timestamp-order", "/4/predicate/metadata/scanStartedOn"),
+            ]
+        );
+        assert!(report.findings[1]
+            .message
+            .contains("rebuild.json was built by https://other.example.com/builder"));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(check_consistency(&[]).is_empty());
+    }
+}
@@ -0,0 +1,270 @@
+//! Validation of documents whose format isn't known in advance.
+//!
+//! The format is detected from the fields that identify it: `payloadType` for DSSE envelopes,
+//! `dsseEnvelope` for Sigstore bundles, `_type` for In-Toto statements, `spdxVersion` for SPDX and
+//! `bomFormat` for CycloneDX. Envelopes and bundles are unwrapped, and the statement inside is
+//! validated like a bare one. Formats contributed by plugins are detected when no built-in format
+//! is.
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+use std::fmt;
+use tracing::{debug, instrument};
+
+use super::policy::Policy;
+use super::report::{Finding, ValidationReport};
+use super::rules::cdxa::check_cdxa;
+use super::{GenericValidator, Validator};
+use crate::attestations::{check_statement, decode_dsse_statement};
+use crate::models::cdxa::CycloneDxAttestation;
+use crate::models::intoto::statement::{STATEMENT_TYPE_V01, STATEMENT_TYPE_V1};
+use crate::models::sbom::{spdx22::Spdx22Document, spdx23::Spdx23};
+use crate::plugin::{self, PluginRegistry};
+
+/// The formats [`detect`] tells apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    DsseEnvelope,
+    SigstoreBundle,
+    InTotoV1,
+    InTotoV01,
+    SpdxV23,
+    SpdxV22,
+    CycloneDx,
+    /// A document type contributed by the plugin with the given name.
+    Plugin(&'static str),
+}
+
+impl fmt::Display for DocumentFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DocumentFormat::DsseEnvelope => "DSSE envelope",
+            DocumentFormat::SigstoreBundle => "Sigstore bundle",
+            DocumentFormat::InTotoV1 => "In-Toto v1 statement",
+            DocumentFormat::InTotoV01 => "In-Toto v0.1 statement",
+            DocumentFormat::SpdxV23 => "SPDX 2.3 document",
+            DocumentFormat::SpdxV22 => "SPDX 2.2 document",
+            DocumentFormat::CycloneDx => "CycloneDX attestation",
+            DocumentFormat::Plugin(name) => return write!(f, "{} document", name),
+        })
+    }
+}
+
+/// The outcome of validating a document in the format it was detected as.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedValidation {
+    pub format: DocumentFormat,
+    pub report: ValidationReport,
+}
+
+/// Detects the format of a document, or fails with what is missing to tell.
+pub fn detect(document: &Value) -> Result<DocumentFormat> {
+    detect_builtin(document).or_else(|err| {
+        PluginRegistry::global()
+            .detect(document)
+            .map(|plugin| DocumentFormat::Plugin(plugin.name()))
+            .ok_or(err)
+    })
+}
+
+fn detect_builtin(document: &Value) -> Result<DocumentFormat> {
+    if document.get("payloadType").is_some() {
+        return Ok(DocumentFormat::DsseEnvelope);
+    }
+    if document.get("dsseEnvelope").is_some() {
+        return Ok(DocumentFormat::SigstoreBundle);
+    }
+    if let Some(statement_type) = document.get("_type") {
+        return match statement_type.as_str() {
+            Some(STATEMENT_TYPE_V1) => Ok(DocumentFormat::InTotoV1),
+            Some(STATEMENT_TYPE_V01) => Ok(DocumentFormat::InTotoV01),
+            other => Err(anyhow!(
+                "Unsupported statement _type {}, expected {} or {}",
+                other.unwrap_or("(none)"),
+                STATEMENT_TYPE_V1,
+                STATEMENT_TYPE_V01
+            )),
+        };
+    }
+    if let Some(version) = document.get("spdxVersion") {
+        return match version.as_str() {
+            Some("SPDX-2.3") => Ok(DocumentFormat::SpdxV23),
+            Some("SPDX-2.2") => Ok(DocumentFormat::SpdxV22),
+            other => Err(anyhow!(
+                "Unsupported spdxVersion {}, expected SPDX-2.3 or SPDX-2.2",
+                other.unwrap_or("(none)")
+            )),
+        };
+    }
+    if document.get("bomFormat").is_some() {
+        return Ok(DocumentFormat::CycloneDx);
+    }
+    bail!(
+        "Unable to detect the document format, expected a DSSE envelope, a Sigstore bundle, an \
+         In-Toto statement, or an SPDX or CycloneDX document"
+    )
+}
+
+/// Detects the format of a document and validates it as that format, running the semantic rules
+/// for the policy against statements, including those in envelopes and bundles.
+///
+/// Documents that don't deserialize into their format's model are reported as an
+/// `invalid-document` error. Only envelopes whose payload can't be decoded, and documents whose
+/// format can't be detected, fail the whole validation.
+#[instrument(skip_all)]
+pub fn detect_and_validate(document: &Value, policy: &Policy) -> Result<DetectedValidation> {
+    let format = detect(document)?;
+    debug!(%format, "Detected the document format");
+
+    let report = match format {
+        DocumentFormat::DsseEnvelope => check_statement(&decode_dsse_statement(document)?, policy),
+        DocumentFormat::SigstoreBundle => {
+            check_statement(&decode_dsse_statement(&document["dsseEnvelope"])?, policy)
+        }
+        DocumentFormat::InTotoV1 | DocumentFormat::InTotoV01 => check_statement(document, policy),
+        DocumentFormat::SpdxV23 => model_report(
+            GenericValidator::<Spdx23>::new()
+                .validate(document)
+                .map(|_| ValidationReport::new()),
+        ),
+        DocumentFormat::SpdxV22 => model_report(
+            GenericValidator::<Spdx22Document>::new()
+                .validate(document)
+                .map(|_| ValidationReport::new()),
+        ),
+        DocumentFormat::CycloneDx => model_report(
+            GenericValidator::<CycloneDxAttestation>::new()
+                .validate(document)
+                .map(|cdxa| check_cdxa(&cdxa)),
+        ),
+        DocumentFormat::Plugin(name) => {
+            let plugin = PluginRegistry::global()
+                .get(name)
+                .ok_or_else(|| anyhow!("No plugin named {}", name))?;
+            plugin::validate(plugin.as_ref(), document, policy)?
+        }
+    };
+    Ok(DetectedValidation { format, report })
+}
+
+/// Returns the report of the semantic checks, or an `invalid-document` error if the document
+/// didn't deserialize into its model.
+fn model_report(result: Result<ValidationReport>) -> ValidationReport {
+    result.unwrap_or_else(|e| {
+        let mut report = ValidationReport::new();
+        report.push(Finding::error("invalid-document", "", e.to_string()));
+        report
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose, Engine};
+    use serde_json::json;
+
+    fn fixture(name: &str) -> Value {
+        let text = std::fs::read_to_string(format!(
+            "{}/tests/fixtures/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        ))
+        .unwrap();
+        serde_json::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn test_detect_and_validate() {
+        let statement = fixture("slsa_provenance_v1.json");
+        let envelope = json!({
+            "payloadType": "application/vnd.in-toto+json",
+            "payload": general_purpose::STANDARD.encode(statement.to_string()),
+            "signatures": []
+        });
+        let cases = [
+            (statement.clone(), DocumentFormat::InTotoV1),
+            (envelope.clone(), DocumentFormat::DsseEnvelope),
+            (
+                json!({"dsseEnvelope": envelope}),
+                DocumentFormat::SigstoreBundle,
+            ),
+            (fixture("spdx_v23_sbom.json"), DocumentFormat::SpdxV23),
+            (fixture("cdxa.json"), DocumentFormat::CycloneDx),
+        ];
+        for (document, format) in cases {
+            let validation = detect_and_validate(&document, &Policy::default()).unwrap();
+            assert_eq!(validation.format, format);
+            assert!(!validation.report.has_errors(), "{}", format);
+        }
+
+        let mut v01 = statement;
+        v01["_type"] = json!(STATEMENT_TYPE_V01);
+        assert_eq!(detect(&v01).unwrap(), DocumentFormat::InTotoV01);
+    }
+
+    #[test]
+    fn test_detect_and_validate_invalid() {
+        let mut spdx = fixture("spdx_v23_sbom.json");
+        spdx.as_object_mut().unwrap().remove("SPDXID");
+        let validation = detect_and_validate(&spdx, &Policy::default()).unwrap();
+        assert_eq!(validation.format, DocumentFormat::SpdxV23);
+        assert_eq!(validation.report.findings[0].code, "invalid-document");
+
+        let statement = fixture("slsa_provenance_v1_invalid.json");
+        let validation = detect_and_validate(&statement, &Policy::default()).unwrap();
+        assert!(validation.report.has_errors());
+
+        spdx["spdxVersion"] = json!("SPDX-3.0");
+        assert!(detect(&spdx)
+            .unwrap_err()
+            .to_string()
+            .contains("Unsupported spdxVersion"));
+        assert!(detect(&json!({"name": "unknown"})).is_err());
+    }
+
+    struct BuildRecord;
+
+    impl crate::plugin::DocumentPlugin for BuildRecord {
+        fn name(&self) -> &'static str {
+            "detect-build-record"
+        }
+
+        fn detect(&self, document: &Value) -> bool {
+            document.get("recordType") == Some(&json!("https://example.com/build-record/v1"))
+        }
+
+        fn schema(&self) -> Value {
+            json!({"type": "object", "required": ["recordType", "builder"]})
+        }
+
+        fn validate(&self, _document: &Value, _policy: &Policy) -> ValidationReport {
+            ValidationReport::new()
+        }
+    }
+
+    #[test]
+    fn test_detect_plugin() {
+        PluginRegistry::global().register(BuildRecord).unwrap();
+
+        let record = json!({"recordType": "https://example.com/build-record/v1", "builder": "ci"});
+        let validation = detect_and_validate(&record, &Policy::default()).unwrap();
+        assert_eq!(
+            validation.format,
+            DocumentFormat::Plugin("detect-build-record")
+        );
+        assert_eq!(
+            validation.format.to_string(),
+            "detect-build-record document"
+        );
+        assert!(!validation.report.has_errors());
+
+        let incomplete = json!({"recordType": "https://example.com/build-record/v1"});
+        let validation = detect_and_validate(&incomplete, &Policy::default()).unwrap();
+        assert_eq!(validation.report.findings[0].code, "schema-violation");
+
+        // Built-in formats take precedence.
+        let mut statement = fixture("slsa_provenance_v1.json");
+        statement["recordType"] = json!("https://example.com/build-record/v1");
+        assert_eq!(detect(&statement).unwrap(), DocumentFormat::InTotoV1);
+    }
+}
@@ -0,0 +1,503 @@
+//! Explanations of validation findings, to debug a document rather than just reject it.
+//!
+//! [`explain`] validates a document in whichever format it is detected as, like
+//! [`detect_and_validate`], and pairs every finding with the part of the document it is about,
+//! the schema constraint or spec requirement behind it, and a suggested fix. Schema violations
+//! are explained from the JSON schema of the document's format, other findings from the
+//! [`guidance`] for their code.
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use tracing::instrument;
+
+use super::detect::{detect_and_validate, DocumentFormat};
+use super::policy::Policy;
+use super::report::Finding;
+use super::suggest::{describe_schema_error, schema_error_hints, schemas_at};
+use crate::attestations::decode_dsse_statement;
+use crate::plugin::PluginRegistry;
+use crate::schema::{cache::SchemaCache, registry::SchemaRegistry};
+
+const STATEMENT_SPEC: &str =
+    "https://github.com/in-toto/attestation/blob/main/spec/v1/statement.md#fields";
+const RESOURCE_DESCRIPTOR_SPEC: &str =
+    "https://github.com/in-toto/attestation/blob/main/spec/v1/resource_descriptor.md#fields";
+const DIGEST_SET_SPEC: &str =
+    "https://github.com/in-toto/attestation/blob/main/spec/v1/digest_set.md";
+const SLSA_V01_REQUIREMENTS: &str = "https://slsa.dev/spec/v0.1/requirements";
+const CYCLONEDX_SPEC: &str = "https://cyclonedx.org/docs/1.6/json/";
+
+/// How many lines of the document are shown for a finding.
+const MAX_EXCERPT_LINES: usize = 12;
+
+/// How long a schema constraint is shown before it is cut off.
+const MAX_CONSTRAINT_LENGTH: usize = 200;
+
+/// The requirement behind a finding code, and how to fix documents that don't meet it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Guidance {
+    /// What the spec, or the policy, requires.
+    pub requirement: &'static str,
+    /// The section of the spec the requirement comes from, if it comes from one.
+    pub reference: Option<&'static str>,
+    pub fix: &'static str,
+}
+
+/// Returns the guidance for the findings with the given code, if there is any.
+pub fn guidance(code: &str) -> Option<Guidance> {
+    let (requirement, reference, fix) = match code {
+        "invalid-statement" => (
+            "A statement MUST have _type, subject and predicateType, and its predicate MUST \
+             follow the model of its predicateType",
+            Some(STATEMENT_SPEC),
+            "Fix the field named in the message, the schema violations point at the exact values",
+        ),
+        "invalid-document" => (
+            "The document MUST follow the model of its format",
+            None,
+            "Fix the field named in the message, the schema violations point at the exact values",
+        ),
+        "unknown-property" => (
+            "Properties are expected to be ones the model defines",
+            None,
+            "Rename the property to the suggested name",
+        ),
+        "malformed-digest" => (
+            "Digests MUST be encoded the way their algorithm defines: git object IDs are 40 or 64 \
+             hex characters, and dirHash is `h1:` followed by a base64-encoded SHA-256",
+            Some(DIGEST_SET_SPEC),
+            "Replace the digest with the one the algorithm computes, or name the algorithm the \
+             digest was computed with",
+        ),
+        "normalized-digest" => (
+            "Hex digests are expected in lowercase, without surrounding whitespace",
+            Some(DIGEST_SET_SPEC),
+            "Lowercase the digest and trim the whitespace around it",
+        ),
+        "empty-resource-descriptor" => (
+            "A resource descriptor MUST set at least one of uri, digest or content",
+            Some(RESOURCE_DESCRIPTOR_SPEC),
+            "Add the digest, URI or content of the resource",
+        ),
+        "empty-subject" => (
+            "subject MUST contain at least one artifact",
+            Some(STATEMENT_SPEC),
+            "Add the artifacts the statement is about, with their digests",
+        ),
+        "wildcard-subject" => (
+            "A subject named `_` matches any artifact, which the policy doesn't allow",
+            Some(STATEMENT_SPEC),
+            "Name the artifact, or allow wildcard subjects in the policy",
+        ),
+        "duplicate-subject-digest" => (
+            "Each subject is expected to be a different artifact",
+            Some(STATEMENT_SPEC),
+            "Remove the duplicate subject, or fix its digest",
+        ),
+        "non-canonical-predicate-type" => (
+            "predicateType SHOULD be the canonical URI of the predicate type",
+            Some(STATEMENT_SPEC),
+            "Use the canonical predicateType given in the message",
+        ),
+        "predicate-type-mismatch" => (
+            "The predicate MUST follow the schema of its predicateType",
+            Some(STATEMENT_SPEC),
+            "Set predicateType to the type the predicate follows, as suggested in the message",
+        ),
+        "external-parameters-shape" => (
+            "externalParameters SHOULD be a JSON object",
+            Some("https://slsa.dev/spec/v1.0/provenance#builddefinition"),
+            "Put the parameters in an object keyed by their names",
+        ),
+        "build-config-shape" => (
+            "buildConfig SHOULD be a JSON object",
+            Some("https://slsa.dev/spec/v0.2/provenance"),
+            "Put the build configuration in an object",
+        ),
+        "conflicting-dependency-digest" => (
+            "A resolved dependency is expected to have a single digest per algorithm",
+            Some("https://slsa.dev/spec/v1.0/provenance#builddefinition"),
+            "Remove the stale entry of the dependency",
+        ),
+        "invocation-id-format" => (
+            "The invocationId is expected in the format the policy sets for the builder",
+            Some("https://slsa.dev/spec/v1.0/provenance#buildmetadata"),
+            "Check that the provenance was made by the builder it names",
+        ),
+        "builder-not-allowed" => (
+            "builder.id MUST be one of the builders the policy trusts",
+            Some("https://slsa.dev/spec/v1.0/provenance#builder"),
+            "Add the builder to the policy's allowed builders if it is trusted, otherwise reject \
+             the artifact",
+        ),
+        "build-type-not-allowed" => (
+            "buildType MUST be one of the build types the policy allows",
+            Some("https://slsa.dev/spec/v1.0/provenance#builddefinition"),
+            "Add the build type to the policy's allowed build types if it is trusted, otherwise \
+             reject the artifact",
+        ),
+        "attestation-stale" => (
+            "The attestation MUST be younger than the maximum age the policy allows",
+            None,
+            "Attest the artifact again, or rebuild it",
+        ),
+        "attestation-expired" => (
+            "Attestations are not accepted after the cut-off date of the policy",
+            None,
+            "Attest the artifact again, or move the cut-off date of the policy",
+        ),
+        "completeness-not-claimed" => (
+            "The policy requires the builder to claim the parameters or materials are complete",
+            Some(SLSA_V01_REQUIREMENTS),
+            "Build with a builder that records them all and claims it",
+        ),
+        "reproducible-not-claimed" => (
+            "The policy requires the builder to claim the build is reproducible",
+            Some(SLSA_V01_REQUIREMENTS),
+            "Make the build reproducible and have the builder claim it",
+        ),
+        "invalid-bom-format" => (
+            "bomFormat MUST be CycloneDX",
+            Some(CYCLONEDX_SPEC),
+            "Set bomFormat to `CycloneDX`",
+        ),
+        "unsupported-spec-version" => (
+            "Declarations are part of CycloneDX 1.6 and later",
+            Some(CYCLONEDX_SPEC),
+            "Set specVersion to 1.6 or later",
+        ),
+        "duplicate-bom-ref" => (
+            "A bom-ref MUST be unique within the document",
+            Some(CYCLONEDX_SPEC),
+            "Give every element its own bom-ref",
+        ),
+        "unknown-assessor"
+        | "unknown-claim"
+        | "unknown-evidence"
+        | "unknown-requirement"
+        | "unknown-target" => (
+            "References between declarations MUST be the bom-ref of an element of the document",
+            Some(CYCLONEDX_SPEC),
+            "Add the element that is referred to, or fix the reference",
+        ),
+        "score-out-of-range" => (
+            "Confidence and conformance scores MUST be between 0 and 1",
+            Some(CYCLONEDX_SPEC),
+            "Scale the score to between 0 and 1",
+        ),
+        "invalid-evidence-expiry" => (
+            "Evidence MUST expire after it was created",
+            Some(CYCLONEDX_SPEC),
+            "Fix the created or expires date of the evidence",
+        ),
+        "expired-evidence" => (
+            "Evidence is expected to be current",
+            Some(CYCLONEDX_SPEC),
+            "Renew the evidence",
+        ),
+        _ => return None,
+    };
+    Some(Guidance {
+        requirement,
+        reference,
+        fix,
+    })
+}
+
+/// A finding, with what is needed to understand and fix it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct Explanation {
+    pub finding: Finding,
+    /// The value the finding is about, pretty printed and cut off after a few lines. Findings
+    /// about the whole document have none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<String>,
+    /// The schema keyword that failed and its value, for schema violations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+    /// The requirement behind the finding.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirement: Option<String>,
+    /// The section of the spec the requirement comes from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.finding)?;
+        for line in self.excerpt.iter().flat_map(|excerpt| excerpt.lines()) {
+            write!(f, "\n  | {}", line)?;
+        }
+        if let Some(constraint) = &self.constraint {
+            write!(f, "\n  = constraint: {}", constraint)?;
+        }
+        if let Some(requirement) = &self.requirement {
+            write!(f, "\n  = requirement: {}", requirement)?;
+            if let Some(reference) = &self.reference {
+                write!(f, " ({})", reference)?;
+            }
+        }
+        if let Some(fix) = &self.fix {
+            write!(f, "\n  = fix: {}", fix)?;
+        }
+        Ok(())
+    }
+}
+
+/// The explanations of the findings of a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentExplanation {
+    pub format: DocumentFormat,
+    /// The explanations of the schema violations, then of the other findings.
+    pub explanations: Vec<Explanation>,
+}
+
+/// Validates a document in the format it is detected as, with the policy, and explains every
+/// finding.
+///
+/// Besides the findings of [`detect_and_validate`], the document is checked against the JSON
+/// schema of its format, so the values behind a document that doesn't deserialize are pointed
+/// out. Statements in envelopes and bundles are decoded, and the paths of their findings are
+/// within the statement.
+#[instrument(skip_all)]
+pub fn explain(document: &Value, policy: &Policy) -> Result<DocumentExplanation> {
+    let validation = detect_and_validate(document, policy)?;
+    let body = match validation.format {
+        DocumentFormat::DsseEnvelope => decode_dsse_statement(document)?,
+        DocumentFormat::SigstoreBundle => decode_dsse_statement(&document["dsseEnvelope"])?,
+        _ => document.clone(),
+    };
+
+    let schema = format_schema(validation.format, &body)?;
+    let compiled = SchemaCache::global().get_or_compile(&schema)?;
+    let mut explanations = Vec::new();
+    if let Err(errors) = compiled.validate(&body) {
+        for error in errors {
+            explanations.push(explain_schema_error(&schema, &body, &error));
+        }
+    }
+    // Plugins report their schema violations themselves, they are explained above instead.
+    for finding in validation.report.findings {
+        if finding.code != "schema-violation" {
+            explanations.push(explain_finding(finding, &body));
+        }
+    }
+    Ok(DocumentExplanation {
+        format: validation.format,
+        explanations,
+    })
+}
+
+/// Returns the JSON schema documents of the format are checked against.
+fn format_schema(format: DocumentFormat, document: &Value) -> Result<Value> {
+    let entry = match format {
+        DocumentFormat::DsseEnvelope
+        | DocumentFormat::SigstoreBundle
+        | DocumentFormat::InTotoV1
+        | DocumentFormat::InTotoV01 => document
+            .get("predicateType")
+            .and_then(Value::as_str)
+            .and_then(SchemaRegistry::for_predicate_type)
+            .or_else(|| SchemaRegistry::get("in-toto-v1")),
+        DocumentFormat::SpdxV23 => SchemaRegistry::get("spdx-v23"),
+        DocumentFormat::SpdxV22 => SchemaRegistry::get("spdx-v22"),
+        DocumentFormat::CycloneDx => SchemaRegistry::get("cdxa"),
+        DocumentFormat::Plugin(name) => {
+            let plugin = PluginRegistry::global()
+                .get(name)
+                .ok_or_else(|| anyhow!("No plugin named {}", name))?;
+            return Ok(plugin.schema());
+        }
+    };
+    entry
+        .map(|entry| entry.schema())
+        .ok_or_else(|| anyhow!("No schema for {}", format))
+}
+
+fn explain_schema_error(
+    schema: &Value,
+    document: &Value,
+    error: &jsonschema::ValidationError,
+) -> Explanation {
+    let path = error.instance_path.to_string();
+    let schema_path = error.schema_path.to_string();
+    let keyword = schema_path.rsplit('/').next().unwrap_or_default();
+    let constraint = schemas_at(schema, &path)
+        .into_iter()
+        .find_map(|schema| schema.get(keyword))
+        .map(|value| {
+            let mut constraint = format!("{}: {}", keyword, value);
+            if constraint.len() > MAX_CONSTRAINT_LENGTH {
+                let end = (0..=MAX_CONSTRAINT_LENGTH)
+                    .rev()
+                    .find(|i| constraint.is_char_boundary(*i))
+                    .unwrap_or_default();
+                constraint.truncate(end);
+                constraint.push_str("...");
+            }
+            constraint
+        });
+
+    let hints = schema_error_hints(schema, error);
+    let fix = match keyword {
+        _ if !hints.is_empty() => Some(capitalize(&hints.join(", "))),
+        "required" => Some("Add the missing property".to_string()),
+        "additionalProperties" => {
+            Some("Remove the properties the schema doesn't define".to_string())
+        }
+        "type" => Some("Change the value to the type the schema expects".to_string()),
+        "enum" | "const" => Some("Use one of the values the schema allows".to_string()),
+        "format" | "pattern" => Some(format!("Change the value to match the {}", keyword)),
+        "minItems" | "minLength" | "minProperties" => {
+            Some("Add to the value until it reaches the minimum size".to_string())
+        }
+        "anyOf" | "oneOf" => {
+            Some("Change the value to match one of the alternatives the schema allows".to_string())
+        }
+        _ => None,
+    };
+
+    Explanation {
+        excerpt: excerpt(document, &path),
+        finding: Finding::error(
+            "schema-violation",
+            path,
+            describe_schema_error(schema, error),
+        ),
+        constraint,
+        requirement: Some(format!(
+            "The value MUST match the `{}` keyword of the schema",
+            keyword
+        )),
+        reference: None,
+        fix,
+    }
+}
+
+fn explain_finding(finding: Finding, document: &Value) -> Explanation {
+    let guidance = guidance(&finding.code);
+    Explanation {
+        excerpt: excerpt(document, &finding.path),
+        constraint: None,
+        requirement: guidance.map(|guidance| guidance.requirement.to_string()),
+        reference: guidance
+            .and_then(|guidance| guidance.reference)
+            .map(str::to_string),
+        fix: guidance.map(|guidance| guidance.fix.to_string()),
+        finding,
+    }
+}
+
+/// Returns the value at the path, pretty printed and cut off after [`MAX_EXCERPT_LINES`].
+fn excerpt(document: &Value, path: &str) -> Option<String> {
+    if path.is_empty() {
+        return None;
+    }
+    let pretty = serde_json::to_string_pretty(document.pointer(path)?).ok()?;
+    let mut lines = pretty
+        .lines()
+        .take(MAX_EXCERPT_LINES + 1)
+        .collect::<Vec<_>>();
+    if lines.len() > MAX_EXCERPT_LINES {
+        lines[MAX_EXCERPT_LINES] = "...";
+    }
+    Some(lines.join("\n"))
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fixture(name: &str) -> Value {
+        let text = std::fs::read_to_string(format!(
+            "{}/tests/fixtures/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        ))
+        .unwrap();
+        serde_json::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn test_explain_schema_violation() {
+        let mut statement = fixture("slsa_provenance_v1.json");
+        let build_definition = statement["predicate"]["buildDefinition"]
+            .as_object_mut()
+            .unwrap();
+        let build_type = build_definition.remove("buildType").unwrap();
+        build_definition.insert("buildtype".to_string(), build_type);
+
+        let explanation = explain(&statement, &Policy::default()).unwrap();
+        assert_eq!(explanation.format, DocumentFormat::InTotoV1);
+        let required = explanation
+            .explanations
+            .iter()
+            .find(|explanation| explanation.finding.message.contains("required"))
+            .unwrap();
+        assert_eq!(required.finding.path, "/predicate/buildDefinition");
+        assert!(required.excerpt.as_ref().unwrap().contains("\"buildtype\""));
+        assert!(required
+            .constraint
+            .as_ref()
+            .unwrap()
+            .starts_with("required: [\"buildType\","));
+        assert_eq!(
+            required.fix.as_deref(),
+            Some("Did you mean `buildType` instead of `buildtype`?")
+        );
+        assert!(explanation
+            .explanations
+            .iter()
+            .any(|explanation| explanation.finding.code == "invalid-statement"));
+    }
+
+    #[test]
+    fn test_explain_finding() {
+        let mut statement = fixture("slsa_provenance_v1.json");
+        statement["subject"] = json!([]);
+
+        let explanation = explain(&statement, &Policy::default()).unwrap();
+        let empty = explanation
+            .explanations
+            .iter()
+            .find(|explanation| explanation.finding.code == "empty-subject")
+            .unwrap();
+        assert_eq!(empty.excerpt.as_deref(), Some("[]"));
+        assert_eq!(empty.reference.as_deref(), Some(STATEMENT_SPEC));
+        assert_eq!(
+            empty.to_string(),
+            format!(
+                "warning[empty-subject] /subject: subject MUST contain at least one artifact\n  \
+                 | []\n  = requirement: subject MUST contain at least one artifact ({})\n  = \
+                 fix: Add the artifacts the statement is about, with their digests",
+                STATEMENT_SPEC
+            )
+        );
+    }
+
+    #[test]
+    fn test_excerpt() {
+        let document = json!({"list": (0..20).collect::<Vec<_>>(), "name": "a"});
+        assert_eq!(excerpt(&document, "/name").as_deref(), Some("\"a\""));
+        assert_eq!(excerpt(&document, ""), None);
+        assert_eq!(excerpt(&document, "/missing"), None);
+        let list = excerpt(&document, "/list").unwrap();
+        assert_eq!(list.lines().count(), MAX_EXCERPT_LINES + 1);
+        assert!(list.ends_with("\n..."));
+    }
+}
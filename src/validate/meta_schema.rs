@@ -0,0 +1,135 @@
+//! Validation of JSON schemas against their meta-schema.
+//!
+//! Compiling an invalid schema only reports the first problem, without saying where it is.
+//! Checking the schema against its meta-schema first reports every authoring error with the
+//! JSON pointer of the offending keyword.
+
+use jsonschema::{Draft, JSONSchema};
+use serde_json::{json, Value};
+
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Returns the draft and meta-schema URL for a `$schema` value, if it is supported.
+fn meta_schema_for(url: &str) -> Option<(Draft, &'static str)> {
+    match url.trim_end_matches('#') {
+        "http://json-schema.org/draft-07/schema" => {
+            Some((Draft::Draft7, "http://json-schema.org/draft-07/schema#"))
+        }
+        "http://json-schema.org/draft-06/schema" => {
+            Some((Draft::Draft6, "http://json-schema.org/draft-06/schema#"))
+        }
+        "http://json-schema.org/draft-04/schema" => {
+            Some((Draft::Draft4, "http://json-schema.org/draft-04/schema#"))
+        }
+        _ => None,
+    }
+}
+
+/// Checks a schema against the meta-schema named by its `$schema` keyword.
+///
+/// Schemas without `$schema` are checked against draft-07, which is also the draft they are
+/// compiled with. Schemas for other drafts are checked against draft-07 with a warning, since
+/// only drafts 4, 6 and 7 are supported for validation. Every violation is reported as an
+/// `invalid-schema` error at its path within the schema.
+pub fn check_schema(schema: &Value) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    let default = (Draft::Draft7, "http://json-schema.org/draft-07/schema#");
+    let (draft, url) = match schema.get("$schema") {
+        None => default,
+        Some(Value::String(url)) => meta_schema_for(url).unwrap_or_else(|| {
+            report.push(Finding::warning(
+                "unsupported-meta-schema",
+                "/$schema",
+                format!(
+                    "meta-schema {} is not supported, checking against draft-07 instead",
+                    url
+                ),
+            ));
+            default
+        }),
+        Some(_) => {
+            report.push(Finding::error(
+                "invalid-schema",
+                "/$schema",
+                "$schema MUST be a string",
+            ));
+            default
+        }
+    };
+
+    // The meta-schemas are bundled with jsonschema, so resolving the reference doesn't make any
+    // network requests.
+    let meta_schema = JSONSchema::options()
+        .with_draft(draft)
+        .with_meta_schemas()
+        .compile(&json!({ "$ref": url }))
+        .expect("bundled meta-schemas always compile");
+    if let Err(errors) = meta_schema.validate(schema) {
+        for error in errors {
+            report.push(Finding::error(
+                "invalid-schema",
+                error.instance_path.to_string(),
+                error.to_string(),
+            ));
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_schema() {
+        let schema = json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": {"name": {"type": "string"}}
+        });
+        assert!(check_schema(&schema).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        let schema = json!({
+            "type": "object",
+            "required": "name",
+            "properties": {
+                "name": {"type": "text"},
+                "age": {"minimum": "0"}
+            }
+        });
+
+        let report = check_schema(&schema);
+        let mut paths = report.errors().map(|f| f.path.as_str()).collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "/properties/age/minimum",
+                "/properties/name/type",
+                "/required"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_meta_schema() {
+        let schema = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "string"
+        });
+
+        let report = check_schema(&schema);
+        assert!(!report.has_errors());
+        assert_eq!(
+            report
+                .warnings()
+                .map(|f| f.code.as_str())
+                .collect::<Vec<_>>(),
+            vec!["unsupported-meta-schema"]
+        );
+    }
+}
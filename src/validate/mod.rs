@@ -5,10 +5,36 @@
 //! Serde will short-circuit on the first error it encounters. Thi means that if there are multiple
 //! the user will have to correct an error in their doc and repeat until Spector reports no more errors.
 
+#[cfg(feature = "validation")]
+pub mod consistency;
+#[cfg(feature = "validation")]
+pub mod detect;
+#[cfg(feature = "validation")]
+pub mod explain;
+#[cfg(feature = "validation")]
+pub mod meta_schema;
+#[cfg(feature = "validation")]
+pub mod policy;
+pub mod render;
+pub mod report;
+#[cfg(feature = "validation")]
+pub mod rules;
+#[cfg(feature = "validation")]
+pub mod stream;
+pub mod suggest;
+
 use anyhow::{anyhow, Result};
-use jsonschema::JSONSchema;
 use serde::de::DeserializeOwned;
 use serde_json::{from_value, Value};
+#[cfg(feature = "validation")]
+use std::collections::BTreeMap;
+#[cfg(feature = "validation")]
+use std::time::Instant;
+#[cfg(feature = "validation")]
+use tracing::{debug, instrument};
+
+#[cfg(feature = "validation")]
+use crate::schema::cache::SchemaCache;
 
 /// A trait for implementing validation logic on JSON values.
 pub trait Validator {
@@ -16,52 +42,90 @@ pub trait Validator {
 
     /// Validates the given JSON value and assuming no errors returns the deserialized output.
     fn validate(&self, value: &Value) -> Result<Self::Output>;
+
+    /// Validates the given JSON value, taking ownership of it so it can be deserialized without
+    /// copying. Prefer this over `validate` when the value isn't needed afterwards, e.g. for large
+    /// SBOMs.
+    fn validate_owned(&self, value: Value) -> Result<Self::Output> {
+        self.validate(&value)
+    }
 }
 
 /// A JSON Schema-based validator for JSON values.
 ///
 /// The `JSONSchemaValidator` struct uses a JSON Schema to validate a JSON value and
 /// then deserializes if it is valid into the specified output type.
+#[cfg(feature = "validation")]
 pub struct JSONSchemaValidator<T: DeserializeOwned> {
     schema: Value,
+    documents: BTreeMap<String, Value>,
 
     // TODO(mlieberman85): this using phantomdata seems like an easy way to tell it return a deserialized values
     // but I should probably look into if I can make this simpler.
     _phantom: std::marker::PhantomData<T>,
 }
 
+#[cfg(feature = "validation")]
 impl<T: DeserializeOwned> JSONSchemaValidator<T> {
     /// Creates a new JSONSchemaValidator with the given JSON Schema.
     pub fn new(schema: &Value) -> Self {
         Self {
             schema: schema.clone(),
+            documents: BTreeMap::new(),
             _phantom: std::marker::PhantomData,
         }
     }
-}
 
-impl<T: DeserializeOwned> Validator for JSONSchemaValidator<T> {
-    type Output = T;
+    /// Resolves remote references to the given documents, keyed by URL, instead of fetching them
+    /// while validating.
+    pub fn with_documents(mut self, documents: BTreeMap<String, Value>) -> Self {
+        self.documents = documents;
+        self
+    }
+}
 
-    fn validate(&self, value: &Value) -> Result<Self::Output> {
-        let schema = JSONSchema::compile(&self.schema)
-            .map_err(|e| anyhow!("Failed to compile schema: {}", e))?;
+#[cfg(feature = "validation")]
+impl<T: DeserializeOwned> JSONSchemaValidator<T> {
+    /// Checks the value against the schema, without deserializing it.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(schema = self.schema.get("$id").and_then(serde_json::Value::as_str))
+    )]
+    fn check_schema(&self, value: &Value) -> Result<()> {
+        let start = Instant::now();
+        let schema =
+            SchemaCache::global().get_or_compile_with_documents(&self.schema, &self.documents)?;
 
         let validate = schema.validate(value);
 
         match validate {
             Ok(_) => {
-                let deserialized_value = from_value(value.clone())
-                    .map_err(|e| anyhow!("Failed to deserialize value: {}", e))?;
-                Ok(deserialized_value)
+                debug!(
+                    elapsed_ms = start.elapsed().as_millis() as u64,
+                    "Valid against the JSON schema"
+                );
+                Ok(())
             }
             Err(e) => {
-                let error_messages = e
+                let errors = e.collect::<Vec<_>>();
+                debug!(
+                    errors = errors.len(),
+                    elapsed_ms = start.elapsed().as_millis() as u64,
+                    "Invalid against the JSON schema"
+                );
+                let error_messages = errors
+                    .into_iter()
                     .map(|e| {
+                        let hints = suggest::schema_error_hints(&self.schema, &e)
+                            .into_iter()
+                            .map(|hint| format!("\nhint: {}", hint))
+                            .collect::<String>();
                         format!(
-                            "{}\npath: {}",
+                            "{}\npath: {}{}",
                             serde_json::to_string_pretty(&e.instance).unwrap_or(e.to_string()),
-                            e.instance_path
+                            e.instance_path,
+                            hints
                         )
                     })
                     .collect::<Vec<_>>()
@@ -72,6 +136,21 @@ impl<T: DeserializeOwned> Validator for JSONSchemaValidator<T> {
     }
 }
 
+#[cfg(feature = "validation")]
+impl<T: DeserializeOwned> Validator for JSONSchemaValidator<T> {
+    type Output = T;
+
+    fn validate(&self, value: &Value) -> Result<Self::Output> {
+        self.check_schema(value)?;
+        T::deserialize(value).map_err(|e| anyhow!("Failed to deserialize value: {}", e))
+    }
+
+    fn validate_owned(&self, value: Value) -> Result<Self::Output> {
+        self.check_schema(&value)?;
+        from_value(value).map_err(|e| anyhow!("Failed to deserialize value: {}", e))
+    }
+}
+
 pub struct GenericValidator<T: DeserializeOwned> {
     _phantom: std::marker::PhantomData<T>,
 }
@@ -80,9 +159,11 @@ impl<T: DeserializeOwned> Validator for GenericValidator<T> {
     type Output = T;
 
     fn validate(&self, value: &Value) -> Result<Self::Output> {
-        let deserialized_value = from_value(value.clone())
-            .map_err(|e| anyhow!("Failed to deserialize value into type: {}", e))?;
-        Ok(deserialized_value)
+        T::deserialize(value).map_err(|e| anyhow!("Failed to deserialize value into type: {}", e))
+    }
+
+    fn validate_owned(&self, value: Value) -> Result<Self::Output> {
+        from_value(value).map_err(|e| anyhow!("Failed to deserialize value into type: {}", e))
     }
 }
 
@@ -94,6 +175,12 @@ impl<T: DeserializeOwned> GenericValidator<T> {
     }
 }
 
+impl<T: DeserializeOwned> Default for GenericValidator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::{Deserialize, Serialize};
@@ -107,6 +194,7 @@ mod tests {
         age: u32,
     }
 
+    #[cfg(feature = "validation")]
     fn person_schema() -> Value {
         json!({
             "type": "object",
@@ -119,6 +207,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "validation")]
     fn test_jsonschema_valid_person() {
         let schema = person_schema();
         let validator = JSONSchemaValidator::<Person>::new(&schema);
@@ -139,6 +228,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "validation")]
     fn test_jsonschema_invalid_person() {
         let schema = person_schema();
         let validator = JSONSchemaValidator::<Person>::new(&schema);
@@ -166,6 +256,35 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    #[cfg(feature = "validation")]
+    fn test_validate_owned() {
+        let json_value = json!({
+            "name": "John Doe",
+            "age": 30
+        });
+        let expected = Person {
+            name: String::from("John Doe"),
+            age: 30,
+        };
+
+        let schema = person_schema();
+        let validator = JSONSchemaValidator::<Person>::new(&schema);
+        assert_eq!(
+            validator.validate_owned(json_value.clone()).unwrap(),
+            expected
+        );
+        assert!(validator
+            .validate_owned(json!({"name": 123, "age": "thirty"}))
+            .is_err());
+
+        let validator = GenericValidator::<Person>::new();
+        assert_eq!(validator.validate_owned(json_value).unwrap(), expected);
+        assert!(validator
+            .validate_owned(json!({"name": "John Doe"}))
+            .is_err());
+    }
+
     #[test]
     fn test_generic_json_value_validation() {
         let validator = GenericValidator::<Value>::new();
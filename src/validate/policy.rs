@@ -0,0 +1,346 @@
+//! Policy configuration for semantic validation.
+//!
+//! A `Policy` holds the expectations a consumer places on documents beyond what the specs
+//! require. It is deserialized from JSON, with every field optional.
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::OnceLock;
+
+/// Expectations applied by the semantic rules.
+///
+/// Every new check comes with a field whose default leaves it off, so policies written for an
+/// older version keep their meaning.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct Policy {
+    /// Whether findings for practices the specs discourage are reported as errors rather
+    /// than warnings.
+    pub strict: bool,
+    /// Whether subjects may use the `_` wildcard name instead of identifying an artifact.
+    pub allow_wildcard_subjects: bool,
+    /// The publishers trusted to publish packages with attestations. If empty, publishers are
+    /// not checked.
+    pub trusted_publishers: Vec<TrustedPublisher>,
+    /// The expected formats of provenance `invocationId`s, by builder. The first rule whose
+    /// prefix matches the builder id applies. Builders without a rule aren't checked.
+    pub invocation_id_rules: Vec<InvocationIdRule>,
+    /// The parts of SLSA v0.2 provenance the builder must claim are complete in
+    /// `metadata.completeness`.
+    pub required_completeness: Vec<Completeness>,
+    /// Whether SLSA v0.2 provenance must claim the build is reproducible.
+    pub require_reproducible: bool,
+    /// Prefixes of the provenance `builder.id`s to accept. If empty, builders are not checked.
+    pub allowed_builders: Vec<String>,
+    /// Prefixes of the provenance `buildType`s to accept. If empty, build types are not checked.
+    pub allowed_build_types: Vec<String>,
+    /// The maximum age, in seconds, of the time an attestation was produced, such as when the
+    /// build finished. Attestations without such a time aren't checked.
+    pub max_age_seconds: Option<u64>,
+    /// The time after which attestations are no longer accepted.
+    pub not_after: Option<DateTime<Utc>>,
+}
+
+/// A part of SLSA v0.2 provenance whose completeness a builder can claim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Completeness {
+    /// `invocation.parameters` captures every external input.
+    Parameters,
+    /// `invocation.environment` captures every builder-controlled input.
+    Environment,
+    /// `materials` lists every dependency.
+    Materials,
+}
+
+impl Completeness {
+    /// Returns the name of the claim in `metadata.completeness`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Completeness::Parameters => "parameters",
+            Completeness::Environment => "environment",
+            Completeness::Materials => "materials",
+        }
+    }
+}
+
+/// A publisher identity, such as a CI workflow, trusted to publish packages.
+///
+/// Only the fields that are set are compared, so a publisher can be trusted by repository
+/// alone or pinned to a specific workflow and environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct TrustedPublisher {
+    /// The kind of publisher, e.g. `GitHub` or `GitLab`.
+    pub kind: Option<String>,
+    /// The repository the package is published from, e.g. `pypa/sampleproject`.
+    pub repository: Option<String>,
+    /// The workflow that publishes the package, e.g. `release.yml`.
+    pub workflow: Option<String>,
+    /// The deployment environment the workflow publishes from.
+    pub environment: Option<String>,
+}
+
+/// The expected format of the `invocationId` of provenance from some builders.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct InvocationIdRule {
+    /// Builder ids starting with this prefix use the rule, e.g.
+    /// `https://github.com/slsa-framework/slsa-github-generator/`.
+    pub builder_id_prefix: String,
+    /// The format the invocation id must have.
+    #[serde(flatten)]
+    pub format: InvocationIdFormat,
+}
+
+/// A built-in invocation id format, or a regular expression.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InvocationIdFormat {
+    /// A named built-in format: `github-actions`, `gitlab-ci` or `uuid`.
+    Format(BuiltinInvocationIdFormat),
+    /// A regular expression the whole id must match.
+    Pattern(InvocationIdPattern),
+}
+
+impl InvocationIdFormat {
+    /// Returns true if the invocation id has the format.
+    pub fn matches(&self, invocation_id: &str) -> bool {
+        match self {
+            InvocationIdFormat::Format(format) => format.regex().is_match(invocation_id),
+            InvocationIdFormat::Pattern(pattern) => pattern.0.is_match(invocation_id),
+        }
+    }
+
+    /// Returns a description of the format for use in messages.
+    pub fn describe(&self) -> String {
+        match self {
+            InvocationIdFormat::Format(format) => format.description().to_string(),
+            InvocationIdFormat::Pattern(pattern) => format!("matching {}", pattern.as_str()),
+        }
+    }
+}
+
+/// The invocation id formats of common builders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BuiltinInvocationIdFormat {
+    /// A GitHub Actions run URL, e.g.
+    /// `https://github.com/org/repo/actions/runs/123/attempts/1`.
+    GithubActions,
+    /// A GitLab CI job URL, e.g. `https://gitlab.com/group/project/-/jobs/123`.
+    GitlabCi,
+    /// A UUID.
+    Uuid,
+}
+
+impl BuiltinInvocationIdFormat {
+    fn regex(self) -> &'static Regex {
+        static GITHUB_ACTIONS: OnceLock<Regex> = OnceLock::new();
+        static GITLAB_CI: OnceLock<Regex> = OnceLock::new();
+        static UUID: OnceLock<Regex> = OnceLock::new();
+        let (regex, pattern) = match self {
+            BuiltinInvocationIdFormat::GithubActions => (
+                &GITHUB_ACTIONS,
+                r"^https://github\.com/[^/]+/[^/]+/actions/runs/\d+(/attempts/\d+)?$",
+            ),
+            BuiltinInvocationIdFormat::GitlabCi => {
+                (&GITLAB_CI, r"^https://[^/]+/[^/]+(/[^/]+)*/-/jobs/\d+$")
+            }
+            BuiltinInvocationIdFormat::Uuid => (
+                &UUID,
+                r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+            ),
+        };
+        regex.get_or_init(|| Regex::new(pattern).expect("built-in patterns are valid"))
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            BuiltinInvocationIdFormat::GithubActions => "a GitHub Actions run URL",
+            BuiltinInvocationIdFormat::GitlabCi => "a GitLab CI job URL",
+            BuiltinInvocationIdFormat::Uuid => "a UUID",
+        }
+    }
+}
+
+/// A regular expression, anchored so it must match the whole invocation id. It is compiled when
+/// the policy is read, so invalid patterns are rejected with the rest of the policy.
+#[derive(Debug, Clone)]
+pub struct InvocationIdPattern(Regex);
+
+impl InvocationIdPattern {
+    /// Compiles a pattern.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(&format!("^(?:{})$", pattern)).map(Self)
+    }
+
+    /// Returns the pattern as written in the policy.
+    pub fn as_str(&self) -> &str {
+        let anchored = self.0.as_str();
+        &anchored[4..anchored.len() - 2]
+    }
+}
+
+impl PartialEq for InvocationIdPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for InvocationIdPattern {}
+
+impl Serialize for InvocationIdPattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for InvocationIdPattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
+impl TrustedPublisher {
+    /// Returns true if every field set on the trusted publisher has the same value in the
+    /// given publisher.
+    pub fn matches(&self, publisher: &TrustedPublisher) -> bool {
+        let matches = |expected: &Option<String>, actual: &Option<String>| match expected {
+            Some(expected) => actual.as_ref() == Some(expected),
+            None => true,
+        };
+        matches(&self.kind, &publisher.kind)
+            && matches(&self.repository, &publisher.repository)
+            && matches(&self.workflow, &publisher.workflow)
+            && matches(&self.environment, &publisher.environment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_policy() {
+        let policy: Policy = serde_json::from_value(json!({
+            "strict": true,
+            "allowWildcardSubjects": true
+        }))
+        .unwrap();
+        assert!(policy.strict);
+        assert!(policy.allow_wildcard_subjects);
+
+        let policy: Policy = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(policy, Policy::default());
+    }
+
+    #[test]
+    fn test_trusted_publisher_matches() {
+        let trusted = TrustedPublisher {
+            repository: Some("pypa/sampleproject".to_string()),
+            ..TrustedPublisher::default()
+        };
+        let publisher: TrustedPublisher = serde_json::from_value(json!({
+            "kind": "GitHub",
+            "repository": "pypa/sampleproject",
+            "workflow": "release.yml"
+        }))
+        .unwrap();
+        assert!(trusted.matches(&publisher));
+
+        let other = TrustedPublisher {
+            repository: Some("pypa/other".to_string()),
+            ..publisher.clone()
+        };
+        assert!(!trusted.matches(&other));
+    }
+
+    #[test]
+    fn test_deserialize_invocation_id_rules() {
+        let policy: Policy = serde_json::from_value(json!({
+            "invocationIdRules": [
+                {
+                    "builderIdPrefix": "https://github.com/slsa-framework/",
+                    "format": "github-actions"
+                },
+                {"builderIdPrefix": "https://builder.example.com/", "pattern": "build-[0-9]+"}
+            ]
+        }))
+        .unwrap();
+        let github = &policy.invocation_id_rules[0].format;
+        assert!(github.matches("https://github.com/org/repo/actions/runs/123/attempts/2"));
+        assert!(github.matches("https://github.com/org/repo/actions/runs/123"));
+        assert!(!github.matches("https://github.com/org/repo/actions/runs/abc"));
+        let pattern = &policy.invocation_id_rules[1].format;
+        assert!(pattern.matches("build-42"));
+        // Patterns must match the whole id.
+        assert!(!pattern.matches("my-build-42"));
+        assert_eq!(pattern.describe(), "matching build-[0-9]+");
+        assert_eq!(
+            serde_json::to_value(&policy.invocation_id_rules[1]).unwrap(),
+            json!({"builderIdPrefix": "https://builder.example.com/", "pattern": "build-[0-9]+"})
+        );
+
+        let result = serde_json::from_value::<Policy>(json!({
+            "invocationIdRules": [{"builderIdPrefix": "https://", "pattern": "build-("}]
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_completeness() {
+        let policy: Policy = serde_json::from_value(json!({
+            "requiredCompleteness": ["parameters", "materials"],
+            "requireReproducible": true
+        }))
+        .unwrap();
+        assert_eq!(
+            policy.required_completeness,
+            vec![Completeness::Parameters, Completeness::Materials]
+        );
+        assert!(policy.require_reproducible);
+
+        let result = serde_json::from_value::<Policy>(json!({
+            "requiredCompleteness": ["dependencies"]
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_allowlists() {
+        let policy: Policy = serde_json::from_value(json!({
+            "allowedBuilders": ["https://github.com/slsa-framework/slsa-github-generator/"],
+            "allowedBuildTypes": ["https://slsa-framework.github.io/github-actions-buildtypes/"]
+        }))
+        .unwrap();
+        assert_eq!(policy.allowed_builders.len(), 1);
+        assert_eq!(policy.allowed_build_types.len(), 1);
+    }
+
+    #[test]
+    fn test_deserialize_freshness() {
+        let policy: Policy = serde_json::from_value(json!({
+            "maxAgeSeconds": 86400,
+            "notAfter": "2024-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        assert_eq!(policy.max_age_seconds, Some(86400));
+        assert_eq!(
+            policy.not_after.unwrap().to_rfc3339(),
+            "2024-01-01T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_policy_unknown_field() {
+        let result = serde_json::from_value::<Policy>(json!({
+            "allowWildcardSubject": true
+        }));
+        assert!(result.is_err());
+    }
+}
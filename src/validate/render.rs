@@ -0,0 +1,349 @@
+//! Renders reports in the formats of Kubernetes policy engines.
+//!
+//! Policy reporting dashboards read the results of Kyverno and Gatekeeper rather than arbitrary
+//! reports, so a [`ValidationReport`] can be rendered as either:
+//!
+//! - a [`PolicyReport`], the `wgpolicyk8s.io/v1alpha2` resource Kyverno writes its results to,
+//!   with a result per finding, and a passing result if there are no errors.
+//! - a [`Constraint`] with the audit status Gatekeeper writes, with a violation per error and
+//!   warning.
+//!
+//! Both are named after the policy the report is for, and findings keep their code and path.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::report::{Severity, ValidationReport};
+
+/// The source of the results in policy reports.
+pub const SOURCE: &str = "spector";
+
+/// The rule of the passing result of a report without errors.
+pub const PASS_RULE: &str = "verify";
+
+/// A `wgpolicyk8s.io/v1alpha2` policy report, as written by Kyverno.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PolicyReport {
+    pub api_version: String,
+    pub kind: String,
+    pub metadata: ObjectMeta,
+    pub summary: PolicyReportSummary,
+    pub results: Vec<PolicyReportResult>,
+}
+
+/// The metadata of a Kubernetes object.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectMeta {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+/// The number of results of each kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyReportSummary {
+    pub pass: usize,
+    pub fail: usize,
+    pub warn: usize,
+    pub error: usize,
+    pub skip: usize,
+}
+
+/// A result of a policy report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyReportResult {
+    pub policy: String,
+    /// The code of the finding, or [`PASS_RULE`] for the passing result.
+    pub rule: String,
+    /// One of `pass`, `fail` or `warn`.
+    pub result: String,
+    /// One of `high`, `medium` or `info`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    pub message: String,
+    pub source: String,
+    pub timestamp: Timestamp,
+    /// The JSON pointer of the finding, as `path`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, String>,
+}
+
+/// A protobuf timestamp, as policy reports write them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timestamp {
+    pub seconds: i64,
+    pub nanos: u32,
+}
+
+/// A Gatekeeper constraint with its audit status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Constraint {
+    pub api_version: String,
+    pub kind: String,
+    pub metadata: ObjectMeta,
+    pub spec: ConstraintSpec,
+    pub status: ConstraintStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstraintSpec {
+    pub enforcement_action: String,
+}
+
+/// The audit status of a constraint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstraintStatus {
+    pub audit_timestamp: DateTime<Utc>,
+    pub total_violations: usize,
+    pub violations: Vec<Violation>,
+}
+
+/// A violation of a constraint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    /// `deny` for errors and `warn` for warnings.
+    pub enforcement_action: String,
+    pub kind: String,
+    /// The JSON pointer of the finding.
+    pub name: String,
+    /// The finding, as `[code] message`.
+    pub message: String,
+}
+
+/// The kind of the constraints Gatekeeper results are rendered as.
+pub const CONSTRAINT_KIND: &str = "SpectorVerification";
+
+/// The kind of the resources Gatekeeper violations are about.
+pub const VIOLATION_KIND: &str = "Attestation";
+
+/// Renders a report as a Kyverno policy report for a policy.
+pub fn policy_report(
+    report: &ValidationReport,
+    policy: &str,
+    timestamp: DateTime<Utc>,
+) -> PolicyReport {
+    let timestamp = Timestamp {
+        seconds: timestamp.timestamp(),
+        nanos: timestamp.timestamp_subsec_nanos(),
+    };
+    let mut summary = PolicyReportSummary::default();
+    let mut results = Vec::new();
+    for finding in &report.findings {
+        let (result, severity) = match finding.severity {
+            Severity::Error => {
+                summary.fail += 1;
+                ("fail", "high")
+            }
+            Severity::Warning => {
+                summary.warn += 1;
+                ("warn", "medium")
+            }
+            _ => {
+                summary.pass += 1;
+                ("pass", "info")
+            }
+        };
+        results.push(PolicyReportResult {
+            policy: policy.to_string(),
+            rule: finding.code.clone(),
+            result: result.to_string(),
+            severity: Some(severity.to_string()),
+            message: finding.message.clone(),
+            source: SOURCE.to_string(),
+            timestamp,
+            properties: BTreeMap::from([("path".to_string(), finding.path.clone())]),
+        });
+    }
+    if !report.has_errors() {
+        summary.pass += 1;
+        results.push(PolicyReportResult {
+            policy: policy.to_string(),
+            rule: PASS_RULE.to_string(),
+            result: "pass".to_string(),
+            severity: None,
+            message: "No errors were found".to_string(),
+            source: SOURCE.to_string(),
+            timestamp,
+            properties: BTreeMap::new(),
+        });
+    }
+    PolicyReport {
+        api_version: "wgpolicyk8s.io/v1alpha2".to_string(),
+        kind: "PolicyReport".to_string(),
+        metadata: ObjectMeta {
+            name: resource_name(policy),
+            labels: BTreeMap::from([(
+                "app.kubernetes.io/managed-by".to_string(),
+                SOURCE.to_string(),
+            )]),
+        },
+        summary,
+        results,
+    }
+}
+
+/// Renders a report as a Gatekeeper constraint for a policy, with the errors and warnings as
+/// its audit violations.
+pub fn constraint(report: &ValidationReport, policy: &str, timestamp: DateTime<Utc>) -> Constraint {
+    let violations = report
+        .findings
+        .iter()
+        .filter_map(|finding| {
+            let action = match finding.severity {
+                Severity::Error => "deny",
+                Severity::Warning => "warn",
+                _ => return None,
+            };
+            Some(Violation {
+                enforcement_action: action.to_string(),
+                kind: VIOLATION_KIND.to_string(),
+                name: finding.path.clone(),
+                message: format!("[{}] {}", finding.code, finding.message),
+            })
+        })
+        .collect::<Vec<_>>();
+    Constraint {
+        api_version: "constraints.gatekeeper.sh/v1beta1".to_string(),
+        kind: CONSTRAINT_KIND.to_string(),
+        metadata: ObjectMeta {
+            name: resource_name(policy),
+            labels: BTreeMap::new(),
+        },
+        spec: ConstraintSpec {
+            enforcement_action: "deny".to_string(),
+        },
+        status: ConstraintStatus {
+            audit_timestamp: timestamp,
+            total_violations: violations.len(),
+            violations,
+        },
+    }
+}
+
+/// Turns a policy name into a Kubernetes resource name: lowercase alphanumerics and dashes, at
+/// most 63 characters.
+fn resource_name(policy: &str) -> String {
+    let name = policy
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+    let name = name.trim_matches('-');
+    let name = name.get(..63).unwrap_or(name).trim_end_matches('-');
+    if name.is_empty() {
+        SOURCE.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::report::Finding;
+    use serde_json::json;
+
+    fn report() -> ValidationReport {
+        let mut report = ValidationReport::new();
+        report.push(Finding::error(
+            "untrusted-key",
+            "/0",
+            "Signed by none of the trusted keys",
+        ));
+        report.push(Finding::warning(
+            "no-inclusion-proof",
+            "/1/tlogEntries/0",
+            "The entry has no proof",
+        ));
+        report
+    }
+
+    fn timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-05-01T12:00:00.5Z")
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_policy_report() {
+        let rendered = policy_report(&report(), "release.yaml", timestamp());
+        assert_eq!(rendered.metadata.name, "release-yaml");
+        assert_eq!(
+            rendered.summary,
+            PolicyReportSummary {
+                fail: 1,
+                warn: 1,
+                ..PolicyReportSummary::default()
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(&rendered.results[0]).unwrap(),
+            json!({
+                "policy": "release.yaml",
+                "rule": "untrusted-key",
+                "result": "fail",
+                "severity": "high",
+                "message": "Signed by none of the trusted keys",
+                "source": "spector",
+                "timestamp": {"seconds": 1714564800, "nanos": 500000000},
+                "properties": {"path": "/0"}
+            })
+        );
+
+        let passing = policy_report(&ValidationReport::new(), "release", timestamp());
+        assert_eq!(passing.summary.pass, 1);
+        assert_eq!(passing.results[0].rule, PASS_RULE);
+        assert_eq!(
+            serde_json::to_value(&passing).unwrap()["apiVersion"],
+            "wgpolicyk8s.io/v1alpha2"
+        );
+    }
+
+    #[test]
+    fn test_constraint() {
+        let rendered = constraint(&report(), "Release Policy", timestamp());
+        let value = serde_json::to_value(&rendered).unwrap();
+        assert_eq!(value["metadata"], json!({"name": "release-policy"}));
+        assert_eq!(
+            value["status"],
+            json!({
+                "auditTimestamp": "2024-05-01T12:00:00.500Z",
+                "totalViolations": 2,
+                "violations": [
+                    {
+                        "enforcementAction": "deny",
+                        "kind": "Attestation",
+                        "name": "/0",
+                        "message": "[untrusted-key] Signed by none of the trusted keys"
+                    },
+                    {
+                        "enforcementAction": "warn",
+                        "kind": "Attestation",
+                        "name": "/1/tlogEntries/0",
+                        "message": "[no-inclusion-proof] The entry has no proof"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_resource_name() {
+        assert_eq!(
+            resource_name("policies/Release_v2.yaml"),
+            "policies-release-v2-yaml"
+        );
+        assert_eq!(resource_name("--"), "spector");
+        assert_eq!(resource_name(&"a".repeat(70)).len(), 63);
+    }
+}
@@ -0,0 +1,182 @@
+//! Findings and reports produced by semantic validation.
+//!
+//! Structural validation (serde and JSON schema) either accepts or rejects a document.
+//! Semantic rules instead produce findings, each with a stable code, a severity and the
+//! JSON pointer of the offending value, which are collected into a `ValidationReport`.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How serious a finding is.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+/// A single issue found in a document. Create findings with [`Finding::new`] or its shorthands,
+/// as fields may be added.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[non_exhaustive]
+pub struct Finding {
+    /// A stable, machine-readable identifier for the kind of issue, e.g. `empty-subject`.
+    pub code: String,
+    pub severity: Severity,
+    /// JSON pointer to the value the finding refers to.
+    pub path: String,
+    /// A human-readable description of the issue.
+    pub message: String,
+}
+
+impl Finding {
+    /// Creates a new finding.
+    pub fn new(
+        code: impl Into<String>,
+        severity: Severity,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            severity,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates a new finding with `Severity::Error`.
+    pub fn error(
+        code: impl Into<String>,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(code, Severity::Error, path, message)
+    }
+
+    /// Creates a new finding with `Severity::Warning`.
+    pub fn warning(
+        code: impl Into<String>,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(code, Severity::Warning, path, message)
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}] {}: {}",
+            self.severity, self.code, self.path, self.message
+        )
+    }
+}
+
+/// The collected findings for a document.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[non_exhaustive]
+pub struct ValidationReport {
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    /// Creates an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a finding to the report.
+    pub fn push(&mut self, finding: Finding) {
+        self.findings.push(finding);
+    }
+
+    /// Returns true if the report contains any findings with `Severity::Error`.
+    pub fn has_errors(&self) -> bool {
+        self.findings.iter().any(|f| f.severity == Severity::Error)
+    }
+
+    /// Returns an iterator over the findings with `Severity::Error`.
+    pub fn errors(&self) -> impl Iterator<Item = &Finding> {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == Severity::Error)
+    }
+
+    /// Returns an iterator over the findings with `Severity::Warning`.
+    pub fn warnings(&self) -> impl Iterator<Item = &Finding> {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == Severity::Warning)
+    }
+
+    /// Returns true if the report contains no findings at all.
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+impl Extend<Finding> for ValidationReport {
+    fn extend<I: IntoIterator<Item = Finding>>(&mut self, iter: I) {
+        self.findings.extend(iter);
+    }
+}
+
+/// Appends a reference token to a JSON pointer, escaping it per RFC 6901.
+pub fn join_pointer(base: &str, token: &str) -> String {
+    format!("{}/{}", base, token.replace('~', "~0").replace('/', "~1"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_pointer() {
+        assert_eq!(join_pointer("", "subject"), "/subject");
+        assert_eq!(join_pointer("/subject", "0"), "/subject/0");
+        assert_eq!(
+            join_pointer("/annotations", "a/b~c"),
+            "/annotations/a~1b~0c"
+        );
+    }
+
+    #[test]
+    fn test_report_severity_filters() {
+        let mut report = ValidationReport::new();
+        assert!(report.is_empty());
+
+        report.push(Finding::warning("some-warning", "/subject", "a warning"));
+        assert!(!report.has_errors());
+
+        report.push(Finding::error("some-error", "/predicate", "an error"));
+        assert!(report.has_errors());
+        assert_eq!(report.errors().count(), 1);
+        assert_eq!(report.warnings().count(), 1);
+    }
+
+    #[test]
+    fn test_finding_display() {
+        let finding = Finding::error("empty-subject", "/subject", "subject must not be empty");
+        assert_eq!(
+            finding.to_string(),
+            "error[empty-subject] /subject: subject must not be empty"
+        );
+    }
+}
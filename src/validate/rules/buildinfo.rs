@@ -0,0 +1,203 @@
+//! Rules for JVM build-info predicates.
+
+use std::collections::HashSet;
+
+use super::Rule;
+use crate::models::intoto::buildinfo::{is_dynamic_version, Checksums, Coordinates};
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Checks the modules, checksums and plugins of JVM build-info.
+///
+/// - Module and dependency ids that aren't coordinates are `invalid-coordinates` errors, and a
+///   module listed twice a `duplicate-module` error.
+/// - Checksums that aren't hex of the algorithm's length are `malformed-digest` errors. Files
+///   without a checksum are `missing-checksum` warnings, and files with only a SHA-1 or MD5
+///   checksum `weak-checksum` warnings.
+/// - Dependencies and plugins with a dynamic version, such as `1.+` or `[1.0,2.0)`, are
+///   `unpinned-version` warnings, as the build can't be reproduced from them.
+pub struct BuildInfoRule;
+
+impl Rule<InTotoStatementV1> for BuildInfoRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Predicate::JvmBuildInfoV1(build_info) = &statement.predicate else {
+            return;
+        };
+        let mut modules = HashSet::new();
+        for (i, module) in build_info.modules.iter().enumerate() {
+            let path = format!("/predicate/modules/{}", i);
+            check_coordinates(&module.id, &format!("{}/id", path), false, report);
+            if !modules.insert(module.id.as_str()) {
+                report.push(Finding::error(
+                    "duplicate-module",
+                    format!("{}/id", path),
+                    format!("module {} is listed more than once", module.id),
+                ));
+            }
+            for (j, artifact) in module.artifacts.iter().enumerate() {
+                check_checksums(
+                    &artifact.checksums,
+                    &artifact.name,
+                    &format!("{}/artifacts/{}", path, j),
+                    report,
+                );
+            }
+            for (j, dependency) in module.dependencies.iter().enumerate() {
+                let path = format!("{}/dependencies/{}", path, j);
+                check_coordinates(&dependency.id, &format!("{}/id", path), true, report);
+                check_checksums(&dependency.checksums, &dependency.id, &path, report);
+            }
+        }
+        for (i, plugin) in build_info.plugins.iter().enumerate() {
+            if plugin.version.trim().is_empty() || is_dynamic_version(&plugin.version) {
+                report.push(Finding::warning(
+                    "unpinned-version",
+                    format!("/predicate/plugins/{}/version", i),
+                    format!(
+                        "plugin {} has no fixed version, found {:?}",
+                        plugin.id, plugin.version
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+fn check_coordinates(id: &str, path: &str, dependency: bool, report: &mut ValidationReport) {
+    match Coordinates::parse(id) {
+        None => report.push(Finding::error(
+            "invalid-coordinates",
+            path,
+            format!("{} is not group:artifact:version coordinates", id),
+        )),
+        Some(coordinates) if dependency && is_dynamic_version(coordinates.version) => {
+            report.push(Finding::warning(
+                "unpinned-version",
+                path,
+                format!(
+                    "dependency {} has the dynamic version {}",
+                    id, coordinates.version
+                ),
+            ))
+        }
+        Some(_) => {}
+    }
+}
+
+fn check_checksums(checksums: &Checksums, name: &str, path: &str, report: &mut ValidationReport) {
+    let values = [
+        ("sha256", &checksums.sha256, 64),
+        ("sha1", &checksums.sha1, 40),
+        ("md5", &checksums.md5, 32),
+    ];
+    for (algorithm, value, len) in values {
+        let Some(value) = value else {
+            continue;
+        };
+        if value.len() != len || !value.bytes().all(|b| b.is_ascii_hexdigit()) {
+            report.push(Finding::error(
+                "malformed-digest",
+                format!("{}/{}", path, algorithm),
+                format!(
+                    "{} checksum MUST be {} hex characters, found {:?}",
+                    algorithm, len, value
+                ),
+            ));
+        }
+    }
+    if checksums.sha256.is_some() {
+        return;
+    }
+    if checksums.sha1.is_some() || checksums.md5.is_some() {
+        report.push(Finding::warning(
+            "weak-checksum",
+            path,
+            format!("{} only has SHA-1 or MD5 checksums", name),
+        ));
+    } else {
+        report.push(Finding::warning(
+            "missing-checksum",
+            path,
+            format!("{} has no checksum", name),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn statement(predicate: Value) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "hello-world-1.2.3.jar", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://jfrog.com/build-info/v1",
+            "predicate": predicate
+        }))
+        .unwrap()
+    }
+
+    fn findings(statement: &InTotoStatementV1) -> Vec<(String, String)> {
+        let mut report = ValidationReport::new();
+        BuildInfoRule.check(statement, &mut report);
+        report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_build_info() {
+        let statement = statement(json!({
+            "name": "hello-world",
+            "number": "42",
+            "modules": [{
+                "id": "com.example:hello-world:1.2.3",
+                "artifacts": [{
+                    "name": "hello-world-1.2.3.jar",
+                    "sha256": "a7f2b6f2d4d0c6f6bbd0ac66b38c3c91ad8ab3e6d0a0b5a1fd42ef2d0b3a4f1c"
+                }],
+                "dependencies": [{
+                    "id": "com.google.guava:guava:33.0.0-jre",
+                    "sha256": "f6bbd0ac66b38c3c91ad8ab3e6d0a0b5a1fd42ef2d0b3a4f1ca7f2b6f2d4d0c6"
+                }]
+            }],
+            "plugins": [{"id": "org.springframework.boot", "version": "3.2.0"}]
+        }));
+        assert!(findings(&statement).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_build_info() {
+        let statement = statement(json!({
+            "name": "hello-world",
+            "number": "42",
+            "modules": [
+                {
+                    "id": "com.example:hello-world:1.2.3",
+                    "artifacts": [{"name": "hello-world-1.2.3.jar", "sha1": "abc"}],
+                    "dependencies": [{"id": "com.google.guava:guava:33.+"}]
+                },
+                {"id": "com.example:hello-world:1.2.3"},
+                {"id": "hello-world"}
+            ],
+            "plugins": [{"id": "org.springframework.boot", "version": "latest.release"}]
+        }));
+        assert_eq!(
+            findings(&statement),
+            [
+                ("malformed-digest", "/predicate/modules/0/artifacts/0/sha1"),
+                ("weak-checksum", "/predicate/modules/0/artifacts/0"),
+                ("unpinned-version", "/predicate/modules/0/dependencies/0/id"),
+                ("missing-checksum", "/predicate/modules/0/dependencies/0"),
+                ("duplicate-module", "/predicate/modules/1/id"),
+                ("invalid-coordinates", "/predicate/modules/2/id"),
+                ("unpinned-version", "/predicate/plugins/0/version"),
+            ]
+            .map(|(code, path)| (code.to_string(), path.to_string()))
+        );
+    }
+}
@@ -0,0 +1,1131 @@
+//! Rules for the parameters of provenance whose buildType has a profile.
+//!
+//! The profile is selected from the `buildType` URI, see [`BuildTypeProfile::detect`].
+//! Provenance with other buildTypes isn't checked.
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+use super::Rule;
+use crate::models::intoto::buildtypes::buildkit::{
+    BuildkitBuildConfig, BuildkitExternalParameters, BuildkitMode, BuildkitRequest,
+    GATEWAY_FRONTEND,
+};
+use crate::models::intoto::buildtypes::buildkite::{
+    BuildkiteExternalParameters, BuildkiteInternalParameters,
+};
+use crate::models::intoto::buildtypes::circleci::{CircleCiExternalParameters, PROJECT_SLUG_VCS};
+use crate::models::intoto::buildtypes::github::{
+    GitHubActionsExternalParameters, GitHubActionsInternalParameters, WORKFLOWS_DIRECTORY,
+};
+use crate::models::intoto::buildtypes::jenkins::{
+    JenkinsExternalParameters, JenkinsInternalParameters,
+};
+use crate::models::intoto::buildtypes::tekton::{
+    is_run_uid, TektonChainsExternalParameters, TektonChainsInternalParameters,
+    TektonPipelineRunBuildConfig, TektonStep, TektonTaskRunBuildConfig, RUN_SPEC_REFERENCES,
+};
+use crate::models::intoto::buildtypes::BuildTypeProfile;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// The parameters of a provenance, with their pointers.
+struct Parameters<'a> {
+    slsa_v1: bool,
+    external: Option<Value>,
+    external_path: &'static str,
+    internal: Option<&'a Map<String, Value>>,
+    internal_path: &'static str,
+    invocation_id: Option<(&'a str, &'static str)>,
+    build_config: Option<&'a Value>,
+    build_config_path: &'static str,
+    has_dependencies: bool,
+    dependencies_path: &'static str,
+    has_timestamps: bool,
+    metadata_path: &'static str,
+}
+
+/// Checks the parameters of provenance against the profile of its buildType.
+///
+/// For all profiles, parameters that don't have the profile's shape are
+/// `invalid-build-parameters` errors, build and pipeline numbers of 0 `invalid-build-number`
+/// errors, and an `invocationId` other than the URL of the build or workflow an
+/// `invocation-id-mismatch` warning. Then:
+///
+/// - Jenkins: a job URL that isn't an http(s) URL of a job is an `invalid-job-url` error. A
+///   missing node is a `missing-build-node` warning, a node without a name an
+///   `invalid-build-node` error, and builds on the controller a `controller-build` warning.
+/// - CircleCI: a malformed project slug, pipeline id or workflow is an `invalid-project-slug`,
+///   `invalid-pipeline-id` or `invalid-workflow` error.
+/// - Buildkite: malformed organization and pipeline slugs or build id are
+///   `invalid-pipeline-slug` and `invalid-build-id` errors. The agent is checked like Jenkins
+///   nodes.
+/// - BuildKit: a missing frontend, or a gateway frontend without its image, is an
+///   `invalid-frontend` error. The provenance mode is detected from the build config, which
+///   only `max` mode records: build args without it are a `provenance-mode-mismatch` warning,
+///   and a build config without LLB steps an `invalid-build-config` error. The build platform,
+///   dependencies and timestamps, which both modes record, are `missing-buildkit-field`
+///   warnings when missing.
+/// - GitHub Actions: a workflow ref, path or repository that isn't a full git ref, a YAML file in
+///   the workflows directory or the https URL of a repository is an `invalid-workflow-ref`,
+///   `invalid-workflow-path` or `invalid-workflow-repository` error, and non-numeric repository
+///   or owner ids an `invalid-repository-id` error. Runs on self-hosted runners are a
+///   `self-hosted-runner` warning.
+/// - Tekton Chains: an `invocationId` other than the UID of the run is an
+///   `invocation-id-mismatch` warning. In SLSA v0.2 provenance, a buildConfig without steps or
+///   tasks, with tasks of the same name or steps without a container is an
+///   `invalid-build-config` error, and a task listed after a task that isn't in the pipeline an
+///   `unknown-task-dependency` error. Step images not pinned by digest are
+///   `unpinned-step-image` warnings, and tasks that didn't succeed `task-not-succeeded`
+///   warnings. In SLSA v1 provenance, a runSpec that doesn't say which Task or Pipeline ran is
+///   an `invalid-run-spec` error, and missing feature flags a `missing-feature-flags` warning.
+pub struct BuildTypeProfileRule;
+
+impl Rule<InTotoStatementV1> for BuildTypeProfileRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let (build_type, parameters) = match &statement.predicate {
+            Predicate::SLSAProvenanceV1(provenance) => {
+                let definition = &provenance.build_definition;
+                let metadata = provenance.run_details.metadata.as_ref();
+                (
+                    provenance.build_definition.build_type.as_str(),
+                    Parameters {
+                        slsa_v1: true,
+                        external: Some(provenance.build_definition.external_parameters.clone()),
+                        external_path: "/predicate/buildDefinition/externalParameters",
+                        internal: provenance.build_definition.internal_parameters.as_ref(),
+                        internal_path: "/predicate/buildDefinition/internalParameters",
+                        invocation_id: provenance
+                            .run_details
+                            .metadata
+                            .as_ref()
+                            .and_then(|metadata| metadata.invocation_id.as_deref())
+                            .map(|id| (id, "/predicate/runDetails/metadata/invocationId")),
+                        build_config: definition
+                            .internal_parameters
+                            .as_ref()
+                            .and_then(|internal| internal.get("buildConfig")),
+                        build_config_path:
+                            "/predicate/buildDefinition/internalParameters/buildConfig",
+                        has_dependencies: definition
+                            .resolved_dependencies
+                            .as_ref()
+                            .is_some_and(|dependencies| !dependencies.is_empty()),
+                        dependencies_path: "/predicate/buildDefinition/resolvedDependencies",
+                        has_timestamps: metadata.is_some_and(|metadata| {
+                            metadata.started_on.is_some() && metadata.finished_on.is_some()
+                        }),
+                        metadata_path: "/predicate/runDetails/metadata",
+                    },
+                )
+            }
+            Predicate::SLSAProvenanceV02(provenance) => {
+                let invocation = provenance.invocation.as_ref();
+                let metadata = provenance.metadata.as_ref();
+                (
+                    provenance.build_type.as_str(),
+                    Parameters {
+                        slsa_v1: false,
+                        external: invocation
+                            .and_then(|invocation| invocation.parameters.clone())
+                            .map(Value::Object),
+                        external_path: "/predicate/invocation/parameters",
+                        internal: invocation.and_then(|invocation| invocation.environment.as_ref()),
+                        internal_path: "/predicate/invocation/environment",
+                        invocation_id: provenance
+                            .metadata
+                            .as_ref()
+                            .and_then(|metadata| metadata.invocation_id.as_deref())
+                            .map(|id| (id, "/predicate/metadata/buildInvocationId")),
+                        build_config: provenance.build_config.as_ref(),
+                        build_config_path: "/predicate/buildConfig",
+                        has_dependencies: provenance
+                            .materials
+                            .as_ref()
+                            .is_some_and(|materials| !materials.is_empty()),
+                        dependencies_path: "/predicate/materials",
+                        has_timestamps: metadata.is_some_and(|metadata| {
+                            metadata.started_on.is_some() && metadata.finished_on.is_some()
+                        }),
+                        metadata_path: "/predicate/metadata",
+                    },
+                )
+            }
+            _ => return,
+        };
+        match BuildTypeProfile::detect(build_type) {
+            Some(BuildTypeProfile::Jenkins) => check_jenkins(&parameters, report),
+            Some(BuildTypeProfile::CircleCi) => check_circleci(&parameters, report),
+            Some(BuildTypeProfile::Buildkite) => check_buildkite(&parameters, report),
+            Some(BuildTypeProfile::Buildkit) => check_buildkit(&parameters, report),
+            Some(BuildTypeProfile::GitHubActions) => check_github_actions(&parameters, report),
+            Some(BuildTypeProfile::TektonTaskRun) => check_tekton_task_run(&parameters, report),
+            Some(BuildTypeProfile::TektonPipelineRun) => {
+                check_tekton_pipeline_run(&parameters, report)
+            }
+            Some(BuildTypeProfile::TektonChains) => check_tekton_chains(&parameters, report),
+            _ => {}
+        }
+    }
+}
+
+fn check_jenkins(parameters: &Parameters, report: &mut ValidationReport) {
+    let external = parse::<JenkinsExternalParameters>(
+        parameters.external.clone(),
+        parameters.external_path,
+        report,
+    );
+    let internal = parse::<JenkinsInternalParameters>(
+        Some(Value::Object(
+            parameters.internal.cloned().unwrap_or_default(),
+        )),
+        parameters.internal_path,
+        report,
+    );
+
+    if let Some(external) = external {
+        if !external.has_job_url() {
+            report.push(Finding::error(
+                "invalid-job-url",
+                format!("{}/jobUrl", parameters.external_path),
+                format!(
+                    "jobUrl {} is not the http(s) URL of a Jenkins job",
+                    external.job_url
+                ),
+            ));
+        }
+        check_build_number(external.build_number, "buildNumber", parameters, report);
+        check_invocation_id(parameters, &external.build_url(), "build", report);
+    }
+
+    let Some(internal) = internal else {
+        return;
+    };
+    let node_path = format!("{}/node", parameters.internal_path);
+    match internal.node {
+        None => report.push(Finding::warning(
+            "missing-build-node",
+            node_path,
+            "The node the build ran on is not recorded",
+        )),
+        Some(node) if node.name.trim().is_empty() => report.push(Finding::error(
+            "invalid-build-node",
+            format!("{}/name", node_path),
+            "The node the build ran on has no name",
+        )),
+        Some(node) if node.is_controller() => report.push(Finding::warning(
+            "controller-build",
+            format!("{}/name", node_path),
+            format!(
+                "The build ran on the controller ({}) rather than an agent",
+                node.name
+            ),
+        )),
+        Some(_) => {}
+    }
+}
+
+fn check_circleci(parameters: &Parameters, report: &mut ValidationReport) {
+    let Some(external) = parse::<CircleCiExternalParameters>(
+        parameters.external.clone(),
+        parameters.external_path,
+        report,
+    ) else {
+        return;
+    };
+    if !external.has_project_slug() {
+        report.push(Finding::error(
+            "invalid-project-slug",
+            format!("{}/projectSlug", parameters.external_path),
+            format!(
+                "projectSlug {} is not <vcs>/<org>/<repo> with a vcs of {}",
+                external.project_slug,
+                PROJECT_SLUG_VCS.join(", ")
+            ),
+        ));
+    }
+    if !external.has_pipeline_id() {
+        report.push(Finding::error(
+            "invalid-pipeline-id",
+            format!("{}/pipelineId", parameters.external_path),
+            format!("pipelineId {} is not a UUID", external.pipeline_id),
+        ));
+    }
+    check_build_number(
+        external.pipeline_number,
+        "pipelineNumber",
+        parameters,
+        report,
+    );
+    if !external.has_workflow() {
+        report.push(Finding::error(
+            "invalid-workflow",
+            format!("{}/workflow", parameters.external_path),
+            "The workflow must have a UUID id and a name",
+        ));
+    }
+    check_invocation_id(parameters, &external.workflow_url(), "workflow", report);
+}
+
+fn check_buildkite(parameters: &Parameters, report: &mut ValidationReport) {
+    let external = parse::<BuildkiteExternalParameters>(
+        parameters.external.clone(),
+        parameters.external_path,
+        report,
+    );
+    let internal = parse::<BuildkiteInternalParameters>(
+        Some(Value::Object(
+            parameters.internal.cloned().unwrap_or_default(),
+        )),
+        parameters.internal_path,
+        report,
+    );
+
+    if let Some(external) = external {
+        if !external.has_slugs() {
+            report.push(Finding::error(
+                "invalid-pipeline-slug",
+                parameters.external_path,
+                format!(
+                    "{}/{} is not an organization and pipeline slug",
+                    external.organization, external.pipeline
+                ),
+            ));
+        }
+        if !external.has_build_id() {
+            report.push(Finding::error(
+                "invalid-build-id",
+                format!("{}/buildId", parameters.external_path),
+                format!("buildId {} is not a UUID", external.build_id),
+            ));
+        }
+        check_build_number(external.build_number, "buildNumber", parameters, report);
+        check_invocation_id(parameters, &external.build_url(), "build", report);
+    }
+
+    let agent_path = format!("{}/agent", parameters.internal_path);
+    match internal.map(|internal| internal.agent) {
+        Some(None) => report.push(Finding::warning(
+            "missing-build-node",
+            agent_path,
+            "The agent the build ran on is not recorded",
+        )),
+        Some(Some(agent)) if agent.name.trim().is_empty() => report.push(Finding::error(
+            "invalid-build-node",
+            format!("{}/name", agent_path),
+            "The agent the build ran on has no name",
+        )),
+        _ => {}
+    }
+}
+
+fn check_buildkit(parameters: &Parameters, report: &mut ValidationReport) {
+    let mode = BuildkitMode::detect(parameters.build_config);
+    let (request, request_path) = if parameters.slsa_v1 {
+        let external = parse::<BuildkitExternalParameters>(
+            parameters.external.clone(),
+            parameters.external_path,
+            report,
+        );
+        (
+            external.map(|external| external.request),
+            format!("{}/request", parameters.external_path),
+        )
+    } else {
+        let request = parse::<BuildkitRequest>(
+            parameters.external.clone(),
+            parameters.external_path,
+            report,
+        );
+        (request, parameters.external_path.to_string())
+    };
+
+    if let Some(request) = request {
+        if request.frontend.trim().is_empty() {
+            report.push(Finding::error(
+                "invalid-frontend",
+                format!("{}/frontend", request_path),
+                "The frontend of the build is not recorded",
+            ));
+        } else if request.frontend == GATEWAY_FRONTEND && request.frontend_image().is_none() {
+            report.push(Finding::error(
+                "invalid-frontend",
+                format!("{}/args", request_path),
+                format!(
+                    "{} builds must record the frontend image as the source arg",
+                    GATEWAY_FRONTEND
+                ),
+            ));
+        }
+        if mode == BuildkitMode::Min && request.build_args().next().is_some() {
+            report.push(Finding::warning(
+                "provenance-mode-mismatch",
+                format!("{}/args", request_path),
+                "Build args are only recorded in max mode, but the provenance has no buildConfig",
+            ));
+        }
+    }
+
+    if mode == BuildkitMode::Max {
+        let build_config = parse::<BuildkitBuildConfig>(
+            parameters.build_config.cloned(),
+            parameters.build_config_path,
+            report,
+        );
+        if build_config.is_some_and(|build_config| build_config.llb_definition.is_empty()) {
+            report.push(Finding::error(
+                "invalid-build-config",
+                format!("{}/llbDefinition", parameters.build_config_path),
+                "The buildConfig of max mode provenance has no LLB steps",
+            ));
+        }
+    }
+
+    // Recorded in both modes.
+    let platform = if parameters.slsa_v1 {
+        "builderPlatform"
+    } else {
+        "platform"
+    };
+    let mut expected = vec![];
+    if parameters
+        .internal
+        .and_then(|internal| internal.get(platform))
+        .is_none()
+    {
+        expected.push((
+            format!("{}/{}", parameters.internal_path, platform),
+            "build platform",
+        ));
+    }
+    if !parameters.has_dependencies {
+        expected.push((parameters.dependencies_path.to_string(), "dependencies"));
+    }
+    if !parameters.has_timestamps {
+        expected.push((
+            parameters.metadata_path.to_string(),
+            "build start and finish times",
+        ));
+    }
+    for (path, what) in expected {
+        report.push(Finding::warning(
+            "missing-buildkit-field",
+            path,
+            format!("BuildKit records the {} in both min and max mode", what),
+        ));
+    }
+}
+
+fn check_github_actions(parameters: &Parameters, report: &mut ValidationReport) {
+    let external = parse::<GitHubActionsExternalParameters>(
+        parameters.external.clone(),
+        parameters.external_path,
+        report,
+    );
+    let internal = parse::<GitHubActionsInternalParameters>(
+        Some(Value::Object(
+            parameters.internal.cloned().unwrap_or_default(),
+        )),
+        parameters.internal_path,
+        report,
+    );
+
+    if let Some(external) = external {
+        let workflow = &external.workflow;
+        let workflow_path = format!("{}/workflow", parameters.external_path);
+        if !workflow.has_ref() {
+            report.push(Finding::error(
+                "invalid-workflow-ref",
+                format!("{}/ref", workflow_path),
+                format!(
+                    "ref {} is not a full git ref, e.g. refs/heads/main",
+                    workflow.git_ref
+                ),
+            ));
+        }
+        if !workflow.has_path() {
+            report.push(Finding::error(
+                "invalid-workflow-path",
+                format!("{}/path", workflow_path),
+                format!(
+                    "path {} is not a YAML file in {}",
+                    workflow.path, WORKFLOWS_DIRECTORY
+                ),
+            ));
+        }
+        if !workflow.has_repository() {
+            report.push(Finding::error(
+                "invalid-workflow-repository",
+                format!("{}/repository", workflow_path),
+                format!(
+                    "repository {} is not the https URL of a repository",
+                    workflow.repository
+                ),
+            ));
+        }
+        match parameters.invocation_id {
+            Some((invocation_id, path)) if !invocation_id.starts_with(&workflow.runs_url()) => {
+                report.push(Finding::warning(
+                    "invocation-id-mismatch",
+                    path,
+                    format!(
+                        "invocationId {} is not the URL of a workflow run under {}",
+                        invocation_id,
+                        workflow.runs_url()
+                    ),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    let Some(internal) = internal else {
+        return;
+    };
+    let github_path = format!("{}/github", parameters.internal_path);
+    if !internal.github.has_ids() {
+        report.push(Finding::error(
+            "invalid-repository-id",
+            github_path.clone(),
+            "repository_id and repository_owner_id must be numeric ids",
+        ));
+    }
+    if internal.github.is_self_hosted() {
+        report.push(Finding::warning(
+            "self-hosted-runner",
+            format!("{}/runner_environment", github_path),
+            "The workflow ran on a self-hosted runner rather than one hosted by GitHub",
+        ));
+    }
+}
+
+fn check_tekton_task_run(parameters: &Parameters, report: &mut ValidationReport) {
+    check_tekton_invocation_id(parameters, report);
+    let Some(build_config) = parse::<TektonTaskRunBuildConfig>(
+        parameters.build_config.cloned(),
+        parameters.build_config_path,
+        report,
+    ) else {
+        return;
+    };
+    let steps_path = format!("{}/steps", parameters.build_config_path);
+    if build_config.steps.is_empty() {
+        report.push(Finding::error(
+            "invalid-build-config",
+            steps_path.clone(),
+            "The buildConfig of the TaskRun has no steps",
+        ));
+    }
+    check_tekton_steps(&build_config.steps, &steps_path, report);
+}
+
+fn check_tekton_pipeline_run(parameters: &Parameters, report: &mut ValidationReport) {
+    check_tekton_invocation_id(parameters, report);
+    let Some(build_config) = parse::<TektonPipelineRunBuildConfig>(
+        parameters.build_config.cloned(),
+        parameters.build_config_path,
+        report,
+    ) else {
+        return;
+    };
+    let tasks_path = format!("{}/tasks", parameters.build_config_path);
+    if build_config.tasks.is_empty() {
+        report.push(Finding::error(
+            "invalid-build-config",
+            tasks_path.clone(),
+            "The buildConfig of the PipelineRun has no tasks",
+        ));
+    }
+    let mut names = HashSet::new();
+    for (i, task) in build_config.tasks.iter().enumerate() {
+        let task_path = format!("{}/{}", tasks_path, i);
+        if !names.insert(task.name.as_str()) {
+            report.push(Finding::error(
+                "invalid-build-config",
+                format!("{}/name", task_path),
+                format!("The pipeline has more than one task named {}", task.name),
+            ));
+        }
+        if !task.succeeded() {
+            report.push(Finding::warning(
+                "task-not-succeeded",
+                format!("{}/status", task_path),
+                format!("Task {} has status {}", task.name, task.status),
+            ));
+        }
+        check_tekton_steps(&task.steps, &format!("{}/steps", task_path), report);
+    }
+    for (i, after) in build_config.unknown_dependencies() {
+        report.push(Finding::error(
+            "unknown-task-dependency",
+            format!("{}/{}/after", tasks_path, i),
+            format!(
+                "Task {} runs after {}, which is not a task of the pipeline",
+                build_config.tasks[i].name, after
+            ),
+        ));
+    }
+}
+
+fn check_tekton_chains(parameters: &Parameters, report: &mut ValidationReport) {
+    check_tekton_invocation_id(parameters, report);
+    let external = parse::<TektonChainsExternalParameters>(
+        parameters.external.clone(),
+        parameters.external_path,
+        report,
+    );
+    let internal = parse::<TektonChainsInternalParameters>(
+        Some(Value::Object(
+            parameters.internal.cloned().unwrap_or_default(),
+        )),
+        parameters.internal_path,
+        report,
+    );
+
+    if external.is_some_and(|external| !external.has_run_reference()) {
+        report.push(Finding::error(
+            "invalid-run-spec",
+            format!("{}/runSpec", parameters.external_path),
+            format!(
+                "runSpec has none of {}, so it doesn't say what ran",
+                RUN_SPEC_REFERENCES.join(", ")
+            ),
+        ));
+    }
+    if internal.is_some_and(|internal| internal.feature_flags.is_none()) {
+        report.push(Finding::warning(
+            "missing-feature-flags",
+            format!(
+                "{}/tekton-pipelines-feature-flags",
+                parameters.internal_path
+            ),
+            "The Tekton Pipelines feature flags the run had are not recorded",
+        ));
+    }
+}
+
+/// Reports steps without a container and step images not pinned by digest.
+fn check_tekton_steps(steps: &[TektonStep], path: &str, report: &mut ValidationReport) {
+    for (i, step) in steps.iter().enumerate() {
+        let environment_path = format!("{}/{}/environment", path, i);
+        if step.environment.container.trim().is_empty() {
+            report.push(Finding::error(
+                "invalid-build-config",
+                format!("{}/container", environment_path),
+                "The container of the step is not recorded",
+            ));
+        }
+        if !step.has_pinned_image() {
+            report.push(Finding::warning(
+                "unpinned-step-image",
+                format!("{}/image", environment_path),
+                format!(
+                    "Step image {} is not pinned by digest",
+                    step.environment.image
+                ),
+            ));
+        }
+    }
+}
+
+/// Reports an `invocationId` other than the UID of the run, which Tekton Chains records.
+fn check_tekton_invocation_id(parameters: &Parameters, report: &mut ValidationReport) {
+    match parameters.invocation_id {
+        Some((invocation_id, path)) if !is_run_uid(invocation_id) => report.push(Finding::warning(
+            "invocation-id-mismatch",
+            path,
+            format!("invocationId {} is not the UID of the run", invocation_id),
+        )),
+        _ => {}
+    }
+}
+
+/// Reports build and pipeline numbers of 0, as the platforms number them from 1.
+fn check_build_number(
+    number: u64,
+    field: &str,
+    parameters: &Parameters,
+    report: &mut ValidationReport,
+) {
+    if number == 0 {
+        report.push(Finding::error(
+            "invalid-build-number",
+            format!("{}/{}", parameters.external_path, field),
+            format!("{} must be 1 or more", field),
+        ));
+    }
+}
+
+/// Reports an `invocationId` other than the URL the platform records, ignoring a trailing
+/// slash.
+fn check_invocation_id(
+    parameters: &Parameters,
+    expected: &str,
+    what: &str,
+    report: &mut ValidationReport,
+) {
+    match parameters.invocation_id {
+        Some((invocation_id, path))
+            if invocation_id.trim_end_matches('/') != expected.trim_end_matches('/') =>
+        {
+            report.push(Finding::warning(
+                "invocation-id-mismatch",
+                path,
+                format!(
+                    "invocationId {} is not the URL of {} {}",
+                    invocation_id, what, expected
+                ),
+            ));
+        }
+        _ => {}
+    }
+}
+
+/// Reads parameters as the profile's type, reporting them if they don't have its shape.
+fn parse<T: DeserializeOwned>(
+    value: Option<Value>,
+    path: &str,
+    report: &mut ValidationReport,
+) -> Option<T> {
+    let result = match value {
+        Some(value) => serde_json::from_value(value).map_err(|e| e.to_string()),
+        None => Err("the parameters are missing".to_string()),
+    };
+    result
+        .map_err(|e| {
+            report.push(Finding::error(
+                "invalid-build-parameters",
+                path,
+                format!("The parameters don't match the buildType: {}", e),
+            ))
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::intoto::buildtypes::buildkit::BUILDKIT_BUILD_TYPE;
+    use crate::models::intoto::buildtypes::buildkite::BUILDKITE_BUILD_TYPE;
+    use crate::models::intoto::buildtypes::circleci::CIRCLECI_BUILD_TYPE;
+    use crate::models::intoto::buildtypes::github::GITHUB_ACTIONS_BUILD_TYPE;
+    use crate::models::intoto::buildtypes::jenkins::JENKINS_BUILD_TYPE;
+    use crate::models::intoto::buildtypes::tekton::{
+        TEKTON_CHAINS_BUILD_TYPE, TEKTON_PIPELINE_RUN_BUILD_TYPE,
+    };
+    use serde_json::json;
+
+    fn provenance(
+        build_type: &str,
+        external: Value,
+        internal: Value,
+        invocation_id: Option<&str>,
+    ) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://slsa.dev/provenance/v1",
+            "predicate": {
+                "buildDefinition": {
+                    "buildType": build_type,
+                    "externalParameters": external,
+                    "internalParameters": internal
+                },
+                "runDetails": {
+                    "builder": {"id": "https://ci.example.com/"},
+                    "metadata": {"invocationId": invocation_id}
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn codes(statement: &InTotoStatementV1) -> Vec<String> {
+        let mut report = ValidationReport::new();
+        BuildTypeProfileRule.check(statement, &mut report);
+        report.findings.into_iter().map(|f| f.code).collect()
+    }
+
+    #[test]
+    fn test_jenkins() {
+        let valid = provenance(
+            JENKINS_BUILD_TYPE,
+            json!({"jobUrl": "https://ci.example.com/job/release/", "buildNumber": 42}),
+            json!({"node": {"name": "linux-agent-1", "labels": ["linux"]}}),
+            Some("https://ci.example.com/job/release/42/"),
+        );
+        assert!(codes(&valid).is_empty());
+
+        let invalid = provenance(
+            JENKINS_BUILD_TYPE,
+            json!({"jobUrl": "file:///release", "buildNumber": 0}),
+            json!({"node": {"name": "built-in"}}),
+            Some("https://ci.example.com/job/release/42/"),
+        );
+        assert_eq!(
+            codes(&invalid),
+            vec![
+                "invalid-job-url",
+                "invalid-build-number",
+                "invocation-id-mismatch",
+                "controller-build"
+            ]
+        );
+
+        let malformed = provenance(
+            JENKINS_BUILD_TYPE,
+            json!({"jobUrl": "https://ci.example.com/job/release/"}),
+            json!({}),
+            Some("https://ci.example.com/job/release/42/"),
+        );
+        assert_eq!(
+            codes(&malformed),
+            vec!["invalid-build-parameters", "missing-build-node"]
+        );
+    }
+
+    #[test]
+    fn test_circleci() {
+        let external = json!({
+            "projectSlug": "gh/octocat/hello-world",
+            "pipelineId": "5034460f-c7c4-4c43-9457-de07e2029e7b",
+            "pipelineNumber": 42,
+            "workflow": {"id": "fda08377-fe7e-46b1-8992-3a7aaecac9c3", "name": "release"}
+        });
+        let valid = provenance(
+            CIRCLECI_BUILD_TYPE,
+            external.clone(),
+            json!({}),
+            Some("https://app.circleci.com/pipelines/gh/octocat/hello-world/42/workflows/fda08377-fe7e-46b1-8992-3a7aaecac9c3"),
+        );
+        assert!(codes(&valid).is_empty());
+
+        let mut invalid = external;
+        invalid["projectSlug"] = json!("octocat/hello-world");
+        invalid["pipelineId"] = json!("42");
+        invalid["workflow"]["name"] = json!("");
+        assert_eq!(
+            codes(&provenance(CIRCLECI_BUILD_TYPE, invalid, json!({}), None)),
+            vec![
+                "invalid-project-slug",
+                "invalid-pipeline-id",
+                "invalid-workflow"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_buildkite() {
+        let external = json!({
+            "organization": "octocat",
+            "pipeline": "hello-world",
+            "buildId": "0190046e-e199-453b-a302-a21a4d649d31",
+            "buildNumber": 42
+        });
+        let valid = provenance(
+            BUILDKITE_BUILD_TYPE,
+            external.clone(),
+            json!({"agent": {"name": "agent-1"}}),
+            Some("https://buildkite.com/octocat/hello-world/builds/42"),
+        );
+        assert!(codes(&valid).is_empty());
+
+        let mut invalid = external;
+        invalid["organization"] = json!("Octo Cat");
+        invalid["buildNumber"] = json!(0);
+        assert_eq!(
+            codes(&provenance(BUILDKITE_BUILD_TYPE, invalid, json!({}), None)),
+            vec![
+                "invalid-pipeline-slug",
+                "invalid-build-number",
+                "missing-build-node"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_github_actions() {
+        let valid = provenance(
+            GITHUB_ACTIONS_BUILD_TYPE,
+            json!({"workflow": {
+                "ref": "refs/heads/main",
+                "repository": "https://github.com/octo-org/octo-repo",
+                "path": ".github/workflows/release.yml"
+            }}),
+            json!({"github": {
+                "event_name": "push",
+                "repository_id": "123456789",
+                "repository_owner_id": "987654321",
+                "runner_environment": "github-hosted"
+            }}),
+            Some("https://github.com/octo-org/octo-repo/actions/runs/1234/attempts/1"),
+        );
+        assert!(codes(&valid).is_empty());
+
+        let invalid = provenance(
+            GITHUB_ACTIONS_BUILD_TYPE,
+            json!({"workflow": {
+                "ref": "main",
+                "repository": "https://github.com/octo-org",
+                "path": "release.yml"
+            }}),
+            json!({"github": {
+                "event_name": "push",
+                "repository_id": "octo-repo",
+                "repository_owner_id": "987654321",
+                "runner_environment": "self-hosted"
+            }}),
+            Some("https://github.com/other-org/octo-repo/actions/runs/1234/attempts/1"),
+        );
+        assert_eq!(
+            codes(&invalid),
+            vec![
+                "invalid-workflow-ref",
+                "invalid-workflow-path",
+                "invalid-workflow-repository",
+                "invocation-id-mismatch",
+                "invalid-repository-id",
+                "self-hosted-runner"
+            ]
+        );
+
+        let missing = provenance(
+            GITHUB_ACTIONS_BUILD_TYPE,
+            json!({"workflow": {"ref": "refs/heads/main"}}),
+            json!({}),
+            None,
+        );
+        assert_eq!(
+            codes(&missing),
+            vec!["invalid-build-parameters", "invalid-build-parameters"]
+        );
+    }
+
+    #[test]
+    fn test_buildkit_v02_min_mode() {
+        let mut statement = json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://slsa.dev/provenance/v0.2",
+            "predicate": {
+                "builder": {"id": "https://github.com/docker/buildx"},
+                "buildType": BUILDKIT_BUILD_TYPE,
+                "invocation": {
+                    "parameters": {"frontend": "dockerfile.v0", "locals": [{"name": "context"}]},
+                    "environment": {"platform": "linux/amd64"}
+                },
+                "materials": [{"uri": "pkg:docker/alpine@3.19", "digest": {"sha256": "aa"}}],
+                "metadata": {
+                    "buildStartedOn": "2024-05-01T12:00:00Z",
+                    "buildFinishedOn": "2024-05-01T12:01:00Z"
+                }
+            }
+        });
+        let valid: InTotoStatementV1 = serde_json::from_value(statement.clone()).unwrap();
+        assert!(codes(&valid).is_empty());
+
+        let parameters = &mut statement["predicate"]["invocation"]["parameters"];
+        parameters["frontend"] = json!("gateway.v0");
+        parameters["args"] = json!({"build-arg:VERSION": "1.2.3"});
+        statement["predicate"]["materials"] = json!([]);
+        let invalid: InTotoStatementV1 = serde_json::from_value(statement).unwrap();
+        assert_eq!(
+            codes(&invalid),
+            vec![
+                "invalid-frontend",
+                "provenance-mode-mismatch",
+                "missing-buildkit-field"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_buildkit_v1_max_mode() {
+        let mut statement = provenance(
+            BUILDKIT_BUILD_TYPE,
+            json!({
+                "configSource": {"path": "Dockerfile"},
+                "request": {
+                    "frontend": "gateway.v0",
+                    "args": {"build-arg:VERSION": "1.2.3", "source": "docker/dockerfile:1"}
+                }
+            }),
+            json!({
+                "builderPlatform": "linux/amd64",
+                "buildConfig": {"llbDefinition": [{"id": "step0", "op": {}}]}
+            }),
+            None,
+        );
+        let Predicate::SLSAProvenanceV1(provenance) = &mut statement.predicate else {
+            unreachable!()
+        };
+        provenance.build_definition.resolved_dependencies =
+            serde_json::from_value(json!([{"uri": "pkg:docker/alpine@3.19"}])).unwrap();
+        provenance.run_details.metadata = serde_json::from_value(json!({
+            "startedOn": "2024-05-01T12:00:00Z",
+            "finishedOn": "2024-05-01T12:01:00Z"
+        }))
+        .unwrap();
+        assert!(codes(&statement).is_empty());
+
+        let Predicate::SLSAProvenanceV1(provenance) = &mut statement.predicate else {
+            unreachable!()
+        };
+        let internal = provenance
+            .build_definition
+            .internal_parameters
+            .as_mut()
+            .unwrap();
+        internal.insert("buildConfig".to_string(), json!({"llbDefinition": []}));
+        internal.remove("builderPlatform");
+        let mut report = ValidationReport::new();
+        BuildTypeProfileRule.check(&statement, &mut report);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                (
+                    "invalid-build-config",
+                    "/predicate/buildDefinition/internalParameters/buildConfig/llbDefinition"
+                ),
+                (
+                    "missing-buildkit-field",
+                    "/predicate/buildDefinition/internalParameters/builderPlatform"
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jenkins_v02() {
+        let statement: InTotoStatementV1 = serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://slsa.dev/provenance/v0.2",
+            "predicate": {
+                "builder": {"id": "https://ci.example.com/"},
+                "buildType": JENKINS_BUILD_TYPE,
+                "invocation": {
+                    "parameters": {"jobUrl": "https://ci.example.com/job/release/", "buildNumber": 7},
+                    "environment": {"node": {"name": ""}}
+                }
+            }
+        }))
+        .unwrap();
+        let mut report = ValidationReport::new();
+        BuildTypeProfileRule.check(&statement, &mut report);
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].code, "invalid-build-node");
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/invocation/environment/node/name"
+        );
+    }
+
+    #[test]
+    fn test_tekton_pipeline_run_v02() {
+        let step = json!({
+            "entryPoint": "buildah bud .",
+            "environment": {
+                "container": "build",
+                "image": "quay.io/buildah/stable@sha256:abcd1234"
+            }
+        });
+        let mut statement = json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://slsa.dev/provenance/v0.2",
+            "predicate": {
+                "builder": {"id": "https://tekton.dev/chains/v2"},
+                "buildType": TEKTON_PIPELINE_RUN_BUILD_TYPE,
+                "metadata": {"buildInvocationId": "5034460f-c7c4-4c43-9457-de07e2029e7b"},
+                "buildConfig": {
+                    "tasks": [
+                        {"name": "git-clone", "status": "Succeeded", "steps": [step]},
+                        {"name": "build", "after": ["git-clone"], "status": "Succeeded", "steps": [step]}
+                    ]
+                }
+            }
+        });
+        let valid: InTotoStatementV1 = serde_json::from_value(statement.clone()).unwrap();
+        assert!(codes(&valid).is_empty());
+
+        statement["predicate"]["metadata"]["buildInvocationId"] = json!("release-run-1");
+        let tasks = &mut statement["predicate"]["buildConfig"]["tasks"];
+        tasks[0]["name"] = json!("build");
+        tasks[1]["status"] = json!("Failed");
+        tasks[1]["steps"][0]["environment"]["image"] = json!("quay.io/buildah/stable:v1");
+        let invalid: InTotoStatementV1 = serde_json::from_value(statement).unwrap();
+        let mut report = ValidationReport::new();
+        BuildTypeProfileRule.check(&invalid, &mut report);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                (
+                    "invocation-id-mismatch",
+                    "/predicate/metadata/buildInvocationId"
+                ),
+                (
+                    "invalid-build-config",
+                    "/predicate/buildConfig/tasks/1/name"
+                ),
+                (
+                    "task-not-succeeded",
+                    "/predicate/buildConfig/tasks/1/status"
+                ),
+                (
+                    "unpinned-step-image",
+                    "/predicate/buildConfig/tasks/1/steps/0/environment/image"
+                ),
+                (
+                    "unknown-task-dependency",
+                    "/predicate/buildConfig/tasks/1/after"
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tekton_chains_v1() {
+        let valid = provenance(
+            TEKTON_CHAINS_BUILD_TYPE,
+            json!({"runSpec": {"pipelineRef": {"name": "release"}, "params": []}}),
+            json!({"tekton-pipelines-feature-flags": {"EnableAPIFields": "beta"}}),
+            Some("5034460f-c7c4-4c43-9457-de07e2029e7b"),
+        );
+        assert!(codes(&valid).is_empty());
+
+        let invalid = provenance(
+            TEKTON_CHAINS_BUILD_TYPE,
+            json!({"runSpec": {"params": []}}),
+            json!({}),
+            Some("5034460f-c7c4-4c43-9457-de07e2029e7b"),
+        );
+        assert_eq!(
+            codes(&invalid),
+            vec!["invalid-run-spec", "missing-feature-flags"]
+        );
+
+        let malformed = provenance(
+            TEKTON_CHAINS_BUILD_TYPE,
+            json!({"runSpec": "release"}),
+            json!({}),
+            None,
+        );
+        assert_eq!(
+            codes(&malformed),
+            vec!["invalid-build-parameters", "missing-feature-flags"]
+        );
+    }
+}
@@ -0,0 +1,400 @@
+//! Rules for CycloneDX attestation documents.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::HashSet;
+
+use super::Rule;
+use crate::models::cdxa::{CycloneDxAttestation, BOM_FORMAT, MIN_SPEC_VERSION};
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Checks the document is a CycloneDX BOM of a spec version with declarations.
+pub struct BomFormatRule;
+
+impl Rule<CycloneDxAttestation> for BomFormatRule {
+    fn check(&self, document: &CycloneDxAttestation, report: &mut ValidationReport) {
+        if document.bom_format != BOM_FORMAT {
+            report.push(Finding::error(
+                "invalid-bom-format",
+                "/bomFormat",
+                format!(
+                    "bomFormat MUST be {}, found {:?}",
+                    BOM_FORMAT, document.bom_format
+                ),
+            ));
+        }
+        if document
+            .spec_version()
+            .is_none_or(|version| version < MIN_SPEC_VERSION)
+        {
+            report.push(Finding::error(
+                "unsupported-spec-version",
+                "/specVersion",
+                format!(
+                    "declarations need CycloneDX {}.{} or later, found {:?}",
+                    MIN_SPEC_VERSION.0, MIN_SPEC_VERSION.1, document.spec_version
+                ),
+            ));
+        }
+    }
+}
+
+/// Checks the `bom-ref`s between the parts of the declarations resolve.
+///
+/// Attestations must refer to known assessors and claims, and claims to known evidence, as
+/// a claim without its evidence can't be checked. Requirements and targets are only checked
+/// when the document defines some, as they may be defined elsewhere. `bom-ref`s must be unique
+/// within the document.
+pub struct BomRefRule;
+
+impl Rule<CycloneDxAttestation> for BomRefRule {
+    fn check(&self, document: &CycloneDxAttestation, report: &mut ValidationReport) {
+        let declarations = &document.declarations;
+        let mut seen = HashSet::new();
+        let mut collect = |refs: Vec<(String, Option<&String>)>| {
+            let mut collected = HashSet::new();
+            for (path, bom_ref) in refs {
+                let Some(bom_ref) = bom_ref else {
+                    continue;
+                };
+                if !seen.insert(bom_ref.clone()) {
+                    report.push(Finding::error(
+                        "duplicate-bom-ref",
+                        format!("{}/bom-ref", path),
+                        format!("bom-ref {} is used more than once", bom_ref),
+                    ));
+                }
+                collected.insert(bom_ref.clone());
+            }
+            collected
+        };
+
+        let assessors = collect(
+            declarations
+                .assessors
+                .iter()
+                .flatten()
+                .enumerate()
+                .map(|(i, a)| (format!("/declarations/assessors/{}", i), a.bom_ref.as_ref()))
+                .collect(),
+        );
+        let claims = collect(
+            declarations
+                .claims
+                .iter()
+                .flatten()
+                .enumerate()
+                .map(|(i, c)| (format!("/declarations/claims/{}", i), c.bom_ref.as_ref()))
+                .collect(),
+        );
+        let evidence = collect(
+            declarations
+                .evidence
+                .iter()
+                .flatten()
+                .enumerate()
+                .map(|(i, e)| (format!("/declarations/evidence/{}", i), e.bom_ref.as_ref()))
+                .collect(),
+        );
+        let targets = declarations.targets.as_ref().map(|targets| {
+            let mut refs = Vec::new();
+            for (kind, values) in [
+                ("organizations", &targets.organizations),
+                ("components", &targets.components),
+                ("services", &targets.services),
+            ] {
+                for (i, value) in values.iter().flatten().enumerate() {
+                    let bom_ref = value.get("bom-ref").and_then(Value::as_str);
+                    refs.push((
+                        format!("/declarations/targets/{}/{}", kind, i),
+                        bom_ref.map(str::to_string),
+                    ));
+                }
+            }
+            collect(refs.iter().map(|(p, r)| (p.clone(), r.as_ref())).collect())
+        });
+        let standards = document
+            .definitions
+            .as_ref()
+            .and_then(|definitions| definitions.standards.as_ref());
+        let requirements = standards.map(|standards| {
+            let mut refs = Vec::new();
+            for (i, standard) in standards.iter().enumerate() {
+                let path = format!("/definitions/standards/{}", i);
+                refs.push((path.clone(), standard.bom_ref.as_ref()));
+                for (j, requirement) in standard.requirements.iter().flatten().enumerate() {
+                    refs.push((
+                        format!("{}/requirements/{}", path, j),
+                        requirement.bom_ref.as_ref(),
+                    ));
+                }
+                for (j, level) in standard.levels.iter().flatten().enumerate() {
+                    refs.push((format!("{}/levels/{}", path, j), level.bom_ref.as_ref()));
+                }
+            }
+            collect(refs);
+            // Only requirements, not standards or levels, can be mapped to claims.
+            standards
+                .iter()
+                .flat_map(|standard| standard.requirements.iter().flatten())
+                .filter_map(|requirement| requirement.bom_ref.clone())
+                .collect::<HashSet<_>>()
+        });
+
+        let mut check = |known: &HashSet<String>, what: &str, path: String, bom_ref: &String| {
+            if !known.contains(bom_ref) {
+                report.push(Finding::error(
+                    format!("unknown-{}", what),
+                    path,
+                    format!("{} {} is not in the document", what, bom_ref),
+                ));
+            }
+        };
+        for (i, attestation) in declarations.attestations.iter().flatten().enumerate() {
+            let path = format!("/declarations/attestations/{}", i);
+            if let Some(assessor) = &attestation.assessor {
+                check(
+                    &assessors,
+                    "assessor",
+                    format!("{}/assessor", path),
+                    assessor,
+                );
+            }
+            for (j, map) in attestation.map.iter().flatten().enumerate() {
+                let path = format!("{}/map/{}", path, j);
+                if let (Some(requirements), Some(requirement)) = (&requirements, &map.requirement) {
+                    check(
+                        requirements,
+                        "requirement",
+                        format!("{}/requirement", path),
+                        requirement,
+                    );
+                }
+                for (field, refs) in [
+                    ("claims", &map.claims),
+                    ("counterClaims", &map.counter_claims),
+                ] {
+                    for (k, claim) in refs.iter().flatten().enumerate() {
+                        check(&claims, "claim", format!("{}/{}/{}", path, field, k), claim);
+                    }
+                }
+            }
+        }
+        for (i, claim) in declarations.claims.iter().flatten().enumerate() {
+            let path = format!("/declarations/claims/{}", i);
+            if let (Some(targets), Some(target)) = (&targets, &claim.target) {
+                check(targets, "target", format!("{}/target", path), target);
+            }
+            for (field, refs) in [
+                ("evidence", &claim.evidence),
+                ("counterEvidence", &claim.counter_evidence),
+            ] {
+                for (k, bom_ref) in refs.iter().flatten().enumerate() {
+                    check(
+                        &evidence,
+                        "evidence",
+                        format!("{}/{}/{}", path, field, k),
+                        bom_ref,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks conformance and confidence scores are between 0 and 1, and evidence is current.
+///
+/// Evidence that expired before `now` gets an `expired-evidence` warning, as its claims may no
+/// longer hold, and evidence that expires before it was created an error.
+pub struct ScoreAndExpiryRule {
+    pub now: DateTime<Utc>,
+}
+
+impl Rule<CycloneDxAttestation> for ScoreAndExpiryRule {
+    fn check(&self, document: &CycloneDxAttestation, report: &mut ValidationReport) {
+        let declarations = &document.declarations;
+        for (i, attestation) in declarations.attestations.iter().flatten().enumerate() {
+            for (j, map) in attestation.map.iter().flatten().enumerate() {
+                let path = format!("/declarations/attestations/{}/map/{}", i, j);
+                let scores = [
+                    (
+                        "conformance",
+                        map.conformance.as_ref().and_then(|c| c.score),
+                    ),
+                    ("confidence", map.confidence.as_ref().and_then(|c| c.score)),
+                ];
+                for (field, score) in scores {
+                    if let Some(score) = score.filter(|score| !(0.0..=1.0).contains(score)) {
+                        report.push(Finding::error(
+                            "score-out-of-range",
+                            format!("{}/{}/score", path, field),
+                            format!("{} score MUST be between 0 and 1, found {}", field, score),
+                        ));
+                    }
+                }
+            }
+        }
+        for (i, evidence) in declarations.evidence.iter().flatten().enumerate() {
+            let Some(expires) = evidence.expires else {
+                continue;
+            };
+            let path = format!("/declarations/evidence/{}/expires", i);
+            if evidence.created.is_some_and(|created| expires < created) {
+                report.push(Finding::error(
+                    "invalid-evidence-expiry",
+                    path,
+                    format!("evidence expires at {}, before it was created", expires),
+                ));
+            } else if expires < self.now {
+                report.push(Finding::warning(
+                    "expired-evidence",
+                    path,
+                    format!("evidence expired at {}", expires),
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the rules run against CycloneDX attestation documents at a time.
+pub fn cdxa_rules(now: DateTime<Utc>) -> Vec<Box<dyn Rule<CycloneDxAttestation>>> {
+    vec![
+        Box::new(BomFormatRule),
+        Box::new(BomRefRule),
+        Box::new(ScoreAndExpiryRule { now }),
+    ]
+}
+
+/// Runs the CycloneDX attestation rules against a document.
+pub fn check_cdxa(document: &CycloneDxAttestation) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    for rule in cdxa_rules(Utc::now()) {
+        rule.check(document, &mut report);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::report::Severity;
+    use serde_json::json;
+
+    fn document() -> Value {
+        serde_json::from_str(
+            &std::fs::read_to_string(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/cdxa.json"
+            ))
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn findings(value: Value) -> Vec<(String, String)> {
+        let document: CycloneDxAttestation = serde_json::from_value(value).unwrap();
+        check_cdxa(&document)
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect()
+    }
+
+    fn owned(findings: &[(&str, &str)]) -> Vec<(String, String)> {
+        findings
+            .iter()
+            .map(|(code, path)| (code.to_string(), path.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_document() {
+        assert_eq!(findings(document()), Vec::new());
+    }
+
+    #[test]
+    fn test_bom_format() {
+        let mut value = document();
+        value["bomFormat"] = json!("SPDX");
+        value["specVersion"] = json!("1.5");
+        assert_eq!(
+            findings(value),
+            owned(&[
+                ("invalid-bom-format", "/bomFormat"),
+                ("unsupported-spec-version", "/specVersion"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_bom_refs() {
+        let mut value = document();
+        let declarations = &mut value["declarations"];
+        declarations["attestations"][0]["assessor"] = json!("assessor-2");
+        declarations["attestations"][0]["map"][0]["requirement"] = json!("level-2");
+        declarations["attestations"][0]["map"][0]["counterClaims"] = json!(["claim-2"]);
+        declarations["claims"][0]["target"] = json!("component-2");
+        declarations["claims"][0]["evidence"] = json!(["evidence-1", "evidence-2"]);
+        declarations["evidence"][0]["bom-ref"] = json!("claim-1");
+        assert_eq!(
+            findings(value),
+            owned(&[
+                ("duplicate-bom-ref", "/declarations/evidence/0/bom-ref"),
+                ("unknown-assessor", "/declarations/attestations/0/assessor"),
+                (
+                    "unknown-requirement",
+                    "/declarations/attestations/0/map/0/requirement"
+                ),
+                (
+                    "unknown-claim",
+                    "/declarations/attestations/0/map/0/counterClaims/0"
+                ),
+                ("unknown-target", "/declarations/claims/0/target"),
+                ("unknown-evidence", "/declarations/claims/0/evidence/0"),
+                ("unknown-evidence", "/declarations/claims/0/evidence/1"),
+            ])
+        );
+
+        // Requirements and targets defined elsewhere aren't checked.
+        let mut value = document();
+        value.as_object_mut().unwrap().remove("definitions");
+        value["declarations"]
+            .as_object_mut()
+            .unwrap()
+            .remove("targets");
+        value["declarations"]["claims"][0]["target"] = json!("component-2");
+        assert_eq!(findings(value), Vec::new());
+    }
+
+    #[test]
+    fn test_scores_and_expiry() {
+        let mut value = document();
+        let declarations = &mut value["declarations"];
+        declarations["attestations"][0]["map"][0]["confidence"]["score"] = json!(90);
+        declarations["evidence"][0]["expires"] = json!("2024-06-01T00:00:00Z");
+        let document: CycloneDxAttestation = serde_json::from_value(value.clone()).unwrap();
+        let now = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .into();
+        let mut report = ValidationReport::new();
+        ScoreAndExpiryRule { now }.check(&document, &mut report);
+        let codes = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.severity))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                ("score-out-of-range", Severity::Error),
+                ("expired-evidence", Severity::Warning),
+            ]
+        );
+
+        value["declarations"]["evidence"][0]["expires"] = json!("2024-01-01T00:00:00Z");
+        assert!(findings(value).contains(&(
+            "invalid-evidence-expiry".to_string(),
+            "/declarations/evidence/0/expires".to_string()
+        )));
+    }
+}
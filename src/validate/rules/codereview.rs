@@ -0,0 +1,138 @@
+//! Rules for code review predicates.
+
+use std::collections::HashSet;
+
+use super::Rule;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Checks that the approvals of a code review come from its reviewers and cover its head.
+///
+/// - Approvals by someone who isn't one of the reviewers are `unknown-reviewer` errors.
+/// - Approvals by the author of the change are `self-approval` warnings, and approvals of a
+///   commit other than the head `stale-approval` warnings, as neither is an independent review
+///   of the change as merged.
+/// - A review without such an independent approval of the head is a `missing-approval` warning.
+pub struct CodeReviewRule;
+
+impl Rule<InTotoStatementV1> for CodeReviewRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Predicate::CodeReviewV01(review) = &statement.predicate else {
+            return;
+        };
+        let reviewers: HashSet<&str> = review.reviewers.iter().map(|r| r.id.as_str()).collect();
+        for (i, approval) in review.approvals.iter().enumerate() {
+            let path = format!("/predicate/approvals/{}", i);
+            if !reviewers.contains(approval.reviewer.as_str()) {
+                report.push(Finding::error(
+                    "unknown-reviewer",
+                    format!("{}/reviewer", path),
+                    format!(
+                        "{} approved the change but is not a reviewer",
+                        approval.reviewer
+                    ),
+                ));
+            }
+            if review.author.as_deref() == Some(approval.reviewer.as_str()) {
+                report.push(Finding::warning(
+                    "self-approval",
+                    format!("{}/reviewer", path),
+                    format!("{} approved their own change", approval.reviewer),
+                ));
+            }
+            if approval.commit != review.commit_range.head {
+                report.push(Finding::warning(
+                    "stale-approval",
+                    format!("{}/commit", path),
+                    format!(
+                        "The approval is of {}, not of the head {}",
+                        approval.commit, review.commit_range.head
+                    ),
+                ));
+            }
+        }
+        if review.independent_approvals().next().is_none() {
+            report.push(Finding::warning(
+                "missing-approval",
+                "/predicate/approvals",
+                "The head of the change was not approved by anyone other than its author",
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn codes(statement: &InTotoStatementV1) -> Vec<(String, String)> {
+        let mut report = ValidationReport::new();
+        CodeReviewRule.check(statement, &mut report);
+        report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect()
+    }
+
+    fn statement(approvals: serde_json::Value) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"gitCommit": "abcd1234"}}],
+            "predicateType": "https://kusari.dev/attestation/code-review/v0.1",
+            "predicate": {
+                "tool": {"name": "GitHub"},
+                "author": "octocat",
+                "commitRange": {
+                    "repository": "https://github.com/octocat/hello-world",
+                    "base": "1234abcd",
+                    "head": "abcd1234"
+                },
+                "reviewers": [{"id": "hubot"}, {"id": "octocat"}],
+                "approvals": approvals
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_code_review_rule() {
+        let valid = statement(json!([{"reviewer": "hubot", "commit": "abcd1234"}]));
+        assert!(codes(&valid).is_empty());
+
+        let invalid = statement(json!([
+            {"reviewer": "monalisa", "commit": "abcd1234"},
+            {"reviewer": "hubot", "commit": "1234abcd"}
+        ]));
+        assert_eq!(
+            codes(&invalid),
+            vec![
+                (
+                    "unknown-reviewer".to_string(),
+                    "/predicate/approvals/0/reviewer".to_string()
+                ),
+                (
+                    "stale-approval".to_string(),
+                    "/predicate/approvals/1/commit".to_string()
+                )
+            ]
+        );
+
+        let unapproved = statement(json!([{"reviewer": "octocat", "commit": "abcd1234"}]));
+        assert_eq!(
+            codes(&unapproved),
+            vec![
+                (
+                    "self-approval".to_string(),
+                    "/predicate/approvals/0/reviewer".to_string()
+                ),
+                (
+                    "missing-approval".to_string(),
+                    "/predicate/approvals".to_string()
+                ),
+            ]
+        );
+    }
+}
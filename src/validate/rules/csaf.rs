@@ -0,0 +1,449 @@
+//! Rules for CSAF VEX predicates.
+
+use std::collections::{HashMap, HashSet};
+
+use super::Rule;
+use crate::models::intoto::csaf::{
+    CsafDocument, ProductStatus, TrackingStatus, Vulnerability, CSAF_VERSION, CSAF_VEX_CATEGORY,
+};
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Checks the document, tracking and product tree of a CSAF VEX document, following the
+/// mandatory tests of the CSAF 2.0 spec.
+///
+/// - A category other than `csaf_vex` is an `invalid-csaf-category` error, and a CSAF version
+///   other than 2.0 an `unsupported-csaf-version` error.
+/// - A final or interim document without revisions, or whose version isn't the number of its
+///   latest revision, is an `invalid-tracking` error.
+/// - A document without a product tree is a `missing-product-tree` error, and one without
+///   vulnerabilities a `missing-vulnerabilities` error. Product ids defined more than once are
+///   `duplicate-product-id` errors, and references to product ids or group ids that aren't
+///   defined `unknown-product-id` errors.
+/// - Vulnerabilities without a CVE or other id are `missing-vulnerability-id` errors, and those
+///   without a known affected, known not affected, fixed or under investigation product a
+///   `missing-product-status` error. Products in contradicting statuses are
+///   `contradicting-product-status` errors.
+/// - Products known not to be affected without a flag or impact threat are
+///   `missing-impact-statement` errors, and products known to be affected without a remediation
+///   `missing-action-statement` errors.
+pub struct CsafVexRule;
+
+impl Rule<InTotoStatementV1> for CsafVexRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Predicate::CsafV2(csaf) = &statement.predicate else {
+            return;
+        };
+        let document = &csaf.document;
+        if document.category != CSAF_VEX_CATEGORY {
+            report.push(Finding::error(
+                "invalid-csaf-category",
+                "/predicate/document/category",
+                format!(
+                    "category {} is not the VEX profile, {}",
+                    document.category, CSAF_VEX_CATEGORY
+                ),
+            ));
+        }
+        if document.csaf_version != CSAF_VERSION {
+            report.push(Finding::error(
+                "unsupported-csaf-version",
+                "/predicate/document/csaf_version",
+                format!(
+                    "CSAF version {} is not supported, use {}",
+                    document.csaf_version, CSAF_VERSION
+                ),
+            ));
+        }
+        check_tracking(csaf, report);
+
+        let (products, groups) = check_product_tree(csaf, report);
+        if csaf.vulnerabilities.is_empty() {
+            report.push(Finding::error(
+                "missing-vulnerabilities",
+                "/predicate/vulnerabilities",
+                "The VEX document has no vulnerabilities",
+            ));
+        }
+        for (i, vulnerability) in csaf.vulnerabilities.iter().enumerate() {
+            let path = format!("/predicate/vulnerabilities/{}", i);
+            check_vulnerability(vulnerability, &path, &products, &groups, report);
+        }
+    }
+}
+
+fn check_tracking(csaf: &CsafDocument, report: &mut ValidationReport) {
+    let tracking = &csaf.document.tracking;
+    let path = "/predicate/document/tracking";
+    if tracking.status == TrackingStatus::Draft {
+        return;
+    }
+    match tracking
+        .revision_history
+        .iter()
+        .max_by_key(|revision| revision.date)
+    {
+        None => report.push(Finding::error(
+            "invalid-tracking",
+            format!("{}/revision_history", path),
+            "A final or interim document has no revision history",
+        )),
+        Some(latest) if latest.number != tracking.version => report.push(Finding::error(
+            "invalid-tracking",
+            format!("{}/version", path),
+            format!(
+                "version {} is not the number of the latest revision, {}",
+                tracking.version, latest.number
+            ),
+        )),
+        _ => {}
+    }
+}
+
+/// Reports a missing product tree and product ids defined more than once, returning the
+/// defined product ids and group ids.
+fn check_product_tree<'a>(
+    csaf: &'a CsafDocument,
+    report: &mut ValidationReport,
+) -> (HashSet<&'a str>, HashMap<&'a str, &'a [String]>) {
+    let mut products = HashSet::new();
+    let mut groups = HashMap::new();
+    let Some(tree) = &csaf.product_tree else {
+        report.push(Finding::error(
+            "missing-product-tree",
+            "/predicate/product_tree",
+            "The VEX document has no product tree",
+        ));
+        return (products, groups);
+    };
+    for (product_id, path) in tree.product_ids() {
+        if !products.insert(product_id) {
+            report.push(Finding::error(
+                "duplicate-product-id",
+                format!("/predicate/product_tree{}", path),
+                format!("product id {} is defined more than once", product_id),
+            ));
+        }
+    }
+    for (i, relationship) in tree.relationships.iter().enumerate() {
+        let path = format!("/predicate/product_tree/relationships/{}", i);
+        check_product_ids(
+            [
+                ("product_reference", &relationship.product_reference),
+                (
+                    "relates_to_product_reference",
+                    &relationship.relates_to_product_reference,
+                ),
+            ],
+            &path,
+            &products,
+            report,
+        );
+    }
+    for (i, group) in tree.product_groups.iter().enumerate() {
+        let path = format!("/predicate/product_tree/product_groups/{}/product_ids", i);
+        check_product_ids(indexed(&group.product_ids), &path, &products, report);
+        groups.insert(group.group_id.as_str(), group.product_ids.as_slice());
+    }
+    (products, groups)
+}
+
+fn check_vulnerability(
+    vulnerability: &Vulnerability,
+    path: &str,
+    products: &HashSet<&str>,
+    groups: &HashMap<&str, &[String]>,
+    report: &mut ValidationReport,
+) {
+    if !vulnerability.is_identified() {
+        report.push(Finding::error(
+            "missing-vulnerability-id",
+            path,
+            "The vulnerability has neither a CVE nor another id",
+        ));
+    }
+    let default = ProductStatus::default();
+    let status = vulnerability.product_status.as_ref().unwrap_or(&default);
+    if status.known_affected.is_empty()
+        && status.known_not_affected.is_empty()
+        && status.fixed.is_empty()
+        && status.under_investigation.is_empty()
+    {
+        report.push(Finding::error(
+            "missing-product-status",
+            format!("{}/product_status", path),
+            "No product is known affected, known not affected, fixed or under investigation",
+        ));
+    }
+    let mut group_statuses: HashMap<&str, &str> = HashMap::new();
+    for (name, ids) in status.statuses() {
+        let status_path = format!("{}/product_status/{}", path, name);
+        check_product_ids(indexed(ids), &status_path, products, report);
+        let Some(group) = status_group(name) else {
+            continue;
+        };
+        for (i, id) in ids.iter().enumerate() {
+            match group_statuses.insert(id.as_str(), group) {
+                Some(other) if other != group => report.push(Finding::error(
+                    "contradicting-product-status",
+                    format!("{}/{}", status_path, i),
+                    format!("product {} is both {} and {}", id, other, group),
+                )),
+                _ => {}
+            }
+        }
+    }
+
+    let mut references = Vec::new();
+    for (kind, items) in [
+        (
+            "flags",
+            vulnerability
+                .flags
+                .iter()
+                .map(|flag| (&flag.product_ids, &flag.group_ids, true))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "threats",
+            vulnerability
+                .threats
+                .iter()
+                .map(|threat| {
+                    (
+                        &threat.product_ids,
+                        &threat.group_ids,
+                        threat.category == "impact",
+                    )
+                })
+                .collect(),
+        ),
+        (
+            "remediations",
+            vulnerability
+                .remediations
+                .iter()
+                .map(|remediation| (&remediation.product_ids, &remediation.group_ids, true))
+                .collect(),
+        ),
+    ] {
+        for (i, (product_ids, group_ids, counts)) in items.into_iter().enumerate() {
+            let item_path = format!("{}/{}/{}", path, kind, i);
+            check_product_ids(
+                indexed(product_ids),
+                &format!("{}/product_ids", item_path),
+                products,
+                report,
+            );
+            for (j, group_id) in group_ids.iter().enumerate() {
+                if !groups.contains_key(group_id.as_str()) {
+                    report.push(Finding::error(
+                        "unknown-product-id",
+                        format!("{}/group_ids/{}", item_path, j),
+                        format!("group id {} is not defined in the product tree", group_id),
+                    ));
+                }
+            }
+            if counts {
+                let grouped = group_ids
+                    .iter()
+                    .filter_map(|group_id| groups.get(group_id.as_str()))
+                    .flat_map(|ids| ids.iter());
+                references.extend(
+                    product_ids
+                        .iter()
+                        .chain(grouped)
+                        .map(|id| (kind, id.as_str())),
+                );
+            }
+        }
+    }
+    let covered = |kinds: &[&str], id: &str| {
+        references
+            .iter()
+            .any(|(kind, covered)| kinds.contains(kind) && *covered == id)
+    };
+    for (i, id) in status.known_not_affected.iter().enumerate() {
+        if !covered(&["flags", "threats"], id) {
+            report.push(Finding::error(
+                "missing-impact-statement",
+                format!("{}/product_status/known_not_affected/{}", path, i),
+                format!(
+                    "product {} is known not affected without a flag or impact threat",
+                    id
+                ),
+            ));
+        }
+    }
+    for (i, id) in status.known_affected.iter().enumerate() {
+        if !covered(&["remediations"], id) {
+            report.push(Finding::error(
+                "missing-action-statement",
+                format!("{}/product_status/known_affected/{}", path, i),
+                format!("product {} is known affected without a remediation", id),
+            ));
+        }
+    }
+}
+
+/// Returns the group of statuses a product status belongs to, as a product can't be in two
+/// groups for the same vulnerability. Recommended products can be in any group.
+fn status_group(status: &str) -> Option<&'static str> {
+    match status {
+        "first_affected" | "known_affected" | "last_affected" => Some("affected"),
+        "known_not_affected" => Some("not affected"),
+        "first_fixed" | "fixed" => Some("fixed"),
+        "under_investigation" => Some("under investigation"),
+        _ => None,
+    }
+}
+
+fn indexed(ids: &[String]) -> Vec<(String, &String)> {
+    ids.iter()
+        .enumerate()
+        .map(|(i, id)| (i.to_string(), id))
+        .collect()
+}
+
+/// Reports references to product ids the product tree doesn't define.
+fn check_product_ids<K: ToString, V: AsRef<str>>(
+    references: impl IntoIterator<Item = (K, V)>,
+    path: &str,
+    products: &HashSet<&str>,
+    report: &mut ValidationReport,
+) {
+    for (key, id) in references {
+        let id = id.as_ref();
+        if !products.contains(id) {
+            report.push(Finding::error(
+                "unknown-product-id",
+                format!("{}/{}", path, key.to_string()),
+                format!("product id {} is not defined in the product tree", id),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn findings(predicate: serde_json::Value) -> Vec<(String, String)> {
+        let statement: InTotoStatementV1 = serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://docs.oasis-open.org/csaf/csaf/v2",
+            "predicate": predicate
+        }))
+        .unwrap();
+        let mut report = ValidationReport::new();
+        CsafVexRule.check(&statement, &mut report);
+        report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect()
+    }
+
+    fn document() -> serde_json::Value {
+        json!({
+            "document": {
+                "category": "csaf_vex",
+                "csaf_version": "2.0",
+                "publisher": {"category": "vendor", "name": "Example", "namespace": "https://example.com"},
+                "title": "Example VEX",
+                "tracking": {
+                    "id": "EX-2024-0001",
+                    "status": "final",
+                    "version": "2",
+                    "initial_release_date": "2024-05-01T12:00:00Z",
+                    "current_release_date": "2024-05-02T12:00:00Z",
+                    "revision_history": [
+                        {"date": "2024-05-01T12:00:00Z", "number": "1", "summary": "Initial"},
+                        {"date": "2024-05-02T12:00:00Z", "number": "2", "summary": "Fixed in 2.0.0"}
+                    ]
+                }
+            },
+            "product_tree": {
+                "full_product_names": [
+                    {"name": "Example 1.2.3", "product_id": "EX-123"},
+                    {"name": "Example 2.0.0", "product_id": "EX-200"}
+                ],
+                "product_groups": [{"group_id": "EX-ALL", "product_ids": ["EX-123", "EX-200"]}]
+            },
+            "vulnerabilities": [{
+                "cve": "CVE-2024-3094",
+                "product_status": {"known_affected": ["EX-123"], "known_not_affected": ["EX-200"]},
+                "flags": [{"label": "vulnerable_code_not_present", "group_ids": ["EX-ALL"]}],
+                "remediations": [{"category": "vendor_fix", "details": "Upgrade to 2.0.0", "product_ids": ["EX-123"]}]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_csaf_vex_rule() {
+        assert!(findings(document()).is_empty());
+
+        let mut invalid = document();
+        invalid["document"]["tracking"]["version"] = json!("1");
+        invalid["product_tree"]["full_product_names"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({"name": "Example 1.2.3-1", "product_id": "EX-123"}));
+        let vulnerability = &mut invalid["vulnerabilities"][0];
+        vulnerability["product_status"]["fixed"] = json!(["EX-123", "EX-300"]);
+        vulnerability["remediations"] = json!([]);
+        vulnerability["flags"][0]["group_ids"] = json!(["EX-NONE"]);
+        let path = |s: &str| s.to_string();
+        assert_eq!(
+            findings(invalid),
+            vec![
+                (
+                    path("invalid-tracking"),
+                    path("/predicate/document/tracking/version")
+                ),
+                (
+                    path("duplicate-product-id"),
+                    path("/predicate/product_tree/full_product_names/2/product_id")
+                ),
+                (
+                    path("unknown-product-id"),
+                    path("/predicate/vulnerabilities/0/product_status/fixed/1")
+                ),
+                (
+                    path("contradicting-product-status"),
+                    path("/predicate/vulnerabilities/0/product_status/known_affected/0")
+                ),
+                (
+                    path("unknown-product-id"),
+                    path("/predicate/vulnerabilities/0/flags/0/group_ids/0")
+                ),
+                (
+                    path("missing-impact-statement"),
+                    path("/predicate/vulnerabilities/0/product_status/known_not_affected/0")
+                ),
+                (
+                    path("missing-action-statement"),
+                    path("/predicate/vulnerabilities/0/product_status/known_affected/0")
+                ),
+            ]
+        );
+
+        let mut empty = document();
+        empty["product_tree"] = serde_json::Value::Null;
+        empty["vulnerabilities"] = json!([{"notes": []}]);
+        assert_eq!(
+            findings(empty)
+                .into_iter()
+                .map(|(code, _)| code)
+                .collect::<Vec<_>>(),
+            vec![
+                "missing-product-tree",
+                "missing-vulnerability-id",
+                "missing-product-status"
+            ]
+        );
+    }
+}
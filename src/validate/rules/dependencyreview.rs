@@ -0,0 +1,144 @@
+//! Rules for dependency review predicates.
+
+use std::collections::HashSet;
+
+use super::Rule;
+use crate::enrich::PackageUrl;
+use crate::models::intoto::dependencyreview::ChangeType;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Checks the packages and advisories of a dependency review.
+///
+/// - Changes whose `purl` isn't a purl are `invalid-purl` errors, and added packages whose purl
+///   has no version `unpinned-dependency` warnings, as the review can't tell which version was
+///   checked against the advisories.
+/// - A package added or removed more than once is a `duplicate-change` error.
+/// - Advisories with an empty id are `missing-advisory-id` errors.
+pub struct DependencyReviewRule;
+
+impl Rule<InTotoStatementV1> for DependencyReviewRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Predicate::DependencyReviewV01(review) = &statement.predicate else {
+            return;
+        };
+        let mut seen = HashSet::new();
+        for (i, change) in review.changes.iter().enumerate() {
+            let path = format!("/predicate/changes/{}", i);
+            match PackageUrl::parse(&change.purl) {
+                None => report.push(Finding::error(
+                    "invalid-purl",
+                    format!("{}/purl", path),
+                    format!("{} is not a purl", change.purl),
+                )),
+                Some(purl) if purl.version.is_none() && change.change_type == ChangeType::Added => {
+                    report.push(Finding::warning(
+                        "unpinned-dependency",
+                        format!("{}/purl", path),
+                        format!(
+                            "{} has no version, so it can't be told which one was reviewed",
+                            change.purl
+                        ),
+                    ))
+                }
+                Some(_) => {}
+            }
+            if !seen.insert((change.change_type, change.purl.as_str())) {
+                report.push(Finding::error(
+                    "duplicate-change",
+                    path.clone(),
+                    format!(
+                        "{} is {} more than once",
+                        change.purl,
+                        match change.change_type {
+                            ChangeType::Added => "added",
+                            ChangeType::Removed => "removed",
+                        }
+                    ),
+                ));
+            }
+            for (j, advisory) in change.advisories.iter().enumerate() {
+                if advisory.id.trim().is_empty() {
+                    report.push(Finding::error(
+                        "missing-advisory-id",
+                        format!("{}/advisories/{}/id", path, j),
+                        "The advisory has no id to look it up by",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn codes(statement: &InTotoStatementV1) -> Vec<(String, String)> {
+        let mut report = ValidationReport::new();
+        DependencyReviewRule.check(statement, &mut report);
+        report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect()
+    }
+
+    fn statement(changes: serde_json::Value) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"gitCommit": "abcd1234"}}],
+            "predicateType": "https://kusari.dev/attestation/dependency-review/v0.1",
+            "predicate": {
+                "tool": {"name": "dependency-review-action"},
+                "base": {"digest": {"gitCommit": "1234abcd"}},
+                "head": {"digest": {"gitCommit": "abcd1234"}},
+                "changes": changes
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dependency_review_rule() {
+        let valid = statement(json!([
+            {"changeType": "removed", "purl": "pkg:npm/lodash@4.17.20"},
+            {"changeType": "added", "purl": "pkg:npm/lodash@4.17.21"},
+            {"changeType": "removed", "purl": "pkg:npm/left-pad"}
+        ]));
+        assert!(codes(&valid).is_empty());
+
+        let invalid = statement(json!([
+            {"changeType": "added", "purl": "npm/lodash@4.17.21"},
+            {"changeType": "added", "purl": "pkg:npm/%40babel/core"},
+            {"changeType": "added", "purl": "pkg:npm/%40babel/core", "advisories": [{"id": ""}]}
+        ]));
+        assert_eq!(
+            codes(&invalid),
+            vec![
+                (
+                    "invalid-purl".to_string(),
+                    "/predicate/changes/0/purl".to_string()
+                ),
+                (
+                    "unpinned-dependency".to_string(),
+                    "/predicate/changes/1/purl".to_string()
+                ),
+                (
+                    "unpinned-dependency".to_string(),
+                    "/predicate/changes/2/purl".to_string()
+                ),
+                (
+                    "duplicate-change".to_string(),
+                    "/predicate/changes/2".to_string()
+                ),
+                (
+                    "missing-advisory-id".to_string(),
+                    "/predicate/changes/2/advisories/0/id".to_string()
+                ),
+            ]
+        );
+    }
+}
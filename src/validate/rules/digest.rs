@@ -0,0 +1,245 @@
+//! Rules for the format of digest values.
+
+use base64::{engine::general_purpose, Engine};
+use std::collections::HashMap;
+
+use super::Rule;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::resource_descriptor::ResourceDescriptor;
+use crate::models::intoto::statement::{Algorithm, InTotoStatementV1};
+use crate::validate::report::{join_pointer, Finding, ValidationReport};
+
+/// Checks that digests for algorithms with a well-defined encoding are well-formed.
+///
+/// Git object digests (`gitCommit`, `gitTree`, `gitBlob`, `gitTag`) must be 40 (SHA-1) or 64
+/// (SHA-256) hex characters, and `dirHash` digests must use the `h1:` format from Go's
+/// dirhash package, i.e. a base64-encoded SHA-256. Resource descriptors must also have a
+/// `uri`, `digest` or `content` to identify what they describe.
+pub struct DigestFormatRule;
+
+impl Rule<InTotoStatementV1> for DigestFormatRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        for (i, subject) in statement.subject.iter().enumerate() {
+            let path = format!("/subject/{}/digest", i);
+            for (algorithm, digest) in subject.digest.iter() {
+                check_digest(
+                    *algorithm,
+                    digest,
+                    &join_pointer(&path, algorithm.as_str()),
+                    report,
+                );
+            }
+        }
+
+        match &statement.predicate {
+            Predicate::SLSAProvenanceV1(provenance) => {
+                let build_definition = &provenance.build_definition;
+                let run_details = &provenance.run_details;
+                check_descriptors(
+                    build_definition.resolved_dependencies.as_deref(),
+                    "/predicate/buildDefinition/resolvedDependencies",
+                    report,
+                );
+                check_descriptors(
+                    run_details.builder.builder_dependencies.as_deref(),
+                    "/predicate/runDetails/builder/builderDependencies",
+                    report,
+                );
+                check_descriptors(
+                    run_details.byproducts.as_deref(),
+                    "/predicate/runDetails/byproducts",
+                    report,
+                );
+            }
+            Predicate::SLSAProvenanceV02(provenance) => {
+                if let Some(config_source) = provenance
+                    .invocation
+                    .as_ref()
+                    .and_then(|i| i.config_source.as_ref())
+                {
+                    check_digest_map(
+                        config_source.digest.as_ref(),
+                        "/predicate/invocation/configSource/digest",
+                        report,
+                    );
+                }
+                check_descriptors(
+                    provenance.materials.as_deref(),
+                    "/predicate/materials",
+                    report,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_descriptors(
+    descriptors: Option<&[ResourceDescriptor]>,
+    path: &str,
+    report: &mut ValidationReport,
+) {
+    for (i, descriptor) in descriptors.unwrap_or_default().iter().enumerate() {
+        if descriptor.is_empty() {
+            report.push(Finding::error(
+                "empty-resource-descriptor",
+                format!("{}/{}", path, i),
+                "resource descriptor MUST set at least one of uri, digest or content",
+            ));
+        }
+        check_digest_map(
+            descriptor.digest.as_ref(),
+            &format!("{}/{}/digest", path, i),
+            report,
+        );
+    }
+}
+
+fn check_digest_map(
+    digests: Option<&HashMap<String, String>>,
+    path: &str,
+    report: &mut ValidationReport,
+) {
+    for (name, digest) in digests.into_iter().flatten() {
+        // Unknown algorithms are allowed in resource descriptors and have no defined format.
+        if let Ok(algorithm) = name.parse::<Algorithm>() {
+            check_digest(algorithm, digest, &join_pointer(path, name), report);
+        }
+    }
+}
+
+/// Checks a single digest value, adding an error to the report if it is malformed.
+pub fn check_digest(algorithm: Algorithm, digest: &str, path: &str, report: &mut ValidationReport) {
+    let message = match algorithm {
+        Algorithm::GitBlob | Algorithm::GitCommit | Algorithm::GitTag | Algorithm::GitTree => {
+            if is_git_object_id(digest) {
+                return;
+            }
+            format!(
+                "{} digest MUST be 40 or 64 hex characters, found {:?}",
+                algorithm, digest
+            )
+        }
+        Algorithm::DirHash => {
+            if is_dirhash(digest) {
+                return;
+            }
+            format!(
+                "dirHash digest MUST be `h1:` followed by a base64-encoded SHA-256, found {:?}",
+                digest
+            )
+        }
+        _ => return,
+    };
+
+    report.push(Finding::error("malformed-digest", path, message));
+}
+
+/// Returns true if the value is a SHA-1 or SHA-256 git object ID.
+fn is_git_object_id(digest: &str) -> bool {
+    (digest.len() == 40 || digest.len() == 64) && digest.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Returns true if the value is a Go dirhash `h1:` hash.
+fn is_dirhash(digest: &str) -> bool {
+    digest
+        .strip_prefix("h1:")
+        .and_then(|encoded| general_purpose::STANDARD.decode(encoded).ok())
+        .map(|decoded| decoded.len() == 32)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn statement(
+        subject_digest: serde_json::Value,
+        dependencies: serde_json::Value,
+    ) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": subject_digest}],
+            "predicateType": "https://slsa.dev/provenance/v1",
+            "predicate": {
+                "buildDefinition": {
+                    "buildType": "https://example.com/buildType/v1",
+                    "externalParameters": {},
+                    "resolvedDependencies": dependencies
+                },
+                "runDetails": {
+                    "builder": {"id": "https://example.com/builder"}
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_valid_digests() {
+        let statement = statement(
+            json!({"dirHash": "h1:47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="}),
+            json!([
+                {
+                    "uri": "git+https://github.com/octocat/hello-world@refs/heads/main",
+                    "digest": {"gitCommit": "c27d339ee6075c1f744c5d4b200f7901aad2c369"}
+                }
+            ]),
+        );
+
+        let mut report = ValidationReport::new();
+        DigestFormatRule.check(&statement, &mut report);
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_malformed_digests() {
+        let statement = statement(
+            json!({"dirHash": "47DEQpj8HBSa"}),
+            json!([
+                {
+                    "uri": "https://example.com/dependency1",
+                    "digest": {"sha256": "not-checked"}
+                },
+                {
+                    "uri": "git+https://github.com/octocat/hello-world@refs/heads/main",
+                    "digest": {"gitCommit": "c27d339"}
+                }
+            ]),
+        );
+
+        let mut report = ValidationReport::new();
+        DigestFormatRule.check(&statement, &mut report);
+        let paths = report.errors().map(|f| f.path.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/subject/0/digest/dirHash",
+                "/predicate/buildDefinition/resolvedDependencies/1/digest/gitCommit"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_descriptors() {
+        let statement = statement(
+            json!({"sha256": "aa"}),
+            json!([{"digest": {"sha256": "bb"}}, {"name": "unidentified"}]),
+        );
+
+        let mut report = ValidationReport::new();
+        DigestFormatRule.check(&statement, &mut report);
+        let findings = report
+            .errors()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![(
+                "empty-resource-descriptor",
+                "/predicate/buildDefinition/resolvedDependencies/1"
+            )]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! Rules for license identifiers.
+
+use super::Rule;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::models::sbom::licenses::{lookup_license_id, LicenseId, LICENSE_LIST_VERSION};
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Checks the identifiers of the licenses detected by a license scan against the SPDX License
+/// List.
+///
+/// - Identifiers that are neither on the list nor `LicenseRef-` identifiers are
+///   `unknown-license-id` errors, as consumers can't tell which license was detected.
+/// - Identifiers the list has deprecated are `deprecated-license-id` warnings, and identifiers
+///   written in a different case than on the list `non-canonical-license-id` warnings.
+pub struct LicenseIdRule;
+
+impl Rule<InTotoStatementV1> for LicenseIdRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Predicate::LicenseScanV01(predicate) = &statement.predicate else {
+            return;
+        };
+        for (i, license) in predicate.licenses.iter().enumerate() {
+            let path = format!("/predicate/licenses/{}/spdxId", i);
+            let id = license.spdx_id.as_str();
+            match lookup_license_id(id) {
+                LicenseId::Listed(listed) if listed != id => report.push(Finding::warning(
+                    "non-canonical-license-id",
+                    path,
+                    format!("{} is written {} on the SPDX License List", id, listed),
+                )),
+                LicenseId::Deprecated(deprecated) => report.push(Finding::warning(
+                    "deprecated-license-id",
+                    path,
+                    format!("{} is deprecated by the SPDX License List", deprecated),
+                )),
+                LicenseId::Unknown => report.push(Finding::error(
+                    "unknown-license-id",
+                    path,
+                    format!(
+                        "{} is neither on the SPDX License List {} nor a LicenseRef- identifier",
+                        id, LICENSE_LIST_VERSION
+                    ),
+                )),
+                LicenseId::Listed(_) | LicenseId::Custom => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn codes(statement: &InTotoStatementV1) -> Vec<(String, String)> {
+        let mut report = ValidationReport::new();
+        LicenseIdRule.check(statement, &mut report);
+        report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect()
+    }
+
+    fn statement(licenses: serde_json::Value) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://kusari.dev/attestation/license-scan/v0.1",
+            "predicate": {
+                "scanner": {"name": "scancode-toolkit"},
+                "target": {"uri": "https://example.com/source.tar.gz"},
+                "licenses": licenses
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_license_id_rule() {
+        let valid = statement(json!([
+            {"spdxId": "Apache-2.0"},
+            {"spdxId": "LicenseRef-scancode-proprietary", "name": "Proprietary"}
+        ]));
+        assert!(codes(&valid).is_empty());
+
+        let invalid = statement(json!([
+            {"spdxId": "mit"},
+            {"spdxId": "GPL-2.0"},
+            {"spdxId": "Apache 2.0"},
+            {"spdxId": "MIT OR Apache-2.0"}
+        ]));
+        assert_eq!(
+            codes(&invalid),
+            vec![
+                (
+                    "non-canonical-license-id".to_string(),
+                    "/predicate/licenses/0/spdxId".to_string()
+                ),
+                (
+                    "deprecated-license-id".to_string(),
+                    "/predicate/licenses/1/spdxId".to_string()
+                ),
+                (
+                    "unknown-license-id".to_string(),
+                    "/predicate/licenses/2/spdxId".to_string()
+                ),
+                (
+                    "unknown-license-id".to_string(),
+                    "/predicate/licenses/3/spdxId".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Semantic rules run against documents that have already been deserialized.
+//!
+//! Serde and JSON schema validation only check the shape of a document. Rules check the
+//! things the specs recommend but don't encode structurally, adding findings to a
+//! `ValidationReport` rather than failing outright.
+
+pub mod buildinfo;
+pub mod buildtype;
+pub mod cdxa;
+pub mod codereview;
+pub mod csaf;
+pub mod dependencyreview;
+pub mod digest;
+pub mod license;
+pub mod predicate_schema;
+pub mod provenance;
+pub mod reference;
+pub mod registered;
+pub mod sarif;
+pub mod subject;
+
+use tracing::{debug, instrument};
+
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::policy::Policy;
+use crate::validate::report::ValidationReport;
+
+/// A semantic check against a deserialized document of type `T`.
+pub trait Rule<T> {
+    /// Checks the document and adds any findings to the report.
+    fn check(&self, document: &T, report: &mut ValidationReport);
+}
+
+/// Returns the set of rules run against In-Toto v1 statements for the given policy.
+pub fn statement_rules(policy: &Policy) -> Vec<Box<dyn Rule<InTotoStatementV1>>> {
+    vec![
+        Box::new(subject::SubjectRule {
+            strict: policy.strict,
+            allow_wildcard: policy.allow_wildcard_subjects,
+        }),
+        Box::new(subject::DuplicateSubjectRule),
+        Box::new(digest::DigestFormatRule),
+        Box::new(provenance::ParametersShapeRule),
+        Box::new(provenance::DuplicateDependencyRule),
+        Box::new(buildtype::BuildTypeProfileRule),
+        Box::new(buildinfo::BuildInfoRule),
+        Box::new(sarif::SarifRule),
+        Box::new(csaf::CsafVexRule),
+        Box::new(reference::ReferenceRule),
+        Box::new(license::LicenseIdRule),
+        Box::new(codereview::CodeReviewRule),
+        Box::new(dependencyreview::DependencyReviewRule),
+        Box::new(registered::RegisteredPredicateRule),
+        Box::new(provenance::InvocationIdFormatRule {
+            rules: policy.invocation_id_rules.clone(),
+        }),
+        Box::new(provenance::BuilderAllowlistRule {
+            builders: policy.allowed_builders.clone(),
+            build_types: policy.allowed_build_types.clone(),
+        }),
+        Box::new(provenance::FreshnessRule::from_policy(policy)),
+        Box::new(provenance::CompletenessRule {
+            required: policy.required_completeness.clone(),
+            reproducible: policy.require_reproducible,
+        }),
+    ]
+}
+
+/// Runs the statement rules for the given policy against the given statement.
+#[instrument(level = "debug", skip_all, fields(predicate_type = %statement.predicate_type))]
+pub fn check_statement(statement: &InTotoStatementV1, policy: &Policy) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    for rule in statement_rules(policy) {
+        rule.check(statement, &mut report);
+    }
+    debug!(findings = report.findings.len(), "Ran the statement rules");
+    report
+}
@@ -0,0 +1,86 @@
+//! Rules checking predicates against JSON schemas supplied by users.
+
+use anyhow::Result;
+use jsonschema::JSONSchema;
+use serde_json::Value;
+use std::sync::Arc;
+
+use super::Rule;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::schema::cache::SchemaCache;
+use crate::validate::report::{Finding, ValidationReport};
+use crate::validate::suggest::describe_schema_error;
+
+/// Checks the predicate of a statement against a JSON schema, typically for a predicate type
+/// Spector doesn't know and so only checks to be a JSON value.
+///
+/// Predicates of known types are checked against the schema too, in addition to their typed
+/// checks. Each violation is a `predicate-schema-violation` error, with its path from the
+/// statement root.
+pub struct PredicateSchemaRule {
+    schema: Value,
+    compiled: Arc<JSONSchema>,
+}
+
+impl PredicateSchemaRule {
+    /// Compiles the schema, failing if it isn't a valid JSON schema.
+    pub fn new(schema: Value) -> Result<Self> {
+        let compiled = SchemaCache::global().get_or_compile(&schema)?;
+        Ok(Self { schema, compiled })
+    }
+}
+
+impl Rule<InTotoStatementV1> for PredicateSchemaRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Ok(predicate) = serde_json::to_value(&statement.predicate) else {
+            return;
+        };
+        let Err(errors) = self.compiled.validate(&predicate) else {
+            return;
+        };
+        for error in errors {
+            report.push(Finding::error(
+                "predicate-schema-violation",
+                format!("/predicate{}", error.instance_path),
+                describe_schema_error(&self.schema, &error),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_predicate_schema_rule() {
+        let rule = PredicateSchemaRule::new(json!({
+            "type": "object",
+            "properties": {"approver": {"type": "string", "minLength": 1}},
+            "required": ["approver"]
+        }))
+        .unwrap();
+        let statement = |predicate: Value| -> InTotoStatementV1 {
+            serde_json::from_value(json!({
+                "_type": "https://in-toto.io/Statement/v1",
+                "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+                "predicateType": "https://example.com/release-approval/v1",
+                "predicate": predicate
+            }))
+            .unwrap()
+        };
+
+        let mut report = ValidationReport::new();
+        rule.check(&statement(json!({"approver": "octocat"})), &mut report);
+        assert!(report.findings.is_empty());
+
+        let mut report = ValidationReport::new();
+        rule.check(&statement(json!({"approver": ""})), &mut report);
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].code, "predicate-schema-violation");
+        assert_eq!(report.findings[0].path, "/predicate/approver");
+
+        assert!(PredicateSchemaRule::new(json!({"type": "not-a-type"})).is_err());
+    }
+}
@@ -0,0 +1,775 @@
+//! Rules for SLSA provenance predicates.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use super::Rule;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::policy::{Completeness, InvocationIdRule, Policy};
+use crate::validate::report::{join_pointer, Finding, ValidationReport};
+
+/// The SLSA requirements that v0.2 provenance claims are evidence for.
+const SLSA_V01_REQUIREMENTS: &str = "https://slsa.dev/spec/v0.1/requirements";
+
+/// Checks that free-form parameter fields use the object shape recommended by the SLSA specs.
+///
+/// `externalParameters` (v1) and `buildConfig` (v0.2) accept arbitrary JSON so that real-world
+/// provenance deserializes, but anything other than an object is flagged as a warning.
+pub struct ParametersShapeRule;
+
+impl Rule<InTotoStatementV1> for ParametersShapeRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        match &statement.predicate {
+            Predicate::SLSAProvenanceV1(provenance) => {
+                let external_parameters = &provenance.build_definition.external_parameters;
+                if !external_parameters.is_object() {
+                    report.push(Finding::warning(
+                        "external-parameters-shape",
+                        "/predicate/buildDefinition/externalParameters",
+                        format!(
+                            "externalParameters SHOULD be a JSON object, found {}",
+                            json_type_name(external_parameters)
+                        ),
+                    ));
+                }
+            }
+            Predicate::SLSAProvenanceV02(provenance) => {
+                if let Some(build_config) = &provenance.build_config {
+                    if !build_config.is_object() {
+                        report.push(Finding::warning(
+                            "build-config-shape",
+                            "/predicate/buildConfig",
+                            format!(
+                                "buildConfig SHOULD be a JSON object, found {}",
+                                json_type_name(build_config)
+                            ),
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Checks that provenance `invocationId`s have the format expected for the builder that
+/// produced them.
+///
+/// The first rule whose prefix matches `builder.id` applies. Ids that don't have its format are
+/// flagged as warnings, while missing ids and builders without a rule aren't checked.
+pub struct InvocationIdFormatRule {
+    pub rules: Vec<InvocationIdRule>,
+}
+
+impl Rule<InTotoStatementV1> for InvocationIdFormatRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let (builder_id, invocation_id, path) = match &statement.predicate {
+            Predicate::SLSAProvenanceV1(provenance) => (
+                &provenance.run_details.builder.id,
+                provenance
+                    .run_details
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.invocation_id.as_ref()),
+                "/predicate/runDetails/metadata/invocationId",
+            ),
+            Predicate::SLSAProvenanceV02(provenance) => (
+                &provenance.builder.id,
+                provenance
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.invocation_id.as_ref()),
+                "/predicate/metadata/buildInvocationId",
+            ),
+            _ => return,
+        };
+        let Some(invocation_id) = invocation_id else {
+            return;
+        };
+        let rule = self
+            .rules
+            .iter()
+            .find(|rule| builder_id.as_str().starts_with(&rule.builder_id_prefix));
+        if let Some(rule) = rule.filter(|rule| !rule.format.matches(invocation_id)) {
+            report.push(Finding::warning(
+                "invocation-id-format",
+                path,
+                format!(
+                    "invocationId {} of builder {} is not {}",
+                    invocation_id,
+                    builder_id,
+                    rule.format.describe()
+                ),
+            ));
+        }
+    }
+}
+
+/// Checks the completeness and reproducibility claims of SLSA v0.2 provenance against the
+/// policy.
+///
+/// Claims the policy requires but the provenance doesn't make, including when they are missing,
+/// are errors, with a reference to the SLSA requirement they support.
+pub struct CompletenessRule {
+    pub required: Vec<Completeness>,
+    pub reproducible: bool,
+}
+
+impl Rule<InTotoStatementV1> for CompletenessRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Predicate::SLSAProvenanceV02(provenance) = &statement.predicate else {
+            return;
+        };
+        let metadata = provenance.metadata.as_ref();
+        let completeness = metadata.and_then(|metadata| metadata.completeness.as_ref());
+        for claim in &self.required {
+            let claimed = completeness.and_then(|completeness| match claim {
+                Completeness::Parameters => completeness.parameters,
+                Completeness::Environment => completeness.environment,
+                Completeness::Materials => completeness.materials,
+            });
+            if claimed != Some(true) {
+                let (requirement, anchor) = match claim {
+                    Completeness::Parameters => (
+                        "Includes all build parameters",
+                        "includes-all-build-parameters",
+                    ),
+                    Completeness::Environment => ("Hermetic", "hermetic"),
+                    Completeness::Materials => ("Dependencies complete", "dependencies-complete"),
+                };
+                report.push(Finding::error(
+                    "completeness-not-claimed",
+                    format!("/predicate/metadata/completeness/{}", claim.name()),
+                    format!(
+                        "The policy requires completeness.{} to be true, for the SLSA requirement \"{}\" ({}#{})",
+                        claim.name(),
+                        requirement,
+                        SLSA_V01_REQUIREMENTS,
+                        anchor
+                    ),
+                ));
+            }
+        }
+        let reproducible = metadata.and_then(|metadata| metadata.reproducible);
+        if self.reproducible && reproducible != Some(true) {
+            report.push(Finding::error(
+                "reproducible-not-claimed",
+                "/predicate/metadata/reproducible",
+                format!(
+                    "The policy requires reproducible to be true, for the SLSA requirement \"Reproducible\" ({}#reproducible)",
+                    SLSA_V01_REQUIREMENTS
+                ),
+            ));
+        }
+    }
+}
+
+/// Checks that provenance comes from an allowed builder and build type.
+///
+/// The `builder.id` and `buildType` must each start with one of the allowed prefixes, or they
+/// are reported as `builder-not-allowed` and `build-type-not-allowed` errors. An empty list
+/// allows anything.
+pub struct BuilderAllowlistRule {
+    pub builders: Vec<String>,
+    pub build_types: Vec<String>,
+}
+
+impl Rule<InTotoStatementV1> for BuilderAllowlistRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let (builder_id, build_type, paths) = match &statement.predicate {
+            Predicate::SLSAProvenanceV1(provenance) => (
+                &provenance.run_details.builder.id,
+                provenance.build_definition.build_type.as_str(),
+                (
+                    "/predicate/runDetails/builder/id",
+                    "/predicate/buildDefinition/buildType",
+                ),
+            ),
+            Predicate::SLSAProvenanceV02(provenance) => (
+                &provenance.builder.id,
+                provenance.build_type.as_str(),
+                ("/predicate/builder/id", "/predicate/buildType"),
+            ),
+            _ => return,
+        };
+        let allowed = |prefixes: &[String], uri: &str| {
+            prefixes.is_empty()
+                || prefixes
+                    .iter()
+                    .any(|prefix| uri.starts_with(prefix.as_str()))
+        };
+        if !allowed(&self.builders, builder_id.as_str()) {
+            report.push(Finding::error(
+                "builder-not-allowed",
+                paths.0,
+                format!("builder {} is not in the allowed builders", builder_id),
+            ));
+        }
+        if !allowed(&self.build_types, build_type) {
+            report.push(Finding::error(
+                "build-type-not-allowed",
+                paths.1,
+                format!("buildType {} is not in the allowed build types", build_type),
+            ));
+        }
+    }
+}
+
+/// Checks that attestations are recent enough to be accepted.
+///
+/// Provenance whose build finished more than the maximum age before `now` is reported as an
+/// `attestation-stale` error, and any statement checked after the policy's `notAfter` time as an
+/// `attestation-expired` error. Provenance without a finish time isn't checked for its age.
+pub struct FreshnessRule {
+    pub max_age_seconds: Option<u64>,
+    pub not_after: Option<DateTime<Utc>>,
+    /// The time the attestations are checked at.
+    pub now: DateTime<Utc>,
+}
+
+impl FreshnessRule {
+    /// Returns the rule for the policy, checking attestations at the current time.
+    pub fn from_policy(policy: &Policy) -> Self {
+        Self {
+            max_age_seconds: policy.max_age_seconds,
+            not_after: policy.not_after,
+            now: Utc::now(),
+        }
+    }
+
+    /// Checks the age of a time an attestation was produced, such as when a build finished or
+    /// when it was added to a transparency log.
+    pub fn check_age(&self, time: &DateTime<Utc>, path: &str, report: &mut ValidationReport) {
+        let Some(max_age_seconds) = self.max_age_seconds else {
+            return;
+        };
+        let age = (self.now - *time).num_seconds();
+        if age > i64::try_from(max_age_seconds).unwrap_or(i64::MAX) {
+            report.push(Finding::error(
+                "attestation-stale",
+                path,
+                format!(
+                    "{} is {} seconds old, more than the maximum age of {} seconds",
+                    time.to_rfc3339(),
+                    age,
+                    max_age_seconds
+                ),
+            ));
+        }
+    }
+}
+
+impl Rule<InTotoStatementV1> for FreshnessRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        if let Some(not_after) = self.not_after.filter(|not_after| self.now > *not_after) {
+            report.push(Finding::error(
+                "attestation-expired",
+                "",
+                format!(
+                    "Attestations are not accepted after {}",
+                    not_after.to_rfc3339()
+                ),
+            ));
+        }
+        let (finished_on, path) = match &statement.predicate {
+            Predicate::SLSAProvenanceV1(provenance) => (
+                provenance
+                    .run_details
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.finished_on.as_ref()),
+                "/predicate/runDetails/metadata/finishedOn",
+            ),
+            Predicate::SLSAProvenanceV02(provenance) => (
+                provenance
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.finished_on.as_ref()),
+                "/predicate/metadata/buildFinishedOn",
+            ),
+            _ => return,
+        };
+        if let Some(finished_on) = finished_on {
+            self.check_age(finished_on, path, report);
+        }
+    }
+}
+
+/// Checks that dependencies listed more than once with the same URI agree on their digests.
+///
+/// A dependency in `resolvedDependencies` (v1) or `materials` (v0.2) whose URI appears earlier
+/// in the list with a different digest for the same algorithm is reported as a warning, as a
+/// single build can't have used two versions of the same resource.
+pub struct DuplicateDependencyRule;
+
+impl Rule<InTotoStatementV1> for DuplicateDependencyRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let (dependencies, path) = match &statement.predicate {
+            Predicate::SLSAProvenanceV1(provenance) => (
+                &provenance.build_definition.resolved_dependencies,
+                "/predicate/buildDefinition/resolvedDependencies",
+            ),
+            Predicate::SLSAProvenanceV02(provenance) => {
+                (&provenance.materials, "/predicate/materials")
+            }
+            _ => return,
+        };
+        let dependencies = dependencies
+            .iter()
+            .flatten()
+            .map(|dependency| (dependency.uri.as_ref(), dependency.digest.as_ref()))
+            .collect::<Vec<_>>();
+
+        for (j, (uri, digests)) in dependencies.iter().enumerate() {
+            let (Some(uri), Some(digests)) = (uri, digests) else {
+                continue;
+            };
+            let mut algorithms = digests.keys().collect::<Vec<_>>();
+            algorithms.sort();
+            let conflict = algorithms.into_iter().find_map(|algorithm| {
+                let digest = &digests[algorithm];
+                dependencies[..j]
+                    .iter()
+                    .find_map(|(other_uri, other_digests)| {
+                        let other = other_digests.as_ref()?.get(algorithm)?;
+                        (*other_uri == Some(*uri) && !other.eq_ignore_ascii_case(digest))
+                            .then_some((algorithm, other))
+                    })
+            });
+            if let Some((algorithm, other)) = conflict {
+                report.push(Finding::warning(
+                    "conflicting-dependency-digest",
+                    join_pointer(&format!("{}/{}/digest", path, j), algorithm),
+                    format!(
+                        "dependency {} is listed more than once with different {} digests, {} and {}",
+                        uri, algorithm, other, digests[algorithm]
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the JSON type name of a value for use in messages.
+pub(crate) fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn statement(predicate_type: &str, predicate: Value) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": predicate_type,
+            "predicate": predicate
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_external_parameters_array() {
+        let statement = statement(
+            "https://slsa.dev/provenance/v1",
+            json!({
+                "buildDefinition": {
+                    "buildType": "https://example.com/buildType/v1",
+                    "externalParameters": ["--release"]
+                },
+                "runDetails": {
+                    "builder": {"id": "https://example.com/builder"}
+                }
+            }),
+        );
+
+        let mut report = ValidationReport::new();
+        ParametersShapeRule.check(&statement, &mut report);
+        assert_eq!(report.warnings().count(), 1);
+        assert_eq!(report.findings[0].code, "external-parameters-shape");
+        assert!(!report.has_errors());
+    }
+
+    #[test]
+    fn test_build_config_steps() {
+        let statement = statement(
+            "https://slsa.dev/provenance/v0.2",
+            json!({
+                "builder": {"id": "https://example.com/builder"},
+                "buildType": "https://example.com/buildType/v1",
+                "buildConfig": [{"command": ["make"]}]
+            }),
+        );
+
+        let mut report = ValidationReport::new();
+        ParametersShapeRule.check(&statement, &mut report);
+        assert_eq!(report.findings[0].code, "build-config-shape");
+        assert_eq!(report.findings[0].path, "/predicate/buildConfig");
+    }
+
+    #[test]
+    fn test_object_parameters() {
+        let statement = statement(
+            "https://slsa.dev/provenance/v1",
+            json!({
+                "buildDefinition": {
+                    "buildType": "https://example.com/buildType/v1",
+                    "externalParameters": {"ref": "main"}
+                },
+                "runDetails": {
+                    "builder": {"id": "https://example.com/builder"}
+                }
+            }),
+        );
+
+        let mut report = ValidationReport::new();
+        ParametersShapeRule.check(&statement, &mut report);
+        assert!(report.is_empty());
+    }
+
+    fn invocation_id_rule() -> InvocationIdFormatRule {
+        let policy: crate::validate::policy::Policy = serde_json::from_value(json!({
+            "invocationIdRules": [
+                {
+                    "builderIdPrefix": "https://github.com/slsa-framework/slsa-github-generator/",
+                    "format": "github-actions"
+                },
+                {"builderIdPrefix": "https://github.com/", "format": "uuid"}
+            ]
+        }))
+        .unwrap();
+        InvocationIdFormatRule {
+            rules: policy.invocation_id_rules,
+        }
+    }
+
+    fn provenance_v1(builder_id: &str, invocation_id: &str) -> InTotoStatementV1 {
+        statement(
+            "https://slsa.dev/provenance/v1",
+            json!({
+                "buildDefinition": {
+                    "buildType": "https://example.com/buildType/v1",
+                    "externalParameters": {}
+                },
+                "runDetails": {
+                    "builder": {"id": builder_id},
+                    "metadata": {"invocationId": invocation_id}
+                }
+            }),
+        )
+    }
+
+    #[test]
+    fn test_invocation_id_format() {
+        let builder = "https://github.com/slsa-framework/slsa-github-generator/.github/workflows/generator_generic_slsa3.yml@refs/tags/v1.9.0";
+        let rule = invocation_id_rule();
+
+        let mut report = ValidationReport::new();
+        rule.check(
+            &provenance_v1(
+                builder,
+                "https://github.com/org/repo/actions/runs/123/attempts/1",
+            ),
+            &mut report,
+        );
+        assert!(report.is_empty());
+
+        rule.check(&provenance_v1(builder, "run-123"), &mut report);
+        assert_eq!(report.warnings().count(), 1);
+        assert_eq!(report.findings[0].code, "invocation-id-format");
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/runDetails/metadata/invocationId"
+        );
+        assert!(report.findings[0]
+            .message
+            .contains("is not a GitHub Actions run URL"));
+    }
+
+    #[test]
+    fn test_invocation_id_format_first_matching_rule() {
+        let rule = invocation_id_rule();
+        let mut report = ValidationReport::new();
+        // Only the more general rule matches this builder, and it expects a UUID.
+        rule.check(
+            &provenance_v1(
+                "https://github.com/other/builder",
+                "https://github.com/org/repo/actions/runs/123",
+            ),
+            &mut report,
+        );
+        assert!(report.findings[0].message.ends_with("is not a UUID"));
+
+        // Builders without a rule aren't checked.
+        let mut report = ValidationReport::new();
+        rule.check(
+            &provenance_v1("https://example.com/builder", "anything"),
+            &mut report,
+        );
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_builder_allowlist() {
+        let rule = BuilderAllowlistRule {
+            builders: vec![
+                "https://github.com/slsa-framework/slsa-github-generator/".to_string(),
+                "https://example.com/builder".to_string(),
+            ],
+            build_types: vec!["https://example.com/buildType/".to_string()],
+        };
+
+        let mut report = ValidationReport::new();
+        rule.check(
+            &provenance_v1("https://example.com/builder", "build-1"),
+            &mut report,
+        );
+        assert!(report.is_empty());
+
+        let statement = statement(
+            "https://slsa.dev/provenance/v0.2",
+            json!({
+                "builder": {"id": "https://untrusted.example.com/builder"},
+                "buildType": "https://other.example.com/buildType/v1"
+            }),
+        );
+        rule.check(&statement, &mut report);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                ("builder-not-allowed", "/predicate/builder/id"),
+                ("build-type-not-allowed", "/predicate/buildType"),
+            ]
+        );
+
+        // Empty lists allow anything.
+        let mut report = ValidationReport::new();
+        BuilderAllowlistRule {
+            builders: vec![],
+            build_types: vec![],
+        }
+        .check(&statement, &mut report);
+        assert!(report.is_empty());
+    }
+
+    fn freshness_rule(now: &str) -> FreshnessRule {
+        FreshnessRule {
+            max_age_seconds: Some(86400),
+            not_after: Some("2023-06-01T00:00:00Z".parse().unwrap()),
+            now: now.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_freshness() {
+        let provenance = statement(
+            "https://slsa.dev/provenance/v1",
+            json!({
+                "buildDefinition": {
+                    "buildType": "https://example.com/buildType/v1",
+                    "externalParameters": {}
+                },
+                "runDetails": {
+                    "builder": {"id": "https://example.com/builder"},
+                    "metadata": {"finishedOn": "2023-01-01T12:00:00Z"}
+                }
+            }),
+        );
+
+        let mut report = ValidationReport::new();
+        freshness_rule("2023-01-02T11:00:00Z").check(&provenance, &mut report);
+        assert!(report.is_empty());
+
+        freshness_rule("2023-01-02T13:00:00Z").check(&provenance, &mut report);
+        assert_eq!(report.findings[0].code, "attestation-stale");
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/runDetails/metadata/finishedOn"
+        );
+        assert_eq!(report.findings.len(), 1);
+    }
+
+    #[test]
+    fn test_freshness_not_after() {
+        // Statements without a finish time are still checked against notAfter.
+        let provenance = provenance_v02(json!({}));
+
+        let mut report = ValidationReport::new();
+        freshness_rule("2023-05-31T23:59:59Z").check(&provenance, &mut report);
+        assert!(report.is_empty());
+
+        freshness_rule("2023-06-01T00:00:01Z").check(&provenance, &mut report);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(findings, vec![("attestation-expired", "")]);
+    }
+
+    #[test]
+    fn test_conflicting_dependency_digest() {
+        let statement = statement(
+            "https://slsa.dev/provenance/v1",
+            json!({
+                "buildDefinition": {
+                    "buildType": "https://example.com/buildType/v1",
+                    "externalParameters": {},
+                    "resolvedDependencies": [
+                        {"uri": "pkg:npm/lodash@4.17.20", "digest": {"sha256": "aa"}},
+                        {"uri": "git+https://github.com/org/repo", "digest": {"gitCommit": "01"}},
+                        {"uri": "pkg:npm/lodash@4.17.20", "digest": {"sha256": "AA", "sha512": "bb"}},
+                        {"uri": "pkg:npm/lodash@4.17.20", "digest": {"sha256": "cc"}}
+                    ]
+                },
+                "runDetails": {
+                    "builder": {"id": "https://example.com/builder"}
+                }
+            }),
+        );
+
+        let mut report = ValidationReport::new();
+        DuplicateDependencyRule.check(&statement, &mut report);
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].code, "conflicting-dependency-digest");
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/buildDefinition/resolvedDependencies/3/digest/sha256"
+        );
+    }
+
+    #[test]
+    fn test_conflicting_material_digest() {
+        let statement = statement(
+            "https://slsa.dev/provenance/v0.2",
+            json!({
+                "builder": {"id": "https://example.com/builder"},
+                "buildType": "https://example.com/buildType/v1",
+                "materials": [
+                    {"uri": "https://example.com/source", "digest": {"sha1": "01"}},
+                    {"uri": "https://example.com/source", "digest": {"sha1": "02"}}
+                ]
+            }),
+        );
+
+        let mut report = ValidationReport::new();
+        DuplicateDependencyRule.check(&statement, &mut report);
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/materials/1/digest/sha1"
+        );
+    }
+
+    fn provenance_v02(metadata: Value) -> InTotoStatementV1 {
+        statement(
+            "https://slsa.dev/provenance/v0.2",
+            json!({
+                "builder": {"id": "https://example.com/builder"},
+                "buildType": "https://example.com/buildType/v1",
+                "metadata": metadata
+            }),
+        )
+    }
+
+    #[test]
+    fn test_completeness() {
+        let rule = CompletenessRule {
+            required: vec![Completeness::Parameters, Completeness::Materials],
+            reproducible: true,
+        };
+
+        let mut report = ValidationReport::new();
+        rule.check(
+            &provenance_v02(json!({
+                "completeness": {"parameters": true, "environment": false, "materials": true},
+                "reproducible": true
+            })),
+            &mut report,
+        );
+        assert!(report.is_empty());
+
+        rule.check(
+            &provenance_v02(json!({"completeness": {"parameters": true, "materials": false}})),
+            &mut report,
+        );
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                (
+                    "completeness-not-claimed",
+                    "/predicate/metadata/completeness/materials"
+                ),
+                (
+                    "reproducible-not-claimed",
+                    "/predicate/metadata/reproducible"
+                ),
+            ]
+        );
+        assert!(report.has_errors());
+        assert!(report.findings[0]
+            .message
+            .contains("https://slsa.dev/spec/v0.1/requirements#dependencies-complete"));
+    }
+
+    #[test]
+    fn test_completeness_missing_metadata() {
+        let rule = CompletenessRule {
+            required: vec![Completeness::Environment],
+            reproducible: false,
+        };
+        let statement = statement(
+            "https://slsa.dev/provenance/v0.2",
+            json!({
+                "builder": {"id": "https://example.com/builder"},
+                "buildType": "https://example.com/buildType/v1"
+            }),
+        );
+
+        let mut report = ValidationReport::new();
+        rule.check(&statement, &mut report);
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/metadata/completeness/environment"
+        );
+        assert_eq!(report.findings.len(), 1);
+    }
+
+    #[test]
+    fn test_invocation_id_format_v02() {
+        let statement = statement(
+            "https://slsa.dev/provenance/v0.2",
+            json!({
+                "builder": {"id": "https://github.com/other/builder"},
+                "buildType": "https://example.com/buildType/v1",
+                "metadata": {"buildInvocationId": "not-a-uuid"}
+            }),
+        );
+
+        let mut report = ValidationReport::new();
+        invocation_id_rule().check(&statement, &mut report);
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/metadata/buildInvocationId"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! Rules for reference predicates.
+
+use super::Rule;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Checks that each reference can be fetched and verified.
+///
+/// - A predicate without references is a `missing-references` error, as it refers to nothing.
+/// - References with neither a `uri` nor a `downloadLocation` are `missing-locator` errors, and
+///   references without a digest `missing-digest` errors, as the referenced document couldn't be
+///   found or couldn't be checked to be the one attested to.
+pub struct ReferenceRule;
+
+impl Rule<InTotoStatementV1> for ReferenceRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Predicate::ReferenceV01(predicate) = &statement.predicate else {
+            return;
+        };
+        if predicate.references.is_empty() {
+            report.push(Finding::error(
+                "missing-references",
+                "/predicate/references",
+                "The predicate has no references, so it refers to nothing",
+            ));
+        }
+        for (i, reference) in predicate.references.iter().enumerate() {
+            let path = format!("/predicate/references/{}", i);
+            if reference.locator().is_none() {
+                report.push(Finding::error(
+                    "missing-locator",
+                    path.clone(),
+                    "The reference has neither a uri nor a downloadLocation to fetch it from",
+                ));
+            }
+            if reference
+                .digest
+                .as_ref()
+                .is_none_or(|digest| digest.is_empty())
+            {
+                report.push(Finding::error(
+                    "missing-digest",
+                    format!("{}/digest", path),
+                    "The reference has no digest to verify the referenced document by",
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn codes(statement: &InTotoStatementV1) -> Vec<(String, String)> {
+        let mut report = ValidationReport::new();
+        ReferenceRule.check(statement, &mut report);
+        report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect()
+    }
+
+    fn statement(references: serde_json::Value) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://in-toto.io/attestation/reference/v0.1",
+            "predicate": {
+                "attester": {"id": "https://example.com/attester"},
+                "references": references
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reference_rule() {
+        let valid = statement(json!([{
+            "downloadLocation": "https://example.com/example.spdx.json",
+            "digest": {"sha256": "abcd1234"},
+            "mediaType": "application/spdx+json"
+        }]));
+        assert!(codes(&valid).is_empty());
+
+        let invalid = statement(json!([
+            {"uri": "https://example.com/example.spdx.json", "digest": {}},
+            {"name": "example.cdx.json", "digest": {"sha256": "abcd1234"}}
+        ]));
+        assert_eq!(
+            codes(&invalid),
+            vec![
+                (
+                    "missing-digest".to_string(),
+                    "/predicate/references/0/digest".to_string()
+                ),
+                (
+                    "missing-locator".to_string(),
+                    "/predicate/references/1".to_string()
+                ),
+            ]
+        );
+
+        let empty = statement(json!([]));
+        assert_eq!(
+            codes(&empty),
+            vec![(
+                "missing-references".to_string(),
+                "/predicate/references".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Rules for predicates of types registered by library users.
+
+use super::Rule;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::registry::PredicateRegistry;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::ValidationReport;
+
+/// Runs the checks registered with [`PredicateRegistry::global`] for the statement's predicate
+/// type, if its predicate is of a registered type.
+pub struct RegisteredPredicateRule;
+
+impl Rule<InTotoStatementV1> for RegisteredPredicateRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        if let Predicate::Registered(predicate) = &statement.predicate {
+            PredicateRegistry::global().check(predicate, report);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::report::Finding;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Deserialize)]
+    struct ReleaseApproval {
+        approver: String,
+    }
+
+    #[test]
+    fn test_registered_predicate() {
+        let predicate_type = "https://example.com/registered-rule-approval/v1";
+        PredicateRegistry::global()
+            .register(predicate_type, |approval: &ReleaseApproval, report| {
+                if approval.approver.is_empty() {
+                    report.push(Finding::error(
+                        "empty-approver",
+                        "/predicate/approver",
+                        "approver is empty",
+                    ));
+                }
+            })
+            .unwrap();
+
+        let statement: InTotoStatementV1 = serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "hello-world", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": predicate_type,
+            "predicate": {"approver": ""}
+        }))
+        .unwrap();
+        assert!(matches!(statement.predicate, Predicate::Registered(_)));
+        let mut report = ValidationReport::new();
+        RegisteredPredicateRule.check(&statement, &mut report);
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].path, "/predicate/approver");
+
+        let invalid = serde_json::from_value::<InTotoStatementV1>(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "hello-world", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": predicate_type,
+            "predicate": {"approved": true}
+        }));
+        assert!(invalid.is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! Rules for SARIF scan result predicates.
+
+use super::Rule;
+use crate::models::intoto::predicate::Predicate;
+use crate::models::intoto::sarif::SARIF_VERSION;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// Checks the runs and results of a SARIF log.
+///
+/// - A version other than 2.1.0 is an `unsupported-sarif-version` error, and a log without runs
+///   a `missing-runs` error, as it attests no scan.
+/// - Runs without results are `missing-results` warnings, as SARIF leaves them out when the tool
+///   failed rather than found nothing.
+/// - Results with a `ruleIndex` past the driver's rules are `unknown-rule` errors, and results
+///   whose `ruleId` isn't one of the driver's rules `unknown-rule` warnings. Results whose message
+///   has neither a text nor an id are `invalid-message` errors.
+pub struct SarifRule;
+
+impl Rule<InTotoStatementV1> for SarifRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let Predicate::SarifV21(log) = &statement.predicate else {
+            return;
+        };
+        if log.version != SARIF_VERSION {
+            report.push(Finding::error(
+                "unsupported-sarif-version",
+                "/predicate/version",
+                format!(
+                    "SARIF version {} is not supported, use {}",
+                    log.version, SARIF_VERSION
+                ),
+            ));
+        }
+        if log.runs.is_empty() {
+            report.push(Finding::error(
+                "missing-runs",
+                "/predicate/runs",
+                "The SARIF log has no runs, so it attests no scan",
+            ));
+        }
+        for (i, run) in log.runs.iter().enumerate() {
+            let path = format!("/predicate/runs/{}", i);
+            let Some(results) = &run.results else {
+                report.push(Finding::warning(
+                    "missing-results",
+                    format!("{}/results", path),
+                    format!(
+                        "The run of {} has no results, so it may not have completed",
+                        run.tool.driver.name
+                    ),
+                ));
+                continue;
+            };
+            let rules = &run.tool.driver.rules;
+            for (j, result) in results.iter().enumerate() {
+                let path = format!("{}/results/{}", path, j);
+                match (
+                    result.rule_index,
+                    result.rule_id.as_deref(),
+                    run.rule(result),
+                ) {
+                    (Some(index), _, None) if index >= 0 => report.push(Finding::error(
+                        "unknown-rule",
+                        format!("{}/ruleIndex", path),
+                        format!(
+                            "ruleIndex {} is past the {} rule(s) of {}",
+                            index,
+                            rules.len(),
+                            run.tool.driver.name
+                        ),
+                    )),
+                    (_, Some(rule_id), None) if !rules.is_empty() => report.push(Finding::warning(
+                        "unknown-rule",
+                        format!("{}/ruleId", path),
+                        format!(
+                            "ruleId {} is not a rule of {}",
+                            rule_id, run.tool.driver.name
+                        ),
+                    )),
+                    _ => {}
+                }
+                if result.message.is_empty() {
+                    report.push(Finding::error(
+                        "invalid-message",
+                        format!("{}/message", path),
+                        "The message of the result has neither a text nor an id",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn codes(statement: &InTotoStatementV1) -> Vec<(String, String)> {
+        let mut report = ValidationReport::new();
+        SarifRule.check(statement, &mut report);
+        report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect()
+    }
+
+    fn statement(predicate: serde_json::Value) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "example", "digest": {"sha256": "abcd1234"}}],
+            "predicateType": "https://docs.oasis-open.org/sarif/sarif/v2.1",
+            "predicate": predicate
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sarif_rule() {
+        let valid = statement(json!({
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {"driver": {"name": "CodeQL", "rules": [{"id": "js/xss"}]}},
+                "results": [{"ruleId": "js/xss", "ruleIndex": 0, "message": {"text": "XSS"}}]
+            }]
+        }));
+        assert!(codes(&valid).is_empty());
+
+        let invalid = statement(json!({
+            "version": "2.0.0",
+            "runs": [
+                {
+                    "tool": {"driver": {"name": "CodeQL", "rules": [{"id": "js/xss"}]}},
+                    "results": [
+                        {"ruleId": "js/xss", "ruleIndex": 1, "message": {"text": "XSS"}},
+                        {"ruleId": "js/sqli", "message": {}}
+                    ]
+                },
+                {"tool": {"driver": {"name": "Semgrep"}}}
+            ]
+        }));
+        assert_eq!(
+            codes(&invalid),
+            vec![
+                (
+                    "unsupported-sarif-version".to_string(),
+                    "/predicate/version".to_string()
+                ),
+                (
+                    "unknown-rule".to_string(),
+                    "/predicate/runs/0/results/0/ruleIndex".to_string()
+                ),
+                (
+                    "unknown-rule".to_string(),
+                    "/predicate/runs/0/results/1/ruleId".to_string()
+                ),
+                (
+                    "invalid-message".to_string(),
+                    "/predicate/runs/0/results/1/message".to_string()
+                ),
+                (
+                    "missing-results".to_string(),
+                    "/predicate/runs/1/results".to_string()
+                ),
+            ]
+        );
+
+        let empty = statement(json!({"version": "2.1.0", "runs": []}));
+        assert_eq!(
+            codes(&empty),
+            vec![("missing-runs".to_string(), "/predicate/runs".to_string())]
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Rules for the subjects of In-Toto statements.
+
+use std::collections::HashSet;
+
+use super::Rule;
+use crate::models::intoto::statement::InTotoStatementV1;
+use crate::validate::report::{Finding, Severity, ValidationReport};
+
+/// The subject name used as a wildcard when no single artifact name applies.
+pub const WILDCARD_SUBJECT_NAME: &str = "_";
+
+/// Checks that a statement's subjects identify the artifacts it attests to.
+///
+/// Empty subject lists and wildcard names make an attestation unverifiable against artifacts.
+/// They are reported as warnings, or as errors in strict mode. Wildcard names aren't reported
+/// at all when the policy allows them.
+pub struct SubjectRule {
+    pub strict: bool,
+    pub allow_wildcard: bool,
+}
+
+impl Rule<InTotoStatementV1> for SubjectRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let severity = if self.strict {
+            Severity::Error
+        } else {
+            Severity::Warning
+        };
+
+        if statement.subject.is_empty() {
+            report.push(Finding::new(
+                "empty-subject",
+                severity,
+                "/subject",
+                "subject MUST contain at least one artifact",
+            ));
+        }
+
+        if self.allow_wildcard {
+            return;
+        }
+        for (i, subject) in statement.subject.iter().enumerate() {
+            if subject.name == WILDCARD_SUBJECT_NAME {
+                report.push(Finding::new(
+                    "wildcard-subject",
+                    severity,
+                    format!("/subject/{}/name", i),
+                    "wildcard subject names are not allowed by the policy",
+                ));
+            }
+        }
+    }
+}
+
+/// Checks that subjects with the same digest have the same name.
+///
+/// The same artifact listed under several names usually means the generator mixed up its
+/// outputs, so a subject sharing a digest with an earlier subject of another name is reported as
+/// a warning. Digests are compared case-insensitively.
+pub struct DuplicateSubjectRule;
+
+impl Rule<InTotoStatementV1> for DuplicateSubjectRule {
+    fn check(&self, statement: &InTotoStatementV1, report: &mut ValidationReport) {
+        let digests = statement
+            .subject
+            .iter()
+            .map(|subject| {
+                subject
+                    .digest
+                    .iter()
+                    .map(|(algorithm, digest)| (*algorithm, digest.trim().to_ascii_lowercase()))
+                    .collect::<HashSet<_>>()
+            })
+            .collect::<Vec<_>>();
+        for (j, subject) in statement.subject.iter().enumerate() {
+            let duplicate = (0..j).find(|&i| {
+                statement.subject[i].name != subject.name && !digests[i].is_disjoint(&digests[j])
+            });
+            if let Some(i) = duplicate {
+                report.push(Finding::warning(
+                    "duplicate-subject-digest",
+                    format!("/subject/{}/digest", j),
+                    format!(
+                        "subject {} has the same digest as subject {} ({})",
+                        subject.name, statement.subject[i].name, i
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn statement(subject: serde_json::Value) -> InTotoStatementV1 {
+        serde_json::from_value(json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": subject,
+            "predicateType": "https://example.com/predicate/v1",
+            "predicate": {}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_empty_subject() {
+        let statement = statement(json!([]));
+
+        let mut report = ValidationReport::new();
+        SubjectRule {
+            strict: true,
+            allow_wildcard: true,
+        }
+        .check(&statement, &mut report);
+        assert!(report.has_errors());
+        assert_eq!(report.findings[0].code, "empty-subject");
+
+        let mut report = ValidationReport::new();
+        SubjectRule {
+            strict: false,
+            allow_wildcard: true,
+        }
+        .check(&statement, &mut report);
+        assert!(!report.has_errors());
+        assert_eq!(report.warnings().count(), 1);
+    }
+
+    #[test]
+    fn test_wildcard_subject() {
+        let statement = statement(json!([
+            {"name": "example", "digest": {"sha256": "abcd1234"}},
+            {"name": "_", "digest": {"sha256": "abcd1234"}}
+        ]));
+
+        let mut report = ValidationReport::new();
+        SubjectRule {
+            strict: true,
+            allow_wildcard: false,
+        }
+        .check(&statement, &mut report);
+        assert_eq!(report.errors().count(), 1);
+        assert_eq!(report.findings[0].code, "wildcard-subject");
+        assert_eq!(report.findings[0].path, "/subject/1/name");
+
+        let mut report = ValidationReport::new();
+        SubjectRule {
+            strict: true,
+            allow_wildcard: true,
+        }
+        .check(&statement, &mut report);
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_subject_digest() {
+        let statement = statement(json!([
+            {"name": "app-linux", "digest": {"sha256": "abcd1234"}},
+            {"name": "app-linux", "digest": {"sha256": "abcd1234"}},
+            {"name": "app-darwin", "digest": {"sha256": "ef567890"}},
+            {"name": "app-windows", "digest": {"sha512": "00", "sha256": "ABCD1234"}}
+        ]));
+
+        let mut report = ValidationReport::new();
+        DuplicateSubjectRule.check(&statement, &mut report);
+        // Listing the same subject twice under the same name isn't a conflict.
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].code, "duplicate-subject-digest");
+        assert_eq!(report.findings[0].path, "/subject/3/digest");
+        assert_eq!(
+            report.findings[0].message,
+            "subject app-windows has the same digest as subject app-linux (0)"
+        );
+    }
+}
@@ -0,0 +1,413 @@
+//! Streaming validation of SPDX documents too large to load into memory.
+//!
+//! The document is parsed straight from a reader. Each entry of `packages`, `files`, `snippets`
+//! and `relationships`, the bulk of an SBOM, is validated against its schema as soon as it is
+//! parsed and then dropped. Of those, only the SPDX IDs of the elements are kept, along with the
+//! relationships' references to IDs not defined yet, which are checked once the whole document
+//! is read. The rest of the document, such as `creationInfo` and `hasExtractedLicensingInfos`, is
+//! kept whole and validated at the end. Memory is so bounded by the largest element, the SPDX
+//! IDs, the forward references and those other fields, rather than the whole document.
+
+use anyhow::{anyhow, Result};
+use jsonschema::JSONSchema;
+use serde::de::{DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{debug, instrument};
+
+use super::report::{Finding, ValidationReport};
+use super::suggest::describe_schema_error;
+use crate::schema::{cache::SchemaCache, registry::SchemaEntry};
+
+/// SPDX IDs that elements may refer to without them being defined in the document.
+const SPECIAL_IDS: [&str; 2] = ["NONE", "NOASSERTION"];
+
+/// The arrays of the document validated one entry at a time.
+const STREAMED: [&str; 4] = ["packages", "files", "snippets", "relationships"];
+
+/// The outcome of validating a document as a stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamValidation {
+    /// The number of packages in the document.
+    pub packages: usize,
+    pub report: ValidationReport,
+}
+
+/// Validates an SPDX document read from `reader` against the schema of `entry`, one package,
+/// file, snippet and relationship at a time. Gzip or zstd compressed documents are decompressed
+/// while reading.
+///
+/// Schema violations are reported as `schema-violation` errors, elements sharing an SPDX ID as
+/// `duplicate-spdx-id` errors, and relationships or `documentDescribes` entries referring to
+/// elements not in the document as `unknown-spdx-id` errors. Malformed JSON fails the whole
+/// validation, as parsing can't continue past it.
+#[instrument(skip_all, fields(schema = %entry.name))]
+pub fn validate_spdx<R: Read>(entry: &SchemaEntry, reader: R) -> Result<StreamValidation> {
+    let start = Instant::now();
+    let schema = entry.schema();
+    let mut schemas = BTreeMap::new();
+    for key in STREAMED {
+        let item_schema = item_schema(&schema, key)
+            .ok_or_else(|| anyhow!("{} has no schema for {}", entry.name, key))?;
+        let compiled = SchemaCache::global().get_or_compile(&item_schema)?;
+        schemas.insert(key, (compiled, item_schema));
+    }
+    let mut checker = ElementChecker {
+        schemas,
+        counts: BTreeMap::new(),
+        ids: HashSet::new(),
+        pending: Vec::new(),
+        report: ValidationReport::new(),
+    };
+
+    let reader =
+        crate::input::decompress(reader).map_err(|e| anyhow!("Failed to read document: {}", e))?;
+    let mut deserializer = serde_json::Deserializer::from_reader(std::io::BufReader::new(reader));
+    let document = DocumentSeed {
+        checker: &mut checker,
+    }
+    .deserialize(&mut deserializer)
+    .map_err(|e| anyhow!("Failed to parse document: {}", e))?;
+    deserializer
+        .end()
+        .map_err(|e| anyhow!("Failed to parse document: {}", e))?;
+
+    let ElementChecker {
+        counts,
+        mut ids,
+        pending,
+        mut report,
+        ..
+    } = checker;
+    // The streamed arrays were replaced with empty ones, so only the rest of the document is
+    // checked.
+    let compiled = SchemaCache::global().get_or_compile(&schema)?;
+    push_schema_errors(&compiled, &schema, &document, "", &mut report);
+    check_references(&document, &mut ids, pending, &mut report);
+
+    let packages = counts.get("packages").copied().unwrap_or_default();
+    debug!(
+        packages,
+        files = counts.get("files").copied().unwrap_or_default(),
+        relationships = counts.get("relationships").copied().unwrap_or_default(),
+        errors = report.errors().count(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Validated SPDX document"
+    );
+    Ok(StreamValidation { packages, report })
+}
+
+/// Returns the schema of a single entry of one of the document's arrays, with the definitions of
+/// the document schema it refers to.
+fn item_schema(schema: &Value, key: &str) -> Option<Value> {
+    let mut item = schema
+        .pointer(&format!("/properties/{}/items", key))?
+        .clone();
+    if let (Some(item), Some(definitions)) = (item.as_object_mut(), schema.get("definitions")) {
+        item.insert("definitions".to_string(), definitions.clone());
+    }
+    Some(item)
+}
+
+/// Reports the schema violations of `value`, with suggestions for misspelled properties.
+fn push_schema_errors(
+    compiled: &JSONSchema,
+    schema: &Value,
+    value: &Value,
+    base: &str,
+    report: &mut ValidationReport,
+) {
+    if let Err(errors) = compiled.validate(value) {
+        for error in errors {
+            report.push(Finding::error(
+                "schema-violation",
+                format!("{}{}", base, error.instance_path),
+                describe_schema_error(schema, &error),
+            ));
+        }
+    }
+}
+
+fn is_known(ids: &HashSet<String>, id: &str) -> bool {
+    ids.contains(id) || SPECIAL_IDS.contains(&id) || id.starts_with("DocumentRef-")
+}
+
+/// Checks that every element referred to by `documentDescribes`, and by the relationships that
+/// referred to elements not parsed yet, exists.
+fn check_references(
+    document: &Value,
+    ids: &mut HashSet<String>,
+    pending: Vec<(String, String)>,
+    report: &mut ValidationReport,
+) {
+    if let Some(id) = document.get("SPDXID").and_then(Value::as_str) {
+        ids.insert(id.to_string());
+    }
+
+    let describes = document.get("documentDescribes").and_then(Value::as_array);
+    let describes = describes
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, id)| Some((format!("/documentDescribes/{}", i), id.as_str()?)));
+    let pending = pending.iter().map(|(path, id)| (path.clone(), id.as_str()));
+    for (path, id) in describes.chain(pending) {
+        if !is_known(ids, id) {
+            report.push(Finding::error(
+                "unknown-spdx-id",
+                path,
+                format!("No element in the document has SPDXID {}", id),
+            ));
+        }
+    }
+}
+
+/// Validates the entries of the streamed arrays as they are parsed, keeping only the SPDX IDs of
+/// the elements and the references to IDs not seen yet.
+struct ElementChecker {
+    schemas: BTreeMap<&'static str, (Arc<JSONSchema>, Value)>,
+    counts: BTreeMap<&'static str, usize>,
+    ids: HashSet<String>,
+    /// The paths of relationship fields referring to IDs not defined when they were parsed.
+    pending: Vec<(String, String)>,
+    report: ValidationReport,
+}
+
+impl ElementChecker {
+    fn check(&mut self, key: &'static str, element: Value) {
+        let count = self.counts.entry(key).or_default();
+        let base = format!("/{}/{}", key, count);
+        *count += 1;
+        let (compiled, schema) = &self.schemas[key];
+        push_schema_errors(compiled, schema, &element, &base, &mut self.report);
+
+        if key == "relationships" {
+            for field in ["spdxElementId", "relatedSpdxElement"] {
+                let Some(id) = element.get(field).and_then(Value::as_str) else {
+                    continue;
+                };
+                if !is_known(&self.ids, id) {
+                    self.pending
+                        .push((format!("{}/{}", base, field), id.to_string()));
+                }
+            }
+        } else if let Some(id) = element.get("SPDXID").and_then(Value::as_str) {
+            if !self.ids.insert(id.to_string()) {
+                self.report.push(Finding::error(
+                    "duplicate-spdx-id",
+                    format!("{}/SPDXID", base),
+                    format!("SPDXID {} is used by more than one element", id),
+                ));
+            }
+        }
+    }
+}
+
+/// Parses the document, handing each entry of the streamed arrays to the checker instead of
+/// keeping it. Returns the rest of the document, with those arrays emptied.
+struct DocumentSeed<'a> {
+    checker: &'a mut ElementChecker,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for DocumentSeed<'a> {
+    type Value = Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for DocumentSeed<'a> {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an SPDX document")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut document = Map::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if let Some(key) = STREAMED.into_iter().find(|streamed| *streamed == key) {
+                map.next_value_seed(ElementsSeed {
+                    key,
+                    checker: &mut *self.checker,
+                })?;
+                document.insert(key.to_string(), json!([]));
+            } else {
+                document.insert(key, map.next_value()?);
+            }
+        }
+        Ok(Value::Object(document))
+    }
+}
+
+/// Parses one of the streamed arrays, handing its entries to the checker.
+struct ElementsSeed<'a> {
+    key: &'static str,
+    checker: &'a mut ElementChecker,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for ElementsSeed<'a> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for ElementsSeed<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of {}", self.key)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(element) = seq.next_element::<Value>()? {
+            self.checker.check(self.key, element);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::registry::SchemaRegistry;
+
+    fn sbom() -> Value {
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/spdx_v23_sbom.json"
+        ))
+        .unwrap();
+        serde_json::from_str(&fixture).unwrap()
+    }
+
+    fn validate(document: &Value) -> StreamValidation {
+        let entry = SchemaRegistry::get("spdx-v23").unwrap();
+        validate_spdx(entry, document.to_string().as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_validate_spdx() {
+        let mut document = sbom();
+        document["relationships"] = json!([{
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": "SPDXRef-Package-lodash"
+        }]);
+
+        let validation = validate(&document);
+        assert_eq!(validation.packages, 2);
+        assert!(validation.report.is_empty(), "{:?}", validation.report);
+    }
+
+    #[test]
+    fn test_validate_spdx_findings() {
+        let mut document = sbom();
+        document["packages"][1]["SPDXID"] = json!("SPDXRef-Package-lodash");
+        document["packages"][1]["name"] = json!(1);
+        document["relationships"] = json!([{
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": "SPDXRef-Package-missing"
+        }]);
+        document.as_object_mut().unwrap().remove("dataLicense");
+
+        let findings = validate(&document)
+            .report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                (
+                    "schema-violation".to_string(),
+                    "/packages/1/name".to_string()
+                ),
+                (
+                    "duplicate-spdx-id".to_string(),
+                    "/packages/1/SPDXID".to_string()
+                ),
+                ("schema-violation".to_string(), "".to_string()),
+                (
+                    "unknown-spdx-id".to_string(),
+                    "/relationships/0/relatedSpdxElement".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_spdx_files_snippets_relationships() {
+        let mut document = sbom();
+        document["files"] = json!([
+            {"SPDXID": "SPDXRef-File-index", "fileName": "./index.js", "checksums": []},
+            {"SPDXID": "SPDXRef-Package-lodash", "fileName": 1, "checksums": []}
+        ]);
+        // The relationships come first, so they refer to elements not parsed yet.
+        let relationships = json!([
+            {
+                "spdxElementId": "SPDXRef-Package-lodash",
+                "relationshipType": "CONTAINS",
+                "relatedSpdxElement": "SPDXRef-File-index"
+            },
+            {
+                "spdxElementId": "SPDXRef-File-missing",
+                "relationshipType": "CONTAINS"
+            }
+        ]);
+        let document = format!(
+            "{{\"relationships\": {}, {}",
+            relationships,
+            &document.to_string()[1..]
+        );
+        let entry = SchemaRegistry::get("spdx-v23").unwrap();
+        let validation = validate_spdx(entry, document.as_bytes()).unwrap();
+
+        assert_eq!(validation.packages, 2);
+        let findings = validation
+            .report
+            .findings
+            .into_iter()
+            .map(|f| (f.code, f.path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                (
+                    "schema-violation".to_string(),
+                    "/relationships/1".to_string()
+                ),
+                (
+                    "schema-violation".to_string(),
+                    "/files/1/fileName".to_string()
+                ),
+                (
+                    "duplicate-spdx-id".to_string(),
+                    "/packages/0/SPDXID".to_string()
+                ),
+                (
+                    "unknown-spdx-id".to_string(),
+                    "/relationships/1/spdxElementId".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_spdx_malformed() {
+        let entry = SchemaRegistry::get("spdx-v23").unwrap();
+        assert!(validate_spdx(entry, &b"{\"packages\": [{}"[..]).is_err());
+        assert!(validate_spdx(entry, &b"{\"packages\": {}}"[..]).is_err());
+        assert!(validate_spdx(entry, &b"{} {}"[..]).is_err());
+    }
+}
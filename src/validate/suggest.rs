@@ -0,0 +1,379 @@
+//! "Did you mean" suggestions for misspelled property names.
+//!
+//! Hand-authored documents often get a property name slightly wrong, like `predicatetype` or
+//! `buildtype`, which otherwise only shows up as a missing required property or an unknown one.
+//! A name is suggested when it differs from the misspelled one only in case, or by at most one
+//! edit for every four characters.
+
+use serde_json::Value;
+
+use super::report::join_pointer;
+
+/// How many `$ref`s are followed in a row before a schema is considered to refer to itself.
+const MAX_REFERENCES: usize = 32;
+
+/// Returns the candidate closest to `name`, if any is close enough to be a likely misspelling.
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 4).max(1);
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the hint for a misspelled property, e.g. "did you mean `buildType` instead of
+/// `buildtype`?".
+pub fn hint(found: &str, suggestion: &str) -> String {
+    format!("did you mean `{}` instead of `{}`?", suggestion, found)
+}
+
+/// Returns the Levenshtein distance between the names, where a difference only in case costs
+/// nothing, so it always wins over a real edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.to_lowercase().chars().collect::<Vec<_>>();
+    let b = b.to_lowercase().chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// A property of a document that its schema doesn't define.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownProperty {
+    /// The JSON pointer of the property.
+    pub path: String,
+    pub name: String,
+    /// The defined property it is most likely a misspelling of, if any.
+    pub suggestion: Option<String>,
+}
+
+impl UnknownProperty {
+    /// Returns a message for the property, with the suggestion if there is one.
+    pub fn message(&self) -> String {
+        match &self.suggestion {
+            Some(suggestion) => format!(
+                "unknown property `{}`, {}",
+                self.name,
+                hint(&self.name, suggestion)
+            ),
+            None => format!("unknown property `{}`", self.name),
+        }
+    }
+}
+
+/// Returns the properties of the document that aren't defined by the object schemas they appear
+/// in. Objects whose schema lists no properties, like maps and free-form values, and objects
+/// whose schema allows additional properties explicitly, aren't checked.
+///
+/// Local references (`#/definitions/...`) are resolved against the schema itself, and the
+/// properties of `allOf`, `anyOf` and `oneOf` variants are all considered defined.
+pub fn unknown_properties(document: &Value, schema: &Value) -> Vec<UnknownProperty> {
+    let mut unknown = Vec::new();
+    walk(document, schema, schema, "", &mut unknown);
+    unknown
+}
+
+fn walk(
+    value: &Value,
+    schema: &Value,
+    root: &Value,
+    path: &str,
+    unknown: &mut Vec<UnknownProperty>,
+) {
+    let mut schemas = Vec::new();
+    collect_variants(schema, root, 0, &mut schemas);
+
+    match value {
+        Value::Object(map) => {
+            let properties = schemas
+                .iter()
+                .filter_map(|schema| schema.get("properties").and_then(Value::as_object))
+                .collect::<Vec<_>>();
+            let additional = schemas
+                .iter()
+                .find_map(|schema| schema.get("additionalProperties").filter(|a| a.is_object()));
+            let open = properties.is_empty()
+                || schemas.iter().any(|schema| {
+                    schema.get("additionalProperties") == Some(&Value::Bool(true))
+                        || schema.get("patternProperties").is_some()
+                });
+
+            for (key, child) in map {
+                let child_path = join_pointer(path, key);
+                match properties.iter().find_map(|properties| properties.get(key)) {
+                    Some(property) => walk(child, property, root, &child_path, unknown),
+                    None => match additional {
+                        Some(additional) => walk(child, additional, root, &child_path, unknown),
+                        None if open => {}
+                        None => unknown.push(UnknownProperty {
+                            path: child_path,
+                            name: key.clone(),
+                            suggestion: did_you_mean(
+                                key,
+                                properties
+                                    .iter()
+                                    .flat_map(|properties| properties.keys())
+                                    .map(String::as_str),
+                            )
+                            .map(str::to_string),
+                        }),
+                    },
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                let item_schema = schemas.iter().find_map(|schema| match schema.get("items") {
+                    Some(Value::Array(items)) => items.get(i),
+                    Some(items) => Some(items),
+                    None => None,
+                });
+                if let Some(item_schema) = item_schema {
+                    walk(
+                        item,
+                        item_schema,
+                        root,
+                        &join_pointer(path, &i.to_string()),
+                        unknown,
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collects the schema and its `allOf`, `anyOf` and `oneOf` variants, with references resolved.
+fn collect_variants<'a>(
+    schema: &'a Value,
+    root: &'a Value,
+    depth: usize,
+    schemas: &mut Vec<&'a Value>,
+) {
+    let Some(schema) = resolve(schema, root) else {
+        return;
+    };
+    if depth > MAX_REFERENCES {
+        return;
+    }
+    schemas.push(schema);
+    for keyword in ["allOf", "anyOf", "oneOf"] {
+        for variant in schema
+            .get(keyword)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            collect_variants(variant, root, depth + 1, schemas);
+        }
+    }
+}
+
+/// Follows local references until reaching a schema that isn't one.
+fn resolve<'a>(mut schema: &'a Value, root: &'a Value) -> Option<&'a Value> {
+    for _ in 0..MAX_REFERENCES {
+        match schema.get("$ref").and_then(Value::as_str) {
+            Some(reference) => schema = root.pointer(reference.strip_prefix('#')?)?,
+            None => return Some(schema),
+        }
+    }
+    None
+}
+
+/// Returns the error message of a schema violation, followed by the hints from
+/// [`schema_error_hints`] if there are any.
+#[cfg(feature = "validation")]
+pub fn describe_schema_error(schema: &Value, error: &jsonschema::ValidationError) -> String {
+    let hints = schema_error_hints(schema, error);
+    if hints.is_empty() {
+        error.to_string()
+    } else {
+        format!("{} ({})", error, hints.join(", "))
+    }
+}
+
+/// Returns suggestions for a schema violation about a property that looks misspelled: an
+/// additional property close to one the schema defines, or a missing required property close to
+/// one the document has.
+#[cfg(feature = "validation")]
+pub fn schema_error_hints(schema: &Value, error: &jsonschema::ValidationError) -> Vec<String> {
+    use jsonschema::error::ValidationErrorKind;
+
+    match &error.kind {
+        ValidationErrorKind::AdditionalProperties { unexpected } => {
+            let schemas = schemas_at(schema, &error.instance_path.to_string());
+            let defined = schemas
+                .iter()
+                .filter_map(|schema| schema.get("properties").and_then(Value::as_object))
+                .flat_map(|properties| properties.keys())
+                .map(String::as_str)
+                .collect::<Vec<_>>();
+            unexpected
+                .iter()
+                .filter_map(|name| {
+                    did_you_mean(name, defined.iter().copied())
+                        .map(|suggestion| hint(name, suggestion))
+                })
+                .collect()
+        }
+        ValidationErrorKind::Required {
+            property: Value::String(property),
+        } => {
+            let found = error.instance.as_object().and_then(|object| {
+                object
+                    .keys()
+                    .map(String::as_str)
+                    .filter(|key| did_you_mean(key, [property.as_str()]).is_some())
+                    .min_by_key(|key| edit_distance(key, property))
+            });
+            found
+                .map(|found| hint(found, property))
+                .into_iter()
+                .collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the schemas that apply to the value at a JSON pointer of a document, with the variants
+/// of `allOf`, `anyOf` and `oneOf` and with references resolved.
+///
+/// The schema paths of validation errors restart at every reference they go through, so this is
+/// how the schema behind an error is found.
+#[cfg(feature = "validation")]
+pub(crate) fn schemas_at<'a>(root: &'a Value, pointer: &str) -> Vec<&'a Value> {
+    let mut schemas = Vec::new();
+    collect_variants(root, root, 0, &mut schemas);
+    for segment in pointer.split('/').skip(1) {
+        let segment = segment.replace("~1", "/").replace("~0", "~");
+        let mut children = Vec::new();
+        for schema in schemas {
+            let child = schema
+                .get("properties")
+                .and_then(|properties| properties.get(&segment))
+                .or_else(|| match schema.get("items") {
+                    Some(Value::Array(items)) => {
+                        segment.parse::<usize>().ok().and_then(|i| items.get(i))
+                    }
+                    Some(items) if segment.parse::<usize>().is_ok() => Some(items),
+                    _ => None,
+                })
+                .or_else(|| schema.get("additionalProperties").filter(|a| a.is_object()));
+            if let Some(child) = child {
+                collect_variants(child, root, 0, &mut children);
+            }
+        }
+        schemas = children;
+    }
+    schemas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_did_you_mean() {
+        let fields = ["_type", "subject", "predicateType", "predicate"];
+        assert_eq!(did_you_mean("predicatetype", fields), Some("predicateType"));
+        assert_eq!(did_you_mean("predicateTyp", fields), Some("predicateType"));
+        assert_eq!(did_you_mean("subjects", fields), Some("subject"));
+        assert_eq!(did_you_mean("type", fields), Some("_type"));
+        assert_eq!(did_you_mean("predicateKind", fields), None);
+        assert_eq!(did_you_mean("materials", fields), None);
+        assert_eq!(did_you_mean("predicate", fields), None);
+    }
+
+    #[test]
+    fn test_unknown_properties() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "predicateType": {"type": "string"},
+                "predicate": {"$ref": "#/definitions/Predicate"},
+                "digest": {"type": "object", "additionalProperties": {"type": "string"}},
+                "parameters": {}
+            },
+            "definitions": {
+                "Predicate": {
+                    "type": "object",
+                    "properties": {"buildType": {"type": "string"}, "builder": {"type": "object"}}
+                }
+            }
+        });
+        let document = json!({
+            "predicate": {"buildtype": "https://example.com", "builder": {}, "extra": 1},
+            "digest": {"sha256": "abc"},
+            "parameters": {"anything": true},
+            "predicatetype": "https://slsa.dev/provenance/v1"
+        });
+
+        let messages = unknown_properties(&document, &schema)
+            .into_iter()
+            .map(|unknown| (unknown.path.clone(), unknown.message()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "/predicate/buildtype".to_string(),
+                    "unknown property `buildtype`, did you mean `buildType` instead of `buildtype`?".to_string()
+                ),
+                ("/predicate/extra".to_string(), "unknown property `extra`".to_string()),
+                (
+                    "/predicatetype".to_string(),
+                    "unknown property `predicatetype`, did you mean `predicateType` instead of \
+                     `predicatetype`?"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_describe_schema_error() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"predicate": {"$ref": "#/definitions/Predicate"}},
+            "definitions": {
+                "Predicate": {
+                    "type": "object",
+                    "properties": {"buildType": {"type": "string"}},
+                    "required": ["buildType"],
+                    "additionalProperties": false
+                }
+            }
+        });
+        let document = json!({"predicate": {"buildtype": "https://example.com"}});
+        let compiled = jsonschema::JSONSchema::compile(&schema).unwrap();
+        let mut messages = compiled
+            .validate(&document)
+            .unwrap_err()
+            .map(|error| describe_schema_error(&schema, &error))
+            .collect::<Vec<_>>();
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec![
+                "\"buildType\" is a required property (did you mean `buildType` instead of `buildtype`?)",
+                "Additional properties are not allowed ('buildtype' was unexpected) (did you mean \
+                 `buildType` instead of `buildtype`?)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Linking an SBOM attestation to the provenance of the same build.
+//!
+//! An SBOM only says what went into an artifact if it describes the artifact the provenance says
+//! was built, and lists what the build actually used. The SBOM attestation must therefore share
+//! a subject with the provenance, and each SBOM package with a checksum should be one of the
+//! provenance dependencies, matched by digest.
+//!
+//! Findings point into the SBOM attestation with `/0` as the first segment of the path, and into
+//! the provenance with `/1`, as for [consistency checks](crate::validate::consistency).
+
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+use crate::validate::consistency::{is_provenance, subject_digests};
+use crate::validate::report::{Finding, ValidationReport};
+
+/// How an SBOM attestation links to a provenance attestation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkageReport {
+    /// The `algorithm:digest`s of the subjects both attestations have.
+    pub shared_subjects: Vec<String>,
+    /// The SBOM packages found among the provenance dependencies.
+    pub linked: Vec<LinkedPackage>,
+    /// The SPDX IDs of the SBOM packages with checksums that aren't among the dependencies.
+    pub unlinked: Vec<String>,
+    /// The number of SBOM packages without checksums, which can't be linked.
+    pub without_checksums: usize,
+    pub report: ValidationReport,
+}
+
+/// An SBOM package and the provenance dependency with the same digest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedPackage {
+    pub spdx_id: String,
+    /// The pointer to the package in the SBOM attestation.
+    pub package: String,
+    /// The pointer to the dependency in the provenance.
+    pub dependency: String,
+}
+
+/// Checks that an SPDX SBOM attestation is linked to a SLSA provenance attestation.
+///
+/// An SBOM sharing no subject digest with the provenance gets an `sbom-subject-mismatch` error,
+/// and each package with checksums matching none of the digests of the v1
+/// `resolvedDependencies` or v0.2 `materials` a `package-not-in-dependencies` error. Packages
+/// with the digest of a subject are the built artifacts themselves, so aren't expected among
+/// the dependencies. Statements of the wrong kind get an `unsupported-predicate-type` error.
+pub fn check_linkage(sbom: &Value, provenance: &Value) -> LinkageReport {
+    let mut linkage = LinkageReport::default();
+    if sbom.pointer("/predicate/spdxVersion").is_none() {
+        linkage.report.push(Finding::error(
+            "unsupported-predicate-type",
+            "/0/predicateType",
+            "Expected an SPDX SBOM attestation",
+        ));
+    }
+    if !is_provenance(provenance) {
+        linkage.report.push(Finding::error(
+            "unsupported-predicate-type",
+            "/1/predicateType",
+            "Expected a SLSA provenance attestation",
+        ));
+    }
+    if linkage.report.has_errors() {
+        return linkage;
+    }
+
+    let subjects = subject_digests(provenance);
+    let mut shared = subject_digests(sbom)
+        .intersection(&subjects)
+        .cloned()
+        .collect::<Vec<_>>();
+    shared.sort();
+    if shared.is_empty() {
+        linkage.report.push(Finding::error(
+            "sbom-subject-mismatch",
+            "/0/subject",
+            "The SBOM has no subject digest in common with the provenance, so it isn't about the built artifact",
+        ));
+    }
+    linkage.shared_subjects = shared;
+
+    let dependencies = dependency_digests(provenance);
+    let packages = sbom
+        .pointer("/predicate/packages")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for (i, package) in packages.iter().enumerate() {
+        let path = format!("/0/predicate/packages/{}", i);
+        let spdx_id = package
+            .get("SPDXID")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let checksums = package_digests(package);
+        if checksums.is_empty() {
+            linkage.without_checksums += 1;
+            continue;
+        }
+        if checksums.iter().any(|digest| subjects.contains(digest)) {
+            continue;
+        }
+        match checksums.iter().find_map(|digest| dependencies.get(digest)) {
+            Some(dependency) => linkage.linked.push(LinkedPackage {
+                spdx_id,
+                package: path,
+                dependency: format!("/1{}", dependency),
+            }),
+            None => {
+                linkage.report.push(Finding::error(
+                    "package-not-in-dependencies",
+                    path,
+                    format!(
+                        "Package {} has no checksum matching a dependency of the provenance",
+                        spdx_id
+                    ),
+                ));
+                linkage.unlinked.push(spdx_id);
+            }
+        }
+    }
+    linkage
+}
+
+/// Returns the `algorithm:digest` pairs of the provenance dependencies, with the pointer to the
+/// dependency of each.
+fn dependency_digests(provenance: &Value) -> HashMap<String, String> {
+    let mut digests = HashMap::new();
+    for pointer in [
+        "/predicate/buildDefinition/resolvedDependencies",
+        "/predicate/materials",
+    ] {
+        let dependencies = provenance
+            .pointer(pointer)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for (i, dependency) in dependencies.iter().enumerate() {
+            let digest = dependency.get("digest").and_then(Value::as_object);
+            for (algorithm, digest) in digest.into_iter().flatten() {
+                if let Some(digest) = digest.as_str() {
+                    digests
+                        .entry(digest_key(algorithm, digest))
+                        .or_insert_with(|| format!("{}/{}", pointer, i));
+                }
+            }
+        }
+    }
+    digests
+}
+
+/// Returns the `algorithm:digest` pairs of an SPDX package's checksums, with the SPDX algorithm
+/// names mapped to the In-Toto ones, e.g. `SHA3-256` to `sha3_256`.
+fn package_digests(package: &Value) -> HashSet<String> {
+    let checksums = package
+        .get("checksums")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    checksums
+        .iter()
+        .filter_map(|checksum| {
+            let algorithm = checksum.get("algorithm")?.as_str()?.replace('-', "_");
+            let value = checksum.get("checksumValue")?.as_str()?;
+            Some(digest_key(&algorithm, value))
+        })
+        .collect()
+}
+
+fn digest_key(algorithm: &str, digest: &str) -> String {
+    format!(
+        "{}:{}",
+        algorithm.to_ascii_lowercase(),
+        digest.trim().to_ascii_lowercase()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const ARTIFACT: &str = "fe4fe40ac7250263c5dbe1cf3138912f3f416140aa248637a60d65fe22c47da4";
+    const LODASH: &str = "c27d339ee6075c1f744c5d4b200f7901aad2c369";
+
+    fn provenance() -> Value {
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        let mut provenance: Value = serde_json::from_str(&fixture).unwrap();
+        provenance["predicate"]["buildDefinition"]["resolvedDependencies"][1]["digest"] =
+            json!({"sha1": LODASH.to_uppercase()});
+        provenance
+    }
+
+    fn sbom(packages: Value) -> Value {
+        json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [{"name": "app", "digest": {"sha256": ARTIFACT}}],
+            "predicateType": "https://spdx.dev/Document",
+            "predicate": {"spdxVersion": "SPDX-2.3", "packages": packages}
+        })
+    }
+
+    #[test]
+    fn test_check_linkage() {
+        let sbom = sbom(json!([
+            {"SPDXID": "SPDXRef-app", "checksums": [{"algorithm": "SHA256", "checksumValue": ARTIFACT}]},
+            {"SPDXID": "SPDXRef-lodash", "checksums": [
+                {"algorithm": "MD5", "checksumValue": "0123"},
+                {"algorithm": "SHA1", "checksumValue": LODASH}
+            ]},
+            {"SPDXID": "SPDXRef-unknown"}
+        ]));
+        let linkage = check_linkage(&sbom, &provenance());
+
+        assert!(linkage.report.findings.is_empty(), "{:?}", linkage.report);
+        assert_eq!(
+            linkage.shared_subjects,
+            vec![format!("sha256:{}", ARTIFACT)]
+        );
+        assert_eq!(
+            linkage.linked,
+            vec![LinkedPackage {
+                spdx_id: "SPDXRef-lodash".to_string(),
+                package: "/0/predicate/packages/1".to_string(),
+                dependency: "/1/predicate/buildDefinition/resolvedDependencies/1".to_string(),
+            }]
+        );
+        assert_eq!(linkage.without_checksums, 1);
+    }
+
+    #[test]
+    fn test_check_linkage_mismatch() {
+        let mut sbom = sbom(json!([
+            {"SPDXID": "SPDXRef-extra", "checksums": [{"algorithm": "SHA256", "checksumValue": "ab"}]}
+        ]));
+        sbom["subject"][0]["digest"]["sha256"] = json!("0".repeat(64));
+        let linkage = check_linkage(&sbom, &provenance());
+
+        let codes = linkage
+            .report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                ("sbom-subject-mismatch", "/0/subject"),
+                ("package-not-in-dependencies", "/0/predicate/packages/0")
+            ]
+        );
+        assert_eq!(linkage.unlinked, vec!["SPDXRef-extra"]);
+
+        let linkage = check_linkage(&provenance(), &sbom);
+        assert_eq!(linkage.report.findings.len(), 2);
+        assert!(linkage.linked.is_empty());
+    }
+}
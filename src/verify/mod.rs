@@ -0,0 +1,577 @@
+//! Verification of attestations against what their consumer expects of them.
+//!
+//! Validation shows a document is well formed. Verification goes further and checks that it says
+//! what the consumer expects, such as an artifact having been built from a given repository by
+//! a trusted builder.
+//!
+//! Signatures aren't verified here, as in [`attestations`](crate::attestations). Verify them
+//! first, e.g. with cosign, before relying on the checks in this module.
+
+pub mod linkage;
+pub mod offline;
+pub mod policy;
+pub mod provenance;
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose, Engine};
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::attestations::decode_dsse_statement;
+use crate::models::dsse::Envelope;
+use crate::models::rekor::{EntryBody, RekorEntry};
+use crate::trust::tuf::{decode_hex, encode_hex};
+
+/// A statement and what its envelope says about who signed it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedStatement {
+    pub statement: Value,
+    /// The `keyid`s of the envelope's signatures, and the key hint of a Sigstore bundle, without
+    /// empty ones.
+    pub key_ids: Vec<String>,
+    /// The number of signatures of the envelope, zero for a bare statement.
+    pub signatures: usize,
+    /// The transparency log entries of a Sigstore bundle.
+    pub log_entries: Vec<LogEntry>,
+    /// The base64 DER signing certificate of a Sigstore bundle and the rest of its chain, leaf
+    /// first.
+    pub certificates: Vec<String>,
+    /// The envelope the statement was unwrapped from, with the signatures to verify.
+    pub envelope: Option<Envelope>,
+}
+
+/// A transparency log entry recorded in a Sigstore bundle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogEntry {
+    /// The id of the log, as written in the bundle.
+    pub log_id: String,
+    /// The index of the entry across all the shards of the log.
+    pub log_index: Option<i64>,
+    /// When the entry was integrated into the log, in seconds since the epoch.
+    pub integrated_time: Option<i64>,
+    /// The base64 body of the entry, the leaf of the log's Merkle tree.
+    pub canonicalized_body: Option<String>,
+    /// The proof the entry is included in the log.
+    pub inclusion_proof: Option<InclusionProof>,
+    /// The log's base64 signed promise to include the entry.
+    pub signed_entry_timestamp: Option<String>,
+}
+
+/// A proof a transparency log entry is included in the log's Merkle tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InclusionProof {
+    /// The index of the entry in the tree.
+    pub log_index: u64,
+    /// The base64 root hash of the tree.
+    pub root_hash: String,
+    pub tree_size: u64,
+    /// The base64 hashes of the proof, from the leaf up.
+    pub hashes: Vec<String>,
+    /// The log's signed note committing to the root hash.
+    pub checkpoint: Option<String>,
+}
+
+impl From<&RekorEntry> for LogEntry {
+    fn from(entry: &RekorEntry) -> Self {
+        // Rekor gives the hashes of its proofs in hex, bundles in base64.
+        let base64 = |hex: &String| {
+            decode_hex(hex)
+                .map(|bytes| general_purpose::STANDARD.encode(bytes))
+                .unwrap_or_else(|| hex.clone())
+        };
+        Self {
+            log_id: entry.log_id.clone(),
+            log_index: Some(entry.log_index),
+            integrated_time: Some(entry.integrated_time),
+            canonicalized_body: Some(general_purpose::STANDARD.encode(&entry.body)),
+            inclusion_proof: entry.inclusion_proof().map(|proof| InclusionProof {
+                log_index: proof.log_index,
+                root_hash: base64(&proof.root_hash),
+                tree_size: proof.tree_size,
+                hashes: proof.hashes.iter().map(base64).collect(),
+                checkpoint: proof.checkpoint.clone(),
+            }),
+            signed_entry_timestamp: entry.signed_entry_timestamp().map(str::to_string),
+        }
+    }
+}
+
+/// Reads the statements in a document, which is either a single JSON document or JSON Lines of
+/// them, as in `.intoto.jsonl` files. Each document is a statement, a DSSE envelope, a Sigstore
+/// bundle or a Rekor log entry with its attestation.
+pub fn read_statements(text: &str) -> Result<Vec<Value>> {
+    Ok(read_signed_statements(text)?
+        .into_iter()
+        .map(|signed| signed.statement)
+        .collect())
+}
+
+/// Reads the statements in a document as [`read_statements`] does, keeping the key ids and
+/// number of signatures of their envelopes. The signatures aren't verified.
+pub fn read_signed_statements(text: &str) -> Result<Vec<SignedStatement>> {
+    read_documents(text)?.iter().map(signed_statement).collect()
+}
+
+/// Reads the documents in a single JSON document or JSON Lines of them, without unwrapping them.
+pub fn read_documents(text: &str) -> Result<Vec<Value>> {
+    match serde_json::from_str::<Value>(text) {
+        Ok(document) => Ok(vec![document]),
+        Err(_) => text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .map_err(|e| anyhow!("Invalid JSON on line {}: {}", i + 1, e))
+            })
+            .collect(),
+    }
+}
+
+/// Unwraps the statement in a DSSE envelope, Sigstore bundle or Rekor log entry, or returns a
+/// bare statement, with what the envelope says about who signed it.
+pub fn signed_statement(document: &Value) -> Result<SignedStatement> {
+    if let Some((uuid, entry)) = rekor_entry(document) {
+        return rekor_signed_statement(uuid, entry);
+    }
+    let envelope = match document.get("dsseEnvelope") {
+        Some(envelope) => envelope,
+        None if document.get("payloadType").is_some() => document,
+        None => {
+            return Ok(SignedStatement {
+                statement: document.clone(),
+                key_ids: Vec::new(),
+                signatures: 0,
+                log_entries: Vec::new(),
+                certificates: Vec::new(),
+                envelope: None,
+            })
+        }
+    };
+    let statement = decode_dsse_statement(envelope)?;
+    let parsed =
+        Envelope::deserialize(envelope).map_err(|e| anyhow!("Invalid DSSE envelope: {}", e))?;
+    let signatures = envelope
+        .get("signatures")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let key_ids = signatures
+        .iter()
+        .filter_map(|signature| signature.get("keyid"))
+        .chain(document.pointer("/verificationMaterial/publicKey/hint"))
+        .filter_map(Value::as_str)
+        .filter(|key_id| !key_id.is_empty())
+        .map(str::to_string)
+        .collect();
+    let log_entries = document
+        .pointer("/verificationMaterial/tlogEntries")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|entry| {
+            Some(LogEntry {
+                log_id: entry.pointer("/logId/keyId")?.as_str()?.to_string(),
+                log_index: entry.get("logIndex").and_then(integer),
+                integrated_time: entry.get("integratedTime").and_then(integer),
+                canonicalized_body: entry
+                    .get("canonicalizedBody")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                inclusion_proof: entry.get("inclusionProof").and_then(inclusion_proof),
+                signed_entry_timestamp: entry
+                    .pointer("/inclusionPromise/signedEntryTimestamp")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+            })
+        })
+        .collect::<Vec<_>>();
+    for (j, entry) in log_entries.iter().enumerate() {
+        check_bundle_entry(j, entry, &parsed.payload)?;
+    }
+    // Bundles before v0.3 carry the whole chain, later ones just the leaf.
+    let certificates = document
+        .pointer("/verificationMaterial/x509CertificateChain/certificates")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .chain(document.pointer("/verificationMaterial/certificate"))
+        .filter_map(|certificate| certificate.get("rawBytes")?.as_str())
+        .map(str::to_string)
+        .collect();
+    Ok(SignedStatement {
+        statement,
+        key_ids,
+        signatures: signatures.len(),
+        log_entries,
+        certificates,
+        envelope: Some(parsed),
+    })
+}
+
+/// Checks the body of a transparency log entry of a Sigstore bundle logged the bundle's envelope,
+/// by the digest of its payload. Entries without a body are left to the inclusion checks, which
+/// fail them.
+fn check_bundle_entry(j: usize, entry: &LogEntry, payload: &[u8]) -> Result<()> {
+    let Some(body) = &entry.canonicalized_body else {
+        return Ok(());
+    };
+    let body: EntryBody = general_purpose::STANDARD
+        .decode(body)
+        .ok()
+        .and_then(|body| serde_json::from_slice(&body).ok())
+        .ok_or_else(|| anyhow!("Unsupported body of transparency log entry {}", j))?;
+    // The bundle has the envelope as JSON rather than as it was submitted, so only the payload's
+    // digest can be compared.
+    match logs(&body, payload, None) {
+        Some(true) => Ok(()),
+        Some(false) => bail!(
+            "The envelope is not the one the {} body of transparency log entry {} logged",
+            body.kind(),
+            j
+        ),
+        None => bail!(
+            "The {} body of transparency log entry {} has no sha256 digest of the payload",
+            body.kind(),
+            j
+        ),
+    }
+}
+
+/// Returns the entry of a Rekor response for a single entry, with its UUID.
+fn rekor_entry(document: &Value) -> Option<(&str, &Value)> {
+    let entries = document.as_object().filter(|entries| entries.len() == 1)?;
+    let (uuid, entry) = entries.iter().next()?;
+    (entry.get("logID").is_some() && entry.get("body").is_some()).then_some((uuid, entry))
+}
+
+/// Reads the attestation Rekor kept for an entry, with the entry as its log entry, failing if
+/// the attestation is not the one the entry logged.
+fn rekor_signed_statement(uuid: &str, entry: &Value) -> Result<SignedStatement> {
+    let entry = RekorEntry::deserialize(entry)
+        .map_err(|e| anyhow!("Invalid Rekor entry {}: {}", uuid, e))?;
+    let body = entry
+        .decode_body()
+        .map_err(|e| anyhow!("Unsupported body of Rekor entry {}: {}", uuid, e))?;
+    let data = entry
+        .attestation
+        .as_ref()
+        .and_then(|attestation| attestation.data.as_deref())
+        .ok_or_else(|| anyhow!("Rekor entry {} has no attestation", uuid))?;
+    let attestation: Value = serde_json::from_slice(data)
+        .map_err(|e| anyhow!("Attestation of Rekor entry {} is not JSON: {}", uuid, e))?;
+    let (statement, envelope) = if attestation.get("payloadType").is_some() {
+        let envelope = Envelope::deserialize(&attestation)
+            .map_err(|e| anyhow!("Invalid DSSE envelope: {}", e))?;
+        (decode_dsse_statement(&attestation)?, Some(envelope))
+    } else {
+        (attestation, None)
+    };
+    let logged = match &envelope {
+        Some(envelope) => logs(&body, &envelope.payload, Some(data)),
+        None => logs(&body, data, None),
+    };
+    match logged {
+        Some(true) => {}
+        Some(false) => bail!(
+            "The attestation of Rekor entry {} is not the one its {} body logged",
+            uuid,
+            body.kind()
+        ),
+        None => bail!(
+            "The {} body of Rekor entry {} has no sha256 digest of its attestation",
+            body.kind(),
+            uuid
+        ),
+    }
+    let key_ids = envelope
+        .iter()
+        .flat_map(|envelope| &envelope.signatures)
+        .filter_map(|signature| signature.keyid.clone())
+        .filter(|key_id| !key_id.is_empty())
+        .collect();
+    Ok(SignedStatement {
+        statement,
+        key_ids,
+        signatures: body.signatures(),
+        log_entries: vec![LogEntry::from(&entry)],
+        certificates: Vec::new(),
+        envelope,
+    })
+}
+
+/// Returns whether a log entry's body logged an attestation, by the sha256 digests of its payload
+/// and, for a `dsse` entry, of its envelope as it was submitted. Returns `None` if the body has no
+/// sha256 digest to compare, so the caller can't tell what was logged.
+fn logs(body: &EntryBody, payload: &[u8], envelope: Option<&[u8]>) -> Option<bool> {
+    let digests = [
+        (body.payload_hash(), Some(payload)),
+        (body.envelope_hash(), envelope),
+    ];
+    let mut checked = false;
+    for (hash, data) in digests {
+        let (Some(hash), Some(data)) = (hash.filter(|hash| hash.algorithm == "sha256"), data)
+        else {
+            continue;
+        };
+        if !hash
+            .value
+            .eq_ignore_ascii_case(&encode_hex(&Sha256::digest(data)))
+        {
+            return Some(false);
+        }
+        checked = true;
+    }
+    checked.then_some(true)
+}
+
+fn inclusion_proof(proof: &Value) -> Option<InclusionProof> {
+    Some(InclusionProof {
+        log_index: proof.get("logIndex").and_then(integer)?.try_into().ok()?,
+        root_hash: proof.get("rootHash")?.as_str()?.to_string(),
+        tree_size: proof.get("treeSize").and_then(integer)?.try_into().ok()?,
+        hashes: proof
+            .get("hashes")?
+            .as_array()?
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        checkpoint: proof
+            .pointer("/checkpoint/envelope")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+    })
+}
+
+/// Reads an integer, which bundles write as a string as protobuf JSON does for 64-bit ones.
+fn integer(value: &Value) -> Option<i64> {
+    match value {
+        Value::String(value) => value.parse().ok(),
+        value => value.as_i64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_read_statements() {
+        let statement = json!({"_type": "https://in-toto.io/Statement/v1", "subject": []});
+        let envelope = json!({
+            "payloadType": "application/vnd.in-toto+json",
+            "payload": general_purpose::STANDARD.encode(statement.to_string()),
+            "signatures": []
+        });
+        let bundle = json!({"dsseEnvelope": envelope});
+
+        assert_eq!(
+            read_statements(&statement.to_string()).unwrap(),
+            vec![statement.clone()]
+        );
+        let lines = format!("{}\n\n{}\n", envelope, bundle);
+        assert_eq!(
+            read_statements(&lines).unwrap(),
+            vec![statement.clone(), statement]
+        );
+        assert!(read_statements("{}\nnot json").is_err());
+    }
+
+    #[test]
+    fn test_read_signed_statements() {
+        let statement = json!({"_type": "https://in-toto.io/Statement/v1", "subject": []});
+        let body = |statement: &Value| {
+            let body = json!({
+                "apiVersion": "0.0.1",
+                "kind": "dsse",
+                "spec": {"payloadHash": {
+                    "algorithm": "sha256",
+                    "value": encode_hex(&Sha256::digest(statement.to_string()))
+                }}
+            });
+            general_purpose::STANDARD.encode(body.to_string())
+        };
+        let bundle = json!({
+            "verificationMaterial": {
+                "publicKey": {"hint": "release-key"},
+                "tlogEntries": [{
+                    "logIndex": "25915956",
+                    "logId": {"keyId": "wNI9"},
+                    "integratedTime": "1700000000",
+                    "canonicalizedBody": body(&statement),
+                    "inclusionProof": {
+                        "logIndex": "7",
+                        "rootHash": "cm9vdA==",
+                        "treeSize": "12",
+                        "hashes": ["aGFzaA=="],
+                        "checkpoint": {"envelope": "rekor.sigstore.dev - 1\n12\ncm9vdA==\n"}
+                    },
+                    "inclusionPromise": {"signedEntryTimestamp": "TUVVQw=="}
+                }]
+            },
+            "dsseEnvelope": {
+                "payloadType": "application/vnd.in-toto+json",
+                "payload": general_purpose::STANDARD.encode(statement.to_string()),
+                "signatures": [{"keyid": "", "sig": "c2ln"}, {"keyid": "ci-key", "sig": "c2ln"}]
+            }
+        });
+        let mut keyless = bundle.clone();
+        keyless["verificationMaterial"] = json!({
+            "x509CertificateChain": {"certificates": [{"rawBytes": "MIIC"}, {"rawBytes": "MIIB"}]}
+        });
+        let lines = format!("{}\n{}\n{}\n", bundle, statement, keyless);
+        let signed = read_signed_statements(&lines).unwrap();
+        assert_eq!(signed[0].key_ids, vec!["ci-key", "release-key"]);
+        assert_eq!(signed[0].signatures, 2);
+        assert_eq!(signed[0].statement, statement);
+        assert_eq!(
+            signed[0].log_entries,
+            vec![LogEntry {
+                log_id: "wNI9".to_string(),
+                log_index: Some(25915956),
+                integrated_time: Some(1_700_000_000),
+                canonicalized_body: Some(body(&statement)),
+                inclusion_proof: Some(InclusionProof {
+                    log_index: 7,
+                    root_hash: "cm9vdA==".to_string(),
+                    tree_size: 12,
+                    hashes: vec!["aGFzaA==".to_string()],
+                    checkpoint: Some("rekor.sigstore.dev - 1\n12\ncm9vdA==\n".to_string()),
+                }),
+                signed_entry_timestamp: Some("TUVVQw==".to_string()),
+            }]
+        );
+        assert!(signed[0].certificates.is_empty());
+        assert_eq!(signed[1].signatures, 0);
+        assert_eq!(signed[2].certificates, vec!["MIIC", "MIIB"]);
+
+        // The entries must have logged the bundle's envelope.
+        let mut substituted = bundle.clone();
+        substituted["verificationMaterial"]["tlogEntries"][0]["canonicalizedBody"] =
+            body(&json!({"subject": []})).into();
+        assert_eq!(
+            signed_statement(&substituted).unwrap_err().to_string(),
+            "The envelope is not the one the dsse body of transparency log entry 0 logged"
+        );
+        substituted["verificationMaterial"]["tlogEntries"][0]["canonicalizedBody"] = "e30=".into();
+        assert_eq!(
+            signed_statement(&substituted).unwrap_err().to_string(),
+            "Unsupported body of transparency log entry 0"
+        );
+    }
+
+    #[test]
+    fn test_read_rekor_entry() {
+        let statement = json!({"_type": "https://in-toto.io/Statement/v1", "subject": []});
+        let payload_hash = encode_hex(&Sha256::digest(statement.to_string()));
+        let body = json!({
+            "apiVersion": "0.0.1",
+            "kind": "dsse",
+            "spec": {
+                "payloadHash": {"algorithm": "sha256", "value": payload_hash},
+                "signatures": [{"signature": "c2lnbmF0dXJl", "verifier": "a2V5"}]
+            }
+        });
+        let (tlog, logged) =
+            crate::trust::inclusion::tests::logged_entry(body.to_string().as_bytes());
+        let proof = logged.inclusion_proof.clone().unwrap();
+        let hex = |base64: &String| encode_hex(&general_purpose::STANDARD.decode(base64).unwrap());
+        let attestation = |statement: &Value| {
+            let envelope = crate::attestations::tests::envelope(statement);
+            json!({"data": general_purpose::STANDARD.encode(envelope.to_string())})
+        };
+        let mut entry = json!({
+            "24296fb24b8ad77a": {
+                "body": logged.canonicalized_body,
+                "integratedTime": logged.integrated_time,
+                "logID": hex(&logged.log_id),
+                "logIndex": logged.log_index,
+                "verification": {
+                    "inclusionProof": {
+                        "logIndex": proof.log_index,
+                        "rootHash": hex(&proof.root_hash),
+                        "treeSize": proof.tree_size,
+                        "hashes": proof.hashes.iter().map(hex).collect::<Vec<_>>(),
+                        "checkpoint": proof.checkpoint
+                    },
+                    "signedEntryTimestamp": logged.signed_entry_timestamp
+                },
+                "attestation": attestation(&statement)
+            }
+        });
+
+        let signed = read_signed_statements(&entry.to_string())
+            .unwrap()
+            .remove(0);
+        assert_eq!(signed.statement, statement);
+        assert_eq!(signed.signatures, 1);
+        assert_eq!(
+            signed.log_entries[0].inclusion_proof,
+            logged.inclusion_proof
+        );
+        let root = crate::trust::TrustedRoot {
+            tlogs: vec![tlog],
+            ..Default::default()
+        };
+        let report = crate::trust::check_inclusion_proofs(&root, &[signed]);
+        assert!(report.findings.is_empty(), "{:?}", report);
+
+        entry["24296fb24b8ad77a"]["attestation"] = attestation(&json!({"subject": []}));
+        assert_eq!(
+            signed_statement(&entry).unwrap_err().to_string(),
+            "The attestation of Rekor entry 24296fb24b8ad77a is not the one its dsse body logged"
+        );
+
+        // The envelope's digest is checked too, against the envelope Rekor kept.
+        let envelope = crate::attestations::tests::envelope(&statement).to_string();
+        entry["24296fb24b8ad77a"]["attestation"] = attestation(&statement);
+        let with_body = |entry: &Value, spec: Value| {
+            let body = json!({"apiVersion": "0.0.1", "kind": "dsse", "spec": spec});
+            let mut entry = entry.clone();
+            entry["24296fb24b8ad77a"]["body"] =
+                general_purpose::STANDARD.encode(body.to_string()).into();
+            entry
+        };
+        let envelope_hash = |value: String| json!({"algorithm": "sha256", "value": value});
+        let mut spec = body["spec"].clone();
+        spec["envelopeHash"] = envelope_hash(encode_hex(&Sha256::digest(&envelope)));
+        assert!(signed_statement(&with_body(&entry, spec.clone())).is_ok());
+        spec["envelopeHash"] = envelope_hash(encode_hex(&Sha256::digest("{}")));
+        assert_eq!(
+            signed_statement(&with_body(&entry, spec.clone()))
+                .unwrap_err()
+                .to_string(),
+            "The attestation of Rekor entry 24296fb24b8ad77a is not the one its dsse body logged"
+        );
+
+        // Without a sha256 digest there's nothing to tell what was logged.
+        let no_digest = "The dsse body of Rekor entry 24296fb24b8ad77a has no sha256 digest of \
+                         its attestation";
+        let spec = json!({"signatures": body["spec"]["signatures"]});
+        assert_eq!(
+            signed_statement(&with_body(&entry, spec))
+                .unwrap_err()
+                .to_string(),
+            no_digest
+        );
+        let mut spec = body["spec"].clone();
+        spec["payloadHash"]["algorithm"] = "sha512".into();
+        assert_eq!(
+            signed_statement(&with_body(&entry, spec))
+                .unwrap_err()
+                .to_string(),
+            no_digest
+        );
+
+        entry["24296fb24b8ad77a"]
+            .as_object_mut()
+            .unwrap()
+            .remove("attestation");
+        assert!(signed_statement(&entry).is_err());
+    }
+}
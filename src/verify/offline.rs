@@ -0,0 +1,266 @@
+//! Offline bundles: attestations packaged with everything needed to verify them in a
+//! disconnected network.
+//!
+//! A bundle holds the attestations as they were signed, so Sigstore bundles keep their
+//! certificates and transparency log entries with their inclusion proofs, along with the trusted
+//! root to check them against and optionally the verification policy to evaluate. Verifying it
+//! needs no network access: the inclusion proofs and certificate timestamps are checked against
+//! the keys in the trusted root rather than by asking the logs.
+//!
+//! The trusted root in the bundle isn't trusted on its own, as whoever made the bundle could
+//! have substituted one with their own keys. The receiving side verifies against a root it
+//! pinned itself, and the bundle fails if its root is a different one. Likewise, the bundle's
+//! policy could have been swapped for a permissive one, so it is only used if the receiving side
+//! pinned its [digest](OfflineBundle::policy_digest), and otherwise a policy must be given.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use p256::ecdsa::VerifyingKey;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tracing::instrument;
+
+use super::policy::{evaluate, VerificationPolicy};
+use super::{signed_statement, SignedStatement};
+use crate::trust::{tuf::encode_hex, TrustedRoot};
+use crate::validate::report::ValidationReport;
+
+/// The media type of offline bundles.
+pub const MEDIA_TYPE: &str = "application/vnd.spector.offline-bundle+json;version=0.1";
+
+/// Attestations with the trusted root and policy to verify them against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct OfflineBundle {
+    pub media_type: String,
+    /// When the bundle was exported.
+    pub created: DateTime<Utc>,
+    pub trusted_root: TrustedRoot,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<VerificationPolicy>,
+    /// The attestations: statements, DSSE envelopes, Sigstore bundles or Rekor log entries.
+    pub attestations: Vec<Value>,
+}
+
+impl OfflineBundle {
+    /// Creates a bundle, failing if one of the attestations can't be read.
+    pub fn new(
+        trusted_root: TrustedRoot,
+        attestations: Vec<Value>,
+        policy: Option<VerificationPolicy>,
+    ) -> Result<Self> {
+        for (i, attestation) in attestations.iter().enumerate() {
+            signed_statement(attestation).map_err(|e| anyhow!("Attestation {}: {}", i, e))?;
+        }
+        Ok(Self {
+            media_type: MEDIA_TYPE.to_string(),
+            created: Utc::now(),
+            trusted_root,
+            policy,
+            attestations,
+        })
+    }
+
+    /// Parses a bundle, failing on other media types.
+    pub fn parse(text: &str) -> Result<Self> {
+        let bundle: Self =
+            serde_json::from_str(text).map_err(|e| anyhow!("Invalid offline bundle: {}", e))?;
+        if bundle.media_type != MEDIA_TYPE {
+            return Err(anyhow!(
+                "Unsupported offline bundle media type {}",
+                bundle.media_type
+            ));
+        }
+        Ok(bundle)
+    }
+
+    /// Returns the digest of the bundle's policy, `sha256:` and the hex SHA-256 digest of its
+    /// JSON, for the receiving side to pin.
+    pub fn policy_digest(&self) -> Option<String> {
+        let policy = serde_json::to_vec(self.policy.as_ref()?).ok()?;
+        Some(format!("sha256:{}", encode_hex(&Sha256::digest(policy))))
+    }
+
+    /// Returns the statements of the attestations, with what their envelopes say about who
+    /// signed them.
+    pub fn signed_statements(&self) -> Result<Vec<SignedStatement>> {
+        self.attestations.iter().map(signed_statement).collect()
+    }
+
+    /// Verifies the attestations against a pinned trusted root and a policy, with their
+    /// transparency log entries, certificates and signatures checked as [`evaluate`] does, and
+    /// the pinned keys for those signed without a certificate. Without a policy, the bundle's own
+    /// is used if its digest is the pinned one, and a bundle without a policy only gets the
+    /// semantic and trust checks. Fails if the bundle's trusted root isn't the pinned one, or if
+    /// it has a policy and neither a policy nor the policy's digest is given.
+    ///
+    /// Findings point into the attestations with their index as the first segment of the path,
+    /// as for [`evaluate`].
+    #[instrument(skip_all, fields(attestations = self.attestations.len()))]
+    pub fn verify(
+        &self,
+        trusted_root: &TrustedRoot,
+        policy: Option<&VerificationPolicy>,
+        policy_digest: Option<&str>,
+        pinned: &[VerifyingKey],
+    ) -> Result<ValidationReport> {
+        if &self.trusted_root != trusted_root {
+            bail!("The trusted root of the bundle is not the pinned one");
+        }
+        let policy = match (policy, self.policy_digest()) {
+            (Some(policy), _) => policy.clone(),
+            (None, None) => VerificationPolicy::default(),
+            (None, Some(digest)) => match policy_digest {
+                Some(pinned) if pinned == digest => self.policy.clone().unwrap_or_default(),
+                Some(pinned) => bail!(
+                    "The digest of the bundle's policy is {}, not the pinned {}",
+                    digest,
+                    pinned
+                ),
+                None => bail!(
+                    "The bundle's policy is only trusted with its pinned digest, give a policy or \
+                     the digest {}",
+                    digest
+                ),
+            },
+        };
+        let attestations = self.signed_statements()?;
+        Ok(evaluate(&policy, trusted_root, &attestations, pinned))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::report::Severity;
+
+    fn fixture(name: &str) -> String {
+        std::fs::read_to_string(format!(
+            "{}/tests/fixtures/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        ))
+        .unwrap()
+    }
+
+    fn trusted_root() -> TrustedRoot {
+        TrustedRoot::from_slice(fixture("sigstore_trusted_root.json").as_bytes()).unwrap()
+    }
+
+    fn release_key() -> Vec<VerifyingKey> {
+        vec![crate::trust::signature::pem_key(&fixture("release_key.pem")).unwrap()]
+    }
+
+    fn bundle() -> OfflineBundle {
+        let attestation =
+            serde_json::from_str(&fixture("slsa_provenance_v1_release.sigstore.json")).unwrap();
+        OfflineBundle::new(trusted_root(), vec![attestation], None).unwrap()
+    }
+
+    fn errors(report: &ValidationReport) -> Vec<&str> {
+        report
+            .findings
+            .iter()
+            .filter(|f| f.severity == Severity::Error)
+            .map(|f| f.code.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let bundle = bundle();
+        let text = serde_json::to_string(&bundle).unwrap();
+        assert_eq!(OfflineBundle::parse(&text).unwrap(), bundle);
+        assert!(OfflineBundle::parse(&text.replace(MEDIA_TYPE, "application/json")).is_err());
+        assert!(OfflineBundle::new(
+            TrustedRoot::default(),
+            vec![serde_json::json!({"payloadType": "x", "payload": "!"})],
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut bundle = bundle();
+        let root = trusted_root();
+        let pinned = release_key();
+        let report = bundle.verify(&root, None, None, &pinned).unwrap();
+        assert!(errors(&report).is_empty(), "{:?}", report);
+        assert!(report.warnings().any(|f| f.code == "no-inclusion-proof"));
+        assert_eq!(
+            errors(&bundle.verify(&root, None, None, &[]).unwrap()),
+            vec!["no-signing-key"]
+        );
+
+        let policy = VerificationPolicy::parse(
+            "artifacts: [{name: hello-world, requiredAttestations: [https://spdx.dev/Document]}]",
+        )
+        .unwrap();
+        bundle.policy = Some(policy.clone());
+        let digest = bundle.policy_digest().unwrap();
+        assert_eq!(
+            errors(&bundle.verify(&root, None, Some(&digest), &pinned).unwrap()),
+            vec!["missing-attestation"]
+        );
+        let default = VerificationPolicy::default();
+        assert!(errors(&bundle.verify(&root, Some(&default), None, &pinned).unwrap()).is_empty());
+
+        let mut root = root;
+        root.tlogs.clear();
+        bundle.trusted_root = root.clone();
+        assert_eq!(
+            errors(&bundle.verify(&root, Some(&policy), None, &pinned).unwrap()),
+            vec!["unknown-transparency-log", "missing-attestation"]
+        );
+    }
+
+    #[test]
+    fn test_verify_untrusted_policy() {
+        // A bundle with a permissive policy in place of the one it was exported with.
+        let mut bundle = bundle();
+        bundle.policy =
+            Some(VerificationPolicy::parse("artifacts: [{name: hello-world}]").unwrap());
+        let digest = bundle.policy_digest().unwrap();
+        bundle.policy = Some(VerificationPolicy::default());
+        let (root, pinned) = (trusted_root(), release_key());
+        assert!(bundle
+            .verify(&root, None, None, &pinned)
+            .unwrap_err()
+            .to_string()
+            .starts_with("The bundle's policy is only trusted with its pinned digest"));
+        assert!(bundle
+            .verify(&root, None, Some(&digest), &pinned)
+            .unwrap_err()
+            .to_string()
+            .ends_with(&format!("not the pinned {}", digest)));
+    }
+
+    #[test]
+    fn test_verify_tampered_signature() {
+        let mut bundle = bundle();
+        bundle.attestations[0]["dsseEnvelope"]["signatures"][0]["sig"] = "AAAA".into();
+        let report = bundle
+            .verify(&trusted_root(), None, None, &release_key())
+            .unwrap();
+        assert_eq!(errors(&report), vec!["invalid-signature"]);
+    }
+
+    #[test]
+    fn test_verify_substituted_root() {
+        // A root with another key for the pinned log, as whoever made the bundle could sign
+        // entries with their own.
+        let mut bundle = bundle();
+        bundle.trusted_root.tlogs[0].public_key.raw_bytes =
+            "MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE".to_string();
+        assert_eq!(
+            bundle
+                .verify(&trusted_root(), None, None, &release_key())
+                .unwrap_err()
+                .to_string(),
+            "The trusted root of the bundle is not the pinned one"
+        );
+    }
+}
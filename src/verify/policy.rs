@@ -0,0 +1,718 @@
+//! Verification policies: the attestations artifacts need before they are trusted.
+//!
+//! A policy is a YAML or JSON document listing the artifacts to verify and the attestations each
+//! must have, along with the keys, builders, maximum age, signing window and SLSA Build level
+//! the attestations must meet. It is evaluated against a set of attestations into a single report, which passes
+//! if it has no errors:
+//!
+//! ```yaml
+//! trustedKeys: [sha256:ce97ec20d43661a19b215cb2e376a698d0b4aa2aa5b4c7854e916bd05082fcee]
+//! allowedBuilders: [https://github.com/slsa-framework/slsa-github-generator/]
+//! maxAgeSeconds: 2592000
+//! signingWindow: {maxDelaySeconds: 3600, clockSkewSeconds: 60}
+//! slsaLevel: 2
+//! artifacts:
+//!   - name: app-*.tar.gz
+//!     requiredAttestations: [https://slsa.dev/provenance/v1, https://spdx.dev/Document]
+//!     slsaLevel: 3
+//! ```
+//!
+//! Only attestations whose signatures verify, with a signing certificate chained up to the
+//! trusted root or a pinned key, count towards the requirements.
+//!
+//! Findings about an attestation point into it with its index as the first segment of the path,
+//! as for [consistency checks](crate::validate::consistency). Findings about a requirement that
+//! isn't met point into the policy, e.g. `/artifacts/0/slsaLevel`.
+
+use anyhow::{anyhow, Result};
+use p256::ecdsa::VerifyingKey;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::time::Instant;
+use tracing::{debug, instrument};
+
+use super::SignedStatement;
+use crate::attestations::check_statement;
+use crate::models::intoto::predicate::normalize_predicate_type;
+use crate::trust::certificate::CertificateIdentity;
+use crate::trust::{self, Signer, TrustedRoot};
+use crate::validate::consistency::{builder_id, finished_on, is_provenance};
+use crate::validate::policy::Policy;
+use crate::validate::report::{Finding, ValidationReport};
+
+/// The builders considered hardened when a policy lists none: the SLSA GitHub generator's
+/// reusable workflows, which produce SLSA Build L3 provenance.
+pub const DEFAULT_HARDENED_BUILDERS: &[&str] =
+    &["https://github.com/slsa-framework/slsa-github-generator/"];
+
+/// What a set of attestations must show for the artifacts they are about to be trusted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct VerificationPolicy {
+    /// The artifacts to verify and the attestations each must have.
+    pub artifacts: Vec<ArtifactRequirements>,
+    /// The [fingerprints](trust::signature::key_fingerprint) of the keys or signing
+    /// certificates trusted to sign attestations, e.g. `sha256:ce97…`. If empty, any verified
+    /// signer is trusted. The key ids the envelopes name aren't compared, as they aren't signed.
+    pub trusted_keys: Vec<String>,
+    /// Prefixes of the provenance builder ids to accept. If empty, builders are not checked.
+    pub allowed_builders: Vec<String>,
+    /// Prefixes of the ids of builders that meet SLSA Build L3. If empty, those in
+    /// [`DEFAULT_HARDENED_BUILDERS`] are used.
+    pub hardened_builders: Vec<String>,
+    /// The maximum age, in seconds, of the attestations.
+    pub max_age_seconds: Option<u64>,
+    /// When provenance must have been signed relative to the end of its build, judged by the
+    /// time the signed entry timestamps of its transparency log entries vouch for.
+    pub signing_window: Option<SigningWindow>,
+    /// The SLSA Build level artifacts must reach, unless they set their own.
+    pub slsa_level: Option<SlsaLevel>,
+    /// The policy for the semantic checks of each attestation.
+    pub validation: Policy,
+}
+
+/// The attestations an artifact must have.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ArtifactRequirements {
+    /// The subject name of the artifact, where `*` matches any characters, e.g. `app-*.tar.gz`.
+    pub name: String,
+    /// The predicate types of the attestations the artifact must have.
+    pub required_attestations: Vec<String>,
+    /// The SLSA Build level the artifact must reach.
+    pub slsa_level: Option<SlsaLevel>,
+}
+
+/// How long after its build provenance must be signed.
+///
+/// Builders sign provenance as the build finishes, so a transparency log entry from before
+/// `finishedOn` shows backdated provenance, and one long after it provenance replayed or signed
+/// outside the build.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct SigningWindow {
+    /// The most seconds provenance can be logged after its build finished.
+    pub max_delay_seconds: u64,
+    /// The seconds the builder's and the log's clocks may differ by, allowed on both sides.
+    pub clock_skew_seconds: u64,
+}
+
+/// A SLSA Build level.
+///
+/// The level is judged from provenance whose signature verified: L1 needs provenance, L2
+/// provenance naming its builder, and L3 provenance from a hardened builder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum SlsaLevel {
+    L1 = 1,
+    L2 = 2,
+    L3 = 3,
+}
+
+impl TryFrom<u8> for SlsaLevel {
+    type Error = String;
+
+    fn try_from(level: u8) -> Result<Self, Self::Error> {
+        match level {
+            1 => Ok(SlsaLevel::L1),
+            2 => Ok(SlsaLevel::L2),
+            3 => Ok(SlsaLevel::L3),
+            _ => Err(format!(
+                "SLSA Build level must be 1, 2 or 3, found {}",
+                level
+            )),
+        }
+    }
+}
+
+impl From<SlsaLevel> for u8 {
+    fn from(level: SlsaLevel) -> Self {
+        level as u8
+    }
+}
+
+impl fmt::Display for SlsaLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "L{}", *self as u8)
+    }
+}
+
+impl VerificationPolicy {
+    /// Parses a policy, reading documents starting with `{` as JSON and others as YAML.
+    pub fn parse(text: &str) -> Result<Self> {
+        if text.trim_start().starts_with('{') {
+            serde_json::from_str(text).map_err(|e| anyhow!("Invalid policy: {}", e))
+        } else {
+            serde_yaml::from_str(text).map_err(|e| anyhow!("Invalid policy: {}", e))
+        }
+    }
+
+    /// Returns the policy for the semantic checks, with the builders and maximum age of this
+    /// policy added.
+    fn validation_policy(&self) -> Policy {
+        let mut policy = self.validation.clone();
+        policy
+            .allowed_builders
+            .extend(self.allowed_builders.iter().cloned());
+        policy.max_age_seconds = self.max_age_seconds.or(policy.max_age_seconds);
+        policy
+    }
+
+    /// Returns the SLSA Build level the provenance shows, once its signature verified. The
+    /// builder of provenance signed with a certificate is the one the certificate was issued to.
+    fn slsa_level(&self, statement: &Value, signer: &Signer) -> SlsaLevel {
+        let Some((_, builder)) = builder_id(statement) else {
+            return SlsaLevel::L1;
+        };
+        let builder = signer
+            .identity()
+            .and_then(CertificateIdentity::builder)
+            .unwrap_or(builder);
+        let hardened = if self.hardened_builders.is_empty() {
+            DEFAULT_HARDENED_BUILDERS
+                .iter()
+                .any(|prefix| builder.starts_with(prefix))
+        } else {
+            self.hardened_builders
+                .iter()
+                .any(|prefix| builder.starts_with(prefix.as_str()))
+        };
+        if hardened {
+            SlsaLevel::L3
+        } else {
+            SlsaLevel::L2
+        }
+    }
+}
+
+/// Evaluates a policy against attestations, reporting every way they fall short.
+///
+/// Each attestation is validated with the semantic checks, and checked against the trusted root
+/// and the pinned keys as [`trust::check`] does, which verifies its signature with a signing
+/// certificate chained up to the root or one of the pinned keys. With trusted keys, the key or certificate it verified with
+/// must be one of them (`untrusted-key`). With a signing window, provenance logged before its build finished gets a
+/// `signed-before-build` error, provenance logged after the window a `signed-after-window`
+/// error, and provenance without a log entry whose time a log of the trusted root signed a
+/// `no-signing-time` error. Only attestations without errors count towards the requirements of
+/// the artifacts they are about: an artifact no attestation is about gets a `missing-artifact`
+/// error, each required predicate type it has no attestation of a `missing-attestation` error,
+/// and provenance below the required SLSA Build level a `slsa-level-not-met` error.
+#[instrument(
+    skip_all,
+    fields(attestations = attestations.len(), artifacts = policy.artifacts.len())
+)]
+pub fn evaluate(
+    policy: &VerificationPolicy,
+    root: &TrustedRoot,
+    attestations: &[SignedStatement],
+    pinned: &[VerifyingKey],
+) -> ValidationReport {
+    let start = Instant::now();
+    let mut report = ValidationReport::new();
+    let validation = policy.validation_policy();
+    let mut valid = Vec::new();
+    for (i, signed) in attestations.iter().enumerate() {
+        let mut findings = check_statement(&signed.statement, &validation);
+        // The checks of a single attestation point into it as the first.
+        let checked = trust::check(root, std::slice::from_ref(signed), pinned);
+        findings.extend(checked.findings.into_iter().map(|mut finding| {
+            finding.path = finding.path.split_off("/0".len());
+            finding
+        }));
+        let signer = trust::verify_signature(root, signed, pinned).ok();
+        if let Some(signer) = &signer {
+            if !policy.trusted_keys.is_empty()
+                && !policy
+                    .trusted_keys
+                    .iter()
+                    .any(|key| key == signer.fingerprint())
+            {
+                findings.push(Finding::error(
+                    "untrusted-key",
+                    "",
+                    format!(
+                        "Signed by none of the trusted keys, but by {}",
+                        signer.fingerprint()
+                    ),
+                ));
+            }
+        }
+        if let Some(window) = &policy.signing_window {
+            check_signing_window(window, root, signed, &mut findings);
+        }
+        match signer {
+            Some(signer) if !findings.has_errors() => valid.push((signed, signer)),
+            _ => {}
+        }
+        report.extend(findings.findings.into_iter().map(|mut finding| {
+            finding.path = format!("/{}{}", i, finding.path);
+            finding
+        }));
+    }
+
+    for (j, artifact) in policy.artifacts.iter().enumerate() {
+        let about = |signed: &SignedStatement| is_about(&signed.statement, &artifact.name);
+        if !attestations.iter().any(about) {
+            report.push(Finding::error(
+                "missing-artifact",
+                format!("/artifacts/{}/name", j),
+                format!("No attestation is about {}", artifact.name),
+            ));
+            continue;
+        }
+        let valid = valid
+            .iter()
+            .filter(|(signed, _)| about(signed))
+            .collect::<Vec<_>>();
+
+        for (k, required) in artifact.required_attestations.iter().enumerate() {
+            let required_type = normalize_predicate_type(required);
+            let found = valid.iter().any(|(signed, _)| {
+                signed
+                    .statement
+                    .get("predicateType")
+                    .and_then(Value::as_str)
+                    .is_some_and(|t| normalize_predicate_type(t) == required_type)
+            });
+            if !found {
+                report.push(Finding::error(
+                    "missing-attestation",
+                    format!("/artifacts/{}/requiredAttestations/{}", j, k),
+                    format!("{} has no valid {} attestation", artifact.name, required),
+                ));
+            }
+        }
+
+        let required_level = match (artifact.slsa_level, policy.slsa_level) {
+            (Some(level), _) => Some((level, format!("/artifacts/{}/slsaLevel", j))),
+            (None, Some(level)) => Some((level, "/slsaLevel".to_string())),
+            (None, None) => None,
+        };
+        if let Some((required, path)) = required_level {
+            let level = valid
+                .iter()
+                .filter(|(signed, _)| is_provenance(&signed.statement))
+                .map(|(signed, signer)| policy.slsa_level(&signed.statement, signer))
+                .max();
+            if level.is_none_or(|level| level < required) {
+                report.push(Finding::error(
+                    "slsa-level-not-met",
+                    path,
+                    match level {
+                        Some(level) => format!(
+                            "{} reaches SLSA Build {}, {} is required",
+                            artifact.name, level, required
+                        ),
+                        None => format!(
+                            "{} has no valid provenance, SLSA Build {} is required",
+                            artifact.name, required
+                        ),
+                    },
+                ));
+            }
+        }
+    }
+    debug!(
+        valid = valid.len(),
+        errors = report.errors().count(),
+        elapsed_ms = start.elapsed().as_millis() as u64,
+        "Evaluated the verification policy"
+    );
+    report
+}
+
+/// Checks the transparency log entries of provenance were integrated within the signing window
+/// after its build finished, by the time their signed entry timestamps vouch for. Entries
+/// without a verified time are left out, and provenance with none fails. Findings point into
+/// the envelope, as `/tlogEntries/<index>`.
+fn check_signing_window(
+    window: &SigningWindow,
+    root: &TrustedRoot,
+    signed: &SignedStatement,
+    report: &mut ValidationReport,
+) {
+    let Some((finished, _)) = finished_on(&signed.statement) else {
+        return;
+    };
+    let mut verified = false;
+    for (j, entry) in signed.log_entries.iter().enumerate() {
+        let Some(integrated) = trust::verified_integrated_time(root, entry) else {
+            continue;
+        };
+        verified = true;
+        let path = format!("/tlogEntries/{}/integratedTime", j);
+        // Compared as seconds so that no bound from the policy can overflow the times.
+        let delay = integrated.signed_duration_since(finished).num_seconds();
+        if delay < 0 && delay.unsigned_abs() > window.clock_skew_seconds {
+            report.push(Finding::error(
+                "signed-before-build",
+                path,
+                format!(
+                    "Logged at {}, before the build finished at {}",
+                    integrated, finished
+                ),
+            ));
+        } else if delay > 0
+            && delay.unsigned_abs()
+                > window
+                    .clock_skew_seconds
+                    .saturating_add(window.max_delay_seconds)
+        {
+            report.push(Finding::error(
+                "signed-after-window",
+                path,
+                format!(
+                    "Logged at {}, more than {} seconds after the build finished at {}",
+                    integrated, window.max_delay_seconds, finished
+                ),
+            ));
+        }
+    }
+    if !verified {
+        report.push(Finding::error(
+            "no-signing-time",
+            "",
+            "No transparency log entry has a signed entry timestamp from a trusted log to show \
+             when the provenance was signed",
+        ));
+    }
+}
+
+/// Returns true if a subject of the statement has a name matching the pattern.
+fn is_about(statement: &Value, pattern: &str) -> bool {
+    statement
+        .get("subject")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|subject| subject.get("name").and_then(Value::as_str))
+        .any(|name| matches_pattern(pattern, name))
+}
+
+/// Matches a name against a pattern where `*` matches any characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let parts = parts.collect::<Vec<_>>();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trust::signature::{key_fingerprint, tests::signed_envelope};
+    use crate::validate::report::Severity;
+    use p256::ecdsa::SigningKey;
+    use serde_json::json;
+
+    fn provenance() -> Value {
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        serde_json::from_str(&fixture).unwrap()
+    }
+
+    fn key(seed: u8) -> VerifyingKey {
+        *SigningKey::from_bytes(&[seed; 32].into())
+            .unwrap()
+            .verifying_key()
+    }
+
+    /// Returns a statement in an envelope signed with the key made from a seed.
+    fn signed(statement: Value, seed: u8) -> SignedStatement {
+        let key = SigningKey::from_bytes(&[seed; 32].into()).unwrap();
+        let (envelope, _) = signed_envelope(&key, &statement);
+        SignedStatement {
+            statement,
+            key_ids: Vec::new(),
+            signatures: 1,
+            log_entries: Vec::new(),
+            certificates: Vec::new(),
+            envelope: Some(envelope),
+        }
+    }
+
+    fn codes(report: &ValidationReport) -> Vec<(&str, &str)> {
+        report
+            .findings
+            .iter()
+            .filter(|f| f.severity == Severity::Error)
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let yaml = "
+trustedKeys: [sha256:ce97ec20d43661a19b215cb2e376a698d0b4aa2aa5b4c7854e916bd05082fcee]
+slsaLevel: 2
+artifacts:
+  - name: hello-*
+    requiredAttestations: [https://slsa.dev/provenance/v1]
+    slsaLevel: 3
+validation:
+  strict: true
+";
+        let policy = VerificationPolicy::parse(yaml).unwrap();
+        assert_eq!(policy.slsa_level, Some(SlsaLevel::L2));
+        assert_eq!(policy.artifacts[0].slsa_level, Some(SlsaLevel::L3));
+        assert!(policy.validation.strict);
+        assert_eq!(
+            VerificationPolicy::parse(&serde_json::to_string(&policy).unwrap()).unwrap(),
+            policy
+        );
+
+        assert!(VerificationPolicy::parse("slsaLevel: 4").is_err());
+        assert!(VerificationPolicy::parse("{\"unknown\": true}").is_err());
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("app", "app"));
+        assert!(!matches_pattern("app", "app.sig"));
+        assert!(matches_pattern("app-*.tar.gz", "app-1.2.3.tar.gz"));
+        assert!(!matches_pattern("app-*.tar.gz", "app-1.2.3.zip"));
+        assert!(matches_pattern("*", "anything"));
+        assert!(matches_pattern("a*b*c", "abc"));
+        assert!(!matches_pattern("a*bc", "abc-bc-"));
+        assert!(!matches_pattern("ab*ba", "aba"));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let name = provenance()["subject"][0]["name"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let policy = VerificationPolicy {
+            trusted_keys: vec![key_fingerprint(&key(7))],
+            slsa_level: Some(SlsaLevel::L2),
+            artifacts: vec![ArtifactRequirements {
+                name: name.clone(),
+                required_attestations: vec!["https://slsa.dev/provenance/v1.0".to_string()],
+                slsa_level: None,
+            }],
+            ..VerificationPolicy::default()
+        };
+        let root = TrustedRoot::default();
+        let pinned = [key(7), key(8)];
+        let report = evaluate(&policy, &root, &[signed(provenance(), 7)], &pinned);
+        assert!(!report.has_errors(), "{:?}", report);
+
+        let policy = VerificationPolicy {
+            artifacts: vec![
+                ArtifactRequirements {
+                    name,
+                    required_attestations: vec![
+                        "https://slsa.dev/provenance/v1".to_string(),
+                        "https://spdx.dev/Document".to_string(),
+                    ],
+                    slsa_level: Some(SlsaLevel::L3),
+                },
+                ArtifactRequirements {
+                    name: "other".to_string(),
+                    ..ArtifactRequirements::default()
+                },
+            ],
+            hardened_builders: vec!["https://ci.example.com/".to_string()],
+            ..policy
+        };
+        let report = evaluate(
+            &policy,
+            &root,
+            &[
+                signed(provenance(), 7),
+                signed(json!({"subject": [{"name": "app"}]}), 8),
+            ],
+            &pinned,
+        );
+        let codes = codes(&report);
+        assert!(codes.contains(&("untrusted-key", "/1")));
+        assert!(codes.contains(&("missing-attestation", "/artifacts/0/requiredAttestations/1")));
+        assert!(codes.contains(&("slsa-level-not-met", "/artifacts/0/slsaLevel")));
+        assert!(codes.contains(&("missing-artifact", "/artifacts/1/name")));
+        assert!(!codes.contains(&("missing-attestation", "/artifacts/0/requiredAttestations/0")));
+    }
+
+    #[test]
+    fn test_evaluate_forged_signature() {
+        let name = provenance()["subject"][0]["name"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let policy = VerificationPolicy {
+            trusted_keys: vec!["release-key".to_string()],
+            slsa_level: Some(SlsaLevel::L3),
+            artifacts: vec![ArtifactRequirements {
+                name,
+                required_attestations: vec!["https://slsa.dev/provenance/v1".to_string()],
+                slsa_level: None,
+            }],
+            ..VerificationPolicy::default()
+        };
+        let root = TrustedRoot::default();
+        let unmet = [
+            ("missing-attestation", "/artifacts/0/requiredAttestations/0"),
+            ("slsa-level-not-met", "/slsaLevel"),
+        ];
+
+        // An envelope naming the trusted key id, with a signature that doesn't verify.
+        let mut forged = signed(provenance(), 7);
+        let signature = &mut forged.envelope.as_mut().unwrap().signatures[0];
+        signature.keyid = Some("release-key".to_string());
+        signature.sig = vec![0; 3];
+        forged.key_ids = vec!["release-key".to_string()];
+        let report = evaluate(&policy, &root, &[forged], &[key(7)]);
+        let mut expected = vec![("invalid-signature", "/0/signatures")];
+        expected.extend(unmet);
+        assert_eq!(codes(&report), expected);
+
+        // Key ids aren't signed, so they don't make a key trusted, only its fingerprint does.
+        let mut genuine = signed(provenance(), 7);
+        genuine.key_ids = vec!["release-key".to_string()];
+        let report = evaluate(&policy, &root, &[genuine.clone()], &[key(7)]);
+        let mut expected = vec![("untrusted-key", "/0")];
+        expected.extend(unmet);
+        assert_eq!(codes(&report), expected);
+
+        // Nor is the key trusted unless it is pinned.
+        let policy = VerificationPolicy {
+            trusted_keys: vec![key_fingerprint(&key(7))],
+            ..policy
+        };
+        let report = evaluate(&policy, &root, &[genuine.clone()], &[]);
+        let mut expected = vec![("no-signing-key", "/0")];
+        expected.extend(unmet);
+        assert_eq!(codes(&report), expected);
+        let report = evaluate(&policy, &root, &[genuine], &[key(7)]);
+        assert!(!report.has_errors(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_signing_window() {
+        use crate::trust::inclusion::tests::{logged_entry, sign_entry_timestamp};
+        use crate::verify::LogEntry;
+
+        let policy = VerificationPolicy::parse(
+            "signingWindow: {maxDelaySeconds: 600, clockSkewSeconds: 30}",
+        )
+        .unwrap();
+        let (tlog, logged) = logged_entry(b"{}");
+        let root = TrustedRoot {
+            tlogs: vec![tlog],
+            ..TrustedRoot::default()
+        };
+        let mut statement = provenance();
+        statement["predicate"]["runDetails"]["metadata"] =
+            json!({"finishedOn": "2024-05-01T12:00:00Z"});
+        let finished = 1_714_564_800;
+        let pinned = [key(7)];
+        let mut attestation = signed(statement.clone(), 7);
+        attestation.log_entries = [-60, -20, 0, 630, 631]
+            .into_iter()
+            .map(|offset| {
+                let mut entry = logged.clone();
+                sign_entry_timestamp(&mut entry, finished + offset);
+                entry
+            })
+            .collect();
+        // The log didn't sign this time, so it is left out.
+        attestation.log_entries.push(LogEntry {
+            integrated_time: Some(finished - 60),
+            ..attestation.log_entries[2].clone()
+        });
+        let report = evaluate(&policy, &root, &[attestation.clone()], &pinned);
+        assert_eq!(
+            codes(&report),
+            vec![
+                (
+                    "invalid-signed-entry-timestamp",
+                    "/0/tlogEntries/5/inclusionPromise"
+                ),
+                ("signed-before-build", "/0/tlogEntries/0/integratedTime"),
+                ("signed-after-window", "/0/tlogEntries/4/integratedTime"),
+            ]
+        );
+
+        // The widest window allows any signing time rather than overflowing.
+        let widest = VerificationPolicy {
+            signing_window: Some(SigningWindow {
+                max_delay_seconds: u64::MAX,
+                clock_skew_seconds: u64::MAX,
+            }),
+            ..VerificationPolicy::default()
+        };
+        let report = evaluate(&widest, &root, &[attestation.clone()], &pinned);
+        assert_eq!(
+            codes(&report),
+            vec![(
+                "invalid-signed-entry-timestamp",
+                "/0/tlogEntries/5/inclusionPromise"
+            )]
+        );
+
+        // Without a log entry the trusted root's log vouches for, the signing time is unknown.
+        let report = evaluate(&policy, &TrustedRoot::default(), &[attestation], &pinned);
+        assert_eq!(codes(&report).last(), Some(&("no-signing-time", "/0")));
+        assert!(codes(&report)[..6]
+            .iter()
+            .all(|(code, _)| *code == "unknown-transparency-log"));
+        let report = evaluate(&policy, &root, &[signed(statement.clone(), 7)], &pinned);
+        assert_eq!(codes(&report), vec![("no-signing-time", "/0")]);
+
+        // Without a window, the signing time isn't checked.
+        let report = evaluate(
+            &VerificationPolicy::default(),
+            &root,
+            &[signed(statement, 7)],
+            &pinned,
+        );
+        assert!(!report.has_errors());
+        assert!(VerificationPolicy::parse("signingWindow: {maxDelay: 600}").is_err());
+    }
+
+    #[test]
+    fn test_slsa_level() {
+        let policy = VerificationPolicy::default();
+        let key = Signer::Key(key_fingerprint(&key(7)));
+        let mut statement = provenance();
+        assert_eq!(policy.slsa_level(&statement, &key), SlsaLevel::L3);
+        assert_eq!(policy.slsa_level(&json!({}), &key), SlsaLevel::L1);
+
+        // The builder a certificate was issued to counts, not the one the provenance names.
+        let certificate = Signer::Certificate {
+            fingerprint: "sha256:00".to_string(),
+            identity: CertificateIdentity {
+                build_signer_uri: Some("https://ci.example.com/build@v1".to_string()),
+                ..CertificateIdentity::default()
+            },
+        };
+        assert_eq!(policy.slsa_level(&statement, &certificate), SlsaLevel::L2);
+
+        statement["predicate"]["runDetails"]["builder"]["id"] = json!("https://ci.example.com");
+        assert_eq!(policy.slsa_level(&statement, &key), SlsaLevel::L2);
+        let policy = VerificationPolicy {
+            hardened_builders: vec!["https://ci.example.com".to_string()],
+            ..policy
+        };
+        assert_eq!(policy.slsa_level(&statement, &key), SlsaLevel::L3);
+        assert_eq!(policy.slsa_level(&statement, &certificate), SlsaLevel::L3);
+    }
+}
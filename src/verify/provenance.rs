@@ -0,0 +1,670 @@
+//! Checks of SLSA provenance against the expected source, builder and workflow inputs.
+//!
+//! These are the checks slsa-verifier makes once the signature is verified: the artifact was
+//! built from the expected repository, at the expected tag or branch, by the expected builder,
+//! and with the expected workflow inputs. [`verify_signed_provenance`] verifies the signature
+//! against a trusted root and the artifact's digest first, and binds the builder and source to
+//! the identity of the signing certificate, as slsa-verifier does. The values are read from
+//! where the GitHub Actions builders record them, for both SLSA v1 and v0.2:
+//!
+//! | | v1 | v0.2 |
+//! |---|---|---|
+//! | Source and ref | `externalParameters.workflow`, `externalParameters.source` or the first `resolvedDependencies` | `invocation.configSource.uri` |
+//! | Builder | `runDetails.builder.id` | `builder.id` |
+//! | Workflow inputs | `externalParameters.inputs` | `invocation.environment.github_event_payload.inputs` |
+
+use p256::ecdsa::VerifyingKey;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use tracing::{debug, instrument};
+
+use super::SignedStatement;
+use crate::attestations::check_subject_digest;
+use crate::models::intoto::predicate::{
+    normalize_predicate_type, SLSA_PROVENANCE_V02, SLSA_PROVENANCE_V1,
+};
+use crate::trust::certificate::CertificateIdentity;
+use crate::trust::{self, Signer, TrustedRoot};
+use crate::validate::report::{join_pointer, Finding, ValidationReport};
+
+/// What the provenance of an artifact is expected to say.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProvenanceExpectations {
+    /// The repository the artifact was built from, e.g. `github.com/org/repo`. The scheme, a
+    /// `git+` prefix and a `.git` suffix are ignored on both sides.
+    pub source_uri: String,
+    /// The builder that must have produced the provenance. Without an `@` version suffix, any
+    /// version of the builder is accepted.
+    pub builder_id: Option<String>,
+    /// The tag the source must have been built at, e.g. `v1.2.3`.
+    pub tag: Option<String>,
+    /// The branch the source must have been built at, e.g. `main`.
+    pub branch: Option<String>,
+    /// The inputs the workflow must have been run with. Values other than strings are compared
+    /// by their JSON text, so `true` matches a boolean input.
+    pub workflow_inputs: BTreeMap<String, String>,
+}
+
+/// The values of a provenance the expectations are checked against, with their pointers.
+#[derive(Default)]
+struct Build<'a> {
+    source: Option<(String, String)>,
+    git_ref: Option<(String, String)>,
+    builder_id: Option<(&'a str, &'static str)>,
+    inputs: Option<(&'a Map<String, Value>, &'static str)>,
+}
+
+/// Verifies signed SLSA provenance of an artifact as slsa-verifier does: its transparency log
+/// entries and its signature, with a signing certificate chained up to a trusted root or a pinned
+/// key, as [`trust::check`] does.
+/// Then a subject must have the artifact's sha256 digest, and only then are the expectations
+/// checked, as [`verify_provenance`] does.
+///
+/// Provenance signed with a certificate must also have been signed by the builder and for the
+/// source the certificate was issued to, as its subject alternative name and Fulcio extensions
+/// record, since anyone can write any builder and source into the payload they sign. Mismatches
+/// get the same errors as those of the provenance, pointing at the certificate. Provenance
+/// signed with a pinned key is trusted to record them, as whoever holds the key vouches for it.
+///
+/// Findings of the signature checks point into the attestation with `/0` as the first segment
+/// of the path.
+pub fn verify_signed_provenance(
+    root: &TrustedRoot,
+    signed: &SignedStatement,
+    sha256: &str,
+    expected: &ProvenanceExpectations,
+    pinned: &[VerifyingKey],
+) -> ValidationReport {
+    let mut report = trust::check(root, std::slice::from_ref(signed), pinned);
+    if report.has_errors() {
+        return report;
+    }
+    check_subject_digest(&signed.statement, "sha256", sha256, &mut report);
+    if report.has_errors() {
+        return report;
+    }
+    let signer = trust::verify_signature(root, signed, pinned).ok();
+    if let Some(identity) = signer.as_ref().and_then(Signer::identity) {
+        check_identity(identity, &signed.statement, expected, &mut report);
+    }
+    report.extend(verify_provenance(&signed.statement, expected).findings);
+    report
+}
+
+/// Checks the identity a signing certificate was issued to against the expectations, and that
+/// the provenance names the builder the certificate was issued to.
+fn check_identity(
+    identity: &CertificateIdentity,
+    statement: &Value,
+    expected: &ProvenanceExpectations,
+    report: &mut ValidationReport,
+) {
+    const CERTIFICATE: &str = "/0/certificates/0";
+    match &identity.source_repository_uri {
+        Some(source) if repository(source) != repository(&expected.source_uri) => {
+            report.push(Finding::error(
+                "source-mismatch",
+                CERTIFICATE,
+                format!(
+                    "The certificate was issued to a build of {}, expected {}",
+                    source, expected.source_uri
+                ),
+            ))
+        }
+        Some(_) => {}
+        None => report.push(Finding::error(
+            "source-mismatch",
+            CERTIFICATE,
+            "The certificate doesn't record the source repository",
+        )),
+    }
+
+    let Some(builder) = identity.builder() else {
+        report.push(Finding::error(
+            "builder-mismatch",
+            CERTIFICATE,
+            "The certificate doesn't record the builder",
+        ));
+        return;
+    };
+    if let Some(expected_id) = &expected.builder_id {
+        if !builder_matches(builder, expected_id) {
+            report.push(Finding::error(
+                "builder-mismatch",
+                CERTIFICATE,
+                format!(
+                    "The certificate was issued to {}, expected {}",
+                    builder, expected_id
+                ),
+            ));
+        }
+    }
+    if let Ok(Build {
+        builder_id: Some((id, path)),
+        ..
+    }) = build(statement)
+    {
+        if id != builder {
+            report.push(Finding::error(
+                "builder-mismatch",
+                path,
+                format!(
+                    "Built by {}, but the certificate was issued to {}",
+                    id, builder
+                ),
+            ));
+        }
+    }
+
+    for expected_ref in expected_refs(expected) {
+        match &identity.source_repository_ref {
+            Some(git_ref) if *git_ref != expected_ref => report.push(Finding::error(
+                "ref-mismatch",
+                CERTIFICATE,
+                format!(
+                    "The certificate was issued to a build at {}, expected {}",
+                    git_ref, expected_ref
+                ),
+            )),
+            Some(_) => {}
+            None => report.push(Finding::error(
+                "ref-mismatch",
+                CERTIFICATE,
+                format!(
+                    "The certificate doesn't record the source ref, expected {}",
+                    expected_ref
+                ),
+            )),
+        }
+    }
+}
+
+/// Checks a SLSA provenance statement against the expectations.
+///
+/// Mismatches are reported as `source-mismatch`, `builder-mismatch`, `ref-mismatch` and
+/// `workflow-input-mismatch` errors, including when the provenance doesn't record the value.
+/// Statements with another predicate type get an `unsupported-predicate-type` error.
+#[instrument(
+    skip_all,
+    fields(source_uri = %expected.source_uri, builder_id = expected.builder_id.as_deref())
+)]
+pub fn verify_provenance(statement: &Value, expected: &ProvenanceExpectations) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    let build = match build(statement) {
+        Ok(build) => build,
+        Err(finding) => {
+            report.push(finding);
+            return report;
+        }
+    };
+
+    match &build.source {
+        Some((source, path)) if repository(source) != repository(&expected.source_uri) => report
+            .push(Finding::error(
+                "source-mismatch",
+                path.as_str(),
+                format!("Built from {}, expected {}", source, expected.source_uri),
+            )),
+        Some(_) => {}
+        None => report.push(Finding::error(
+            "source-mismatch",
+            "/predicate",
+            "The provenance doesn't record the source repository",
+        )),
+    }
+
+    if let Some(expected_id) = &expected.builder_id {
+        match build.builder_id {
+            Some((id, path)) if !builder_matches(id, expected_id) => report.push(Finding::error(
+                "builder-mismatch",
+                path,
+                format!("Built by {}, expected {}", id, expected_id),
+            )),
+            Some(_) => {}
+            None => report.push(Finding::error(
+                "builder-mismatch",
+                "/predicate",
+                "The provenance doesn't record the builder",
+            )),
+        }
+    }
+
+    for expected_ref in expected_refs(expected) {
+        match &build.git_ref {
+            Some((git_ref, path)) if *git_ref != expected_ref => report.push(Finding::error(
+                "ref-mismatch",
+                path.as_str(),
+                format!("Built at {}, expected {}", git_ref, expected_ref),
+            )),
+            Some(_) => {}
+            None => report.push(Finding::error(
+                "ref-mismatch",
+                "/predicate",
+                format!(
+                    "The provenance doesn't record the source ref, expected {}",
+                    expected_ref
+                ),
+            )),
+        }
+    }
+
+    for (name, expected_value) in &expected.workflow_inputs {
+        let (value, path) = match build.inputs {
+            Some((inputs, path)) => (inputs.get(name), join_pointer(path, name)),
+            None => (None, "/predicate".to_string()),
+        };
+        let value = value.map(|value| match value {
+            Value::String(value) => value.clone(),
+            value => value.to_string(),
+        });
+        if value.as_ref() != Some(expected_value) {
+            report.push(Finding::error(
+                "workflow-input-mismatch",
+                path,
+                format!(
+                    "Workflow input {} is {}, expected {}",
+                    name,
+                    value.as_deref().unwrap_or("not set"),
+                    expected_value
+                ),
+            ));
+        }
+    }
+    debug!(errors = report.errors().count(), "Verified provenance");
+    report
+}
+
+/// Reads the values of a SLSA provenance statement, failing with an `unsupported-predicate-type`
+/// error for other predicate types.
+fn build(statement: &Value) -> Result<Build<'_>, Finding> {
+    let predicate_type = statement
+        .get("predicateType")
+        .and_then(Value::as_str)
+        .map(normalize_predicate_type);
+    match predicate_type.as_deref() {
+        Some(SLSA_PROVENANCE_V1) => Ok(v1_build(statement)),
+        Some(SLSA_PROVENANCE_V02) => Ok(v02_build(statement)),
+        _ => Err(Finding::error(
+            "unsupported-predicate-type",
+            "/predicateType",
+            format!(
+                "Expected SLSA provenance, got {}",
+                predicate_type.as_deref().unwrap_or("no predicateType")
+            ),
+        )),
+    }
+}
+
+fn v1_build(statement: &Value) -> Build<'_> {
+    const EXTERNAL: &str = "/predicate/buildDefinition/externalParameters";
+    let mut build = Build {
+        builder_id: statement
+            .pointer("/predicate/runDetails/builder/id")
+            .and_then(Value::as_str)
+            .map(|id| (id, "/predicate/runDetails/builder/id")),
+        inputs: statement
+            .pointer(&format!("{}/inputs", EXTERNAL))
+            .and_then(Value::as_object)
+            .map(|inputs| {
+                (
+                    inputs,
+                    "/predicate/buildDefinition/externalParameters/inputs",
+                )
+            }),
+        ..Build::default()
+    };
+
+    // The GitHub Actions workflow build type records the repository and ref separately, while
+    // other builders record a git URI with the ref after an `@`.
+    let workflow = format!("{}/workflow", EXTERNAL);
+    if let Some(repository) = string_at(statement, &format!("{}/repository", workflow)) {
+        build.source = Some((repository.to_string(), format!("{}/repository", workflow)));
+        build.git_ref = string_at(statement, &format!("{}/ref", workflow))
+            .map(|git_ref| (git_ref.to_string(), format!("{}/ref", workflow)));
+        return build;
+    }
+    let uri_paths = [
+        format!("{}/source", EXTERNAL),
+        format!("{}/source/uri", EXTERNAL),
+        "/predicate/buildDefinition/resolvedDependencies/0/uri".to_string(),
+    ];
+    if let Some((uri, path)) = uri_paths
+        .into_iter()
+        .find_map(|path| string_at(statement, &path).map(|uri| (uri, path)))
+    {
+        set_git_uri(&mut build, uri, path);
+    }
+    build
+}
+
+fn v02_build(statement: &Value) -> Build<'_> {
+    let mut build = Build {
+        builder_id: statement
+            .pointer("/predicate/builder/id")
+            .and_then(Value::as_str)
+            .map(|id| (id, "/predicate/builder/id")),
+        inputs: statement
+            .pointer("/predicate/invocation/environment/github_event_payload/inputs")
+            .and_then(Value::as_object)
+            .map(|inputs| {
+                (
+                    inputs,
+                    "/predicate/invocation/environment/github_event_payload/inputs",
+                )
+            }),
+        ..Build::default()
+    };
+    let path = "/predicate/invocation/configSource/uri";
+    if let Some(uri) = string_at(statement, path) {
+        set_git_uri(&mut build, uri, path.to_string());
+    }
+    build
+}
+
+fn string_at<'a>(value: &'a Value, pointer: &str) -> Option<&'a str> {
+    value.pointer(pointer).and_then(Value::as_str)
+}
+
+/// Sets the source and ref from a git URI like `git+https://github.com/org/repo@refs/tags/v1`.
+fn set_git_uri(build: &mut Build, uri: &str, path: String) {
+    match uri.rsplit_once('@') {
+        Some((source, git_ref)) if git_ref.starts_with("refs/") => {
+            build.source = Some((source.to_string(), path.clone()));
+            build.git_ref = Some((git_ref.to_string(), path));
+        }
+        _ => build.source = Some((uri.to_string(), path)),
+    }
+}
+
+/// Reduces a repository URI to its host and path, e.g. `github.com/org/repo`.
+fn repository(uri: &str) -> &str {
+    let uri = uri.strip_prefix("git+").unwrap_or(uri);
+    let uri = uri.split_once("://").map_or(uri, |(_, rest)| rest);
+    let uri = uri.trim_end_matches('/');
+    uri.strip_suffix(".git").unwrap_or(uri)
+}
+
+/// Returns true if the builder id matches, ignoring its version if none is expected.
+fn builder_matches(id: &str, expected: &str) -> bool {
+    if expected.contains('@') {
+        id == expected
+    } else {
+        id.split_once('@').map_or(id, |(name, _)| name) == expected
+    }
+}
+
+/// Returns the refs the source must have been built at, qualified.
+fn expected_refs(expected: &ProvenanceExpectations) -> Vec<String> {
+    let tag = expected
+        .tag
+        .as_deref()
+        .map(|tag| qualify_ref("refs/tags/", tag));
+    let branch = expected
+        .branch
+        .as_deref()
+        .map(|branch| qualify_ref("refs/heads/", branch));
+    tag.into_iter().chain(branch).collect()
+}
+
+fn qualify_ref(prefix: &str, name: &str) -> String {
+    if name.starts_with("refs/") {
+        name.to_string()
+    } else {
+        format!("{}{}", prefix, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose, Engine};
+    use serde_json::json;
+
+    const BUILDER: &str =
+        "https://github.com/slsa-framework/slsa-github-generator/.github/workflows/builder_go_slsa3.yml";
+
+    fn v1() -> Value {
+        let fixture = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/slsa_provenance_v1.json"
+        ))
+        .unwrap();
+        serde_json::from_str(&fixture).unwrap()
+    }
+
+    fn v02() -> Value {
+        json!({
+            "_type": "https://in-toto.io/Statement/v0.1",
+            "predicateType": "https://slsa.dev/provenance/v0.2",
+            "subject": [{"name": "binary", "digest": {"sha256": "ab"}}],
+            "predicate": {
+                "builder": {"id": format!("{}@refs/tags/v1.9.0", BUILDER)},
+                "buildType": "https://github.com/slsa-framework/slsa-github-generator/go@v1",
+                "invocation": {
+                    "configSource": {
+                        "uri": "git+https://github.com/octocat/hello-world@refs/tags/v1.2.3",
+                        "entryPoint": ".github/workflows/release.yml"
+                    },
+                    "environment": {
+                        "github_event_payload": {"inputs": {"release": true}}
+                    }
+                }
+            }
+        })
+    }
+
+    fn codes(report: &ValidationReport) -> Vec<&str> {
+        report.findings.iter().map(|f| f.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_verify_provenance_v1() {
+        let expected = ProvenanceExpectations {
+            source_uri: "github.com/octocat/hello-world".to_string(),
+            builder_id: Some(BUILDER.to_string()),
+            branch: Some("main".to_string()),
+            workflow_inputs: BTreeMap::from([
+                ("build_id".to_string(), "123456768".to_string()),
+                ("deploy_target".to_string(), "deployment_sys_1a".to_string()),
+            ]),
+            ..ProvenanceExpectations::default()
+        };
+        let report = verify_provenance(&v1(), &expected);
+        assert!(report.findings.is_empty(), "{:?}", report);
+
+        let expected = ProvenanceExpectations {
+            source_uri: "https://github.com/octocat/other".to_string(),
+            builder_id: Some(format!("{}@refs/tags/v1.9.0", BUILDER)),
+            tag: Some("v1.2.3".to_string()),
+            workflow_inputs: BTreeMap::from([("deploy_target".to_string(), "prod".to_string())]),
+            ..ProvenanceExpectations::default()
+        };
+        let report = verify_provenance(&v1(), &expected);
+        assert_eq!(
+            codes(&report),
+            vec![
+                "source-mismatch",
+                "builder-mismatch",
+                "ref-mismatch",
+                "workflow-input-mismatch"
+            ]
+        );
+        assert_eq!(
+            report.findings[0].path,
+            "/predicate/buildDefinition/externalParameters/workflow/repository"
+        );
+        assert_eq!(
+            report.findings[3].path,
+            "/predicate/buildDefinition/externalParameters/inputs/deploy_target"
+        );
+    }
+
+    #[test]
+    fn test_verify_provenance_git_uri() {
+        let mut statement = v1();
+        statement["predicate"]["buildDefinition"]["externalParameters"] = json!({});
+        let expected = ProvenanceExpectations {
+            source_uri: "git+https://github.com/octocat/hello-world.git".to_string(),
+            branch: Some("refs/heads/main".to_string()),
+            ..ProvenanceExpectations::default()
+        };
+        assert!(verify_provenance(&statement, &expected).findings.is_empty());
+
+        let expected = ProvenanceExpectations {
+            workflow_inputs: BTreeMap::from([("release".to_string(), "true".to_string())]),
+            ..expected
+        };
+        let report = verify_provenance(&statement, &expected);
+        assert_eq!(codes(&report), vec!["workflow-input-mismatch"]);
+        assert_eq!(report.findings[0].path, "/predicate");
+    }
+
+    #[test]
+    fn test_verify_provenance_v02() {
+        let expected = ProvenanceExpectations {
+            source_uri: "github.com/octocat/hello-world".to_string(),
+            builder_id: Some(BUILDER.to_string()),
+            tag: Some("v1.2.3".to_string()),
+            workflow_inputs: BTreeMap::from([("release".to_string(), "true".to_string())]),
+            ..ProvenanceExpectations::default()
+        };
+        let report = verify_provenance(&v02(), &expected);
+        assert!(report.findings.is_empty(), "{:?}", report);
+
+        let mut statement = v02();
+        statement["predicate"]["invocation"] = json!({});
+        let report = verify_provenance(&statement, &expected);
+        assert_eq!(
+            codes(&report),
+            vec!["source-mismatch", "ref-mismatch", "workflow-input-mismatch"]
+        );
+    }
+
+    #[test]
+    fn test_verify_signed_provenance() {
+        let statement = v02();
+        let key = p256::ecdsa::SigningKey::from_bytes(&[7; 32].into()).unwrap();
+        let (envelope, body) = trust::signature::tests::signed_envelope(&key, &statement);
+        let body = general_purpose::STANDARD.decode(body).unwrap();
+        let (tlog, entry) = trust::inclusion::tests::logged_entry(&body);
+        let root = TrustedRoot {
+            tlogs: vec![tlog],
+            ..TrustedRoot::default()
+        };
+        let signed = SignedStatement {
+            statement,
+            key_ids: Vec::new(),
+            signatures: 1,
+            log_entries: vec![entry],
+            certificates: Vec::new(),
+            envelope: Some(envelope),
+        };
+        let sha256 = "ab";
+        let expected = ProvenanceExpectations {
+            source_uri: "github.com/octocat/hello-world".to_string(),
+            ..ProvenanceExpectations::default()
+        };
+        let pinned = [*key.verifying_key()];
+        let report = verify_signed_provenance(&root, &signed, sha256, &expected, &pinned);
+        assert!(report.findings.is_empty(), "{:?}", report);
+
+        let report = verify_signed_provenance(&root, &signed, "00", &expected, &pinned);
+        assert_eq!(codes(&report), vec!["subject-digest-mismatch"]);
+
+        // A key is only trusted if it is pinned, not because the log entry records it.
+        let report = verify_signed_provenance(&root, &signed, sha256, &expected, &[]);
+        assert_eq!(codes(&report), vec!["no-signing-key"]);
+
+        // Nothing else is checked until the signature verifies.
+        let mut forged = signed.clone();
+        forged.envelope.as_mut().unwrap().payload = b"{}".to_vec();
+        let expected = ProvenanceExpectations {
+            source_uri: "github.com/octocat/other".to_string(),
+            ..expected
+        };
+        let report = verify_signed_provenance(&root, &forged, "00", &expected, &pinned);
+        assert_eq!(codes(&report), vec!["invalid-signature"]);
+        let report =
+            verify_signed_provenance(&TrustedRoot::default(), &signed, sha256, &expected, &pinned);
+        assert_eq!(codes(&report), vec!["unknown-transparency-log"]);
+    }
+
+    /// Returns provenance signed with a Fulcio certificate issued to [`BUILDER`] building
+    /// octocat/hello-world at v1.2.3, logged while the certificate was valid, and a trusted root
+    /// with the certificate's authority.
+    fn keyless(statement: Value, ca_seed: u8) -> (SignedStatement, TrustedRoot) {
+        let key = p256::ecdsa::SigningKey::from_bytes(&[5; 32].into()).unwrap();
+        let (envelope, body) = trust::signature::tests::signed_envelope(&key, &statement);
+        let body = general_purpose::STANDARD.decode(body).unwrap();
+        let (tlog, mut entry) = trust::inclusion::tests::logged_entry(&body);
+        trust::inclusion::tests::sign_entry_timestamp(&mut entry, 1_900_000_000);
+        let (_, leaf, mut root) = trust::sct::tests::fixture_with_ca("leaf", ca_seed);
+        root.tlogs = vec![tlog];
+        let signed = SignedStatement {
+            statement,
+            key_ids: Vec::new(),
+            signatures: 1,
+            log_entries: vec![entry],
+            certificates: vec![general_purpose::STANDARD.encode(leaf)],
+            envelope: Some(envelope),
+        };
+        (signed, root)
+    }
+
+    #[test]
+    fn test_verify_keyless_provenance() {
+        let (signed, root) = keyless(v02(), 3);
+        let expected = ProvenanceExpectations {
+            source_uri: "github.com/octocat/hello-world".to_string(),
+            builder_id: Some(BUILDER.to_string()),
+            tag: Some("v1.2.3".to_string()),
+            ..ProvenanceExpectations::default()
+        };
+        let report = verify_signed_provenance(&root, &signed, "ab", &expected, &[]);
+        assert!(report.findings.is_empty(), "{:?}", report);
+
+        // A certificate from an authority the root doesn't have.
+        let (untrusted, _) = keyless(v02(), 9);
+        let report = verify_signed_provenance(&root, &untrusted, "ab", &expected, &[]);
+        assert_eq!(
+            codes(&report),
+            vec!["certificate-not-logged", "untrusted-certificate"]
+        );
+
+        // Provenance claiming another source and builder than the certificate was issued to.
+        let mut statement = v02();
+        statement["predicate"]["builder"]["id"] = json!("https://example.com/builder@v1");
+        statement["predicate"]["invocation"]["configSource"]["uri"] =
+            json!("git+https://github.com/octocat/other@refs/tags/v2.0.0");
+        let (signed, root) = keyless(statement, 3);
+        let expected = ProvenanceExpectations {
+            source_uri: "github.com/octocat/other".to_string(),
+            builder_id: Some("https://example.com/builder".to_string()),
+            tag: Some("v2.0.0".to_string()),
+            ..ProvenanceExpectations::default()
+        };
+        let report = verify_signed_provenance(&root, &signed, "ab", &expected, &[]);
+        let findings = report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            vec![
+                ("source-mismatch", "/0/certificates/0"),
+                ("builder-mismatch", "/0/certificates/0"),
+                ("builder-mismatch", "/predicate/builder/id"),
+                ("ref-mismatch", "/0/certificates/0"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_other_predicate() {
+        let mut statement = v1();
+        statement["predicateType"] = json!("https://spdx.dev/Document");
+        let report = verify_provenance(&statement, &ProvenanceExpectations::default());
+        assert_eq!(codes(&report), vec!["unsupported-predicate-type"]);
+    }
+}
@@ -0,0 +1,422 @@
+//! Walking the artifacts, digests and URIs of documents.
+//!
+//! Analyses such as building an inventory, checking policies or rewriting references need the
+//! same parts of every document, but those parts live in different places in each predicate and
+//! SBOM format. The walker knows where they are in In-Toto statements, with SLSA provenance,
+//! SCAI and the other predicates that refer to resource descriptors, and in SPDX documents, and
+//! hands them to a [`Visitor`] with their JSON pointers, so an analysis only implements the
+//! callbacks it needs.
+//!
+//! Documents are walked as JSON values, so they don't need to be valid, and parts that don't
+//! have the expected shape are skipped. [`walk_mut`] hands out mutable references for rewriting
+//! documents in place.
+
+use serde_json::Value;
+
+use crate::models::intoto::predicate::{
+    normalize_predicate_type, APKO_BUILD_V1, CODE_REVIEW_V01, DEPENDENCY_REVIEW_V01,
+    LICENSE_SCAN_V01, MELANGE_BUILD_V1, REFERENCE_V01, SCAI_ATTRIBUTE_REPORT, SCAI_V03,
+    SLSA_PROVENANCE_V02, SLSA_PROVENANCE_V1,
+};
+use crate::validate::report::join_pointer;
+
+/// SPDX values standing for the absence of a URI.
+const SPDX_NO_URI: [&str; 2] = ["NONE", "NOASSERTION"];
+
+/// Callbacks for the parts of a document. Every method does nothing by default.
+///
+/// Digests and URIs are visited after the subject, resource descriptor or package containing
+/// them, and every callback gets the JSON pointer to the part it is called with.
+#[allow(unused_variables)]
+pub trait Visitor {
+    /// Called with each subject of a statement.
+    fn visit_subject(&mut self, path: &str, subject: &Value) {}
+    /// Called with the predicate of a statement and its `predicateType`.
+    fn visit_predicate(&mut self, path: &str, predicate_type: &str, predicate: &Value) {}
+    /// Called with each resource descriptor of a predicate, such as a provenance dependency.
+    fn visit_resource_descriptor(&mut self, path: &str, descriptor: &Value) {}
+    /// Called with each package of an SBOM.
+    fn visit_package(&mut self, path: &str, package: &Value) {}
+    /// Called with each digest, with the algorithm as written in the document, e.g. `sha256` or
+    /// SPDX's `SHA256`.
+    fn visit_digest(&mut self, path: &str, algorithm: &str, digest: &str) {}
+    /// Called with each URI, such as a dependency's `uri`, a builder id or a package's
+    /// `downloadLocation`.
+    fn visit_uri(&mut self, path: &str, uri: &str) {}
+}
+
+/// Callbacks for rewriting the parts of a document. Every method does nothing by default.
+///
+/// The parts are visited in the same order as with [`Visitor`]. Parts removed or moved by an
+/// earlier callback are skipped.
+#[allow(unused_variables)]
+pub trait VisitorMut {
+    /// Called with each subject of a statement.
+    fn visit_subject(&mut self, path: &str, subject: &mut Value) {}
+    /// Called with the predicate of a statement and its `predicateType`.
+    fn visit_predicate(&mut self, path: &str, predicate_type: &str, predicate: &mut Value) {}
+    /// Called with each resource descriptor of a predicate, such as a provenance dependency.
+    fn visit_resource_descriptor(&mut self, path: &str, descriptor: &mut Value) {}
+    /// Called with each package of an SBOM.
+    fn visit_package(&mut self, path: &str, package: &mut Value) {}
+    /// Called with each digest, with the algorithm as written in the document.
+    fn visit_digest(&mut self, path: &str, algorithm: &str, digest: &mut String) {}
+    /// Called with each URI.
+    fn visit_uri(&mut self, path: &str, uri: &mut String) {}
+}
+
+/// Walks a statement or SBOM, calling the visitor for each of its parts.
+pub fn walk<V: Visitor + ?Sized>(document: &Value, visitor: &mut V) {
+    for (path, part) in parts(document) {
+        let Some(value) = document.pointer(&path) else {
+            continue;
+        };
+        match (part, value) {
+            (Part::Subject, _) => visitor.visit_subject(&path, value),
+            (Part::Predicate(predicate_type), _) => {
+                visitor.visit_predicate(&path, &predicate_type, value)
+            }
+            (Part::ResourceDescriptor, _) => visitor.visit_resource_descriptor(&path, value),
+            (Part::Package, _) => visitor.visit_package(&path, value),
+            (Part::Digest(algorithm), Value::String(digest)) => {
+                visitor.visit_digest(&path, &algorithm, digest)
+            }
+            (Part::Uri, Value::String(uri)) => visitor.visit_uri(&path, uri),
+            _ => {}
+        }
+    }
+}
+
+/// Walks a statement or SBOM, calling the visitor with mutable references to each of its parts.
+pub fn walk_mut<V: VisitorMut + ?Sized>(document: &mut Value, visitor: &mut V) {
+    for (path, part) in parts(document) {
+        let Some(value) = document.pointer_mut(&path) else {
+            continue;
+        };
+        match (part, value) {
+            (Part::Subject, value) => visitor.visit_subject(&path, value),
+            (Part::Predicate(predicate_type), value) => {
+                visitor.visit_predicate(&path, &predicate_type, value)
+            }
+            (Part::ResourceDescriptor, value) => visitor.visit_resource_descriptor(&path, value),
+            (Part::Package, value) => visitor.visit_package(&path, value),
+            (Part::Digest(algorithm), Value::String(digest)) => {
+                visitor.visit_digest(&path, &algorithm, digest)
+            }
+            (Part::Uri, Value::String(uri)) => visitor.visit_uri(&path, uri),
+            _ => {}
+        }
+    }
+}
+
+/// A kind of part a visitor is called for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Subject,
+    Predicate(String),
+    ResourceDescriptor,
+    Package,
+    Digest(String),
+    Uri,
+}
+
+/// Returns the pointers to the parts of a document, in the order they are visited.
+///
+/// The parts are found before any is visited, so that the mutable walk can hand out one part at
+/// a time.
+fn parts(document: &Value) -> Vec<(String, Part)> {
+    let mut parts = Vec::new();
+    if document.get("spdxVersion").is_some() {
+        spdx_parts(document, &mut parts);
+    } else if document.get("_type").is_some() {
+        statement_parts(document, &mut parts);
+    }
+    parts
+}
+
+fn statement_parts(statement: &Value, parts: &mut Vec<(String, Part)>) {
+    for (i, subject) in elements(statement.get("subject")) {
+        let path = format!("/subject/{}", i);
+        parts.push((path.clone(), Part::Subject));
+        digest_parts(subject.get("digest"), &format!("{}/digest", path), parts);
+    }
+
+    let Some(predicate) = statement.get("predicate") else {
+        return;
+    };
+    let predicate_type = statement
+        .get("predicateType")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    parts.push((
+        "/predicate".to_string(),
+        Part::Predicate(predicate_type.to_string()),
+    ));
+
+    let (uris, descriptors): (&[&str], &[&str]) =
+        match normalize_predicate_type(predicate_type).as_str() {
+            SLSA_PROVENANCE_V1 => (
+                &["/buildDefinition/buildType", "/runDetails/builder/id"],
+                &[
+                    "/buildDefinition/resolvedDependencies",
+                    "/runDetails/builder/builderDependencies",
+                    "/runDetails/byproducts",
+                ],
+            ),
+            SLSA_PROVENANCE_V02 => (
+                &["/buildType", "/builder/id"],
+                &["/invocation/configSource", "/materials"],
+            ),
+            SCAI_ATTRIBUTE_REPORT | SCAI_V03 => (&[], &["/attributes", "/producer"]),
+            APKO_BUILD_V1 | MELANGE_BUILD_V1 => (&[], &["/sboms"]),
+            REFERENCE_V01 => (&["/attester/id"], &["/references"]),
+            LICENSE_SCAN_V01 => (&["/scanner/uri"], &["/target"]),
+            CODE_REVIEW_V01 => (&["/changeUri", "/commitRange/repository"], &[]),
+            DEPENDENCY_REVIEW_V01 => (&[], &["/base", "/head"]),
+            _ => (&[], &[]),
+        };
+    for pointer in uris {
+        if predicate.pointer(pointer).is_some() {
+            parts.push((format!("/predicate{}", pointer), Part::Uri));
+        }
+    }
+    for pointer in descriptors {
+        let path = format!("/predicate{}", pointer);
+        match predicate.pointer(pointer) {
+            // SCAI attributes aren't descriptors themselves, but refer to them.
+            Some(Value::Array(attributes)) if *pointer == "/attributes" => {
+                for (i, attribute) in attributes.iter().enumerate() {
+                    for field in ["target", "evidence"] {
+                        if let Some(descriptor) = attribute.get(field) {
+                            let path = format!("{}/{}/{}", path, i, field);
+                            descriptor_parts(descriptor, &path, parts);
+                        }
+                    }
+                }
+            }
+            Some(Value::Array(descriptors)) => {
+                for (i, descriptor) in descriptors.iter().enumerate() {
+                    descriptor_parts(descriptor, &format!("{}/{}", path, i), parts);
+                }
+            }
+            Some(descriptor) => descriptor_parts(descriptor, &path, parts),
+            None => {}
+        }
+    }
+}
+
+fn descriptor_parts(descriptor: &Value, path: &str, parts: &mut Vec<(String, Part)>) {
+    if !descriptor.is_object() {
+        return;
+    }
+    parts.push((path.to_string(), Part::ResourceDescriptor));
+    digest_parts(descriptor.get("digest"), &format!("{}/digest", path), parts);
+    for field in ["uri", "downloadLocation"] {
+        if descriptor.get(field).is_some() {
+            parts.push((format!("{}/{}", path, field), Part::Uri));
+        }
+    }
+}
+
+/// Adds the digests of a DigestSet, in the order of their algorithms.
+fn digest_parts(digests: Option<&Value>, path: &str, parts: &mut Vec<(String, Part)>) {
+    if let Some(digests) = digests.and_then(Value::as_object) {
+        for algorithm in digests.keys() {
+            parts.push((
+                join_pointer(path, algorithm),
+                Part::Digest(algorithm.clone()),
+            ));
+        }
+    }
+}
+
+fn spdx_parts(document: &Value, parts: &mut Vec<(String, Part)>) {
+    if document.get("documentNamespace").is_some() {
+        parts.push(("/documentNamespace".to_string(), Part::Uri));
+    }
+    for (i, package) in elements(document.get("packages")) {
+        let path = format!("/packages/{}", i);
+        parts.push((path.clone(), Part::Package));
+        checksum_parts(package, &path, parts);
+        for field in ["downloadLocation", "homepage"] {
+            let uri = package.get(field).and_then(Value::as_str);
+            if uri.is_some_and(|uri| !SPDX_NO_URI.contains(&uri)) {
+                parts.push((format!("{}/{}", path, field), Part::Uri));
+            }
+        }
+        for (j, external_ref) in elements(package.get("externalRefs")) {
+            if external_ref.get("referenceType").and_then(Value::as_str) == Some("purl") {
+                parts.push((
+                    format!("{}/externalRefs/{}/referenceLocator", path, j),
+                    Part::Uri,
+                ));
+            }
+        }
+    }
+    for (i, file) in elements(document.get("files")) {
+        checksum_parts(file, &format!("/files/{}", i), parts);
+    }
+}
+
+/// Adds the values of SPDX `checksums` as digests.
+fn checksum_parts(element: &Value, path: &str, parts: &mut Vec<(String, Part)>) {
+    for (i, checksum) in elements(element.get("checksums")) {
+        if let Some(algorithm) = checksum.get("algorithm").and_then(Value::as_str) {
+            parts.push((
+                format!("{}/checksums/{}/checksumValue", path, i),
+                Part::Digest(algorithm.to_string()),
+            ));
+        }
+    }
+}
+
+/// Returns the elements of an array with their indices, or none if the value isn't an array.
+fn elements(value: Option<&Value>) -> impl Iterator<Item = (usize, &Value)> {
+    value
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fixture(name: &str) -> Value {
+        let fixture = std::fs::read_to_string(format!(
+            "{}/tests/fixtures/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        ))
+        .unwrap();
+        serde_json::from_str(&fixture).unwrap()
+    }
+
+    /// Records every callback as a line.
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl Visitor for Recorder {
+        fn visit_subject(&mut self, path: &str, _: &Value) {
+            self.0.push(format!("subject {}", path));
+        }
+        fn visit_predicate(&mut self, path: &str, predicate_type: &str, _: &Value) {
+            self.0
+                .push(format!("predicate {} {}", path, predicate_type));
+        }
+        fn visit_resource_descriptor(&mut self, path: &str, _: &Value) {
+            self.0.push(format!("descriptor {}", path));
+        }
+        fn visit_package(&mut self, path: &str, _: &Value) {
+            self.0.push(format!("package {}", path));
+        }
+        fn visit_digest(&mut self, path: &str, algorithm: &str, digest: &str) {
+            self.0
+                .push(format!("digest {} {}:{}", path, algorithm, &digest[..7]));
+        }
+        fn visit_uri(&mut self, path: &str, uri: &str) {
+            self.0.push(format!("uri {} {}", path, uri));
+        }
+    }
+
+    #[test]
+    fn test_walk_provenance() {
+        let mut recorder = Recorder::default();
+        walk(&fixture("slsa_provenance_v1.json"), &mut recorder);
+        assert_eq!(
+            recorder.0,
+            vec![
+                "subject /subject/0",
+                "digest /subject/0/digest/sha256 sha256:fe4fe40",
+                "predicate /predicate https://slsa.dev/provenance/v1",
+                "uri /predicate/buildDefinition/buildType https://slsa-framework.github.io/github-actions-buildtypes/workflow/v1",
+                "uri /predicate/runDetails/builder/id https://github.com/slsa-framework/slsa-github-generator/.github/workflows/builder_go_slsa3.yml@refs/tags/v0.0.1",
+                "descriptor /predicate/buildDefinition/resolvedDependencies/0",
+                "digest /predicate/buildDefinition/resolvedDependencies/0/digest/gitCommit gitCommit:c27d339",
+                "uri /predicate/buildDefinition/resolvedDependencies/0/uri git+https://github.com/octocat/hello-world@refs/heads/main",
+                "descriptor /predicate/buildDefinition/resolvedDependencies/1",
+                "uri /predicate/buildDefinition/resolvedDependencies/1/uri https://github.com/actions/virtual-environments/releases/tag/ubuntu20/20220515.1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_spdx_and_scai() {
+        let mut sbom = fixture("spdx_v23_sbom.json");
+        sbom["packages"][0]["checksums"] =
+            json!([{"algorithm": "SHA256", "checksumValue": "abcdef0123456789"}]);
+        sbom["packages"][1]["downloadLocation"] = json!("NOASSERTION");
+        let mut recorder = Recorder::default();
+        walk(&sbom, &mut recorder);
+        assert!(recorder
+            .0
+            .contains(&"digest /packages/0/checksums/0/checksumValue SHA256:abcdef0".to_string()));
+        assert!(recorder.0.contains(
+            &"uri /packages/0/externalRefs/0/referenceLocator pkg:npm/lodash@4.17.20".to_string()
+        ));
+        assert!(!recorder.0.iter().any(|line| line.contains("NOASSERTION")));
+
+        let scai = json!({
+            "_type": "https://in-toto.io/Statement/v1",
+            "subject": [],
+            "predicateType": SCAI_ATTRIBUTE_REPORT,
+            "predicate": {
+                "attributes": [{"attribute": "A", "evidence": {"uri": "https://example.com/evidence"}}]
+            }
+        });
+        let mut recorder = Recorder::default();
+        walk(&scai, &mut recorder);
+        assert_eq!(
+            recorder.0[1..],
+            [
+                "descriptor /predicate/attributes/0/evidence",
+                "uri /predicate/attributes/0/evidence/uri https://example.com/evidence",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_reference() {
+        let mut recorder = Recorder::default();
+        walk(&fixture("reference_v01.json"), &mut recorder);
+        assert_eq!(
+            recorder.0[2..],
+            [
+                "predicate /predicate https://in-toto.io/attestation/reference/v0.1",
+                "uri /predicate/attester/id https://github.com/actions/attest-sbom",
+                "descriptor /predicate/references/0",
+                "digest /predicate/references/0/digest/sha256 sha256:7d865e9",
+                "uri /predicate/references/0/downloadLocation https://example.com/releases/hello-world-1.2.3.spdx.json",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_mut() {
+        struct Lowercase;
+
+        impl VisitorMut for Lowercase {
+            fn visit_digest(&mut self, _: &str, _: &str, digest: &mut String) {
+                *digest = digest.to_lowercase();
+            }
+            fn visit_resource_descriptor(&mut self, _: &str, descriptor: &mut Value) {
+                // Removed parts are skipped rather than visited.
+                descriptor.as_object_mut().unwrap().remove("uri");
+            }
+            fn visit_uri(&mut self, path: &str, _: &mut String) {
+                assert!(!path.ends_with("/uri"));
+            }
+        }
+
+        let mut statement = fixture("slsa_provenance_v1.json");
+        statement["subject"][0]["digest"]["sha256"] = json!("ABCDEF");
+        walk_mut(&mut statement, &mut Lowercase);
+        assert_eq!(statement["subject"][0]["digest"]["sha256"], "abcdef");
+        assert!(statement
+            .pointer("/predicate/buildDefinition/resolvedDependencies/0/uri")
+            .is_none());
+    }
+}
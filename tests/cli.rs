@@ -1,5 +1,6 @@
 use assert_cmd::Command;
 use predicates::prelude::*;
+use spector::test_server::{serve, Response};
 use std::path::PathBuf;
 
 fn fixture_path(name: &str) -> PathBuf {
@@ -14,7 +15,7 @@ fn test_valid_slsa_provenance_v1_document() {
     let mut cmd = Command::cargo_bin("spector").unwrap();
     let fixture = fixture_path("slsa_provenance_v1.json");
 
-    cmd.args(&[
+    cmd.args([
         "validate",
         "in-toto-v1",
         "--file",
@@ -27,12 +28,289 @@ fn test_valid_slsa_provenance_v1_document() {
     ));
 }
 
+#[test]
+fn test_valid_apko_build_v1_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("apko_build_v1.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "apko-build-v1",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 ApkoBuildV1 document",
+    ));
+}
+
+#[test]
+fn test_valid_jvm_build_info_v1_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("jvm_build_info_v1.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "jvm-build-info-v1",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stderr(predicate::str::contains(
+        "warning[unpinned-version] /predicate/modules/0/dependencies/1/id",
+    ))
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 JvmBuildInfoV1 document",
+    ));
+}
+
+#[test]
+fn test_valid_slsa_verification_summary_v1_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("slsa_verification_summary_v1.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "slsa-verification-summary-v1",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 SLSAVerificationSummaryV1 document",
+    ));
+}
+
+#[test]
+fn test_valid_link_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("in_toto_link_v1.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "link",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Valid InTotoV1 Link document"));
+}
+
+#[test]
+fn test_valid_spdx_document_attestation() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("spdx_v23_sbom_attestation.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "spdx-document",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 SpdxDocument document",
+    ));
+}
+
+#[test]
+fn test_valid_cyclonedx_bom_attestation() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("cyclonedx_bom_attestation.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "cyclonedx-bom",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 CycloneDX document",
+    ));
+}
+
+#[test]
+fn test_valid_test_result_v01_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("test_result_v01.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "test-result-v01",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 TestResultV01 document",
+    ));
+}
+
+#[test]
+fn test_valid_sarif_v21_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("sarif_v21.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "sarif-v21",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Valid InTotoV1 SarifV21 document"));
+}
+
+#[test]
+fn test_valid_csaf_v2_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("csaf_vex_v2.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "csaf-v2",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Valid InTotoV1 CsafV2 document"));
+}
+
+#[test]
+fn test_valid_scai_v03_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("scai_v03.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "scaiv03-predicate",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 SCAIV03Predicate document",
+    ));
+}
+
+#[test]
+fn test_valid_reference_v01_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("reference_v01.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "reference-v01",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 ReferenceV01 document",
+    ));
+}
+
+#[test]
+fn test_valid_license_scan_v01_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("license_scan_v01.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "license-scan-v01",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 LicenseScanV01 document",
+    ));
+}
+
+#[test]
+fn test_valid_code_review_v01_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("code_review_v01.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "code-review-v01",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 CodeReviewV01 document",
+    ));
+}
+
+#[test]
+fn test_valid_dependency_review_v01_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("dependency_review_v01.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "dependency-review-v01",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 DependencyReviewV01 document",
+    ));
+}
+
 #[test]
 fn test_invalid_slsa_provenance_v1_document() {
     let mut cmd = Command::cargo_bin("spector").unwrap();
     let fixture = fixture_path("slsa_provenance_v1_invalid.json");
 
-    cmd.args(&[
+    cmd.args([
         "validate",
         "in-toto-v1",
         "--file",
@@ -50,7 +328,7 @@ fn test_invalid_predicate_slsa_provenance_v1_document() {
     let mut cmd = Command::cargo_bin("spector").unwrap();
     let fixture = fixture_path("slsa_provenance_v1_invalid_predicate.json");
 
-    cmd.args(&[
+    cmd.args([
         "validate",
         "in-toto-v1",
         "--predicate",
@@ -66,34 +344,2178 @@ fn test_invalid_predicate_slsa_provenance_v1_document() {
 }
 
 #[test]
-fn test_generate_in_toto_v1_schema() {
+fn test_mismatched_predicate_slsa_provenance_v1_document() {
     let mut cmd = Command::cargo_bin("spector").unwrap();
-    let fixture = std::fs::read_to_string(fixture_path("in_toto_v1_schema.json")).unwrap();
+    let fixture = fixture_path("slsa_provenance_v1_mismatched_predicate.json");
 
-    cmd.args(&["schema-generate", "in-toto-v1"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains(fixture));
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[predicate-type-mismatch] /predicateType: predicate looks like https://slsa.dev/provenance/v0.2",
+    ));
 }
 
 #[test]
-fn test_generate_slsa_provenance_v1_schema() {
+fn test_invalid_statement_type_in_toto_v1_document() {
     let mut cmd = Command::cargo_bin("spector").unwrap();
-    let fixture = std::fs::read_to_string(fixture_path("slsa_provenance_v1_schema.json")).unwrap();
+    let fixture = fixture_path("slsa_provenance_v1_invalid_predicate.json");
 
-    cmd.args(&["schema-generate", "in-toto-v1", "--predicate", "slsa-provenance-v1"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains(fixture));
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--statement-type",
+        "v1",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "unexpected _type https://in-toto.io/, expected https://in-toto.io/Statement/v1",
+    ));
 }
 
 #[test]
-fn test_generate_rust_code() {
+fn test_empty_predicate_in_toto_v1_document() {
     let mut cmd = Command::cargo_bin("spector").unwrap();
-    let fixture = std::fs::read_to_string(fixture_path("in_toto_v1.rs")).unwrap();
+    let fixture = fixture_path("in_toto_v1_empty_predicate.json");
 
-    cmd.args(&["code-generate", "json-schema", "--file", "tests/fixtures/in_toto_v1_schema.json"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains(fixture));
-}
\ No newline at end of file
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success();
+}
+
+#[test]
+fn test_empty_predicate_in_toto_v1_document_strict() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("in_toto_v1_empty_predicate.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--strict",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "predicate must not be null or missing in strict mode",
+    ));
+}
+
+#[test]
+fn test_wildcard_subject_strict() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("slsa_provenance_v1.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--strict",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("error[wildcard-subject]"));
+}
+
+#[test]
+fn test_wildcard_subject_strict_allowed_by_policy() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("slsa_provenance_v1.json");
+    let policy = fixture_path("policy_allow_wildcard.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--strict",
+        "--policy",
+        policy.to_str().unwrap(),
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success();
+}
+
+#[test]
+fn test_misspelled_property_strict() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let document = std::fs::read_to_string(fixture_path("slsa_provenance_v1.json"))
+        .unwrap()
+        .replace("\"buildType\"", "\"buildtype\"");
+    let file = std::env::temp_dir().join(format!(
+        "spector-cli-misspelled-{}.json",
+        std::process::id()
+    ));
+    std::fs::write(&file, document).unwrap();
+
+    cmd.args(["validate", "in-toto-v1", "--strict", "--file", file.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error[unknown-property] /predicate/buildDefinition/buildtype: unknown property `buildtype`, did you mean `buildType` instead of `buildtype`?"));
+    std::fs::remove_file(&file).unwrap();
+}
+
+#[test]
+fn test_validate_cdxa() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["validate", "cdxa", "--file", "tests/fixtures/cdxa.json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Valid CycloneDX attestation document",
+        ));
+}
+
+#[test]
+fn test_validate_cdxa_unknown_evidence() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let document = std::fs::read_to_string(fixture_path("cdxa.json"))
+        .unwrap()
+        .replace("\"evidence-1\"\n        ]", "\"evidence-2\"\n        ]");
+    let file = std::env::temp_dir().join(format!("spector-cli-cdxa-{}.json", std::process::id()));
+    std::fs::write(&file, document).unwrap();
+
+    cmd.args(["validate", "cdxa", "--file", file.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error[unknown-evidence] /declarations/claims/0/evidence/0: evidence evidence-2 is not in the document"))
+        .stderr(predicate::str::contains("Document failed 1 semantic check(s)"));
+    std::fs::remove_file(&file).unwrap();
+}
+
+#[test]
+fn test_validate_auto() {
+    for (fixture, format) in [
+        ("slsa_provenance_v1.json", "Valid In-Toto v1 statement"),
+        (
+            "slsa_provenance_v1_release.sigstore.json",
+            "Valid Sigstore bundle",
+        ),
+        ("spdx_v23_sbom.json", "Valid SPDX 2.3 document"),
+        ("cdxa.json", "Valid CycloneDX attestation"),
+    ] {
+        let mut cmd = Command::cargo_bin("spector").unwrap();
+        cmd.args([
+            "validate",
+            "--auto",
+            "--file",
+            fixture_path(fixture).to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format));
+    }
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "validate",
+        "--auto",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1_invalid.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "Error: In-Toto v1 statement failed",
+    ));
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "validate",
+        "--auto",
+        "--file",
+        "tests/fixtures/policy_allow_wildcard.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "Error: Unable to detect the document format",
+    ));
+}
+
+#[test]
+fn test_validate_unknown_plugin() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "validate",
+        "plugin",
+        "acme-build-record",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "Error: No plugin named acme-build-record",
+    ));
+}
+
+#[test]
+fn test_explain() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let document = std::fs::read_to_string(fixture_path("slsa_provenance_v1.json"))
+        .unwrap()
+        .replace("\"buildType\"", "\"buildtype\"");
+    let file =
+        std::env::temp_dir().join(format!("spector-cli-explain-{}.json", std::process::id()));
+    std::fs::write(&file, document).unwrap();
+
+    cmd.args(["explain", "--file", file.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("error[schema-violation] /predicate/buildDefinition: \"buildType\" is a required property"))
+        .stdout(predicate::str::contains("  |   \"buildtype\": \"https://slsa-framework.github.io/github-actions-buildtypes/workflow/v1\","))
+        .stdout(predicate::str::contains("  = constraint: required: [\"buildType\",\"externalParameters\"]"))
+        .stdout(predicate::str::contains("  = fix: Did you mean `buildType` instead of `buildtype`?"))
+        .stdout(predicate::str::contains("In-Toto v1 statement: 2 error(s), 0 warning(s)"));
+    std::fs::remove_file(&file).unwrap();
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args(["explain", "--file", "tests/fixtures/cdxa.json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "No findings for the CycloneDX attestation",
+        ));
+}
+
+#[test]
+fn test_generate_in_toto_v1_schema() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = std::fs::read_to_string(fixture_path("in_toto_v1_schema.json")).unwrap();
+
+    cmd.args(["schema-generate", "in-toto-v1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(fixture));
+}
+
+#[test]
+fn test_generate_slsa_provenance_v1_schema() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = std::fs::read_to_string(fixture_path("slsa_provenance_v1_schema.json")).unwrap();
+
+    cmd.args([
+        "schema-generate",
+        "in-toto-v1",
+        "--predicate",
+        "slsa-provenance-v1",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(fixture));
+}
+
+#[test]
+fn test_generate_rust_code() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = std::fs::read_to_string(fixture_path("in_toto_v1.rs")).unwrap();
+
+    cmd.args([
+        "code-generate",
+        "json-schema",
+        "--file",
+        "tests/fixtures/in_toto_v1_schema.json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(fixture));
+}
+#[test]
+fn test_generate_typescript_code() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "code-generate",
+        "json-schema",
+        "--file",
+        "tests/fixtures/in_toto_v1_schema.json",
+        "--lang",
+        "typescript",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "export interface InTotoStatementV1ForPredicate {",
+    ));
+}
+
+#[test]
+fn test_generate_zod_code() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "code-generate",
+        "json-schema",
+        "--file",
+        "tests/fixtures/in_toto_v1_schema.json",
+        "--lang",
+        "zod",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "export const InTotoStatementV1ForPredicateSchema = z.object({",
+    ));
+}
+
+#[test]
+fn test_generate_python_code() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "code-generate",
+        "json-schema",
+        "--file",
+        "tests/fixtures/in_toto_v1_schema.json",
+        "--lang",
+        "python",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "class InTotoStatementV1ForPredicate(BaseModel):",
+    ));
+}
+
+#[test]
+fn test_generate_rust_code_to_directory() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let out_dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("codegen_out_dir");
+    let _ = std::fs::remove_dir_all(&out_dir);
+
+    cmd.args([
+        "code-generate",
+        "json-schema",
+        "--file",
+        "tests/fixtures/in_toto_v1_schema.json",
+        "--out-dir",
+    ])
+    .arg(&out_dir)
+    .args(["--crate-name", "in-toto-types"])
+    .assert()
+    .success();
+
+    let lib = std::fs::read_to_string(out_dir.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("pub use in_toto_statement_v1_for_predicate::*;"));
+    assert!(out_dir.join("src/builder.rs").exists());
+    assert!(out_dir.join("Cargo.toml").exists());
+}
+
+#[test]
+fn test_schema_validate_with_embedded_schema() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "schema-validate",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1.json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid document based on JSON schema",
+    ));
+}
+
+#[test]
+fn test_schema_validate_without_embedded_schema() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "schema-validate",
+        "--file",
+        "tests/fixtures/in_toto_v1_empty_predicate.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "No embedded schema for predicateType https://example.com/predicate/v1",
+    ));
+}
+
+#[test]
+fn test_schema_validate_unreachable_url() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let cache_dir =
+        std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("schema_validate_url_cache");
+
+    cmd.args([
+        "schema-validate",
+        "http://127.0.0.1:1/schema.json",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1.json",
+        "--cache-dir",
+    ])
+    .arg(&cache_dir)
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "Failed to fetch schema from http://127.0.0.1:1/schema.json",
+    ));
+}
+
+#[test]
+fn test_schema_validate_sha256_requires_url() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "schema-validate",
+        "tests/fixtures/invalid_schema.json",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1.json",
+        "--sha256",
+        "00",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "--sha256 can only be used with a schema URL",
+    ));
+}
+
+#[test]
+fn test_generate_all_schemas() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let out_dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("schema_generate_all");
+    let _ = std::fs::remove_dir_all(&out_dir);
+
+    cmd.args(["schema-generate", "all", "--out-dir"])
+        .arg(&out_dir)
+        .assert()
+        .success();
+
+    let schema = std::fs::read_to_string(out_dir.join("slsa-provenance-v1.json")).unwrap();
+    assert!(schema.contains("\"title\": \"InTotoStatementV1_for_SLSAProvenanceV1Predicate\""));
+    assert!(out_dir.join("in-toto-v1.json").exists());
+    assert!(out_dir.join("spdx-v23.json").exists());
+}
+
+#[test]
+fn test_generate_spdx_schemas() {
+    for (subcommand, title) in [("spdxv23", "Spdx23"), ("spdxv22", "Spdx22Document")] {
+        let mut cmd = Command::cargo_bin("spector").unwrap();
+
+        cmd.args(["schema-generate", subcommand])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(format!(
+                "\"title\": \"{}\"",
+                title
+            )))
+            .stdout(predicate::str::contains("\"creationInfo\""));
+    }
+}
+
+#[test]
+fn test_generate_rust_code_from_protobuf() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "code-generate",
+        "protobuf",
+        "--file",
+        "tests/fixtures/in_toto_v1_statement.binpb",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("pub struct Statement {"))
+    .stdout(predicate::str::contains("#[serde(rename = \"_type\""))
+    .stdout(predicate::str::contains(
+        "pub digest: std::collections::HashMap<String, String>",
+    ));
+}
+
+#[test]
+fn test_generate_openapi() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["schema-generate", "openapi"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"openapi\": \"3.1.0\""))
+        .stdout(predicate::str::contains(
+            "\"$ref\": \"#/components/schemas/Finding\"",
+        ));
+}
+
+#[test]
+fn test_schema_validate_invalid_schema() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "schema-validate",
+        "tests/fixtures/invalid_schema.json",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[invalid-schema] /properties/name/type:",
+    ))
+    .stderr(predicate::str::contains("error[invalid-schema] /required:"));
+}
+
+#[test]
+fn test_schema_sample() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    let output = cmd
+        .args([
+            "schema",
+            "sample",
+            "--file",
+            "tests/fixtures/in_toto_v1_schema.json",
+            "--seed",
+            "3",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let document: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert!(document["_type"].is_string());
+    assert!(document["subject"].is_array());
+}
+
+#[test]
+fn test_generate_rust_code_with_typify_options() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "code-generate",
+        "json-schema",
+        "--file",
+        "tests/fixtures/in_toto_v1_schema.json",
+    ])
+    .args([
+        "--derive",
+        "PartialEq",
+        "--no-builder",
+        "--deny-unknown-fields",
+    ])
+    .args(["--rename", "InTotoStatementV1ForPredicate=Statement"])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("pub struct Statement {"))
+    .stdout(predicate::str::contains("#[serde(deny_unknown_fields)]"))
+    .stdout(predicate::str::contains("pub mod builder").not());
+}
+
+#[test]
+fn test_generate_rust_code_invalid_rename() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "code-generate",
+        "json-schema",
+        "--file",
+        "tests/fixtures/in_toto_v1_schema.json",
+        "--rename",
+        "Statement",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("expected OldName=NewName"));
+}
+
+#[test]
+fn test_generate_rust_code_with_round_trip_tests() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "code-generate",
+        "json-schema",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1_schema.json",
+        "--round-trip-tests",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "#[cfg(test)]\nmod round_trip_tests {",
+    ))
+    .stdout(predicate::str::contains("fn test_round_trip_samples() {"));
+}
+
+#[test]
+fn test_schema_compat_compatible() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "schema",
+        "compat",
+        "tests/fixtures/in_toto_v1_schema.json",
+        "tests/fixtures/in_toto_v1_schema.json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Schema changes are backward-compatible",
+    ));
+}
+
+#[test]
+fn test_schema_compat_breaking() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "schema",
+        "compat",
+        "tests/fixtures/in_toto_v1_schema.json",
+        "tests/fixtures/in_toto_v1_schema_breaking.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[required-added] /properties/subject/items/required: Property uri is now required",
+    ))
+    .stderr(predicate::str::contains("Schema has 1 breaking change(s)"));
+}
+
+#[test]
+fn test_docs_generate() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["docs-generate", "in-toto-v1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "| Field | Type | Required | Description |",
+        ))
+        .stdout(predicate::str::contains(
+            "| `subject` | array of [Subject](#subject) | yes |",
+        ));
+}
+
+#[test]
+fn test_docs_generate_all() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let out_dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("docs_generate_all");
+    let _ = std::fs::remove_dir_all(&out_dir);
+
+    cmd.args(["docs-generate", "--out-dir"])
+        .arg(&out_dir)
+        .assert()
+        .success();
+
+    let reference = std::fs::read_to_string(out_dir.join("slsa-provenance-v1.md")).unwrap();
+    assert!(reference.starts_with("# InTotoStatementV1_for_SLSAProvenanceV1Predicate\n"));
+    assert!(out_dir.join("spdx-v22.md").exists());
+}
+
+#[test]
+fn test_docs_generate_unknown_document_type() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["docs-generate", "cyclonedx"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown document type cyclonedx, expected one of: in-toto-v1",
+        ));
+}
+
+#[test]
+fn test_push_without_endpoint() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.env_remove("SPECTOR_PUSH_URL")
+        .env("XDG_CONFIG_HOME", env!("CARGO_TARGET_TMPDIR"))
+        .args([
+            "push",
+            "in-toto-v1",
+            "--file",
+            "tests/fixtures/slsa_provenance_v1.json",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No endpoint to push to"));
+}
+
+#[test]
+fn test_push_invalid_document_is_not_pushed() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    // Nothing listens on the endpoint, so the command would fail differently if it pushed.
+    cmd.args([
+        "push",
+        "--endpoint",
+        "http://127.0.0.1:1/ingest",
+        "in-toto-v1",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1_invalid.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("Failed to push").not());
+}
+
+#[test]
+fn test_push() {
+    let (url, _requests) = serve(|_| Response::ok(""));
+    let endpoint = format!("{}/ingest", url);
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "push",
+        "--endpoint",
+        &endpoint,
+        "in-toto-v1",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1.json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(format!(
+        "Pushed tests/fixtures/slsa_provenance_v1.json to {}",
+        endpoint
+    )));
+}
+
+#[test]
+fn test_guac_push() {
+    let (url, _requests) = serve(|_| Response::ok(r#"{"data": {}}"#));
+    let endpoint = format!("{}/query", url);
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "guac-push",
+        "--endpoint",
+        &endpoint,
+        "tests/fixtures/slsa_provenance_v1.json",
+        "tests/fixtures/spdx_v23_sbom_attestation.json",
+        "tests/fixtures/spdx_v23_sbom.json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "tests/fixtures/spdx_v23_sbom.json: valid",
+    ))
+    .stdout(predicate::str::contains(
+        "Pushed 1 SLSA and 2 SBOM relation(s) to GUAC in 3 request(s)",
+    ));
+}
+
+#[test]
+fn test_guac_push_invalid_document_is_not_pushed() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    // Nothing listens on the endpoint, so the command would fail differently if it pushed.
+    cmd.args([
+        "guac-push",
+        "--endpoint",
+        "http://127.0.0.1:1/query",
+        "--retries",
+        "0",
+        "tests/fixtures/slsa_provenance_v1_invalid.json",
+    ])
+    .assert()
+    .failure()
+    .stdout(predicate::str::contains(
+        "tests/fixtures/slsa_provenance_v1_invalid.json: invalid",
+    ))
+    .stderr(predicate::str::contains(
+        "1 document(s) failed validation and were not pushed",
+    ));
+}
+
+#[test]
+fn test_fetch_npm_invalid_package() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["fetch", "npm", "left-pad"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid package left-pad, expected <name>@<version>",
+        ));
+}
+
+#[test]
+fn test_fetch_npm_unreachable_registry() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "fetch",
+        "npm",
+        "left-pad@1.3.0",
+        "--registry",
+        "http://127.0.0.1:1",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "Failed to fetch http://127.0.0.1:1/left-pad/1.3.0",
+    ));
+}
+
+#[test]
+fn test_fetch_pypi_invalid_file() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["fetch", "pypi", "sampleproject.egg"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid distribution file sampleproject.egg",
+        ));
+}
+
+#[test]
+fn test_fetch_pypi_unreachable_index() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "fetch",
+        "pypi",
+        "sampleproject-4.0.0.tar.gz",
+        "--index",
+        "http://127.0.0.1:1",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "Failed to fetch http://127.0.0.1:1/pypi/sampleproject/4.0.0/json",
+    ));
+}
+
+#[test]
+fn test_fetch_pypi_needs_trusted_root() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["fetch", "pypi", "sampleproject-4.0.0.tar.gz"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "A trusted root is needed to verify the attestations",
+        ));
+}
+
+#[test]
+fn test_sbom_scan() {
+    let (api, _requests) =
+        serve(|_| Response::ok(r#"{"results": [{"vulns": [{"id": "GHSA-35jh-r3h4-6jhm"}]}, {}]}"#));
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["sbom", "scan", "--file", "tests/fixtures/spdx_v23_sbom.json", "--osv-api", &api, "--fail-on-vulnerabilities"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Found 1 known vulnerabilities in 2 packages"))
+        .stderr(predicate::str::contains(
+            "warning[known-vulnerability] /packages/0/externalRefs/0/referenceLocator: pkg:npm/lodash@4.17.20 is affected by GHSA-35jh-r3h4-6jhm",
+        ))
+        .stderr(predicate::str::contains("tests/fixtures/spdx_v23_sbom.json has known vulnerabilities"));
+}
+
+#[test]
+fn test_sbom_scan_unreachable_osv() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "sbom",
+        "scan",
+        "--file",
+        "tests/fixtures/spdx_v23_sbom.json",
+        "--osv-api",
+        "http://127.0.0.1:1",
+    ])
+    .assert()
+    .failure()
+    .stdout(predicate::str::contains("Valid document"))
+    .stderr(predicate::str::contains(
+        "Failed to query http://127.0.0.1:1/v1/querybatch",
+    ));
+}
+
+#[test]
+fn test_sbom_scan_unsupported_document() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "sbom",
+        "scan",
+        "--file",
+        "tests/fixtures/slsa_provenance_v1.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("Unsupported spdxVersion (none)"));
+}
+
+#[test]
+fn test_validate_deps_dev() {
+    let (api, _requests) = serve(|request| {
+        let body = match request.path() {
+            "/v3/systems/npm/packages/lodash" => {
+                r#"{"versions": [{"versionKey": {"version": "4.17.21"}, "isDefault": true}]}"#
+            }
+            "/v3/systems/npm/packages/lodash/versions/4.17.20" => r#"{"licenses": ["MIT"]}"#,
+            _ => "{}",
+        };
+        Response::ok(body)
+    });
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["validate", "in-toto-v1", "--file", "tests/fixtures/slsa_provenance_v1_purl_dependencies.json", "--deps-dev", "--deps-dev-api", &api])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Valid InTotoV1 SLSAProvenanceV1 document"))
+        .stderr(predicate::str::contains(
+            "info[dependency-outdated] /predicate/buildDefinition/resolvedDependencies/2/uri: pkg:npm/lodash@4.17.20 has a newer version 4.17.21",
+        ))
+        .stderr(predicate::str::contains("info[dependency-license] /predicate/buildDefinition/resolvedDependencies/2/uri: pkg:npm/lodash@4.17.20 is licensed under MIT"));
+}
+
+#[test]
+fn test_sbom_fill_licenses() {
+    let (api, _requests) = serve(|_| {
+        Response::ok(r#"{"pypi/pypi/-/requests/2.31.0": {"licensed": {"declared": "Apache-2.0"}}}"#)
+    });
+    let output = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("spdx_v23_sbom_filled.json");
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args(["sbom", "fill-licenses", "--file", "tests/fixtures/spdx_v23_sbom.json", "--clearlydefined-api", &api, "--output", output.to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "info[license-filled] /packages/1/licenseConcluded: Set licenseConcluded of requests to Apache-2.0 from ClearlyDefined",
+        ));
+    let patched: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+    assert_eq!(patched["packages"][1]["licenseConcluded"], "Apache-2.0");
+    assert_eq!(patched["packages"][1]["licenseDeclared"], "Apache-2.0");
+    assert_eq!(patched["packages"][0]["licenseConcluded"], "MIT");
+}
+
+#[test]
+fn test_validate_object_store_uri_without_feature() {
+    if cfg!(feature = "object-store") {
+        return;
+    }
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--file",
+        "s3://attestations/slsa_provenance_v1.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "requires spector to be built with the object-store feature",
+    ));
+}
+
+#[test]
+fn test_validate_spdx_stream() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "validate",
+        "spdxv23",
+        "--stream",
+        "--file",
+        "tests/fixtures/spdx_v23_sbom.json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Valid document with 2 packages"));
+}
+
+#[test]
+fn test_validate_spdx_stream_invalid() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    // The fixture uses SPDX 2.3 reference categories, which aren't valid in SPDX 2.2.
+    cmd.args([
+        "validate",
+        "spdxv22",
+        "--stream",
+        "--file",
+        "tests/fixtures/spdx_v23_sbom.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[schema-violation] /packages/0/externalRefs/0/referenceCategory",
+    ));
+}
+
+#[test]
+fn test_consistency() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "consistency",
+        "tests/fixtures/slsa_provenance_v1.json",
+        "tests/fixtures/slsa_provenance_v1_purl_dependencies.json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "/1: tests/fixtures/slsa_provenance_v1_purl_dependencies.json",
+    ))
+    .stdout(predicate::str::contains(
+        "Checked 2 attestations with 0 conflicts",
+    ));
+}
+
+#[test]
+fn test_consistency_subject_mismatch() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "consistency",
+        "tests/fixtures/slsa_provenance_v1.json",
+        "tests/fixtures/slsa_provenance_v02.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[subject-mismatch] /1/subject",
+    ))
+    .stderr(predicate::str::contains(
+        "Attestations are not about the same artifact",
+    ));
+}
+
+#[test]
+fn test_consistency_with_store() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "consistency",
+        "tests/fixtures/slsa_provenance_v1.json",
+        "--store",
+        "tests/fixtures",
+    ])
+    .args([
+        "--digest",
+        "sha256:fe4fe40ac7250263c5dbe1cf3138912f3f416140aa248637a60d65fe22c47da4",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "/1: tests/fixtures/slsa_provenance_v1_invalid.json",
+    ))
+    .stdout(predicate::str::contains(
+        "/4: tests/fixtures/spdx_v23_sbom_attestation.json",
+    ))
+    .stdout(predicate::str::contains(
+        "Checked 5 attestations with 0 conflicts",
+    ));
+}
+
+#[test]
+fn test_completeness_required_by_policy() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("slsa_provenance_v02.json");
+    let policy = fixture_path("policy_require_completeness.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--policy",
+        policy.to_str().unwrap(),
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[completeness-not-claimed] /predicate/metadata/completeness/parameters",
+    ))
+    .stderr(predicate::str::contains(
+        "error[reproducible-not-claimed] /predicate/metadata/reproducible",
+    ));
+}
+
+#[test]
+fn test_validate_dsse_envelope() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("slsa_provenance_v02.dsse.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--predicate",
+        "slsa-provenance-v02",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 SLSAProvenanceV02 document",
+    ));
+}
+
+#[test]
+fn test_predicate_schema() {
+    let fixture = fixture_path("release_approval.json");
+    let schema = fixture_path("release_approval_schema.json");
+
+    Command::cargo_bin("spector")
+        .unwrap()
+        .args([
+            "validate",
+            "in-toto-v1",
+            "--predicate-schema",
+            schema.to_str().unwrap(),
+            "--file",
+            fixture.to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "error[predicate-schema-violation] /predicate",
+        ))
+        .stderr(predicate::str::contains(
+            "\"approver\" is a required property",
+        ));
+
+    let invalid = std::env::temp_dir().join(format!(
+        "spector-cli-predicate-schema-{}.json",
+        std::process::id()
+    ));
+    std::fs::write(&invalid, r#"{"type": "approval"}"#).unwrap();
+    Command::cargo_bin("spector")
+        .unwrap()
+        .args([
+            "validate",
+            "in-toto-v1",
+            "--predicate-schema",
+            invalid.to_str().unwrap(),
+            "--file",
+            fixture.to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid predicate schema"));
+    std::fs::remove_file(&invalid).unwrap();
+}
+
+#[test]
+fn test_builder_allowlist() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("slsa_provenance_v1.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--allow-builder",
+        "https://github.com/slsa-framework/slsa-github-generator/",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success();
+}
+
+#[test]
+fn test_builder_not_allowed() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("slsa_provenance_v1.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--allow-builder",
+        "https://example.com/builder",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[builder-not-allowed] /predicate/runDetails/builder/id",
+    ));
+}
+
+#[test]
+fn test_attestation_expired() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = fixture_path("slsa_provenance_v1.json");
+
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--not-after",
+        "2000-01-01T00:00:00Z",
+        "--file",
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("error[attestation-expired]"));
+}
+
+#[test]
+fn test_validate_gzip_compressed() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let fixture = std::fs::read(fixture_path("slsa_provenance_v1.json")).unwrap();
+    let path = std::env::temp_dir().join(format!("spector-cli-{}.json.gz", std::process::id()));
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    std::io::Write::write_all(&mut encoder, &fixture).unwrap();
+    std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+    let assert = cmd
+        .args(["validate", "in-toto-v1", "--file", path.to_str().unwrap()])
+        .assert();
+    std::fs::remove_file(&path).unwrap();
+    assert.success();
+}
+
+#[test]
+fn test_patch() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let patch = fixture_path("patch_rename_digest.json");
+    let fixture = fixture_path("slsa_provenance_v1_bad_digest.json");
+
+    cmd.args([
+        "patch",
+        "--patch",
+        patch.to_str().unwrap(),
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "\"sha256\": \"fe4fe40ac7250263c5dbe1cf3138912f3f416140aa248637a60d65fe22c47da4\"",
+    ))
+    .stderr(predicate::str::contains("Patched 1 statements"));
+}
+
+#[test]
+fn test_patch_invalid_result() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let patch = fixture_path("patch_rename_digest.json");
+    let fixture = fixture_path("slsa_provenance_v1.json");
+
+    // The statement has no sha-256 digest to move, so the patch fails.
+    cmd.args([
+        "patch",
+        "--patch",
+        patch.to_str().unwrap(),
+        fixture.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "1 of 1 statements could not be patched",
+    ));
+}
+
+#[test]
+fn test_verify_provenance() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let provenance = fixture_path("slsa_provenance_v1_release.sigstore.json");
+    let artifact = fixture_path("hello-world");
+
+    cmd.args(["verify", "provenance", provenance.to_str().unwrap(), "--trusted-root", "tests/fixtures/sigstore_trusted_root.json"])
+        .args(["--source-uri", "github.com/octocat/hello-world", "--source-tag", "v1.2.3"])
+        .args(["--builder-id", "https://github.com/slsa-framework/slsa-github-generator/.github/workflows/builder_go_slsa3.yml"])
+        .args(["--build-workflow-input", "deploy_target=deployment_sys_1a", "--artifact", artifact.to_str().unwrap()])
+        .args(["--key", "tests/fixtures/release_key.pem"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Verified 1 provenance statement(s) for github.com/octocat/hello-world"));
+}
+
+#[test]
+fn test_verify_provenance_needs_signature() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let provenance = fixture_path("slsa_provenance_v1_release.intoto.jsonl");
+    let artifact = fixture_path("hello-world");
+
+    cmd.args([
+        "verify",
+        "provenance",
+        provenance.to_str().unwrap(),
+        "--artifact",
+        artifact.to_str().unwrap(),
+    ])
+    .args(["--source-uri", "github.com/octocat/hello-world"])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "A trusted root is needed to verify the signature",
+    ));
+
+    // The envelopes carry no key or certificate to verify their signatures with.
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "provenance",
+        provenance.to_str().unwrap(),
+        "--artifact",
+        artifact.to_str().unwrap(),
+    ])
+    .args([
+        "--source-uri",
+        "github.com/octocat/hello-world",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("error[no-signing-key] /0"))
+    .stderr(predicate::str::contains(
+        "1 provenance statement(s) failed verification",
+    ));
+
+    // The bundle's log entry records the key it was signed with, but only a pinned key is
+    // trusted.
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "provenance",
+        fixture_path("slsa_provenance_v1_release.sigstore.json")
+            .to_str()
+            .unwrap(),
+        "--artifact",
+        artifact.to_str().unwrap(),
+    ])
+    .args([
+        "--source-uri",
+        "github.com/octocat/hello-world",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("error[no-signing-key] /0"));
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "provenance",
+        provenance.to_str().unwrap(),
+        "--source-uri",
+        "github.com/octocat/hello-world",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("--artifact"));
+}
+
+#[test]
+fn test_verify_provenance_from_store() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let artifact = fixture_path("hello-world");
+
+    cmd.args([
+        "verify",
+        "provenance",
+        "--store",
+        fixture_path("").to_str().unwrap(),
+        "--artifact",
+        artifact.to_str().unwrap(),
+    ])
+    .args([
+        "--source-uri",
+        "github.com/octocat/hello-world",
+        "--tag",
+        "v1.2.3",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+    ])
+    .assert()
+    .failure()
+    .stdout(predicate::str::contains(
+        "slsa_provenance_v1_release.intoto.jsonl#0: https://slsa.dev/provenance/v1",
+    ))
+    .stdout(predicate::str::contains(
+        "slsa_provenance_v1_release.sigstore.json: https://slsa.dev/provenance/v1",
+    ))
+    .stderr(predicate::str::contains("error[no-signing-key] /0"))
+    .stderr(predicate::str::contains(
+        "2 provenance statement(s) failed verification",
+    ));
+
+    let store = std::env::temp_dir().join(format!("spector-cli-store-{}", std::process::id()));
+    std::fs::create_dir_all(&store).unwrap();
+    std::fs::copy(
+        fixture_path("slsa_provenance_v1_release.sigstore.json"),
+        store.join("provenance.sigstore.json"),
+    )
+    .unwrap();
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "provenance",
+        "--store",
+        store.to_str().unwrap(),
+        "--artifact",
+        artifact.to_str().unwrap(),
+    ])
+    .args([
+        "--source-uri",
+        "github.com/octocat/hello-world",
+        "--tag",
+        "v1.2.3",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Verified 1 provenance statement(s) for github.com/octocat/hello-world",
+    ));
+    std::fs::remove_dir_all(&store).unwrap();
+}
+
+#[test]
+fn test_verify_provenance_from_store_missing_annotation() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let artifact = fixture_path("hello-world");
+
+    cmd.args([
+        "verify",
+        "provenance",
+        "--store",
+        fixture_path("").to_str().unwrap(),
+        "--artifact",
+        artifact.to_str().unwrap(),
+    ])
+    .args([
+        "--source-uri",
+        "github.com/octocat/hello-world",
+        "-a",
+        "env=prod",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("None of the 2 attestation(s) in"))
+    .stderr(predicate::str::contains("have the required annotations"));
+}
+
+#[test]
+fn test_verify_policy() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "verify",
+        "--policy",
+        "tests/fixtures/verification_policy.yaml",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+        "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "2 attestation(s) meet the policy tests/fixtures/verification_policy.yaml",
+    ));
+}
+
+#[test]
+fn test_verify_policy_needs_trusted_root() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "verify",
+        "--policy",
+        "tests/fixtures/verification_policy.yaml",
+        "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "A trusted root is needed to verify the attestations",
+    ));
+}
+
+#[test]
+fn test_verify_policy_not_met() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let policy =
+        std::env::temp_dir().join(format!("spector-cli-policy-{}.json", std::process::id()));
+    std::fs::write(
+        &policy,
+        r#"{"trustedKeys": ["release-key"], "artifacts": [{"name": "hello-world", "requiredAttestations": ["https://spdx.dev/Document"]}]}"#,
+    )
+    .unwrap();
+
+    cmd.args([
+        "verify",
+        "--policy",
+        policy.to_str().unwrap(),
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+        "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[untrusted-key] /0: Signed by none of the trusted keys",
+    ))
+    .stderr(predicate::str::contains(
+        "error[missing-attestation] /artifacts/0/requiredAttestations/0",
+    ))
+    .stderr(predicate::str::contains(
+        "Attestations do not meet the policy",
+    ));
+    std::fs::remove_file(&policy).unwrap();
+}
+
+#[test]
+fn test_verify_policy_forged_signature() {
+    // An envelope naming the trusted key id, with a signature that doesn't verify.
+    let envelope = std::env::temp_dir().join(format!(
+        "spector-cli-forged-{}.intoto.jsonl",
+        std::process::id()
+    ));
+    let fixture =
+        std::fs::read_to_string("tests/fixtures/slsa_provenance_v1_release.intoto.jsonl").unwrap();
+    let mut document: serde_json::Value =
+        serde_json::from_str(fixture.lines().next().unwrap()).unwrap();
+    document["signatures"] = serde_json::json!([{"keyid": "release-key", "sig": "AAAA"}]);
+    std::fs::write(&envelope, document.to_string()).unwrap();
+    let policy = std::env::temp_dir().join(format!(
+        "spector-cli-forged-policy-{}.json",
+        std::process::id()
+    ));
+    std::fs::write(
+        &policy,
+        r#"{"trustedKeys": ["release-key"], "slsaLevel": 3, "artifacts": [{"name": "hello-world", "requiredAttestations": ["https://slsa.dev/provenance/v1"]}]}"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "--policy",
+        policy.to_str().unwrap(),
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+        envelope.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[invalid-signature] /0/signatures",
+    ))
+    .stderr(predicate::str::contains(
+        "error[slsa-level-not-met] /slsaLevel",
+    ))
+    .stderr(predicate::str::contains(
+        "Attestations do not meet the policy",
+    ));
+    std::fs::remove_file(&envelope).unwrap();
+    std::fs::remove_file(&policy).unwrap();
+}
+
+#[test]
+fn test_verify_policy_trusted_root() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "verify",
+        "--policy",
+        "tests/fixtures/verification_policy.yaml",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+    ])
+    .args([
+        "tests/fixtures/slsa_provenance_v1_release.sigstore.json",
+        "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("3 attestation(s) meet the policy"));
+}
+
+#[test]
+fn test_verify_policy_unknown_transparency_log() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let trusted_root = std::env::temp_dir().join(format!(
+        "spector-cli-trusted-root-{}.json",
+        std::process::id()
+    ));
+    let fixture = std::fs::read_to_string("tests/fixtures/sigstore_trusted_root.json").unwrap();
+    std::fs::write(
+        &trusted_root,
+        fixture.replace("i9AG+fXp6MYTAeYtvOZu19EaKOrMZM4kKIGcdj6JzhU=", "AAAA"),
+    )
+    .unwrap();
+
+    cmd.args([
+        "verify",
+        "--policy",
+        "tests/fixtures/verification_policy.yaml",
+        "--trusted-root",
+        trusted_root.to_str().unwrap(),
+        "--key",
+        "tests/fixtures/release_key.pem",
+    ])
+    .args([
+        "tests/fixtures/slsa_provenance_v1_release.sigstore.json",
+        "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[unknown-transparency-log] /0/tlogEntries/0/logId",
+    ));
+    std::fs::remove_file(&trusted_root).unwrap();
+}
+
+#[test]
+fn test_verify_policy_certificate_not_logged() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let bundle = std::env::temp_dir().join(format!(
+        "spector-cli-keyless-{}.sigstore.json",
+        std::process::id()
+    ));
+    let mut document: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string("tests/fixtures/slsa_provenance_v1_release.sigstore.json")
+            .unwrap(),
+    )
+    .unwrap();
+    document["verificationMaterial"]["certificate"] = serde_json::json!({"rawBytes": "MIIB"});
+    std::fs::write(&bundle, document.to_string()).unwrap();
+
+    cmd.args([
+        "verify",
+        "--policy",
+        "tests/fixtures/verification_policy.yaml",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+    ])
+    .args([
+        bundle.to_str().unwrap(),
+        "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[certificate-not-logged] /0/certificates/0: MIIB: Invalid certificate",
+    ));
+    std::fs::remove_file(&bundle).unwrap();
+}
+
+#[test]
+fn test_export_and_verify_bundle() {
+    let bundle =
+        std::env::temp_dir().join(format!("spector-cli-offline-{}.json", std::process::id()));
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let output = cmd
+        .args([
+            "export-bundle",
+            "--trusted-root",
+            "tests/fixtures/sigstore_trusted_root.json",
+            "--policy",
+            "tests/fixtures/verification_policy.yaml",
+            "-o",
+            bundle.to_str().unwrap(),
+        ])
+        .args([
+            "tests/fixtures/slsa_provenance_v1_release.sigstore.json",
+            "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported 3 attestation(s)"));
+    let stderr = String::from_utf8(output.get_output().stderr.clone()).unwrap();
+    let digest = stderr
+        .lines()
+        .find_map(|line| line.strip_prefix("Policy digest: "))
+        .unwrap();
+
+    // The bundle's own policy is only used with its pinned digest.
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "--bundle",
+        bundle.to_str().unwrap(),
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "The bundle's policy is only trusted with its pinned digest",
+    ));
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "--bundle",
+        bundle.to_str().unwrap(),
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+        "--policy-digest",
+        digest,
+    ])
+    .assert()
+    .success()
+    .stderr(predicate::str::contains(
+        "warning[no-inclusion-proof] /0/tlogEntries/0",
+    ))
+    .stdout(predicate::str::contains("3 attestation(s) in"));
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let policy = std::env::temp_dir().join(format!(
+        "spector-cli-offline-policy-{}.yaml",
+        std::process::id()
+    ));
+    std::fs::write(
+        &policy,
+        "artifacts: [{name: hello-world, requiredAttestations: [https://spdx.dev/Document]}]",
+    )
+    .unwrap();
+    cmd.args([
+        "verify",
+        "--bundle",
+        bundle.to_str().unwrap(),
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+        "--policy",
+        policy.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[missing-attestation] /artifacts/0/requiredAttestations/0",
+    ))
+    .stderr(predicate::str::contains("does not verify"));
+
+    // A bundle with a forged signature, in the envelope of its first attestation.
+    let mut document: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&bundle).unwrap()).unwrap();
+    document["attestations"][0]["dsseEnvelope"]["signatures"][0]["sig"] = "AAAA".into();
+    std::fs::write(&bundle, document.to_string()).unwrap();
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "--bundle",
+        bundle.to_str().unwrap(),
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "--key",
+        "tests/fixtures/release_key.pem",
+        "--policy-digest",
+        digest,
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[invalid-signature] /0/signatures",
+    ))
+    .stderr(predicate::str::contains("does not verify"));
+    std::fs::remove_file(&bundle).unwrap();
+    std::fs::remove_file(&policy).unwrap();
+}
+
+#[test]
+fn test_verify_bundle_pinned_trusted_root() {
+    let bundle = std::env::temp_dir().join(format!(
+        "spector-cli-offline-substituted-{}.json",
+        std::process::id()
+    ));
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "export-bundle",
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+        "-o",
+        bundle.to_str().unwrap(),
+    ])
+    .arg("tests/fixtures/slsa_provenance_v1_release.sigstore.json")
+    .assert()
+    .success();
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args(["verify", "--bundle", bundle.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("A trusted root is needed"));
+
+    let mut document: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&bundle).unwrap()).unwrap();
+    document["trustedRoot"]["tlogs"][0]["publicKey"]["rawBytes"] =
+        "MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE".into();
+    std::fs::write(&bundle, document.to_string()).unwrap();
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "verify",
+        "--bundle",
+        bundle.to_str().unwrap(),
+        "--trusted-root",
+        "tests/fixtures/sigstore_trusted_root.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "The trusted root of the bundle is not the pinned one",
+    ));
+    std::fs::remove_file(&bundle).unwrap();
+}
+
+#[test]
+fn test_export_bundle_needs_trusted_root() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "export-bundle",
+        "tests/fixtures/slsa_provenance_v1_release.sigstore.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("A trusted root is needed"));
+}
+
+#[test]
+fn test_verify_policy_report_formats() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    let output = cmd
+        .args([
+            "verify",
+            "--policy",
+            "tests/fixtures/verification_policy.yaml",
+            "--trusted-root",
+            "tests/fixtures/sigstore_trusted_root.json",
+            "--key",
+            "tests/fixtures/release_key.pem",
+            "--format",
+            "policy-report",
+            "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(report["kind"], "PolicyReport");
+    assert_eq!(
+        report["metadata"]["name"],
+        "tests-fixtures-verification-policy-yaml"
+    );
+    assert_eq!(report["summary"]["pass"], 1);
+    assert_eq!(
+        report["results"][0]["policy"],
+        "tests/fixtures/verification_policy.yaml"
+    );
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let policy = std::env::temp_dir().join(format!(
+        "spector-cli-gatekeeper-policy-{}.json",
+        std::process::id()
+    ));
+    std::fs::write(&policy, r#"{"trustedKeys": ["release-key"]}"#).unwrap();
+    let output = cmd
+        .args([
+            "verify",
+            "--policy",
+            policy.to_str().unwrap(),
+            "--trusted-root",
+            "tests/fixtures/sigstore_trusted_root.json",
+            "--key",
+            "tests/fixtures/release_key.pem",
+            "--format",
+            "gatekeeper",
+            "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Attestations do not meet the policy",
+        ))
+        .get_output()
+        .stdout
+        .clone();
+    let constraint: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(constraint["kind"], "SpectorVerification");
+    assert_eq!(constraint["status"]["totalViolations"], 2);
+    assert_eq!(
+        constraint["status"]["violations"][0]["enforcementAction"],
+        "deny"
+    );
+    assert_eq!(constraint["status"]["violations"][0]["name"], "/0");
+    std::fs::remove_file(&policy).unwrap();
+}
+
+#[test]
+fn test_verify_provenance_mismatch() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    let provenance = fixture_path("slsa_provenance_v1_release.sigstore.json");
+    let artifact = fixture_path("hello-world");
+
+    cmd.args(["verify", "provenance", provenance.to_str().unwrap(), "--artifact", artifact.to_str().unwrap()])
+        .args(["--source-uri", "github.com/octocat/other", "--tag", "v1.2.4", "--trusted-root", "tests/fixtures/sigstore_trusted_root.json"])
+        .args(["--key", "tests/fixtures/release_key.pem"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error[source-mismatch] /predicate/buildDefinition/externalParameters/workflow/repository"))
+        .stderr(predicate::str::contains("error[ref-mismatch] /predicate/buildDefinition/externalParameters/workflow/ref: Built at refs/tags/v1.2.3"))
+        .stderr(predicate::str::contains("1 provenance statement(s) failed verification"));
+}
+
+#[test]
+fn test_verify_linkage() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "verify",
+        "linkage",
+        "tests/fixtures/spdx_v23_sbom_attestation.json",
+        "tests/fixtures/slsa_provenance_v1_purl_dependencies.json",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "\"dependency\": \"/1/predicate/buildDefinition/resolvedDependencies/2\"",
+    ));
+}
+
+#[test]
+fn test_verify_linkage_missing_dependency() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.args([
+        "verify",
+        "linkage",
+        "tests/fixtures/spdx_v23_sbom_attestation.json",
+        "tests/fixtures/slsa_provenance_v1.json",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "error[package-not-in-dependencies] /0/predicate/packages/1",
+    ))
+    .stderr(predicate::str::contains(
+        "The SBOM is not linked to the provenance",
+    ));
+}
+
+#[test]
+fn test_import_bazel_bep() {
+    let statement =
+        std::env::temp_dir().join(format!("spector-cli-bazel-{}.json", std::process::id()));
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "import",
+        "bazel-bep",
+        "tests/fixtures/bazel_bep.json",
+        "--builder-id",
+        "https://ci.example.com/bazel",
+        "-o",
+        statement.to_str().unwrap(),
+    ])
+    .assert()
+    .success();
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--file",
+        statement.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Valid InTotoV1 SLSAProvenanceV1 document",
+    ));
+    std::fs::remove_file(&statement).unwrap();
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "import",
+        "bazel-bep",
+        "tests/fixtures/slsa_provenance_v1.json",
+        "--builder-id",
+        "https://ci.example.com/bazel",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "Error: Invalid build event on line",
+    ));
+}
+
+#[test]
+fn test_test_corpus_generate() {
+    let dir = std::env::temp_dir().join(format!("spector-cli-corpus-{}", std::process::id()));
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "test-corpus",
+        "generate",
+        "--model",
+        "slsa-provenance-v1",
+        "--out-dir",
+        dir.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("Generated 1 valid and"));
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("manifest.json")).unwrap()).unwrap();
+    let cases = manifest.as_array().unwrap();
+    let file = |kind: &str| {
+        dir.join(
+            cases.iter().find(|case| case["kind"] == kind).unwrap()["file"]
+                .as_str()
+                .unwrap(),
+        )
+    };
+
+    // The CLI agrees with the manifest about what is valid.
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--file",
+        file("valid").to_str().unwrap(),
+    ])
+    .assert()
+    .success();
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "validate",
+        "in-toto-v1",
+        "--file",
+        file("bad-digest").to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("malformed-digest"));
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args([
+        "test-corpus",
+        "generate",
+        "--model",
+        "unknown",
+        "--out-dir",
+        dir.to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("Error: No schema named unknown"));
+}
+
+#[test]
+fn test_tracing() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    cmd.env("SPECTOR_LOG", "spector=debug")
+        .args([
+            "verify",
+            "--policy",
+            "tests/fixtures/verification_policy.yaml",
+            "--trusted-root",
+            "tests/fixtures/sigstore_trusted_root.json",
+            "--key",
+            "tests/fixtures/release_key.pem",
+            "tests/fixtures/slsa_provenance_v1_release.intoto.jsonl",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Read document bytes=3830"))
+        .stderr(predicate::str::contains(
+            "evaluate{attestations=2 artifacts=2}",
+        ))
+        .stderr(predicate::str::contains(
+            "Evaluated the verification policy valid=2 errors=0",
+        ));
+}
+
+#[test]
+fn test_json_log_format() {
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+
+    let output = cmd
+        .args([
+            "--log-format",
+            "json",
+            "patch",
+            "--patch",
+            "tests/fixtures/patch_rename_digest.json",
+        ])
+        .args([
+            "tests/fixtures/slsa_provenance_v1.json",
+            "tests/fixtures/slsa_provenance_v1_bad_digest.json",
+        ])
+        .assert()
+        .failure()
+        .get_output()
+        .stderr
+        .clone();
+    let records = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .collect::<Vec<_>>();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["path"], "tests/fixtures/slsa_provenance_v1.json");
+    assert_eq!(records[0]["result"], "fail");
+    assert!(records[0]["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Failed to apply patch"));
+    assert_eq!(records[1]["result"], "pass");
+    assert_eq!(
+        records[1]["warnings"],
+        serde_json::json!(["wildcard-subject"])
+    );
+    assert!(records[1]["durationMs"].is_u64());
+
+    let mut cmd = Command::cargo_bin("spector").unwrap();
+    cmd.args(["validate", "in-toto-v1", "--log-format", "json", "--file", "tests/fixtures/slsa_provenance_v1.json"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("\"command\":\"validate\",\"path\":\"tests/fixtures/slsa_provenance_v1.json\",\"result\":\"pass\""))
+        .stderr(predicate::str::contains("warning[wildcard-subject]").not());
+}
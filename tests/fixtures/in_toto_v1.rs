@@ -3,6 +3,284 @@
 #![allow(clippy::all)]
 #![allow(warnings)]
 use serde::{Deserialize, Serialize};
+///The users and groups of an image.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Accounts {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<Group>,
+    ///The user the entrypoint runs as.
+    #[serde(rename = "run-as", default, skip_serializing_if = "Option::is_none")]
+    pub run_as: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<User>,
+}
+impl From<&Accounts> for Accounts {
+    fn from(value: &Accounts) -> Self {
+        value.clone()
+    }
+}
+impl Accounts {
+    pub fn builder() -> builder::Accounts {
+        builder::Accounts::default()
+    }
+}
+///A security advisory against a package.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Advisory {
+    ///The id of the advisory, e.g. `GHSA-35jh-r3h4-6jhm` or `CVE-2021-23337`.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<AdvisorySeverity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+impl From<&Advisory> for Advisory {
+    fn from(value: &Advisory) -> Self {
+        value.clone()
+    }
+}
+impl Advisory {
+    pub fn builder() -> builder::Advisory {
+        builder::Advisory::default()
+    }
+}
+///The severity of an advisory, as GitHub rates them.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum AdvisorySeverity {
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "moderate")]
+    Moderate,
+    #[serde(rename = "high")]
+    High,
+    #[serde(rename = "critical")]
+    Critical,
+}
+impl From<&AdvisorySeverity> for AdvisorySeverity {
+    fn from(value: &AdvisorySeverity) -> Self {
+        value.clone()
+    }
+}
+impl ToString for AdvisorySeverity {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Low => "low".to_string(),
+            Self::Moderate => "moderate".to_string(),
+            Self::High => "high".to_string(),
+            Self::Critical => "critical".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for AdvisorySeverity {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "low" => Ok(Self::Low),
+            "moderate" => Ok(Self::Moderate),
+            "high" => Ok(Self::High),
+            "critical" => Ok(Self::Critical),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for AdvisorySeverity {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for AdvisorySeverity {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for AdvisorySeverity {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Agent {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl From<&Agent> for Agent {
+    fn from(value: &Agent) -> Self {
+        value.clone()
+    }
+}
+impl Agent {
+    pub fn builder() -> builder::Agent {
+        builder::Agent::default()
+    }
+}
+///The APK repositories, keys and packages an image or build environment is made of.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ApkContents {
+    ///The URLs or paths of the keys the repositories are signed with.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keyring: Vec<String>,
+    ///The packages to install, optionally with a version constraint, e.g. `busybox>=1.36`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repositories: Vec<String>,
+}
+impl From<&ApkContents> for ApkContents {
+    fn from(value: &ApkContents) -> Self {
+        value.clone()
+    }
+}
+impl ApkContents {
+    pub fn builder() -> builder::ApkContents {
+        builder::ApkContents::default()
+    }
+}
+///An APK package, as installed by apko or built by melange.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ApkPackage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    ///The APK checksum of the package control section, e.g. `Q1...=`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    pub name: String,
+    ///The repository the package was installed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    ///The full version, including the epoch as the `-r` suffix, e.g. `1.36.1-r2`.
+    pub version: String,
+}
+impl From<&ApkPackage> for ApkPackage {
+    fn from(value: &ApkPackage) -> Self {
+        value.clone()
+    }
+}
+impl ApkPackage {
+    pub fn builder() -> builder::ApkPackage {
+        builder::ApkPackage::default()
+    }
+}
+///A struct representing the apko build predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ApkoBuildPredicate {
+    ///The image configuration the image was built from.
+    pub config: ImageConfiguration,
+    ///The packages installed in the image, as resolved at build time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<ApkPackage>,
+    ///The SBOMs generated for the image.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sboms: Vec<ResourceDescriptor>,
+}
+impl From<&ApkoBuildPredicate> for ApkoBuildPredicate {
+    fn from(value: &ApkoBuildPredicate) -> Self {
+        value.clone()
+    }
+}
+impl ApkoBuildPredicate {
+    pub fn builder() -> builder::ApkoBuildPredicate {
+        builder::ApkoBuildPredicate::default()
+    }
+}
+///The approval of a commit of a change by a reviewer.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Approval {
+    ///The commit that was approved. Approvals of commits before the head don't cover the commits after them.
+    pub commit: String,
+    ///The id of the approving reviewer.
+    pub reviewer: String,
+    #[serde(rename = "timeApproved", default, skip_serializing_if = "Option::is_none")]
+    pub time_approved: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+impl From<&Approval> for Approval {
+    fn from(value: &Approval) -> Self {
+        value.clone()
+    }
+}
+impl Approval {
+    pub fn builder() -> builder::Approval {
+        builder::Approval::default()
+    }
+}
+///A file a module published.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Artifact {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub md5: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    ///The type of the file, e.g. `jar` or `pom`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+impl From<&Artifact> for Artifact {
+    fn from(value: &Artifact) -> Self {
+        value.clone()
+    }
+}
+impl Artifact {
+    pub fn builder() -> builder::Artifact {
+        builder::Artifact::default()
+    }
+}
+///The location of an artifact, relative to the base URI `uriBaseId` names if it has one.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ArtifactLocation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(rename = "uriBaseId", default, skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<String>,
+}
+impl From<&ArtifactLocation> for ArtifactLocation {
+    fn from(value: &ArtifactLocation) -> Self {
+        value.clone()
+    }
+}
+impl ArtifactLocation {
+    pub fn builder() -> builder::ArtifactLocation {
+        builder::ArtifactLocation::default()
+    }
+}
+///The entity that attests to the references.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Attester {
+    pub id: String,
+}
+impl From<&Attester> for Attester {
+    fn from(value: &Attester) -> Self {
+        value.clone()
+    }
+}
+impl Attester {
+    pub fn builder() -> builder::Attester {
+        builder::Attester::default()
+    }
+}
 ///A struct
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
 pub struct Attribute {
@@ -24,6 +302,74 @@ impl Attribute {
         builder::Attribute::default()
     }
 }
+///An assertion that a target, or the producer if there is none, has an attribute.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct AttributeAssertion {
+    ///The attribute, e.g. `WITH_STACK_PROTECTION` or `HAS_SLSA_PROVENANCE`.
+    pub attribute: String,
+    ///The conditions under which the attribute holds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<serde_json::Map<String, serde_json::Value>>,
+    ///The evidence the attribute holds, e.g. an attestation or a test report.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<ResourceDescriptor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<ResourceDescriptor>,
+}
+impl From<&AttributeAssertion> for AttributeAssertion {
+    fn from(value: &AttributeAssertion) -> Self {
+        value.clone()
+    }
+}
+impl AttributeAssertion {
+    pub fn builder() -> builder::AttributeAssertion {
+        builder::AttributeAssertion::default()
+    }
+}
+///When, by what and for what the BOM was created.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct BomMetadata {
+    ///The component the BOM describes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<Component>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    ///The tools that created the BOM, as a list of tools (before 1.5) or an object of components and services.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools: Option<serde_json::Value>,
+}
+impl From<&BomMetadata> for BomMetadata {
+    fn from(value: &BomMetadata) -> Self {
+        value.clone()
+    }
+}
+impl BomMetadata {
+    pub fn builder() -> builder::BomMetadata {
+        builder::BomMetadata::default()
+    }
+}
+///A node of the product tree, e.g. a vendor, product name or version.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Branch {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<Branch>,
+    ///The kind of node, e.g. `vendor`, `product_name` or `product_version`.
+    pub category: String,
+    pub name: String,
+    ///The product at a leaf of the tree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product: Option<FullProductName>,
+}
+impl From<&Branch> for Branch {
+    fn from(value: &Branch) -> Self {
+        value.clone()
+    }
+}
+impl Branch {
+    pub fn builder() -> builder::Branch {
+        builder::Branch::default()
+    }
+}
 ///A structure representing the build definition of the SLSA Provenance v1 Predicate.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
 pub struct BuildDefinition {
@@ -31,7 +377,7 @@ pub struct BuildDefinition {
     pub build_type: String,
     ///The parameters that are under external control, such as those set by a user or tenant of the build platform. They MUST be complete at SLSA Build L3, meaning that there is no additional mechanism for an external party to influence the build. (At lower SLSA Build levels, the completeness MAY be best effort.)\nThe build platform SHOULD be designed to minimize the size and complexity of externalParameters, in order to reduce fragility and ease verification. Consumers SHOULD have an expectation of what “good” looks like; the more information that they need to check, the harder that task becomes.\nVerifiers SHOULD reject unrecognized or unexpected fields within externalParameters.
     #[serde(rename = "externalParameters")]
-    pub external_parameters: serde_json::Map<String, serde_json::Value>,
+    pub external_parameters: serde_json::Value,
     ///Unordered collection of artifacts needed at build time. Completeness is best effort, at least through SLSA Build L3. For example, if the build script fetches and executes “example.com/foo.sh”, which in turn fetches “example.com/bar.tar.gz”, then both “foo.sh” and “bar.tar.gz” SHOULD be listed here.
     #[serde(
         rename = "internalParameters",
@@ -57,6 +403,21 @@ impl BuildDefinition {
         builder::BuildDefinition::default()
     }
 }
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct BuildEnvironment {
+    #[serde(default = "defaults::build_environment_contents")]
+    pub contents: ApkContents,
+}
+impl From<&BuildEnvironment> for BuildEnvironment {
+    fn from(value: &BuildEnvironment) -> Self {
+        value.clone()
+    }
+}
+impl BuildEnvironment {
+    pub fn builder() -> builder::BuildEnvironment {
+        builder::BuildEnvironment::default()
+    }
+}
 ///A structure representing the metadata of the SLSA Provenance v1 Predicate.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
 pub struct BuildMetadata {
@@ -155,6 +516,133 @@ impl Builder2 {
         builder::Builder2::default()
     }
 }
+///What the command printed and returned.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Byproducts {
+    #[serde(rename = "return-value", default, skip_serializing_if = "Option::is_none")]
+    pub return_value: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+}
+impl From<&Byproducts> for Byproducts {
+    fn from(value: &Byproducts) -> Self {
+        value.clone()
+    }
+}
+impl Byproducts {
+    pub fn builder() -> builder::Byproducts {
+        builder::Byproducts::default()
+    }
+}
+///Whether a package was added or removed.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum ChangeType {
+    #[serde(rename = "added")]
+    Added,
+    #[serde(rename = "removed")]
+    Removed,
+}
+impl From<&ChangeType> for ChangeType {
+    fn from(value: &ChangeType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for ChangeType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Added => "added".to_string(),
+            Self::Removed => "removed".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for ChangeType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "added" => Ok(Self::Added),
+            "removed" => Ok(Self::Removed),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for ChangeType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for ChangeType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for ChangeType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A struct representing the Code Review v0.1 Predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct CodeReviewPredicate {
+    pub approvals: Vec<Approval>,
+    ///The id of the author of the change in the review tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    ///The URI of the reviewed change, e.g. a pull request or a merge request.
+    #[serde(rename = "changeUri", default, skip_serializing_if = "Option::is_none")]
+    pub change_uri: Option<String>,
+    #[serde(rename = "commitRange")]
+    pub commit_range: CommitRange,
+    ///Everyone asked to or who did review the change.
+    pub reviewers: Vec<Reviewer>,
+    ///When the change was merged, if it was.
+    #[serde(rename = "timeMerged", default, skip_serializing_if = "Option::is_none")]
+    pub time_merged: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub tool: ReviewTool,
+}
+impl From<&CodeReviewPredicate> for CodeReviewPredicate {
+    fn from(value: &CodeReviewPredicate) -> Self {
+        value.clone()
+    }
+}
+impl CodeReviewPredicate {
+    pub fn builder() -> builder::CodeReviewPredicate {
+        builder::CodeReviewPredicate::default()
+    }
+}
+///The commits of a change, after `base` up to and including `head`.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct CommitRange {
+    pub base: String,
+    pub head: String,
+    pub repository: String,
+}
+impl From<&CommitRange> for CommitRange {
+    fn from(value: &CommitRange) -> Self {
+        value.clone()
+    }
+}
+impl CommitRange {
+    pub fn builder() -> builder::CommitRange {
+        builder::CommitRange::default()
+    }
+}
 ///A structure representing the completeness claims of the SLSA Provenance v0.2 Predicate.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
 pub struct Completeness {
@@ -178,6 +666,62 @@ impl Completeness {
         builder::Completeness::default()
     }
 }
+///A software or hardware component.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Component {
+    ///An identifier of the component, unique within the BOM, that other elements refer to it by.
+    #[serde(rename = "bom-ref", default, skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    ///The components this component contains.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpe: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hashes: Vec<ComponentHash>,
+    ///The license choices of the component, as license objects or SPDX expressions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub licenses: Vec<serde_json::Value>,
+    pub name: String,
+    ///The package URL of the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+    ///The type of component, e.g. `library`, `application` or `container`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl From<&Component> for Component {
+    fn from(value: &Component) -> Self {
+        value.clone()
+    }
+}
+impl Component {
+    pub fn builder() -> builder::Component {
+        builder::Component::default()
+    }
+}
+///A hash of a component.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ComponentHash {
+    ///The algorithm, e.g. `SHA-256`.
+    pub alg: String,
+    ///The hash, as hex.
+    pub content: String,
+}
+impl From<&ComponentHash> for ComponentHash {
+    fn from(value: &ComponentHash) -> Self {
+        value.clone()
+    }
+}
+impl ComponentHash {
+    pub fn builder() -> builder::ComponentHash {
+        builder::ComponentHash::default()
+    }
+}
 ///A structure representing the description of where the config file that kicked off the build came from in the SLSA Provenance v0.2 Predicate.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
 pub struct ConfigSource {
@@ -201,1741 +745,18779 @@ impl ConfigSource {
         builder::ConfigSource::default()
     }
 }
-///Represents a set of digests, mapping algorithms to their respective digest strings.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct DigestSet(pub std::collections::HashMap<String, String>);
-impl std::ops::Deref for DigestSet {
-    type Target = std::collections::HashMap<String, String>;
-    fn deref(&self) -> &std::collections::HashMap<String, String> {
-        &self.0
+pub struct Copyright {
+    ///An SPDX license expression.
+    pub license: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+}
+impl From<&Copyright> for Copyright {
+    fn from(value: &Copyright) -> Self {
+        value.clone()
     }
 }
-impl From<DigestSet> for std::collections::HashMap<String, String> {
-    fn from(value: DigestSet) -> Self {
-        value.0
+impl Copyright {
+    pub fn builder() -> builder::Copyright {
+        builder::Copyright::default()
     }
 }
-impl From<&DigestSet> for DigestSet {
-    fn from(value: &DigestSet) -> Self {
+///A struct representing a CSAF document.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct CsafDocument {
+    pub document: DocumentMetadata,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_tree: Option<ProductTree>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+impl From<&CsafDocument> for CsafDocument {
+    fn from(value: &CsafDocument) -> Self {
         value.clone()
     }
 }
-impl From<std::collections::HashMap<String, String>> for DigestSet {
-    fn from(value: std::collections::HashMap<String, String>) -> Self {
-        Self(value)
+impl CsafDocument {
+    pub fn builder() -> builder::CsafDocument {
+        builder::CsafDocument::default()
     }
 }
-///Represents an In-Toto v1 statement.
+///A struct representing a CycloneDX BOM.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct InTotoStatementV1ForPredicate {
-    pub predicate: Predicate,
-    #[serde(rename = "predicateType")]
-    pub predicate_type: String,
-    pub subject: Vec<Subject>,
-    #[serde(rename = "_type")]
-    pub type_: String,
+pub struct CycloneDxBom {
+    ///Always `CycloneDX`.
+    #[serde(rename = "bomFormat")]
+    pub bom_format: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    ///The dependency graph of the components, by `bom-ref`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<Dependency2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<BomMetadata>,
+    #[serde(rename = "serialNumber", default, skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<String>,
+    ///The version of the CycloneDX spec, e.g. `1.5`.
+    #[serde(rename = "specVersion")]
+    pub spec_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
 }
-impl From<&InTotoStatementV1ForPredicate> for InTotoStatementV1ForPredicate {
-    fn from(value: &InTotoStatementV1ForPredicate) -> Self {
+impl From<&CycloneDxBom> for CycloneDxBom {
+    fn from(value: &CycloneDxBom) -> Self {
         value.clone()
     }
 }
-impl InTotoStatementV1ForPredicate {
-    pub fn builder() -> builder::InTotoStatementV1ForPredicate {
-        builder::InTotoStatementV1ForPredicate::default()
+impl CycloneDxBom {
+    pub fn builder() -> builder::CycloneDxBom {
+        builder::CycloneDxBom::default()
     }
 }
-///A structure identifying the event that kicked off the build in the SLSA Provenance v0.2 Predicate.
+///A dependency a module was built with.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct Invocation {
-    ///Description of where the config file that kicked off the build came from. This is effectively a pointer to the source where buildConfig came from.
-    #[serde(rename = "configSource", default, skip_serializing_if = "Option::is_none")]
-    pub config_source: Option<ConfigSource>,
-    ///Any other builder-controlled inputs necessary for correctly evaluating the build. Usually only needed for reproducing the build but not evaluated as part of policy.
+pub struct Dependency {
+    ///The coordinates of the dependency, `group:artifact:version`.
+    pub id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub environment: Option<serde_json::Map<String, serde_json::Value>>,
-    ///Collection of all external inputs that influenced the build on top of invocation.configSource.
+    pub md5: Option<String>,
+    ///The configurations or scopes the dependency is in, e.g. `compile` or `runtimeClasspath`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<serde_json::Map<String, serde_json::Value>>,
+    pub sha1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
 }
-impl From<&Invocation> for Invocation {
-    fn from(value: &Invocation) -> Self {
+impl From<&Dependency> for Dependency {
+    fn from(value: &Dependency) -> Self {
         value.clone()
     }
 }
-impl Invocation {
-    pub fn builder() -> builder::Invocation {
-        builder::Invocation::default()
+impl Dependency {
+    pub fn builder() -> builder::Dependency {
+        builder::Dependency::default()
     }
 }
-/**An enum representing different predicate types.
-
-Known predicate types have their own variants, while unknown types are represented by the `Other` variant, which stores the raw JSON value.
-
-TODO(mlieberman85): Support (de)serializing the predicates based on the predicateType URL in the statement.*/
+///The components a component depends on.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct Predicate {
-    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
-    pub subtype_0: Option<SlsaProvenanceV1Predicate>,
-    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
-    pub subtype_1: Option<SlsaProvenanceV02Predicate>,
-    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
-    pub subtype_2: Option<Scaiv02Predicate>,
-    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
-    pub subtype_3: Option<serde_json::Value>,
+pub struct Dependency2 {
+    ///The `bom-ref`s of the components it depends on.
+    #[serde(rename = "dependsOn", default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    ///The `bom-ref` of the depending component.
+    #[serde(rename = "ref")]
+    pub ref_: String,
 }
-impl From<&Predicate> for Predicate {
-    fn from(value: &Predicate) -> Self {
+impl From<&Dependency2> for Dependency2 {
+    fn from(value: &Dependency2) -> Self {
         value.clone()
     }
 }
-impl Predicate {
-    pub fn builder() -> builder::Predicate {
-        builder::Predicate::default()
+impl Dependency2 {
+    pub fn builder() -> builder::Dependency2 {
+        builder::Dependency2::default()
     }
 }
-///A size-efficient description of any software artifact or resource (mutable or immutable).
+///A package added or removed by the change.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct ResourceDescriptor {
-    ///This field MAY be used to provide additional information or metadata about the resource or artifact that may be useful to the consumer when evaluating the attestation against a policy.
+pub struct DependencyChange {
+    ///The advisories known against the package when it was reviewed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub advisories: Vec<Advisory>,
+    #[serde(rename = "changeType")]
+    pub change_type: ChangeType,
+    ///The SPDX license expression of the package.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub annotations: Option<serde_json::Map<String, serde_json::Value>>,
-    ///The contents of the resource or artifact. This field is REQUIRED unless either uri or digest is set.
+    pub license: Option<String>,
+    ///The path of the manifest or lockfile declaring the package.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
-    ///A set of cryptographic digests of the contents of the resource or artifact. This field is REQUIRED unless either uri or content is set.
+    pub manifest: Option<String>,
+    ///The purl of the package, with its version, e.g. `pkg:npm/lodash@4.17.21`.
+    pub purl: String,
+    ///The scope of the dependency, e.g. `runtime` or `development`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub digest: Option<std::collections::HashMap<String, String>>,
-    ///The location of the described resource or artifact, if different from the uri.
-    #[serde(
-        rename = "downloadLocation",
-        default,
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub download_location: Option<String>,
-    ///The MIME Type (i.e., media type) of the described resource or artifact.
-    #[serde(rename = "mediaType", default, skip_serializing_if = "Option::is_none")]
-    pub media_type: Option<String>,
-    ///Machine-readable identifier for distinguishing between descriptors.
+    pub scope: Option<String>,
+}
+impl From<&DependencyChange> for DependencyChange {
+    fn from(value: &DependencyChange) -> Self {
+        value.clone()
+    }
+}
+impl DependencyChange {
+    pub fn builder() -> builder::DependencyChange {
+        builder::DependencyChange::default()
+    }
+}
+///A struct representing the Dependency Review v0.1 Predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct DependencyReviewPredicate {
+    ///The revision the dependencies were compared against, e.g. the base of a pull request.
+    pub base: ResourceDescriptor,
+    ///The packages added and removed between the base and the head. Empty if the dependencies didn't change.
+    pub changes: Vec<DependencyChange>,
+    ///The revision whose dependencies were reviewed.
+    pub head: ResourceDescriptor,
+    ///The tool that reviewed the dependencies.
+    pub tool: ReviewTool,
+}
+impl From<&DependencyReviewPredicate> for DependencyReviewPredicate {
+    fn from(value: &DependencyReviewPredicate) -> Self {
+        value.clone()
+    }
+}
+impl DependencyReviewPredicate {
+    pub fn builder() -> builder::DependencyReviewPredicate {
+        builder::DependencyReviewPredicate::default()
+    }
+}
+///A license detected in the target.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct DetectedLicense {
+    ///The paths in the target the license was detected in.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+    ///The name of the license, for `LicenseRef-` identifiers.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    ///A URI used to identify the resource or artifact globally. This field is REQUIRED unless either digest or content is set.
-    pub uri: String,
+    ///The SPDX short identifier of the license, e.g. `Apache-2.0`, or a `LicenseRef-` identifier for licenses that aren't on the SPDX License List.
+    #[serde(rename = "spdxId")]
+    pub spdx_id: String,
 }
-impl From<&ResourceDescriptor> for ResourceDescriptor {
-    fn from(value: &ResourceDescriptor) -> Self {
+impl From<&DetectedLicense> for DetectedLicense {
+    fn from(value: &DetectedLicense) -> Self {
         value.clone()
     }
 }
-impl ResourceDescriptor {
-    pub fn builder() -> builder::ResourceDescriptor {
-        builder::ResourceDescriptor::default()
+impl DetectedLicense {
+    pub fn builder() -> builder::DetectedLicense {
+        builder::DetectedLicense::default()
+    }
+}
+///Represents a set of digests, mapping algorithms to their respective digest strings.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct DigestSet(pub std::collections::HashMap<String, String>);
+impl std::ops::Deref for DigestSet {
+    type Target = std::collections::HashMap<String, String>;
+    fn deref(&self) -> &std::collections::HashMap<String, String> {
+        &self.0
     }
 }
-///A size-efficient description of any software artifact or resource (mutable or immutable).
+impl From<DigestSet> for std::collections::HashMap<String, String> {
+    fn from(value: DigestSet) -> Self {
+        value.0
+    }
+}
+impl From<&DigestSet> for DigestSet {
+    fn from(value: &DigestSet) -> Self {
+        value.clone()
+    }
+}
+impl From<std::collections::HashMap<String, String>> for DigestSet {
+    fn from(value: std::collections::HashMap<String, String>) -> Self {
+        Self(value)
+    }
+}
+///The metadata of a CSAF document.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct ResourceDescriptor2 {
-    ///A set of cryptographic digests of the contents of the resource or artifact. This field is REQUIRED unless uri is set.
+pub struct DocumentMetadata {
+    ///The profile of the document, `csaf_vex` for VEX documents.
+    pub category: String,
+    ///The version of CSAF the document conforms to, `2.0`.
+    pub csaf_version: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub digest: Option<std::collections::HashMap<String, String>>,
-    ///A URI used to identify the resource or artifact globally. This field is REQUIRED unless digest is set.
+    pub distribution: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub uri: Option<String>,
+    pub lang: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<serde_json::Value>,
+    pub publisher: Publisher,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub references: Option<serde_json::Value>,
+    pub title: String,
+    pub tracking: Tracking,
 }
-impl From<&ResourceDescriptor2> for ResourceDescriptor2 {
-    fn from(value: &ResourceDescriptor2) -> Self {
+impl From<&DocumentMetadata> for DocumentMetadata {
+    fn from(value: &DocumentMetadata) -> Self {
         value.clone()
     }
 }
-impl ResourceDescriptor2 {
-    pub fn builder() -> builder::ResourceDescriptor2 {
-        builder::ResourceDescriptor2::default()
+impl DocumentMetadata {
+    pub fn builder() -> builder::DocumentMetadata {
+        builder::DocumentMetadata::default()
     }
 }
-///A structure representing the run details of the SLSA Provenance v1 Predicate.
+///The entrypoint of an image.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct RunDetails {
-    ///Identifies the build platform that executed the invocation, which is trusted to have correctly performed the operation and populated this provenance.
-    pub builder: Builder,
-    ///Additional artifacts generated during the build that are not considered the “output” of the build but that might be needed during debugging or incident response. For example, this might reference logs generated during the build and/or a digest of the fully evaluated build configuration.\nIn most cases, this SHOULD NOT contain all intermediate files generated during the build. Instead, this SHOULD only contain files that are likely to be useful later and that cannot be easily reproduced.
+pub struct Entrypoint {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub byproducts: Option<Vec<ResourceDescriptor>>,
-    ///metadata about this particular execution of the build.
+    pub command: Option<String>,
+}
+impl From<&Entrypoint> for Entrypoint {
+    fn from(value: &Entrypoint) -> Self {
+        value.clone()
+    }
+}
+impl Entrypoint {
+    pub fn builder() -> builder::Entrypoint {
+        builder::Entrypoint::default()
+    }
+}
+///A justification of why products are not affected.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Flag {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<BuildMetadata>,
+    pub date: Option<chrono::DateTime<chrono::offset::Utc>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_ids: Vec<String>,
+    pub label: FlagLabel,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_ids: Vec<String>,
 }
-impl From<&RunDetails> for RunDetails {
-    fn from(value: &RunDetails) -> Self {
+impl From<&Flag> for Flag {
+    fn from(value: &Flag) -> Self {
         value.clone()
     }
 }
-impl RunDetails {
-    pub fn builder() -> builder::RunDetails {
-        builder::RunDetails::default()
+impl Flag {
+    pub fn builder() -> builder::Flag {
+        builder::Flag::default()
+    }
+}
+///The justifications of the VEX spec for a product not being affected.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum FlagLabel {
+    #[serde(rename = "component_not_present")]
+    ComponentNotPresent,
+    #[serde(rename = "inline_mitigations_already_exist")]
+    InlineMitigationsAlreadyExist,
+    #[serde(rename = "vulnerable_code_cannot_be_controlled_by_adversary")]
+    VulnerableCodeCannotBeControlledByAdversary,
+    #[serde(rename = "vulnerable_code_not_in_execute_path")]
+    VulnerableCodeNotInExecutePath,
+    #[serde(rename = "vulnerable_code_not_present")]
+    VulnerableCodeNotPresent,
+}
+impl From<&FlagLabel> for FlagLabel {
+    fn from(value: &FlagLabel) -> Self {
+        value.clone()
+    }
+}
+impl ToString for FlagLabel {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::ComponentNotPresent => "component_not_present".to_string(),
+            Self::InlineMitigationsAlreadyExist => {
+                "inline_mitigations_already_exist".to_string()
+            }
+            Self::VulnerableCodeCannotBeControlledByAdversary => {
+                "vulnerable_code_cannot_be_controlled_by_adversary".to_string()
+            }
+            Self::VulnerableCodeNotInExecutePath => {
+                "vulnerable_code_not_in_execute_path".to_string()
+            }
+            Self::VulnerableCodeNotPresent => "vulnerable_code_not_present".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for FlagLabel {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "component_not_present" => Ok(Self::ComponentNotPresent),
+            "inline_mitigations_already_exist" => Ok(Self::InlineMitigationsAlreadyExist),
+            "vulnerable_code_cannot_be_controlled_by_adversary" => {
+                Ok(Self::VulnerableCodeCannotBeControlledByAdversary)
+            }
+            "vulnerable_code_not_in_execute_path" => {
+                Ok(Self::VulnerableCodeNotInExecutePath)
+            }
+            "vulnerable_code_not_present" => Ok(Self::VulnerableCodeNotPresent),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for FlagLabel {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
     }
 }
-///This is based on the model in: { "predicateType": "https://in-toto.io/attestation/scai/attribute-report/v0.2", "predicate": { "attributes": [{ "attribute": "<ATTRIBUTE>", "target": { [ResourceDescriptor] }, // optional "conditions": { /* object */ }, // optional "evidence": { [ResourceDescriptor] } // optional }], "producer": { [ResourceDescriptor] } // optional } } A struct representing the SCAI V0.2 Predicate.
+impl std::convert::TryFrom<&String> for FlagLabel {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for FlagLabel {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A product, with the id the rest of the document refers to it by.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct Scaiv02Predicate {
-    pub attributes: Vec<Attribute>,
+pub struct FullProductName {
+    pub name: String,
+    pub product_id: String,
+    ///Identifiers of the product outside of the document, e.g. its package URL or CPE.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub producer: Option<ResourceDescriptor>,
+    pub product_identification_helper: Option<serde_json::Value>,
 }
-impl From<&Scaiv02Predicate> for Scaiv02Predicate {
-    fn from(value: &Scaiv02Predicate) -> Self {
+impl From<&FullProductName> for FullProductName {
+    fn from(value: &FullProductName) -> Self {
         value.clone()
     }
 }
-impl Scaiv02Predicate {
-    pub fn builder() -> builder::Scaiv02Predicate {
-        builder::Scaiv02Predicate::default()
+impl FullProductName {
+    pub fn builder() -> builder::FullProductName {
+        builder::FullProductName::default()
     }
 }
-///A structure representing the SLSA Provenance v0.2 Predicate.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct SlsaProvenanceV02Predicate {
-    ///The steps in the build. If invocation.configSource is not available, buildConfig can be used to verify information about the build.
-    #[serde(rename = "buildConfig", default, skip_serializing_if = "Option::is_none")]
-    pub build_config: Option<serde_json::Map<String, serde_json::Value>>,
-    ///The type of build that was performed.
-    #[serde(rename = "buildType")]
-    pub build_type: String,
-    ///The entity that executed the invocation, which is trusted to have correctly performed the operation and populated this provenance.
-    pub builder: Builder2,
-    ///The event that kicked off the build.
+pub struct Group {
+    pub gid: u32,
+    pub groupname: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
+}
+impl From<&Group> for Group {
+    fn from(value: &Group) -> Self {
+        value.clone()
+    }
+}
+impl Group {
+    pub fn builder() -> builder::Group {
+        builder::Group::default()
+    }
+}
+///An apko image configuration.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ImageConfiguration {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub invocation: Option<Invocation>,
-    ///Unordered collection of artifacts that influenced the build including sources, dependencies, build tools, base images, and so on. Completeness is best effort, at least through SLSA Build L3. For example, if the build script fetches and executes “example.com/foo.sh”, which in turn fetches “example.com/bar.tar.gz”, then both “foo.sh” and “bar.tar.gz” SHOULD be listed here.
+    pub accounts: Option<Accounts>,
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub annotations: std::collections::HashMap<String, String>,
+    ///The architectures the image is built for, e.g. `x86_64` and `aarch64`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub archs: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub materials: Option<Vec<ResourceDescriptor2>>,
-    ///Metadata about this particular execution of the build.
+    pub cmd: Option<String>,
+    pub contents: ApkContents,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<BuildMetadata2>,
+    pub entrypoint: Option<Entrypoint>,
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub environment: std::collections::HashMap<String, String>,
+    #[serde(rename = "work-dir", default, skip_serializing_if = "Option::is_none")]
+    pub work_dir: Option<String>,
 }
-impl From<&SlsaProvenanceV02Predicate> for SlsaProvenanceV02Predicate {
-    fn from(value: &SlsaProvenanceV02Predicate) -> Self {
+impl From<&ImageConfiguration> for ImageConfiguration {
+    fn from(value: &ImageConfiguration) -> Self {
         value.clone()
     }
 }
-impl SlsaProvenanceV02Predicate {
-    pub fn builder() -> builder::SlsaProvenanceV02Predicate {
-        builder::SlsaProvenanceV02Predicate::default()
+impl ImageConfiguration {
+    pub fn builder() -> builder::ImageConfiguration {
+        builder::ImageConfiguration::default()
     }
 }
-///A structure representing the SLSA Provenance v1 Predicate.
+///Represents an In-Toto v1 statement.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct SlsaProvenanceV1Predicate {
-    #[serde(rename = "buildDefinition")]
-    pub build_definition: BuildDefinition,
-    #[serde(rename = "runDetails")]
-    pub run_details: RunDetails,
+pub struct InTotoStatementV1ForPredicate {
+    pub predicate: Predicate,
+    #[serde(rename = "predicateType")]
+    pub predicate_type: String,
+    pub subject: Vec<Subject>,
+    #[serde(rename = "_type")]
+    pub type_: String,
 }
-impl From<&SlsaProvenanceV1Predicate> for SlsaProvenanceV1Predicate {
-    fn from(value: &SlsaProvenanceV1Predicate) -> Self {
+impl From<&InTotoStatementV1ForPredicate> for InTotoStatementV1ForPredicate {
+    fn from(value: &InTotoStatementV1ForPredicate) -> Self {
         value.clone()
     }
 }
-impl SlsaProvenanceV1Predicate {
-    pub fn builder() -> builder::SlsaProvenanceV1Predicate {
-        builder::SlsaProvenanceV1Predicate::default()
+impl InTotoStatementV1ForPredicate {
+    pub fn builder() -> builder::InTotoStatementV1ForPredicate {
+        builder::InTotoStatementV1ForPredicate::default()
     }
 }
-///Represents a subject in an In-Toto v1 statement.
+///A structure identifying the event that kicked off the build in the SLSA Provenance v0.2 Predicate.
 #[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
-pub struct Subject {
-    pub digest: DigestSet,
+pub struct Invocation {
+    ///Description of where the config file that kicked off the build came from. This is effectively a pointer to the source where buildConfig came from.
+    #[serde(rename = "configSource", default, skip_serializing_if = "Option::is_none")]
+    pub config_source: Option<ConfigSource>,
+    ///Any other builder-controlled inputs necessary for correctly evaluating the build. Usually only needed for reproducing the build but not evaluated as part of policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<serde_json::Map<String, serde_json::Value>>,
+    ///Collection of all external inputs that influenced the build on top of invocation.configSource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Map<String, serde_json::Value>>,
+}
+impl From<&Invocation> for Invocation {
+    fn from(value: &Invocation) -> Self {
+        value.clone()
+    }
+}
+impl Invocation {
+    pub fn builder() -> builder::Invocation {
+        builder::Invocation::default()
+    }
+}
+///A struct representing the JVM build-info predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct JvmBuildInfoPredicate {
+    ///The CI system the build tool ran in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<Agent>,
+    ///The build tool, e.g. Gradle or Maven.
+    #[serde(rename = "buildAgent", default, skip_serializing_if = "Option::is_none")]
+    pub build_agent: Option<Agent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<Module>,
+    ///The name of the build.
     pub name: String,
+    ///The number of the build, as set by the CI system.
+    pub number: String,
+    ///The build plugins that were applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugins: Vec<Plugin>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started: Option<chrono::DateTime<chrono::offset::Utc>>,
 }
-impl From<&Subject> for Subject {
-    fn from(value: &Subject) -> Self {
+impl From<&JvmBuildInfoPredicate> for JvmBuildInfoPredicate {
+    fn from(value: &JvmBuildInfoPredicate) -> Self {
         value.clone()
     }
 }
-impl Subject {
-    pub fn builder() -> builder::Subject {
-        builder::Subject::default()
+impl JvmBuildInfoPredicate {
+    pub fn builder() -> builder::JvmBuildInfoPredicate {
+        builder::JvmBuildInfoPredicate::default()
     }
 }
-pub mod builder {
-    #[derive(Clone, Debug)]
-    pub struct Attribute {
-        attribute: Result<String, String>,
-        conditions: Result<Option<std::collections::HashMap<String, String>>, String>,
-        evidence: Result<Option<super::ResourceDescriptor>, String>,
-        target: Result<Option<super::ResourceDescriptor>, String>,
+///The severity of a result.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Level {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "note")]
+    Note,
+    #[serde(rename = "warning")]
+    Warning,
+    #[serde(rename = "error")]
+    Error,
+}
+impl From<&Level> for Level {
+    fn from(value: &Level) -> Self {
+        value.clone()
     }
-    impl Default for Attribute {
-        fn default() -> Self {
-            Self {
-                attribute: Err("no value supplied for attribute".to_string()),
-                conditions: Ok(Default::default()),
-                evidence: Ok(Default::default()),
-                target: Ok(Default::default()),
-            }
+}
+impl ToString for Level {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::None => "none".to_string(),
+            Self::Note => "note".to_string(),
+            Self::Warning => "warning".to_string(),
+            Self::Error => "error".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Level {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "none" => Ok(Self::None),
+            "note" => Ok(Self::Note),
+            "warning" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Level {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Level {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Level {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A struct representing the License Scan v0.1 Predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct LicenseScanPredicate {
+    ///The licenses detected in the target. Empty if none were found.
+    pub licenses: Vec<DetectedLicense>,
+    pub scanner: Scanner,
+    ///What was scanned, e.g. the source tree the subjects were built from.
+    pub target: ResourceDescriptor,
+    #[serde(rename = "timeScanned", default, skip_serializing_if = "Option::is_none")]
+    pub time_scanned: Option<chrono::DateTime<chrono::offset::Utc>>,
+}
+impl From<&LicenseScanPredicate> for LicenseScanPredicate {
+    fn from(value: &LicenseScanPredicate) -> Self {
+        value.clone()
+    }
+}
+impl LicenseScanPredicate {
+    pub fn builder() -> builder::LicenseScanPredicate {
+        builder::LicenseScanPredicate::default()
+    }
+}
+///A struct representing the In-Toto Link predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct LinkPredicate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub byproducts: Option<Byproducts>,
+    ///The command that was run, as its arguments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command: Vec<String>,
+    ///Information about the environment the step ran in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<serde_json::Map<String, serde_json::Value>>,
+    ///The digests of the files the step read, by path.
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub materials: std::collections::HashMap<String, DigestSet>,
+    ///The name of the step the link was recorded for.
+    pub name: String,
+    ///The digests of the files the step wrote, by path.
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub products: std::collections::HashMap<String, DigestSet>,
+}
+impl From<&LinkPredicate> for LinkPredicate {
+    fn from(value: &LinkPredicate) -> Self {
+        value.clone()
+    }
+}
+impl LinkPredicate {
+    pub fn builder() -> builder::LinkPredicate {
+        builder::LinkPredicate::default()
+    }
+}
+///Where a result was found.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Location {
+    ///Locations by the names of the functions, classes or namespaces they are in.
+    #[serde(
+        rename = "logicalLocations",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub logical_locations: Option<serde_json::Value>,
+    #[serde(
+        rename = "physicalLocation",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub physical_location: Option<PhysicalLocation>,
+}
+impl From<&Location> for Location {
+    fn from(value: &Location) -> Self {
+        value.clone()
+    }
+}
+impl Location {
+    pub fn builder() -> builder::Location {
+        builder::Location::default()
+    }
+}
+///A struct representing the melange build predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct MelangeBuildPredicate {
+    ///The build configuration the packages were built from.
+    pub config: MelangeConfiguration,
+    ///The packages that were built, the main package and its subpackages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<ApkPackage>,
+    ///The SBOMs generated for the packages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sboms: Vec<ResourceDescriptor>,
+}
+impl From<&MelangeBuildPredicate> for MelangeBuildPredicate {
+    fn from(value: &MelangeBuildPredicate) -> Self {
+        value.clone()
+    }
+}
+impl MelangeBuildPredicate {
+    pub fn builder() -> builder::MelangeBuildPredicate {
+        builder::MelangeBuildPredicate::default()
+    }
+}
+///A melange build configuration.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct MelangeConfiguration {
+    ///The build environment, which is assembled like an apko image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<BuildEnvironment>,
+    pub package: MelangePackage,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pipeline: Vec<PipelineStep>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subpackages: Vec<Subpackage>,
+}
+impl From<&MelangeConfiguration> for MelangeConfiguration {
+    fn from(value: &MelangeConfiguration) -> Self {
+        value.clone()
+    }
+}
+impl MelangeConfiguration {
+    pub fn builder() -> builder::MelangeConfiguration {
+        builder::MelangeConfiguration::default()
+    }
+}
+///The package a melange configuration builds.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct MelangePackage {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub copyright: Vec<Copyright>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    ///The number of the build of the version, the `-r` suffix of the full version.
+    pub epoch: u64,
+    pub name: String,
+    pub version: String,
+}
+impl From<&MelangePackage> for MelangePackage {
+    fn from(value: &MelangePackage) -> Self {
+        value.clone()
+    }
+}
+impl MelangePackage {
+    pub fn builder() -> builder::MelangePackage {
+        builder::MelangePackage::default()
+    }
+}
+///The message of a result, given by its `text` or by the `id` of a message of its rule.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Message {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+impl From<&Message> for Message {
+    fn from(value: &Message) -> Self {
+        value.clone()
+    }
+}
+impl Message {
+    pub fn builder() -> builder::Message {
+        builder::Message::default()
+    }
+}
+///A module the build produced.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Module {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<Artifact>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<Dependency>,
+    ///The coordinates of the module, `group:artifact:version`.
+    pub id: String,
+    ///The build tool of the module, e.g. `gradle` or `maven`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+impl From<&Module> for Module {
+    fn from(value: &Module) -> Self {
+        value.clone()
+    }
+}
+impl Module {
+    pub fn builder() -> builder::Module {
+        builder::Module::default()
+    }
+}
+///A message with a plain text and optionally a Markdown form.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct MultiformatMessage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<String>,
+    pub text: String,
+}
+impl From<&MultiformatMessage> for MultiformatMessage {
+    fn from(value: &MultiformatMessage) -> Self {
+        value.clone()
+    }
+}
+impl MultiformatMessage {
+    pub fn builder() -> builder::MultiformatMessage {
+        builder::MultiformatMessage::default()
+    }
+}
+///A location in an artifact, e.g. a range of lines of a file.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct PhysicalLocation {
+    #[serde(
+        rename = "artifactLocation",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub artifact_location: Option<ArtifactLocation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<serde_json::Value>,
+}
+impl From<&PhysicalLocation> for PhysicalLocation {
+    fn from(value: &PhysicalLocation) -> Self {
+        value.clone()
+    }
+}
+impl PhysicalLocation {
+    pub fn builder() -> builder::PhysicalLocation {
+        builder::PhysicalLocation::default()
+    }
+}
+///A step of a melange pipeline, which either uses a built-in pipeline or runs a script.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct PipelineStep {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    ///The script the step runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<String>,
+    ///The built-in pipeline, e.g. `git-checkout` or `autoconf/make`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uses: Option<String>,
+    ///The inputs of the built-in pipeline.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub with: serde_json::Map<String, serde_json::Value>,
+}
+impl From<&PipelineStep> for PipelineStep {
+    fn from(value: &PipelineStep) -> Self {
+        value.clone()
+    }
+}
+impl PipelineStep {
+    pub fn builder() -> builder::PipelineStep {
+        builder::PipelineStep::default()
+    }
+}
+///A build plugin, identified by its plugin id or coordinates.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Plugin {
+    pub id: String,
+    pub version: String,
+}
+impl From<&Plugin> for Plugin {
+    fn from(value: &Plugin) -> Self {
+        value.clone()
+    }
+}
+impl Plugin {
+    pub fn builder() -> builder::Plugin {
+        builder::Plugin::default()
+    }
+}
+/**An enum representing different predicate types.
+
+Known predicate types have their own variants, while unknown types are represented by the `Other` variant, which stores the raw JSON value. The attestation spec allows the predicate to be unset when the predicateType fully describes it; this is represented by the `Empty` variant.
+
+TODO(mlieberman85): Support (de)serializing the predicates based on the predicateType URL in the statement.*/
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Predicate {
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_0: Option<SlsaProvenanceV1Predicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_1: Option<SlsaProvenanceV02Predicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_2: Option<Scaiv02Predicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_3: Option<Scaiv03Predicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_4: Option<ApkoBuildPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_5: Option<MelangeBuildPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_6: Option<JvmBuildInfoPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_7: Option<VerificationSummaryV1Predicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_8: Option<LinkPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_9: Option<SpdxDocumentPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_10: Option<CycloneDxBom>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_11: Option<TestResultPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_12: Option<SarifLog>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_13: Option<CsafDocument>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_14: Option<ReferencePredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_15: Option<LicenseScanPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_16: Option<CodeReviewPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_17: Option<DependencyReviewPredicate>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_18: Option<serde_json::Value>,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub subtype_19: Option<()>,
+}
+impl From<&Predicate> for Predicate {
+    fn from(value: &Predicate) -> Self {
+        value.clone()
+    }
+}
+impl Predicate {
+    pub fn builder() -> builder::Predicate {
+        builder::Predicate::default()
+    }
+}
+///A group of products, that flags, threats and remediations can refer to at once.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ProductGroup {
+    pub group_id: String,
+    pub product_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+impl From<&ProductGroup> for ProductGroup {
+    fn from(value: &ProductGroup) -> Self {
+        value.clone()
+    }
+}
+impl ProductGroup {
+    pub fn builder() -> builder::ProductGroup {
+        builder::ProductGroup::default()
+    }
+}
+///The products in each status towards a vulnerability, by product id.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ProductStatus {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub first_affected: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub first_fixed: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixed: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub known_affected: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub known_not_affected: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub last_affected: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recommended: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub under_investigation: Vec<String>,
+}
+impl From<&ProductStatus> for ProductStatus {
+    fn from(value: &ProductStatus) -> Self {
+        value.clone()
+    }
+}
+impl ProductStatus {
+    pub fn builder() -> builder::ProductStatus {
+        builder::ProductStatus::default()
+    }
+}
+///The products a document is about, which the vulnerabilities refer to by product id.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ProductTree {
+    ///The products, grouped by vendor, product name and version.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<Branch>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub full_product_names: Vec<FullProductName>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_groups: Vec<ProductGroup>,
+    ///Products made of other products, e.g. a component installed on a platform.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<Relationship>,
+}
+impl From<&ProductTree> for ProductTree {
+    fn from(value: &ProductTree) -> Self {
+        value.clone()
+    }
+}
+impl ProductTree {
+    pub fn builder() -> builder::ProductTree {
+        builder::ProductTree::default()
+    }
+}
+///The organization that published the document.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Publisher {
+    ///The kind of publisher, e.g. `vendor` or `coordinator`.
+    pub category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_details: Option<String>,
+    pub name: String,
+    ///The URL of the publisher's namespace, which with the tracking id identifies the document.
+    pub namespace: String,
+}
+impl From<&Publisher> for Publisher {
+    fn from(value: &Publisher) -> Self {
+        value.clone()
+    }
+}
+impl Publisher {
+    pub fn builder() -> builder::Publisher {
+        builder::Publisher::default()
+    }
+}
+///A struct representing the Reference v0.1 Predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ReferencePredicate {
+    pub attester: Attester,
+    ///The documents the subjects refer to, e.g. their SBOMs. Each is located by its `uri` or `downloadLocation` and pinned by its `digest`.
+    pub references: Vec<ResourceDescriptor>,
+}
+impl From<&ReferencePredicate> for ReferencePredicate {
+    fn from(value: &ReferencePredicate) -> Self {
+        value.clone()
+    }
+}
+impl ReferencePredicate {
+    pub fn builder() -> builder::ReferencePredicate {
+        builder::ReferencePredicate::default()
+    }
+}
+///A product made of two other products.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Relationship {
+    ///How the products relate, e.g. `default_component_of` or `installed_on`.
+    pub category: String,
+    pub full_product_name: FullProductName,
+    pub product_reference: String,
+    pub relates_to_product_reference: String,
+}
+impl From<&Relationship> for Relationship {
+    fn from(value: &Relationship) -> Self {
+        value.clone()
+    }
+}
+impl Relationship {
+    pub fn builder() -> builder::Relationship {
+        builder::Relationship::default()
+    }
+}
+///A remediation of the vulnerability in products.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Remediation {
+    ///The kind of remediation, e.g. `vendor_fix`, `workaround` or `none_available`.
+    pub category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub details: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+impl From<&Remediation> for Remediation {
+    fn from(value: &Remediation) -> Self {
+        value.clone()
+    }
+}
+impl Remediation {
+    pub fn builder() -> builder::Remediation {
+        builder::Remediation::default()
+    }
+}
+///A rule of an analysis tool.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ReportingDescriptor {
+    ///The level and other settings results of the rule have unless they say otherwise.
+    #[serde(
+        rename = "defaultConfiguration",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_configuration: Option<serde_json::Value>,
+    #[serde(rename = "helpUri", default, skip_serializing_if = "Option::is_none")]
+    pub help_uri: Option<String>,
+    ///A stable identifier of the rule, e.g. `js/xss` or `CVE-2024-3094`.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<serde_json::Value>,
+    #[serde(
+        rename = "shortDescription",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub short_description: Option<MultiformatMessage>,
+}
+impl From<&ReportingDescriptor> for ReportingDescriptor {
+    fn from(value: &ReportingDescriptor) -> Self {
+        value.clone()
+    }
+}
+impl ReportingDescriptor {
+    pub fn builder() -> builder::ReportingDescriptor {
+        builder::ReportingDescriptor::default()
+    }
+}
+/**A size-efficient description of any software artifact or resource (mutable or immutable).
+
+The spec requires at least one of `uri`, `digest` or `content` to be set.*/
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ResourceDescriptor {
+    ///This field MAY be used to provide additional information or metadata about the resource or artifact that may be useful to the consumer when evaluating the attestation against a policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<serde_json::Map<String, serde_json::Value>>,
+    ///The contents of the resource or artifact. This field is REQUIRED unless either uri or digest is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    ///A set of cryptographic digests of the contents of the resource or artifact. This field is REQUIRED unless either uri or content is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<std::collections::HashMap<String, String>>,
+    ///The location of the described resource or artifact, if different from the uri.
+    #[serde(
+        rename = "downloadLocation",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub download_location: Option<String>,
+    ///The MIME Type (i.e., media type) of the described resource or artifact.
+    #[serde(rename = "mediaType", default, skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+    ///Machine-readable identifier for distinguishing between descriptors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    ///A URI used to identify the resource or artifact globally. This field is REQUIRED unless either digest or content is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+impl From<&ResourceDescriptor> for ResourceDescriptor {
+    fn from(value: &ResourceDescriptor) -> Self {
+        value.clone()
+    }
+}
+impl ResourceDescriptor {
+    pub fn builder() -> builder::ResourceDescriptor {
+        builder::ResourceDescriptor::default()
+    }
+}
+///The tool a review took place in.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ReviewTool {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl From<&ReviewTool> for ReviewTool {
+    fn from(value: &ReviewTool) -> Self {
+        value.clone()
+    }
+}
+impl ReviewTool {
+    pub fn builder() -> builder::ReviewTool {
+        builder::ReviewTool::default()
+    }
+}
+///A reviewer of a change.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Reviewer {
+    ///The id of the reviewer in the review tool.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+impl From<&Reviewer> for Reviewer {
+    fn from(value: &Reviewer) -> Self {
+        value.clone()
+    }
+}
+impl Reviewer {
+    pub fn builder() -> builder::Reviewer {
+        builder::Reviewer::default()
+    }
+}
+///A revision of a document.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Revision {
+    pub date: chrono::DateTime<chrono::offset::Utc>,
+    pub number: String,
+    pub summary: String,
+}
+impl From<&Revision> for Revision {
+    fn from(value: &Revision) -> Self {
+        value.clone()
+    }
+}
+impl Revision {
+    pub fn builder() -> builder::Revision {
+        builder::Revision::default()
+    }
+}
+///A run of a single analysis tool.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Run {
+    ///The results of the run. Absent if the tool failed to run, as opposed to empty if it found nothing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<SarifResult>>,
+    pub tool: Tool,
+}
+impl From<&Run> for Run {
+    fn from(value: &Run) -> Self {
+        value.clone()
+    }
+}
+impl Run {
+    pub fn builder() -> builder::Run {
+        builder::Run::default()
+    }
+}
+///A structure representing the run details of the SLSA Provenance v1 Predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct RunDetails {
+    ///Identifies the build platform that executed the invocation, which is trusted to have correctly performed the operation and populated this provenance.
+    pub builder: Builder,
+    ///Additional artifacts generated during the build that are not considered the “output” of the build but that might be needed during debugging or incident response. For example, this might reference logs generated during the build and/or a digest of the fully evaluated build configuration.\nIn most cases, this SHOULD NOT contain all intermediate files generated during the build. Instead, this SHOULD only contain files that are likely to be useful later and that cannot be easily reproduced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub byproducts: Option<Vec<ResourceDescriptor>>,
+    ///metadata about this particular execution of the build.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<BuildMetadata>,
+}
+impl From<&RunDetails> for RunDetails {
+    fn from(value: &RunDetails) -> Self {
+        value.clone()
+    }
+}
+impl RunDetails {
+    pub fn builder() -> builder::RunDetails {
+        builder::RunDetails::default()
+    }
+}
+///A struct representing a SARIF log.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct SarifLog {
+    ///The runs of analysis tools the log contains.
+    pub runs: Vec<Run>,
+    ///The URI of the SARIF JSON schema the log conforms to.
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    ///The version of SARIF the log conforms to, `2.1.0`.
+    pub version: String,
+}
+impl From<&SarifLog> for SarifLog {
+    fn from(value: &SarifLog) -> Self {
+        value.clone()
+    }
+}
+impl SarifLog {
+    pub fn builder() -> builder::SarifLog {
+        builder::SarifLog::default()
+    }
+}
+///A result found by an analysis tool.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct SarifResult {
+    ///Identifiers of the result that are stable across runs, by the algorithm that computed them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprints: Option<std::collections::HashMap<String, String>>,
+    ///The kind of result, e.g. `fail` or `pass`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<Level>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<Location>,
+    pub message: Message,
+    #[serde(
+        rename = "partialFingerprints",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub partial_fingerprints: Option<std::collections::HashMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<serde_json::Value>,
+    ///The id of the rule the result was found by.
+    #[serde(rename = "ruleId", default, skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    ///The index of the rule in the driver's `rules`, or -1 if it isn't known.
+    #[serde(rename = "ruleIndex", default, skip_serializing_if = "Option::is_none")]
+    pub rule_index: Option<i64>,
+}
+impl From<&SarifResult> for SarifResult {
+    fn from(value: &SarifResult) -> Self {
+        value.clone()
+    }
+}
+impl SarifResult {
+    pub fn builder() -> builder::SarifResult {
+        builder::SarifResult::default()
+    }
+}
+/**This is based on the model in: { "predicateType": "https://in-toto.io/attestation/scai/attribute-report/v0.2", "predicate": { "attributes": [{ "attribute": "<ATTRIBUTE>", "target": { [ResourceDescriptor] }, // optional "conditions": { /* object */ }, // optional "evidence": { [ResourceDescriptor] } // optional }], "producer": { [ResourceDescriptor] } // optional } }
+
+A struct representing the SCAI V0.2 Predicate.*/
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Scaiv02Predicate {
+    pub attributes: Vec<Attribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub producer: Option<ResourceDescriptor>,
+}
+impl From<&Scaiv02Predicate> for Scaiv02Predicate {
+    fn from(value: &Scaiv02Predicate) -> Self {
+        value.clone()
+    }
+}
+impl Scaiv02Predicate {
+    pub fn builder() -> builder::Scaiv02Predicate {
+        builder::Scaiv02Predicate::default()
+    }
+}
+/**This is based on the model in: { "predicateType": "https://in-toto.io/attestation/scai/v0.3", "predicate": { "attributes": [{ "attribute": "<ATTRIBUTE>", "target": { [ResourceDescriptor] }, // optional "conditions": { /* object */ }, // optional "evidence": { [ResourceDescriptor] } // optional }], "producer": { [ResourceDescriptor] } // optional } }
+
+A struct representing the SCAI V0.3 Predicate. v0.3 dropped `attribute-report` from the predicateType, and the conditions of an attribute assertion can be any JSON object.*/
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Scaiv03Predicate {
+    pub attributes: Vec<AttributeAssertion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub producer: Option<ResourceDescriptor>,
+}
+impl From<&Scaiv03Predicate> for Scaiv03Predicate {
+    fn from(value: &Scaiv03Predicate) -> Self {
+        value.clone()
+    }
+}
+impl Scaiv03Predicate {
+    pub fn builder() -> builder::Scaiv03Predicate {
+        builder::Scaiv03Predicate::default()
+    }
+}
+///The license scanner that ran.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Scanner {
+    ///The version of the SPDX License List the scanner's identifiers are from, e.g. `3.25`.
+    #[serde(
+        rename = "licenseListVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_list_version: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl From<&Scanner> for Scanner {
+    fn from(value: &Scanner) -> Self {
+        value.clone()
+    }
+}
+impl Scanner {
+    pub fn builder() -> builder::Scanner {
+        builder::Scanner::default()
+    }
+}
+///A structure representing the SLSA Provenance v0.2 Predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct SlsaProvenanceV02Predicate {
+    ///The steps in the build. If invocation.configSource is not available, buildConfig can be used to verify information about the build.
+    #[serde(rename = "buildConfig", default, skip_serializing_if = "Option::is_none")]
+    pub build_config: Option<serde_json::Value>,
+    ///The type of build that was performed.
+    #[serde(rename = "buildType")]
+    pub build_type: String,
+    ///The entity that executed the invocation, which is trusted to have correctly performed the operation and populated this provenance.
+    pub builder: Builder2,
+    ///The event that kicked off the build.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invocation: Option<Invocation>,
+    ///Unordered collection of artifacts that influenced the build including sources, dependencies, build tools, base images, and so on. Completeness is best effort, at least through SLSA Build L3. For example, if the build script fetches and executes “example.com/foo.sh”, which in turn fetches “example.com/bar.tar.gz”, then both “foo.sh” and “bar.tar.gz” SHOULD be listed here.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub materials: Option<Vec<ResourceDescriptor>>,
+    ///Metadata about this particular execution of the build.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<BuildMetadata2>,
+}
+impl From<&SlsaProvenanceV02Predicate> for SlsaProvenanceV02Predicate {
+    fn from(value: &SlsaProvenanceV02Predicate) -> Self {
+        value.clone()
+    }
+}
+impl SlsaProvenanceV02Predicate {
+    pub fn builder() -> builder::SlsaProvenanceV02Predicate {
+        builder::SlsaProvenanceV02Predicate::default()
+    }
+}
+///A structure representing the SLSA Provenance v1 Predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct SlsaProvenanceV1Predicate {
+    #[serde(rename = "buildDefinition")]
+    pub build_definition: BuildDefinition,
+    #[serde(rename = "runDetails")]
+    pub run_details: RunDetails,
+}
+impl From<&SlsaProvenanceV1Predicate> for SlsaProvenanceV1Predicate {
+    fn from(value: &SlsaProvenanceV1Predicate) -> Self {
+        value.clone()
+    }
+}
+impl SlsaProvenanceV1Predicate {
+    pub fn builder() -> builder::SlsaProvenanceV1Predicate {
+        builder::SlsaProvenanceV1Predicate::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22Document {
+    ///Provide additional information about an SpdxElement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Spdx22DocumentAnnotationsItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(rename = "creationInfo", default, skip_serializing_if = "Option::is_none")]
+    pub creation_info: Option<Spdx22DocumentCreationInfo>,
+    ///License expression for dataLicense.  Compliance with the SPDX specification includes populating the SPDX fields therein with data related to such fields ("SPDX-Metadata"). The SPDX specification contains numerous fields where an SPDX document creator may provide relevant explanatory text in SPDX-Metadata. Without opining on the lawfulness of "database rights" (in jurisdictions where applicable), such explanatory text is copyrightable subject matter in most Berne Convention countries. By using the SPDX specification, or any portion hereof, you hereby agree that any copyright rights (as determined by your jurisdiction) in any SPDX-Metadata, including without limitation explanatory text, shall be subject to the terms of the Creative Commons CC0 1.0 Universal license. For SPDX-Metadata not containing any copyright rights, you hereby agree and acknowledge that the SPDX-Metadata is provided to you "as-is" and without any representations or warranties of any kind concerning the SPDX-Metadata, express, implied, statutory or otherwise, including without limitation warranties of title, merchantability, fitness for a particular purpose, non-infringement, or the absence of latent or other defects, accuracy, or the presence or absence of errors, whether or not discoverable, all to the greatest extent permissible under applicable law.
+    #[serde(rename = "dataLicense", default, skip_serializing_if = "Option::is_none")]
+    pub data_license: Option<String>,
+    ///The describesPackage property relates an SpdxDocument to the package which it describes.
+    #[serde(
+        rename = "describesPackages",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub describes_packages: Vec<String>,
+    ///Identify any external SPDX documents referenced within this SPDX document.
+    #[serde(
+        rename = "externalDocumentRefs",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub external_document_refs: Vec<Spdx22DocumentExternalDocumentRefsItem>,
+    ///Files referenced in the SPDX document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<Spdx22DocumentFilesItem>,
+    ///Indicates that a particular ExtractedLicensingInfo was defined in the subject SpdxDocument.
+    #[serde(
+        rename = "hasExtractedLicensingInfos",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub has_extracted_licensing_infos: Vec<Spdx22DocumentHasExtractedLicensingInfosItem>,
+    ///Identify name of this SpdxElement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    ///Packages referenced in the SPDX document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<Spdx22DocumentPackagesItem>,
+    ///Relationships referenced in the SPDX document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<Spdx22DocumentRelationshipsItem>,
+    ///Reviewed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub revieweds: Vec<Spdx22DocumentReviewedsItem>,
+    ///Snippets referenced in the SPDX document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub snippets: Vec<Spdx22DocumentSnippetsItem>,
+    ///Provide a reference number that can be used to understand how to parse and interpret the rest of the file. It will enable both future changes to the specification and to support backward compatibility. The version number consists of a major and minor version indicator. The major field will be incremented when incompatible changes between versions are made (one or more sections are created, modified or deleted). The minor field will be incremented when backwards compatible changes are made.
+    #[serde(rename = "spdxVersion", default, skip_serializing_if = "Option::is_none")]
+    pub spdx_version: Option<String>,
+}
+impl From<&Spdx22Document> for Spdx22Document {
+    fn from(value: &Spdx22Document) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22Document {
+    pub fn builder() -> builder::Spdx22Document {
+        builder::Spdx22Document::default()
+    }
+}
+///An Annotation is a comment on an SpdxItem by an agent.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentAnnotationsItem {
+    ///Identify when the comment was made. This is to be specified according to the combined date and time in the UTC format, as specified in the ISO 8601 standard.
+    #[serde(rename = "annotationDate", default, skip_serializing_if = "Option::is_none")]
+    pub annotation_date: Option<String>,
+    ///Type of the annotation.
+    #[serde(rename = "annotationType", default, skip_serializing_if = "Option::is_none")]
+    pub annotation_type: Option<Spdx22DocumentAnnotationsItemAnnotationType>,
+    ///This field identifies the person, organization or tool that has commented on a file, package, or the entire document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+impl From<&Spdx22DocumentAnnotationsItem> for Spdx22DocumentAnnotationsItem {
+    fn from(value: &Spdx22DocumentAnnotationsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentAnnotationsItem {
+    pub fn builder() -> builder::Spdx22DocumentAnnotationsItem {
+        builder::Spdx22DocumentAnnotationsItem::default()
+    }
+}
+///Type of the annotation.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentAnnotationsItemAnnotationType {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "REVIEW")]
+    Review,
+}
+impl From<&Spdx22DocumentAnnotationsItemAnnotationType>
+for Spdx22DocumentAnnotationsItemAnnotationType {
+    fn from(value: &Spdx22DocumentAnnotationsItemAnnotationType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentAnnotationsItemAnnotationType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Review => "REVIEW".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentAnnotationsItemAnnotationType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "REVIEW" => Ok(Self::Review),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx22DocumentAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx22DocumentAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx22DocumentAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///One instance is required for each SPDX file produced. It provides the necessary information for forward and backward compatibility for processing tools.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentCreationInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///Identify when the SPDX file was originally created. The date is to be specified according to combined date and time in UTC format as specified in ISO 8601 standard. This field is distinct from the fields in section 8, which involves the addition of information during a subsequent review.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    ///Identify who (or what, in the case of a tool) created the SPDX file. If the SPDX file was created by an individual, indicate the person's name. If the SPDX file was created on behalf of a company or organization, indicate the entity name. If the SPDX file was created using a software tool, indicate the name and version for that tool. If multiple participants or tools were involved, use multiple instances of this field. Person name or organization name may be designated as “anonymous” if appropriate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub creators: Vec<String>,
+    ///An optional field for creators of the SPDX file to provide the version of the SPDX License List used when the SPDX file was created.
+    #[serde(
+        rename = "licenseListVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_list_version: Option<String>,
+}
+impl From<&Spdx22DocumentCreationInfo> for Spdx22DocumentCreationInfo {
+    fn from(value: &Spdx22DocumentCreationInfo) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentCreationInfo {
+    pub fn builder() -> builder::Spdx22DocumentCreationInfo {
+        builder::Spdx22DocumentCreationInfo::default()
+    }
+}
+///Information about an external SPDX document reference including the checksum. This allows for verification of the external references.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentExternalDocumentRefsItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<Spdx22DocumentExternalDocumentRefsItemChecksum>,
+    ///externalDocumentId is a string containing letters, numbers, ., - and/or + which uniquely identifies an external document within this document.
+    #[serde(
+        rename = "externalDocumentId",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub external_document_id: Option<String>,
+    ///SPDX ID for SpdxDocument.  A propoerty containing an SPDX document.
+    #[serde(rename = "spdxDocument", default, skip_serializing_if = "Option::is_none")]
+    pub spdx_document: Option<String>,
+}
+impl From<&Spdx22DocumentExternalDocumentRefsItem>
+for Spdx22DocumentExternalDocumentRefsItem {
+    fn from(value: &Spdx22DocumentExternalDocumentRefsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentExternalDocumentRefsItem {
+    pub fn builder() -> builder::Spdx22DocumentExternalDocumentRefsItem {
+        builder::Spdx22DocumentExternalDocumentRefsItem::default()
+    }
+}
+///A Checksum is value that allows the contents of a file to be authenticated. Even small changes to the content of the file will change its checksum. This class allows the results of a variety of checksum and cryptographic message digest algorithms to be represented.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentExternalDocumentRefsItemChecksum {
+    ///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm>,
+    ///The checksumValue property provides a lower case hexidecimal encoded digest value produced using a specific algorithm.
+    #[serde(rename = "checksumValue", default, skip_serializing_if = "Option::is_none")]
+    pub checksum_value: Option<String>,
+}
+impl From<&Spdx22DocumentExternalDocumentRefsItemChecksum>
+for Spdx22DocumentExternalDocumentRefsItemChecksum {
+    fn from(value: &Spdx22DocumentExternalDocumentRefsItemChecksum) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentExternalDocumentRefsItemChecksum {
+    pub fn builder() -> builder::Spdx22DocumentExternalDocumentRefsItemChecksum {
+        builder::Spdx22DocumentExternalDocumentRefsItemChecksum::default()
+    }
+}
+///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm {
+    #[serde(rename = "SHA256")]
+    Sha256,
+    #[serde(rename = "SHA1")]
+    Sha1,
+    #[serde(rename = "SHA384")]
+    Sha384,
+    #[serde(rename = "MD2")]
+    Md2,
+    #[serde(rename = "MD4")]
+    Md4,
+    #[serde(rename = "SHA512")]
+    Sha512,
+    #[serde(rename = "MD6")]
+    Md6,
+    #[serde(rename = "MD5")]
+    Md5,
+    #[serde(rename = "SHA224")]
+    Sha224,
+}
+impl From<&Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm>
+for Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm {
+    fn from(value: &Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Sha256 => "SHA256".to_string(),
+            Self::Sha1 => "SHA1".to_string(),
+            Self::Sha384 => "SHA384".to_string(),
+            Self::Md2 => "MD2".to_string(),
+            Self::Md4 => "MD4".to_string(),
+            Self::Sha512 => "SHA512".to_string(),
+            Self::Md6 => "MD6".to_string(),
+            Self::Md5 => "MD5".to_string(),
+            Self::Sha224 => "SHA224".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "SHA256" => Ok(Self::Sha256),
+            "SHA1" => Ok(Self::Sha1),
+            "SHA384" => Ok(Self::Sha384),
+            "MD2" => Ok(Self::Md2),
+            "MD4" => Ok(Self::Md4),
+            "SHA512" => Ok(Self::Sha512),
+            "MD6" => Ok(Self::Md6),
+            "MD5" => Ok(Self::Md5),
+            "SHA224" => Ok(Self::Sha224),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str>
+for Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String>
+for Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String>
+for Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentFilesItem {
+    ///Provide additional information about an SpdxElement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Spdx22DocumentFilesItemAnnotationsItem>,
+    ///Indicates the project in which the SpdxElement originated. Tools must preserve doap:homepage and doap:name properties and the URI (if one is known) of doap:Project resources that are values of this property. All other properties of doap:Projects are not directly supported by SPDX and may be dropped when translating to or from some SPDX formats.
+    #[serde(rename = "artifactOfs", default, skip_serializing_if = "Vec::is_empty")]
+    pub artifact_ofs: Vec<serde_json::Map<String, serde_json::Value>>,
+    ///This field provides a place for the SPDX data creator to record acknowledgements that may be required to be communicated in some contexts. This is not meant to include theactual complete license text (see licenseConculded and licenseDeclared), and may or may not include copyright notices (see also copyrightText). The SPDX data creator may use this field to record other acknowledgements, such as particular clauses from license texts, which may be necessary or desirable to reproduce.
+    #[serde(rename = "attributionTexts", default, skip_serializing_if = "Vec::is_empty")]
+    pub attribution_texts: Vec<String>,
+    ///The checksum property provides a mechanism that can be used to verify that the contents of a File or Package have not changed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksums: Vec<Spdx22DocumentFilesItemChecksumsItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///The text of copyright declarations recited in the Package or File.
+    #[serde(rename = "copyrightText", default, skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    ///This field provides a place for the SPDX file creator to record file contributors. Contributors could include names of copyright holders and/or authors who may not be copyright holders yet contributed to the file content.
+    #[serde(rename = "fileContributors", default, skip_serializing_if = "Vec::is_empty")]
+    pub file_contributors: Vec<String>,
+    #[serde(rename = "fileDependencies", default, skip_serializing_if = "Vec::is_empty")]
+    pub file_dependencies: Vec<String>,
+    ///The name of the file relative to the root of the package.
+    #[serde(rename = "fileName", default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    ///The type of the file.
+    #[serde(rename = "fileTypes", default, skip_serializing_if = "Vec::is_empty")]
+    pub file_types: Vec<Spdx22DocumentFilesItemFileTypesItem>,
+    ///The licenseComments property allows the preparer of the SPDX document to describe why the licensing in spdx:licenseConcluded was chosen.
+    #[serde(
+        rename = "licenseComments",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_comments: Option<String>,
+    ///The licensing information that was discovered directly within the package. There will be an instance of this property for each distinct value of alllicenseInfoInFile properties of all files contained in the package.
+    #[serde(
+        rename = "licenseInfoFromFiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub license_info_from_files: Vec<String>,
+    ///Licensing information that was discovered directly in the subject file. This is also considered a declared license for the file.
+    #[serde(
+        rename = "licenseInfoInFiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub license_info_in_files: Vec<String>,
+    ///Identify name of this SpdxElement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    ///This field provides a place for the SPDX file creator to record potential legal notices found in the file. This may or may not include copyright statements.
+    #[serde(rename = "noticeText", default, skip_serializing_if = "Option::is_none")]
+    pub notice_text: Option<String>,
+}
+impl From<&Spdx22DocumentFilesItem> for Spdx22DocumentFilesItem {
+    fn from(value: &Spdx22DocumentFilesItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentFilesItem {
+    pub fn builder() -> builder::Spdx22DocumentFilesItem {
+        builder::Spdx22DocumentFilesItem::default()
+    }
+}
+///An Annotation is a comment on an SpdxItem by an agent.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentFilesItemAnnotationsItem {
+    ///Identify when the comment was made. This is to be specified according to the combined date and time in the UTC format, as specified in the ISO 8601 standard.
+    #[serde(rename = "annotationDate", default, skip_serializing_if = "Option::is_none")]
+    pub annotation_date: Option<String>,
+    ///Type of the annotation.
+    #[serde(rename = "annotationType", default, skip_serializing_if = "Option::is_none")]
+    pub annotation_type: Option<Spdx22DocumentFilesItemAnnotationsItemAnnotationType>,
+    ///This field identifies the person, organization or tool that has commented on a file, package, or the entire document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+impl From<&Spdx22DocumentFilesItemAnnotationsItem>
+for Spdx22DocumentFilesItemAnnotationsItem {
+    fn from(value: &Spdx22DocumentFilesItemAnnotationsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentFilesItemAnnotationsItem {
+    pub fn builder() -> builder::Spdx22DocumentFilesItemAnnotationsItem {
+        builder::Spdx22DocumentFilesItemAnnotationsItem::default()
+    }
+}
+///Type of the annotation.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentFilesItemAnnotationsItemAnnotationType {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "REVIEW")]
+    Review,
+}
+impl From<&Spdx22DocumentFilesItemAnnotationsItemAnnotationType>
+for Spdx22DocumentFilesItemAnnotationsItemAnnotationType {
+    fn from(value: &Spdx22DocumentFilesItemAnnotationsItemAnnotationType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentFilesItemAnnotationsItemAnnotationType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Review => "REVIEW".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentFilesItemAnnotationsItemAnnotationType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "REVIEW" => Ok(Self::Review),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str>
+for Spdx22DocumentFilesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String>
+for Spdx22DocumentFilesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String>
+for Spdx22DocumentFilesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A Checksum is value that allows the contents of a file to be authenticated. Even small changes to the content of the file will change its checksum. This class allows the results of a variety of checksum and cryptographic message digest algorithms to be represented.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentFilesItemChecksumsItem {
+    ///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<Spdx22DocumentFilesItemChecksumsItemAlgorithm>,
+    ///The checksumValue property provides a lower case hexidecimal encoded digest value produced using a specific algorithm.
+    #[serde(rename = "checksumValue", default, skip_serializing_if = "Option::is_none")]
+    pub checksum_value: Option<String>,
+}
+impl From<&Spdx22DocumentFilesItemChecksumsItem>
+for Spdx22DocumentFilesItemChecksumsItem {
+    fn from(value: &Spdx22DocumentFilesItemChecksumsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentFilesItemChecksumsItem {
+    pub fn builder() -> builder::Spdx22DocumentFilesItemChecksumsItem {
+        builder::Spdx22DocumentFilesItemChecksumsItem::default()
+    }
+}
+///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentFilesItemChecksumsItemAlgorithm {
+    #[serde(rename = "SHA256")]
+    Sha256,
+    #[serde(rename = "SHA1")]
+    Sha1,
+    #[serde(rename = "SHA384")]
+    Sha384,
+    #[serde(rename = "MD2")]
+    Md2,
+    #[serde(rename = "MD4")]
+    Md4,
+    #[serde(rename = "SHA512")]
+    Sha512,
+    #[serde(rename = "MD6")]
+    Md6,
+    #[serde(rename = "MD5")]
+    Md5,
+    #[serde(rename = "SHA224")]
+    Sha224,
+}
+impl From<&Spdx22DocumentFilesItemChecksumsItemAlgorithm>
+for Spdx22DocumentFilesItemChecksumsItemAlgorithm {
+    fn from(value: &Spdx22DocumentFilesItemChecksumsItemAlgorithm) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentFilesItemChecksumsItemAlgorithm {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Sha256 => "SHA256".to_string(),
+            Self::Sha1 => "SHA1".to_string(),
+            Self::Sha384 => "SHA384".to_string(),
+            Self::Md2 => "MD2".to_string(),
+            Self::Md4 => "MD4".to_string(),
+            Self::Sha512 => "SHA512".to_string(),
+            Self::Md6 => "MD6".to_string(),
+            Self::Md5 => "MD5".to_string(),
+            Self::Sha224 => "SHA224".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentFilesItemChecksumsItemAlgorithm {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "SHA256" => Ok(Self::Sha256),
+            "SHA1" => Ok(Self::Sha1),
+            "SHA384" => Ok(Self::Sha384),
+            "MD2" => Ok(Self::Md2),
+            "MD4" => Ok(Self::Md4),
+            "SHA512" => Ok(Self::Sha512),
+            "MD6" => Ok(Self::Md6),
+            "MD5" => Ok(Self::Md5),
+            "SHA224" => Ok(Self::Sha224),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx22DocumentFilesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx22DocumentFilesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx22DocumentFilesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///The type of the file.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentFilesItemFileTypesItem {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "DOCUMENTATION")]
+    Documentation,
+    #[serde(rename = "IMAGE")]
+    Image,
+    #[serde(rename = "VIDEO")]
+    Video,
+    #[serde(rename = "ARCHIVE")]
+    Archive,
+    #[serde(rename = "SPDX")]
+    Spdx,
+    #[serde(rename = "APPLICATION")]
+    Application,
+    #[serde(rename = "SOURCE")]
+    Source,
+    #[serde(rename = "BINARY")]
+    Binary,
+    #[serde(rename = "TEXT")]
+    Text,
+    #[serde(rename = "AUDIO")]
+    Audio,
+}
+impl From<&Spdx22DocumentFilesItemFileTypesItem>
+for Spdx22DocumentFilesItemFileTypesItem {
+    fn from(value: &Spdx22DocumentFilesItemFileTypesItem) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentFilesItemFileTypesItem {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Documentation => "DOCUMENTATION".to_string(),
+            Self::Image => "IMAGE".to_string(),
+            Self::Video => "VIDEO".to_string(),
+            Self::Archive => "ARCHIVE".to_string(),
+            Self::Spdx => "SPDX".to_string(),
+            Self::Application => "APPLICATION".to_string(),
+            Self::Source => "SOURCE".to_string(),
+            Self::Binary => "BINARY".to_string(),
+            Self::Text => "TEXT".to_string(),
+            Self::Audio => "AUDIO".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentFilesItemFileTypesItem {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "DOCUMENTATION" => Ok(Self::Documentation),
+            "IMAGE" => Ok(Self::Image),
+            "VIDEO" => Ok(Self::Video),
+            "ARCHIVE" => Ok(Self::Archive),
+            "SPDX" => Ok(Self::Spdx),
+            "APPLICATION" => Ok(Self::Application),
+            "SOURCE" => Ok(Self::Source),
+            "BINARY" => Ok(Self::Binary),
+            "TEXT" => Ok(Self::Text),
+            "AUDIO" => Ok(Self::Audio),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx22DocumentFilesItemFileTypesItem {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx22DocumentFilesItemFileTypesItem {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx22DocumentFilesItemFileTypesItem {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///An ExtractedLicensingInfo represents a license or licensing notice that was found in the package. Any license text that is recognized as a license may be represented as a License rather than an ExtractedLicensingInfo.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentHasExtractedLicensingInfosItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///Verbatim license or licensing notice text that was discovered.
+    #[serde(rename = "extractedText", default, skip_serializing_if = "Option::is_none")]
+    pub extracted_text: Option<String>,
+    ///A human readable short form license identifier for a license. The license ID is iether on the standard license oist or the form "LicenseRef-"[idString] where [idString] is a unique string containing letters, numbers, ".", "-" or "+".
+    #[serde(rename = "licenseId", default, skip_serializing_if = "Option::is_none")]
+    pub license_id: Option<String>,
+    ///Identify name of this SpdxElement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "seeAlsos", default, skip_serializing_if = "Vec::is_empty")]
+    pub see_alsos: Vec<String>,
+}
+impl From<&Spdx22DocumentHasExtractedLicensingInfosItem>
+for Spdx22DocumentHasExtractedLicensingInfosItem {
+    fn from(value: &Spdx22DocumentHasExtractedLicensingInfosItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentHasExtractedLicensingInfosItem {
+    pub fn builder() -> builder::Spdx22DocumentHasExtractedLicensingInfosItem {
+        builder::Spdx22DocumentHasExtractedLicensingInfosItem::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentPackagesItem {
+    ///Provide additional information about an SpdxElement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Spdx22DocumentPackagesItemAnnotationsItem>,
+    ///This field provides a place for the SPDX data creator to record acknowledgements that may be required to be communicated in some contexts. This is not meant to include theactual complete license text (see licenseConculded and licenseDeclared), and may or may not include copyright notices (see also copyrightText). The SPDX data creator may use this field to record other acknowledgements, such as particular clauses from license texts, which may be necessary or desirable to reproduce.
+    #[serde(rename = "attributionTexts", default, skip_serializing_if = "Vec::is_empty")]
+    pub attribution_texts: Vec<String>,
+    ///The checksum property provides a mechanism that can be used to verify that the contents of a File or Package have not changed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksums: Vec<Spdx22DocumentPackagesItemChecksumsItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///The text of copyright declarations recited in the Package or File.
+    #[serde(rename = "copyrightText", default, skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    ///Provides a detailed description of the package.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    ///The URI at which this package is available for download. Private (i.e., not publicly reachable) URIs are acceptable as values of this property. The values http://spdx.org/rdf/terms#none and http://spdx.org/rdf/terms#noassertion may be used to specify that the package is not downloadable or that no attempt was made to determine its download location, respectively.
+    #[serde(
+        rename = "downloadLocation",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub download_location: Option<String>,
+    ///An External Reference allows a Package to reference an external source of additional information, metadata, enumerations, asset identifiers, or downloadable content believed to be relevant to the Package.
+    #[serde(rename = "externalRefs", default, skip_serializing_if = "Vec::is_empty")]
+    pub external_refs: Vec<Spdx22DocumentPackagesItemExternalRefsItem>,
+    ///Indicates whether the file content of this package has been available for or subjected to analysis when creating the SPDX document. If false indicates packages that represent metadata or URI references to a project, product, artifact, distribution or a component. If set to false, the package must not contain any files.
+    #[serde(rename = "filesAnalyzed", default, skip_serializing_if = "Option::is_none")]
+    pub files_analyzed: Option<bool>,
+    ///Indicates that a particular file belongs to a package.
+    #[serde(rename = "hasFiles", default, skip_serializing_if = "Vec::is_empty")]
+    pub has_files: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    ///The licenseComments property allows the preparer of the SPDX document to describe why the licensing in spdx:licenseConcluded was chosen.
+    #[serde(
+        rename = "licenseComments",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_comments: Option<String>,
+    ///The licensing information that was discovered directly within the package. There will be an instance of this property for each distinct value of alllicenseInfoInFile properties of all files contained in the package.
+    #[serde(
+        rename = "licenseInfoFromFiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub license_info_from_files: Vec<String>,
+    ///Identify name of this SpdxElement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    ///The name and, optionally, contact information of the person or organization that originally created the package. Values of this property must conform to the agent and tool syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub originator: Option<String>,
+    ///The base name of the package file name. For example, zlib-1.2.5.tar.gz.
+    #[serde(
+        rename = "packageFileName",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub package_file_name: Option<String>,
+    #[serde(
+        rename = "packageVerificationCode",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub package_verification_code: Option<
+        Spdx22DocumentPackagesItemPackageVerificationCode,
+    >,
+    ///Allows the producer(s) of the SPDX document to describe how the package was acquired and/or changed from the original source.
+    #[serde(rename = "sourceInfo", default, skip_serializing_if = "Option::is_none")]
+    pub source_info: Option<String>,
+    ///Provides a short description of the package.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    ///The name and, optionally, contact information of the person or organization who was the immediate supplier of this package to the recipient. The supplier may be different than originator when the software has been repackaged. Values of this property must conform to the agent and tool syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supplier: Option<String>,
+    ///Provides an indication of the version of the package that is described by this SpdxDocument.
+    #[serde(rename = "versionInfo", default, skip_serializing_if = "Option::is_none")]
+    pub version_info: Option<String>,
+}
+impl From<&Spdx22DocumentPackagesItem> for Spdx22DocumentPackagesItem {
+    fn from(value: &Spdx22DocumentPackagesItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentPackagesItem {
+    pub fn builder() -> builder::Spdx22DocumentPackagesItem {
+        builder::Spdx22DocumentPackagesItem::default()
+    }
+}
+///An Annotation is a comment on an SpdxItem by an agent.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentPackagesItemAnnotationsItem {
+    ///Identify when the comment was made. This is to be specified according to the combined date and time in the UTC format, as specified in the ISO 8601 standard.
+    #[serde(rename = "annotationDate", default, skip_serializing_if = "Option::is_none")]
+    pub annotation_date: Option<String>,
+    ///Type of the annotation.
+    #[serde(rename = "annotationType", default, skip_serializing_if = "Option::is_none")]
+    pub annotation_type: Option<Spdx22DocumentPackagesItemAnnotationsItemAnnotationType>,
+    ///This field identifies the person, organization or tool that has commented on a file, package, or the entire document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+impl From<&Spdx22DocumentPackagesItemAnnotationsItem>
+for Spdx22DocumentPackagesItemAnnotationsItem {
+    fn from(value: &Spdx22DocumentPackagesItemAnnotationsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentPackagesItemAnnotationsItem {
+    pub fn builder() -> builder::Spdx22DocumentPackagesItemAnnotationsItem {
+        builder::Spdx22DocumentPackagesItemAnnotationsItem::default()
+    }
+}
+///Type of the annotation.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentPackagesItemAnnotationsItemAnnotationType {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "REVIEW")]
+    Review,
+}
+impl From<&Spdx22DocumentPackagesItemAnnotationsItemAnnotationType>
+for Spdx22DocumentPackagesItemAnnotationsItemAnnotationType {
+    fn from(value: &Spdx22DocumentPackagesItemAnnotationsItemAnnotationType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentPackagesItemAnnotationsItemAnnotationType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Review => "REVIEW".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentPackagesItemAnnotationsItemAnnotationType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "REVIEW" => Ok(Self::Review),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str>
+for Spdx22DocumentPackagesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String>
+for Spdx22DocumentPackagesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String>
+for Spdx22DocumentPackagesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A Checksum is value that allows the contents of a file to be authenticated. Even small changes to the content of the file will change its checksum. This class allows the results of a variety of checksum and cryptographic message digest algorithms to be represented.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentPackagesItemChecksumsItem {
+    ///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<Spdx22DocumentPackagesItemChecksumsItemAlgorithm>,
+    ///The checksumValue property provides a lower case hexidecimal encoded digest value produced using a specific algorithm.
+    #[serde(rename = "checksumValue", default, skip_serializing_if = "Option::is_none")]
+    pub checksum_value: Option<String>,
+}
+impl From<&Spdx22DocumentPackagesItemChecksumsItem>
+for Spdx22DocumentPackagesItemChecksumsItem {
+    fn from(value: &Spdx22DocumentPackagesItemChecksumsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentPackagesItemChecksumsItem {
+    pub fn builder() -> builder::Spdx22DocumentPackagesItemChecksumsItem {
+        builder::Spdx22DocumentPackagesItemChecksumsItem::default()
+    }
+}
+///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentPackagesItemChecksumsItemAlgorithm {
+    #[serde(rename = "SHA256")]
+    Sha256,
+    #[serde(rename = "SHA1")]
+    Sha1,
+    #[serde(rename = "SHA384")]
+    Sha384,
+    #[serde(rename = "MD2")]
+    Md2,
+    #[serde(rename = "MD4")]
+    Md4,
+    #[serde(rename = "SHA512")]
+    Sha512,
+    #[serde(rename = "MD6")]
+    Md6,
+    #[serde(rename = "MD5")]
+    Md5,
+    #[serde(rename = "SHA224")]
+    Sha224,
+}
+impl From<&Spdx22DocumentPackagesItemChecksumsItemAlgorithm>
+for Spdx22DocumentPackagesItemChecksumsItemAlgorithm {
+    fn from(value: &Spdx22DocumentPackagesItemChecksumsItemAlgorithm) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentPackagesItemChecksumsItemAlgorithm {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Sha256 => "SHA256".to_string(),
+            Self::Sha1 => "SHA1".to_string(),
+            Self::Sha384 => "SHA384".to_string(),
+            Self::Md2 => "MD2".to_string(),
+            Self::Md4 => "MD4".to_string(),
+            Self::Sha512 => "SHA512".to_string(),
+            Self::Md6 => "MD6".to_string(),
+            Self::Md5 => "MD5".to_string(),
+            Self::Sha224 => "SHA224".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentPackagesItemChecksumsItemAlgorithm {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "SHA256" => Ok(Self::Sha256),
+            "SHA1" => Ok(Self::Sha1),
+            "SHA384" => Ok(Self::Sha384),
+            "MD2" => Ok(Self::Md2),
+            "MD4" => Ok(Self::Md4),
+            "SHA512" => Ok(Self::Sha512),
+            "MD6" => Ok(Self::Md6),
+            "MD5" => Ok(Self::Md5),
+            "SHA224" => Ok(Self::Sha224),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx22DocumentPackagesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String>
+for Spdx22DocumentPackagesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx22DocumentPackagesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///An External Reference allows a Package to reference an external source of additional information, metadata, enumerations, asset identifiers, or downloadable content believed to be relevant to the Package.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentPackagesItemExternalRefsItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///Category for the external reference
+    #[serde(
+        rename = "referenceCategory",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reference_category: Option<
+        Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory,
+    >,
+    ///The unique string with no spaces necessary to access the package-specific information, metadata, or content within the target location. The format of the locator is subject to constraints defined by the <type>.
+    #[serde(
+        rename = "referenceLocator",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reference_locator: Option<String>,
+    ///Type of the external reference. These are definined in an appendix in the SPDX specification.
+    #[serde(rename = "referenceType", default, skip_serializing_if = "Option::is_none")]
+    pub reference_type: Option<String>,
+}
+impl From<&Spdx22DocumentPackagesItemExternalRefsItem>
+for Spdx22DocumentPackagesItemExternalRefsItem {
+    fn from(value: &Spdx22DocumentPackagesItemExternalRefsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentPackagesItemExternalRefsItem {
+    pub fn builder() -> builder::Spdx22DocumentPackagesItemExternalRefsItem {
+        builder::Spdx22DocumentPackagesItemExternalRefsItem::default()
+    }
+}
+///Category for the external reference
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "SECURITY")]
+    Security,
+    #[serde(rename = "PACKAGE_MANAGER")]
+    PackageManager,
+}
+impl From<&Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory>
+for Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory {
+    fn from(
+        value: &Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory,
+    ) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Security => "SECURITY".to_string(),
+            Self::PackageManager => "PACKAGE_MANAGER".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "SECURITY" => Ok(Self::Security),
+            "PACKAGE_MANAGER" => Ok(Self::PackageManager),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str>
+for Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String>
+for Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String>
+for Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A manifest based verification code (the algorithm is defined in section 4.7 of the full specification) of the SPDX Item. This allows consumers of this data and/or database to determine if an SPDX item they have in hand is identical to the SPDX item from which the data was produced. This algorithm works even if the SPDX document is included in the SPDX item.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentPackagesItemPackageVerificationCode {
+    ///A file that was excluded when calculating the package verification code. This is usually a file containing SPDX data regarding the package. If a package contains more than one SPDX file all SPDX files must be excluded from the package verification code. If this is not done it would be impossible to correctly calculate the verification codes in both files.
+    #[serde(
+        rename = "packageVerificationCodeExcludedFiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub package_verification_code_excluded_files: Vec<String>,
+    ///The actual package verification code as a hex encoded value.
+    #[serde(
+        rename = "packageVerificationCodeValue",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub package_verification_code_value: Option<String>,
+}
+impl From<&Spdx22DocumentPackagesItemPackageVerificationCode>
+for Spdx22DocumentPackagesItemPackageVerificationCode {
+    fn from(value: &Spdx22DocumentPackagesItemPackageVerificationCode) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentPackagesItemPackageVerificationCode {
+    pub fn builder() -> builder::Spdx22DocumentPackagesItemPackageVerificationCode {
+        builder::Spdx22DocumentPackagesItemPackageVerificationCode::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentRelationshipsItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///SPDX ID for SpdxElement.  A related SpdxElement.
+    #[serde(
+        rename = "relatedSpdxElement",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub related_spdx_element: Option<String>,
+    ///Describes the type of relationship between two SPDX elements.
+    #[serde(
+        rename = "relationshipType",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub relationship_type: Option<Spdx22DocumentRelationshipsItemRelationshipType>,
+}
+impl From<&Spdx22DocumentRelationshipsItem> for Spdx22DocumentRelationshipsItem {
+    fn from(value: &Spdx22DocumentRelationshipsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentRelationshipsItem {
+    pub fn builder() -> builder::Spdx22DocumentRelationshipsItem {
+        builder::Spdx22DocumentRelationshipsItem::default()
+    }
+}
+///Describes the type of relationship between two SPDX elements.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentRelationshipsItemRelationshipType {
+    #[serde(rename = "VARIANT_OF")]
+    VariantOf,
+    #[serde(rename = "COPY_OF")]
+    CopyOf,
+    #[serde(rename = "PATCH_FOR")]
+    PatchFor,
+    #[serde(rename = "TEST_DEPENDENCY_OF")]
+    TestDependencyOf,
+    #[serde(rename = "CONTAINED_BY")]
+    ContainedBy,
+    #[serde(rename = "DATA_FILE_OF")]
+    DataFileOf,
+    #[serde(rename = "OPTIONAL_COMPONENT_OF")]
+    OptionalComponentOf,
+    #[serde(rename = "ANCESTOR_OF")]
+    AncestorOf,
+    #[serde(rename = "GENERATES")]
+    Generates,
+    #[serde(rename = "CONTAINS")]
+    Contains,
+    #[serde(rename = "OPTIONAL_DEPENDENCY_OF")]
+    OptionalDependencyOf,
+    #[serde(rename = "FILE_ADDED")]
+    FileAdded,
+    #[serde(rename = "DEV_DEPENDENCY_OF")]
+    DevDependencyOf,
+    #[serde(rename = "DEPENDENCY_OF")]
+    DependencyOf,
+    #[serde(rename = "BUILD_DEPENDENCY_OF")]
+    BuildDependencyOf,
+    #[serde(rename = "DESCRIBES")]
+    Describes,
+    #[serde(rename = "PREREQUISITE_FOR")]
+    PrerequisiteFor,
+    #[serde(rename = "HAS_PREREQUISITE")]
+    HasPrerequisite,
+    #[serde(rename = "PROVIDED_DEPENDENCY_OF")]
+    ProvidedDependencyOf,
+    #[serde(rename = "DYNAMIC_LINK")]
+    DynamicLink,
+    #[serde(rename = "DESCRIBED_BY")]
+    DescribedBy,
+    #[serde(rename = "METAFILE_OF")]
+    MetafileOf,
+    #[serde(rename = "DEPENDENCY_MANIFEST_OF")]
+    DependencyManifestOf,
+    #[serde(rename = "PATCH_APPLIED")]
+    PatchApplied,
+    #[serde(rename = "RUNTIME_DEPENDENCY_OF")]
+    RuntimeDependencyOf,
+    #[serde(rename = "TEST_OF")]
+    TestOf,
+    #[serde(rename = "TEST_TOOL_OF")]
+    TestToolOf,
+    #[serde(rename = "DEPENDS_ON")]
+    DependsOn,
+    #[serde(rename = "FILE_MODIFIED")]
+    FileModified,
+    #[serde(rename = "DISTRIBUTION_ARTIFACT")]
+    DistributionArtifact,
+    #[serde(rename = "DOCUMENTATION_OF")]
+    DocumentationOf,
+    #[serde(rename = "GENERATED_FROM")]
+    GeneratedFrom,
+    #[serde(rename = "STATIC_LINK")]
+    StaticLink,
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "BUILD_TOOL_OF")]
+    BuildToolOf,
+    #[serde(rename = "TEST_CASE_OF")]
+    TestCaseOf,
+    #[serde(rename = "PACKAGE_OF")]
+    PackageOf,
+    #[serde(rename = "DESCENDANT_OF")]
+    DescendantOf,
+    #[serde(rename = "FILE_DELETED")]
+    FileDeleted,
+    #[serde(rename = "EXPANDED_FROM_ARCHIVE")]
+    ExpandedFromArchive,
+    #[serde(rename = "DEV_TOOL_OF")]
+    DevToolOf,
+    #[serde(rename = "EXAMPLE_OF")]
+    ExampleOf,
+}
+impl From<&Spdx22DocumentRelationshipsItemRelationshipType>
+for Spdx22DocumentRelationshipsItemRelationshipType {
+    fn from(value: &Spdx22DocumentRelationshipsItemRelationshipType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentRelationshipsItemRelationshipType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::VariantOf => "VARIANT_OF".to_string(),
+            Self::CopyOf => "COPY_OF".to_string(),
+            Self::PatchFor => "PATCH_FOR".to_string(),
+            Self::TestDependencyOf => "TEST_DEPENDENCY_OF".to_string(),
+            Self::ContainedBy => "CONTAINED_BY".to_string(),
+            Self::DataFileOf => "DATA_FILE_OF".to_string(),
+            Self::OptionalComponentOf => "OPTIONAL_COMPONENT_OF".to_string(),
+            Self::AncestorOf => "ANCESTOR_OF".to_string(),
+            Self::Generates => "GENERATES".to_string(),
+            Self::Contains => "CONTAINS".to_string(),
+            Self::OptionalDependencyOf => "OPTIONAL_DEPENDENCY_OF".to_string(),
+            Self::FileAdded => "FILE_ADDED".to_string(),
+            Self::DevDependencyOf => "DEV_DEPENDENCY_OF".to_string(),
+            Self::DependencyOf => "DEPENDENCY_OF".to_string(),
+            Self::BuildDependencyOf => "BUILD_DEPENDENCY_OF".to_string(),
+            Self::Describes => "DESCRIBES".to_string(),
+            Self::PrerequisiteFor => "PREREQUISITE_FOR".to_string(),
+            Self::HasPrerequisite => "HAS_PREREQUISITE".to_string(),
+            Self::ProvidedDependencyOf => "PROVIDED_DEPENDENCY_OF".to_string(),
+            Self::DynamicLink => "DYNAMIC_LINK".to_string(),
+            Self::DescribedBy => "DESCRIBED_BY".to_string(),
+            Self::MetafileOf => "METAFILE_OF".to_string(),
+            Self::DependencyManifestOf => "DEPENDENCY_MANIFEST_OF".to_string(),
+            Self::PatchApplied => "PATCH_APPLIED".to_string(),
+            Self::RuntimeDependencyOf => "RUNTIME_DEPENDENCY_OF".to_string(),
+            Self::TestOf => "TEST_OF".to_string(),
+            Self::TestToolOf => "TEST_TOOL_OF".to_string(),
+            Self::DependsOn => "DEPENDS_ON".to_string(),
+            Self::FileModified => "FILE_MODIFIED".to_string(),
+            Self::DistributionArtifact => "DISTRIBUTION_ARTIFACT".to_string(),
+            Self::DocumentationOf => "DOCUMENTATION_OF".to_string(),
+            Self::GeneratedFrom => "GENERATED_FROM".to_string(),
+            Self::StaticLink => "STATIC_LINK".to_string(),
+            Self::Other => "OTHER".to_string(),
+            Self::BuildToolOf => "BUILD_TOOL_OF".to_string(),
+            Self::TestCaseOf => "TEST_CASE_OF".to_string(),
+            Self::PackageOf => "PACKAGE_OF".to_string(),
+            Self::DescendantOf => "DESCENDANT_OF".to_string(),
+            Self::FileDeleted => "FILE_DELETED".to_string(),
+            Self::ExpandedFromArchive => "EXPANDED_FROM_ARCHIVE".to_string(),
+            Self::DevToolOf => "DEV_TOOL_OF".to_string(),
+            Self::ExampleOf => "EXAMPLE_OF".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentRelationshipsItemRelationshipType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "VARIANT_OF" => Ok(Self::VariantOf),
+            "COPY_OF" => Ok(Self::CopyOf),
+            "PATCH_FOR" => Ok(Self::PatchFor),
+            "TEST_DEPENDENCY_OF" => Ok(Self::TestDependencyOf),
+            "CONTAINED_BY" => Ok(Self::ContainedBy),
+            "DATA_FILE_OF" => Ok(Self::DataFileOf),
+            "OPTIONAL_COMPONENT_OF" => Ok(Self::OptionalComponentOf),
+            "ANCESTOR_OF" => Ok(Self::AncestorOf),
+            "GENERATES" => Ok(Self::Generates),
+            "CONTAINS" => Ok(Self::Contains),
+            "OPTIONAL_DEPENDENCY_OF" => Ok(Self::OptionalDependencyOf),
+            "FILE_ADDED" => Ok(Self::FileAdded),
+            "DEV_DEPENDENCY_OF" => Ok(Self::DevDependencyOf),
+            "DEPENDENCY_OF" => Ok(Self::DependencyOf),
+            "BUILD_DEPENDENCY_OF" => Ok(Self::BuildDependencyOf),
+            "DESCRIBES" => Ok(Self::Describes),
+            "PREREQUISITE_FOR" => Ok(Self::PrerequisiteFor),
+            "HAS_PREREQUISITE" => Ok(Self::HasPrerequisite),
+            "PROVIDED_DEPENDENCY_OF" => Ok(Self::ProvidedDependencyOf),
+            "DYNAMIC_LINK" => Ok(Self::DynamicLink),
+            "DESCRIBED_BY" => Ok(Self::DescribedBy),
+            "METAFILE_OF" => Ok(Self::MetafileOf),
+            "DEPENDENCY_MANIFEST_OF" => Ok(Self::DependencyManifestOf),
+            "PATCH_APPLIED" => Ok(Self::PatchApplied),
+            "RUNTIME_DEPENDENCY_OF" => Ok(Self::RuntimeDependencyOf),
+            "TEST_OF" => Ok(Self::TestOf),
+            "TEST_TOOL_OF" => Ok(Self::TestToolOf),
+            "DEPENDS_ON" => Ok(Self::DependsOn),
+            "FILE_MODIFIED" => Ok(Self::FileModified),
+            "DISTRIBUTION_ARTIFACT" => Ok(Self::DistributionArtifact),
+            "DOCUMENTATION_OF" => Ok(Self::DocumentationOf),
+            "GENERATED_FROM" => Ok(Self::GeneratedFrom),
+            "STATIC_LINK" => Ok(Self::StaticLink),
+            "OTHER" => Ok(Self::Other),
+            "BUILD_TOOL_OF" => Ok(Self::BuildToolOf),
+            "TEST_CASE_OF" => Ok(Self::TestCaseOf),
+            "PACKAGE_OF" => Ok(Self::PackageOf),
+            "DESCENDANT_OF" => Ok(Self::DescendantOf),
+            "FILE_DELETED" => Ok(Self::FileDeleted),
+            "EXPANDED_FROM_ARCHIVE" => Ok(Self::ExpandedFromArchive),
+            "DEV_TOOL_OF" => Ok(Self::DevToolOf),
+            "EXAMPLE_OF" => Ok(Self::ExampleOf),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx22DocumentRelationshipsItemRelationshipType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx22DocumentRelationshipsItemRelationshipType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx22DocumentRelationshipsItemRelationshipType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentReviewedsItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///The date and time at which the SpdxDocument was reviewed. This value must be in UTC and have 'Z' as its timezone indicator.
+    #[serde(rename = "reviewDate", default, skip_serializing_if = "Option::is_none")]
+    pub review_date: Option<String>,
+    ///The name and, optionally, contact information of the person who performed the review. Values of this property must conform to the agent and tool syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviewer: Option<String>,
+}
+impl From<&Spdx22DocumentReviewedsItem> for Spdx22DocumentReviewedsItem {
+    fn from(value: &Spdx22DocumentReviewedsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentReviewedsItem {
+    pub fn builder() -> builder::Spdx22DocumentReviewedsItem {
+        builder::Spdx22DocumentReviewedsItem::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentSnippetsItem {
+    ///Provide additional information about an SpdxElement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Spdx22DocumentSnippetsItemAnnotationsItem>,
+    ///This field provides a place for the SPDX data creator to record acknowledgements that may be required to be communicated in some contexts. This is not meant to include theactual complete license text (see licenseConculded and licenseDeclared), and may or may not include copyright notices (see also copyrightText). The SPDX data creator may use this field to record other acknowledgements, such as particular clauses from license texts, which may be necessary or desirable to reproduce.
+    #[serde(rename = "attributionTexts", default, skip_serializing_if = "Vec::is_empty")]
+    pub attribution_texts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///The text of copyright declarations recited in the Package or File.
+    #[serde(rename = "copyrightText", default, skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    ///The licenseComments property allows the preparer of the SPDX document to describe why the licensing in spdx:licenseConcluded was chosen.
+    #[serde(
+        rename = "licenseComments",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_comments: Option<String>,
+    ///The licensing information that was discovered directly within the package. There will be an instance of this property for each distinct value of alllicenseInfoInFile properties of all files contained in the package.
+    #[serde(
+        rename = "licenseInfoFromFiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub license_info_from_files: Vec<String>,
+    ///Licensing information that was discovered directly in the subject snippet. This is also considered a declared license for the snippet.
+    #[serde(
+        rename = "licenseInfoInSnippets",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub license_info_in_snippets: Vec<String>,
+    ///Identify name of this SpdxElement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    ///This field defines the byte range in the original host file (in X.2) that the snippet information applies to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ranges: Vec<Spdx22DocumentSnippetsItemRangesItem>,
+    ///SPDX ID for File.  File containing the SPDX element (e.g. the file contaning a snippet).
+    #[serde(
+        rename = "snippetFromFile",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub snippet_from_file: Option<String>,
+}
+impl From<&Spdx22DocumentSnippetsItem> for Spdx22DocumentSnippetsItem {
+    fn from(value: &Spdx22DocumentSnippetsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentSnippetsItem {
+    pub fn builder() -> builder::Spdx22DocumentSnippetsItem {
+        builder::Spdx22DocumentSnippetsItem::default()
+    }
+}
+///An Annotation is a comment on an SpdxItem by an agent.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentSnippetsItemAnnotationsItem {
+    ///Identify when the comment was made. This is to be specified according to the combined date and time in the UTC format, as specified in the ISO 8601 standard.
+    #[serde(rename = "annotationDate", default, skip_serializing_if = "Option::is_none")]
+    pub annotation_date: Option<String>,
+    ///Type of the annotation.
+    #[serde(rename = "annotationType", default, skip_serializing_if = "Option::is_none")]
+    pub annotation_type: Option<Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType>,
+    ///This field identifies the person, organization or tool that has commented on a file, package, or the entire document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+impl From<&Spdx22DocumentSnippetsItemAnnotationsItem>
+for Spdx22DocumentSnippetsItemAnnotationsItem {
+    fn from(value: &Spdx22DocumentSnippetsItemAnnotationsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentSnippetsItemAnnotationsItem {
+    pub fn builder() -> builder::Spdx22DocumentSnippetsItemAnnotationsItem {
+        builder::Spdx22DocumentSnippetsItemAnnotationsItem::default()
+    }
+}
+///Type of the annotation.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "REVIEW")]
+    Review,
+}
+impl From<&Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType>
+for Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType {
+    fn from(value: &Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Review => "REVIEW".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "REVIEW" => Ok(Self::Review),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str>
+for Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String>
+for Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String>
+for Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentSnippetsItemRangesItem {
+    #[serde(rename = "endPointer", default, skip_serializing_if = "Option::is_none")]
+    pub end_pointer: Option<Spdx22DocumentSnippetsItemRangesItemEndPointer>,
+    #[serde(rename = "startPointer", default, skip_serializing_if = "Option::is_none")]
+    pub start_pointer: Option<Spdx22DocumentSnippetsItemRangesItemStartPointer>,
+}
+impl From<&Spdx22DocumentSnippetsItemRangesItem>
+for Spdx22DocumentSnippetsItemRangesItem {
+    fn from(value: &Spdx22DocumentSnippetsItemRangesItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentSnippetsItemRangesItem {
+    pub fn builder() -> builder::Spdx22DocumentSnippetsItemRangesItem {
+        builder::Spdx22DocumentSnippetsItemRangesItem::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentSnippetsItemRangesItemEndPointer {
+    ///SPDX ID for File
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+impl From<&Spdx22DocumentSnippetsItemRangesItemEndPointer>
+for Spdx22DocumentSnippetsItemRangesItemEndPointer {
+    fn from(value: &Spdx22DocumentSnippetsItemRangesItemEndPointer) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentSnippetsItemRangesItemEndPointer {
+    pub fn builder() -> builder::Spdx22DocumentSnippetsItemRangesItemEndPointer {
+        builder::Spdx22DocumentSnippetsItemRangesItemEndPointer::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Spdx22DocumentSnippetsItemRangesItemStartPointer {
+    ///SPDX ID for File
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+impl From<&Spdx22DocumentSnippetsItemRangesItemStartPointer>
+for Spdx22DocumentSnippetsItemRangesItemStartPointer {
+    fn from(value: &Spdx22DocumentSnippetsItemRangesItemStartPointer) -> Self {
+        value.clone()
+    }
+}
+impl Spdx22DocumentSnippetsItemRangesItemStartPointer {
+    pub fn builder() -> builder::Spdx22DocumentSnippetsItemRangesItemStartPointer {
+        builder::Spdx22DocumentSnippetsItemRangesItemStartPointer::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23 {
+    ///Provide additional information about an SpdxElement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Spdx23AnnotationsItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(rename = "creationInfo")]
+    pub creation_info: Spdx23CreationInfo,
+    ///License expression for dataLicense. See SPDX Annex D for the license expression syntax.  Compliance with the SPDX specification includes populating the SPDX fields therein with data related to such fields ("SPDX-Metadata"). The SPDX specification contains numerous fields where an SPDX document creator may provide relevant explanatory text in SPDX-Metadata. Without opining on the lawfulness of "database rights" (in jurisdictions where applicable), such explanatory text is copyrightable subject matter in most Berne Convention countries. By using the SPDX specification, or any portion hereof, you hereby agree that any copyright rights (as determined by your jurisdiction) in any SPDX-Metadata, including without limitation explanatory text, shall be subject to the terms of the Creative Commons CC0 1.0 Universal license. For SPDX-Metadata not containing any copyright rights, you hereby agree and acknowledge that the SPDX-Metadata is provided to you "as-is" and without any representations or warranties of any kind concerning the SPDX-Metadata, express, implied, statutory or otherwise, including without limitation warranties of title, merchantability, fitness for a particular purpose, non-infringement, or the absence of latent or other defects, accuracy, or the presence or absence of errors, whether or not discoverable, all to the greatest extent permissible under applicable law.
+    #[serde(rename = "dataLicense")]
+    pub data_license: String,
+    ///DEPRECATED: use relationships instead of this field. Packages, files and/or Snippets described by this SPDX document
+    #[serde(
+        rename = "documentDescribes",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub document_describes: Vec<String>,
+    ///The URI provides an unambiguous mechanism for other SPDX documents to reference SPDX elements within this SPDX document.
+    #[serde(rename = "documentNamespace")]
+    pub document_namespace: String,
+    ///Identify any external SPDX documents referenced within this SPDX document.
+    #[serde(
+        rename = "externalDocumentRefs",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub external_document_refs: Vec<Spdx23ExternalDocumentRefsItem>,
+    ///Files referenced in the SPDX document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<Spdx23FilesItem>,
+    ///Indicates that a particular ExtractedLicensingInfo was defined in the subject SpdxDocument.
+    #[serde(
+        rename = "hasExtractedLicensingInfos",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub has_extracted_licensing_infos: Vec<Spdx23HasExtractedLicensingInfosItem>,
+    ///Identify name of this SpdxElement.
+    pub name: String,
+    ///Packages referenced in the SPDX document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<Spdx23PackagesItem>,
+    ///Relationships referenced in the SPDX document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<Spdx23RelationshipsItem>,
+    ///Reviewed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub revieweds: Vec<Spdx23ReviewedsItem>,
+    ///Snippets referenced in the SPDX document
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub snippets: Vec<Spdx23SnippetsItem>,
+    ///Provide a reference number that can be used to understand how to parse and interpret the rest of the file. It will enable both future changes to the specification and to support backward compatibility. The version number consists of a major and minor version indicator. The major field will be incremented when incompatible changes between versions are made (one or more sections are created, modified or deleted). The minor field will be incremented when backwards compatible changes are made.
+    #[serde(rename = "spdxVersion")]
+    pub spdx_version: String,
+    ///Uniquely identify any element in an SPDX document which may be referenced by other elements.
+    #[serde(rename = "SPDXID")]
+    pub spdxid: String,
+}
+impl From<&Spdx23> for Spdx23 {
+    fn from(value: &Spdx23) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23 {
+    pub fn builder() -> builder::Spdx23 {
+        builder::Spdx23::default()
+    }
+}
+///An Annotation is a comment on an SpdxItem by an agent.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23AnnotationsItem {
+    ///Identify when the comment was made. This is to be specified according to the combined date and time in the UTC format, as specified in the ISO 8601 standard.
+    #[serde(rename = "annotationDate")]
+    pub annotation_date: String,
+    ///Type of the annotation.
+    #[serde(rename = "annotationType")]
+    pub annotation_type: Spdx23AnnotationsItemAnnotationType,
+    ///This field identifies the person, organization, or tool that has commented on a file, package, snippet, or the entire document.
+    pub annotator: String,
+    pub comment: String,
+}
+impl From<&Spdx23AnnotationsItem> for Spdx23AnnotationsItem {
+    fn from(value: &Spdx23AnnotationsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23AnnotationsItem {
+    pub fn builder() -> builder::Spdx23AnnotationsItem {
+        builder::Spdx23AnnotationsItem::default()
+    }
+}
+///Type of the annotation.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23AnnotationsItemAnnotationType {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "REVIEW")]
+    Review,
+}
+impl From<&Spdx23AnnotationsItemAnnotationType> for Spdx23AnnotationsItemAnnotationType {
+    fn from(value: &Spdx23AnnotationsItemAnnotationType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23AnnotationsItemAnnotationType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Review => "REVIEW".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23AnnotationsItemAnnotationType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "REVIEW" => Ok(Self::Review),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23AnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23AnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23AnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///One instance is required for each SPDX file produced. It provides the necessary information for forward and backward compatibility for processing tools.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23CreationInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///Identify when the SPDX document was originally created. The date is to be specified according to combined date and time in UTC format as specified in ISO 8601 standard.
+    pub created: String,
+    ///Identify who (or what, in the case of a tool) created the SPDX document. If the SPDX document was created by an individual, indicate the person's name. If the SPDX document was created on behalf of a company or organization, indicate the entity name. If the SPDX document was created using a software tool, indicate the name and version for that tool. If multiple participants or tools were involved, use multiple instances of this field. Person name or organization name may be designated as “anonymous” if appropriate.
+    pub creators: Vec<String>,
+    ///An optional field for creators of the SPDX file to provide the version of the SPDX License List used when the SPDX file was created.
+    #[serde(
+        rename = "licenseListVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_list_version: Option<String>,
+}
+impl From<&Spdx23CreationInfo> for Spdx23CreationInfo {
+    fn from(value: &Spdx23CreationInfo) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23CreationInfo {
+    pub fn builder() -> builder::Spdx23CreationInfo {
+        builder::Spdx23CreationInfo::default()
+    }
+}
+///Information about an external SPDX document reference including the checksum. This allows for verification of the external references.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23ExternalDocumentRefsItem {
+    pub checksum: Spdx23ExternalDocumentRefsItemChecksum,
+    ///externalDocumentId is a string containing letters, numbers, ., - and/or + which uniquely identifies an external document within this document.
+    #[serde(rename = "externalDocumentId")]
+    pub external_document_id: String,
+    ///SPDX ID for SpdxDocument.  A property containing an SPDX document.
+    #[serde(rename = "spdxDocument")]
+    pub spdx_document: String,
+}
+impl From<&Spdx23ExternalDocumentRefsItem> for Spdx23ExternalDocumentRefsItem {
+    fn from(value: &Spdx23ExternalDocumentRefsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23ExternalDocumentRefsItem {
+    pub fn builder() -> builder::Spdx23ExternalDocumentRefsItem {
+        builder::Spdx23ExternalDocumentRefsItem::default()
+    }
+}
+///A Checksum is value that allows the contents of a file to be authenticated. Even small changes to the content of the file will change its checksum. This class allows the results of a variety of checksum and cryptographic message digest algorithms to be represented.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23ExternalDocumentRefsItemChecksum {
+    ///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+    pub algorithm: Spdx23ExternalDocumentRefsItemChecksumAlgorithm,
+    ///The checksumValue property provides a lower case hexidecimal encoded digest value produced using a specific algorithm.
+    #[serde(rename = "checksumValue")]
+    pub checksum_value: String,
+}
+impl From<&Spdx23ExternalDocumentRefsItemChecksum>
+for Spdx23ExternalDocumentRefsItemChecksum {
+    fn from(value: &Spdx23ExternalDocumentRefsItemChecksum) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23ExternalDocumentRefsItemChecksum {
+    pub fn builder() -> builder::Spdx23ExternalDocumentRefsItemChecksum {
+        builder::Spdx23ExternalDocumentRefsItemChecksum::default()
+    }
+}
+///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23ExternalDocumentRefsItemChecksumAlgorithm {
+    #[serde(rename = "SHA1")]
+    Sha1,
+    #[serde(rename = "BLAKE3")]
+    Blake3,
+    #[serde(rename = "SHA3-384")]
+    Sha3384,
+    #[serde(rename = "SHA256")]
+    Sha256,
+    #[serde(rename = "SHA384")]
+    Sha384,
+    #[serde(rename = "BLAKE2b-512")]
+    Blake2b512,
+    #[serde(rename = "BLAKE2b-256")]
+    Blake2b256,
+    #[serde(rename = "SHA3-512")]
+    Sha3512,
+    #[serde(rename = "MD2")]
+    Md2,
+    #[serde(rename = "ADLER32")]
+    Adler32,
+    #[serde(rename = "MD4")]
+    Md4,
+    #[serde(rename = "SHA3-256")]
+    Sha3256,
+    #[serde(rename = "BLAKE2b-384")]
+    Blake2b384,
+    #[serde(rename = "SHA512")]
+    Sha512,
+    #[serde(rename = "MD6")]
+    Md6,
+    #[serde(rename = "MD5")]
+    Md5,
+    #[serde(rename = "SHA224")]
+    Sha224,
+}
+impl From<&Spdx23ExternalDocumentRefsItemChecksumAlgorithm>
+for Spdx23ExternalDocumentRefsItemChecksumAlgorithm {
+    fn from(value: &Spdx23ExternalDocumentRefsItemChecksumAlgorithm) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23ExternalDocumentRefsItemChecksumAlgorithm {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Sha1 => "SHA1".to_string(),
+            Self::Blake3 => "BLAKE3".to_string(),
+            Self::Sha3384 => "SHA3-384".to_string(),
+            Self::Sha256 => "SHA256".to_string(),
+            Self::Sha384 => "SHA384".to_string(),
+            Self::Blake2b512 => "BLAKE2b-512".to_string(),
+            Self::Blake2b256 => "BLAKE2b-256".to_string(),
+            Self::Sha3512 => "SHA3-512".to_string(),
+            Self::Md2 => "MD2".to_string(),
+            Self::Adler32 => "ADLER32".to_string(),
+            Self::Md4 => "MD4".to_string(),
+            Self::Sha3256 => "SHA3-256".to_string(),
+            Self::Blake2b384 => "BLAKE2b-384".to_string(),
+            Self::Sha512 => "SHA512".to_string(),
+            Self::Md6 => "MD6".to_string(),
+            Self::Md5 => "MD5".to_string(),
+            Self::Sha224 => "SHA224".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23ExternalDocumentRefsItemChecksumAlgorithm {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "SHA1" => Ok(Self::Sha1),
+            "BLAKE3" => Ok(Self::Blake3),
+            "SHA3-384" => Ok(Self::Sha3384),
+            "SHA256" => Ok(Self::Sha256),
+            "SHA384" => Ok(Self::Sha384),
+            "BLAKE2b-512" => Ok(Self::Blake2b512),
+            "BLAKE2b-256" => Ok(Self::Blake2b256),
+            "SHA3-512" => Ok(Self::Sha3512),
+            "MD2" => Ok(Self::Md2),
+            "ADLER32" => Ok(Self::Adler32),
+            "MD4" => Ok(Self::Md4),
+            "SHA3-256" => Ok(Self::Sha3256),
+            "BLAKE2b-384" => Ok(Self::Blake2b384),
+            "SHA512" => Ok(Self::Sha512),
+            "MD6" => Ok(Self::Md6),
+            "MD5" => Ok(Self::Md5),
+            "SHA224" => Ok(Self::Sha224),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23ExternalDocumentRefsItemChecksumAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23ExternalDocumentRefsItemChecksumAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23ExternalDocumentRefsItemChecksumAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23FilesItem {
+    ///Provide additional information about an SpdxElement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Spdx23FilesItemAnnotationsItem>,
+    ///Indicates the project in which the SpdxElement originated. Tools must preserve doap:homepage and doap:name properties and the URI (if one is known) of doap:Project resources that are values of this property. All other properties of doap:Projects are not directly supported by SPDX and may be dropped when translating to or from some SPDX formats.
+    #[serde(rename = "artifactOfs", default, skip_serializing_if = "Vec::is_empty")]
+    pub artifact_ofs: Vec<serde_json::Map<String, serde_json::Value>>,
+    ///This field provides a place for the SPDX data creator to record acknowledgements that may be required to be communicated in some contexts. This is not meant to include the actual complete license text (see licenseConculded and licenseDeclared), and may or may not include copyright notices (see also copyrightText). The SPDX data creator may use this field to record other acknowledgements, such as particular clauses from license texts, which may be necessary or desirable to reproduce.
+    #[serde(rename = "attributionTexts", default, skip_serializing_if = "Vec::is_empty")]
+    pub attribution_texts: Vec<String>,
+    ///The checksum property provides a mechanism that can be used to verify that the contents of a File or Package have not changed.
+    pub checksums: Vec<Spdx23FilesItemChecksumsItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /**The text of copyright declarations recited in the package, file or snippet.
+
+If the copyrightText field is not present, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(rename = "copyrightText", default, skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    ///This field provides a place for the SPDX file creator to record file contributors. Contributors could include names of copyright holders and/or authors who may not be copyright holders yet contributed to the file content.
+    #[serde(rename = "fileContributors", default, skip_serializing_if = "Vec::is_empty")]
+    pub file_contributors: Vec<String>,
+    ///This field is deprecated since SPDX 2.0 in favor of using Section 7 which provides more granularity about relationships.
+    #[serde(rename = "fileDependencies", default, skip_serializing_if = "Vec::is_empty")]
+    pub file_dependencies: Vec<String>,
+    ///The name of the file relative to the root of the package.
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+    ///The type of the file.
+    #[serde(rename = "fileTypes", default, skip_serializing_if = "Vec::is_empty")]
+    pub file_types: Vec<Spdx23FilesItemFileTypesItem>,
+    ///The licenseComments property allows the preparer of the SPDX document to describe why the licensing in spdx:licenseConcluded was chosen.
+    #[serde(
+        rename = "licenseComments",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_comments: Option<String>,
+    /**License expression for licenseConcluded. See SPDX Annex D for the license expression syntax.  The licensing that the preparer of this SPDX document has concluded, based on the evidence, actually applies to the SPDX Item.
+
+If the licenseConcluded field is not present for an SPDX Item, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(
+        rename = "licenseConcluded",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_concluded: Option<String>,
+    /**Licensing information that was discovered directly in the subject file. This is also considered a declared license for the file.
+
+If the licenseInfoInFile field is not present for a file, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(
+        rename = "licenseInfoInFiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub license_info_in_files: Vec<String>,
+    ///This field provides a place for the SPDX file creator to record potential legal notices found in the file. This may or may not include copyright statements.
+    #[serde(rename = "noticeText", default, skip_serializing_if = "Option::is_none")]
+    pub notice_text: Option<String>,
+    ///Uniquely identify any element in an SPDX document which may be referenced by other elements.
+    #[serde(rename = "SPDXID")]
+    pub spdxid: String,
+}
+impl From<&Spdx23FilesItem> for Spdx23FilesItem {
+    fn from(value: &Spdx23FilesItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23FilesItem {
+    pub fn builder() -> builder::Spdx23FilesItem {
+        builder::Spdx23FilesItem::default()
+    }
+}
+///An Annotation is a comment on an SpdxItem by an agent.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23FilesItemAnnotationsItem {
+    ///Identify when the comment was made. This is to be specified according to the combined date and time in the UTC format, as specified in the ISO 8601 standard.
+    #[serde(rename = "annotationDate")]
+    pub annotation_date: String,
+    ///Type of the annotation.
+    #[serde(rename = "annotationType")]
+    pub annotation_type: Spdx23FilesItemAnnotationsItemAnnotationType,
+    ///This field identifies the person, organization, or tool that has commented on a file, package, snippet, or the entire document.
+    pub annotator: String,
+    pub comment: String,
+}
+impl From<&Spdx23FilesItemAnnotationsItem> for Spdx23FilesItemAnnotationsItem {
+    fn from(value: &Spdx23FilesItemAnnotationsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23FilesItemAnnotationsItem {
+    pub fn builder() -> builder::Spdx23FilesItemAnnotationsItem {
+        builder::Spdx23FilesItemAnnotationsItem::default()
+    }
+}
+///Type of the annotation.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23FilesItemAnnotationsItemAnnotationType {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "REVIEW")]
+    Review,
+}
+impl From<&Spdx23FilesItemAnnotationsItemAnnotationType>
+for Spdx23FilesItemAnnotationsItemAnnotationType {
+    fn from(value: &Spdx23FilesItemAnnotationsItemAnnotationType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23FilesItemAnnotationsItemAnnotationType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Review => "REVIEW".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23FilesItemAnnotationsItemAnnotationType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "REVIEW" => Ok(Self::Review),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23FilesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23FilesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23FilesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A Checksum is value that allows the contents of a file to be authenticated. Even small changes to the content of the file will change its checksum. This class allows the results of a variety of checksum and cryptographic message digest algorithms to be represented.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23FilesItemChecksumsItem {
+    ///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+    pub algorithm: Spdx23FilesItemChecksumsItemAlgorithm,
+    ///The checksumValue property provides a lower case hexidecimal encoded digest value produced using a specific algorithm.
+    #[serde(rename = "checksumValue")]
+    pub checksum_value: String,
+}
+impl From<&Spdx23FilesItemChecksumsItem> for Spdx23FilesItemChecksumsItem {
+    fn from(value: &Spdx23FilesItemChecksumsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23FilesItemChecksumsItem {
+    pub fn builder() -> builder::Spdx23FilesItemChecksumsItem {
+        builder::Spdx23FilesItemChecksumsItem::default()
+    }
+}
+///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23FilesItemChecksumsItemAlgorithm {
+    #[serde(rename = "SHA1")]
+    Sha1,
+    #[serde(rename = "BLAKE3")]
+    Blake3,
+    #[serde(rename = "SHA3-384")]
+    Sha3384,
+    #[serde(rename = "SHA256")]
+    Sha256,
+    #[serde(rename = "SHA384")]
+    Sha384,
+    #[serde(rename = "BLAKE2b-512")]
+    Blake2b512,
+    #[serde(rename = "BLAKE2b-256")]
+    Blake2b256,
+    #[serde(rename = "SHA3-512")]
+    Sha3512,
+    #[serde(rename = "MD2")]
+    Md2,
+    #[serde(rename = "ADLER32")]
+    Adler32,
+    #[serde(rename = "MD4")]
+    Md4,
+    #[serde(rename = "SHA3-256")]
+    Sha3256,
+    #[serde(rename = "BLAKE2b-384")]
+    Blake2b384,
+    #[serde(rename = "SHA512")]
+    Sha512,
+    #[serde(rename = "MD6")]
+    Md6,
+    #[serde(rename = "MD5")]
+    Md5,
+    #[serde(rename = "SHA224")]
+    Sha224,
+}
+impl From<&Spdx23FilesItemChecksumsItemAlgorithm>
+for Spdx23FilesItemChecksumsItemAlgorithm {
+    fn from(value: &Spdx23FilesItemChecksumsItemAlgorithm) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23FilesItemChecksumsItemAlgorithm {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Sha1 => "SHA1".to_string(),
+            Self::Blake3 => "BLAKE3".to_string(),
+            Self::Sha3384 => "SHA3-384".to_string(),
+            Self::Sha256 => "SHA256".to_string(),
+            Self::Sha384 => "SHA384".to_string(),
+            Self::Blake2b512 => "BLAKE2b-512".to_string(),
+            Self::Blake2b256 => "BLAKE2b-256".to_string(),
+            Self::Sha3512 => "SHA3-512".to_string(),
+            Self::Md2 => "MD2".to_string(),
+            Self::Adler32 => "ADLER32".to_string(),
+            Self::Md4 => "MD4".to_string(),
+            Self::Sha3256 => "SHA3-256".to_string(),
+            Self::Blake2b384 => "BLAKE2b-384".to_string(),
+            Self::Sha512 => "SHA512".to_string(),
+            Self::Md6 => "MD6".to_string(),
+            Self::Md5 => "MD5".to_string(),
+            Self::Sha224 => "SHA224".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23FilesItemChecksumsItemAlgorithm {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "SHA1" => Ok(Self::Sha1),
+            "BLAKE3" => Ok(Self::Blake3),
+            "SHA3-384" => Ok(Self::Sha3384),
+            "SHA256" => Ok(Self::Sha256),
+            "SHA384" => Ok(Self::Sha384),
+            "BLAKE2b-512" => Ok(Self::Blake2b512),
+            "BLAKE2b-256" => Ok(Self::Blake2b256),
+            "SHA3-512" => Ok(Self::Sha3512),
+            "MD2" => Ok(Self::Md2),
+            "ADLER32" => Ok(Self::Adler32),
+            "MD4" => Ok(Self::Md4),
+            "SHA3-256" => Ok(Self::Sha3256),
+            "BLAKE2b-384" => Ok(Self::Blake2b384),
+            "SHA512" => Ok(Self::Sha512),
+            "MD6" => Ok(Self::Md6),
+            "MD5" => Ok(Self::Md5),
+            "SHA224" => Ok(Self::Sha224),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23FilesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23FilesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23FilesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///The type of the file.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23FilesItemFileTypesItem {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "DOCUMENTATION")]
+    Documentation,
+    #[serde(rename = "IMAGE")]
+    Image,
+    #[serde(rename = "VIDEO")]
+    Video,
+    #[serde(rename = "ARCHIVE")]
+    Archive,
+    #[serde(rename = "SPDX")]
+    Spdx,
+    #[serde(rename = "APPLICATION")]
+    Application,
+    #[serde(rename = "SOURCE")]
+    Source,
+    #[serde(rename = "BINARY")]
+    Binary,
+    #[serde(rename = "TEXT")]
+    Text,
+    #[serde(rename = "AUDIO")]
+    Audio,
+}
+impl From<&Spdx23FilesItemFileTypesItem> for Spdx23FilesItemFileTypesItem {
+    fn from(value: &Spdx23FilesItemFileTypesItem) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23FilesItemFileTypesItem {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Documentation => "DOCUMENTATION".to_string(),
+            Self::Image => "IMAGE".to_string(),
+            Self::Video => "VIDEO".to_string(),
+            Self::Archive => "ARCHIVE".to_string(),
+            Self::Spdx => "SPDX".to_string(),
+            Self::Application => "APPLICATION".to_string(),
+            Self::Source => "SOURCE".to_string(),
+            Self::Binary => "BINARY".to_string(),
+            Self::Text => "TEXT".to_string(),
+            Self::Audio => "AUDIO".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23FilesItemFileTypesItem {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "DOCUMENTATION" => Ok(Self::Documentation),
+            "IMAGE" => Ok(Self::Image),
+            "VIDEO" => Ok(Self::Video),
+            "ARCHIVE" => Ok(Self::Archive),
+            "SPDX" => Ok(Self::Spdx),
+            "APPLICATION" => Ok(Self::Application),
+            "SOURCE" => Ok(Self::Source),
+            "BINARY" => Ok(Self::Binary),
+            "TEXT" => Ok(Self::Text),
+            "AUDIO" => Ok(Self::Audio),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23FilesItemFileTypesItem {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23FilesItemFileTypesItem {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23FilesItemFileTypesItem {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///An ExtractedLicensingInfo represents a license or licensing notice that was found in a package, file or snippet. Any license text that is recognized as a license may be represented as a License rather than an ExtractedLicensingInfo.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23HasExtractedLicensingInfosItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///Cross Reference Detail for a license SeeAlso URL
+    #[serde(rename = "crossRefs", default, skip_serializing_if = "Vec::is_empty")]
+    pub cross_refs: Vec<Spdx23HasExtractedLicensingInfosItemCrossRefsItem>,
+    ///Provide a copy of the actual text of the license reference extracted from the package, file or snippet that is associated with the License Identifier to aid in future analysis.
+    #[serde(rename = "extractedText")]
+    pub extracted_text: String,
+    ///A human readable short form license identifier for a license. The license ID is either on the standard license list or the form "LicenseRef-[idString]" where [idString] is a unique string containing letters, numbers, "." or "-".  When used within a license expression, the license ID can optionally include a reference to an external document in the form "DocumentRef-[docrefIdString]:LicenseRef-[idString]" where docRefIdString is an ID for an external document reference.
+    #[serde(rename = "licenseId")]
+    pub license_id: String,
+    ///Identify name of this SpdxElement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "seeAlsos", default, skip_serializing_if = "Vec::is_empty")]
+    pub see_alsos: Vec<String>,
+}
+impl From<&Spdx23HasExtractedLicensingInfosItem>
+for Spdx23HasExtractedLicensingInfosItem {
+    fn from(value: &Spdx23HasExtractedLicensingInfosItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23HasExtractedLicensingInfosItem {
+    pub fn builder() -> builder::Spdx23HasExtractedLicensingInfosItem {
+        builder::Spdx23HasExtractedLicensingInfosItem::default()
+    }
+}
+///Cross reference details for the a URL reference
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+    ///Indicate a URL is still a live accessible location on the public internet
+    #[serde(rename = "isLive", default, skip_serializing_if = "Option::is_none")]
+    pub is_live: Option<bool>,
+    ///True if the URL is a valid well formed URL
+    #[serde(rename = "isValid", default, skip_serializing_if = "Option::is_none")]
+    pub is_valid: Option<bool>,
+    ///True if the License SeeAlso URL points to a Wayback archive
+    #[serde(rename = "isWayBackLink", default, skip_serializing_if = "Option::is_none")]
+    pub is_way_back_link: Option<bool>,
+    ///Status of a License List SeeAlso URL reference if it refers to a website that matches the license text.
+    #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
+    pub match_: Option<String>,
+    ///The ordinal order of this element within a list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i64>,
+    ///Timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    ///URL Reference
+    pub url: String,
+}
+impl From<&Spdx23HasExtractedLicensingInfosItemCrossRefsItem>
+for Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+    fn from(value: &Spdx23HasExtractedLicensingInfosItemCrossRefsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+    pub fn builder() -> builder::Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+        builder::Spdx23HasExtractedLicensingInfosItemCrossRefsItem::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23PackagesItem {
+    ///Provide additional information about an SpdxElement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Spdx23PackagesItemAnnotationsItem>,
+    ///This field provides a place for the SPDX data creator to record acknowledgements that may be required to be communicated in some contexts. This is not meant to include the actual complete license text (see licenseConculded and licenseDeclared), and may or may not include copyright notices (see also copyrightText). The SPDX data creator may use this field to record other acknowledgements, such as particular clauses from license texts, which may be necessary or desirable to reproduce.
+    #[serde(rename = "attributionTexts", default, skip_serializing_if = "Vec::is_empty")]
+    pub attribution_texts: Vec<String>,
+    ///This field provides a place for recording the actual date the package was built.
+    #[serde(rename = "builtDate", default, skip_serializing_if = "Option::is_none")]
+    pub built_date: Option<String>,
+    ///The checksum property provides a mechanism that can be used to verify that the contents of a File or Package have not changed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksums: Vec<Spdx23PackagesItemChecksumsItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /**The text of copyright declarations recited in the package, file or snippet.
+
+If the copyrightText field is not present, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(rename = "copyrightText", default, skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    ///Provides a detailed description of the package.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    ///The URI at which this package is available for download. Private (i.e., not publicly reachable) URIs are acceptable as values of this property. The values http://spdx.org/rdf/terms#none and http://spdx.org/rdf/terms#noassertion may be used to specify that the package is not downloadable or that no attempt was made to determine its download location, respectively.
+    #[serde(rename = "downloadLocation")]
+    pub download_location: String,
+    ///An External Reference allows a Package to reference an external source of additional information, metadata, enumerations, asset identifiers, or downloadable content believed to be relevant to the Package.
+    #[serde(rename = "externalRefs", default, skip_serializing_if = "Vec::is_empty")]
+    pub external_refs: Vec<Spdx23PackagesItemExternalRefsItem>,
+    ///Indicates whether the file content of this package has been available for or subjected to analysis when creating the SPDX document. If false indicates packages that represent metadata or URI references to a project, product, artifact, distribution or a component. If set to false, the package must not contain any files.
+    #[serde(rename = "filesAnalyzed", default, skip_serializing_if = "Option::is_none")]
+    pub files_analyzed: Option<bool>,
+    ///DEPRECATED: use relationships instead of this field. Indicates that a particular file belongs to a package.
+    #[serde(rename = "hasFiles", default, skip_serializing_if = "Vec::is_empty")]
+    pub has_files: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    ///The licenseComments property allows the preparer of the SPDX document to describe why the licensing in spdx:licenseConcluded was chosen.
+    #[serde(
+        rename = "licenseComments",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_comments: Option<String>,
+    /**License expression for licenseConcluded. See SPDX Annex D for the license expression syntax.  The licensing that the preparer of this SPDX document has concluded, based on the evidence, actually applies to the SPDX Item.
+
+If the licenseConcluded field is not present for an SPDX Item, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(
+        rename = "licenseConcluded",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_concluded: Option<String>,
+    ///License expression for licenseDeclared. See SPDX Annex D for the license expression syntax.  The licensing that the creators of the software in the package, or the packager, have declared. Declarations by the original software creator should be preferred, if they exist.
+    #[serde(
+        rename = "licenseDeclared",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_declared: Option<String>,
+    /**The licensing information that was discovered directly within the package. There will be an instance of this property for each distinct value of alllicenseInfoInFile properties of all files contained in the package.
+
+If the licenseInfoFromFiles field is not present for a package and filesAnalyzed property for that same package is true or omitted, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(
+        rename = "licenseInfoFromFiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub license_info_from_files: Vec<String>,
+    ///Identify name of this SpdxElement.
+    pub name: String,
+    ///The name and, optionally, contact information of the person or organization that originally created the package. Values of this property must conform to the agent and tool syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub originator: Option<String>,
+    ///The base name of the package file name. For example, zlib-1.2.5.tar.gz.
+    #[serde(
+        rename = "packageFileName",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub package_file_name: Option<String>,
+    #[serde(
+        rename = "packageVerificationCode",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub package_verification_code: Option<Spdx23PackagesItemPackageVerificationCode>,
+    ///This field provides information about the primary purpose of the identified package. Package Purpose is intrinsic to how the package is being used rather than the content of the package.
+    #[serde(
+        rename = "primaryPackagePurpose",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub primary_package_purpose: Option<Spdx23PackagesItemPrimaryPackagePurpose>,
+    ///This field provides a place for recording the date the package was released.
+    #[serde(rename = "releaseDate", default, skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    ///Allows the producer(s) of the SPDX document to describe how the package was acquired and/or changed from the original source.
+    #[serde(rename = "sourceInfo", default, skip_serializing_if = "Option::is_none")]
+    pub source_info: Option<String>,
+    ///Uniquely identify any element in an SPDX document which may be referenced by other elements.
+    #[serde(rename = "SPDXID")]
+    pub spdxid: String,
+    ///Provides a short description of the package.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    ///The name and, optionally, contact information of the person or organization who was the immediate supplier of this package to the recipient. The supplier may be different than originator when the software has been repackaged. Values of this property must conform to the agent and tool syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supplier: Option<String>,
+    ///This field provides a place for recording the end of the support period for a package from the supplier.
+    #[serde(rename = "validUntilDate", default, skip_serializing_if = "Option::is_none")]
+    pub valid_until_date: Option<String>,
+    ///Provides an indication of the version of the package that is described by this SpdxDocument.
+    #[serde(rename = "versionInfo", default, skip_serializing_if = "Option::is_none")]
+    pub version_info: Option<String>,
+}
+impl From<&Spdx23PackagesItem> for Spdx23PackagesItem {
+    fn from(value: &Spdx23PackagesItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23PackagesItem {
+    pub fn builder() -> builder::Spdx23PackagesItem {
+        builder::Spdx23PackagesItem::default()
+    }
+}
+///An Annotation is a comment on an SpdxItem by an agent.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23PackagesItemAnnotationsItem {
+    ///Identify when the comment was made. This is to be specified according to the combined date and time in the UTC format, as specified in the ISO 8601 standard.
+    #[serde(rename = "annotationDate")]
+    pub annotation_date: String,
+    ///Type of the annotation.
+    #[serde(rename = "annotationType")]
+    pub annotation_type: Spdx23PackagesItemAnnotationsItemAnnotationType,
+    ///This field identifies the person, organization, or tool that has commented on a file, package, snippet, or the entire document.
+    pub annotator: String,
+    pub comment: String,
+}
+impl From<&Spdx23PackagesItemAnnotationsItem> for Spdx23PackagesItemAnnotationsItem {
+    fn from(value: &Spdx23PackagesItemAnnotationsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23PackagesItemAnnotationsItem {
+    pub fn builder() -> builder::Spdx23PackagesItemAnnotationsItem {
+        builder::Spdx23PackagesItemAnnotationsItem::default()
+    }
+}
+///Type of the annotation.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23PackagesItemAnnotationsItemAnnotationType {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "REVIEW")]
+    Review,
+}
+impl From<&Spdx23PackagesItemAnnotationsItemAnnotationType>
+for Spdx23PackagesItemAnnotationsItemAnnotationType {
+    fn from(value: &Spdx23PackagesItemAnnotationsItemAnnotationType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23PackagesItemAnnotationsItemAnnotationType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Review => "REVIEW".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23PackagesItemAnnotationsItemAnnotationType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "REVIEW" => Ok(Self::Review),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23PackagesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23PackagesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23PackagesItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A Checksum is value that allows the contents of a file to be authenticated. Even small changes to the content of the file will change its checksum. This class allows the results of a variety of checksum and cryptographic message digest algorithms to be represented.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23PackagesItemChecksumsItem {
+    ///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+    pub algorithm: Spdx23PackagesItemChecksumsItemAlgorithm,
+    ///The checksumValue property provides a lower case hexidecimal encoded digest value produced using a specific algorithm.
+    #[serde(rename = "checksumValue")]
+    pub checksum_value: String,
+}
+impl From<&Spdx23PackagesItemChecksumsItem> for Spdx23PackagesItemChecksumsItem {
+    fn from(value: &Spdx23PackagesItemChecksumsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23PackagesItemChecksumsItem {
+    pub fn builder() -> builder::Spdx23PackagesItemChecksumsItem {
+        builder::Spdx23PackagesItemChecksumsItem::default()
+    }
+}
+///Identifies the algorithm used to produce the subject Checksum. Currently, SHA-1 is the only supported algorithm. It is anticipated that other algorithms will be supported at a later time.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23PackagesItemChecksumsItemAlgorithm {
+    #[serde(rename = "SHA1")]
+    Sha1,
+    #[serde(rename = "BLAKE3")]
+    Blake3,
+    #[serde(rename = "SHA3-384")]
+    Sha3384,
+    #[serde(rename = "SHA256")]
+    Sha256,
+    #[serde(rename = "SHA384")]
+    Sha384,
+    #[serde(rename = "BLAKE2b-512")]
+    Blake2b512,
+    #[serde(rename = "BLAKE2b-256")]
+    Blake2b256,
+    #[serde(rename = "SHA3-512")]
+    Sha3512,
+    #[serde(rename = "MD2")]
+    Md2,
+    #[serde(rename = "ADLER32")]
+    Adler32,
+    #[serde(rename = "MD4")]
+    Md4,
+    #[serde(rename = "SHA3-256")]
+    Sha3256,
+    #[serde(rename = "BLAKE2b-384")]
+    Blake2b384,
+    #[serde(rename = "SHA512")]
+    Sha512,
+    #[serde(rename = "MD6")]
+    Md6,
+    #[serde(rename = "MD5")]
+    Md5,
+    #[serde(rename = "SHA224")]
+    Sha224,
+}
+impl From<&Spdx23PackagesItemChecksumsItemAlgorithm>
+for Spdx23PackagesItemChecksumsItemAlgorithm {
+    fn from(value: &Spdx23PackagesItemChecksumsItemAlgorithm) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23PackagesItemChecksumsItemAlgorithm {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Sha1 => "SHA1".to_string(),
+            Self::Blake3 => "BLAKE3".to_string(),
+            Self::Sha3384 => "SHA3-384".to_string(),
+            Self::Sha256 => "SHA256".to_string(),
+            Self::Sha384 => "SHA384".to_string(),
+            Self::Blake2b512 => "BLAKE2b-512".to_string(),
+            Self::Blake2b256 => "BLAKE2b-256".to_string(),
+            Self::Sha3512 => "SHA3-512".to_string(),
+            Self::Md2 => "MD2".to_string(),
+            Self::Adler32 => "ADLER32".to_string(),
+            Self::Md4 => "MD4".to_string(),
+            Self::Sha3256 => "SHA3-256".to_string(),
+            Self::Blake2b384 => "BLAKE2b-384".to_string(),
+            Self::Sha512 => "SHA512".to_string(),
+            Self::Md6 => "MD6".to_string(),
+            Self::Md5 => "MD5".to_string(),
+            Self::Sha224 => "SHA224".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23PackagesItemChecksumsItemAlgorithm {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "SHA1" => Ok(Self::Sha1),
+            "BLAKE3" => Ok(Self::Blake3),
+            "SHA3-384" => Ok(Self::Sha3384),
+            "SHA256" => Ok(Self::Sha256),
+            "SHA384" => Ok(Self::Sha384),
+            "BLAKE2b-512" => Ok(Self::Blake2b512),
+            "BLAKE2b-256" => Ok(Self::Blake2b256),
+            "SHA3-512" => Ok(Self::Sha3512),
+            "MD2" => Ok(Self::Md2),
+            "ADLER32" => Ok(Self::Adler32),
+            "MD4" => Ok(Self::Md4),
+            "SHA3-256" => Ok(Self::Sha3256),
+            "BLAKE2b-384" => Ok(Self::Blake2b384),
+            "SHA512" => Ok(Self::Sha512),
+            "MD6" => Ok(Self::Md6),
+            "MD5" => Ok(Self::Md5),
+            "SHA224" => Ok(Self::Sha224),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23PackagesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23PackagesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23PackagesItemChecksumsItemAlgorithm {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///An External Reference allows a Package to reference an external source of additional information, metadata, enumerations, asset identifiers, or downloadable content believed to be relevant to the Package.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23PackagesItemExternalRefsItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///Category for the external reference
+    #[serde(rename = "referenceCategory")]
+    pub reference_category: Spdx23PackagesItemExternalRefsItemReferenceCategory,
+    ///The unique string with no spaces necessary to access the package-specific information, metadata, or content within the target location. The format of the locator is subject to constraints defined by the <type>.
+    #[serde(rename = "referenceLocator")]
+    pub reference_locator: String,
+    ///Type of the external reference. These are defined in an appendix in the SPDX specification.
+    #[serde(rename = "referenceType")]
+    pub reference_type: String,
+}
+impl From<&Spdx23PackagesItemExternalRefsItem> for Spdx23PackagesItemExternalRefsItem {
+    fn from(value: &Spdx23PackagesItemExternalRefsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23PackagesItemExternalRefsItem {
+    pub fn builder() -> builder::Spdx23PackagesItemExternalRefsItem {
+        builder::Spdx23PackagesItemExternalRefsItem::default()
+    }
+}
+///Category for the external reference
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23PackagesItemExternalRefsItemReferenceCategory {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "PERSISTENT-ID")]
+    PersistentId,
+    #[serde(rename = "SECURITY")]
+    Security,
+    #[serde(rename = "PACKAGE-MANAGER")]
+    PackageManager,
+}
+impl From<&Spdx23PackagesItemExternalRefsItemReferenceCategory>
+for Spdx23PackagesItemExternalRefsItemReferenceCategory {
+    fn from(value: &Spdx23PackagesItemExternalRefsItemReferenceCategory) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23PackagesItemExternalRefsItemReferenceCategory {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::PersistentId => "PERSISTENT-ID".to_string(),
+            Self::Security => "SECURITY".to_string(),
+            Self::PackageManager => "PACKAGE-MANAGER".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23PackagesItemExternalRefsItemReferenceCategory {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "PERSISTENT-ID" => Ok(Self::PersistentId),
+            "SECURITY" => Ok(Self::Security),
+            "PACKAGE-MANAGER" => Ok(Self::PackageManager),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str>
+for Spdx23PackagesItemExternalRefsItemReferenceCategory {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String>
+for Spdx23PackagesItemExternalRefsItemReferenceCategory {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String>
+for Spdx23PackagesItemExternalRefsItemReferenceCategory {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A manifest based verification code (the algorithm is defined in section 4.7 of the full specification) of the SPDX Item. This allows consumers of this data and/or database to determine if an SPDX item they have in hand is identical to the SPDX item from which the data was produced. This algorithm works even if the SPDX document is included in the SPDX item.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23PackagesItemPackageVerificationCode {
+    ///A file that was excluded when calculating the package verification code. This is usually a file containing SPDX data regarding the package. If a package contains more than one SPDX file all SPDX files must be excluded from the package verification code. If this is not done it would be impossible to correctly calculate the verification codes in both files.
+    #[serde(
+        rename = "packageVerificationCodeExcludedFiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub package_verification_code_excluded_files: Vec<String>,
+    ///The actual package verification code as a hex encoded value.
+    #[serde(rename = "packageVerificationCodeValue")]
+    pub package_verification_code_value: String,
+}
+impl From<&Spdx23PackagesItemPackageVerificationCode>
+for Spdx23PackagesItemPackageVerificationCode {
+    fn from(value: &Spdx23PackagesItemPackageVerificationCode) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23PackagesItemPackageVerificationCode {
+    pub fn builder() -> builder::Spdx23PackagesItemPackageVerificationCode {
+        builder::Spdx23PackagesItemPackageVerificationCode::default()
+    }
+}
+///This field provides information about the primary purpose of the identified package. Package Purpose is intrinsic to how the package is being used rather than the content of the package.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23PackagesItemPrimaryPackagePurpose {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "INSTALL")]
+    Install,
+    #[serde(rename = "ARCHIVE")]
+    Archive,
+    #[serde(rename = "FIRMWARE")]
+    Firmware,
+    #[serde(rename = "APPLICATION")]
+    Application,
+    #[serde(rename = "FRAMEWORK")]
+    Framework,
+    #[serde(rename = "LIBRARY")]
+    Library,
+    #[serde(rename = "CONTAINER")]
+    Container,
+    #[serde(rename = "SOURCE")]
+    Source,
+    #[serde(rename = "DEVICE")]
+    Device,
+    #[serde(rename = "OPERATING_SYSTEM")]
+    OperatingSystem,
+    #[serde(rename = "FILE")]
+    File,
+}
+impl From<&Spdx23PackagesItemPrimaryPackagePurpose>
+for Spdx23PackagesItemPrimaryPackagePurpose {
+    fn from(value: &Spdx23PackagesItemPrimaryPackagePurpose) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23PackagesItemPrimaryPackagePurpose {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Install => "INSTALL".to_string(),
+            Self::Archive => "ARCHIVE".to_string(),
+            Self::Firmware => "FIRMWARE".to_string(),
+            Self::Application => "APPLICATION".to_string(),
+            Self::Framework => "FRAMEWORK".to_string(),
+            Self::Library => "LIBRARY".to_string(),
+            Self::Container => "CONTAINER".to_string(),
+            Self::Source => "SOURCE".to_string(),
+            Self::Device => "DEVICE".to_string(),
+            Self::OperatingSystem => "OPERATING_SYSTEM".to_string(),
+            Self::File => "FILE".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23PackagesItemPrimaryPackagePurpose {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "INSTALL" => Ok(Self::Install),
+            "ARCHIVE" => Ok(Self::Archive),
+            "FIRMWARE" => Ok(Self::Firmware),
+            "APPLICATION" => Ok(Self::Application),
+            "FRAMEWORK" => Ok(Self::Framework),
+            "LIBRARY" => Ok(Self::Library),
+            "CONTAINER" => Ok(Self::Container),
+            "SOURCE" => Ok(Self::Source),
+            "DEVICE" => Ok(Self::Device),
+            "OPERATING_SYSTEM" => Ok(Self::OperatingSystem),
+            "FILE" => Ok(Self::File),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23PackagesItemPrimaryPackagePurpose {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23PackagesItemPrimaryPackagePurpose {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23PackagesItemPrimaryPackagePurpose {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23RelationshipsItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///SPDX ID for SpdxElement.  A related SpdxElement.
+    #[serde(rename = "relatedSpdxElement")]
+    pub related_spdx_element: String,
+    ///Describes the type of relationship between two SPDX elements.
+    #[serde(rename = "relationshipType")]
+    pub relationship_type: Spdx23RelationshipsItemRelationshipType,
+    ///Id to which the SPDX element is related
+    #[serde(rename = "spdxElementId")]
+    pub spdx_element_id: String,
+}
+impl From<&Spdx23RelationshipsItem> for Spdx23RelationshipsItem {
+    fn from(value: &Spdx23RelationshipsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23RelationshipsItem {
+    pub fn builder() -> builder::Spdx23RelationshipsItem {
+        builder::Spdx23RelationshipsItem::default()
+    }
+}
+///Describes the type of relationship between two SPDX elements.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23RelationshipsItemRelationshipType {
+    #[serde(rename = "VARIANT_OF")]
+    VariantOf,
+    #[serde(rename = "COPY_OF")]
+    CopyOf,
+    #[serde(rename = "PATCH_FOR")]
+    PatchFor,
+    #[serde(rename = "TEST_DEPENDENCY_OF")]
+    TestDependencyOf,
+    #[serde(rename = "CONTAINED_BY")]
+    ContainedBy,
+    #[serde(rename = "DATA_FILE_OF")]
+    DataFileOf,
+    #[serde(rename = "OPTIONAL_COMPONENT_OF")]
+    OptionalComponentOf,
+    #[serde(rename = "ANCESTOR_OF")]
+    AncestorOf,
+    #[serde(rename = "GENERATES")]
+    Generates,
+    #[serde(rename = "CONTAINS")]
+    Contains,
+    #[serde(rename = "OPTIONAL_DEPENDENCY_OF")]
+    OptionalDependencyOf,
+    #[serde(rename = "FILE_ADDED")]
+    FileAdded,
+    #[serde(rename = "REQUIREMENT_DESCRIPTION_FOR")]
+    RequirementDescriptionFor,
+    #[serde(rename = "DEV_DEPENDENCY_OF")]
+    DevDependencyOf,
+    #[serde(rename = "DEPENDENCY_OF")]
+    DependencyOf,
+    #[serde(rename = "BUILD_DEPENDENCY_OF")]
+    BuildDependencyOf,
+    #[serde(rename = "DESCRIBES")]
+    Describes,
+    #[serde(rename = "PREREQUISITE_FOR")]
+    PrerequisiteFor,
+    #[serde(rename = "HAS_PREREQUISITE")]
+    HasPrerequisite,
+    #[serde(rename = "PROVIDED_DEPENDENCY_OF")]
+    ProvidedDependencyOf,
+    #[serde(rename = "DYNAMIC_LINK")]
+    DynamicLink,
+    #[serde(rename = "DESCRIBED_BY")]
+    DescribedBy,
+    #[serde(rename = "METAFILE_OF")]
+    MetafileOf,
+    #[serde(rename = "DEPENDENCY_MANIFEST_OF")]
+    DependencyManifestOf,
+    #[serde(rename = "PATCH_APPLIED")]
+    PatchApplied,
+    #[serde(rename = "RUNTIME_DEPENDENCY_OF")]
+    RuntimeDependencyOf,
+    #[serde(rename = "TEST_OF")]
+    TestOf,
+    #[serde(rename = "TEST_TOOL_OF")]
+    TestToolOf,
+    #[serde(rename = "DEPENDS_ON")]
+    DependsOn,
+    #[serde(rename = "SPECIFICATION_FOR")]
+    SpecificationFor,
+    #[serde(rename = "FILE_MODIFIED")]
+    FileModified,
+    #[serde(rename = "DISTRIBUTION_ARTIFACT")]
+    DistributionArtifact,
+    #[serde(rename = "AMENDS")]
+    Amends,
+    #[serde(rename = "DOCUMENTATION_OF")]
+    DocumentationOf,
+    #[serde(rename = "GENERATED_FROM")]
+    GeneratedFrom,
+    #[serde(rename = "STATIC_LINK")]
+    StaticLink,
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "BUILD_TOOL_OF")]
+    BuildToolOf,
+    #[serde(rename = "TEST_CASE_OF")]
+    TestCaseOf,
+    #[serde(rename = "PACKAGE_OF")]
+    PackageOf,
+    #[serde(rename = "DESCENDANT_OF")]
+    DescendantOf,
+    #[serde(rename = "FILE_DELETED")]
+    FileDeleted,
+    #[serde(rename = "EXPANDED_FROM_ARCHIVE")]
+    ExpandedFromArchive,
+    #[serde(rename = "DEV_TOOL_OF")]
+    DevToolOf,
+    #[serde(rename = "EXAMPLE_OF")]
+    ExampleOf,
+}
+impl From<&Spdx23RelationshipsItemRelationshipType>
+for Spdx23RelationshipsItemRelationshipType {
+    fn from(value: &Spdx23RelationshipsItemRelationshipType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23RelationshipsItemRelationshipType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::VariantOf => "VARIANT_OF".to_string(),
+            Self::CopyOf => "COPY_OF".to_string(),
+            Self::PatchFor => "PATCH_FOR".to_string(),
+            Self::TestDependencyOf => "TEST_DEPENDENCY_OF".to_string(),
+            Self::ContainedBy => "CONTAINED_BY".to_string(),
+            Self::DataFileOf => "DATA_FILE_OF".to_string(),
+            Self::OptionalComponentOf => "OPTIONAL_COMPONENT_OF".to_string(),
+            Self::AncestorOf => "ANCESTOR_OF".to_string(),
+            Self::Generates => "GENERATES".to_string(),
+            Self::Contains => "CONTAINS".to_string(),
+            Self::OptionalDependencyOf => "OPTIONAL_DEPENDENCY_OF".to_string(),
+            Self::FileAdded => "FILE_ADDED".to_string(),
+            Self::RequirementDescriptionFor => "REQUIREMENT_DESCRIPTION_FOR".to_string(),
+            Self::DevDependencyOf => "DEV_DEPENDENCY_OF".to_string(),
+            Self::DependencyOf => "DEPENDENCY_OF".to_string(),
+            Self::BuildDependencyOf => "BUILD_DEPENDENCY_OF".to_string(),
+            Self::Describes => "DESCRIBES".to_string(),
+            Self::PrerequisiteFor => "PREREQUISITE_FOR".to_string(),
+            Self::HasPrerequisite => "HAS_PREREQUISITE".to_string(),
+            Self::ProvidedDependencyOf => "PROVIDED_DEPENDENCY_OF".to_string(),
+            Self::DynamicLink => "DYNAMIC_LINK".to_string(),
+            Self::DescribedBy => "DESCRIBED_BY".to_string(),
+            Self::MetafileOf => "METAFILE_OF".to_string(),
+            Self::DependencyManifestOf => "DEPENDENCY_MANIFEST_OF".to_string(),
+            Self::PatchApplied => "PATCH_APPLIED".to_string(),
+            Self::RuntimeDependencyOf => "RUNTIME_DEPENDENCY_OF".to_string(),
+            Self::TestOf => "TEST_OF".to_string(),
+            Self::TestToolOf => "TEST_TOOL_OF".to_string(),
+            Self::DependsOn => "DEPENDS_ON".to_string(),
+            Self::SpecificationFor => "SPECIFICATION_FOR".to_string(),
+            Self::FileModified => "FILE_MODIFIED".to_string(),
+            Self::DistributionArtifact => "DISTRIBUTION_ARTIFACT".to_string(),
+            Self::Amends => "AMENDS".to_string(),
+            Self::DocumentationOf => "DOCUMENTATION_OF".to_string(),
+            Self::GeneratedFrom => "GENERATED_FROM".to_string(),
+            Self::StaticLink => "STATIC_LINK".to_string(),
+            Self::Other => "OTHER".to_string(),
+            Self::BuildToolOf => "BUILD_TOOL_OF".to_string(),
+            Self::TestCaseOf => "TEST_CASE_OF".to_string(),
+            Self::PackageOf => "PACKAGE_OF".to_string(),
+            Self::DescendantOf => "DESCENDANT_OF".to_string(),
+            Self::FileDeleted => "FILE_DELETED".to_string(),
+            Self::ExpandedFromArchive => "EXPANDED_FROM_ARCHIVE".to_string(),
+            Self::DevToolOf => "DEV_TOOL_OF".to_string(),
+            Self::ExampleOf => "EXAMPLE_OF".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23RelationshipsItemRelationshipType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "VARIANT_OF" => Ok(Self::VariantOf),
+            "COPY_OF" => Ok(Self::CopyOf),
+            "PATCH_FOR" => Ok(Self::PatchFor),
+            "TEST_DEPENDENCY_OF" => Ok(Self::TestDependencyOf),
+            "CONTAINED_BY" => Ok(Self::ContainedBy),
+            "DATA_FILE_OF" => Ok(Self::DataFileOf),
+            "OPTIONAL_COMPONENT_OF" => Ok(Self::OptionalComponentOf),
+            "ANCESTOR_OF" => Ok(Self::AncestorOf),
+            "GENERATES" => Ok(Self::Generates),
+            "CONTAINS" => Ok(Self::Contains),
+            "OPTIONAL_DEPENDENCY_OF" => Ok(Self::OptionalDependencyOf),
+            "FILE_ADDED" => Ok(Self::FileAdded),
+            "REQUIREMENT_DESCRIPTION_FOR" => Ok(Self::RequirementDescriptionFor),
+            "DEV_DEPENDENCY_OF" => Ok(Self::DevDependencyOf),
+            "DEPENDENCY_OF" => Ok(Self::DependencyOf),
+            "BUILD_DEPENDENCY_OF" => Ok(Self::BuildDependencyOf),
+            "DESCRIBES" => Ok(Self::Describes),
+            "PREREQUISITE_FOR" => Ok(Self::PrerequisiteFor),
+            "HAS_PREREQUISITE" => Ok(Self::HasPrerequisite),
+            "PROVIDED_DEPENDENCY_OF" => Ok(Self::ProvidedDependencyOf),
+            "DYNAMIC_LINK" => Ok(Self::DynamicLink),
+            "DESCRIBED_BY" => Ok(Self::DescribedBy),
+            "METAFILE_OF" => Ok(Self::MetafileOf),
+            "DEPENDENCY_MANIFEST_OF" => Ok(Self::DependencyManifestOf),
+            "PATCH_APPLIED" => Ok(Self::PatchApplied),
+            "RUNTIME_DEPENDENCY_OF" => Ok(Self::RuntimeDependencyOf),
+            "TEST_OF" => Ok(Self::TestOf),
+            "TEST_TOOL_OF" => Ok(Self::TestToolOf),
+            "DEPENDS_ON" => Ok(Self::DependsOn),
+            "SPECIFICATION_FOR" => Ok(Self::SpecificationFor),
+            "FILE_MODIFIED" => Ok(Self::FileModified),
+            "DISTRIBUTION_ARTIFACT" => Ok(Self::DistributionArtifact),
+            "AMENDS" => Ok(Self::Amends),
+            "DOCUMENTATION_OF" => Ok(Self::DocumentationOf),
+            "GENERATED_FROM" => Ok(Self::GeneratedFrom),
+            "STATIC_LINK" => Ok(Self::StaticLink),
+            "OTHER" => Ok(Self::Other),
+            "BUILD_TOOL_OF" => Ok(Self::BuildToolOf),
+            "TEST_CASE_OF" => Ok(Self::TestCaseOf),
+            "PACKAGE_OF" => Ok(Self::PackageOf),
+            "DESCENDANT_OF" => Ok(Self::DescendantOf),
+            "FILE_DELETED" => Ok(Self::FileDeleted),
+            "EXPANDED_FROM_ARCHIVE" => Ok(Self::ExpandedFromArchive),
+            "DEV_TOOL_OF" => Ok(Self::DevToolOf),
+            "EXAMPLE_OF" => Ok(Self::ExampleOf),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23RelationshipsItemRelationshipType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23RelationshipsItemRelationshipType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23RelationshipsItemRelationshipType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///This class has been deprecated in favor of an Annotation with an Annotation type of review.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23ReviewedsItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    ///The date and time at which the SpdxDocument was reviewed. This value must be in UTC and have 'Z' as its timezone indicator.
+    #[serde(rename = "reviewDate")]
+    pub review_date: String,
+    ///The name and, optionally, contact information of the person who performed the review. Values of this property must conform to the agent and tool syntax.  The reviewer property is deprecated in favor of Annotation with an annotationType review.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviewer: Option<String>,
+}
+impl From<&Spdx23ReviewedsItem> for Spdx23ReviewedsItem {
+    fn from(value: &Spdx23ReviewedsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23ReviewedsItem {
+    pub fn builder() -> builder::Spdx23ReviewedsItem {
+        builder::Spdx23ReviewedsItem::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23SnippetsItem {
+    ///Provide additional information about an SpdxElement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Spdx23SnippetsItemAnnotationsItem>,
+    ///This field provides a place for the SPDX data creator to record acknowledgements that may be required to be communicated in some contexts. This is not meant to include the actual complete license text (see licenseConculded and licenseDeclared), and may or may not include copyright notices (see also copyrightText). The SPDX data creator may use this field to record other acknowledgements, such as particular clauses from license texts, which may be necessary or desirable to reproduce.
+    #[serde(rename = "attributionTexts", default, skip_serializing_if = "Vec::is_empty")]
+    pub attribution_texts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /**The text of copyright declarations recited in the package, file or snippet.
+
+If the copyrightText field is not present, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(rename = "copyrightText", default, skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    ///The licenseComments property allows the preparer of the SPDX document to describe why the licensing in spdx:licenseConcluded was chosen.
+    #[serde(
+        rename = "licenseComments",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_comments: Option<String>,
+    /**License expression for licenseConcluded. See SPDX Annex D for the license expression syntax.  The licensing that the preparer of this SPDX document has concluded, based on the evidence, actually applies to the SPDX Item.
+
+If the licenseConcluded field is not present for an SPDX Item, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(
+        rename = "licenseConcluded",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub license_concluded: Option<String>,
+    /**Licensing information that was discovered directly in the subject snippet. This is also considered a declared license for the snippet.
+
+If the licenseInfoInSnippet field is not present for a snippet, it implies an equivalent meaning to NOASSERTION.*/
+    #[serde(
+        rename = "licenseInfoInSnippets",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub license_info_in_snippets: Vec<String>,
+    ///Identify name of this SpdxElement.
+    pub name: String,
+    ///This field defines the byte range in the original host file (in X.2) that the snippet information applies to
+    pub ranges: Vec<Spdx23SnippetsItemRangesItem>,
+    ///SPDX ID for File.  File containing the SPDX element (e.g. the file contaning a snippet).
+    #[serde(rename = "snippetFromFile")]
+    pub snippet_from_file: String,
+    ///Uniquely identify any element in an SPDX document which may be referenced by other elements.
+    #[serde(rename = "SPDXID")]
+    pub spdxid: String,
+}
+impl From<&Spdx23SnippetsItem> for Spdx23SnippetsItem {
+    fn from(value: &Spdx23SnippetsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23SnippetsItem {
+    pub fn builder() -> builder::Spdx23SnippetsItem {
+        builder::Spdx23SnippetsItem::default()
+    }
+}
+///An Annotation is a comment on an SpdxItem by an agent.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23SnippetsItemAnnotationsItem {
+    ///Identify when the comment was made. This is to be specified according to the combined date and time in the UTC format, as specified in the ISO 8601 standard.
+    #[serde(rename = "annotationDate")]
+    pub annotation_date: String,
+    ///Type of the annotation.
+    #[serde(rename = "annotationType")]
+    pub annotation_type: Spdx23SnippetsItemAnnotationsItemAnnotationType,
+    ///This field identifies the person, organization, or tool that has commented on a file, package, snippet, or the entire document.
+    pub annotator: String,
+    pub comment: String,
+}
+impl From<&Spdx23SnippetsItemAnnotationsItem> for Spdx23SnippetsItemAnnotationsItem {
+    fn from(value: &Spdx23SnippetsItemAnnotationsItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23SnippetsItemAnnotationsItem {
+    pub fn builder() -> builder::Spdx23SnippetsItemAnnotationsItem {
+        builder::Spdx23SnippetsItemAnnotationsItem::default()
+    }
+}
+///Type of the annotation.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum Spdx23SnippetsItemAnnotationsItemAnnotationType {
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "REVIEW")]
+    Review,
+}
+impl From<&Spdx23SnippetsItemAnnotationsItemAnnotationType>
+for Spdx23SnippetsItemAnnotationsItemAnnotationType {
+    fn from(value: &Spdx23SnippetsItemAnnotationsItemAnnotationType) -> Self {
+        value.clone()
+    }
+}
+impl ToString for Spdx23SnippetsItemAnnotationsItemAnnotationType {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Other => "OTHER".to_string(),
+            Self::Review => "REVIEW".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for Spdx23SnippetsItemAnnotationsItemAnnotationType {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "OTHER" => Ok(Self::Other),
+            "REVIEW" => Ok(Self::Review),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for Spdx23SnippetsItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for Spdx23SnippetsItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for Spdx23SnippetsItemAnnotationsItemAnnotationType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23SnippetsItemRangesItem {
+    #[serde(rename = "endPointer")]
+    pub end_pointer: Spdx23SnippetsItemRangesItemEndPointer,
+    #[serde(rename = "startPointer")]
+    pub start_pointer: Spdx23SnippetsItemRangesItemStartPointer,
+}
+impl From<&Spdx23SnippetsItemRangesItem> for Spdx23SnippetsItemRangesItem {
+    fn from(value: &Spdx23SnippetsItemRangesItem) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23SnippetsItemRangesItem {
+    pub fn builder() -> builder::Spdx23SnippetsItemRangesItem {
+        builder::Spdx23SnippetsItemRangesItem::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23SnippetsItemRangesItemEndPointer {
+    ///line number offset in the file
+    #[serde(rename = "lineNumber", default, skip_serializing_if = "Option::is_none")]
+    pub line_number: Option<i64>,
+    ///Byte offset in the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    ///SPDX ID for File
+    pub reference: String,
+}
+impl From<&Spdx23SnippetsItemRangesItemEndPointer>
+for Spdx23SnippetsItemRangesItemEndPointer {
+    fn from(value: &Spdx23SnippetsItemRangesItemEndPointer) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23SnippetsItemRangesItemEndPointer {
+    pub fn builder() -> builder::Spdx23SnippetsItemRangesItemEndPointer {
+        builder::Spdx23SnippetsItemRangesItemEndPointer::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Spdx23SnippetsItemRangesItemStartPointer {
+    ///line number offset in the file
+    #[serde(rename = "lineNumber", default, skip_serializing_if = "Option::is_none")]
+    pub line_number: Option<i64>,
+    ///Byte offset in the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    ///SPDX ID for File
+    pub reference: String,
+}
+impl From<&Spdx23SnippetsItemRangesItemStartPointer>
+for Spdx23SnippetsItemRangesItemStartPointer {
+    fn from(value: &Spdx23SnippetsItemRangesItemStartPointer) -> Self {
+        value.clone()
+    }
+}
+impl Spdx23SnippetsItemRangesItemStartPointer {
+    pub fn builder() -> builder::Spdx23SnippetsItemRangesItemStartPointer {
+        builder::Spdx23SnippetsItemRangesItemStartPointer::default()
+    }
+}
+///An SPDX document used as an In-Toto predicate, in the version given by its `spdxVersion`.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(untagged)]
+pub enum SpdxDocumentPredicate {
+    Spdx23(Spdx23),
+    Spdx22Document(Spdx22Document),
+}
+impl From<&SpdxDocumentPredicate> for SpdxDocumentPredicate {
+    fn from(value: &SpdxDocumentPredicate) -> Self {
+        value.clone()
+    }
+}
+impl From<Spdx23> for SpdxDocumentPredicate {
+    fn from(value: Spdx23) -> Self {
+        Self::Spdx23(value)
+    }
+}
+impl From<Spdx22Document> for SpdxDocumentPredicate {
+    fn from(value: Spdx22Document) -> Self {
+        Self::Spdx22Document(value)
+    }
+}
+///Represents a subject in an In-Toto v1 statement.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Subject {
+    pub digest: DigestSet,
+    pub name: String,
+}
+impl From<&Subject> for Subject {
+    fn from(value: &Subject) -> Self {
+        value.clone()
+    }
+}
+impl Subject {
+    pub fn builder() -> builder::Subject {
+        builder::Subject::default()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Subpackage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub name: String,
+}
+impl From<&Subpackage> for Subpackage {
+    fn from(value: &Subpackage) -> Self {
+        value.clone()
+    }
+}
+impl Subpackage {
+    pub fn builder() -> builder::Subpackage {
+        builder::Subpackage::default()
+    }
+}
+///The overall outcome of the test run.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum TestResult {
+    #[serde(rename = "PASSED")]
+    Passed,
+    #[serde(rename = "WARNED")]
+    Warned,
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+impl From<&TestResult> for TestResult {
+    fn from(value: &TestResult) -> Self {
+        value.clone()
+    }
+}
+impl ToString for TestResult {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Passed => "PASSED".to_string(),
+            Self::Warned => "WARNED".to_string(),
+            Self::Failed => "FAILED".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for TestResult {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "PASSED" => Ok(Self::Passed),
+            "WARNED" => Ok(Self::Warned),
+            "FAILED" => Ok(Self::Failed),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for TestResult {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for TestResult {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for TestResult {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A struct representing the In-Toto test result v0.1 predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct TestResultPredicate {
+    ///The configuration the tests ran with, e.g. the CI workflow.
+    pub configuration: Vec<ResourceDescriptor>,
+    #[serde(rename = "failedTests", default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_tests: Vec<String>,
+    #[serde(rename = "passedTests", default, skip_serializing_if = "Vec::is_empty")]
+    pub passed_tests: Vec<String>,
+    pub result: TestResult,
+    ///Where the test run can be looked at, e.g. the CI job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(rename = "warnedTests", default, skip_serializing_if = "Vec::is_empty")]
+    pub warned_tests: Vec<String>,
+}
+impl From<&TestResultPredicate> for TestResultPredicate {
+    fn from(value: &TestResultPredicate) -> Self {
+        value.clone()
+    }
+}
+impl TestResultPredicate {
+    pub fn builder() -> builder::TestResultPredicate {
+        builder::TestResultPredicate::default()
+    }
+}
+///A statement about the vulnerability's threat to products.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Threat {
+    ///The kind of statement, e.g. `impact` or `exploit_status`.
+    pub category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<chrono::DateTime<chrono::offset::Utc>>,
+    pub details: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_ids: Vec<String>,
+}
+impl From<&Threat> for Threat {
+    fn from(value: &Threat) -> Self {
+        value.clone()
+    }
+}
+impl Threat {
+    pub fn builder() -> builder::Threat {
+        builder::Threat::default()
+    }
+}
+///The analysis tool of a run.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Tool {
+    ///The tool's primary executable.
+    pub driver: ToolComponent,
+    ///The plugins and extensions of the tool that ran.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<ToolComponent>,
+}
+impl From<&Tool> for Tool {
+    fn from(value: &Tool) -> Self {
+        value.clone()
+    }
+}
+impl Tool {
+    pub fn builder() -> builder::Tool {
+        builder::Tool::default()
+    }
+}
+///The driver or an extension of an analysis tool.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct ToolComponent {
+    #[serde(rename = "informationUri", default, skip_serializing_if = "Option::is_none")]
+    pub information_uri: Option<String>,
+    pub name: String,
+    ///The rules the component checks, which results refer to by `ruleIndex` or `ruleId`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<ReportingDescriptor>,
+    #[serde(
+        rename = "semanticVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub semantic_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+impl From<&ToolComponent> for ToolComponent {
+    fn from(value: &ToolComponent) -> Self {
+        value.clone()
+    }
+}
+impl ToolComponent {
+    pub fn builder() -> builder::ToolComponent {
+        builder::ToolComponent::default()
+    }
+}
+///The identity and release history of a document.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Tracking {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    pub current_release_date: chrono::DateTime<chrono::offset::Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generator: Option<serde_json::Value>,
+    ///The id of the document, unique within the publisher's namespace.
+    pub id: String,
+    pub initial_release_date: chrono::DateTime<chrono::offset::Utc>,
+    pub revision_history: Vec<Revision>,
+    pub status: TrackingStatus,
+    ///The version of the document, which is the number of its latest revision.
+    pub version: String,
+}
+impl From<&Tracking> for Tracking {
+    fn from(value: &Tracking) -> Self {
+        value.clone()
+    }
+}
+impl Tracking {
+    pub fn builder() -> builder::Tracking {
+        builder::Tracking::default()
+    }
+}
+///The status of a document.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum TrackingStatus {
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "interim")]
+    Interim,
+    #[serde(rename = "final")]
+    Final,
+}
+impl From<&TrackingStatus> for TrackingStatus {
+    fn from(value: &TrackingStatus) -> Self {
+        value.clone()
+    }
+}
+impl ToString for TrackingStatus {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Draft => "draft".to_string(),
+            Self::Interim => "interim".to_string(),
+            Self::Final => "final".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for TrackingStatus {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "draft" => Ok(Self::Draft),
+            "interim" => Ok(Self::Interim),
+            "final" => Ok(Self::Final),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for TrackingStatus {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for TrackingStatus {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for TrackingStatus {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct User {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gid: Option<u32>,
+    pub uid: u32,
+    pub username: String,
+}
+impl From<&User> for User {
+    fn from(value: &User) -> Self {
+        value.clone()
+    }
+}
+impl User {
+    pub fn builder() -> builder::User {
+        builder::User::default()
+    }
+}
+///Whether the subject passed verification.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    schemars::JsonSchema
+)]
+pub enum VerificationResult {
+    #[serde(rename = "PASSED")]
+    Passed,
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+impl From<&VerificationResult> for VerificationResult {
+    fn from(value: &VerificationResult) -> Self {
+        value.clone()
+    }
+}
+impl ToString for VerificationResult {
+    fn to_string(&self) -> String {
+        match *self {
+            Self::Passed => "PASSED".to_string(),
+            Self::Failed => "FAILED".to_string(),
+        }
+    }
+}
+impl std::str::FromStr for VerificationResult {
+    type Err = &'static str;
+    fn from_str(value: &str) -> Result<Self, &'static str> {
+        match value {
+            "PASSED" => Ok(Self::Passed),
+            "FAILED" => Ok(Self::Failed),
+            _ => Err("invalid value"),
+        }
+    }
+}
+impl std::convert::TryFrom<&str> for VerificationResult {
+    type Error = &'static str;
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<&String> for VerificationResult {
+    type Error = &'static str;
+    fn try_from(value: &String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+impl std::convert::TryFrom<String> for VerificationResult {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, &'static str> {
+        value.parse()
+    }
+}
+///A structure representing the SLSA Verification Summary v1 Predicate.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct VerificationSummaryV1Predicate {
+    ///The number of the subject's transitive dependencies verified at each level.
+    #[serde(
+        rename = "dependencyLevels",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dependency_levels: Option<std::collections::HashMap<String, u64>>,
+    ///The attestations the verification was based on.
+    #[serde(
+        rename = "inputAttestations",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub input_attestations: Option<Vec<ResourceDescriptor>>,
+    ///The policy the subject was verified against. Its `uri` identifies the policy.
+    pub policy: ResourceDescriptor,
+    ///The URI of the artifact the verification was requested for.
+    #[serde(rename = "resourceUri")]
+    pub resource_uri: String,
+    ///The version of the SLSA spec used for the verification, `MAJOR.MINOR`.
+    #[serde(rename = "slsaVersion", default, skip_serializing_if = "Option::is_none")]
+    pub slsa_version: Option<String>,
+    ///When the verification took place.
+    #[serde(rename = "timeVerified", default, skip_serializing_if = "Option::is_none")]
+    pub time_verified: Option<chrono::DateTime<chrono::offset::Utc>>,
+    #[serde(rename = "verificationResult")]
+    pub verification_result: VerificationResult,
+    ///The levels the subject was verified to meet, e.g. `SLSA_BUILD_LEVEL_3`.
+    #[serde(rename = "verifiedLevels")]
+    pub verified_levels: Vec<String>,
+    pub verifier: Verifier,
+}
+impl From<&VerificationSummaryV1Predicate> for VerificationSummaryV1Predicate {
+    fn from(value: &VerificationSummaryV1Predicate) -> Self {
+        value.clone()
+    }
+}
+impl VerificationSummaryV1Predicate {
+    pub fn builder() -> builder::VerificationSummaryV1Predicate {
+        builder::VerificationSummaryV1Predicate::default()
+    }
+}
+///The entity that performed the verification.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Verifier {
+    pub id: String,
+    ///The versions of the verifier's components, by component name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<std::collections::HashMap<String, String>>,
+}
+impl From<&Verifier> for Verifier {
+    fn from(value: &Verifier) -> Self {
+        value.clone()
+    }
+}
+impl Verifier {
+    pub fn builder() -> builder::Verifier {
+        builder::Verifier::default()
+    }
+}
+///A vulnerability and how it affects the products.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct Vulnerability {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cve: Option<String>,
+    ///Machine-readable justifications of why products are not affected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<Flag>,
+    ///Ids of the vulnerability in other systems, e.g. GHSA ids.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ids: Vec<VulnerabilityId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_status: Option<ProductStatus>,
+    ///What to do about affected products.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remediations: Vec<Remediation>,
+    ///Free-form statements, including the impact statements of products that are not affected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub threats: Vec<Threat>,
+}
+impl From<&Vulnerability> for Vulnerability {
+    fn from(value: &Vulnerability) -> Self {
+        value.clone()
+    }
+}
+impl Vulnerability {
+    pub fn builder() -> builder::Vulnerability {
+        builder::Vulnerability::default()
+    }
+}
+///An id of a vulnerability in a tracking system.
+#[derive(Clone, Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct VulnerabilityId {
+    pub system_name: String,
+    pub text: String,
+}
+impl From<&VulnerabilityId> for VulnerabilityId {
+    fn from(value: &VulnerabilityId) -> Self {
+        value.clone()
+    }
+}
+impl VulnerabilityId {
+    pub fn builder() -> builder::VulnerabilityId {
+        builder::VulnerabilityId::default()
+    }
+}
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Accounts {
+        groups: Result<Vec<super::Group>, String>,
+        run_as: Result<Option<String>, String>,
+        users: Result<Vec<super::User>, String>,
+    }
+    impl Default for Accounts {
+        fn default() -> Self {
+            Self {
+                groups: Ok(Default::default()),
+                run_as: Ok(Default::default()),
+                users: Ok(Default::default()),
+            }
+        }
+    }
+    impl Accounts {
+        pub fn groups<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Group>>,
+            T::Error: std::fmt::Display,
+        {
+            self.groups = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for groups: {}", e)
+                });
+            self
+        }
+        pub fn run_as<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.run_as = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for run_as: {}", e)
+                });
+            self
+        }
+        pub fn users<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::User>>,
+            T::Error: std::fmt::Display,
+        {
+            self.users = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for users: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Accounts> for super::Accounts {
+        type Error = String;
+        fn try_from(value: Accounts) -> Result<Self, String> {
+            Ok(Self {
+                groups: value.groups?,
+                run_as: value.run_as?,
+                users: value.users?,
+            })
+        }
+    }
+    impl From<super::Accounts> for Accounts {
+        fn from(value: super::Accounts) -> Self {
+            Self {
+                groups: Ok(value.groups),
+                run_as: Ok(value.run_as),
+                users: Ok(value.users),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Advisory {
+        id: Result<String, String>,
+        severity: Result<Option<super::AdvisorySeverity>, String>,
+        summary: Result<Option<String>, String>,
+        url: Result<Option<String>, String>,
+    }
+    impl Default for Advisory {
+        fn default() -> Self {
+            Self {
+                id: Err("no value supplied for id".to_string()),
+                severity: Ok(Default::default()),
+                summary: Ok(Default::default()),
+                url: Ok(Default::default()),
+            }
+        }
+    }
+    impl Advisory {
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+        pub fn severity<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::AdvisorySeverity>>,
+            T::Error: std::fmt::Display,
+        {
+            self.severity = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for severity: {}", e)
+                });
+            self
+        }
+        pub fn summary<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.summary = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for summary: {}", e)
+                });
+            self
+        }
+        pub fn url<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.url = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for url: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Advisory> for super::Advisory {
+        type Error = String;
+        fn try_from(value: Advisory) -> Result<Self, String> {
+            Ok(Self {
+                id: value.id?,
+                severity: value.severity?,
+                summary: value.summary?,
+                url: value.url?,
+            })
+        }
+    }
+    impl From<super::Advisory> for Advisory {
+        fn from(value: super::Advisory) -> Self {
+            Self {
+                id: Ok(value.id),
+                severity: Ok(value.severity),
+                summary: Ok(value.summary),
+                url: Ok(value.url),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Agent {
+        name: Result<String, String>,
+        version: Result<Option<String>, String>,
+    }
+    impl Default for Agent {
+        fn default() -> Self {
+            Self {
+                name: Err("no value supplied for name".to_string()),
+                version: Ok(Default::default()),
+            }
+        }
+    }
+    impl Agent {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Agent> for super::Agent {
+        type Error = String;
+        fn try_from(value: Agent) -> Result<Self, String> {
+            Ok(Self {
+                name: value.name?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::Agent> for Agent {
+        fn from(value: super::Agent) -> Self {
+            Self {
+                name: Ok(value.name),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ApkContents {
+        keyring: Result<Vec<String>, String>,
+        packages: Result<Vec<String>, String>,
+        repositories: Result<Vec<String>, String>,
+    }
+    impl Default for ApkContents {
+        fn default() -> Self {
+            Self {
+                keyring: Ok(Default::default()),
+                packages: Ok(Default::default()),
+                repositories: Ok(Default::default()),
+            }
+        }
+    }
+    impl ApkContents {
+        pub fn keyring<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.keyring = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for keyring: {}", e)
+                });
+            self
+        }
+        pub fn packages<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.packages = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for packages: {}", e)
+                });
+            self
+        }
+        pub fn repositories<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.repositories = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for repositories: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ApkContents> for super::ApkContents {
+        type Error = String;
+        fn try_from(value: ApkContents) -> Result<Self, String> {
+            Ok(Self {
+                keyring: value.keyring?,
+                packages: value.packages?,
+                repositories: value.repositories?,
+            })
+        }
+    }
+    impl From<super::ApkContents> for ApkContents {
+        fn from(value: super::ApkContents) -> Self {
+            Self {
+                keyring: Ok(value.keyring),
+                packages: Ok(value.packages),
+                repositories: Ok(value.repositories),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ApkPackage {
+        arch: Result<Option<String>, String>,
+        checksum: Result<Option<String>, String>,
+        name: Result<String, String>,
+        repository: Result<Option<String>, String>,
+        version: Result<String, String>,
+    }
+    impl Default for ApkPackage {
+        fn default() -> Self {
+            Self {
+                arch: Ok(Default::default()),
+                checksum: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                repository: Ok(Default::default()),
+                version: Err("no value supplied for version".to_string()),
+            }
+        }
+    }
+    impl ApkPackage {
+        pub fn arch<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.arch = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for arch: {}", e));
+            self
+        }
+        pub fn checksum<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn repository<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.repository = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for repository: {}", e)
+                });
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ApkPackage> for super::ApkPackage {
+        type Error = String;
+        fn try_from(value: ApkPackage) -> Result<Self, String> {
+            Ok(Self {
+                arch: value.arch?,
+                checksum: value.checksum?,
+                name: value.name?,
+                repository: value.repository?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::ApkPackage> for ApkPackage {
+        fn from(value: super::ApkPackage) -> Self {
+            Self {
+                arch: Ok(value.arch),
+                checksum: Ok(value.checksum),
+                name: Ok(value.name),
+                repository: Ok(value.repository),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ApkoBuildPredicate {
+        config: Result<super::ImageConfiguration, String>,
+        packages: Result<Vec<super::ApkPackage>, String>,
+        sboms: Result<Vec<super::ResourceDescriptor>, String>,
+    }
+    impl Default for ApkoBuildPredicate {
+        fn default() -> Self {
+            Self {
+                config: Err("no value supplied for config".to_string()),
+                packages: Ok(Default::default()),
+                sboms: Ok(Default::default()),
+            }
+        }
+    }
+    impl ApkoBuildPredicate {
+        pub fn config<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ImageConfiguration>,
+            T::Error: std::fmt::Display,
+        {
+            self.config = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for config: {}", e)
+                });
+            self
+        }
+        pub fn packages<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::ApkPackage>>,
+            T::Error: std::fmt::Display,
+        {
+            self.packages = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for packages: {}", e)
+                });
+            self
+        }
+        pub fn sboms<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.sboms = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for sboms: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ApkoBuildPredicate> for super::ApkoBuildPredicate {
+        type Error = String;
+        fn try_from(value: ApkoBuildPredicate) -> Result<Self, String> {
+            Ok(Self {
+                config: value.config?,
+                packages: value.packages?,
+                sboms: value.sboms?,
+            })
+        }
+    }
+    impl From<super::ApkoBuildPredicate> for ApkoBuildPredicate {
+        fn from(value: super::ApkoBuildPredicate) -> Self {
+            Self {
+                config: Ok(value.config),
+                packages: Ok(value.packages),
+                sboms: Ok(value.sboms),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Approval {
+        commit: Result<String, String>,
+        reviewer: Result<String, String>,
+        time_approved: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+    }
+    impl Default for Approval {
+        fn default() -> Self {
+            Self {
+                commit: Err("no value supplied for commit".to_string()),
+                reviewer: Err("no value supplied for reviewer".to_string()),
+                time_approved: Ok(Default::default()),
+            }
+        }
+    }
+    impl Approval {
+        pub fn commit<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.commit = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for commit: {}", e)
+                });
+            self
+        }
+        pub fn reviewer<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.reviewer = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for reviewer: {}", e)
+                });
+            self
+        }
+        pub fn time_approved<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.time_approved = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for time_approved: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Approval> for super::Approval {
+        type Error = String;
+        fn try_from(value: Approval) -> Result<Self, String> {
+            Ok(Self {
+                commit: value.commit?,
+                reviewer: value.reviewer?,
+                time_approved: value.time_approved?,
+            })
+        }
+    }
+    impl From<super::Approval> for Approval {
+        fn from(value: super::Approval) -> Self {
+            Self {
+                commit: Ok(value.commit),
+                reviewer: Ok(value.reviewer),
+                time_approved: Ok(value.time_approved),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Artifact {
+        md5: Result<Option<String>, String>,
+        name: Result<String, String>,
+        path: Result<Option<String>, String>,
+        sha1: Result<Option<String>, String>,
+        sha256: Result<Option<String>, String>,
+        type_: Result<Option<String>, String>,
+    }
+    impl Default for Artifact {
+        fn default() -> Self {
+            Self {
+                md5: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                path: Ok(Default::default()),
+                sha1: Ok(Default::default()),
+                sha256: Ok(Default::default()),
+                type_: Ok(Default::default()),
+            }
+        }
+    }
+    impl Artifact {
+        pub fn md5<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.md5 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for md5: {}", e));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn path<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.path = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for path: {}", e));
+            self
+        }
+        pub fn sha1<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.sha1 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for sha1: {}", e));
+            self
+        }
+        pub fn sha256<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.sha256 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for sha256: {}", e)
+                });
+            self
+        }
+        pub fn type_<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.type_ = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for type_: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Artifact> for super::Artifact {
+        type Error = String;
+        fn try_from(value: Artifact) -> Result<Self, String> {
+            Ok(Self {
+                md5: value.md5?,
+                name: value.name?,
+                path: value.path?,
+                sha1: value.sha1?,
+                sha256: value.sha256?,
+                type_: value.type_?,
+            })
+        }
+    }
+    impl From<super::Artifact> for Artifact {
+        fn from(value: super::Artifact) -> Self {
+            Self {
+                md5: Ok(value.md5),
+                name: Ok(value.name),
+                path: Ok(value.path),
+                sha1: Ok(value.sha1),
+                sha256: Ok(value.sha256),
+                type_: Ok(value.type_),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ArtifactLocation {
+        index: Result<Option<i64>, String>,
+        uri: Result<Option<String>, String>,
+        uri_base_id: Result<Option<String>, String>,
+    }
+    impl Default for ArtifactLocation {
+        fn default() -> Self {
+            Self {
+                index: Ok(Default::default()),
+                uri: Ok(Default::default()),
+                uri_base_id: Ok(Default::default()),
+            }
+        }
+    }
+    impl ArtifactLocation {
+        pub fn index<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<i64>>,
+            T::Error: std::fmt::Display,
+        {
+            self.index = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for index: {}", e)
+                });
+            self
+        }
+        pub fn uri<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.uri = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for uri: {}", e));
+            self
+        }
+        pub fn uri_base_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.uri_base_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for uri_base_id: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ArtifactLocation> for super::ArtifactLocation {
+        type Error = String;
+        fn try_from(value: ArtifactLocation) -> Result<Self, String> {
+            Ok(Self {
+                index: value.index?,
+                uri: value.uri?,
+                uri_base_id: value.uri_base_id?,
+            })
+        }
+    }
+    impl From<super::ArtifactLocation> for ArtifactLocation {
+        fn from(value: super::ArtifactLocation) -> Self {
+            Self {
+                index: Ok(value.index),
+                uri: Ok(value.uri),
+                uri_base_id: Ok(value.uri_base_id),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Attester {
+        id: Result<String, String>,
+    }
+    impl Default for Attester {
+        fn default() -> Self {
+            Self {
+                id: Err("no value supplied for id".to_string()),
+            }
+        }
+    }
+    impl Attester {
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Attester> for super::Attester {
+        type Error = String;
+        fn try_from(value: Attester) -> Result<Self, String> {
+            Ok(Self { id: value.id? })
+        }
+    }
+    impl From<super::Attester> for Attester {
+        fn from(value: super::Attester) -> Self {
+            Self { id: Ok(value.id) }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Attribute {
+        attribute: Result<String, String>,
+        conditions: Result<Option<std::collections::HashMap<String, String>>, String>,
+        evidence: Result<Option<super::ResourceDescriptor>, String>,
+        target: Result<Option<super::ResourceDescriptor>, String>,
+    }
+    impl Default for Attribute {
+        fn default() -> Self {
+            Self {
+                attribute: Err("no value supplied for attribute".to_string()),
+                conditions: Ok(Default::default()),
+                evidence: Ok(Default::default()),
+                target: Ok(Default::default()),
+            }
+        }
+    }
+    impl Attribute {
+        pub fn attribute<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.attribute = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for attribute: {}", e)
+                });
+            self
+        }
+        pub fn conditions<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.conditions = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for conditions: {}", e)
+                });
+            self
+        }
+        pub fn evidence<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.evidence = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for evidence: {}", e)
+                });
+            self
+        }
+        pub fn target<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.target = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for target: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Attribute> for super::Attribute {
+        type Error = String;
+        fn try_from(value: Attribute) -> Result<Self, String> {
+            Ok(Self {
+                attribute: value.attribute?,
+                conditions: value.conditions?,
+                evidence: value.evidence?,
+                target: value.target?,
+            })
+        }
+    }
+    impl From<super::Attribute> for Attribute {
+        fn from(value: super::Attribute) -> Self {
+            Self {
+                attribute: Ok(value.attribute),
+                conditions: Ok(value.conditions),
+                evidence: Ok(value.evidence),
+                target: Ok(value.target),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct AttributeAssertion {
+        attribute: Result<String, String>,
+        conditions: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
+        evidence: Result<Option<super::ResourceDescriptor>, String>,
+        target: Result<Option<super::ResourceDescriptor>, String>,
+    }
+    impl Default for AttributeAssertion {
+        fn default() -> Self {
+            Self {
+                attribute: Err("no value supplied for attribute".to_string()),
+                conditions: Ok(Default::default()),
+                evidence: Ok(Default::default()),
+                target: Ok(Default::default()),
+            }
+        }
+    }
+    impl AttributeAssertion {
+        pub fn attribute<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.attribute = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for attribute: {}", e)
+                });
+            self
+        }
+        pub fn conditions<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.conditions = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for conditions: {}", e)
+                });
+            self
+        }
+        pub fn evidence<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.evidence = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for evidence: {}", e)
+                });
+            self
+        }
+        pub fn target<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.target = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for target: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<AttributeAssertion> for super::AttributeAssertion {
+        type Error = String;
+        fn try_from(value: AttributeAssertion) -> Result<Self, String> {
+            Ok(Self {
+                attribute: value.attribute?,
+                conditions: value.conditions?,
+                evidence: value.evidence?,
+                target: value.target?,
+            })
+        }
+    }
+    impl From<super::AttributeAssertion> for AttributeAssertion {
+        fn from(value: super::AttributeAssertion) -> Self {
+            Self {
+                attribute: Ok(value.attribute),
+                conditions: Ok(value.conditions),
+                evidence: Ok(value.evidence),
+                target: Ok(value.target),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct BomMetadata {
+        component: Result<Option<super::Component>, String>,
+        timestamp: Result<Option<String>, String>,
+        tools: Result<Option<serde_json::Value>, String>,
+    }
+    impl Default for BomMetadata {
+        fn default() -> Self {
+            Self {
+                component: Ok(Default::default()),
+                timestamp: Ok(Default::default()),
+                tools: Ok(Default::default()),
+            }
+        }
+    }
+    impl BomMetadata {
+        pub fn component<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Component>>,
+            T::Error: std::fmt::Display,
+        {
+            self.component = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for component: {}", e)
+                });
+            self
+        }
+        pub fn timestamp<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.timestamp = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for timestamp: {}", e)
+                });
+            self
+        }
+        pub fn tools<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.tools = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for tools: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<BomMetadata> for super::BomMetadata {
+        type Error = String;
+        fn try_from(value: BomMetadata) -> Result<Self, String> {
+            Ok(Self {
+                component: value.component?,
+                timestamp: value.timestamp?,
+                tools: value.tools?,
+            })
+        }
+    }
+    impl From<super::BomMetadata> for BomMetadata {
+        fn from(value: super::BomMetadata) -> Self {
+            Self {
+                component: Ok(value.component),
+                timestamp: Ok(value.timestamp),
+                tools: Ok(value.tools),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Branch {
+        branches: Result<Vec<super::Branch>, String>,
+        category: Result<String, String>,
+        name: Result<String, String>,
+        product: Result<Option<super::FullProductName>, String>,
+    }
+    impl Default for Branch {
+        fn default() -> Self {
+            Self {
+                branches: Ok(Default::default()),
+                category: Err("no value supplied for category".to_string()),
+                name: Err("no value supplied for name".to_string()),
+                product: Ok(Default::default()),
+            }
+        }
+    }
+    impl Branch {
+        pub fn branches<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Branch>>,
+            T::Error: std::fmt::Display,
+        {
+            self.branches = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for branches: {}", e)
+                });
+            self
+        }
+        pub fn category<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.category = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for category: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn product<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::FullProductName>>,
+            T::Error: std::fmt::Display,
+        {
+            self.product = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for product: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Branch> for super::Branch {
+        type Error = String;
+        fn try_from(value: Branch) -> Result<Self, String> {
+            Ok(Self {
+                branches: value.branches?,
+                category: value.category?,
+                name: value.name?,
+                product: value.product?,
+            })
+        }
+    }
+    impl From<super::Branch> for Branch {
+        fn from(value: super::Branch) -> Self {
+            Self {
+                branches: Ok(value.branches),
+                category: Ok(value.category),
+                name: Ok(value.name),
+                product: Ok(value.product),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct BuildDefinition {
+        build_type: Result<String, String>,
+        external_parameters: Result<serde_json::Value, String>,
+        internal_parameters: Result<
+            Option<serde_json::Map<String, serde_json::Value>>,
+            String,
+        >,
+        resolved_dependencies: Result<Option<Vec<super::ResourceDescriptor>>, String>,
+    }
+    impl Default for BuildDefinition {
+        fn default() -> Self {
+            Self {
+                build_type: Err("no value supplied for build_type".to_string()),
+                external_parameters: Err(
+                    "no value supplied for external_parameters".to_string(),
+                ),
+                internal_parameters: Ok(Default::default()),
+                resolved_dependencies: Ok(Default::default()),
+            }
+        }
+    }
+    impl BuildDefinition {
+        pub fn build_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.build_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for build_type: {}", e)
+                });
+            self
+        }
+        pub fn external_parameters<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<serde_json::Value>,
+            T::Error: std::fmt::Display,
+        {
+            self.external_parameters = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for external_parameters: {}", e
+                    )
+                });
+            self
+        }
+        pub fn internal_parameters<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.internal_parameters = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for internal_parameters: {}", e
+                    )
+                });
+            self
+        }
+        pub fn resolved_dependencies<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.resolved_dependencies = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for resolved_dependencies: {}",
+                        e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<BuildDefinition> for super::BuildDefinition {
+        type Error = String;
+        fn try_from(value: BuildDefinition) -> Result<Self, String> {
+            Ok(Self {
+                build_type: value.build_type?,
+                external_parameters: value.external_parameters?,
+                internal_parameters: value.internal_parameters?,
+                resolved_dependencies: value.resolved_dependencies?,
+            })
+        }
+    }
+    impl From<super::BuildDefinition> for BuildDefinition {
+        fn from(value: super::BuildDefinition) -> Self {
+            Self {
+                build_type: Ok(value.build_type),
+                external_parameters: Ok(value.external_parameters),
+                internal_parameters: Ok(value.internal_parameters),
+                resolved_dependencies: Ok(value.resolved_dependencies),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct BuildEnvironment {
+        contents: Result<super::ApkContents, String>,
+    }
+    impl Default for BuildEnvironment {
+        fn default() -> Self {
+            Self {
+                contents: Ok(super::defaults::build_environment_contents()),
+            }
+        }
+    }
+    impl BuildEnvironment {
+        pub fn contents<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ApkContents>,
+            T::Error: std::fmt::Display,
+        {
+            self.contents = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for contents: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<BuildEnvironment> for super::BuildEnvironment {
+        type Error = String;
+        fn try_from(value: BuildEnvironment) -> Result<Self, String> {
+            Ok(Self { contents: value.contents? })
+        }
+    }
+    impl From<super::BuildEnvironment> for BuildEnvironment {
+        fn from(value: super::BuildEnvironment) -> Self {
+            Self {
+                contents: Ok(value.contents),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct BuildMetadata {
+        finished_on: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+        invocation_id: Result<Option<String>, String>,
+        started_on: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+    }
+    impl Default for BuildMetadata {
+        fn default() -> Self {
+            Self {
+                finished_on: Ok(Default::default()),
+                invocation_id: Ok(Default::default()),
+                started_on: Ok(Default::default()),
+            }
+        }
+    }
+    impl BuildMetadata {
+        pub fn finished_on<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.finished_on = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for finished_on: {}", e)
+                });
+            self
+        }
+        pub fn invocation_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.invocation_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for invocation_id: {}", e)
+                });
+            self
+        }
+        pub fn started_on<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.started_on = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for started_on: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<BuildMetadata> for super::BuildMetadata {
+        type Error = String;
+        fn try_from(value: BuildMetadata) -> Result<Self, String> {
+            Ok(Self {
+                finished_on: value.finished_on?,
+                invocation_id: value.invocation_id?,
+                started_on: value.started_on?,
+            })
+        }
+    }
+    impl From<super::BuildMetadata> for BuildMetadata {
+        fn from(value: super::BuildMetadata) -> Self {
+            Self {
+                finished_on: Ok(value.finished_on),
+                invocation_id: Ok(value.invocation_id),
+                started_on: Ok(value.started_on),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct BuildMetadata2 {
+        build_finished_on: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+        build_invocation_id: Result<Option<String>, String>,
+        build_started_on: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+        completeness: Result<Option<super::Completeness>, String>,
+        reproducible: Result<Option<bool>, String>,
+    }
+    impl Default for BuildMetadata2 {
+        fn default() -> Self {
+            Self {
+                build_finished_on: Ok(Default::default()),
+                build_invocation_id: Ok(Default::default()),
+                build_started_on: Ok(Default::default()),
+                completeness: Ok(Default::default()),
+                reproducible: Ok(Default::default()),
+            }
+        }
+    }
+    impl BuildMetadata2 {
+        pub fn build_finished_on<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.build_finished_on = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for build_finished_on: {}", e
+                    )
+                });
+            self
+        }
+        pub fn build_invocation_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.build_invocation_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for build_invocation_id: {}", e
+                    )
+                });
+            self
+        }
+        pub fn build_started_on<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.build_started_on = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for build_started_on: {}", e
+                    )
+                });
+            self
+        }
+        pub fn completeness<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Completeness>>,
+            T::Error: std::fmt::Display,
+        {
+            self.completeness = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for completeness: {}", e)
+                });
+            self
+        }
+        pub fn reproducible<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.reproducible = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for reproducible: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<BuildMetadata2> for super::BuildMetadata2 {
+        type Error = String;
+        fn try_from(value: BuildMetadata2) -> Result<Self, String> {
+            Ok(Self {
+                build_finished_on: value.build_finished_on?,
+                build_invocation_id: value.build_invocation_id?,
+                build_started_on: value.build_started_on?,
+                completeness: value.completeness?,
+                reproducible: value.reproducible?,
+            })
+        }
+    }
+    impl From<super::BuildMetadata2> for BuildMetadata2 {
+        fn from(value: super::BuildMetadata2) -> Self {
+            Self {
+                build_finished_on: Ok(value.build_finished_on),
+                build_invocation_id: Ok(value.build_invocation_id),
+                build_started_on: Ok(value.build_started_on),
+                completeness: Ok(value.completeness),
+                reproducible: Ok(value.reproducible),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Builder {
+        builder_dependencies: Result<Option<Vec<super::ResourceDescriptor>>, String>,
+        id: Result<String, String>,
+        version: Result<Option<String>, String>,
+    }
+    impl Default for Builder {
+        fn default() -> Self {
+            Self {
+                builder_dependencies: Ok(Default::default()),
+                id: Err("no value supplied for id".to_string()),
+                version: Ok(Default::default()),
+            }
+        }
+    }
+    impl Builder {
+        pub fn builder_dependencies<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.builder_dependencies = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for builder_dependencies: {}", e
+                    )
+                });
+            self
+        }
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Builder> for super::Builder {
+        type Error = String;
+        fn try_from(value: Builder) -> Result<Self, String> {
+            Ok(Self {
+                builder_dependencies: value.builder_dependencies?,
+                id: value.id?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::Builder> for Builder {
+        fn from(value: super::Builder) -> Self {
+            Self {
+                builder_dependencies: Ok(value.builder_dependencies),
+                id: Ok(value.id),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Builder2 {
+        id: Result<String, String>,
+    }
+    impl Default for Builder2 {
+        fn default() -> Self {
+            Self {
+                id: Err("no value supplied for id".to_string()),
+            }
+        }
+    }
+    impl Builder2 {
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Builder2> for super::Builder2 {
+        type Error = String;
+        fn try_from(value: Builder2) -> Result<Self, String> {
+            Ok(Self { id: value.id? })
+        }
+    }
+    impl From<super::Builder2> for Builder2 {
+        fn from(value: super::Builder2) -> Self {
+            Self { id: Ok(value.id) }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Byproducts {
+        return_value: Result<Option<i64>, String>,
+        stderr: Result<Option<String>, String>,
+        stdout: Result<Option<String>, String>,
+    }
+    impl Default for Byproducts {
+        fn default() -> Self {
+            Self {
+                return_value: Ok(Default::default()),
+                stderr: Ok(Default::default()),
+                stdout: Ok(Default::default()),
+            }
+        }
+    }
+    impl Byproducts {
+        pub fn return_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<i64>>,
+            T::Error: std::fmt::Display,
+        {
+            self.return_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for return_value: {}", e)
+                });
+            self
+        }
+        pub fn stderr<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.stderr = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for stderr: {}", e)
+                });
+            self
+        }
+        pub fn stdout<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.stdout = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for stdout: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Byproducts> for super::Byproducts {
+        type Error = String;
+        fn try_from(value: Byproducts) -> Result<Self, String> {
+            Ok(Self {
+                return_value: value.return_value?,
+                stderr: value.stderr?,
+                stdout: value.stdout?,
+            })
+        }
+    }
+    impl From<super::Byproducts> for Byproducts {
+        fn from(value: super::Byproducts) -> Self {
+            Self {
+                return_value: Ok(value.return_value),
+                stderr: Ok(value.stderr),
+                stdout: Ok(value.stdout),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct CodeReviewPredicate {
+        approvals: Result<Vec<super::Approval>, String>,
+        author: Result<Option<String>, String>,
+        change_uri: Result<Option<String>, String>,
+        commit_range: Result<super::CommitRange, String>,
+        reviewers: Result<Vec<super::Reviewer>, String>,
+        time_merged: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+        tool: Result<super::ReviewTool, String>,
+    }
+    impl Default for CodeReviewPredicate {
+        fn default() -> Self {
+            Self {
+                approvals: Err("no value supplied for approvals".to_string()),
+                author: Ok(Default::default()),
+                change_uri: Ok(Default::default()),
+                commit_range: Err("no value supplied for commit_range".to_string()),
+                reviewers: Err("no value supplied for reviewers".to_string()),
+                time_merged: Ok(Default::default()),
+                tool: Err("no value supplied for tool".to_string()),
+            }
+        }
+    }
+    impl CodeReviewPredicate {
+        pub fn approvals<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Approval>>,
+            T::Error: std::fmt::Display,
+        {
+            self.approvals = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for approvals: {}", e)
+                });
+            self
+        }
+        pub fn author<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.author = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for author: {}", e)
+                });
+            self
+        }
+        pub fn change_uri<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.change_uri = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for change_uri: {}", e)
+                });
+            self
+        }
+        pub fn commit_range<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::CommitRange>,
+            T::Error: std::fmt::Display,
+        {
+            self.commit_range = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for commit_range: {}", e)
+                });
+            self
+        }
+        pub fn reviewers<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Reviewer>>,
+            T::Error: std::fmt::Display,
+        {
+            self.reviewers = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for reviewers: {}", e)
+                });
+            self
+        }
+        pub fn time_merged<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.time_merged = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for time_merged: {}", e)
+                });
+            self
+        }
+        pub fn tool<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ReviewTool>,
+            T::Error: std::fmt::Display,
+        {
+            self.tool = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tool: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<CodeReviewPredicate> for super::CodeReviewPredicate {
+        type Error = String;
+        fn try_from(value: CodeReviewPredicate) -> Result<Self, String> {
+            Ok(Self {
+                approvals: value.approvals?,
+                author: value.author?,
+                change_uri: value.change_uri?,
+                commit_range: value.commit_range?,
+                reviewers: value.reviewers?,
+                time_merged: value.time_merged?,
+                tool: value.tool?,
+            })
+        }
+    }
+    impl From<super::CodeReviewPredicate> for CodeReviewPredicate {
+        fn from(value: super::CodeReviewPredicate) -> Self {
+            Self {
+                approvals: Ok(value.approvals),
+                author: Ok(value.author),
+                change_uri: Ok(value.change_uri),
+                commit_range: Ok(value.commit_range),
+                reviewers: Ok(value.reviewers),
+                time_merged: Ok(value.time_merged),
+                tool: Ok(value.tool),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct CommitRange {
+        base: Result<String, String>,
+        head: Result<String, String>,
+        repository: Result<String, String>,
+    }
+    impl Default for CommitRange {
+        fn default() -> Self {
+            Self {
+                base: Err("no value supplied for base".to_string()),
+                head: Err("no value supplied for head".to_string()),
+                repository: Err("no value supplied for repository".to_string()),
+            }
+        }
+    }
+    impl CommitRange {
+        pub fn base<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.base = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for base: {}", e));
+            self
+        }
+        pub fn head<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.head = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for head: {}", e));
+            self
+        }
+        pub fn repository<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.repository = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for repository: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<CommitRange> for super::CommitRange {
+        type Error = String;
+        fn try_from(value: CommitRange) -> Result<Self, String> {
+            Ok(Self {
+                base: value.base?,
+                head: value.head?,
+                repository: value.repository?,
+            })
+        }
+    }
+    impl From<super::CommitRange> for CommitRange {
+        fn from(value: super::CommitRange) -> Self {
+            Self {
+                base: Ok(value.base),
+                head: Ok(value.head),
+                repository: Ok(value.repository),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Completeness {
+        environment: Result<Option<bool>, String>,
+        materials: Result<Option<bool>, String>,
+        parameters: Result<Option<bool>, String>,
+    }
+    impl Default for Completeness {
+        fn default() -> Self {
+            Self {
+                environment: Ok(Default::default()),
+                materials: Ok(Default::default()),
+                parameters: Ok(Default::default()),
+            }
+        }
+    }
+    impl Completeness {
+        pub fn environment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.environment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for environment: {}", e)
+                });
+            self
+        }
+        pub fn materials<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.materials = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for materials: {}", e)
+                });
+            self
+        }
+        pub fn parameters<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.parameters = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for parameters: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Completeness> for super::Completeness {
+        type Error = String;
+        fn try_from(value: Completeness) -> Result<Self, String> {
+            Ok(Self {
+                environment: value.environment?,
+                materials: value.materials?,
+                parameters: value.parameters?,
+            })
+        }
+    }
+    impl From<super::Completeness> for Completeness {
+        fn from(value: super::Completeness) -> Self {
+            Self {
+                environment: Ok(value.environment),
+                materials: Ok(value.materials),
+                parameters: Ok(value.parameters),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Component {
+        bom_ref: Result<Option<String>, String>,
+        components: Result<Vec<super::Component>, String>,
+        cpe: Result<Option<String>, String>,
+        group: Result<Option<String>, String>,
+        hashes: Result<Vec<super::ComponentHash>, String>,
+        licenses: Result<Vec<serde_json::Value>, String>,
+        name: Result<String, String>,
+        purl: Result<Option<String>, String>,
+        type_: Result<String, String>,
+        version: Result<Option<String>, String>,
+    }
+    impl Default for Component {
+        fn default() -> Self {
+            Self {
+                bom_ref: Ok(Default::default()),
+                components: Ok(Default::default()),
+                cpe: Ok(Default::default()),
+                group: Ok(Default::default()),
+                hashes: Ok(Default::default()),
+                licenses: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                purl: Ok(Default::default()),
+                type_: Err("no value supplied for type_".to_string()),
+                version: Ok(Default::default()),
+            }
+        }
+    }
+    impl Component {
+        pub fn bom_ref<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.bom_ref = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for bom_ref: {}", e)
+                });
+            self
+        }
+        pub fn components<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Component>>,
+            T::Error: std::fmt::Display,
+        {
+            self.components = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for components: {}", e)
+                });
+            self
+        }
+        pub fn cpe<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.cpe = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for cpe: {}", e));
+            self
+        }
+        pub fn group<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.group = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for group: {}", e)
+                });
+            self
+        }
+        pub fn hashes<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::ComponentHash>>,
+            T::Error: std::fmt::Display,
+        {
+            self.hashes = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for hashes: {}", e)
+                });
+            self
+        }
+        pub fn licenses<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.licenses = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for licenses: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn purl<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.purl = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for purl: {}", e));
+            self
+        }
+        pub fn type_<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.type_ = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for type_: {}", e)
+                });
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Component> for super::Component {
+        type Error = String;
+        fn try_from(value: Component) -> Result<Self, String> {
+            Ok(Self {
+                bom_ref: value.bom_ref?,
+                components: value.components?,
+                cpe: value.cpe?,
+                group: value.group?,
+                hashes: value.hashes?,
+                licenses: value.licenses?,
+                name: value.name?,
+                purl: value.purl?,
+                type_: value.type_?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::Component> for Component {
+        fn from(value: super::Component) -> Self {
+            Self {
+                bom_ref: Ok(value.bom_ref),
+                components: Ok(value.components),
+                cpe: Ok(value.cpe),
+                group: Ok(value.group),
+                hashes: Ok(value.hashes),
+                licenses: Ok(value.licenses),
+                name: Ok(value.name),
+                purl: Ok(value.purl),
+                type_: Ok(value.type_),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ComponentHash {
+        alg: Result<String, String>,
+        content: Result<String, String>,
+    }
+    impl Default for ComponentHash {
+        fn default() -> Self {
+            Self {
+                alg: Err("no value supplied for alg".to_string()),
+                content: Err("no value supplied for content".to_string()),
+            }
+        }
+    }
+    impl ComponentHash {
+        pub fn alg<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.alg = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for alg: {}", e));
+            self
+        }
+        pub fn content<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.content = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for content: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ComponentHash> for super::ComponentHash {
+        type Error = String;
+        fn try_from(value: ComponentHash) -> Result<Self, String> {
+            Ok(Self {
+                alg: value.alg?,
+                content: value.content?,
+            })
+        }
+    }
+    impl From<super::ComponentHash> for ComponentHash {
+        fn from(value: super::ComponentHash) -> Self {
+            Self {
+                alg: Ok(value.alg),
+                content: Ok(value.content),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ConfigSource {
+        digest: Result<Option<std::collections::HashMap<String, String>>, String>,
+        entry_point: Result<Option<String>, String>,
+        uri: Result<Option<String>, String>,
+    }
+    impl Default for ConfigSource {
+        fn default() -> Self {
+            Self {
+                digest: Ok(Default::default()),
+                entry_point: Ok(Default::default()),
+                uri: Ok(Default::default()),
+            }
+        }
+    }
+    impl ConfigSource {
+        pub fn digest<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.digest = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for digest: {}", e)
+                });
+            self
+        }
+        pub fn entry_point<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.entry_point = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for entry_point: {}", e)
+                });
+            self
+        }
+        pub fn uri<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.uri = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for uri: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<ConfigSource> for super::ConfigSource {
+        type Error = String;
+        fn try_from(value: ConfigSource) -> Result<Self, String> {
+            Ok(Self {
+                digest: value.digest?,
+                entry_point: value.entry_point?,
+                uri: value.uri?,
+            })
+        }
+    }
+    impl From<super::ConfigSource> for ConfigSource {
+        fn from(value: super::ConfigSource) -> Self {
+            Self {
+                digest: Ok(value.digest),
+                entry_point: Ok(value.entry_point),
+                uri: Ok(value.uri),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Copyright {
+        license: Result<String, String>,
+        paths: Result<Vec<String>, String>,
+    }
+    impl Default for Copyright {
+        fn default() -> Self {
+            Self {
+                license: Err("no value supplied for license".to_string()),
+                paths: Ok(Default::default()),
+            }
+        }
+    }
+    impl Copyright {
+        pub fn license<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.license = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for license: {}", e)
+                });
+            self
+        }
+        pub fn paths<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.paths = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for paths: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Copyright> for super::Copyright {
+        type Error = String;
+        fn try_from(value: Copyright) -> Result<Self, String> {
+            Ok(Self {
+                license: value.license?,
+                paths: value.paths?,
+            })
+        }
+    }
+    impl From<super::Copyright> for Copyright {
+        fn from(value: super::Copyright) -> Self {
+            Self {
+                license: Ok(value.license),
+                paths: Ok(value.paths),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct CsafDocument {
+        document: Result<super::DocumentMetadata, String>,
+        product_tree: Result<Option<super::ProductTree>, String>,
+        vulnerabilities: Result<Vec<super::Vulnerability>, String>,
+    }
+    impl Default for CsafDocument {
+        fn default() -> Self {
+            Self {
+                document: Err("no value supplied for document".to_string()),
+                product_tree: Ok(Default::default()),
+                vulnerabilities: Ok(Default::default()),
+            }
+        }
+    }
+    impl CsafDocument {
+        pub fn document<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::DocumentMetadata>,
+            T::Error: std::fmt::Display,
+        {
+            self.document = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for document: {}", e)
+                });
+            self
+        }
+        pub fn product_tree<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ProductTree>>,
+            T::Error: std::fmt::Display,
+        {
+            self.product_tree = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for product_tree: {}", e)
+                });
+            self
+        }
+        pub fn vulnerabilities<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Vulnerability>>,
+            T::Error: std::fmt::Display,
+        {
+            self.vulnerabilities = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for vulnerabilities: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<CsafDocument> for super::CsafDocument {
+        type Error = String;
+        fn try_from(value: CsafDocument) -> Result<Self, String> {
+            Ok(Self {
+                document: value.document?,
+                product_tree: value.product_tree?,
+                vulnerabilities: value.vulnerabilities?,
+            })
+        }
+    }
+    impl From<super::CsafDocument> for CsafDocument {
+        fn from(value: super::CsafDocument) -> Self {
+            Self {
+                document: Ok(value.document),
+                product_tree: Ok(value.product_tree),
+                vulnerabilities: Ok(value.vulnerabilities),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct CycloneDxBom {
+        bom_format: Result<String, String>,
+        components: Result<Vec<super::Component>, String>,
+        dependencies: Result<Vec<super::Dependency2>, String>,
+        metadata: Result<Option<super::BomMetadata>, String>,
+        serial_number: Result<Option<String>, String>,
+        spec_version: Result<String, String>,
+        version: Result<Option<u64>, String>,
+    }
+    impl Default for CycloneDxBom {
+        fn default() -> Self {
+            Self {
+                bom_format: Err("no value supplied for bom_format".to_string()),
+                components: Ok(Default::default()),
+                dependencies: Ok(Default::default()),
+                metadata: Ok(Default::default()),
+                serial_number: Ok(Default::default()),
+                spec_version: Err("no value supplied for spec_version".to_string()),
+                version: Ok(Default::default()),
+            }
+        }
+    }
+    impl CycloneDxBom {
+        pub fn bom_format<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.bom_format = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for bom_format: {}", e)
+                });
+            self
+        }
+        pub fn components<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Component>>,
+            T::Error: std::fmt::Display,
+        {
+            self.components = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for components: {}", e)
+                });
+            self
+        }
+        pub fn dependencies<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Dependency2>>,
+            T::Error: std::fmt::Display,
+        {
+            self.dependencies = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for dependencies: {}", e)
+                });
+            self
+        }
+        pub fn metadata<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::BomMetadata>>,
+            T::Error: std::fmt::Display,
+        {
+            self.metadata = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for metadata: {}", e)
+                });
+            self
+        }
+        pub fn serial_number<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.serial_number = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for serial_number: {}", e)
+                });
+            self
+        }
+        pub fn spec_version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.spec_version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spec_version: {}", e)
+                });
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<u64>>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<CycloneDxBom> for super::CycloneDxBom {
+        type Error = String;
+        fn try_from(value: CycloneDxBom) -> Result<Self, String> {
+            Ok(Self {
+                bom_format: value.bom_format?,
+                components: value.components?,
+                dependencies: value.dependencies?,
+                metadata: value.metadata?,
+                serial_number: value.serial_number?,
+                spec_version: value.spec_version?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::CycloneDxBom> for CycloneDxBom {
+        fn from(value: super::CycloneDxBom) -> Self {
+            Self {
+                bom_format: Ok(value.bom_format),
+                components: Ok(value.components),
+                dependencies: Ok(value.dependencies),
+                metadata: Ok(value.metadata),
+                serial_number: Ok(value.serial_number),
+                spec_version: Ok(value.spec_version),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Dependency {
+        id: Result<String, String>,
+        md5: Result<Option<String>, String>,
+        scopes: Result<Vec<String>, String>,
+        sha1: Result<Option<String>, String>,
+        sha256: Result<Option<String>, String>,
+        type_: Result<Option<String>, String>,
+    }
+    impl Default for Dependency {
+        fn default() -> Self {
+            Self {
+                id: Err("no value supplied for id".to_string()),
+                md5: Ok(Default::default()),
+                scopes: Ok(Default::default()),
+                sha1: Ok(Default::default()),
+                sha256: Ok(Default::default()),
+                type_: Ok(Default::default()),
+            }
+        }
+    }
+    impl Dependency {
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+        pub fn md5<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.md5 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for md5: {}", e));
+            self
+        }
+        pub fn scopes<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.scopes = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for scopes: {}", e)
+                });
+            self
+        }
+        pub fn sha1<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.sha1 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for sha1: {}", e));
+            self
+        }
+        pub fn sha256<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.sha256 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for sha256: {}", e)
+                });
+            self
+        }
+        pub fn type_<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.type_ = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for type_: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Dependency> for super::Dependency {
+        type Error = String;
+        fn try_from(value: Dependency) -> Result<Self, String> {
+            Ok(Self {
+                id: value.id?,
+                md5: value.md5?,
+                scopes: value.scopes?,
+                sha1: value.sha1?,
+                sha256: value.sha256?,
+                type_: value.type_?,
+            })
+        }
+    }
+    impl From<super::Dependency> for Dependency {
+        fn from(value: super::Dependency) -> Self {
+            Self {
+                id: Ok(value.id),
+                md5: Ok(value.md5),
+                scopes: Ok(value.scopes),
+                sha1: Ok(value.sha1),
+                sha256: Ok(value.sha256),
+                type_: Ok(value.type_),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Dependency2 {
+        depends_on: Result<Vec<String>, String>,
+        ref_: Result<String, String>,
+    }
+    impl Default for Dependency2 {
+        fn default() -> Self {
+            Self {
+                depends_on: Ok(Default::default()),
+                ref_: Err("no value supplied for ref_".to_string()),
+            }
+        }
+    }
+    impl Dependency2 {
+        pub fn depends_on<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.depends_on = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for depends_on: {}", e)
+                });
+            self
+        }
+        pub fn ref_<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.ref_ = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for ref_: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Dependency2> for super::Dependency2 {
+        type Error = String;
+        fn try_from(value: Dependency2) -> Result<Self, String> {
+            Ok(Self {
+                depends_on: value.depends_on?,
+                ref_: value.ref_?,
+            })
+        }
+    }
+    impl From<super::Dependency2> for Dependency2 {
+        fn from(value: super::Dependency2) -> Self {
+            Self {
+                depends_on: Ok(value.depends_on),
+                ref_: Ok(value.ref_),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct DependencyChange {
+        advisories: Result<Vec<super::Advisory>, String>,
+        change_type: Result<super::ChangeType, String>,
+        license: Result<Option<String>, String>,
+        manifest: Result<Option<String>, String>,
+        purl: Result<String, String>,
+        scope: Result<Option<String>, String>,
+    }
+    impl Default for DependencyChange {
+        fn default() -> Self {
+            Self {
+                advisories: Ok(Default::default()),
+                change_type: Err("no value supplied for change_type".to_string()),
+                license: Ok(Default::default()),
+                manifest: Ok(Default::default()),
+                purl: Err("no value supplied for purl".to_string()),
+                scope: Ok(Default::default()),
+            }
+        }
+    }
+    impl DependencyChange {
+        pub fn advisories<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Advisory>>,
+            T::Error: std::fmt::Display,
+        {
+            self.advisories = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for advisories: {}", e)
+                });
+            self
+        }
+        pub fn change_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ChangeType>,
+            T::Error: std::fmt::Display,
+        {
+            self.change_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for change_type: {}", e)
+                });
+            self
+        }
+        pub fn license<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for license: {}", e)
+                });
+            self
+        }
+        pub fn manifest<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.manifest = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for manifest: {}", e)
+                });
+            self
+        }
+        pub fn purl<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.purl = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for purl: {}", e));
+            self
+        }
+        pub fn scope<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.scope = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for scope: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<DependencyChange> for super::DependencyChange {
+        type Error = String;
+        fn try_from(value: DependencyChange) -> Result<Self, String> {
+            Ok(Self {
+                advisories: value.advisories?,
+                change_type: value.change_type?,
+                license: value.license?,
+                manifest: value.manifest?,
+                purl: value.purl?,
+                scope: value.scope?,
+            })
+        }
+    }
+    impl From<super::DependencyChange> for DependencyChange {
+        fn from(value: super::DependencyChange) -> Self {
+            Self {
+                advisories: Ok(value.advisories),
+                change_type: Ok(value.change_type),
+                license: Ok(value.license),
+                manifest: Ok(value.manifest),
+                purl: Ok(value.purl),
+                scope: Ok(value.scope),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct DependencyReviewPredicate {
+        base: Result<super::ResourceDescriptor, String>,
+        changes: Result<Vec<super::DependencyChange>, String>,
+        head: Result<super::ResourceDescriptor, String>,
+        tool: Result<super::ReviewTool, String>,
+    }
+    impl Default for DependencyReviewPredicate {
+        fn default() -> Self {
+            Self {
+                base: Err("no value supplied for base".to_string()),
+                changes: Err("no value supplied for changes".to_string()),
+                head: Err("no value supplied for head".to_string()),
+                tool: Err("no value supplied for tool".to_string()),
+            }
+        }
+    }
+    impl DependencyReviewPredicate {
+        pub fn base<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ResourceDescriptor>,
+            T::Error: std::fmt::Display,
+        {
+            self.base = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for base: {}", e));
+            self
+        }
+        pub fn changes<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::DependencyChange>>,
+            T::Error: std::fmt::Display,
+        {
+            self.changes = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for changes: {}", e)
+                });
+            self
+        }
+        pub fn head<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ResourceDescriptor>,
+            T::Error: std::fmt::Display,
+        {
+            self.head = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for head: {}", e));
+            self
+        }
+        pub fn tool<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ReviewTool>,
+            T::Error: std::fmt::Display,
+        {
+            self.tool = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tool: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<DependencyReviewPredicate>
+    for super::DependencyReviewPredicate {
+        type Error = String;
+        fn try_from(value: DependencyReviewPredicate) -> Result<Self, String> {
+            Ok(Self {
+                base: value.base?,
+                changes: value.changes?,
+                head: value.head?,
+                tool: value.tool?,
+            })
+        }
+    }
+    impl From<super::DependencyReviewPredicate> for DependencyReviewPredicate {
+        fn from(value: super::DependencyReviewPredicate) -> Self {
+            Self {
+                base: Ok(value.base),
+                changes: Ok(value.changes),
+                head: Ok(value.head),
+                tool: Ok(value.tool),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct DetectedLicense {
+        files: Result<Vec<String>, String>,
+        name: Result<Option<String>, String>,
+        spdx_id: Result<String, String>,
+    }
+    impl Default for DetectedLicense {
+        fn default() -> Self {
+            Self {
+                files: Ok(Default::default()),
+                name: Ok(Default::default()),
+                spdx_id: Err("no value supplied for spdx_id".to_string()),
+            }
+        }
+    }
+    impl DetectedLicense {
+        pub fn files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.files = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for files: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn spdx_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdx_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdx_id: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<DetectedLicense> for super::DetectedLicense {
+        type Error = String;
+        fn try_from(value: DetectedLicense) -> Result<Self, String> {
+            Ok(Self {
+                files: value.files?,
+                name: value.name?,
+                spdx_id: value.spdx_id?,
+            })
+        }
+    }
+    impl From<super::DetectedLicense> for DetectedLicense {
+        fn from(value: super::DetectedLicense) -> Self {
+            Self {
+                files: Ok(value.files),
+                name: Ok(value.name),
+                spdx_id: Ok(value.spdx_id),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct DocumentMetadata {
+        category: Result<String, String>,
+        csaf_version: Result<String, String>,
+        distribution: Result<Option<serde_json::Value>, String>,
+        lang: Result<Option<String>, String>,
+        notes: Result<Option<serde_json::Value>, String>,
+        publisher: Result<super::Publisher, String>,
+        references: Result<Option<serde_json::Value>, String>,
+        title: Result<String, String>,
+        tracking: Result<super::Tracking, String>,
+    }
+    impl Default for DocumentMetadata {
+        fn default() -> Self {
+            Self {
+                category: Err("no value supplied for category".to_string()),
+                csaf_version: Err("no value supplied for csaf_version".to_string()),
+                distribution: Ok(Default::default()),
+                lang: Ok(Default::default()),
+                notes: Ok(Default::default()),
+                publisher: Err("no value supplied for publisher".to_string()),
+                references: Ok(Default::default()),
+                title: Err("no value supplied for title".to_string()),
+                tracking: Err("no value supplied for tracking".to_string()),
+            }
+        }
+    }
+    impl DocumentMetadata {
+        pub fn category<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.category = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for category: {}", e)
+                });
+            self
+        }
+        pub fn csaf_version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.csaf_version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for csaf_version: {}", e)
+                });
+            self
+        }
+        pub fn distribution<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.distribution = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for distribution: {}", e)
+                });
+            self
+        }
+        pub fn lang<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.lang = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for lang: {}", e));
+            self
+        }
+        pub fn notes<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.notes = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for notes: {}", e)
+                });
+            self
+        }
+        pub fn publisher<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Publisher>,
+            T::Error: std::fmt::Display,
+        {
+            self.publisher = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for publisher: {}", e)
+                });
+            self
+        }
+        pub fn references<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.references = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for references: {}", e)
+                });
+            self
+        }
+        pub fn title<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.title = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for title: {}", e)
+                });
+            self
+        }
+        pub fn tracking<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Tracking>,
+            T::Error: std::fmt::Display,
+        {
+            self.tracking = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for tracking: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<DocumentMetadata> for super::DocumentMetadata {
+        type Error = String;
+        fn try_from(value: DocumentMetadata) -> Result<Self, String> {
+            Ok(Self {
+                category: value.category?,
+                csaf_version: value.csaf_version?,
+                distribution: value.distribution?,
+                lang: value.lang?,
+                notes: value.notes?,
+                publisher: value.publisher?,
+                references: value.references?,
+                title: value.title?,
+                tracking: value.tracking?,
+            })
+        }
+    }
+    impl From<super::DocumentMetadata> for DocumentMetadata {
+        fn from(value: super::DocumentMetadata) -> Self {
+            Self {
+                category: Ok(value.category),
+                csaf_version: Ok(value.csaf_version),
+                distribution: Ok(value.distribution),
+                lang: Ok(value.lang),
+                notes: Ok(value.notes),
+                publisher: Ok(value.publisher),
+                references: Ok(value.references),
+                title: Ok(value.title),
+                tracking: Ok(value.tracking),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Entrypoint {
+        command: Result<Option<String>, String>,
+    }
+    impl Default for Entrypoint {
+        fn default() -> Self {
+            Self {
+                command: Ok(Default::default()),
+            }
+        }
+    }
+    impl Entrypoint {
+        pub fn command<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.command = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for command: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Entrypoint> for super::Entrypoint {
+        type Error = String;
+        fn try_from(value: Entrypoint) -> Result<Self, String> {
+            Ok(Self { command: value.command? })
+        }
+    }
+    impl From<super::Entrypoint> for Entrypoint {
+        fn from(value: super::Entrypoint) -> Self {
+            Self { command: Ok(value.command) }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Flag {
+        date: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+        group_ids: Result<Vec<String>, String>,
+        label: Result<super::FlagLabel, String>,
+        product_ids: Result<Vec<String>, String>,
+    }
+    impl Default for Flag {
+        fn default() -> Self {
+            Self {
+                date: Ok(Default::default()),
+                group_ids: Ok(Default::default()),
+                label: Err("no value supplied for label".to_string()),
+                product_ids: Ok(Default::default()),
+            }
+        }
+    }
+    impl Flag {
+        pub fn date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.date = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for date: {}", e));
+            self
+        }
+        pub fn group_ids<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.group_ids = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for group_ids: {}", e)
+                });
+            self
+        }
+        pub fn label<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::FlagLabel>,
+            T::Error: std::fmt::Display,
+        {
+            self.label = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for label: {}", e)
+                });
+            self
+        }
+        pub fn product_ids<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.product_ids = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for product_ids: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Flag> for super::Flag {
+        type Error = String;
+        fn try_from(value: Flag) -> Result<Self, String> {
+            Ok(Self {
+                date: value.date?,
+                group_ids: value.group_ids?,
+                label: value.label?,
+                product_ids: value.product_ids?,
+            })
+        }
+    }
+    impl From<super::Flag> for Flag {
+        fn from(value: super::Flag) -> Self {
+            Self {
+                date: Ok(value.date),
+                group_ids: Ok(value.group_ids),
+                label: Ok(value.label),
+                product_ids: Ok(value.product_ids),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct FullProductName {
+        name: Result<String, String>,
+        product_id: Result<String, String>,
+        product_identification_helper: Result<Option<serde_json::Value>, String>,
+    }
+    impl Default for FullProductName {
+        fn default() -> Self {
+            Self {
+                name: Err("no value supplied for name".to_string()),
+                product_id: Err("no value supplied for product_id".to_string()),
+                product_identification_helper: Ok(Default::default()),
+            }
+        }
+    }
+    impl FullProductName {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn product_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.product_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for product_id: {}", e)
+                });
+            self
+        }
+        pub fn product_identification_helper<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.product_identification_helper = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for product_identification_helper: {}",
+                        e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<FullProductName> for super::FullProductName {
+        type Error = String;
+        fn try_from(value: FullProductName) -> Result<Self, String> {
+            Ok(Self {
+                name: value.name?,
+                product_id: value.product_id?,
+                product_identification_helper: value.product_identification_helper?,
+            })
+        }
+    }
+    impl From<super::FullProductName> for FullProductName {
+        fn from(value: super::FullProductName) -> Self {
+            Self {
+                name: Ok(value.name),
+                product_id: Ok(value.product_id),
+                product_identification_helper: Ok(value.product_identification_helper),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Group {
+        gid: Result<u32, String>,
+        groupname: Result<String, String>,
+        members: Result<Vec<String>, String>,
+    }
+    impl Default for Group {
+        fn default() -> Self {
+            Self {
+                gid: Err("no value supplied for gid".to_string()),
+                groupname: Err("no value supplied for groupname".to_string()),
+                members: Ok(Default::default()),
+            }
+        }
+    }
+    impl Group {
+        pub fn gid<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<u32>,
+            T::Error: std::fmt::Display,
+        {
+            self.gid = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for gid: {}", e));
+            self
+        }
+        pub fn groupname<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.groupname = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for groupname: {}", e)
+                });
+            self
+        }
+        pub fn members<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.members = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for members: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Group> for super::Group {
+        type Error = String;
+        fn try_from(value: Group) -> Result<Self, String> {
+            Ok(Self {
+                gid: value.gid?,
+                groupname: value.groupname?,
+                members: value.members?,
+            })
+        }
+    }
+    impl From<super::Group> for Group {
+        fn from(value: super::Group) -> Self {
+            Self {
+                gid: Ok(value.gid),
+                groupname: Ok(value.groupname),
+                members: Ok(value.members),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ImageConfiguration {
+        accounts: Result<Option<super::Accounts>, String>,
+        annotations: Result<std::collections::HashMap<String, String>, String>,
+        archs: Result<Vec<String>, String>,
+        cmd: Result<Option<String>, String>,
+        contents: Result<super::ApkContents, String>,
+        entrypoint: Result<Option<super::Entrypoint>, String>,
+        environment: Result<std::collections::HashMap<String, String>, String>,
+        work_dir: Result<Option<String>, String>,
+    }
+    impl Default for ImageConfiguration {
+        fn default() -> Self {
+            Self {
+                accounts: Ok(Default::default()),
+                annotations: Ok(Default::default()),
+                archs: Ok(Default::default()),
+                cmd: Ok(Default::default()),
+                contents: Err("no value supplied for contents".to_string()),
+                entrypoint: Ok(Default::default()),
+                environment: Ok(Default::default()),
+                work_dir: Ok(Default::default()),
+            }
+        }
+    }
+    impl ImageConfiguration {
+        pub fn accounts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Accounts>>,
+            T::Error: std::fmt::Display,
+        {
+            self.accounts = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for accounts: {}", e)
+                });
+            self
+        }
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<std::collections::HashMap<String, String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn archs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.archs = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for archs: {}", e)
+                });
+            self
+        }
+        pub fn cmd<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.cmd = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for cmd: {}", e));
+            self
+        }
+        pub fn contents<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ApkContents>,
+            T::Error: std::fmt::Display,
+        {
+            self.contents = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for contents: {}", e)
+                });
+            self
+        }
+        pub fn entrypoint<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Entrypoint>>,
+            T::Error: std::fmt::Display,
+        {
+            self.entrypoint = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for entrypoint: {}", e)
+                });
+            self
+        }
+        pub fn environment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<std::collections::HashMap<String, String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.environment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for environment: {}", e)
+                });
+            self
+        }
+        pub fn work_dir<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.work_dir = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for work_dir: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ImageConfiguration> for super::ImageConfiguration {
+        type Error = String;
+        fn try_from(value: ImageConfiguration) -> Result<Self, String> {
+            Ok(Self {
+                accounts: value.accounts?,
+                annotations: value.annotations?,
+                archs: value.archs?,
+                cmd: value.cmd?,
+                contents: value.contents?,
+                entrypoint: value.entrypoint?,
+                environment: value.environment?,
+                work_dir: value.work_dir?,
+            })
+        }
+    }
+    impl From<super::ImageConfiguration> for ImageConfiguration {
+        fn from(value: super::ImageConfiguration) -> Self {
+            Self {
+                accounts: Ok(value.accounts),
+                annotations: Ok(value.annotations),
+                archs: Ok(value.archs),
+                cmd: Ok(value.cmd),
+                contents: Ok(value.contents),
+                entrypoint: Ok(value.entrypoint),
+                environment: Ok(value.environment),
+                work_dir: Ok(value.work_dir),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct InTotoStatementV1ForPredicate {
+        predicate: Result<super::Predicate, String>,
+        predicate_type: Result<String, String>,
+        subject: Result<Vec<super::Subject>, String>,
+        type_: Result<String, String>,
+    }
+    impl Default for InTotoStatementV1ForPredicate {
+        fn default() -> Self {
+            Self {
+                predicate: Err("no value supplied for predicate".to_string()),
+                predicate_type: Err("no value supplied for predicate_type".to_string()),
+                subject: Err("no value supplied for subject".to_string()),
+                type_: Err("no value supplied for type_".to_string()),
+            }
+        }
+    }
+    impl InTotoStatementV1ForPredicate {
+        pub fn predicate<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Predicate>,
+            T::Error: std::fmt::Display,
+        {
+            self.predicate = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for predicate: {}", e)
+                });
+            self
+        }
+        pub fn predicate_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.predicate_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for predicate_type: {}", e)
+                });
+            self
+        }
+        pub fn subject<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Subject>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subject = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subject: {}", e)
+                });
+            self
+        }
+        pub fn type_<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.type_ = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for type_: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<InTotoStatementV1ForPredicate>
+    for super::InTotoStatementV1ForPredicate {
+        type Error = String;
+        fn try_from(value: InTotoStatementV1ForPredicate) -> Result<Self, String> {
+            Ok(Self {
+                predicate: value.predicate?,
+                predicate_type: value.predicate_type?,
+                subject: value.subject?,
+                type_: value.type_?,
+            })
+        }
+    }
+    impl From<super::InTotoStatementV1ForPredicate> for InTotoStatementV1ForPredicate {
+        fn from(value: super::InTotoStatementV1ForPredicate) -> Self {
+            Self {
+                predicate: Ok(value.predicate),
+                predicate_type: Ok(value.predicate_type),
+                subject: Ok(value.subject),
+                type_: Ok(value.type_),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Invocation {
+        config_source: Result<Option<super::ConfigSource>, String>,
+        environment: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
+        parameters: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
+    }
+    impl Default for Invocation {
+        fn default() -> Self {
+            Self {
+                config_source: Ok(Default::default()),
+                environment: Ok(Default::default()),
+                parameters: Ok(Default::default()),
+            }
+        }
+    }
+    impl Invocation {
+        pub fn config_source<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ConfigSource>>,
+            T::Error: std::fmt::Display,
+        {
+            self.config_source = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for config_source: {}", e)
+                });
+            self
+        }
+        pub fn environment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.environment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for environment: {}", e)
+                });
+            self
+        }
+        pub fn parameters<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.parameters = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for parameters: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Invocation> for super::Invocation {
+        type Error = String;
+        fn try_from(value: Invocation) -> Result<Self, String> {
+            Ok(Self {
+                config_source: value.config_source?,
+                environment: value.environment?,
+                parameters: value.parameters?,
+            })
+        }
+    }
+    impl From<super::Invocation> for Invocation {
+        fn from(value: super::Invocation) -> Self {
+            Self {
+                config_source: Ok(value.config_source),
+                environment: Ok(value.environment),
+                parameters: Ok(value.parameters),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct JvmBuildInfoPredicate {
+        agent: Result<Option<super::Agent>, String>,
+        build_agent: Result<Option<super::Agent>, String>,
+        modules: Result<Vec<super::Module>, String>,
+        name: Result<String, String>,
+        number: Result<String, String>,
+        plugins: Result<Vec<super::Plugin>, String>,
+        started: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+    }
+    impl Default for JvmBuildInfoPredicate {
+        fn default() -> Self {
+            Self {
+                agent: Ok(Default::default()),
+                build_agent: Ok(Default::default()),
+                modules: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                number: Err("no value supplied for number".to_string()),
+                plugins: Ok(Default::default()),
+                started: Ok(Default::default()),
+            }
+        }
+    }
+    impl JvmBuildInfoPredicate {
+        pub fn agent<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Agent>>,
+            T::Error: std::fmt::Display,
+        {
+            self.agent = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for agent: {}", e)
+                });
+            self
+        }
+        pub fn build_agent<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Agent>>,
+            T::Error: std::fmt::Display,
+        {
+            self.build_agent = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for build_agent: {}", e)
+                });
+            self
+        }
+        pub fn modules<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Module>>,
+            T::Error: std::fmt::Display,
+        {
+            self.modules = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for modules: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn number<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.number = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for number: {}", e)
+                });
+            self
+        }
+        pub fn plugins<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Plugin>>,
+            T::Error: std::fmt::Display,
+        {
+            self.plugins = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for plugins: {}", e)
+                });
+            self
+        }
+        pub fn started<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.started = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for started: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<JvmBuildInfoPredicate> for super::JvmBuildInfoPredicate {
+        type Error = String;
+        fn try_from(value: JvmBuildInfoPredicate) -> Result<Self, String> {
+            Ok(Self {
+                agent: value.agent?,
+                build_agent: value.build_agent?,
+                modules: value.modules?,
+                name: value.name?,
+                number: value.number?,
+                plugins: value.plugins?,
+                started: value.started?,
+            })
+        }
+    }
+    impl From<super::JvmBuildInfoPredicate> for JvmBuildInfoPredicate {
+        fn from(value: super::JvmBuildInfoPredicate) -> Self {
+            Self {
+                agent: Ok(value.agent),
+                build_agent: Ok(value.build_agent),
+                modules: Ok(value.modules),
+                name: Ok(value.name),
+                number: Ok(value.number),
+                plugins: Ok(value.plugins),
+                started: Ok(value.started),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct LicenseScanPredicate {
+        licenses: Result<Vec<super::DetectedLicense>, String>,
+        scanner: Result<super::Scanner, String>,
+        target: Result<super::ResourceDescriptor, String>,
+        time_scanned: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+    }
+    impl Default for LicenseScanPredicate {
+        fn default() -> Self {
+            Self {
+                licenses: Err("no value supplied for licenses".to_string()),
+                scanner: Err("no value supplied for scanner".to_string()),
+                target: Err("no value supplied for target".to_string()),
+                time_scanned: Ok(Default::default()),
+            }
+        }
+    }
+    impl LicenseScanPredicate {
+        pub fn licenses<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::DetectedLicense>>,
+            T::Error: std::fmt::Display,
+        {
+            self.licenses = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for licenses: {}", e)
+                });
+            self
+        }
+        pub fn scanner<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Scanner>,
+            T::Error: std::fmt::Display,
+        {
+            self.scanner = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for scanner: {}", e)
+                });
+            self
+        }
+        pub fn target<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::ResourceDescriptor>,
+            T::Error: std::fmt::Display,
+        {
+            self.target = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for target: {}", e)
+                });
+            self
+        }
+        pub fn time_scanned<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.time_scanned = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for time_scanned: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<LicenseScanPredicate> for super::LicenseScanPredicate {
+        type Error = String;
+        fn try_from(value: LicenseScanPredicate) -> Result<Self, String> {
+            Ok(Self {
+                licenses: value.licenses?,
+                scanner: value.scanner?,
+                target: value.target?,
+                time_scanned: value.time_scanned?,
+            })
+        }
+    }
+    impl From<super::LicenseScanPredicate> for LicenseScanPredicate {
+        fn from(value: super::LicenseScanPredicate) -> Self {
+            Self {
+                licenses: Ok(value.licenses),
+                scanner: Ok(value.scanner),
+                target: Ok(value.target),
+                time_scanned: Ok(value.time_scanned),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct LinkPredicate {
+        byproducts: Result<Option<super::Byproducts>, String>,
+        command: Result<Vec<String>, String>,
+        environment: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
+        materials: Result<std::collections::HashMap<String, super::DigestSet>, String>,
+        name: Result<String, String>,
+        products: Result<std::collections::HashMap<String, super::DigestSet>, String>,
+    }
+    impl Default for LinkPredicate {
+        fn default() -> Self {
+            Self {
+                byproducts: Ok(Default::default()),
+                command: Ok(Default::default()),
+                environment: Ok(Default::default()),
+                materials: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                products: Ok(Default::default()),
+            }
+        }
+    }
+    impl LinkPredicate {
+        pub fn byproducts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Byproducts>>,
+            T::Error: std::fmt::Display,
+        {
+            self.byproducts = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for byproducts: {}", e)
+                });
+            self
+        }
+        pub fn command<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.command = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for command: {}", e)
+                });
+            self
+        }
+        pub fn environment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.environment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for environment: {}", e)
+                });
+            self
+        }
+        pub fn materials<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                std::collections::HashMap<String, super::DigestSet>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.materials = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for materials: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn products<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                std::collections::HashMap<String, super::DigestSet>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.products = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for products: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<LinkPredicate> for super::LinkPredicate {
+        type Error = String;
+        fn try_from(value: LinkPredicate) -> Result<Self, String> {
+            Ok(Self {
+                byproducts: value.byproducts?,
+                command: value.command?,
+                environment: value.environment?,
+                materials: value.materials?,
+                name: value.name?,
+                products: value.products?,
+            })
+        }
+    }
+    impl From<super::LinkPredicate> for LinkPredicate {
+        fn from(value: super::LinkPredicate) -> Self {
+            Self {
+                byproducts: Ok(value.byproducts),
+                command: Ok(value.command),
+                environment: Ok(value.environment),
+                materials: Ok(value.materials),
+                name: Ok(value.name),
+                products: Ok(value.products),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Location {
+        logical_locations: Result<Option<serde_json::Value>, String>,
+        physical_location: Result<Option<super::PhysicalLocation>, String>,
+    }
+    impl Default for Location {
+        fn default() -> Self {
+            Self {
+                logical_locations: Ok(Default::default()),
+                physical_location: Ok(Default::default()),
+            }
+        }
+    }
+    impl Location {
+        pub fn logical_locations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.logical_locations = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for logical_locations: {}", e
+                    )
+                });
+            self
+        }
+        pub fn physical_location<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::PhysicalLocation>>,
+            T::Error: std::fmt::Display,
+        {
+            self.physical_location = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for physical_location: {}", e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Location> for super::Location {
+        type Error = String;
+        fn try_from(value: Location) -> Result<Self, String> {
+            Ok(Self {
+                logical_locations: value.logical_locations?,
+                physical_location: value.physical_location?,
+            })
+        }
+    }
+    impl From<super::Location> for Location {
+        fn from(value: super::Location) -> Self {
+            Self {
+                logical_locations: Ok(value.logical_locations),
+                physical_location: Ok(value.physical_location),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct MelangeBuildPredicate {
+        config: Result<super::MelangeConfiguration, String>,
+        packages: Result<Vec<super::ApkPackage>, String>,
+        sboms: Result<Vec<super::ResourceDescriptor>, String>,
+    }
+    impl Default for MelangeBuildPredicate {
+        fn default() -> Self {
+            Self {
+                config: Err("no value supplied for config".to_string()),
+                packages: Ok(Default::default()),
+                sboms: Ok(Default::default()),
+            }
+        }
+    }
+    impl MelangeBuildPredicate {
+        pub fn config<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::MelangeConfiguration>,
+            T::Error: std::fmt::Display,
+        {
+            self.config = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for config: {}", e)
+                });
+            self
+        }
+        pub fn packages<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::ApkPackage>>,
+            T::Error: std::fmt::Display,
+        {
+            self.packages = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for packages: {}", e)
+                });
+            self
+        }
+        pub fn sboms<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.sboms = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for sboms: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<MelangeBuildPredicate> for super::MelangeBuildPredicate {
+        type Error = String;
+        fn try_from(value: MelangeBuildPredicate) -> Result<Self, String> {
+            Ok(Self {
+                config: value.config?,
+                packages: value.packages?,
+                sboms: value.sboms?,
+            })
+        }
+    }
+    impl From<super::MelangeBuildPredicate> for MelangeBuildPredicate {
+        fn from(value: super::MelangeBuildPredicate) -> Self {
+            Self {
+                config: Ok(value.config),
+                packages: Ok(value.packages),
+                sboms: Ok(value.sboms),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct MelangeConfiguration {
+        environment: Result<Option<super::BuildEnvironment>, String>,
+        package: Result<super::MelangePackage, String>,
+        pipeline: Result<Vec<super::PipelineStep>, String>,
+        subpackages: Result<Vec<super::Subpackage>, String>,
+    }
+    impl Default for MelangeConfiguration {
+        fn default() -> Self {
+            Self {
+                environment: Ok(Default::default()),
+                package: Err("no value supplied for package".to_string()),
+                pipeline: Ok(Default::default()),
+                subpackages: Ok(Default::default()),
+            }
+        }
+    }
+    impl MelangeConfiguration {
+        pub fn environment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::BuildEnvironment>>,
+            T::Error: std::fmt::Display,
+        {
+            self.environment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for environment: {}", e)
+                });
+            self
+        }
+        pub fn package<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::MelangePackage>,
+            T::Error: std::fmt::Display,
+        {
+            self.package = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for package: {}", e)
+                });
+            self
+        }
+        pub fn pipeline<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::PipelineStep>>,
+            T::Error: std::fmt::Display,
+        {
+            self.pipeline = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for pipeline: {}", e)
+                });
+            self
+        }
+        pub fn subpackages<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Subpackage>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subpackages = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subpackages: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<MelangeConfiguration> for super::MelangeConfiguration {
+        type Error = String;
+        fn try_from(value: MelangeConfiguration) -> Result<Self, String> {
+            Ok(Self {
+                environment: value.environment?,
+                package: value.package?,
+                pipeline: value.pipeline?,
+                subpackages: value.subpackages?,
+            })
+        }
+    }
+    impl From<super::MelangeConfiguration> for MelangeConfiguration {
+        fn from(value: super::MelangeConfiguration) -> Self {
+            Self {
+                environment: Ok(value.environment),
+                package: Ok(value.package),
+                pipeline: Ok(value.pipeline),
+                subpackages: Ok(value.subpackages),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct MelangePackage {
+        copyright: Result<Vec<super::Copyright>, String>,
+        description: Result<Option<String>, String>,
+        epoch: Result<u64, String>,
+        name: Result<String, String>,
+        version: Result<String, String>,
+    }
+    impl Default for MelangePackage {
+        fn default() -> Self {
+            Self {
+                copyright: Ok(Default::default()),
+                description: Ok(Default::default()),
+                epoch: Err("no value supplied for epoch".to_string()),
+                name: Err("no value supplied for name".to_string()),
+                version: Err("no value supplied for version".to_string()),
+            }
+        }
+    }
+    impl MelangePackage {
+        pub fn copyright<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Copyright>>,
+            T::Error: std::fmt::Display,
+        {
+            self.copyright = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for copyright: {}", e)
+                });
+            self
+        }
+        pub fn description<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.description = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for description: {}", e)
+                });
+            self
+        }
+        pub fn epoch<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<u64>,
+            T::Error: std::fmt::Display,
+        {
+            self.epoch = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for epoch: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<MelangePackage> for super::MelangePackage {
+        type Error = String;
+        fn try_from(value: MelangePackage) -> Result<Self, String> {
+            Ok(Self {
+                copyright: value.copyright?,
+                description: value.description?,
+                epoch: value.epoch?,
+                name: value.name?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::MelangePackage> for MelangePackage {
+        fn from(value: super::MelangePackage) -> Self {
+            Self {
+                copyright: Ok(value.copyright),
+                description: Ok(value.description),
+                epoch: Ok(value.epoch),
+                name: Ok(value.name),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Message {
+        arguments: Result<Vec<String>, String>,
+        id: Result<Option<String>, String>,
+        markdown: Result<Option<String>, String>,
+        text: Result<Option<String>, String>,
+    }
+    impl Default for Message {
+        fn default() -> Self {
+            Self {
+                arguments: Ok(Default::default()),
+                id: Ok(Default::default()),
+                markdown: Ok(Default::default()),
+                text: Ok(Default::default()),
+            }
+        }
+    }
+    impl Message {
+        pub fn arguments<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.arguments = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for arguments: {}", e)
+                });
+            self
+        }
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+        pub fn markdown<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.markdown = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for markdown: {}", e)
+                });
+            self
+        }
+        pub fn text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.text = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for text: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Message> for super::Message {
+        type Error = String;
+        fn try_from(value: Message) -> Result<Self, String> {
+            Ok(Self {
+                arguments: value.arguments?,
+                id: value.id?,
+                markdown: value.markdown?,
+                text: value.text?,
+            })
+        }
+    }
+    impl From<super::Message> for Message {
+        fn from(value: super::Message) -> Self {
+            Self {
+                arguments: Ok(value.arguments),
+                id: Ok(value.id),
+                markdown: Ok(value.markdown),
+                text: Ok(value.text),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Module {
+        artifacts: Result<Vec<super::Artifact>, String>,
+        dependencies: Result<Vec<super::Dependency>, String>,
+        id: Result<String, String>,
+        type_: Result<Option<String>, String>,
+    }
+    impl Default for Module {
+        fn default() -> Self {
+            Self {
+                artifacts: Ok(Default::default()),
+                dependencies: Ok(Default::default()),
+                id: Err("no value supplied for id".to_string()),
+                type_: Ok(Default::default()),
+            }
+        }
+    }
+    impl Module {
+        pub fn artifacts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Artifact>>,
+            T::Error: std::fmt::Display,
+        {
+            self.artifacts = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for artifacts: {}", e)
+                });
+            self
+        }
+        pub fn dependencies<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Dependency>>,
+            T::Error: std::fmt::Display,
+        {
+            self.dependencies = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for dependencies: {}", e)
+                });
+            self
+        }
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+        pub fn type_<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.type_ = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for type_: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Module> for super::Module {
+        type Error = String;
+        fn try_from(value: Module) -> Result<Self, String> {
+            Ok(Self {
+                artifacts: value.artifacts?,
+                dependencies: value.dependencies?,
+                id: value.id?,
+                type_: value.type_?,
+            })
+        }
+    }
+    impl From<super::Module> for Module {
+        fn from(value: super::Module) -> Self {
+            Self {
+                artifacts: Ok(value.artifacts),
+                dependencies: Ok(value.dependencies),
+                id: Ok(value.id),
+                type_: Ok(value.type_),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct MultiformatMessage {
+        markdown: Result<Option<String>, String>,
+        text: Result<String, String>,
+    }
+    impl Default for MultiformatMessage {
+        fn default() -> Self {
+            Self {
+                markdown: Ok(Default::default()),
+                text: Err("no value supplied for text".to_string()),
+            }
+        }
+    }
+    impl MultiformatMessage {
+        pub fn markdown<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.markdown = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for markdown: {}", e)
+                });
+            self
+        }
+        pub fn text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.text = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for text: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<MultiformatMessage> for super::MultiformatMessage {
+        type Error = String;
+        fn try_from(value: MultiformatMessage) -> Result<Self, String> {
+            Ok(Self {
+                markdown: value.markdown?,
+                text: value.text?,
+            })
+        }
+    }
+    impl From<super::MultiformatMessage> for MultiformatMessage {
+        fn from(value: super::MultiformatMessage) -> Self {
+            Self {
+                markdown: Ok(value.markdown),
+                text: Ok(value.text),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PhysicalLocation {
+        artifact_location: Result<Option<super::ArtifactLocation>, String>,
+        region: Result<Option<serde_json::Value>, String>,
+    }
+    impl Default for PhysicalLocation {
+        fn default() -> Self {
+            Self {
+                artifact_location: Ok(Default::default()),
+                region: Ok(Default::default()),
+            }
+        }
+    }
+    impl PhysicalLocation {
+        pub fn artifact_location<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ArtifactLocation>>,
+            T::Error: std::fmt::Display,
+        {
+            self.artifact_location = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for artifact_location: {}", e
+                    )
+                });
+            self
+        }
+        pub fn region<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.region = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for region: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<PhysicalLocation> for super::PhysicalLocation {
+        type Error = String;
+        fn try_from(value: PhysicalLocation) -> Result<Self, String> {
+            Ok(Self {
+                artifact_location: value.artifact_location?,
+                region: value.region?,
+            })
+        }
+    }
+    impl From<super::PhysicalLocation> for PhysicalLocation {
+        fn from(value: super::PhysicalLocation) -> Self {
+            Self {
+                artifact_location: Ok(value.artifact_location),
+                region: Ok(value.region),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PipelineStep {
+        name: Result<Option<String>, String>,
+        runs: Result<Option<String>, String>,
+        uses: Result<Option<String>, String>,
+        with: Result<serde_json::Map<String, serde_json::Value>, String>,
+    }
+    impl Default for PipelineStep {
+        fn default() -> Self {
+            Self {
+                name: Ok(Default::default()),
+                runs: Ok(Default::default()),
+                uses: Ok(Default::default()),
+                with: Ok(Default::default()),
+            }
+        }
+    }
+    impl PipelineStep {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn runs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.runs = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for runs: {}", e));
+            self
+        }
+        pub fn uses<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.uses = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for uses: {}", e));
+            self
+        }
+        pub fn with<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<serde_json::Map<String, serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.with = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for with: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<PipelineStep> for super::PipelineStep {
+        type Error = String;
+        fn try_from(value: PipelineStep) -> Result<Self, String> {
+            Ok(Self {
+                name: value.name?,
+                runs: value.runs?,
+                uses: value.uses?,
+                with: value.with?,
+            })
+        }
+    }
+    impl From<super::PipelineStep> for PipelineStep {
+        fn from(value: super::PipelineStep) -> Self {
+            Self {
+                name: Ok(value.name),
+                runs: Ok(value.runs),
+                uses: Ok(value.uses),
+                with: Ok(value.with),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Plugin {
+        id: Result<String, String>,
+        version: Result<String, String>,
+    }
+    impl Default for Plugin {
+        fn default() -> Self {
+            Self {
+                id: Err("no value supplied for id".to_string()),
+                version: Err("no value supplied for version".to_string()),
+            }
+        }
+    }
+    impl Plugin {
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Plugin> for super::Plugin {
+        type Error = String;
+        fn try_from(value: Plugin) -> Result<Self, String> {
+            Ok(Self {
+                id: value.id?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::Plugin> for Plugin {
+        fn from(value: super::Plugin) -> Self {
+            Self {
+                id: Ok(value.id),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Predicate {
+        subtype_0: Result<Option<super::SlsaProvenanceV1Predicate>, String>,
+        subtype_1: Result<Option<super::SlsaProvenanceV02Predicate>, String>,
+        subtype_2: Result<Option<super::Scaiv02Predicate>, String>,
+        subtype_3: Result<Option<super::Scaiv03Predicate>, String>,
+        subtype_4: Result<Option<super::ApkoBuildPredicate>, String>,
+        subtype_5: Result<Option<super::MelangeBuildPredicate>, String>,
+        subtype_6: Result<Option<super::JvmBuildInfoPredicate>, String>,
+        subtype_7: Result<Option<super::VerificationSummaryV1Predicate>, String>,
+        subtype_8: Result<Option<super::LinkPredicate>, String>,
+        subtype_9: Result<Option<super::SpdxDocumentPredicate>, String>,
+        subtype_10: Result<Option<super::CycloneDxBom>, String>,
+        subtype_11: Result<Option<super::TestResultPredicate>, String>,
+        subtype_12: Result<Option<super::SarifLog>, String>,
+        subtype_13: Result<Option<super::CsafDocument>, String>,
+        subtype_14: Result<Option<super::ReferencePredicate>, String>,
+        subtype_15: Result<Option<super::LicenseScanPredicate>, String>,
+        subtype_16: Result<Option<super::CodeReviewPredicate>, String>,
+        subtype_17: Result<Option<super::DependencyReviewPredicate>, String>,
+        subtype_18: Result<Option<serde_json::Value>, String>,
+        subtype_19: Result<Option<()>, String>,
+    }
+    impl Default for Predicate {
+        fn default() -> Self {
+            Self {
+                subtype_0: Ok(Default::default()),
+                subtype_1: Ok(Default::default()),
+                subtype_2: Ok(Default::default()),
+                subtype_3: Ok(Default::default()),
+                subtype_4: Ok(Default::default()),
+                subtype_5: Ok(Default::default()),
+                subtype_6: Ok(Default::default()),
+                subtype_7: Ok(Default::default()),
+                subtype_8: Ok(Default::default()),
+                subtype_9: Ok(Default::default()),
+                subtype_10: Ok(Default::default()),
+                subtype_11: Ok(Default::default()),
+                subtype_12: Ok(Default::default()),
+                subtype_13: Ok(Default::default()),
+                subtype_14: Ok(Default::default()),
+                subtype_15: Ok(Default::default()),
+                subtype_16: Ok(Default::default()),
+                subtype_17: Ok(Default::default()),
+                subtype_18: Ok(Default::default()),
+                subtype_19: Ok(Default::default()),
+            }
+        }
+    }
+    impl Predicate {
+        pub fn subtype_0<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::SlsaProvenanceV1Predicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_0 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_0: {}", e)
+                });
+            self
+        }
+        pub fn subtype_1<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::SlsaProvenanceV02Predicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_1 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_1: {}", e)
+                });
+            self
+        }
+        pub fn subtype_2<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Scaiv02Predicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_2 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_2: {}", e)
+                });
+            self
+        }
+        pub fn subtype_3<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Scaiv03Predicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_3 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_3: {}", e)
+                });
+            self
+        }
+        pub fn subtype_4<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ApkoBuildPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_4 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_4: {}", e)
+                });
+            self
+        }
+        pub fn subtype_5<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::MelangeBuildPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_5 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_5: {}", e)
+                });
+            self
+        }
+        pub fn subtype_6<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::JvmBuildInfoPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_6 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_6: {}", e)
+                });
+            self
+        }
+        pub fn subtype_7<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::VerificationSummaryV1Predicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_7 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_7: {}", e)
+                });
+            self
+        }
+        pub fn subtype_8<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::LinkPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_8 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_8: {}", e)
+                });
+            self
+        }
+        pub fn subtype_9<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::SpdxDocumentPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_9 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_9: {}", e)
+                });
+            self
+        }
+        pub fn subtype_10<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::CycloneDxBom>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_10 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_10: {}", e)
+                });
+            self
+        }
+        pub fn subtype_11<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::TestResultPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_11 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_11: {}", e)
+                });
+            self
+        }
+        pub fn subtype_12<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::SarifLog>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_12 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_12: {}", e)
+                });
+            self
+        }
+        pub fn subtype_13<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::CsafDocument>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_13 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_13: {}", e)
+                });
+            self
+        }
+        pub fn subtype_14<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ReferencePredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_14 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_14: {}", e)
+                });
+            self
+        }
+        pub fn subtype_15<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::LicenseScanPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_15 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_15: {}", e)
+                });
+            self
+        }
+        pub fn subtype_16<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::CodeReviewPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_16 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_16: {}", e)
+                });
+            self
+        }
+        pub fn subtype_17<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::DependencyReviewPredicate>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_17 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_17: {}", e)
+                });
+            self
+        }
+        pub fn subtype_18<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_18 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_18: {}", e)
+                });
+            self
+        }
+        pub fn subtype_19<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<()>>,
+            T::Error: std::fmt::Display,
+        {
+            self.subtype_19 = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for subtype_19: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Predicate> for super::Predicate {
+        type Error = String;
+        fn try_from(value: Predicate) -> Result<Self, String> {
+            Ok(Self {
+                subtype_0: value.subtype_0?,
+                subtype_1: value.subtype_1?,
+                subtype_2: value.subtype_2?,
+                subtype_3: value.subtype_3?,
+                subtype_4: value.subtype_4?,
+                subtype_5: value.subtype_5?,
+                subtype_6: value.subtype_6?,
+                subtype_7: value.subtype_7?,
+                subtype_8: value.subtype_8?,
+                subtype_9: value.subtype_9?,
+                subtype_10: value.subtype_10?,
+                subtype_11: value.subtype_11?,
+                subtype_12: value.subtype_12?,
+                subtype_13: value.subtype_13?,
+                subtype_14: value.subtype_14?,
+                subtype_15: value.subtype_15?,
+                subtype_16: value.subtype_16?,
+                subtype_17: value.subtype_17?,
+                subtype_18: value.subtype_18?,
+                subtype_19: value.subtype_19?,
+            })
+        }
+    }
+    impl From<super::Predicate> for Predicate {
+        fn from(value: super::Predicate) -> Self {
+            Self {
+                subtype_0: Ok(value.subtype_0),
+                subtype_1: Ok(value.subtype_1),
+                subtype_2: Ok(value.subtype_2),
+                subtype_3: Ok(value.subtype_3),
+                subtype_4: Ok(value.subtype_4),
+                subtype_5: Ok(value.subtype_5),
+                subtype_6: Ok(value.subtype_6),
+                subtype_7: Ok(value.subtype_7),
+                subtype_8: Ok(value.subtype_8),
+                subtype_9: Ok(value.subtype_9),
+                subtype_10: Ok(value.subtype_10),
+                subtype_11: Ok(value.subtype_11),
+                subtype_12: Ok(value.subtype_12),
+                subtype_13: Ok(value.subtype_13),
+                subtype_14: Ok(value.subtype_14),
+                subtype_15: Ok(value.subtype_15),
+                subtype_16: Ok(value.subtype_16),
+                subtype_17: Ok(value.subtype_17),
+                subtype_18: Ok(value.subtype_18),
+                subtype_19: Ok(value.subtype_19),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ProductGroup {
+        group_id: Result<String, String>,
+        product_ids: Result<Vec<String>, String>,
+        summary: Result<Option<String>, String>,
+    }
+    impl Default for ProductGroup {
+        fn default() -> Self {
+            Self {
+                group_id: Err("no value supplied for group_id".to_string()),
+                product_ids: Err("no value supplied for product_ids".to_string()),
+                summary: Ok(Default::default()),
+            }
+        }
+    }
+    impl ProductGroup {
+        pub fn group_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.group_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for group_id: {}", e)
+                });
+            self
+        }
+        pub fn product_ids<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.product_ids = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for product_ids: {}", e)
+                });
+            self
+        }
+        pub fn summary<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.summary = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for summary: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ProductGroup> for super::ProductGroup {
+        type Error = String;
+        fn try_from(value: ProductGroup) -> Result<Self, String> {
+            Ok(Self {
+                group_id: value.group_id?,
+                product_ids: value.product_ids?,
+                summary: value.summary?,
+            })
+        }
+    }
+    impl From<super::ProductGroup> for ProductGroup {
+        fn from(value: super::ProductGroup) -> Self {
+            Self {
+                group_id: Ok(value.group_id),
+                product_ids: Ok(value.product_ids),
+                summary: Ok(value.summary),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ProductStatus {
+        first_affected: Result<Vec<String>, String>,
+        first_fixed: Result<Vec<String>, String>,
+        fixed: Result<Vec<String>, String>,
+        known_affected: Result<Vec<String>, String>,
+        known_not_affected: Result<Vec<String>, String>,
+        last_affected: Result<Vec<String>, String>,
+        recommended: Result<Vec<String>, String>,
+        under_investigation: Result<Vec<String>, String>,
+    }
+    impl Default for ProductStatus {
+        fn default() -> Self {
+            Self {
+                first_affected: Ok(Default::default()),
+                first_fixed: Ok(Default::default()),
+                fixed: Ok(Default::default()),
+                known_affected: Ok(Default::default()),
+                known_not_affected: Ok(Default::default()),
+                last_affected: Ok(Default::default()),
+                recommended: Ok(Default::default()),
+                under_investigation: Ok(Default::default()),
+            }
+        }
+    }
+    impl ProductStatus {
+        pub fn first_affected<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.first_affected = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for first_affected: {}", e)
+                });
+            self
+        }
+        pub fn first_fixed<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.first_fixed = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for first_fixed: {}", e)
+                });
+            self
+        }
+        pub fn fixed<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.fixed = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for fixed: {}", e)
+                });
+            self
+        }
+        pub fn known_affected<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.known_affected = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for known_affected: {}", e)
+                });
+            self
+        }
+        pub fn known_not_affected<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.known_not_affected = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for known_not_affected: {}", e
+                    )
+                });
+            self
+        }
+        pub fn last_affected<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.last_affected = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for last_affected: {}", e)
+                });
+            self
+        }
+        pub fn recommended<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.recommended = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for recommended: {}", e)
+                });
+            self
+        }
+        pub fn under_investigation<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.under_investigation = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for under_investigation: {}", e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ProductStatus> for super::ProductStatus {
+        type Error = String;
+        fn try_from(value: ProductStatus) -> Result<Self, String> {
+            Ok(Self {
+                first_affected: value.first_affected?,
+                first_fixed: value.first_fixed?,
+                fixed: value.fixed?,
+                known_affected: value.known_affected?,
+                known_not_affected: value.known_not_affected?,
+                last_affected: value.last_affected?,
+                recommended: value.recommended?,
+                under_investigation: value.under_investigation?,
+            })
+        }
+    }
+    impl From<super::ProductStatus> for ProductStatus {
+        fn from(value: super::ProductStatus) -> Self {
+            Self {
+                first_affected: Ok(value.first_affected),
+                first_fixed: Ok(value.first_fixed),
+                fixed: Ok(value.fixed),
+                known_affected: Ok(value.known_affected),
+                known_not_affected: Ok(value.known_not_affected),
+                last_affected: Ok(value.last_affected),
+                recommended: Ok(value.recommended),
+                under_investigation: Ok(value.under_investigation),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ProductTree {
+        branches: Result<Vec<super::Branch>, String>,
+        full_product_names: Result<Vec<super::FullProductName>, String>,
+        product_groups: Result<Vec<super::ProductGroup>, String>,
+        relationships: Result<Vec<super::Relationship>, String>,
+    }
+    impl Default for ProductTree {
+        fn default() -> Self {
+            Self {
+                branches: Ok(Default::default()),
+                full_product_names: Ok(Default::default()),
+                product_groups: Ok(Default::default()),
+                relationships: Ok(Default::default()),
+            }
+        }
+    }
+    impl ProductTree {
+        pub fn branches<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Branch>>,
+            T::Error: std::fmt::Display,
+        {
+            self.branches = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for branches: {}", e)
+                });
+            self
+        }
+        pub fn full_product_names<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::FullProductName>>,
+            T::Error: std::fmt::Display,
+        {
+            self.full_product_names = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for full_product_names: {}", e
+                    )
+                });
+            self
+        }
+        pub fn product_groups<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::ProductGroup>>,
+            T::Error: std::fmt::Display,
+        {
+            self.product_groups = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for product_groups: {}", e)
+                });
+            self
+        }
+        pub fn relationships<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Relationship>>,
+            T::Error: std::fmt::Display,
+        {
+            self.relationships = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for relationships: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ProductTree> for super::ProductTree {
+        type Error = String;
+        fn try_from(value: ProductTree) -> Result<Self, String> {
+            Ok(Self {
+                branches: value.branches?,
+                full_product_names: value.full_product_names?,
+                product_groups: value.product_groups?,
+                relationships: value.relationships?,
+            })
+        }
+    }
+    impl From<super::ProductTree> for ProductTree {
+        fn from(value: super::ProductTree) -> Self {
+            Self {
+                branches: Ok(value.branches),
+                full_product_names: Ok(value.full_product_names),
+                product_groups: Ok(value.product_groups),
+                relationships: Ok(value.relationships),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Publisher {
+        category: Result<String, String>,
+        contact_details: Result<Option<String>, String>,
+        name: Result<String, String>,
+        namespace: Result<String, String>,
+    }
+    impl Default for Publisher {
+        fn default() -> Self {
+            Self {
+                category: Err("no value supplied for category".to_string()),
+                contact_details: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                namespace: Err("no value supplied for namespace".to_string()),
+            }
+        }
+    }
+    impl Publisher {
+        pub fn category<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.category = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for category: {}", e)
+                });
+            self
+        }
+        pub fn contact_details<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.contact_details = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for contact_details: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn namespace<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.namespace = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for namespace: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Publisher> for super::Publisher {
+        type Error = String;
+        fn try_from(value: Publisher) -> Result<Self, String> {
+            Ok(Self {
+                category: value.category?,
+                contact_details: value.contact_details?,
+                name: value.name?,
+                namespace: value.namespace?,
+            })
+        }
+    }
+    impl From<super::Publisher> for Publisher {
+        fn from(value: super::Publisher) -> Self {
+            Self {
+                category: Ok(value.category),
+                contact_details: Ok(value.contact_details),
+                name: Ok(value.name),
+                namespace: Ok(value.namespace),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ReferencePredicate {
+        attester: Result<super::Attester, String>,
+        references: Result<Vec<super::ResourceDescriptor>, String>,
+    }
+    impl Default for ReferencePredicate {
+        fn default() -> Self {
+            Self {
+                attester: Err("no value supplied for attester".to_string()),
+                references: Err("no value supplied for references".to_string()),
+            }
+        }
+    }
+    impl ReferencePredicate {
+        pub fn attester<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Attester>,
+            T::Error: std::fmt::Display,
+        {
+            self.attester = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for attester: {}", e)
+                });
+            self
+        }
+        pub fn references<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.references = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for references: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ReferencePredicate> for super::ReferencePredicate {
+        type Error = String;
+        fn try_from(value: ReferencePredicate) -> Result<Self, String> {
+            Ok(Self {
+                attester: value.attester?,
+                references: value.references?,
+            })
+        }
+    }
+    impl From<super::ReferencePredicate> for ReferencePredicate {
+        fn from(value: super::ReferencePredicate) -> Self {
+            Self {
+                attester: Ok(value.attester),
+                references: Ok(value.references),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Relationship {
+        category: Result<String, String>,
+        full_product_name: Result<super::FullProductName, String>,
+        product_reference: Result<String, String>,
+        relates_to_product_reference: Result<String, String>,
+    }
+    impl Default for Relationship {
+        fn default() -> Self {
+            Self {
+                category: Err("no value supplied for category".to_string()),
+                full_product_name: Err(
+                    "no value supplied for full_product_name".to_string(),
+                ),
+                product_reference: Err(
+                    "no value supplied for product_reference".to_string(),
+                ),
+                relates_to_product_reference: Err(
+                    "no value supplied for relates_to_product_reference".to_string(),
+                ),
+            }
+        }
+    }
+    impl Relationship {
+        pub fn category<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.category = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for category: {}", e)
+                });
+            self
+        }
+        pub fn full_product_name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::FullProductName>,
+            T::Error: std::fmt::Display,
+        {
+            self.full_product_name = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for full_product_name: {}", e
+                    )
+                });
+            self
+        }
+        pub fn product_reference<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.product_reference = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for product_reference: {}", e
+                    )
+                });
+            self
+        }
+        pub fn relates_to_product_reference<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.relates_to_product_reference = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for relates_to_product_reference: {}",
+                        e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Relationship> for super::Relationship {
+        type Error = String;
+        fn try_from(value: Relationship) -> Result<Self, String> {
+            Ok(Self {
+                category: value.category?,
+                full_product_name: value.full_product_name?,
+                product_reference: value.product_reference?,
+                relates_to_product_reference: value.relates_to_product_reference?,
+            })
+        }
+    }
+    impl From<super::Relationship> for Relationship {
+        fn from(value: super::Relationship) -> Self {
+            Self {
+                category: Ok(value.category),
+                full_product_name: Ok(value.full_product_name),
+                product_reference: Ok(value.product_reference),
+                relates_to_product_reference: Ok(value.relates_to_product_reference),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Remediation {
+        category: Result<String, String>,
+        date: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+        details: Result<String, String>,
+        group_ids: Result<Vec<String>, String>,
+        product_ids: Result<Vec<String>, String>,
+        url: Result<Option<String>, String>,
+    }
+    impl Default for Remediation {
+        fn default() -> Self {
+            Self {
+                category: Err("no value supplied for category".to_string()),
+                date: Ok(Default::default()),
+                details: Err("no value supplied for details".to_string()),
+                group_ids: Ok(Default::default()),
+                product_ids: Ok(Default::default()),
+                url: Ok(Default::default()),
+            }
+        }
+    }
+    impl Remediation {
+        pub fn category<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.category = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for category: {}", e)
+                });
+            self
+        }
+        pub fn date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.date = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for date: {}", e));
+            self
+        }
+        pub fn details<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.details = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for details: {}", e)
+                });
+            self
+        }
+        pub fn group_ids<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.group_ids = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for group_ids: {}", e)
+                });
+            self
+        }
+        pub fn product_ids<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.product_ids = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for product_ids: {}", e)
+                });
+            self
+        }
+        pub fn url<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.url = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for url: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Remediation> for super::Remediation {
+        type Error = String;
+        fn try_from(value: Remediation) -> Result<Self, String> {
+            Ok(Self {
+                category: value.category?,
+                date: value.date?,
+                details: value.details?,
+                group_ids: value.group_ids?,
+                product_ids: value.product_ids?,
+                url: value.url?,
+            })
+        }
+    }
+    impl From<super::Remediation> for Remediation {
+        fn from(value: super::Remediation) -> Self {
+            Self {
+                category: Ok(value.category),
+                date: Ok(value.date),
+                details: Ok(value.details),
+                group_ids: Ok(value.group_ids),
+                product_ids: Ok(value.product_ids),
+                url: Ok(value.url),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ReportingDescriptor {
+        default_configuration: Result<Option<serde_json::Value>, String>,
+        help_uri: Result<Option<String>, String>,
+        id: Result<String, String>,
+        name: Result<Option<String>, String>,
+        properties: Result<Option<serde_json::Value>, String>,
+        short_description: Result<Option<super::MultiformatMessage>, String>,
+    }
+    impl Default for ReportingDescriptor {
+        fn default() -> Self {
+            Self {
+                default_configuration: Ok(Default::default()),
+                help_uri: Ok(Default::default()),
+                id: Err("no value supplied for id".to_string()),
+                name: Ok(Default::default()),
+                properties: Ok(Default::default()),
+                short_description: Ok(Default::default()),
+            }
+        }
+    }
+    impl ReportingDescriptor {
+        pub fn default_configuration<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.default_configuration = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for default_configuration: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn help_uri<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.help_uri = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for help_uri: {}", e)
+                });
+            self
+        }
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn properties<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.properties = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for properties: {}", e)
+                });
+            self
+        }
+        pub fn short_description<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::MultiformatMessage>>,
+            T::Error: std::fmt::Display,
+        {
+            self.short_description = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for short_description: {}", e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ReportingDescriptor> for super::ReportingDescriptor {
+        type Error = String;
+        fn try_from(value: ReportingDescriptor) -> Result<Self, String> {
+            Ok(Self {
+                default_configuration: value.default_configuration?,
+                help_uri: value.help_uri?,
+                id: value.id?,
+                name: value.name?,
+                properties: value.properties?,
+                short_description: value.short_description?,
+            })
+        }
+    }
+    impl From<super::ReportingDescriptor> for ReportingDescriptor {
+        fn from(value: super::ReportingDescriptor) -> Self {
+            Self {
+                default_configuration: Ok(value.default_configuration),
+                help_uri: Ok(value.help_uri),
+                id: Ok(value.id),
+                name: Ok(value.name),
+                properties: Ok(value.properties),
+                short_description: Ok(value.short_description),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ResourceDescriptor {
+        annotations: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
+        content: Result<Option<String>, String>,
+        digest: Result<Option<std::collections::HashMap<String, String>>, String>,
+        download_location: Result<Option<String>, String>,
+        media_type: Result<Option<String>, String>,
+        name: Result<Option<String>, String>,
+        uri: Result<Option<String>, String>,
+    }
+    impl Default for ResourceDescriptor {
+        fn default() -> Self {
+            Self {
+                annotations: Ok(Default::default()),
+                content: Ok(Default::default()),
+                digest: Ok(Default::default()),
+                download_location: Ok(Default::default()),
+                media_type: Ok(Default::default()),
+                name: Ok(Default::default()),
+                uri: Ok(Default::default()),
+            }
+        }
+    }
+    impl ResourceDescriptor {
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn content<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.content = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for content: {}", e)
+                });
+            self
+        }
+        pub fn digest<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.digest = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for digest: {}", e)
+                });
+            self
+        }
+        pub fn download_location<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.download_location = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for download_location: {}", e
+                    )
+                });
+            self
+        }
+        pub fn media_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.media_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for media_type: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn uri<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.uri = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for uri: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<ResourceDescriptor> for super::ResourceDescriptor {
+        type Error = String;
+        fn try_from(value: ResourceDescriptor) -> Result<Self, String> {
+            Ok(Self {
+                annotations: value.annotations?,
+                content: value.content?,
+                digest: value.digest?,
+                download_location: value.download_location?,
+                media_type: value.media_type?,
+                name: value.name?,
+                uri: value.uri?,
+            })
+        }
+    }
+    impl From<super::ResourceDescriptor> for ResourceDescriptor {
+        fn from(value: super::ResourceDescriptor) -> Self {
+            Self {
+                annotations: Ok(value.annotations),
+                content: Ok(value.content),
+                digest: Ok(value.digest),
+                download_location: Ok(value.download_location),
+                media_type: Ok(value.media_type),
+                name: Ok(value.name),
+                uri: Ok(value.uri),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ReviewTool {
+        name: Result<String, String>,
+        uri: Result<Option<String>, String>,
+        version: Result<Option<String>, String>,
+    }
+    impl Default for ReviewTool {
+        fn default() -> Self {
+            Self {
+                name: Err("no value supplied for name".to_string()),
+                uri: Ok(Default::default()),
+                version: Ok(Default::default()),
+            }
+        }
+    }
+    impl ReviewTool {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn uri<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.uri = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for uri: {}", e));
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<ReviewTool> for super::ReviewTool {
+        type Error = String;
+        fn try_from(value: ReviewTool) -> Result<Self, String> {
+            Ok(Self {
+                name: value.name?,
+                uri: value.uri?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::ReviewTool> for ReviewTool {
+        fn from(value: super::ReviewTool) -> Self {
+            Self {
+                name: Ok(value.name),
+                uri: Ok(value.uri),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Reviewer {
+        id: Result<String, String>,
+        name: Result<Option<String>, String>,
+    }
+    impl Default for Reviewer {
+        fn default() -> Self {
+            Self {
+                id: Err("no value supplied for id".to_string()),
+                name: Ok(Default::default()),
+            }
+        }
+    }
+    impl Reviewer {
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Reviewer> for super::Reviewer {
+        type Error = String;
+        fn try_from(value: Reviewer) -> Result<Self, String> {
+            Ok(Self {
+                id: value.id?,
+                name: value.name?,
+            })
+        }
+    }
+    impl From<super::Reviewer> for Reviewer {
+        fn from(value: super::Reviewer) -> Self {
+            Self {
+                id: Ok(value.id),
+                name: Ok(value.name),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Revision {
+        date: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        number: Result<String, String>,
+        summary: Result<String, String>,
+    }
+    impl Default for Revision {
+        fn default() -> Self {
+            Self {
+                date: Err("no value supplied for date".to_string()),
+                number: Err("no value supplied for number".to_string()),
+                summary: Err("no value supplied for summary".to_string()),
+            }
+        }
+    }
+    impl Revision {
+        pub fn date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+            T::Error: std::fmt::Display,
+        {
+            self.date = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for date: {}", e));
+            self
+        }
+        pub fn number<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.number = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for number: {}", e)
+                });
+            self
+        }
+        pub fn summary<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.summary = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for summary: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Revision> for super::Revision {
+        type Error = String;
+        fn try_from(value: Revision) -> Result<Self, String> {
+            Ok(Self {
+                date: value.date?,
+                number: value.number?,
+                summary: value.summary?,
+            })
+        }
+    }
+    impl From<super::Revision> for Revision {
+        fn from(value: super::Revision) -> Self {
+            Self {
+                date: Ok(value.date),
+                number: Ok(value.number),
+                summary: Ok(value.summary),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Run {
+        results: Result<Option<Vec<super::SarifResult>>, String>,
+        tool: Result<super::Tool, String>,
+    }
+    impl Default for Run {
+        fn default() -> Self {
+            Self {
+                results: Ok(Default::default()),
+                tool: Err("no value supplied for tool".to_string()),
+            }
+        }
+    }
+    impl Run {
+        pub fn results<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<Vec<super::SarifResult>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.results = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for results: {}", e)
+                });
+            self
+        }
+        pub fn tool<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Tool>,
+            T::Error: std::fmt::Display,
+        {
+            self.tool = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tool: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Run> for super::Run {
+        type Error = String;
+        fn try_from(value: Run) -> Result<Self, String> {
+            Ok(Self {
+                results: value.results?,
+                tool: value.tool?,
+            })
+        }
+    }
+    impl From<super::Run> for Run {
+        fn from(value: super::Run) -> Self {
+            Self {
+                results: Ok(value.results),
+                tool: Ok(value.tool),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct RunDetails {
+        builder: Result<super::Builder, String>,
+        byproducts: Result<Option<Vec<super::ResourceDescriptor>>, String>,
+        metadata: Result<Option<super::BuildMetadata>, String>,
+    }
+    impl Default for RunDetails {
+        fn default() -> Self {
+            Self {
+                builder: Err("no value supplied for builder".to_string()),
+                byproducts: Ok(Default::default()),
+                metadata: Ok(Default::default()),
+            }
+        }
+    }
+    impl RunDetails {
+        pub fn builder<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Builder>,
+            T::Error: std::fmt::Display,
+        {
+            self.builder = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for builder: {}", e)
+                });
+            self
+        }
+        pub fn byproducts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.byproducts = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for byproducts: {}", e)
+                });
+            self
+        }
+        pub fn metadata<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::BuildMetadata>>,
+            T::Error: std::fmt::Display,
+        {
+            self.metadata = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for metadata: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<RunDetails> for super::RunDetails {
+        type Error = String;
+        fn try_from(value: RunDetails) -> Result<Self, String> {
+            Ok(Self {
+                builder: value.builder?,
+                byproducts: value.byproducts?,
+                metadata: value.metadata?,
+            })
+        }
+    }
+    impl From<super::RunDetails> for RunDetails {
+        fn from(value: super::RunDetails) -> Self {
+            Self {
+                builder: Ok(value.builder),
+                byproducts: Ok(value.byproducts),
+                metadata: Ok(value.metadata),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct SarifLog {
+        runs: Result<Vec<super::Run>, String>,
+        schema: Result<Option<String>, String>,
+        version: Result<String, String>,
+    }
+    impl Default for SarifLog {
+        fn default() -> Self {
+            Self {
+                runs: Err("no value supplied for runs".to_string()),
+                schema: Ok(Default::default()),
+                version: Err("no value supplied for version".to_string()),
+            }
+        }
+    }
+    impl SarifLog {
+        pub fn runs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Run>>,
+            T::Error: std::fmt::Display,
+        {
+            self.runs = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for runs: {}", e));
+            self
+        }
+        pub fn schema<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.schema = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for schema: {}", e)
+                });
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<SarifLog> for super::SarifLog {
+        type Error = String;
+        fn try_from(value: SarifLog) -> Result<Self, String> {
+            Ok(Self {
+                runs: value.runs?,
+                schema: value.schema?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::SarifLog> for SarifLog {
+        fn from(value: super::SarifLog) -> Self {
+            Self {
+                runs: Ok(value.runs),
+                schema: Ok(value.schema),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct SarifResult {
+        fingerprints: Result<Option<std::collections::HashMap<String, String>>, String>,
+        kind: Result<Option<String>, String>,
+        level: Result<Option<super::Level>, String>,
+        locations: Result<Vec<super::Location>, String>,
+        message: Result<super::Message, String>,
+        partial_fingerprints: Result<
+            Option<std::collections::HashMap<String, String>>,
+            String,
+        >,
+        properties: Result<Option<serde_json::Value>, String>,
+        rule_id: Result<Option<String>, String>,
+        rule_index: Result<Option<i64>, String>,
+    }
+    impl Default for SarifResult {
+        fn default() -> Self {
+            Self {
+                fingerprints: Ok(Default::default()),
+                kind: Ok(Default::default()),
+                level: Ok(Default::default()),
+                locations: Ok(Default::default()),
+                message: Err("no value supplied for message".to_string()),
+                partial_fingerprints: Ok(Default::default()),
+                properties: Ok(Default::default()),
+                rule_id: Ok(Default::default()),
+                rule_index: Ok(Default::default()),
+            }
+        }
+    }
+    impl SarifResult {
+        pub fn fingerprints<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.fingerprints = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for fingerprints: {}", e)
+                });
+            self
+        }
+        pub fn kind<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.kind = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for kind: {}", e));
+            self
+        }
+        pub fn level<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Level>>,
+            T::Error: std::fmt::Display,
+        {
+            self.level = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for level: {}", e)
+                });
+            self
+        }
+        pub fn locations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Location>>,
+            T::Error: std::fmt::Display,
+        {
+            self.locations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for locations: {}", e)
+                });
+            self
+        }
+        pub fn message<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Message>,
+            T::Error: std::fmt::Display,
+        {
+            self.message = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for message: {}", e)
+                });
+            self
+        }
+        pub fn partial_fingerprints<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.partial_fingerprints = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for partial_fingerprints: {}", e
+                    )
+                });
+            self
+        }
+        pub fn properties<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.properties = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for properties: {}", e)
+                });
+            self
+        }
+        pub fn rule_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.rule_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for rule_id: {}", e)
+                });
+            self
+        }
+        pub fn rule_index<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<i64>>,
+            T::Error: std::fmt::Display,
+        {
+            self.rule_index = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for rule_index: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<SarifResult> for super::SarifResult {
+        type Error = String;
+        fn try_from(value: SarifResult) -> Result<Self, String> {
+            Ok(Self {
+                fingerprints: value.fingerprints?,
+                kind: value.kind?,
+                level: value.level?,
+                locations: value.locations?,
+                message: value.message?,
+                partial_fingerprints: value.partial_fingerprints?,
+                properties: value.properties?,
+                rule_id: value.rule_id?,
+                rule_index: value.rule_index?,
+            })
+        }
+    }
+    impl From<super::SarifResult> for SarifResult {
+        fn from(value: super::SarifResult) -> Self {
+            Self {
+                fingerprints: Ok(value.fingerprints),
+                kind: Ok(value.kind),
+                level: Ok(value.level),
+                locations: Ok(value.locations),
+                message: Ok(value.message),
+                partial_fingerprints: Ok(value.partial_fingerprints),
+                properties: Ok(value.properties),
+                rule_id: Ok(value.rule_id),
+                rule_index: Ok(value.rule_index),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Scaiv02Predicate {
+        attributes: Result<Vec<super::Attribute>, String>,
+        producer: Result<Option<super::ResourceDescriptor>, String>,
+    }
+    impl Default for Scaiv02Predicate {
+        fn default() -> Self {
+            Self {
+                attributes: Err("no value supplied for attributes".to_string()),
+                producer: Ok(Default::default()),
+            }
+        }
+    }
+    impl Scaiv02Predicate {
+        pub fn attributes<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Attribute>>,
+            T::Error: std::fmt::Display,
+        {
+            self.attributes = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for attributes: {}", e)
+                });
+            self
+        }
+        pub fn producer<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.producer = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for producer: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Scaiv02Predicate> for super::Scaiv02Predicate {
+        type Error = String;
+        fn try_from(value: Scaiv02Predicate) -> Result<Self, String> {
+            Ok(Self {
+                attributes: value.attributes?,
+                producer: value.producer?,
+            })
+        }
+    }
+    impl From<super::Scaiv02Predicate> for Scaiv02Predicate {
+        fn from(value: super::Scaiv02Predicate) -> Self {
+            Self {
+                attributes: Ok(value.attributes),
+                producer: Ok(value.producer),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Scaiv03Predicate {
+        attributes: Result<Vec<super::AttributeAssertion>, String>,
+        producer: Result<Option<super::ResourceDescriptor>, String>,
+    }
+    impl Default for Scaiv03Predicate {
+        fn default() -> Self {
+            Self {
+                attributes: Err("no value supplied for attributes".to_string()),
+                producer: Ok(Default::default()),
+            }
+        }
+    }
+    impl Scaiv03Predicate {
+        pub fn attributes<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::AttributeAssertion>>,
+            T::Error: std::fmt::Display,
+        {
+            self.attributes = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for attributes: {}", e)
+                });
+            self
+        }
+        pub fn producer<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.producer = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for producer: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Scaiv03Predicate> for super::Scaiv03Predicate {
+        type Error = String;
+        fn try_from(value: Scaiv03Predicate) -> Result<Self, String> {
+            Ok(Self {
+                attributes: value.attributes?,
+                producer: value.producer?,
+            })
+        }
+    }
+    impl From<super::Scaiv03Predicate> for Scaiv03Predicate {
+        fn from(value: super::Scaiv03Predicate) -> Self {
+            Self {
+                attributes: Ok(value.attributes),
+                producer: Ok(value.producer),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Scanner {
+        license_list_version: Result<Option<String>, String>,
+        name: Result<String, String>,
+        uri: Result<Option<String>, String>,
+        version: Result<Option<String>, String>,
+    }
+    impl Default for Scanner {
+        fn default() -> Self {
+            Self {
+                license_list_version: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                uri: Ok(Default::default()),
+                version: Ok(Default::default()),
+            }
+        }
+    }
+    impl Scanner {
+        pub fn license_list_version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_list_version = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_list_version: {}", e
+                    )
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn uri<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.uri = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for uri: {}", e));
+            self
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Scanner> for super::Scanner {
+        type Error = String;
+        fn try_from(value: Scanner) -> Result<Self, String> {
+            Ok(Self {
+                license_list_version: value.license_list_version?,
+                name: value.name?,
+                uri: value.uri?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::Scanner> for Scanner {
+        fn from(value: super::Scanner) -> Self {
+            Self {
+                license_list_version: Ok(value.license_list_version),
+                name: Ok(value.name),
+                uri: Ok(value.uri),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct SlsaProvenanceV02Predicate {
+        build_config: Result<Option<serde_json::Value>, String>,
+        build_type: Result<String, String>,
+        builder: Result<super::Builder2, String>,
+        invocation: Result<Option<super::Invocation>, String>,
+        materials: Result<Option<Vec<super::ResourceDescriptor>>, String>,
+        metadata: Result<Option<super::BuildMetadata2>, String>,
+    }
+    impl Default for SlsaProvenanceV02Predicate {
+        fn default() -> Self {
+            Self {
+                build_config: Ok(Default::default()),
+                build_type: Err("no value supplied for build_type".to_string()),
+                builder: Err("no value supplied for builder".to_string()),
+                invocation: Ok(Default::default()),
+                materials: Ok(Default::default()),
+                metadata: Ok(Default::default()),
+            }
+        }
+    }
+    impl SlsaProvenanceV02Predicate {
+        pub fn build_config<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.build_config = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for build_config: {}", e)
+                });
+            self
+        }
+        pub fn build_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.build_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for build_type: {}", e)
+                });
+            self
+        }
+        pub fn builder<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Builder2>,
+            T::Error: std::fmt::Display,
+        {
+            self.builder = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for builder: {}", e)
+                });
+            self
+        }
+        pub fn invocation<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Invocation>>,
+            T::Error: std::fmt::Display,
+        {
+            self.invocation = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for invocation: {}", e)
+                });
+            self
+        }
+        pub fn materials<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.materials = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for materials: {}", e)
+                });
+            self
+        }
+        pub fn metadata<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::BuildMetadata2>>,
+            T::Error: std::fmt::Display,
+        {
+            self.metadata = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for metadata: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<SlsaProvenanceV02Predicate>
+    for super::SlsaProvenanceV02Predicate {
+        type Error = String;
+        fn try_from(value: SlsaProvenanceV02Predicate) -> Result<Self, String> {
+            Ok(Self {
+                build_config: value.build_config?,
+                build_type: value.build_type?,
+                builder: value.builder?,
+                invocation: value.invocation?,
+                materials: value.materials?,
+                metadata: value.metadata?,
+            })
+        }
+    }
+    impl From<super::SlsaProvenanceV02Predicate> for SlsaProvenanceV02Predicate {
+        fn from(value: super::SlsaProvenanceV02Predicate) -> Self {
+            Self {
+                build_config: Ok(value.build_config),
+                build_type: Ok(value.build_type),
+                builder: Ok(value.builder),
+                invocation: Ok(value.invocation),
+                materials: Ok(value.materials),
+                metadata: Ok(value.metadata),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct SlsaProvenanceV1Predicate {
+        build_definition: Result<super::BuildDefinition, String>,
+        run_details: Result<super::RunDetails, String>,
+    }
+    impl Default for SlsaProvenanceV1Predicate {
+        fn default() -> Self {
+            Self {
+                build_definition: Err(
+                    "no value supplied for build_definition".to_string(),
+                ),
+                run_details: Err("no value supplied for run_details".to_string()),
+            }
+        }
+    }
+    impl SlsaProvenanceV1Predicate {
+        pub fn build_definition<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::BuildDefinition>,
+            T::Error: std::fmt::Display,
+        {
+            self.build_definition = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for build_definition: {}", e
+                    )
+                });
+            self
+        }
+        pub fn run_details<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::RunDetails>,
+            T::Error: std::fmt::Display,
+        {
+            self.run_details = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for run_details: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<SlsaProvenanceV1Predicate>
+    for super::SlsaProvenanceV1Predicate {
+        type Error = String;
+        fn try_from(value: SlsaProvenanceV1Predicate) -> Result<Self, String> {
+            Ok(Self {
+                build_definition: value.build_definition?,
+                run_details: value.run_details?,
+            })
+        }
+    }
+    impl From<super::SlsaProvenanceV1Predicate> for SlsaProvenanceV1Predicate {
+        fn from(value: super::SlsaProvenanceV1Predicate) -> Self {
+            Self {
+                build_definition: Ok(value.build_definition),
+                run_details: Ok(value.run_details),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22Document {
+        annotations: Result<Vec<super::Spdx22DocumentAnnotationsItem>, String>,
+        comment: Result<Option<String>, String>,
+        creation_info: Result<Option<super::Spdx22DocumentCreationInfo>, String>,
+        data_license: Result<Option<String>, String>,
+        describes_packages: Result<Vec<String>, String>,
+        external_document_refs: Result<
+            Vec<super::Spdx22DocumentExternalDocumentRefsItem>,
+            String,
+        >,
+        files: Result<Vec<super::Spdx22DocumentFilesItem>, String>,
+        has_extracted_licensing_infos: Result<
+            Vec<super::Spdx22DocumentHasExtractedLicensingInfosItem>,
+            String,
+        >,
+        name: Result<Option<String>, String>,
+        packages: Result<Vec<super::Spdx22DocumentPackagesItem>, String>,
+        relationships: Result<Vec<super::Spdx22DocumentRelationshipsItem>, String>,
+        revieweds: Result<Vec<super::Spdx22DocumentReviewedsItem>, String>,
+        snippets: Result<Vec<super::Spdx22DocumentSnippetsItem>, String>,
+        spdx_version: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22Document {
+        fn default() -> Self {
+            Self {
+                annotations: Ok(Default::default()),
+                comment: Ok(Default::default()),
+                creation_info: Ok(Default::default()),
+                data_license: Ok(Default::default()),
+                describes_packages: Ok(Default::default()),
+                external_document_refs: Ok(Default::default()),
+                files: Ok(Default::default()),
+                has_extracted_licensing_infos: Ok(Default::default()),
+                name: Ok(Default::default()),
+                packages: Ok(Default::default()),
+                relationships: Ok(Default::default()),
+                revieweds: Ok(Default::default()),
+                snippets: Ok(Default::default()),
+                spdx_version: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22Document {
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentAnnotationsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn creation_info<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<super::Spdx22DocumentCreationInfo>>,
+            T::Error: std::fmt::Display,
+        {
+            self.creation_info = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for creation_info: {}", e)
+                });
+            self
+        }
+        pub fn data_license<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.data_license = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for data_license: {}", e)
+                });
+            self
+        }
+        pub fn describes_packages<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.describes_packages = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for describes_packages: {}", e
+                    )
+                });
+            self
+        }
+        pub fn external_document_refs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentExternalDocumentRefsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.external_document_refs = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for external_document_refs: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentFilesItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.files = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for files: {}", e)
+                });
+            self
+        }
+        pub fn has_extracted_licensing_infos<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Vec<super::Spdx22DocumentHasExtractedLicensingInfosItem>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.has_extracted_licensing_infos = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for has_extracted_licensing_infos: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn packages<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentPackagesItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.packages = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for packages: {}", e)
+                });
+            self
+        }
+        pub fn relationships<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentRelationshipsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.relationships = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for relationships: {}", e)
+                });
+            self
+        }
+        pub fn revieweds<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentReviewedsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.revieweds = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for revieweds: {}", e)
+                });
+            self
+        }
+        pub fn snippets<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentSnippetsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.snippets = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for snippets: {}", e)
+                });
+            self
+        }
+        pub fn spdx_version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdx_version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdx_version: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22Document> for super::Spdx22Document {
+        type Error = String;
+        fn try_from(value: Spdx22Document) -> Result<Self, String> {
+            Ok(Self {
+                annotations: value.annotations?,
+                comment: value.comment?,
+                creation_info: value.creation_info?,
+                data_license: value.data_license?,
+                describes_packages: value.describes_packages?,
+                external_document_refs: value.external_document_refs?,
+                files: value.files?,
+                has_extracted_licensing_infos: value.has_extracted_licensing_infos?,
+                name: value.name?,
+                packages: value.packages?,
+                relationships: value.relationships?,
+                revieweds: value.revieweds?,
+                snippets: value.snippets?,
+                spdx_version: value.spdx_version?,
+            })
+        }
+    }
+    impl From<super::Spdx22Document> for Spdx22Document {
+        fn from(value: super::Spdx22Document) -> Self {
+            Self {
+                annotations: Ok(value.annotations),
+                comment: Ok(value.comment),
+                creation_info: Ok(value.creation_info),
+                data_license: Ok(value.data_license),
+                describes_packages: Ok(value.describes_packages),
+                external_document_refs: Ok(value.external_document_refs),
+                files: Ok(value.files),
+                has_extracted_licensing_infos: Ok(value.has_extracted_licensing_infos),
+                name: Ok(value.name),
+                packages: Ok(value.packages),
+                relationships: Ok(value.relationships),
+                revieweds: Ok(value.revieweds),
+                snippets: Ok(value.snippets),
+                spdx_version: Ok(value.spdx_version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentAnnotationsItem {
+        annotation_date: Result<Option<String>, String>,
+        annotation_type: Result<
+            Option<super::Spdx22DocumentAnnotationsItemAnnotationType>,
+            String,
+        >,
+        annotator: Result<Option<String>, String>,
+        comment: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentAnnotationsItem {
+        fn default() -> Self {
+            Self {
+                annotation_date: Ok(Default::default()),
+                annotation_type: Ok(Default::default()),
+                annotator: Ok(Default::default()),
+                comment: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentAnnotationsItem {
+        pub fn annotation_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_date: {}", e)
+                });
+            self
+        }
+        pub fn annotation_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentAnnotationsItemAnnotationType>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_type: {}", e)
+                });
+            self
+        }
+        pub fn annotator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotator: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentAnnotationsItem>
+    for super::Spdx22DocumentAnnotationsItem {
+        type Error = String;
+        fn try_from(value: Spdx22DocumentAnnotationsItem) -> Result<Self, String> {
+            Ok(Self {
+                annotation_date: value.annotation_date?,
+                annotation_type: value.annotation_type?,
+                annotator: value.annotator?,
+                comment: value.comment?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentAnnotationsItem> for Spdx22DocumentAnnotationsItem {
+        fn from(value: super::Spdx22DocumentAnnotationsItem) -> Self {
+            Self {
+                annotation_date: Ok(value.annotation_date),
+                annotation_type: Ok(value.annotation_type),
+                annotator: Ok(value.annotator),
+                comment: Ok(value.comment),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentCreationInfo {
+        comment: Result<Option<String>, String>,
+        created: Result<Option<String>, String>,
+        creators: Result<Vec<String>, String>,
+        license_list_version: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentCreationInfo {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                created: Ok(Default::default()),
+                creators: Ok(Default::default()),
+                license_list_version: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentCreationInfo {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn created<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.created = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for created: {}", e)
+                });
+            self
+        }
+        pub fn creators<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.creators = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for creators: {}", e)
+                });
+            self
+        }
+        pub fn license_list_version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_list_version = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_list_version: {}", e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentCreationInfo>
+    for super::Spdx22DocumentCreationInfo {
+        type Error = String;
+        fn try_from(value: Spdx22DocumentCreationInfo) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                created: value.created?,
+                creators: value.creators?,
+                license_list_version: value.license_list_version?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentCreationInfo> for Spdx22DocumentCreationInfo {
+        fn from(value: super::Spdx22DocumentCreationInfo) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                created: Ok(value.created),
+                creators: Ok(value.creators),
+                license_list_version: Ok(value.license_list_version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentExternalDocumentRefsItem {
+        checksum: Result<
+            Option<super::Spdx22DocumentExternalDocumentRefsItemChecksum>,
+            String,
+        >,
+        external_document_id: Result<Option<String>, String>,
+        spdx_document: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentExternalDocumentRefsItem {
+        fn default() -> Self {
+            Self {
+                checksum: Ok(Default::default()),
+                external_document_id: Ok(Default::default()),
+                spdx_document: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentExternalDocumentRefsItem {
+        pub fn checksum<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentExternalDocumentRefsItemChecksum>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum: {}", e)
+                });
+            self
+        }
+        pub fn external_document_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.external_document_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for external_document_id: {}", e
+                    )
+                });
+            self
+        }
+        pub fn spdx_document<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdx_document = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdx_document: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentExternalDocumentRefsItem>
+    for super::Spdx22DocumentExternalDocumentRefsItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentExternalDocumentRefsItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                checksum: value.checksum?,
+                external_document_id: value.external_document_id?,
+                spdx_document: value.spdx_document?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentExternalDocumentRefsItem>
+    for Spdx22DocumentExternalDocumentRefsItem {
+        fn from(value: super::Spdx22DocumentExternalDocumentRefsItem) -> Self {
+            Self {
+                checksum: Ok(value.checksum),
+                external_document_id: Ok(value.external_document_id),
+                spdx_document: Ok(value.spdx_document),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentExternalDocumentRefsItemChecksum {
+        algorithm: Result<
+            Option<super::Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm>,
+            String,
+        >,
+        checksum_value: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentExternalDocumentRefsItemChecksum {
+        fn default() -> Self {
+            Self {
+                algorithm: Ok(Default::default()),
+                checksum_value: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentExternalDocumentRefsItemChecksum {
+        pub fn algorithm<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentExternalDocumentRefsItemChecksumAlgorithm>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.algorithm = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for algorithm: {}", e)
+                });
+            self
+        }
+        pub fn checksum_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum_value: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentExternalDocumentRefsItemChecksum>
+    for super::Spdx22DocumentExternalDocumentRefsItemChecksum {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentExternalDocumentRefsItemChecksum,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                algorithm: value.algorithm?,
+                checksum_value: value.checksum_value?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentExternalDocumentRefsItemChecksum>
+    for Spdx22DocumentExternalDocumentRefsItemChecksum {
+        fn from(value: super::Spdx22DocumentExternalDocumentRefsItemChecksum) -> Self {
+            Self {
+                algorithm: Ok(value.algorithm),
+                checksum_value: Ok(value.checksum_value),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentFilesItem {
+        annotations: Result<Vec<super::Spdx22DocumentFilesItemAnnotationsItem>, String>,
+        artifact_ofs: Result<Vec<serde_json::Map<String, serde_json::Value>>, String>,
+        attribution_texts: Result<Vec<String>, String>,
+        checksums: Result<Vec<super::Spdx22DocumentFilesItemChecksumsItem>, String>,
+        comment: Result<Option<String>, String>,
+        copyright_text: Result<Option<String>, String>,
+        file_contributors: Result<Vec<String>, String>,
+        file_dependencies: Result<Vec<String>, String>,
+        file_name: Result<Option<String>, String>,
+        file_types: Result<Vec<super::Spdx22DocumentFilesItemFileTypesItem>, String>,
+        license_comments: Result<Option<String>, String>,
+        license_info_from_files: Result<Vec<String>, String>,
+        license_info_in_files: Result<Vec<String>, String>,
+        name: Result<Option<String>, String>,
+        notice_text: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentFilesItem {
+        fn default() -> Self {
+            Self {
+                annotations: Ok(Default::default()),
+                artifact_ofs: Ok(Default::default()),
+                attribution_texts: Ok(Default::default()),
+                checksums: Ok(Default::default()),
+                comment: Ok(Default::default()),
+                copyright_text: Ok(Default::default()),
+                file_contributors: Ok(Default::default()),
+                file_dependencies: Ok(Default::default()),
+                file_name: Ok(Default::default()),
+                file_types: Ok(Default::default()),
+                license_comments: Ok(Default::default()),
+                license_info_from_files: Ok(Default::default()),
+                license_info_in_files: Ok(Default::default()),
+                name: Ok(Default::default()),
+                notice_text: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentFilesItem {
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentFilesItemAnnotationsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn artifact_ofs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<serde_json::Map<String, serde_json::Value>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.artifact_ofs = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for artifact_ofs: {}", e)
+                });
+            self
+        }
+        pub fn attribution_texts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.attribution_texts = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for attribution_texts: {}", e
+                    )
+                });
+            self
+        }
+        pub fn checksums<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentFilesItemChecksumsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksums = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksums: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn copyright_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.copyright_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for copyright_text: {}", e)
+                });
+            self
+        }
+        pub fn file_contributors<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.file_contributors = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for file_contributors: {}", e
+                    )
+                });
+            self
+        }
+        pub fn file_dependencies<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.file_dependencies = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for file_dependencies: {}", e
+                    )
+                });
+            self
+        }
+        pub fn file_name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.file_name = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for file_name: {}", e)
+                });
+            self
+        }
+        pub fn file_types<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentFilesItemFileTypesItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.file_types = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for file_types: {}", e)
+                });
+            self
+        }
+        pub fn license_comments<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_comments = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_comments: {}", e
+                    )
+                });
+            self
+        }
+        pub fn license_info_from_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_info_from_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_info_from_files: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn license_info_in_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_info_in_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_info_in_files: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn notice_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.notice_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for notice_text: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentFilesItem>
+    for super::Spdx22DocumentFilesItem {
+        type Error = String;
+        fn try_from(value: Spdx22DocumentFilesItem) -> Result<Self, String> {
+            Ok(Self {
+                annotations: value.annotations?,
+                artifact_ofs: value.artifact_ofs?,
+                attribution_texts: value.attribution_texts?,
+                checksums: value.checksums?,
+                comment: value.comment?,
+                copyright_text: value.copyright_text?,
+                file_contributors: value.file_contributors?,
+                file_dependencies: value.file_dependencies?,
+                file_name: value.file_name?,
+                file_types: value.file_types?,
+                license_comments: value.license_comments?,
+                license_info_from_files: value.license_info_from_files?,
+                license_info_in_files: value.license_info_in_files?,
+                name: value.name?,
+                notice_text: value.notice_text?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentFilesItem> for Spdx22DocumentFilesItem {
+        fn from(value: super::Spdx22DocumentFilesItem) -> Self {
+            Self {
+                annotations: Ok(value.annotations),
+                artifact_ofs: Ok(value.artifact_ofs),
+                attribution_texts: Ok(value.attribution_texts),
+                checksums: Ok(value.checksums),
+                comment: Ok(value.comment),
+                copyright_text: Ok(value.copyright_text),
+                file_contributors: Ok(value.file_contributors),
+                file_dependencies: Ok(value.file_dependencies),
+                file_name: Ok(value.file_name),
+                file_types: Ok(value.file_types),
+                license_comments: Ok(value.license_comments),
+                license_info_from_files: Ok(value.license_info_from_files),
+                license_info_in_files: Ok(value.license_info_in_files),
+                name: Ok(value.name),
+                notice_text: Ok(value.notice_text),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentFilesItemAnnotationsItem {
+        annotation_date: Result<Option<String>, String>,
+        annotation_type: Result<
+            Option<super::Spdx22DocumentFilesItemAnnotationsItemAnnotationType>,
+            String,
+        >,
+        annotator: Result<Option<String>, String>,
+        comment: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentFilesItemAnnotationsItem {
+        fn default() -> Self {
+            Self {
+                annotation_date: Ok(Default::default()),
+                annotation_type: Ok(Default::default()),
+                annotator: Ok(Default::default()),
+                comment: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentFilesItemAnnotationsItem {
+        pub fn annotation_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_date: {}", e)
+                });
+            self
+        }
+        pub fn annotation_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentFilesItemAnnotationsItemAnnotationType>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_type: {}", e)
+                });
+            self
+        }
+        pub fn annotator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotator: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentFilesItemAnnotationsItem>
+    for super::Spdx22DocumentFilesItemAnnotationsItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentFilesItemAnnotationsItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                annotation_date: value.annotation_date?,
+                annotation_type: value.annotation_type?,
+                annotator: value.annotator?,
+                comment: value.comment?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentFilesItemAnnotationsItem>
+    for Spdx22DocumentFilesItemAnnotationsItem {
+        fn from(value: super::Spdx22DocumentFilesItemAnnotationsItem) -> Self {
+            Self {
+                annotation_date: Ok(value.annotation_date),
+                annotation_type: Ok(value.annotation_type),
+                annotator: Ok(value.annotator),
+                comment: Ok(value.comment),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentFilesItemChecksumsItem {
+        algorithm: Result<
+            Option<super::Spdx22DocumentFilesItemChecksumsItemAlgorithm>,
+            String,
+        >,
+        checksum_value: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentFilesItemChecksumsItem {
+        fn default() -> Self {
+            Self {
+                algorithm: Ok(Default::default()),
+                checksum_value: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentFilesItemChecksumsItem {
+        pub fn algorithm<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentFilesItemChecksumsItemAlgorithm>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.algorithm = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for algorithm: {}", e)
+                });
+            self
+        }
+        pub fn checksum_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum_value: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentFilesItemChecksumsItem>
+    for super::Spdx22DocumentFilesItemChecksumsItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentFilesItemChecksumsItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                algorithm: value.algorithm?,
+                checksum_value: value.checksum_value?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentFilesItemChecksumsItem>
+    for Spdx22DocumentFilesItemChecksumsItem {
+        fn from(value: super::Spdx22DocumentFilesItemChecksumsItem) -> Self {
+            Self {
+                algorithm: Ok(value.algorithm),
+                checksum_value: Ok(value.checksum_value),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentHasExtractedLicensingInfosItem {
+        comment: Result<Option<String>, String>,
+        extracted_text: Result<Option<String>, String>,
+        license_id: Result<Option<String>, String>,
+        name: Result<Option<String>, String>,
+        see_alsos: Result<Vec<String>, String>,
+    }
+    impl Default for Spdx22DocumentHasExtractedLicensingInfosItem {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                extracted_text: Ok(Default::default()),
+                license_id: Ok(Default::default()),
+                name: Ok(Default::default()),
+                see_alsos: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentHasExtractedLicensingInfosItem {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn extracted_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.extracted_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for extracted_text: {}", e)
+                });
+            self
+        }
+        pub fn license_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for license_id: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn see_alsos<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.see_alsos = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for see_alsos: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentHasExtractedLicensingInfosItem>
+    for super::Spdx22DocumentHasExtractedLicensingInfosItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentHasExtractedLicensingInfosItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                extracted_text: value.extracted_text?,
+                license_id: value.license_id?,
+                name: value.name?,
+                see_alsos: value.see_alsos?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentHasExtractedLicensingInfosItem>
+    for Spdx22DocumentHasExtractedLicensingInfosItem {
+        fn from(value: super::Spdx22DocumentHasExtractedLicensingInfosItem) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                extracted_text: Ok(value.extracted_text),
+                license_id: Ok(value.license_id),
+                name: Ok(value.name),
+                see_alsos: Ok(value.see_alsos),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentPackagesItem {
+        annotations: Result<
+            Vec<super::Spdx22DocumentPackagesItemAnnotationsItem>,
+            String,
+        >,
+        attribution_texts: Result<Vec<String>, String>,
+        checksums: Result<Vec<super::Spdx22DocumentPackagesItemChecksumsItem>, String>,
+        comment: Result<Option<String>, String>,
+        copyright_text: Result<Option<String>, String>,
+        description: Result<Option<String>, String>,
+        download_location: Result<Option<String>, String>,
+        external_refs: Result<
+            Vec<super::Spdx22DocumentPackagesItemExternalRefsItem>,
+            String,
+        >,
+        files_analyzed: Result<Option<bool>, String>,
+        has_files: Result<Vec<String>, String>,
+        homepage: Result<Option<String>, String>,
+        license_comments: Result<Option<String>, String>,
+        license_info_from_files: Result<Vec<String>, String>,
+        name: Result<Option<String>, String>,
+        originator: Result<Option<String>, String>,
+        package_file_name: Result<Option<String>, String>,
+        package_verification_code: Result<
+            Option<super::Spdx22DocumentPackagesItemPackageVerificationCode>,
+            String,
+        >,
+        source_info: Result<Option<String>, String>,
+        summary: Result<Option<String>, String>,
+        supplier: Result<Option<String>, String>,
+        version_info: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentPackagesItem {
+        fn default() -> Self {
+            Self {
+                annotations: Ok(Default::default()),
+                attribution_texts: Ok(Default::default()),
+                checksums: Ok(Default::default()),
+                comment: Ok(Default::default()),
+                copyright_text: Ok(Default::default()),
+                description: Ok(Default::default()),
+                download_location: Ok(Default::default()),
+                external_refs: Ok(Default::default()),
+                files_analyzed: Ok(Default::default()),
+                has_files: Ok(Default::default()),
+                homepage: Ok(Default::default()),
+                license_comments: Ok(Default::default()),
+                license_info_from_files: Ok(Default::default()),
+                name: Ok(Default::default()),
+                originator: Ok(Default::default()),
+                package_file_name: Ok(Default::default()),
+                package_verification_code: Ok(Default::default()),
+                source_info: Ok(Default::default()),
+                summary: Ok(Default::default()),
+                supplier: Ok(Default::default()),
+                version_info: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentPackagesItem {
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Vec<super::Spdx22DocumentPackagesItemAnnotationsItem>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn attribution_texts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.attribution_texts = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for attribution_texts: {}", e
+                    )
+                });
+            self
+        }
+        pub fn checksums<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Vec<super::Spdx22DocumentPackagesItemChecksumsItem>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.checksums = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksums: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn copyright_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.copyright_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for copyright_text: {}", e)
+                });
+            self
+        }
+        pub fn description<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.description = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for description: {}", e)
+                });
+            self
+        }
+        pub fn download_location<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.download_location = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for download_location: {}", e
+                    )
+                });
+            self
+        }
+        pub fn external_refs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Vec<super::Spdx22DocumentPackagesItemExternalRefsItem>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.external_refs = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for external_refs: {}", e)
+                });
+            self
+        }
+        pub fn files_analyzed<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.files_analyzed = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for files_analyzed: {}", e)
+                });
+            self
+        }
+        pub fn has_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.has_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for has_files: {}", e)
+                });
+            self
+        }
+        pub fn homepage<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.homepage = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for homepage: {}", e)
+                });
+            self
+        }
+        pub fn license_comments<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_comments = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_comments: {}", e
+                    )
+                });
+            self
+        }
+        pub fn license_info_from_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_info_from_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_info_from_files: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn originator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.originator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for originator: {}", e)
+                });
+            self
+        }
+        pub fn package_file_name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.package_file_name = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for package_file_name: {}", e
+                    )
+                });
+            self
+        }
+        pub fn package_verification_code<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentPackagesItemPackageVerificationCode>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.package_verification_code = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for package_verification_code: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn source_info<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.source_info = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for source_info: {}", e)
+                });
+            self
+        }
+        pub fn summary<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.summary = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for summary: {}", e)
+                });
+            self
+        }
+        pub fn supplier<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.supplier = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for supplier: {}", e)
+                });
+            self
+        }
+        pub fn version_info<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.version_info = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version_info: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentPackagesItem>
+    for super::Spdx22DocumentPackagesItem {
+        type Error = String;
+        fn try_from(value: Spdx22DocumentPackagesItem) -> Result<Self, String> {
+            Ok(Self {
+                annotations: value.annotations?,
+                attribution_texts: value.attribution_texts?,
+                checksums: value.checksums?,
+                comment: value.comment?,
+                copyright_text: value.copyright_text?,
+                description: value.description?,
+                download_location: value.download_location?,
+                external_refs: value.external_refs?,
+                files_analyzed: value.files_analyzed?,
+                has_files: value.has_files?,
+                homepage: value.homepage?,
+                license_comments: value.license_comments?,
+                license_info_from_files: value.license_info_from_files?,
+                name: value.name?,
+                originator: value.originator?,
+                package_file_name: value.package_file_name?,
+                package_verification_code: value.package_verification_code?,
+                source_info: value.source_info?,
+                summary: value.summary?,
+                supplier: value.supplier?,
+                version_info: value.version_info?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentPackagesItem> for Spdx22DocumentPackagesItem {
+        fn from(value: super::Spdx22DocumentPackagesItem) -> Self {
+            Self {
+                annotations: Ok(value.annotations),
+                attribution_texts: Ok(value.attribution_texts),
+                checksums: Ok(value.checksums),
+                comment: Ok(value.comment),
+                copyright_text: Ok(value.copyright_text),
+                description: Ok(value.description),
+                download_location: Ok(value.download_location),
+                external_refs: Ok(value.external_refs),
+                files_analyzed: Ok(value.files_analyzed),
+                has_files: Ok(value.has_files),
+                homepage: Ok(value.homepage),
+                license_comments: Ok(value.license_comments),
+                license_info_from_files: Ok(value.license_info_from_files),
+                name: Ok(value.name),
+                originator: Ok(value.originator),
+                package_file_name: Ok(value.package_file_name),
+                package_verification_code: Ok(value.package_verification_code),
+                source_info: Ok(value.source_info),
+                summary: Ok(value.summary),
+                supplier: Ok(value.supplier),
+                version_info: Ok(value.version_info),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentPackagesItemAnnotationsItem {
+        annotation_date: Result<Option<String>, String>,
+        annotation_type: Result<
+            Option<super::Spdx22DocumentPackagesItemAnnotationsItemAnnotationType>,
+            String,
+        >,
+        annotator: Result<Option<String>, String>,
+        comment: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentPackagesItemAnnotationsItem {
+        fn default() -> Self {
+            Self {
+                annotation_date: Ok(Default::default()),
+                annotation_type: Ok(Default::default()),
+                annotator: Ok(Default::default()),
+                comment: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentPackagesItemAnnotationsItem {
+        pub fn annotation_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_date: {}", e)
+                });
+            self
+        }
+        pub fn annotation_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentPackagesItemAnnotationsItemAnnotationType>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_type: {}", e)
+                });
+            self
+        }
+        pub fn annotator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotator: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentPackagesItemAnnotationsItem>
+    for super::Spdx22DocumentPackagesItemAnnotationsItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentPackagesItemAnnotationsItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                annotation_date: value.annotation_date?,
+                annotation_type: value.annotation_type?,
+                annotator: value.annotator?,
+                comment: value.comment?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentPackagesItemAnnotationsItem>
+    for Spdx22DocumentPackagesItemAnnotationsItem {
+        fn from(value: super::Spdx22DocumentPackagesItemAnnotationsItem) -> Self {
+            Self {
+                annotation_date: Ok(value.annotation_date),
+                annotation_type: Ok(value.annotation_type),
+                annotator: Ok(value.annotator),
+                comment: Ok(value.comment),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentPackagesItemChecksumsItem {
+        algorithm: Result<
+            Option<super::Spdx22DocumentPackagesItemChecksumsItemAlgorithm>,
+            String,
+        >,
+        checksum_value: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentPackagesItemChecksumsItem {
+        fn default() -> Self {
+            Self {
+                algorithm: Ok(Default::default()),
+                checksum_value: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentPackagesItemChecksumsItem {
+        pub fn algorithm<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentPackagesItemChecksumsItemAlgorithm>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.algorithm = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for algorithm: {}", e)
+                });
+            self
+        }
+        pub fn checksum_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum_value: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentPackagesItemChecksumsItem>
+    for super::Spdx22DocumentPackagesItemChecksumsItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentPackagesItemChecksumsItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                algorithm: value.algorithm?,
+                checksum_value: value.checksum_value?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentPackagesItemChecksumsItem>
+    for Spdx22DocumentPackagesItemChecksumsItem {
+        fn from(value: super::Spdx22DocumentPackagesItemChecksumsItem) -> Self {
+            Self {
+                algorithm: Ok(value.algorithm),
+                checksum_value: Ok(value.checksum_value),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentPackagesItemExternalRefsItem {
+        comment: Result<Option<String>, String>,
+        reference_category: Result<
+            Option<super::Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory>,
+            String,
+        >,
+        reference_locator: Result<Option<String>, String>,
+        reference_type: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentPackagesItemExternalRefsItem {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                reference_category: Ok(Default::default()),
+                reference_locator: Ok(Default::default()),
+                reference_type: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentPackagesItemExternalRefsItem {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn reference_category<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<
+                    super::Spdx22DocumentPackagesItemExternalRefsItemReferenceCategory,
+                >,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.reference_category = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for reference_category: {}", e
+                    )
+                });
+            self
+        }
+        pub fn reference_locator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.reference_locator = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for reference_locator: {}", e
+                    )
+                });
+            self
+        }
+        pub fn reference_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.reference_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for reference_type: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentPackagesItemExternalRefsItem>
+    for super::Spdx22DocumentPackagesItemExternalRefsItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentPackagesItemExternalRefsItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                reference_category: value.reference_category?,
+                reference_locator: value.reference_locator?,
+                reference_type: value.reference_type?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentPackagesItemExternalRefsItem>
+    for Spdx22DocumentPackagesItemExternalRefsItem {
+        fn from(value: super::Spdx22DocumentPackagesItemExternalRefsItem) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                reference_category: Ok(value.reference_category),
+                reference_locator: Ok(value.reference_locator),
+                reference_type: Ok(value.reference_type),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentPackagesItemPackageVerificationCode {
+        package_verification_code_excluded_files: Result<Vec<String>, String>,
+        package_verification_code_value: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentPackagesItemPackageVerificationCode {
+        fn default() -> Self {
+            Self {
+                package_verification_code_excluded_files: Ok(Default::default()),
+                package_verification_code_value: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentPackagesItemPackageVerificationCode {
+        pub fn package_verification_code_excluded_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.package_verification_code_excluded_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for package_verification_code_excluded_files: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn package_verification_code_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.package_verification_code_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for package_verification_code_value: {}",
+                        e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentPackagesItemPackageVerificationCode>
+    for super::Spdx22DocumentPackagesItemPackageVerificationCode {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentPackagesItemPackageVerificationCode,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                package_verification_code_excluded_files: value
+                    .package_verification_code_excluded_files?,
+                package_verification_code_value: value.package_verification_code_value?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentPackagesItemPackageVerificationCode>
+    for Spdx22DocumentPackagesItemPackageVerificationCode {
+        fn from(
+            value: super::Spdx22DocumentPackagesItemPackageVerificationCode,
+        ) -> Self {
+            Self {
+                package_verification_code_excluded_files: Ok(
+                    value.package_verification_code_excluded_files,
+                ),
+                package_verification_code_value: Ok(
+                    value.package_verification_code_value,
+                ),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentRelationshipsItem {
+        comment: Result<Option<String>, String>,
+        related_spdx_element: Result<Option<String>, String>,
+        relationship_type: Result<
+            Option<super::Spdx22DocumentRelationshipsItemRelationshipType>,
+            String,
+        >,
+    }
+    impl Default for Spdx22DocumentRelationshipsItem {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                related_spdx_element: Ok(Default::default()),
+                relationship_type: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentRelationshipsItem {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn related_spdx_element<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.related_spdx_element = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for related_spdx_element: {}", e
+                    )
+                });
+            self
+        }
+        pub fn relationship_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentRelationshipsItemRelationshipType>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.relationship_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for relationship_type: {}", e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentRelationshipsItem>
+    for super::Spdx22DocumentRelationshipsItem {
+        type Error = String;
+        fn try_from(value: Spdx22DocumentRelationshipsItem) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                related_spdx_element: value.related_spdx_element?,
+                relationship_type: value.relationship_type?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentRelationshipsItem>
+    for Spdx22DocumentRelationshipsItem {
+        fn from(value: super::Spdx22DocumentRelationshipsItem) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                related_spdx_element: Ok(value.related_spdx_element),
+                relationship_type: Ok(value.relationship_type),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentReviewedsItem {
+        comment: Result<Option<String>, String>,
+        review_date: Result<Option<String>, String>,
+        reviewer: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentReviewedsItem {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                review_date: Ok(Default::default()),
+                reviewer: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentReviewedsItem {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn review_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.review_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for review_date: {}", e)
+                });
+            self
+        }
+        pub fn reviewer<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.reviewer = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for reviewer: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentReviewedsItem>
+    for super::Spdx22DocumentReviewedsItem {
+        type Error = String;
+        fn try_from(value: Spdx22DocumentReviewedsItem) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                review_date: value.review_date?,
+                reviewer: value.reviewer?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentReviewedsItem> for Spdx22DocumentReviewedsItem {
+        fn from(value: super::Spdx22DocumentReviewedsItem) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                review_date: Ok(value.review_date),
+                reviewer: Ok(value.reviewer),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentSnippetsItem {
+        annotations: Result<
+            Vec<super::Spdx22DocumentSnippetsItemAnnotationsItem>,
+            String,
+        >,
+        attribution_texts: Result<Vec<String>, String>,
+        comment: Result<Option<String>, String>,
+        copyright_text: Result<Option<String>, String>,
+        license_comments: Result<Option<String>, String>,
+        license_info_from_files: Result<Vec<String>, String>,
+        license_info_in_snippets: Result<Vec<String>, String>,
+        name: Result<Option<String>, String>,
+        ranges: Result<Vec<super::Spdx22DocumentSnippetsItemRangesItem>, String>,
+        snippet_from_file: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentSnippetsItem {
+        fn default() -> Self {
+            Self {
+                annotations: Ok(Default::default()),
+                attribution_texts: Ok(Default::default()),
+                comment: Ok(Default::default()),
+                copyright_text: Ok(Default::default()),
+                license_comments: Ok(Default::default()),
+                license_info_from_files: Ok(Default::default()),
+                license_info_in_snippets: Ok(Default::default()),
+                name: Ok(Default::default()),
+                ranges: Ok(Default::default()),
+                snippet_from_file: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentSnippetsItem {
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Vec<super::Spdx22DocumentSnippetsItemAnnotationsItem>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn attribution_texts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.attribution_texts = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for attribution_texts: {}", e
+                    )
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn copyright_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.copyright_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for copyright_text: {}", e)
+                });
+            self
+        }
+        pub fn license_comments<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_comments = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_comments: {}", e
+                    )
+                });
+            self
+        }
+        pub fn license_info_from_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_info_from_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_info_from_files: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn license_info_in_snippets<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_info_in_snippets = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_info_in_snippets: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn ranges<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx22DocumentSnippetsItemRangesItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.ranges = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for ranges: {}", e)
+                });
+            self
+        }
+        pub fn snippet_from_file<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.snippet_from_file = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for snippet_from_file: {}", e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentSnippetsItem>
+    for super::Spdx22DocumentSnippetsItem {
+        type Error = String;
+        fn try_from(value: Spdx22DocumentSnippetsItem) -> Result<Self, String> {
+            Ok(Self {
+                annotations: value.annotations?,
+                attribution_texts: value.attribution_texts?,
+                comment: value.comment?,
+                copyright_text: value.copyright_text?,
+                license_comments: value.license_comments?,
+                license_info_from_files: value.license_info_from_files?,
+                license_info_in_snippets: value.license_info_in_snippets?,
+                name: value.name?,
+                ranges: value.ranges?,
+                snippet_from_file: value.snippet_from_file?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentSnippetsItem> for Spdx22DocumentSnippetsItem {
+        fn from(value: super::Spdx22DocumentSnippetsItem) -> Self {
+            Self {
+                annotations: Ok(value.annotations),
+                attribution_texts: Ok(value.attribution_texts),
+                comment: Ok(value.comment),
+                copyright_text: Ok(value.copyright_text),
+                license_comments: Ok(value.license_comments),
+                license_info_from_files: Ok(value.license_info_from_files),
+                license_info_in_snippets: Ok(value.license_info_in_snippets),
+                name: Ok(value.name),
+                ranges: Ok(value.ranges),
+                snippet_from_file: Ok(value.snippet_from_file),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentSnippetsItemAnnotationsItem {
+        annotation_date: Result<Option<String>, String>,
+        annotation_type: Result<
+            Option<super::Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType>,
+            String,
+        >,
+        annotator: Result<Option<String>, String>,
+        comment: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentSnippetsItemAnnotationsItem {
+        fn default() -> Self {
+            Self {
+                annotation_date: Ok(Default::default()),
+                annotation_type: Ok(Default::default()),
+                annotator: Ok(Default::default()),
+                comment: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentSnippetsItemAnnotationsItem {
+        pub fn annotation_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_date: {}", e)
+                });
+            self
+        }
+        pub fn annotation_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentSnippetsItemAnnotationsItemAnnotationType>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_type: {}", e)
+                });
+            self
+        }
+        pub fn annotator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotator: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentSnippetsItemAnnotationsItem>
+    for super::Spdx22DocumentSnippetsItemAnnotationsItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentSnippetsItemAnnotationsItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                annotation_date: value.annotation_date?,
+                annotation_type: value.annotation_type?,
+                annotator: value.annotator?,
+                comment: value.comment?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentSnippetsItemAnnotationsItem>
+    for Spdx22DocumentSnippetsItemAnnotationsItem {
+        fn from(value: super::Spdx22DocumentSnippetsItemAnnotationsItem) -> Self {
+            Self {
+                annotation_date: Ok(value.annotation_date),
+                annotation_type: Ok(value.annotation_type),
+                annotator: Ok(value.annotator),
+                comment: Ok(value.comment),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentSnippetsItemRangesItem {
+        end_pointer: Result<
+            Option<super::Spdx22DocumentSnippetsItemRangesItemEndPointer>,
+            String,
+        >,
+        start_pointer: Result<
+            Option<super::Spdx22DocumentSnippetsItemRangesItemStartPointer>,
+            String,
+        >,
+    }
+    impl Default for Spdx22DocumentSnippetsItemRangesItem {
+        fn default() -> Self {
+            Self {
+                end_pointer: Ok(Default::default()),
+                start_pointer: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentSnippetsItemRangesItem {
+        pub fn end_pointer<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentSnippetsItemRangesItemEndPointer>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.end_pointer = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for end_pointer: {}", e)
+                });
+            self
+        }
+        pub fn start_pointer<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx22DocumentSnippetsItemRangesItemStartPointer>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.start_pointer = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for start_pointer: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentSnippetsItemRangesItem>
+    for super::Spdx22DocumentSnippetsItemRangesItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentSnippetsItemRangesItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                end_pointer: value.end_pointer?,
+                start_pointer: value.start_pointer?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentSnippetsItemRangesItem>
+    for Spdx22DocumentSnippetsItemRangesItem {
+        fn from(value: super::Spdx22DocumentSnippetsItemRangesItem) -> Self {
+            Self {
+                end_pointer: Ok(value.end_pointer),
+                start_pointer: Ok(value.start_pointer),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentSnippetsItemRangesItemEndPointer {
+        reference: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentSnippetsItemRangesItemEndPointer {
+        fn default() -> Self {
+            Self {
+                reference: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentSnippetsItemRangesItemEndPointer {
+        pub fn reference<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.reference = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for reference: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentSnippetsItemRangesItemEndPointer>
+    for super::Spdx22DocumentSnippetsItemRangesItemEndPointer {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentSnippetsItemRangesItemEndPointer,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                reference: value.reference?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentSnippetsItemRangesItemEndPointer>
+    for Spdx22DocumentSnippetsItemRangesItemEndPointer {
+        fn from(value: super::Spdx22DocumentSnippetsItemRangesItemEndPointer) -> Self {
+            Self {
+                reference: Ok(value.reference),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx22DocumentSnippetsItemRangesItemStartPointer {
+        reference: Result<Option<String>, String>,
+    }
+    impl Default for Spdx22DocumentSnippetsItemRangesItemStartPointer {
+        fn default() -> Self {
+            Self {
+                reference: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx22DocumentSnippetsItemRangesItemStartPointer {
+        pub fn reference<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.reference = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for reference: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx22DocumentSnippetsItemRangesItemStartPointer>
+    for super::Spdx22DocumentSnippetsItemRangesItemStartPointer {
+        type Error = String;
+        fn try_from(
+            value: Spdx22DocumentSnippetsItemRangesItemStartPointer,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                reference: value.reference?,
+            })
+        }
+    }
+    impl From<super::Spdx22DocumentSnippetsItemRangesItemStartPointer>
+    for Spdx22DocumentSnippetsItemRangesItemStartPointer {
+        fn from(value: super::Spdx22DocumentSnippetsItemRangesItemStartPointer) -> Self {
+            Self {
+                reference: Ok(value.reference),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23 {
+        annotations: Result<Vec<super::Spdx23AnnotationsItem>, String>,
+        comment: Result<Option<String>, String>,
+        creation_info: Result<super::Spdx23CreationInfo, String>,
+        data_license: Result<String, String>,
+        document_describes: Result<Vec<String>, String>,
+        document_namespace: Result<String, String>,
+        external_document_refs: Result<
+            Vec<super::Spdx23ExternalDocumentRefsItem>,
+            String,
+        >,
+        files: Result<Vec<super::Spdx23FilesItem>, String>,
+        has_extracted_licensing_infos: Result<
+            Vec<super::Spdx23HasExtractedLicensingInfosItem>,
+            String,
+        >,
+        name: Result<String, String>,
+        packages: Result<Vec<super::Spdx23PackagesItem>, String>,
+        relationships: Result<Vec<super::Spdx23RelationshipsItem>, String>,
+        revieweds: Result<Vec<super::Spdx23ReviewedsItem>, String>,
+        snippets: Result<Vec<super::Spdx23SnippetsItem>, String>,
+        spdx_version: Result<String, String>,
+        spdxid: Result<String, String>,
+    }
+    impl Default for Spdx23 {
+        fn default() -> Self {
+            Self {
+                annotations: Ok(Default::default()),
+                comment: Ok(Default::default()),
+                creation_info: Err("no value supplied for creation_info".to_string()),
+                data_license: Err("no value supplied for data_license".to_string()),
+                document_describes: Ok(Default::default()),
+                document_namespace: Err(
+                    "no value supplied for document_namespace".to_string(),
+                ),
+                external_document_refs: Ok(Default::default()),
+                files: Ok(Default::default()),
+                has_extracted_licensing_infos: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                packages: Ok(Default::default()),
+                relationships: Ok(Default::default()),
+                revieweds: Ok(Default::default()),
+                snippets: Ok(Default::default()),
+                spdx_version: Err("no value supplied for spdx_version".to_string()),
+                spdxid: Err("no value supplied for spdxid".to_string()),
+            }
+        }
+    }
+    impl Spdx23 {
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23AnnotationsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn creation_info<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Spdx23CreationInfo>,
+            T::Error: std::fmt::Display,
+        {
+            self.creation_info = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for creation_info: {}", e)
+                });
+            self
+        }
+        pub fn data_license<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.data_license = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for data_license: {}", e)
+                });
+            self
+        }
+        pub fn document_describes<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.document_describes = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for document_describes: {}", e
+                    )
+                });
+            self
+        }
+        pub fn document_namespace<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.document_namespace = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for document_namespace: {}", e
+                    )
+                });
+            self
+        }
+        pub fn external_document_refs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23ExternalDocumentRefsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.external_document_refs = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for external_document_refs: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23FilesItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.files = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for files: {}", e)
+                });
+            self
+        }
+        pub fn has_extracted_licensing_infos<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23HasExtractedLicensingInfosItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.has_extracted_licensing_infos = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for has_extracted_licensing_infos: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn packages<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23PackagesItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.packages = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for packages: {}", e)
+                });
+            self
+        }
+        pub fn relationships<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23RelationshipsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.relationships = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for relationships: {}", e)
+                });
+            self
+        }
+        pub fn revieweds<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23ReviewedsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.revieweds = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for revieweds: {}", e)
+                });
+            self
+        }
+        pub fn snippets<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23SnippetsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.snippets = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for snippets: {}", e)
+                });
+            self
+        }
+        pub fn spdx_version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdx_version = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdx_version: {}", e)
+                });
+            self
+        }
+        pub fn spdxid<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdxid = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdxid: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23> for super::Spdx23 {
+        type Error = String;
+        fn try_from(value: Spdx23) -> Result<Self, String> {
+            Ok(Self {
+                annotations: value.annotations?,
+                comment: value.comment?,
+                creation_info: value.creation_info?,
+                data_license: value.data_license?,
+                document_describes: value.document_describes?,
+                document_namespace: value.document_namespace?,
+                external_document_refs: value.external_document_refs?,
+                files: value.files?,
+                has_extracted_licensing_infos: value.has_extracted_licensing_infos?,
+                name: value.name?,
+                packages: value.packages?,
+                relationships: value.relationships?,
+                revieweds: value.revieweds?,
+                snippets: value.snippets?,
+                spdx_version: value.spdx_version?,
+                spdxid: value.spdxid?,
+            })
+        }
+    }
+    impl From<super::Spdx23> for Spdx23 {
+        fn from(value: super::Spdx23) -> Self {
+            Self {
+                annotations: Ok(value.annotations),
+                comment: Ok(value.comment),
+                creation_info: Ok(value.creation_info),
+                data_license: Ok(value.data_license),
+                document_describes: Ok(value.document_describes),
+                document_namespace: Ok(value.document_namespace),
+                external_document_refs: Ok(value.external_document_refs),
+                files: Ok(value.files),
+                has_extracted_licensing_infos: Ok(value.has_extracted_licensing_infos),
+                name: Ok(value.name),
+                packages: Ok(value.packages),
+                relationships: Ok(value.relationships),
+                revieweds: Ok(value.revieweds),
+                snippets: Ok(value.snippets),
+                spdx_version: Ok(value.spdx_version),
+                spdxid: Ok(value.spdxid),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23AnnotationsItem {
+        annotation_date: Result<String, String>,
+        annotation_type: Result<super::Spdx23AnnotationsItemAnnotationType, String>,
+        annotator: Result<String, String>,
+        comment: Result<String, String>,
+    }
+    impl Default for Spdx23AnnotationsItem {
+        fn default() -> Self {
+            Self {
+                annotation_date: Err(
+                    "no value supplied for annotation_date".to_string(),
+                ),
+                annotation_type: Err(
+                    "no value supplied for annotation_type".to_string(),
+                ),
+                annotator: Err("no value supplied for annotator".to_string()),
+                comment: Err("no value supplied for comment".to_string()),
+            }
+        }
+    }
+    impl Spdx23AnnotationsItem {
+        pub fn annotation_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_date: {}", e)
+                });
+            self
+        }
+        pub fn annotation_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Spdx23AnnotationsItemAnnotationType>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_type: {}", e)
+                });
+            self
+        }
+        pub fn annotator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotator: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23AnnotationsItem> for super::Spdx23AnnotationsItem {
+        type Error = String;
+        fn try_from(value: Spdx23AnnotationsItem) -> Result<Self, String> {
+            Ok(Self {
+                annotation_date: value.annotation_date?,
+                annotation_type: value.annotation_type?,
+                annotator: value.annotator?,
+                comment: value.comment?,
+            })
+        }
+    }
+    impl From<super::Spdx23AnnotationsItem> for Spdx23AnnotationsItem {
+        fn from(value: super::Spdx23AnnotationsItem) -> Self {
+            Self {
+                annotation_date: Ok(value.annotation_date),
+                annotation_type: Ok(value.annotation_type),
+                annotator: Ok(value.annotator),
+                comment: Ok(value.comment),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23CreationInfo {
+        comment: Result<Option<String>, String>,
+        created: Result<String, String>,
+        creators: Result<Vec<String>, String>,
+        license_list_version: Result<Option<String>, String>,
+    }
+    impl Default for Spdx23CreationInfo {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                created: Err("no value supplied for created".to_string()),
+                creators: Err("no value supplied for creators".to_string()),
+                license_list_version: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx23CreationInfo {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn created<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.created = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for created: {}", e)
+                });
+            self
+        }
+        pub fn creators<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.creators = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for creators: {}", e)
+                });
+            self
+        }
+        pub fn license_list_version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_list_version = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_list_version: {}", e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23CreationInfo> for super::Spdx23CreationInfo {
+        type Error = String;
+        fn try_from(value: Spdx23CreationInfo) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                created: value.created?,
+                creators: value.creators?,
+                license_list_version: value.license_list_version?,
+            })
+        }
+    }
+    impl From<super::Spdx23CreationInfo> for Spdx23CreationInfo {
+        fn from(value: super::Spdx23CreationInfo) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                created: Ok(value.created),
+                creators: Ok(value.creators),
+                license_list_version: Ok(value.license_list_version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23ExternalDocumentRefsItem {
+        checksum: Result<super::Spdx23ExternalDocumentRefsItemChecksum, String>,
+        external_document_id: Result<String, String>,
+        spdx_document: Result<String, String>,
+    }
+    impl Default for Spdx23ExternalDocumentRefsItem {
+        fn default() -> Self {
+            Self {
+                checksum: Err("no value supplied for checksum".to_string()),
+                external_document_id: Err(
+                    "no value supplied for external_document_id".to_string(),
+                ),
+                spdx_document: Err("no value supplied for spdx_document".to_string()),
+            }
+        }
+    }
+    impl Spdx23ExternalDocumentRefsItem {
+        pub fn checksum<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Spdx23ExternalDocumentRefsItemChecksum>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum: {}", e)
+                });
+            self
+        }
+        pub fn external_document_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.external_document_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for external_document_id: {}", e
+                    )
+                });
+            self
+        }
+        pub fn spdx_document<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdx_document = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdx_document: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23ExternalDocumentRefsItem>
+    for super::Spdx23ExternalDocumentRefsItem {
+        type Error = String;
+        fn try_from(value: Spdx23ExternalDocumentRefsItem) -> Result<Self, String> {
+            Ok(Self {
+                checksum: value.checksum?,
+                external_document_id: value.external_document_id?,
+                spdx_document: value.spdx_document?,
+            })
+        }
+    }
+    impl From<super::Spdx23ExternalDocumentRefsItem> for Spdx23ExternalDocumentRefsItem {
+        fn from(value: super::Spdx23ExternalDocumentRefsItem) -> Self {
+            Self {
+                checksum: Ok(value.checksum),
+                external_document_id: Ok(value.external_document_id),
+                spdx_document: Ok(value.spdx_document),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23ExternalDocumentRefsItemChecksum {
+        algorithm: Result<
+            super::Spdx23ExternalDocumentRefsItemChecksumAlgorithm,
+            String,
+        >,
+        checksum_value: Result<String, String>,
+    }
+    impl Default for Spdx23ExternalDocumentRefsItemChecksum {
+        fn default() -> Self {
+            Self {
+                algorithm: Err("no value supplied for algorithm".to_string()),
+                checksum_value: Err("no value supplied for checksum_value".to_string()),
+            }
+        }
+    }
+    impl Spdx23ExternalDocumentRefsItemChecksum {
+        pub fn algorithm<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                super::Spdx23ExternalDocumentRefsItemChecksumAlgorithm,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.algorithm = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for algorithm: {}", e)
+                });
+            self
+        }
+        pub fn checksum_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum_value: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23ExternalDocumentRefsItemChecksum>
+    for super::Spdx23ExternalDocumentRefsItemChecksum {
+        type Error = String;
+        fn try_from(
+            value: Spdx23ExternalDocumentRefsItemChecksum,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                algorithm: value.algorithm?,
+                checksum_value: value.checksum_value?,
+            })
+        }
+    }
+    impl From<super::Spdx23ExternalDocumentRefsItemChecksum>
+    for Spdx23ExternalDocumentRefsItemChecksum {
+        fn from(value: super::Spdx23ExternalDocumentRefsItemChecksum) -> Self {
+            Self {
+                algorithm: Ok(value.algorithm),
+                checksum_value: Ok(value.checksum_value),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23FilesItem {
+        annotations: Result<Vec<super::Spdx23FilesItemAnnotationsItem>, String>,
+        artifact_ofs: Result<Vec<serde_json::Map<String, serde_json::Value>>, String>,
+        attribution_texts: Result<Vec<String>, String>,
+        checksums: Result<Vec<super::Spdx23FilesItemChecksumsItem>, String>,
+        comment: Result<Option<String>, String>,
+        copyright_text: Result<Option<String>, String>,
+        file_contributors: Result<Vec<String>, String>,
+        file_dependencies: Result<Vec<String>, String>,
+        file_name: Result<String, String>,
+        file_types: Result<Vec<super::Spdx23FilesItemFileTypesItem>, String>,
+        license_comments: Result<Option<String>, String>,
+        license_concluded: Result<Option<String>, String>,
+        license_info_in_files: Result<Vec<String>, String>,
+        notice_text: Result<Option<String>, String>,
+        spdxid: Result<String, String>,
+    }
+    impl Default for Spdx23FilesItem {
+        fn default() -> Self {
+            Self {
+                annotations: Ok(Default::default()),
+                artifact_ofs: Ok(Default::default()),
+                attribution_texts: Ok(Default::default()),
+                checksums: Err("no value supplied for checksums".to_string()),
+                comment: Ok(Default::default()),
+                copyright_text: Ok(Default::default()),
+                file_contributors: Ok(Default::default()),
+                file_dependencies: Ok(Default::default()),
+                file_name: Err("no value supplied for file_name".to_string()),
+                file_types: Ok(Default::default()),
+                license_comments: Ok(Default::default()),
+                license_concluded: Ok(Default::default()),
+                license_info_in_files: Ok(Default::default()),
+                notice_text: Ok(Default::default()),
+                spdxid: Err("no value supplied for spdxid".to_string()),
+            }
+        }
+    }
+    impl Spdx23FilesItem {
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23FilesItemAnnotationsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn artifact_ofs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<serde_json::Map<String, serde_json::Value>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.artifact_ofs = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for artifact_ofs: {}", e)
+                });
+            self
+        }
+        pub fn attribution_texts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.attribution_texts = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for attribution_texts: {}", e
+                    )
+                });
+            self
+        }
+        pub fn checksums<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23FilesItemChecksumsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksums = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksums: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn copyright_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.copyright_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for copyright_text: {}", e)
+                });
+            self
+        }
+        pub fn file_contributors<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.file_contributors = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for file_contributors: {}", e
+                    )
+                });
+            self
+        }
+        pub fn file_dependencies<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.file_dependencies = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for file_dependencies: {}", e
+                    )
+                });
+            self
+        }
+        pub fn file_name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.file_name = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for file_name: {}", e)
+                });
+            self
+        }
+        pub fn file_types<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23FilesItemFileTypesItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.file_types = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for file_types: {}", e)
+                });
+            self
+        }
+        pub fn license_comments<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_comments = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_comments: {}", e
+                    )
+                });
+            self
+        }
+        pub fn license_concluded<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_concluded = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_concluded: {}", e
+                    )
+                });
+            self
+        }
+        pub fn license_info_in_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_info_in_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_info_in_files: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn notice_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.notice_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for notice_text: {}", e)
+                });
+            self
+        }
+        pub fn spdxid<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdxid = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdxid: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23FilesItem> for super::Spdx23FilesItem {
+        type Error = String;
+        fn try_from(value: Spdx23FilesItem) -> Result<Self, String> {
+            Ok(Self {
+                annotations: value.annotations?,
+                artifact_ofs: value.artifact_ofs?,
+                attribution_texts: value.attribution_texts?,
+                checksums: value.checksums?,
+                comment: value.comment?,
+                copyright_text: value.copyright_text?,
+                file_contributors: value.file_contributors?,
+                file_dependencies: value.file_dependencies?,
+                file_name: value.file_name?,
+                file_types: value.file_types?,
+                license_comments: value.license_comments?,
+                license_concluded: value.license_concluded?,
+                license_info_in_files: value.license_info_in_files?,
+                notice_text: value.notice_text?,
+                spdxid: value.spdxid?,
+            })
+        }
+    }
+    impl From<super::Spdx23FilesItem> for Spdx23FilesItem {
+        fn from(value: super::Spdx23FilesItem) -> Self {
+            Self {
+                annotations: Ok(value.annotations),
+                artifact_ofs: Ok(value.artifact_ofs),
+                attribution_texts: Ok(value.attribution_texts),
+                checksums: Ok(value.checksums),
+                comment: Ok(value.comment),
+                copyright_text: Ok(value.copyright_text),
+                file_contributors: Ok(value.file_contributors),
+                file_dependencies: Ok(value.file_dependencies),
+                file_name: Ok(value.file_name),
+                file_types: Ok(value.file_types),
+                license_comments: Ok(value.license_comments),
+                license_concluded: Ok(value.license_concluded),
+                license_info_in_files: Ok(value.license_info_in_files),
+                notice_text: Ok(value.notice_text),
+                spdxid: Ok(value.spdxid),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23FilesItemAnnotationsItem {
+        annotation_date: Result<String, String>,
+        annotation_type: Result<
+            super::Spdx23FilesItemAnnotationsItemAnnotationType,
+            String,
+        >,
+        annotator: Result<String, String>,
+        comment: Result<String, String>,
+    }
+    impl Default for Spdx23FilesItemAnnotationsItem {
+        fn default() -> Self {
+            Self {
+                annotation_date: Err(
+                    "no value supplied for annotation_date".to_string(),
+                ),
+                annotation_type: Err(
+                    "no value supplied for annotation_type".to_string(),
+                ),
+                annotator: Err("no value supplied for annotator".to_string()),
+                comment: Err("no value supplied for comment".to_string()),
+            }
+        }
+    }
+    impl Spdx23FilesItemAnnotationsItem {
+        pub fn annotation_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_date: {}", e)
+                });
+            self
+        }
+        pub fn annotation_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                super::Spdx23FilesItemAnnotationsItemAnnotationType,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_type: {}", e)
+                });
+            self
+        }
+        pub fn annotator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotator: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23FilesItemAnnotationsItem>
+    for super::Spdx23FilesItemAnnotationsItem {
+        type Error = String;
+        fn try_from(value: Spdx23FilesItemAnnotationsItem) -> Result<Self, String> {
+            Ok(Self {
+                annotation_date: value.annotation_date?,
+                annotation_type: value.annotation_type?,
+                annotator: value.annotator?,
+                comment: value.comment?,
+            })
+        }
+    }
+    impl From<super::Spdx23FilesItemAnnotationsItem> for Spdx23FilesItemAnnotationsItem {
+        fn from(value: super::Spdx23FilesItemAnnotationsItem) -> Self {
+            Self {
+                annotation_date: Ok(value.annotation_date),
+                annotation_type: Ok(value.annotation_type),
+                annotator: Ok(value.annotator),
+                comment: Ok(value.comment),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23FilesItemChecksumsItem {
+        algorithm: Result<super::Spdx23FilesItemChecksumsItemAlgorithm, String>,
+        checksum_value: Result<String, String>,
+    }
+    impl Default for Spdx23FilesItemChecksumsItem {
+        fn default() -> Self {
+            Self {
+                algorithm: Err("no value supplied for algorithm".to_string()),
+                checksum_value: Err("no value supplied for checksum_value".to_string()),
+            }
+        }
+    }
+    impl Spdx23FilesItemChecksumsItem {
+        pub fn algorithm<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Spdx23FilesItemChecksumsItemAlgorithm>,
+            T::Error: std::fmt::Display,
+        {
+            self.algorithm = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for algorithm: {}", e)
+                });
+            self
+        }
+        pub fn checksum_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum_value: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23FilesItemChecksumsItem>
+    for super::Spdx23FilesItemChecksumsItem {
+        type Error = String;
+        fn try_from(value: Spdx23FilesItemChecksumsItem) -> Result<Self, String> {
+            Ok(Self {
+                algorithm: value.algorithm?,
+                checksum_value: value.checksum_value?,
+            })
+        }
+    }
+    impl From<super::Spdx23FilesItemChecksumsItem> for Spdx23FilesItemChecksumsItem {
+        fn from(value: super::Spdx23FilesItemChecksumsItem) -> Self {
+            Self {
+                algorithm: Ok(value.algorithm),
+                checksum_value: Ok(value.checksum_value),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23HasExtractedLicensingInfosItem {
+        comment: Result<Option<String>, String>,
+        cross_refs: Result<
+            Vec<super::Spdx23HasExtractedLicensingInfosItemCrossRefsItem>,
+            String,
+        >,
+        extracted_text: Result<String, String>,
+        license_id: Result<String, String>,
+        name: Result<Option<String>, String>,
+        see_alsos: Result<Vec<String>, String>,
+    }
+    impl Default for Spdx23HasExtractedLicensingInfosItem {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                cross_refs: Ok(Default::default()),
+                extracted_text: Err("no value supplied for extracted_text".to_string()),
+                license_id: Err("no value supplied for license_id".to_string()),
+                name: Ok(Default::default()),
+                see_alsos: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx23HasExtractedLicensingInfosItem {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn cross_refs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Vec<super::Spdx23HasExtractedLicensingInfosItemCrossRefsItem>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.cross_refs = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for cross_refs: {}", e)
+                });
+            self
+        }
+        pub fn extracted_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.extracted_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for extracted_text: {}", e)
+                });
+            self
+        }
+        pub fn license_id<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for license_id: {}", e)
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn see_alsos<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.see_alsos = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for see_alsos: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23HasExtractedLicensingInfosItem>
+    for super::Spdx23HasExtractedLicensingInfosItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx23HasExtractedLicensingInfosItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                cross_refs: value.cross_refs?,
+                extracted_text: value.extracted_text?,
+                license_id: value.license_id?,
+                name: value.name?,
+                see_alsos: value.see_alsos?,
+            })
+        }
+    }
+    impl From<super::Spdx23HasExtractedLicensingInfosItem>
+    for Spdx23HasExtractedLicensingInfosItem {
+        fn from(value: super::Spdx23HasExtractedLicensingInfosItem) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                cross_refs: Ok(value.cross_refs),
+                extracted_text: Ok(value.extracted_text),
+                license_id: Ok(value.license_id),
+                name: Ok(value.name),
+                see_alsos: Ok(value.see_alsos),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+        is_live: Result<Option<bool>, String>,
+        is_valid: Result<Option<bool>, String>,
+        is_way_back_link: Result<Option<bool>, String>,
+        match_: Result<Option<String>, String>,
+        order: Result<Option<i64>, String>,
+        timestamp: Result<Option<String>, String>,
+        url: Result<String, String>,
+    }
+    impl Default for Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+        fn default() -> Self {
+            Self {
+                is_live: Ok(Default::default()),
+                is_valid: Ok(Default::default()),
+                is_way_back_link: Ok(Default::default()),
+                match_: Ok(Default::default()),
+                order: Ok(Default::default()),
+                timestamp: Ok(Default::default()),
+                url: Err("no value supplied for url".to_string()),
+            }
+        }
+    }
+    impl Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+        pub fn is_live<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.is_live = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for is_live: {}", e)
+                });
+            self
+        }
+        pub fn is_valid<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.is_valid = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for is_valid: {}", e)
+                });
+            self
+        }
+        pub fn is_way_back_link<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.is_way_back_link = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for is_way_back_link: {}", e
+                    )
+                });
+            self
+        }
+        pub fn match_<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.match_ = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for match_: {}", e)
+                });
+            self
+        }
+        pub fn order<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<i64>>,
+            T::Error: std::fmt::Display,
+        {
+            self.order = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for order: {}", e)
+                });
+            self
+        }
+        pub fn timestamp<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.timestamp = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for timestamp: {}", e)
+                });
+            self
+        }
+        pub fn url<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.url = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for url: {}", e));
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23HasExtractedLicensingInfosItemCrossRefsItem>
+    for super::Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+        type Error = String;
+        fn try_from(
+            value: Spdx23HasExtractedLicensingInfosItemCrossRefsItem,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                is_live: value.is_live?,
+                is_valid: value.is_valid?,
+                is_way_back_link: value.is_way_back_link?,
+                match_: value.match_?,
+                order: value.order?,
+                timestamp: value.timestamp?,
+                url: value.url?,
+            })
+        }
+    }
+    impl From<super::Spdx23HasExtractedLicensingInfosItemCrossRefsItem>
+    for Spdx23HasExtractedLicensingInfosItemCrossRefsItem {
+        fn from(
+            value: super::Spdx23HasExtractedLicensingInfosItemCrossRefsItem,
+        ) -> Self {
+            Self {
+                is_live: Ok(value.is_live),
+                is_valid: Ok(value.is_valid),
+                is_way_back_link: Ok(value.is_way_back_link),
+                match_: Ok(value.match_),
+                order: Ok(value.order),
+                timestamp: Ok(value.timestamp),
+                url: Ok(value.url),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23PackagesItem {
+        annotations: Result<Vec<super::Spdx23PackagesItemAnnotationsItem>, String>,
+        attribution_texts: Result<Vec<String>, String>,
+        built_date: Result<Option<String>, String>,
+        checksums: Result<Vec<super::Spdx23PackagesItemChecksumsItem>, String>,
+        comment: Result<Option<String>, String>,
+        copyright_text: Result<Option<String>, String>,
+        description: Result<Option<String>, String>,
+        download_location: Result<String, String>,
+        external_refs: Result<Vec<super::Spdx23PackagesItemExternalRefsItem>, String>,
+        files_analyzed: Result<Option<bool>, String>,
+        has_files: Result<Vec<String>, String>,
+        homepage: Result<Option<String>, String>,
+        license_comments: Result<Option<String>, String>,
+        license_concluded: Result<Option<String>, String>,
+        license_declared: Result<Option<String>, String>,
+        license_info_from_files: Result<Vec<String>, String>,
+        name: Result<String, String>,
+        originator: Result<Option<String>, String>,
+        package_file_name: Result<Option<String>, String>,
+        package_verification_code: Result<
+            Option<super::Spdx23PackagesItemPackageVerificationCode>,
+            String,
+        >,
+        primary_package_purpose: Result<
+            Option<super::Spdx23PackagesItemPrimaryPackagePurpose>,
+            String,
+        >,
+        release_date: Result<Option<String>, String>,
+        source_info: Result<Option<String>, String>,
+        spdxid: Result<String, String>,
+        summary: Result<Option<String>, String>,
+        supplier: Result<Option<String>, String>,
+        valid_until_date: Result<Option<String>, String>,
+        version_info: Result<Option<String>, String>,
+    }
+    impl Default for Spdx23PackagesItem {
+        fn default() -> Self {
+            Self {
+                annotations: Ok(Default::default()),
+                attribution_texts: Ok(Default::default()),
+                built_date: Ok(Default::default()),
+                checksums: Ok(Default::default()),
+                comment: Ok(Default::default()),
+                copyright_text: Ok(Default::default()),
+                description: Ok(Default::default()),
+                download_location: Err(
+                    "no value supplied for download_location".to_string(),
+                ),
+                external_refs: Ok(Default::default()),
+                files_analyzed: Ok(Default::default()),
+                has_files: Ok(Default::default()),
+                homepage: Ok(Default::default()),
+                license_comments: Ok(Default::default()),
+                license_concluded: Ok(Default::default()),
+                license_declared: Ok(Default::default()),
+                license_info_from_files: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                originator: Ok(Default::default()),
+                package_file_name: Ok(Default::default()),
+                package_verification_code: Ok(Default::default()),
+                primary_package_purpose: Ok(Default::default()),
+                release_date: Ok(Default::default()),
+                source_info: Ok(Default::default()),
+                spdxid: Err("no value supplied for spdxid".to_string()),
+                summary: Ok(Default::default()),
+                supplier: Ok(Default::default()),
+                valid_until_date: Ok(Default::default()),
+                version_info: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx23PackagesItem {
+        pub fn annotations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23PackagesItemAnnotationsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn attribution_texts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.attribution_texts = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for attribution_texts: {}", e
+                    )
+                });
+            self
+        }
+        pub fn built_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.built_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for built_date: {}", e)
+                });
+            self
+        }
+        pub fn checksums<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23PackagesItemChecksumsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksums = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksums: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn copyright_text<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.copyright_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for copyright_text: {}", e)
+                });
+            self
+        }
+        pub fn description<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.description = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for description: {}", e)
+                });
+            self
+        }
+        pub fn download_location<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.download_location = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for download_location: {}", e
+                    )
+                });
+            self
+        }
+        pub fn external_refs<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23PackagesItemExternalRefsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.external_refs = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for external_refs: {}", e)
+                });
+            self
+        }
+        pub fn files_analyzed<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<bool>>,
+            T::Error: std::fmt::Display,
+        {
+            self.files_analyzed = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for files_analyzed: {}", e)
+                });
+            self
+        }
+        pub fn has_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.has_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for has_files: {}", e)
+                });
+            self
+        }
+        pub fn homepage<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.homepage = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for homepage: {}", e)
+                });
+            self
+        }
+        pub fn license_comments<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_comments = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_comments: {}", e
+                    )
+                });
+            self
+        }
+        pub fn license_concluded<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_concluded = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_concluded: {}", e
+                    )
+                });
+            self
+        }
+        pub fn license_declared<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_declared = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_declared: {}", e
+                    )
+                });
+            self
+        }
+        pub fn license_info_from_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_info_from_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_info_from_files: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn originator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.originator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for originator: {}", e)
+                });
+            self
+        }
+        pub fn package_file_name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.package_file_name = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for package_file_name: {}", e
+                    )
+                });
+            self
+        }
+        pub fn package_verification_code<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx23PackagesItemPackageVerificationCode>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.package_verification_code = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for package_verification_code: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn primary_package_purpose<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                Option<super::Spdx23PackagesItemPrimaryPackagePurpose>,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.primary_package_purpose = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for primary_package_purpose: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn release_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.release_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for release_date: {}", e)
+                });
+            self
+        }
+        pub fn source_info<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.source_info = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for source_info: {}", e)
+                });
+            self
+        }
+        pub fn spdxid<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdxid = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdxid: {}", e)
+                });
+            self
+        }
+        pub fn summary<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.summary = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for summary: {}", e)
+                });
+            self
+        }
+        pub fn supplier<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.supplier = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for supplier: {}", e)
+                });
+            self
+        }
+        pub fn valid_until_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.valid_until_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for valid_until_date: {}", e
+                    )
+                });
+            self
+        }
+        pub fn version_info<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.version_info = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for version_info: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23PackagesItem> for super::Spdx23PackagesItem {
+        type Error = String;
+        fn try_from(value: Spdx23PackagesItem) -> Result<Self, String> {
+            Ok(Self {
+                annotations: value.annotations?,
+                attribution_texts: value.attribution_texts?,
+                built_date: value.built_date?,
+                checksums: value.checksums?,
+                comment: value.comment?,
+                copyright_text: value.copyright_text?,
+                description: value.description?,
+                download_location: value.download_location?,
+                external_refs: value.external_refs?,
+                files_analyzed: value.files_analyzed?,
+                has_files: value.has_files?,
+                homepage: value.homepage?,
+                license_comments: value.license_comments?,
+                license_concluded: value.license_concluded?,
+                license_declared: value.license_declared?,
+                license_info_from_files: value.license_info_from_files?,
+                name: value.name?,
+                originator: value.originator?,
+                package_file_name: value.package_file_name?,
+                package_verification_code: value.package_verification_code?,
+                primary_package_purpose: value.primary_package_purpose?,
+                release_date: value.release_date?,
+                source_info: value.source_info?,
+                spdxid: value.spdxid?,
+                summary: value.summary?,
+                supplier: value.supplier?,
+                valid_until_date: value.valid_until_date?,
+                version_info: value.version_info?,
+            })
+        }
+    }
+    impl From<super::Spdx23PackagesItem> for Spdx23PackagesItem {
+        fn from(value: super::Spdx23PackagesItem) -> Self {
+            Self {
+                annotations: Ok(value.annotations),
+                attribution_texts: Ok(value.attribution_texts),
+                built_date: Ok(value.built_date),
+                checksums: Ok(value.checksums),
+                comment: Ok(value.comment),
+                copyright_text: Ok(value.copyright_text),
+                description: Ok(value.description),
+                download_location: Ok(value.download_location),
+                external_refs: Ok(value.external_refs),
+                files_analyzed: Ok(value.files_analyzed),
+                has_files: Ok(value.has_files),
+                homepage: Ok(value.homepage),
+                license_comments: Ok(value.license_comments),
+                license_concluded: Ok(value.license_concluded),
+                license_declared: Ok(value.license_declared),
+                license_info_from_files: Ok(value.license_info_from_files),
+                name: Ok(value.name),
+                originator: Ok(value.originator),
+                package_file_name: Ok(value.package_file_name),
+                package_verification_code: Ok(value.package_verification_code),
+                primary_package_purpose: Ok(value.primary_package_purpose),
+                release_date: Ok(value.release_date),
+                source_info: Ok(value.source_info),
+                spdxid: Ok(value.spdxid),
+                summary: Ok(value.summary),
+                supplier: Ok(value.supplier),
+                valid_until_date: Ok(value.valid_until_date),
+                version_info: Ok(value.version_info),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23PackagesItemAnnotationsItem {
+        annotation_date: Result<String, String>,
+        annotation_type: Result<
+            super::Spdx23PackagesItemAnnotationsItemAnnotationType,
+            String,
+        >,
+        annotator: Result<String, String>,
+        comment: Result<String, String>,
+    }
+    impl Default for Spdx23PackagesItemAnnotationsItem {
+        fn default() -> Self {
+            Self {
+                annotation_date: Err(
+                    "no value supplied for annotation_date".to_string(),
+                ),
+                annotation_type: Err(
+                    "no value supplied for annotation_type".to_string(),
+                ),
+                annotator: Err("no value supplied for annotator".to_string()),
+                comment: Err("no value supplied for comment".to_string()),
+            }
+        }
+    }
+    impl Spdx23PackagesItemAnnotationsItem {
+        pub fn annotation_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_date: {}", e)
+                });
+            self
+        }
+        pub fn annotation_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                super::Spdx23PackagesItemAnnotationsItemAnnotationType,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.annotation_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotation_type: {}", e)
+                });
+            self
+        }
+        pub fn annotator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotator: {}", e)
+                });
+            self
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23PackagesItemAnnotationsItem>
+    for super::Spdx23PackagesItemAnnotationsItem {
+        type Error = String;
+        fn try_from(value: Spdx23PackagesItemAnnotationsItem) -> Result<Self, String> {
+            Ok(Self {
+                annotation_date: value.annotation_date?,
+                annotation_type: value.annotation_type?,
+                annotator: value.annotator?,
+                comment: value.comment?,
+            })
+        }
+    }
+    impl From<super::Spdx23PackagesItemAnnotationsItem>
+    for Spdx23PackagesItemAnnotationsItem {
+        fn from(value: super::Spdx23PackagesItemAnnotationsItem) -> Self {
+            Self {
+                annotation_date: Ok(value.annotation_date),
+                annotation_type: Ok(value.annotation_type),
+                annotator: Ok(value.annotator),
+                comment: Ok(value.comment),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23PackagesItemChecksumsItem {
+        algorithm: Result<super::Spdx23PackagesItemChecksumsItemAlgorithm, String>,
+        checksum_value: Result<String, String>,
+    }
+    impl Default for Spdx23PackagesItemChecksumsItem {
+        fn default() -> Self {
+            Self {
+                algorithm: Err("no value supplied for algorithm".to_string()),
+                checksum_value: Err("no value supplied for checksum_value".to_string()),
+            }
+        }
+    }
+    impl Spdx23PackagesItemChecksumsItem {
+        pub fn algorithm<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Spdx23PackagesItemChecksumsItemAlgorithm>,
+            T::Error: std::fmt::Display,
+        {
+            self.algorithm = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for algorithm: {}", e)
+                });
+            self
+        }
+        pub fn checksum_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.checksum_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for checksum_value: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23PackagesItemChecksumsItem>
+    for super::Spdx23PackagesItemChecksumsItem {
+        type Error = String;
+        fn try_from(value: Spdx23PackagesItemChecksumsItem) -> Result<Self, String> {
+            Ok(Self {
+                algorithm: value.algorithm?,
+                checksum_value: value.checksum_value?,
+            })
+        }
+    }
+    impl From<super::Spdx23PackagesItemChecksumsItem>
+    for Spdx23PackagesItemChecksumsItem {
+        fn from(value: super::Spdx23PackagesItemChecksumsItem) -> Self {
+            Self {
+                algorithm: Ok(value.algorithm),
+                checksum_value: Ok(value.checksum_value),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23PackagesItemExternalRefsItem {
+        comment: Result<Option<String>, String>,
+        reference_category: Result<
+            super::Spdx23PackagesItemExternalRefsItemReferenceCategory,
+            String,
+        >,
+        reference_locator: Result<String, String>,
+        reference_type: Result<String, String>,
+    }
+    impl Default for Spdx23PackagesItemExternalRefsItem {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                reference_category: Err(
+                    "no value supplied for reference_category".to_string(),
+                ),
+                reference_locator: Err(
+                    "no value supplied for reference_locator".to_string(),
+                ),
+                reference_type: Err("no value supplied for reference_type".to_string()),
+            }
+        }
+    }
+    impl Spdx23PackagesItemExternalRefsItem {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
+        }
+        pub fn reference_category<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<
+                super::Spdx23PackagesItemExternalRefsItemReferenceCategory,
+            >,
+            T::Error: std::fmt::Display,
+        {
+            self.reference_category = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for reference_category: {}", e
+                    )
+                });
+            self
+        }
+        pub fn reference_locator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.reference_locator = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for reference_locator: {}", e
+                    )
+                });
+            self
+        }
+        pub fn reference_type<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.reference_type = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for reference_type: {}", e)
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23PackagesItemExternalRefsItem>
+    for super::Spdx23PackagesItemExternalRefsItem {
+        type Error = String;
+        fn try_from(value: Spdx23PackagesItemExternalRefsItem) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                reference_category: value.reference_category?,
+                reference_locator: value.reference_locator?,
+                reference_type: value.reference_type?,
+            })
+        }
+    }
+    impl From<super::Spdx23PackagesItemExternalRefsItem>
+    for Spdx23PackagesItemExternalRefsItem {
+        fn from(value: super::Spdx23PackagesItemExternalRefsItem) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                reference_category: Ok(value.reference_category),
+                reference_locator: Ok(value.reference_locator),
+                reference_type: Ok(value.reference_type),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23PackagesItemPackageVerificationCode {
+        package_verification_code_excluded_files: Result<Vec<String>, String>,
+        package_verification_code_value: Result<String, String>,
+    }
+    impl Default for Spdx23PackagesItemPackageVerificationCode {
+        fn default() -> Self {
+            Self {
+                package_verification_code_excluded_files: Ok(Default::default()),
+                package_verification_code_value: Err(
+                    "no value supplied for package_verification_code_value".to_string(),
+                ),
+            }
+        }
+    }
+    impl Spdx23PackagesItemPackageVerificationCode {
+        pub fn package_verification_code_excluded_files<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.package_verification_code_excluded_files = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for package_verification_code_excluded_files: {}",
+                        e
+                    )
+                });
+            self
+        }
+        pub fn package_verification_code_value<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.package_verification_code_value = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for package_verification_code_value: {}",
+                        e
+                    )
+                });
+            self
+        }
+    }
+    impl std::convert::TryFrom<Spdx23PackagesItemPackageVerificationCode>
+    for super::Spdx23PackagesItemPackageVerificationCode {
+        type Error = String;
+        fn try_from(
+            value: Spdx23PackagesItemPackageVerificationCode,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                package_verification_code_excluded_files: value
+                    .package_verification_code_excluded_files?,
+                package_verification_code_value: value.package_verification_code_value?,
+            })
+        }
+    }
+    impl From<super::Spdx23PackagesItemPackageVerificationCode>
+    for Spdx23PackagesItemPackageVerificationCode {
+        fn from(value: super::Spdx23PackagesItemPackageVerificationCode) -> Self {
+            Self {
+                package_verification_code_excluded_files: Ok(
+                    value.package_verification_code_excluded_files,
+                ),
+                package_verification_code_value: Ok(
+                    value.package_verification_code_value,
+                ),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23RelationshipsItem {
+        comment: Result<Option<String>, String>,
+        related_spdx_element: Result<String, String>,
+        relationship_type: Result<
+            super::Spdx23RelationshipsItemRelationshipType,
+            String,
+        >,
+        spdx_element_id: Result<String, String>,
+    }
+    impl Default for Spdx23RelationshipsItem {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                related_spdx_element: Err(
+                    "no value supplied for related_spdx_element".to_string(),
+                ),
+                relationship_type: Err(
+                    "no value supplied for relationship_type".to_string(),
+                ),
+                spdx_element_id: Err("no value supplied for spdx_element_id".to_string()),
+            }
+        }
+    }
+    impl Spdx23RelationshipsItem {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for comment: {}", e)
+                });
+            self
         }
-    }
-    impl Attribute {
-        pub fn attribute<T>(mut self, value: T) -> Self
+        pub fn related_spdx_element<T>(mut self, value: T) -> Self
         where
             T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .attribute = value
+            self.related_spdx_element = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for attribute: {}", e)
+                    format!(
+                        "error converting supplied value for related_spdx_element: {}", e
+                    )
                 });
             self
         }
-        pub fn conditions<T>(mut self, value: T) -> Self
+        pub fn relationship_type<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T: std::convert::TryInto<super::Spdx23RelationshipsItemRelationshipType>,
             T::Error: std::fmt::Display,
         {
-            self
-                .conditions = value
+            self.relationship_type = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for conditions: {}", e)
+                    format!(
+                        "error converting supplied value for relationship_type: {}", e
+                    )
                 });
             self
         }
-        pub fn evidence<T>(mut self, value: T) -> Self
+        pub fn spdx_element_id<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
+            self.spdx_element_id = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdx_element_id: {}", e)
+                });
             self
-                .evidence = value
+        }
+    }
+    impl std::convert::TryFrom<Spdx23RelationshipsItem>
+    for super::Spdx23RelationshipsItem {
+        type Error = String;
+        fn try_from(value: Spdx23RelationshipsItem) -> Result<Self, String> {
+            Ok(Self {
+                comment: value.comment?,
+                related_spdx_element: value.related_spdx_element?,
+                relationship_type: value.relationship_type?,
+                spdx_element_id: value.spdx_element_id?,
+            })
+        }
+    }
+    impl From<super::Spdx23RelationshipsItem> for Spdx23RelationshipsItem {
+        fn from(value: super::Spdx23RelationshipsItem) -> Self {
+            Self {
+                comment: Ok(value.comment),
+                related_spdx_element: Ok(value.related_spdx_element),
+                relationship_type: Ok(value.relationship_type),
+                spdx_element_id: Ok(value.spdx_element_id),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23ReviewedsItem {
+        comment: Result<Option<String>, String>,
+        review_date: Result<String, String>,
+        reviewer: Result<Option<String>, String>,
+    }
+    impl Default for Spdx23ReviewedsItem {
+        fn default() -> Self {
+            Self {
+                comment: Ok(Default::default()),
+                review_date: Err("no value supplied for review_date".to_string()),
+                reviewer: Ok(Default::default()),
+            }
+        }
+    }
+    impl Spdx23ReviewedsItem {
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for evidence: {}", e)
+                    format!("error converting supplied value for comment: {}", e)
                 });
             self
         }
-        pub fn target<T>(mut self, value: T) -> Self
+        pub fn review_date<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
+            self.review_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for review_date: {}", e)
+                });
             self
-                .target = value
+        }
+        pub fn reviewer<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.reviewer = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for target: {}", e)
+                    format!("error converting supplied value for reviewer: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<Attribute> for super::Attribute {
+    impl std::convert::TryFrom<Spdx23ReviewedsItem> for super::Spdx23ReviewedsItem {
         type Error = String;
-        fn try_from(value: Attribute) -> Result<Self, String> {
+        fn try_from(value: Spdx23ReviewedsItem) -> Result<Self, String> {
             Ok(Self {
-                attribute: value.attribute?,
-                conditions: value.conditions?,
-                evidence: value.evidence?,
-                target: value.target?,
+                comment: value.comment?,
+                review_date: value.review_date?,
+                reviewer: value.reviewer?,
             })
         }
     }
-    impl From<super::Attribute> for Attribute {
-        fn from(value: super::Attribute) -> Self {
+    impl From<super::Spdx23ReviewedsItem> for Spdx23ReviewedsItem {
+        fn from(value: super::Spdx23ReviewedsItem) -> Self {
             Self {
-                attribute: Ok(value.attribute),
-                conditions: Ok(value.conditions),
-                evidence: Ok(value.evidence),
-                target: Ok(value.target),
+                comment: Ok(value.comment),
+                review_date: Ok(value.review_date),
+                reviewer: Ok(value.reviewer),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct BuildDefinition {
-        build_type: Result<String, String>,
-        external_parameters: Result<serde_json::Map<String, serde_json::Value>, String>,
-        internal_parameters: Result<
-            Option<serde_json::Map<String, serde_json::Value>>,
-            String,
-        >,
-        resolved_dependencies: Result<Option<Vec<super::ResourceDescriptor>>, String>,
+    pub struct Spdx23SnippetsItem {
+        annotations: Result<Vec<super::Spdx23SnippetsItemAnnotationsItem>, String>,
+        attribution_texts: Result<Vec<String>, String>,
+        comment: Result<Option<String>, String>,
+        copyright_text: Result<Option<String>, String>,
+        license_comments: Result<Option<String>, String>,
+        license_concluded: Result<Option<String>, String>,
+        license_info_in_snippets: Result<Vec<String>, String>,
+        name: Result<String, String>,
+        ranges: Result<Vec<super::Spdx23SnippetsItemRangesItem>, String>,
+        snippet_from_file: Result<String, String>,
+        spdxid: Result<String, String>,
     }
-    impl Default for BuildDefinition {
+    impl Default for Spdx23SnippetsItem {
         fn default() -> Self {
             Self {
-                build_type: Err("no value supplied for build_type".to_string()),
-                external_parameters: Err(
-                    "no value supplied for external_parameters".to_string(),
+                annotations: Ok(Default::default()),
+                attribution_texts: Ok(Default::default()),
+                comment: Ok(Default::default()),
+                copyright_text: Ok(Default::default()),
+                license_comments: Ok(Default::default()),
+                license_concluded: Ok(Default::default()),
+                license_info_in_snippets: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                ranges: Err("no value supplied for ranges".to_string()),
+                snippet_from_file: Err(
+                    "no value supplied for snippet_from_file".to_string(),
                 ),
-                internal_parameters: Ok(Default::default()),
-                resolved_dependencies: Ok(Default::default()),
+                spdxid: Err("no value supplied for spdxid".to_string()),
             }
         }
     }
-    impl BuildDefinition {
-        pub fn build_type<T>(mut self, value: T) -> Self
+    impl Spdx23SnippetsItem {
+        pub fn annotations<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<String>,
+            T: std::convert::TryInto<Vec<super::Spdx23SnippetsItemAnnotationsItem>>,
+            T::Error: std::fmt::Display,
+        {
+            self.annotations = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotations: {}", e)
+                });
+            self
+        }
+        pub fn attribution_texts<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
             T::Error: std::fmt::Display,
         {
+            self.attribution_texts = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for attribution_texts: {}", e
+                    )
+                });
             self
-                .build_type = value
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for build_type: {}", e)
+                    format!("error converting supplied value for comment: {}", e)
                 });
             self
         }
-        pub fn external_parameters<T>(mut self, value: T) -> Self
+        pub fn copyright_text<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<serde_json::Map<String, serde_json::Value>>,
+            T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
+            self.copyright_text = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for copyright_text: {}", e)
+                });
             self
-                .external_parameters = value
+        }
+        pub fn license_comments<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_comments = value
                 .try_into()
                 .map_err(|e| {
                     format!(
-                        "error converting supplied value for external_parameters: {}", e
+                        "error converting supplied value for license_comments: {}", e
                     )
                 });
             self
         }
-        pub fn internal_parameters<T>(mut self, value: T) -> Self
+        pub fn license_concluded<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
+            self.license_concluded = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for license_concluded: {}", e
+                    )
+                });
             self
-                .internal_parameters = value
+        }
+        pub fn license_info_in_snippets<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.license_info_in_snippets = value
                 .try_into()
                 .map_err(|e| {
                     format!(
-                        "error converting supplied value for internal_parameters: {}", e
+                        "error converting supplied value for license_info_in_snippets: {}",
+                        e
                     )
                 });
             self
         }
-        pub fn resolved_dependencies<T>(mut self, value: T) -> Self
+        pub fn name<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor>>>,
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
+            self
+        }
+        pub fn ranges<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::Spdx23SnippetsItemRangesItem>>,
             T::Error: std::fmt::Display,
         {
+            self.ranges = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for ranges: {}", e)
+                });
             self
-                .resolved_dependencies = value
+        }
+        pub fn snippet_from_file<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.snippet_from_file = value
                 .try_into()
                 .map_err(|e| {
                     format!(
-                        "error converting supplied value for resolved_dependencies: {}",
-                        e
+                        "error converting supplied value for snippet_from_file: {}", e
                     )
                 });
             self
         }
+        pub fn spdxid<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.spdxid = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for spdxid: {}", e)
+                });
+            self
+        }
     }
-    impl std::convert::TryFrom<BuildDefinition> for super::BuildDefinition {
+    impl std::convert::TryFrom<Spdx23SnippetsItem> for super::Spdx23SnippetsItem {
         type Error = String;
-        fn try_from(value: BuildDefinition) -> Result<Self, String> {
+        fn try_from(value: Spdx23SnippetsItem) -> Result<Self, String> {
             Ok(Self {
-                build_type: value.build_type?,
-                external_parameters: value.external_parameters?,
-                internal_parameters: value.internal_parameters?,
-                resolved_dependencies: value.resolved_dependencies?,
+                annotations: value.annotations?,
+                attribution_texts: value.attribution_texts?,
+                comment: value.comment?,
+                copyright_text: value.copyright_text?,
+                license_comments: value.license_comments?,
+                license_concluded: value.license_concluded?,
+                license_info_in_snippets: value.license_info_in_snippets?,
+                name: value.name?,
+                ranges: value.ranges?,
+                snippet_from_file: value.snippet_from_file?,
+                spdxid: value.spdxid?,
             })
         }
     }
-    impl From<super::BuildDefinition> for BuildDefinition {
-        fn from(value: super::BuildDefinition) -> Self {
+    impl From<super::Spdx23SnippetsItem> for Spdx23SnippetsItem {
+        fn from(value: super::Spdx23SnippetsItem) -> Self {
             Self {
-                build_type: Ok(value.build_type),
-                external_parameters: Ok(value.external_parameters),
-                internal_parameters: Ok(value.internal_parameters),
-                resolved_dependencies: Ok(value.resolved_dependencies),
+                annotations: Ok(value.annotations),
+                attribution_texts: Ok(value.attribution_texts),
+                comment: Ok(value.comment),
+                copyright_text: Ok(value.copyright_text),
+                license_comments: Ok(value.license_comments),
+                license_concluded: Ok(value.license_concluded),
+                license_info_in_snippets: Ok(value.license_info_in_snippets),
+                name: Ok(value.name),
+                ranges: Ok(value.ranges),
+                snippet_from_file: Ok(value.snippet_from_file),
+                spdxid: Ok(value.spdxid),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct BuildMetadata {
-        finished_on: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-        invocation_id: Result<Option<String>, String>,
-        started_on: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+    pub struct Spdx23SnippetsItemAnnotationsItem {
+        annotation_date: Result<String, String>,
+        annotation_type: Result<
+            super::Spdx23SnippetsItemAnnotationsItemAnnotationType,
+            String,
+        >,
+        annotator: Result<String, String>,
+        comment: Result<String, String>,
     }
-    impl Default for BuildMetadata {
+    impl Default for Spdx23SnippetsItemAnnotationsItem {
         fn default() -> Self {
             Self {
-                finished_on: Ok(Default::default()),
-                invocation_id: Ok(Default::default()),
-                started_on: Ok(Default::default()),
+                annotation_date: Err(
+                    "no value supplied for annotation_date".to_string(),
+                ),
+                annotation_type: Err(
+                    "no value supplied for annotation_type".to_string(),
+                ),
+                annotator: Err("no value supplied for annotator".to_string()),
+                comment: Err("no value supplied for comment".to_string()),
             }
         }
     }
-    impl BuildMetadata {
-        pub fn finished_on<T>(mut self, value: T) -> Self
+    impl Spdx23SnippetsItemAnnotationsItem {
+        pub fn annotation_date<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .finished_on = value
+            self.annotation_date = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for finished_on: {}", e)
+                    format!("error converting supplied value for annotation_date: {}", e)
                 });
             self
         }
-        pub fn invocation_id<T>(mut self, value: T) -> Self
+        pub fn annotation_type<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<String>>,
+            T: std::convert::TryInto<
+                super::Spdx23SnippetsItemAnnotationsItemAnnotationType,
+            >,
             T::Error: std::fmt::Display,
         {
-            self
-                .invocation_id = value
+            self.annotation_type = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for invocation_id: {}", e)
+                    format!("error converting supplied value for annotation_type: {}", e)
                 });
             self
         }
-        pub fn started_on<T>(mut self, value: T) -> Self
+        pub fn annotator<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
+            self.annotator = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for annotator: {}", e)
+                });
             self
-                .started_on = value
+        }
+        pub fn comment<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.comment = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for started_on: {}", e)
+                    format!("error converting supplied value for comment: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<BuildMetadata> for super::BuildMetadata {
+    impl std::convert::TryFrom<Spdx23SnippetsItemAnnotationsItem>
+    for super::Spdx23SnippetsItemAnnotationsItem {
         type Error = String;
-        fn try_from(value: BuildMetadata) -> Result<Self, String> {
+        fn try_from(value: Spdx23SnippetsItemAnnotationsItem) -> Result<Self, String> {
             Ok(Self {
-                finished_on: value.finished_on?,
-                invocation_id: value.invocation_id?,
-                started_on: value.started_on?,
+                annotation_date: value.annotation_date?,
+                annotation_type: value.annotation_type?,
+                annotator: value.annotator?,
+                comment: value.comment?,
             })
         }
     }
-    impl From<super::BuildMetadata> for BuildMetadata {
-        fn from(value: super::BuildMetadata) -> Self {
+    impl From<super::Spdx23SnippetsItemAnnotationsItem>
+    for Spdx23SnippetsItemAnnotationsItem {
+        fn from(value: super::Spdx23SnippetsItemAnnotationsItem) -> Self {
             Self {
-                finished_on: Ok(value.finished_on),
-                invocation_id: Ok(value.invocation_id),
-                started_on: Ok(value.started_on),
+                annotation_date: Ok(value.annotation_date),
+                annotation_type: Ok(value.annotation_type),
+                annotator: Ok(value.annotator),
+                comment: Ok(value.comment),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct BuildMetadata2 {
-        build_finished_on: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-        build_invocation_id: Result<Option<String>, String>,
-        build_started_on: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-        completeness: Result<Option<super::Completeness>, String>,
-        reproducible: Result<Option<bool>, String>,
+    pub struct Spdx23SnippetsItemRangesItem {
+        end_pointer: Result<super::Spdx23SnippetsItemRangesItemEndPointer, String>,
+        start_pointer: Result<super::Spdx23SnippetsItemRangesItemStartPointer, String>,
     }
-    impl Default for BuildMetadata2 {
+    impl Default for Spdx23SnippetsItemRangesItem {
         fn default() -> Self {
             Self {
-                build_finished_on: Ok(Default::default()),
-                build_invocation_id: Ok(Default::default()),
-                build_started_on: Ok(Default::default()),
-                completeness: Ok(Default::default()),
-                reproducible: Ok(Default::default()),
+                end_pointer: Err("no value supplied for end_pointer".to_string()),
+                start_pointer: Err("no value supplied for start_pointer".to_string()),
             }
         }
     }
-    impl BuildMetadata2 {
-        pub fn build_finished_on<T>(mut self, value: T) -> Self
+    impl Spdx23SnippetsItemRangesItem {
+        pub fn end_pointer<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T: std::convert::TryInto<super::Spdx23SnippetsItemRangesItemEndPointer>,
             T::Error: std::fmt::Display,
         {
-            self
-                .build_finished_on = value
+            self.end_pointer = value
                 .try_into()
                 .map_err(|e| {
-                    format!(
-                        "error converting supplied value for build_finished_on: {}", e
-                    )
+                    format!("error converting supplied value for end_pointer: {}", e)
                 });
             self
         }
-        pub fn build_invocation_id<T>(mut self, value: T) -> Self
+        pub fn start_pointer<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<String>>,
+            T: std::convert::TryInto<super::Spdx23SnippetsItemRangesItemStartPointer>,
             T::Error: std::fmt::Display,
         {
-            self
-                .build_invocation_id = value
+            self.start_pointer = value
                 .try_into()
                 .map_err(|e| {
-                    format!(
-                        "error converting supplied value for build_invocation_id: {}", e
-                    )
+                    format!("error converting supplied value for start_pointer: {}", e)
                 });
             self
         }
-        pub fn build_started_on<T>(mut self, value: T) -> Self
+    }
+    impl std::convert::TryFrom<Spdx23SnippetsItemRangesItem>
+    for super::Spdx23SnippetsItemRangesItem {
+        type Error = String;
+        fn try_from(value: Spdx23SnippetsItemRangesItem) -> Result<Self, String> {
+            Ok(Self {
+                end_pointer: value.end_pointer?,
+                start_pointer: value.start_pointer?,
+            })
+        }
+    }
+    impl From<super::Spdx23SnippetsItemRangesItem> for Spdx23SnippetsItemRangesItem {
+        fn from(value: super::Spdx23SnippetsItemRangesItem) -> Self {
+            Self {
+                end_pointer: Ok(value.end_pointer),
+                start_pointer: Ok(value.start_pointer),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Spdx23SnippetsItemRangesItemEndPointer {
+        line_number: Result<Option<i64>, String>,
+        offset: Result<Option<i64>, String>,
+        reference: Result<String, String>,
+    }
+    impl Default for Spdx23SnippetsItemRangesItemEndPointer {
+        fn default() -> Self {
+            Self {
+                line_number: Ok(Default::default()),
+                offset: Ok(Default::default()),
+                reference: Err("no value supplied for reference".to_string()),
+            }
+        }
+    }
+    impl Spdx23SnippetsItemRangesItemEndPointer {
+        pub fn line_number<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+            T: std::convert::TryInto<Option<i64>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .build_started_on = value
+            self.line_number = value
                 .try_into()
                 .map_err(|e| {
-                    format!(
-                        "error converting supplied value for build_started_on: {}", e
-                    )
+                    format!("error converting supplied value for line_number: {}", e)
                 });
             self
         }
-        pub fn completeness<T>(mut self, value: T) -> Self
+        pub fn offset<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::Completeness>>,
+            T: std::convert::TryInto<Option<i64>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .completeness = value
+            self.offset = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for completeness: {}", e)
+                    format!("error converting supplied value for offset: {}", e)
                 });
             self
         }
-        pub fn reproducible<T>(mut self, value: T) -> Self
+        pub fn reference<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<bool>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .reproducible = value
+            self.reference = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for reproducible: {}", e)
+                    format!("error converting supplied value for reference: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<BuildMetadata2> for super::BuildMetadata2 {
+    impl std::convert::TryFrom<Spdx23SnippetsItemRangesItemEndPointer>
+    for super::Spdx23SnippetsItemRangesItemEndPointer {
         type Error = String;
-        fn try_from(value: BuildMetadata2) -> Result<Self, String> {
+        fn try_from(
+            value: Spdx23SnippetsItemRangesItemEndPointer,
+        ) -> Result<Self, String> {
             Ok(Self {
-                build_finished_on: value.build_finished_on?,
-                build_invocation_id: value.build_invocation_id?,
-                build_started_on: value.build_started_on?,
-                completeness: value.completeness?,
-                reproducible: value.reproducible?,
+                line_number: value.line_number?,
+                offset: value.offset?,
+                reference: value.reference?,
             })
         }
     }
-    impl From<super::BuildMetadata2> for BuildMetadata2 {
-        fn from(value: super::BuildMetadata2) -> Self {
+    impl From<super::Spdx23SnippetsItemRangesItemEndPointer>
+    for Spdx23SnippetsItemRangesItemEndPointer {
+        fn from(value: super::Spdx23SnippetsItemRangesItemEndPointer) -> Self {
             Self {
-                build_finished_on: Ok(value.build_finished_on),
-                build_invocation_id: Ok(value.build_invocation_id),
-                build_started_on: Ok(value.build_started_on),
-                completeness: Ok(value.completeness),
-                reproducible: Ok(value.reproducible),
+                line_number: Ok(value.line_number),
+                offset: Ok(value.offset),
+                reference: Ok(value.reference),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct Builder {
-        builder_dependencies: Result<Option<Vec<super::ResourceDescriptor>>, String>,
-        id: Result<String, String>,
-        version: Result<Option<String>, String>,
+    pub struct Spdx23SnippetsItemRangesItemStartPointer {
+        line_number: Result<Option<i64>, String>,
+        offset: Result<Option<i64>, String>,
+        reference: Result<String, String>,
     }
-    impl Default for Builder {
+    impl Default for Spdx23SnippetsItemRangesItemStartPointer {
         fn default() -> Self {
             Self {
-                builder_dependencies: Ok(Default::default()),
-                id: Err("no value supplied for id".to_string()),
-                version: Ok(Default::default()),
+                line_number: Ok(Default::default()),
+                offset: Ok(Default::default()),
+                reference: Err("no value supplied for reference".to_string()),
             }
         }
     }
-    impl Builder {
-        pub fn builder_dependencies<T>(mut self, value: T) -> Self
+    impl Spdx23SnippetsItemRangesItemStartPointer {
+        pub fn line_number<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor>>>,
+            T: std::convert::TryInto<Option<i64>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .builder_dependencies = value
+            self.line_number = value
                 .try_into()
                 .map_err(|e| {
-                    format!(
-                        "error converting supplied value for builder_dependencies: {}", e
-                    )
+                    format!("error converting supplied value for line_number: {}", e)
                 });
             self
         }
-        pub fn id<T>(mut self, value: T) -> Self
+        pub fn offset<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<String>,
+            T: std::convert::TryInto<Option<i64>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .id = value
+            self.offset = value
                 .try_into()
-                .map_err(|e| format!("error converting supplied value for id: {}", e));
+                .map_err(|e| {
+                    format!("error converting supplied value for offset: {}", e)
+                });
             self
         }
-        pub fn version<T>(mut self, value: T) -> Self
+        pub fn reference<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<String>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .version = value
+            self.reference = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for version: {}", e)
+                    format!("error converting supplied value for reference: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<Builder> for super::Builder {
+    impl std::convert::TryFrom<Spdx23SnippetsItemRangesItemStartPointer>
+    for super::Spdx23SnippetsItemRangesItemStartPointer {
         type Error = String;
-        fn try_from(value: Builder) -> Result<Self, String> {
+        fn try_from(
+            value: Spdx23SnippetsItemRangesItemStartPointer,
+        ) -> Result<Self, String> {
             Ok(Self {
-                builder_dependencies: value.builder_dependencies?,
-                id: value.id?,
-                version: value.version?,
+                line_number: value.line_number?,
+                offset: value.offset?,
+                reference: value.reference?,
             })
         }
     }
-    impl From<super::Builder> for Builder {
-        fn from(value: super::Builder) -> Self {
+    impl From<super::Spdx23SnippetsItemRangesItemStartPointer>
+    for Spdx23SnippetsItemRangesItemStartPointer {
+        fn from(value: super::Spdx23SnippetsItemRangesItemStartPointer) -> Self {
             Self {
-                builder_dependencies: Ok(value.builder_dependencies),
-                id: Ok(value.id),
-                version: Ok(value.version),
+                line_number: Ok(value.line_number),
+                offset: Ok(value.offset),
+                reference: Ok(value.reference),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct Builder2 {
-        id: Result<String, String>,
+    pub struct Subject {
+        digest: Result<super::DigestSet, String>,
+        name: Result<String, String>,
     }
-    impl Default for Builder2 {
+    impl Default for Subject {
         fn default() -> Self {
             Self {
-                id: Err("no value supplied for id".to_string()),
+                digest: Err("no value supplied for digest".to_string()),
+                name: Err("no value supplied for name".to_string()),
             }
         }
     }
-    impl Builder2 {
-        pub fn id<T>(mut self, value: T) -> Self
+    impl Subject {
+        pub fn digest<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<String>,
+            T: std::convert::TryInto<super::DigestSet>,
             T::Error: std::fmt::Display,
         {
+            self.digest = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for digest: {}", e)
+                });
             self
-                .id = value
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.name = value
                 .try_into()
-                .map_err(|e| format!("error converting supplied value for id: {}", e));
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
             self
         }
     }
-    impl std::convert::TryFrom<Builder2> for super::Builder2 {
+    impl std::convert::TryFrom<Subject> for super::Subject {
         type Error = String;
-        fn try_from(value: Builder2) -> Result<Self, String> {
-            Ok(Self { id: value.id? })
+        fn try_from(value: Subject) -> Result<Self, String> {
+            Ok(Self {
+                digest: value.digest?,
+                name: value.name?,
+            })
         }
     }
-    impl From<super::Builder2> for Builder2 {
-        fn from(value: super::Builder2) -> Self {
-            Self { id: Ok(value.id) }
+    impl From<super::Subject> for Subject {
+        fn from(value: super::Subject) -> Self {
+            Self {
+                digest: Ok(value.digest),
+                name: Ok(value.name),
+            }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct Completeness {
-        environment: Result<Option<bool>, String>,
-        materials: Result<Option<bool>, String>,
-        parameters: Result<Option<bool>, String>,
+    pub struct Subpackage {
+        description: Result<Option<String>, String>,
+        name: Result<String, String>,
     }
-    impl Default for Completeness {
+    impl Default for Subpackage {
         fn default() -> Self {
             Self {
-                environment: Ok(Default::default()),
-                materials: Ok(Default::default()),
-                parameters: Ok(Default::default()),
+                description: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
             }
         }
     }
-    impl Completeness {
-        pub fn environment<T>(mut self, value: T) -> Self
-        where
-            T: std::convert::TryInto<Option<bool>>,
-            T::Error: std::fmt::Display,
-        {
-            self
-                .environment = value
-                .try_into()
-                .map_err(|e| {
-                    format!("error converting supplied value for environment: {}", e)
-                });
-            self
-        }
-        pub fn materials<T>(mut self, value: T) -> Self
+    impl Subpackage {
+        pub fn description<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<bool>>,
+            T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .materials = value
+            self.description = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for materials: {}", e)
+                    format!("error converting supplied value for description: {}", e)
                 });
             self
         }
-        pub fn parameters<T>(mut self, value: T) -> Self
+        pub fn name<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<bool>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .parameters = value
+            self.name = value
                 .try_into()
-                .map_err(|e| {
-                    format!("error converting supplied value for parameters: {}", e)
-                });
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
             self
         }
     }
-    impl std::convert::TryFrom<Completeness> for super::Completeness {
+    impl std::convert::TryFrom<Subpackage> for super::Subpackage {
         type Error = String;
-        fn try_from(value: Completeness) -> Result<Self, String> {
+        fn try_from(value: Subpackage) -> Result<Self, String> {
             Ok(Self {
-                environment: value.environment?,
-                materials: value.materials?,
-                parameters: value.parameters?,
+                description: value.description?,
+                name: value.name?,
             })
         }
     }
-    impl From<super::Completeness> for Completeness {
-        fn from(value: super::Completeness) -> Self {
+    impl From<super::Subpackage> for Subpackage {
+        fn from(value: super::Subpackage) -> Self {
             Self {
-                environment: Ok(value.environment),
-                materials: Ok(value.materials),
-                parameters: Ok(value.parameters),
+                description: Ok(value.description),
+                name: Ok(value.name),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct ConfigSource {
-        digest: Result<Option<std::collections::HashMap<String, String>>, String>,
-        entry_point: Result<Option<String>, String>,
-        uri: Result<Option<String>, String>,
+    pub struct TestResultPredicate {
+        configuration: Result<Vec<super::ResourceDescriptor>, String>,
+        failed_tests: Result<Vec<String>, String>,
+        passed_tests: Result<Vec<String>, String>,
+        result: Result<super::TestResult, String>,
+        url: Result<Option<String>, String>,
+        warned_tests: Result<Vec<String>, String>,
     }
-    impl Default for ConfigSource {
+    impl Default for TestResultPredicate {
         fn default() -> Self {
             Self {
-                digest: Ok(Default::default()),
-                entry_point: Ok(Default::default()),
-                uri: Ok(Default::default()),
+                configuration: Err("no value supplied for configuration".to_string()),
+                failed_tests: Ok(Default::default()),
+                passed_tests: Ok(Default::default()),
+                result: Err("no value supplied for result".to_string()),
+                url: Ok(Default::default()),
+                warned_tests: Ok(Default::default()),
             }
         }
     }
-    impl ConfigSource {
-        pub fn digest<T>(mut self, value: T) -> Self
+    impl TestResultPredicate {
+        pub fn configuration<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T: std::convert::TryInto<Vec<super::ResourceDescriptor>>,
             T::Error: std::fmt::Display,
         {
+            self.configuration = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for configuration: {}", e)
+                });
             self
-                .digest = value
+        }
+        pub fn failed_tests<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.failed_tests = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for digest: {}", e)
+                    format!("error converting supplied value for failed_tests: {}", e)
                 });
             self
         }
-        pub fn entry_point<T>(mut self, value: T) -> Self
+        pub fn passed_tests<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<String>>,
+            T: std::convert::TryInto<Vec<String>>,
             T::Error: std::fmt::Display,
         {
+            self.passed_tests = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for passed_tests: {}", e)
+                });
             self
-                .entry_point = value
+        }
+        pub fn result<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::TestResult>,
+            T::Error: std::fmt::Display,
+        {
+            self.result = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for entry_point: {}", e)
+                    format!("error converting supplied value for result: {}", e)
                 });
             self
         }
-        pub fn uri<T>(mut self, value: T) -> Self
+        pub fn url<T>(mut self, value: T) -> Self
         where
             T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
+            self.url = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for url: {}", e));
             self
-                .uri = value
+        }
+        pub fn warned_tests<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<String>>,
+            T::Error: std::fmt::Display,
+        {
+            self.warned_tests = value
                 .try_into()
-                .map_err(|e| format!("error converting supplied value for uri: {}", e));
+                .map_err(|e| {
+                    format!("error converting supplied value for warned_tests: {}", e)
+                });
             self
         }
     }
-    impl std::convert::TryFrom<ConfigSource> for super::ConfigSource {
+    impl std::convert::TryFrom<TestResultPredicate> for super::TestResultPredicate {
         type Error = String;
-        fn try_from(value: ConfigSource) -> Result<Self, String> {
+        fn try_from(value: TestResultPredicate) -> Result<Self, String> {
             Ok(Self {
-                digest: value.digest?,
-                entry_point: value.entry_point?,
-                uri: value.uri?,
+                configuration: value.configuration?,
+                failed_tests: value.failed_tests?,
+                passed_tests: value.passed_tests?,
+                result: value.result?,
+                url: value.url?,
+                warned_tests: value.warned_tests?,
             })
         }
     }
-    impl From<super::ConfigSource> for ConfigSource {
-        fn from(value: super::ConfigSource) -> Self {
+    impl From<super::TestResultPredicate> for TestResultPredicate {
+        fn from(value: super::TestResultPredicate) -> Self {
             Self {
-                digest: Ok(value.digest),
-                entry_point: Ok(value.entry_point),
-                uri: Ok(value.uri),
+                configuration: Ok(value.configuration),
+                failed_tests: Ok(value.failed_tests),
+                passed_tests: Ok(value.passed_tests),
+                result: Ok(value.result),
+                url: Ok(value.url),
+                warned_tests: Ok(value.warned_tests),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct InTotoStatementV1ForPredicate {
-        predicate: Result<super::Predicate, String>,
-        predicate_type: Result<String, String>,
-        subject: Result<Vec<super::Subject>, String>,
-        type_: Result<String, String>,
+    pub struct Threat {
+        category: Result<String, String>,
+        date: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+        details: Result<String, String>,
+        group_ids: Result<Vec<String>, String>,
+        product_ids: Result<Vec<String>, String>,
     }
-    impl Default for InTotoStatementV1ForPredicate {
+    impl Default for Threat {
         fn default() -> Self {
             Self {
-                predicate: Err("no value supplied for predicate".to_string()),
-                predicate_type: Err("no value supplied for predicate_type".to_string()),
-                subject: Err("no value supplied for subject".to_string()),
-                type_: Err("no value supplied for type_".to_string()),
+                category: Err("no value supplied for category".to_string()),
+                date: Ok(Default::default()),
+                details: Err("no value supplied for details".to_string()),
+                group_ids: Ok(Default::default()),
+                product_ids: Ok(Default::default()),
             }
         }
     }
-    impl InTotoStatementV1ForPredicate {
-        pub fn predicate<T>(mut self, value: T) -> Self
+    impl Threat {
+        pub fn category<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<super::Predicate>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .predicate = value
+            self.category = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for predicate: {}", e)
+                    format!("error converting supplied value for category: {}", e)
                 });
             self
         }
-        pub fn predicate_type<T>(mut self, value: T) -> Self
+        pub fn date<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<String>,
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
             T::Error: std::fmt::Display,
         {
+            self.date = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for date: {}", e));
             self
-                .predicate_type = value
+        }
+        pub fn details<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.details = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for predicate_type: {}", e)
+                    format!("error converting supplied value for details: {}", e)
                 });
             self
         }
-        pub fn subject<T>(mut self, value: T) -> Self
+        pub fn group_ids<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Vec<super::Subject>>,
+            T: std::convert::TryInto<Vec<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .subject = value
+            self.group_ids = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for subject: {}", e)
+                    format!("error converting supplied value for group_ids: {}", e)
                 });
             self
         }
-        pub fn type_<T>(mut self, value: T) -> Self
+        pub fn product_ids<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<String>,
+            T: std::convert::TryInto<Vec<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .type_ = value
+            self.product_ids = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for type_: {}", e)
+                    format!("error converting supplied value for product_ids: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<InTotoStatementV1ForPredicate>
-    for super::InTotoStatementV1ForPredicate {
+    impl std::convert::TryFrom<Threat> for super::Threat {
         type Error = String;
-        fn try_from(value: InTotoStatementV1ForPredicate) -> Result<Self, String> {
+        fn try_from(value: Threat) -> Result<Self, String> {
             Ok(Self {
-                predicate: value.predicate?,
-                predicate_type: value.predicate_type?,
-                subject: value.subject?,
-                type_: value.type_?,
+                category: value.category?,
+                date: value.date?,
+                details: value.details?,
+                group_ids: value.group_ids?,
+                product_ids: value.product_ids?,
             })
         }
     }
-    impl From<super::InTotoStatementV1ForPredicate> for InTotoStatementV1ForPredicate {
-        fn from(value: super::InTotoStatementV1ForPredicate) -> Self {
+    impl From<super::Threat> for Threat {
+        fn from(value: super::Threat) -> Self {
             Self {
-                predicate: Ok(value.predicate),
-                predicate_type: Ok(value.predicate_type),
-                subject: Ok(value.subject),
-                type_: Ok(value.type_),
+                category: Ok(value.category),
+                date: Ok(value.date),
+                details: Ok(value.details),
+                group_ids: Ok(value.group_ids),
+                product_ids: Ok(value.product_ids),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct Invocation {
-        config_source: Result<Option<super::ConfigSource>, String>,
-        environment: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
-        parameters: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
+    pub struct Tool {
+        driver: Result<super::ToolComponent, String>,
+        extensions: Result<Vec<super::ToolComponent>, String>,
     }
-    impl Default for Invocation {
+    impl Default for Tool {
         fn default() -> Self {
             Self {
-                config_source: Ok(Default::default()),
-                environment: Ok(Default::default()),
-                parameters: Ok(Default::default()),
+                driver: Err("no value supplied for driver".to_string()),
+                extensions: Ok(Default::default()),
             }
         }
     }
-    impl Invocation {
-        pub fn config_source<T>(mut self, value: T) -> Self
-        where
-            T: std::convert::TryInto<Option<super::ConfigSource>>,
-            T::Error: std::fmt::Display,
-        {
-            self
-                .config_source = value
-                .try_into()
-                .map_err(|e| {
-                    format!("error converting supplied value for config_source: {}", e)
-                });
-            self
-        }
-        pub fn environment<T>(mut self, value: T) -> Self
+    impl Tool {
+        pub fn driver<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T: std::convert::TryInto<super::ToolComponent>,
             T::Error: std::fmt::Display,
         {
-            self
-                .environment = value
+            self.driver = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for environment: {}", e)
+                    format!("error converting supplied value for driver: {}", e)
                 });
             self
         }
-        pub fn parameters<T>(mut self, value: T) -> Self
+        pub fn extensions<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T: std::convert::TryInto<Vec<super::ToolComponent>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .parameters = value
+            self.extensions = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for parameters: {}", e)
+                    format!("error converting supplied value for extensions: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<Invocation> for super::Invocation {
+    impl std::convert::TryFrom<Tool> for super::Tool {
         type Error = String;
-        fn try_from(value: Invocation) -> Result<Self, String> {
+        fn try_from(value: Tool) -> Result<Self, String> {
             Ok(Self {
-                config_source: value.config_source?,
-                environment: value.environment?,
-                parameters: value.parameters?,
+                driver: value.driver?,
+                extensions: value.extensions?,
             })
         }
     }
-    impl From<super::Invocation> for Invocation {
-        fn from(value: super::Invocation) -> Self {
+    impl From<super::Tool> for Tool {
+        fn from(value: super::Tool) -> Self {
             Self {
-                config_source: Ok(value.config_source),
-                environment: Ok(value.environment),
-                parameters: Ok(value.parameters),
+                driver: Ok(value.driver),
+                extensions: Ok(value.extensions),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct Predicate {
-        subtype_0: Result<Option<super::SlsaProvenanceV1Predicate>, String>,
-        subtype_1: Result<Option<super::SlsaProvenanceV02Predicate>, String>,
-        subtype_2: Result<Option<super::Scaiv02Predicate>, String>,
-        subtype_3: Result<Option<serde_json::Value>, String>,
+    pub struct ToolComponent {
+        information_uri: Result<Option<String>, String>,
+        name: Result<String, String>,
+        rules: Result<Vec<super::ReportingDescriptor>, String>,
+        semantic_version: Result<Option<String>, String>,
+        version: Result<Option<String>, String>,
     }
-    impl Default for Predicate {
+    impl Default for ToolComponent {
         fn default() -> Self {
             Self {
-                subtype_0: Ok(Default::default()),
-                subtype_1: Ok(Default::default()),
-                subtype_2: Ok(Default::default()),
-                subtype_3: Ok(Default::default()),
+                information_uri: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                rules: Ok(Default::default()),
+                semantic_version: Ok(Default::default()),
+                version: Ok(Default::default()),
             }
         }
     }
-    impl Predicate {
-        pub fn subtype_0<T>(mut self, value: T) -> Self
+    impl ToolComponent {
+        pub fn information_uri<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::SlsaProvenanceV1Predicate>>,
+            T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .subtype_0 = value
+            self.information_uri = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for subtype_0: {}", e)
+                    format!("error converting supplied value for information_uri: {}", e)
                 });
             self
         }
-        pub fn subtype_1<T>(mut self, value: T) -> Self
+        pub fn name<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::SlsaProvenanceV02Predicate>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {}", e));
             self
-                .subtype_1 = value
+        }
+        pub fn rules<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Vec<super::ReportingDescriptor>>,
+            T::Error: std::fmt::Display,
+        {
+            self.rules = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for subtype_1: {}", e)
+                    format!("error converting supplied value for rules: {}", e)
                 });
             self
         }
-        pub fn subtype_2<T>(mut self, value: T) -> Self
+        pub fn semantic_version<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::Scaiv02Predicate>>,
+            T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .subtype_2 = value
+            self.semantic_version = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for subtype_2: {}", e)
+                    format!(
+                        "error converting supplied value for semantic_version: {}", e
+                    )
                 });
             self
         }
-        pub fn subtype_3<T>(mut self, value: T) -> Self
+        pub fn version<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .subtype_3 = value
+            self.version = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for subtype_3: {}", e)
+                    format!("error converting supplied value for version: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<Predicate> for super::Predicate {
+    impl std::convert::TryFrom<ToolComponent> for super::ToolComponent {
         type Error = String;
-        fn try_from(value: Predicate) -> Result<Self, String> {
+        fn try_from(value: ToolComponent) -> Result<Self, String> {
             Ok(Self {
-                subtype_0: value.subtype_0?,
-                subtype_1: value.subtype_1?,
-                subtype_2: value.subtype_2?,
-                subtype_3: value.subtype_3?,
+                information_uri: value.information_uri?,
+                name: value.name?,
+                rules: value.rules?,
+                semantic_version: value.semantic_version?,
+                version: value.version?,
             })
         }
     }
-    impl From<super::Predicate> for Predicate {
-        fn from(value: super::Predicate) -> Self {
+    impl From<super::ToolComponent> for ToolComponent {
+        fn from(value: super::ToolComponent) -> Self {
             Self {
-                subtype_0: Ok(value.subtype_0),
-                subtype_1: Ok(value.subtype_1),
-                subtype_2: Ok(value.subtype_2),
-                subtype_3: Ok(value.subtype_3),
+                information_uri: Ok(value.information_uri),
+                name: Ok(value.name),
+                rules: Ok(value.rules),
+                semantic_version: Ok(value.semantic_version),
+                version: Ok(value.version),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct ResourceDescriptor {
-        annotations: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
-        content: Result<Option<String>, String>,
-        digest: Result<Option<std::collections::HashMap<String, String>>, String>,
-        download_location: Result<Option<String>, String>,
-        media_type: Result<Option<String>, String>,
-        name: Result<Option<String>, String>,
-        uri: Result<String, String>,
+    pub struct Tracking {
+        aliases: Result<Vec<String>, String>,
+        current_release_date: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        generator: Result<Option<serde_json::Value>, String>,
+        id: Result<String, String>,
+        initial_release_date: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        revision_history: Result<Vec<super::Revision>, String>,
+        status: Result<super::TrackingStatus, String>,
+        version: Result<String, String>,
     }
-    impl Default for ResourceDescriptor {
+    impl Default for Tracking {
         fn default() -> Self {
             Self {
-                annotations: Ok(Default::default()),
-                content: Ok(Default::default()),
-                digest: Ok(Default::default()),
-                download_location: Ok(Default::default()),
-                media_type: Ok(Default::default()),
-                name: Ok(Default::default()),
-                uri: Err("no value supplied for uri".to_string()),
+                aliases: Ok(Default::default()),
+                current_release_date: Err(
+                    "no value supplied for current_release_date".to_string(),
+                ),
+                generator: Ok(Default::default()),
+                id: Err("no value supplied for id".to_string()),
+                initial_release_date: Err(
+                    "no value supplied for initial_release_date".to_string(),
+                ),
+                revision_history: Err(
+                    "no value supplied for revision_history".to_string(),
+                ),
+                status: Err("no value supplied for status".to_string()),
+                version: Err("no value supplied for version".to_string()),
             }
         }
     }
-    impl ResourceDescriptor {
-        pub fn annotations<T>(mut self, value: T) -> Self
+    impl Tracking {
+        pub fn aliases<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
+            T: std::convert::TryInto<Vec<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .annotations = value
+            self.aliases = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for annotations: {}", e)
+                    format!("error converting supplied value for aliases: {}", e)
                 });
             self
         }
-        pub fn content<T>(mut self, value: T) -> Self
+        pub fn current_release_date<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<String>>,
+            T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
             T::Error: std::fmt::Display,
         {
+            self.current_release_date = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for current_release_date: {}", e
+                    )
+                });
             self
-                .content = value
+        }
+        pub fn generator<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<serde_json::Value>>,
+            T::Error: std::fmt::Display,
+        {
+            self.generator = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for content: {}", e)
+                    format!("error converting supplied value for generator: {}", e)
                 });
             self
         }
-        pub fn digest<T>(mut self, value: T) -> Self
+        pub fn id<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
             self
-                .digest = value
+        }
+        pub fn initial_release_date<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+            T::Error: std::fmt::Display,
+        {
+            self.initial_release_date = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for digest: {}", e)
+                    format!(
+                        "error converting supplied value for initial_release_date: {}", e
+                    )
                 });
             self
         }
-        pub fn download_location<T>(mut self, value: T) -> Self
+        pub fn revision_history<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<String>>,
+            T: std::convert::TryInto<Vec<super::Revision>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .download_location = value
+            self.revision_history = value
                 .try_into()
                 .map_err(|e| {
                     format!(
-                        "error converting supplied value for download_location: {}", e
+                        "error converting supplied value for revision_history: {}", e
                     )
                 });
             self
         }
-        pub fn media_type<T>(mut self, value: T) -> Self
+        pub fn status<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<String>>,
+            T: std::convert::TryInto<super::TrackingStatus>,
             T::Error: std::fmt::Display,
         {
+            self.status = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for status: {}", e)
+                });
             self
-                .media_type = value
+        }
+        pub fn version<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.version = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for media_type: {}", e)
+                    format!("error converting supplied value for version: {}", e)
                 });
             self
         }
-        pub fn name<T>(mut self, value: T) -> Self
+    }
+    impl std::convert::TryFrom<Tracking> for super::Tracking {
+        type Error = String;
+        fn try_from(value: Tracking) -> Result<Self, String> {
+            Ok(Self {
+                aliases: value.aliases?,
+                current_release_date: value.current_release_date?,
+                generator: value.generator?,
+                id: value.id?,
+                initial_release_date: value.initial_release_date?,
+                revision_history: value.revision_history?,
+                status: value.status?,
+                version: value.version?,
+            })
+        }
+    }
+    impl From<super::Tracking> for Tracking {
+        fn from(value: super::Tracking) -> Self {
+            Self {
+                aliases: Ok(value.aliases),
+                current_release_date: Ok(value.current_release_date),
+                generator: Ok(value.generator),
+                id: Ok(value.id),
+                initial_release_date: Ok(value.initial_release_date),
+                revision_history: Ok(value.revision_history),
+                status: Ok(value.status),
+                version: Ok(value.version),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct User {
+        gid: Result<Option<u32>, String>,
+        uid: Result<u32, String>,
+        username: Result<String, String>,
+    }
+    impl Default for User {
+        fn default() -> Self {
+            Self {
+                gid: Ok(Default::default()),
+                uid: Err("no value supplied for uid".to_string()),
+                username: Err("no value supplied for username".to_string()),
+            }
+        }
+    }
+    impl User {
+        pub fn gid<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<String>>,
+            T: std::convert::TryInto<Option<u32>>,
             T::Error: std::fmt::Display,
         {
+            self.gid = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for gid: {}", e));
             self
-                .name = value
+        }
+        pub fn uid<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<u32>,
+            T::Error: std::fmt::Display,
+        {
+            self.uid = value
                 .try_into()
-                .map_err(|e| format!("error converting supplied value for name: {}", e));
+                .map_err(|e| format!("error converting supplied value for uid: {}", e));
             self
         }
-        pub fn uri<T>(mut self, value: T) -> Self
+        pub fn username<T>(mut self, value: T) -> Self
         where
             T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .uri = value
+            self.username = value
                 .try_into()
-                .map_err(|e| format!("error converting supplied value for uri: {}", e));
+                .map_err(|e| {
+                    format!("error converting supplied value for username: {}", e)
+                });
             self
         }
     }
-    impl std::convert::TryFrom<ResourceDescriptor> for super::ResourceDescriptor {
+    impl std::convert::TryFrom<User> for super::User {
         type Error = String;
-        fn try_from(value: ResourceDescriptor) -> Result<Self, String> {
+        fn try_from(value: User) -> Result<Self, String> {
             Ok(Self {
-                annotations: value.annotations?,
-                content: value.content?,
-                digest: value.digest?,
-                download_location: value.download_location?,
-                media_type: value.media_type?,
-                name: value.name?,
-                uri: value.uri?,
+                gid: value.gid?,
+                uid: value.uid?,
+                username: value.username?,
             })
         }
     }
-    impl From<super::ResourceDescriptor> for ResourceDescriptor {
-        fn from(value: super::ResourceDescriptor) -> Self {
+    impl From<super::User> for User {
+        fn from(value: super::User) -> Self {
             Self {
-                annotations: Ok(value.annotations),
-                content: Ok(value.content),
-                digest: Ok(value.digest),
-                download_location: Ok(value.download_location),
-                media_type: Ok(value.media_type),
-                name: Ok(value.name),
-                uri: Ok(value.uri),
+                gid: Ok(value.gid),
+                uid: Ok(value.uid),
+                username: Ok(value.username),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct ResourceDescriptor2 {
-        digest: Result<Option<std::collections::HashMap<String, String>>, String>,
-        uri: Result<Option<String>, String>,
+    pub struct VerificationSummaryV1Predicate {
+        dependency_levels: Result<
+            Option<std::collections::HashMap<String, u64>>,
+            String,
+        >,
+        input_attestations: Result<Option<Vec<super::ResourceDescriptor>>, String>,
+        policy: Result<super::ResourceDescriptor, String>,
+        resource_uri: Result<String, String>,
+        slsa_version: Result<Option<String>, String>,
+        time_verified: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+        verification_result: Result<super::VerificationResult, String>,
+        verified_levels: Result<Vec<String>, String>,
+        verifier: Result<super::Verifier, String>,
     }
-    impl Default for ResourceDescriptor2 {
+    impl Default for VerificationSummaryV1Predicate {
         fn default() -> Self {
             Self {
-                digest: Ok(Default::default()),
-                uri: Ok(Default::default()),
+                dependency_levels: Ok(Default::default()),
+                input_attestations: Ok(Default::default()),
+                policy: Err("no value supplied for policy".to_string()),
+                resource_uri: Err("no value supplied for resource_uri".to_string()),
+                slsa_version: Ok(Default::default()),
+                time_verified: Ok(Default::default()),
+                verification_result: Err(
+                    "no value supplied for verification_result".to_string(),
+                ),
+                verified_levels: Err(
+                    "no value supplied for verified_levels".to_string(),
+                ),
+                verifier: Err("no value supplied for verifier".to_string()),
             }
         }
-    }
-    impl ResourceDescriptor2 {
-        pub fn digest<T>(mut self, value: T) -> Self
+    }
+    impl VerificationSummaryV1Predicate {
+        pub fn dependency_levels<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<std::collections::HashMap<String, u64>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.dependency_levels = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for dependency_levels: {}", e
+                    )
+                });
+            self
+        }
+        pub fn input_attestations<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor>>>,
+            T::Error: std::fmt::Display,
+        {
+            self.input_attestations = value
+                .try_into()
+                .map_err(|e| {
+                    format!(
+                        "error converting supplied value for input_attestations: {}", e
+                    )
+                });
+            self
+        }
+        pub fn policy<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
+            T: std::convert::TryInto<super::ResourceDescriptor>,
             T::Error: std::fmt::Display,
         {
+            self.policy = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for policy: {}", e)
+                });
             self
-                .digest = value
+        }
+        pub fn resource_uri<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<String>,
+            T::Error: std::fmt::Display,
+        {
+            self.resource_uri = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for digest: {}", e)
+                    format!("error converting supplied value for resource_uri: {}", e)
                 });
             self
         }
-        pub fn uri<T>(mut self, value: T) -> Self
+        pub fn slsa_version<T>(mut self, value: T) -> Self
         where
             T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .uri = value
+            self.slsa_version = value
                 .try_into()
-                .map_err(|e| format!("error converting supplied value for uri: {}", e));
+                .map_err(|e| {
+                    format!("error converting supplied value for slsa_version: {}", e)
+                });
             self
         }
-    }
-    impl std::convert::TryFrom<ResourceDescriptor2> for super::ResourceDescriptor2 {
-        type Error = String;
-        fn try_from(value: ResourceDescriptor2) -> Result<Self, String> {
-            Ok(Self {
-                digest: value.digest?,
-                uri: value.uri?,
-            })
-        }
-    }
-    impl From<super::ResourceDescriptor2> for ResourceDescriptor2 {
-        fn from(value: super::ResourceDescriptor2) -> Self {
-            Self {
-                digest: Ok(value.digest),
-                uri: Ok(value.uri),
-            }
-        }
-    }
-    #[derive(Clone, Debug)]
-    pub struct RunDetails {
-        builder: Result<super::Builder, String>,
-        byproducts: Result<Option<Vec<super::ResourceDescriptor>>, String>,
-        metadata: Result<Option<super::BuildMetadata>, String>,
-    }
-    impl Default for RunDetails {
-        fn default() -> Self {
-            Self {
-                builder: Err("no value supplied for builder".to_string()),
-                byproducts: Ok(Default::default()),
-                metadata: Ok(Default::default()),
-            }
-        }
-    }
-    impl RunDetails {
-        pub fn builder<T>(mut self, value: T) -> Self
+        pub fn time_verified<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<super::Builder>,
+            T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .builder = value
+            self.time_verified = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for builder: {}", e)
+                    format!("error converting supplied value for time_verified: {}", e)
                 });
             self
         }
-        pub fn byproducts<T>(mut self, value: T) -> Self
+        pub fn verification_result<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor>>>,
+            T: std::convert::TryInto<super::VerificationResult>,
             T::Error: std::fmt::Display,
         {
-            self
-                .byproducts = value
+            self.verification_result = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for byproducts: {}", e)
+                    format!(
+                        "error converting supplied value for verification_result: {}", e
+                    )
                 });
             self
         }
-        pub fn metadata<T>(mut self, value: T) -> Self
+        pub fn verified_levels<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::BuildMetadata>>,
+            T: std::convert::TryInto<Vec<String>>,
             T::Error: std::fmt::Display,
         {
+            self.verified_levels = value
+                .try_into()
+                .map_err(|e| {
+                    format!("error converting supplied value for verified_levels: {}", e)
+                });
             self
-                .metadata = value
+        }
+        pub fn verifier<T>(mut self, value: T) -> Self
+        where
+            T: std::convert::TryInto<super::Verifier>,
+            T::Error: std::fmt::Display,
+        {
+            self.verifier = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for metadata: {}", e)
+                    format!("error converting supplied value for verifier: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<RunDetails> for super::RunDetails {
+    impl std::convert::TryFrom<VerificationSummaryV1Predicate>
+    for super::VerificationSummaryV1Predicate {
         type Error = String;
-        fn try_from(value: RunDetails) -> Result<Self, String> {
+        fn try_from(value: VerificationSummaryV1Predicate) -> Result<Self, String> {
             Ok(Self {
-                builder: value.builder?,
-                byproducts: value.byproducts?,
-                metadata: value.metadata?,
+                dependency_levels: value.dependency_levels?,
+                input_attestations: value.input_attestations?,
+                policy: value.policy?,
+                resource_uri: value.resource_uri?,
+                slsa_version: value.slsa_version?,
+                time_verified: value.time_verified?,
+                verification_result: value.verification_result?,
+                verified_levels: value.verified_levels?,
+                verifier: value.verifier?,
             })
         }
     }
-    impl From<super::RunDetails> for RunDetails {
-        fn from(value: super::RunDetails) -> Self {
+    impl From<super::VerificationSummaryV1Predicate> for VerificationSummaryV1Predicate {
+        fn from(value: super::VerificationSummaryV1Predicate) -> Self {
             Self {
-                builder: Ok(value.builder),
-                byproducts: Ok(value.byproducts),
-                metadata: Ok(value.metadata),
+                dependency_levels: Ok(value.dependency_levels),
+                input_attestations: Ok(value.input_attestations),
+                policy: Ok(value.policy),
+                resource_uri: Ok(value.resource_uri),
+                slsa_version: Ok(value.slsa_version),
+                time_verified: Ok(value.time_verified),
+                verification_result: Ok(value.verification_result),
+                verified_levels: Ok(value.verified_levels),
+                verifier: Ok(value.verifier),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct Scaiv02Predicate {
-        attributes: Result<Vec<super::Attribute>, String>,
-        producer: Result<Option<super::ResourceDescriptor>, String>,
+    pub struct Verifier {
+        id: Result<String, String>,
+        version: Result<Option<std::collections::HashMap<String, String>>, String>,
     }
-    impl Default for Scaiv02Predicate {
+    impl Default for Verifier {
         fn default() -> Self {
             Self {
-                attributes: Err("no value supplied for attributes".to_string()),
-                producer: Ok(Default::default()),
+                id: Err("no value supplied for id".to_string()),
+                version: Ok(Default::default()),
             }
         }
     }
-    impl Scaiv02Predicate {
-        pub fn attributes<T>(mut self, value: T) -> Self
+    impl Verifier {
+        pub fn id<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Vec<super::Attribute>>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .attributes = value
+            self.id = value
                 .try_into()
-                .map_err(|e| {
-                    format!("error converting supplied value for attributes: {}", e)
-                });
+                .map_err(|e| format!("error converting supplied value for id: {}", e));
             self
         }
-        pub fn producer<T>(mut self, value: T) -> Self
+        pub fn version<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::ResourceDescriptor>>,
+            T: std::convert::TryInto<Option<std::collections::HashMap<String, String>>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .producer = value
+            self.version = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for producer: {}", e)
+                    format!("error converting supplied value for version: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<Scaiv02Predicate> for super::Scaiv02Predicate {
+    impl std::convert::TryFrom<Verifier> for super::Verifier {
         type Error = String;
-        fn try_from(value: Scaiv02Predicate) -> Result<Self, String> {
+        fn try_from(value: Verifier) -> Result<Self, String> {
             Ok(Self {
-                attributes: value.attributes?,
-                producer: value.producer?,
+                id: value.id?,
+                version: value.version?,
             })
         }
     }
-    impl From<super::Scaiv02Predicate> for Scaiv02Predicate {
-        fn from(value: super::Scaiv02Predicate) -> Self {
+    impl From<super::Verifier> for Verifier {
+        fn from(value: super::Verifier) -> Self {
             Self {
-                attributes: Ok(value.attributes),
-                producer: Ok(value.producer),
+                id: Ok(value.id),
+                version: Ok(value.version),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct SlsaProvenanceV02Predicate {
-        build_config: Result<Option<serde_json::Map<String, serde_json::Value>>, String>,
-        build_type: Result<String, String>,
-        builder: Result<super::Builder2, String>,
-        invocation: Result<Option<super::Invocation>, String>,
-        materials: Result<Option<Vec<super::ResourceDescriptor2>>, String>,
-        metadata: Result<Option<super::BuildMetadata2>, String>,
+    pub struct Vulnerability {
+        cve: Result<Option<String>, String>,
+        flags: Result<Vec<super::Flag>, String>,
+        ids: Result<Vec<super::VulnerabilityId>, String>,
+        notes: Result<Option<serde_json::Value>, String>,
+        product_status: Result<Option<super::ProductStatus>, String>,
+        remediations: Result<Vec<super::Remediation>, String>,
+        threats: Result<Vec<super::Threat>, String>,
     }
-    impl Default for SlsaProvenanceV02Predicate {
+    impl Default for Vulnerability {
         fn default() -> Self {
             Self {
-                build_config: Ok(Default::default()),
-                build_type: Err("no value supplied for build_type".to_string()),
-                builder: Err("no value supplied for builder".to_string()),
-                invocation: Ok(Default::default()),
-                materials: Ok(Default::default()),
-                metadata: Ok(Default::default()),
+                cve: Ok(Default::default()),
+                flags: Ok(Default::default()),
+                ids: Ok(Default::default()),
+                notes: Ok(Default::default()),
+                product_status: Ok(Default::default()),
+                remediations: Ok(Default::default()),
+                threats: Ok(Default::default()),
             }
         }
     }
-    impl SlsaProvenanceV02Predicate {
-        pub fn build_config<T>(mut self, value: T) -> Self
-        where
-            T: std::convert::TryInto<Option<serde_json::Map<String, serde_json::Value>>>,
-            T::Error: std::fmt::Display,
-        {
-            self
-                .build_config = value
-                .try_into()
-                .map_err(|e| {
-                    format!("error converting supplied value for build_config: {}", e)
-                });
-            self
-        }
-        pub fn build_type<T>(mut self, value: T) -> Self
+    impl Vulnerability {
+        pub fn cve<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<String>,
+            T: std::convert::TryInto<Option<String>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .build_type = value
+            self.cve = value
                 .try_into()
-                .map_err(|e| {
-                    format!("error converting supplied value for build_type: {}", e)
-                });
+                .map_err(|e| format!("error converting supplied value for cve: {}", e));
             self
         }
-        pub fn builder<T>(mut self, value: T) -> Self
+        pub fn flags<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<super::Builder2>,
+            T: std::convert::TryInto<Vec<super::Flag>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .builder = value
+            self.flags = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for builder: {}", e)
+                    format!("error converting supplied value for flags: {}", e)
                 });
             self
         }
-        pub fn invocation<T>(mut self, value: T) -> Self
+        pub fn ids<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::Invocation>>,
+            T: std::convert::TryInto<Vec<super::VulnerabilityId>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .invocation = value
+            self.ids = value
                 .try_into()
-                .map_err(|e| {
-                    format!("error converting supplied value for invocation: {}", e)
-                });
+                .map_err(|e| format!("error converting supplied value for ids: {}", e));
             self
         }
-        pub fn materials<T>(mut self, value: T) -> Self
+        pub fn notes<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<Vec<super::ResourceDescriptor2>>>,
+            T: std::convert::TryInto<Option<serde_json::Value>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .materials = value
+            self.notes = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for materials: {}", e)
+                    format!("error converting supplied value for notes: {}", e)
                 });
             self
         }
-        pub fn metadata<T>(mut self, value: T) -> Self
+        pub fn product_status<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<Option<super::BuildMetadata2>>,
+            T: std::convert::TryInto<Option<super::ProductStatus>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .metadata = value
+            self.product_status = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for metadata: {}", e)
+                    format!("error converting supplied value for product_status: {}", e)
                 });
             self
         }
-    }
-    impl std::convert::TryFrom<SlsaProvenanceV02Predicate>
-    for super::SlsaProvenanceV02Predicate {
-        type Error = String;
-        fn try_from(value: SlsaProvenanceV02Predicate) -> Result<Self, String> {
-            Ok(Self {
-                build_config: value.build_config?,
-                build_type: value.build_type?,
-                builder: value.builder?,
-                invocation: value.invocation?,
-                materials: value.materials?,
-                metadata: value.metadata?,
-            })
-        }
-    }
-    impl From<super::SlsaProvenanceV02Predicate> for SlsaProvenanceV02Predicate {
-        fn from(value: super::SlsaProvenanceV02Predicate) -> Self {
-            Self {
-                build_config: Ok(value.build_config),
-                build_type: Ok(value.build_type),
-                builder: Ok(value.builder),
-                invocation: Ok(value.invocation),
-                materials: Ok(value.materials),
-                metadata: Ok(value.metadata),
-            }
-        }
-    }
-    #[derive(Clone, Debug)]
-    pub struct SlsaProvenanceV1Predicate {
-        build_definition: Result<super::BuildDefinition, String>,
-        run_details: Result<super::RunDetails, String>,
-    }
-    impl Default for SlsaProvenanceV1Predicate {
-        fn default() -> Self {
-            Self {
-                build_definition: Err(
-                    "no value supplied for build_definition".to_string(),
-                ),
-                run_details: Err("no value supplied for run_details".to_string()),
-            }
-        }
-    }
-    impl SlsaProvenanceV1Predicate {
-        pub fn build_definition<T>(mut self, value: T) -> Self
+        pub fn remediations<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<super::BuildDefinition>,
+            T: std::convert::TryInto<Vec<super::Remediation>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .build_definition = value
+            self.remediations = value
                 .try_into()
                 .map_err(|e| {
-                    format!(
-                        "error converting supplied value for build_definition: {}", e
-                    )
+                    format!("error converting supplied value for remediations: {}", e)
                 });
             self
         }
-        pub fn run_details<T>(mut self, value: T) -> Self
+        pub fn threats<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<super::RunDetails>,
+            T: std::convert::TryInto<Vec<super::Threat>>,
             T::Error: std::fmt::Display,
         {
-            self
-                .run_details = value
+            self.threats = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for run_details: {}", e)
+                    format!("error converting supplied value for threats: {}", e)
                 });
             self
         }
     }
-    impl std::convert::TryFrom<SlsaProvenanceV1Predicate>
-    for super::SlsaProvenanceV1Predicate {
+    impl std::convert::TryFrom<Vulnerability> for super::Vulnerability {
         type Error = String;
-        fn try_from(value: SlsaProvenanceV1Predicate) -> Result<Self, String> {
+        fn try_from(value: Vulnerability) -> Result<Self, String> {
             Ok(Self {
-                build_definition: value.build_definition?,
-                run_details: value.run_details?,
+                cve: value.cve?,
+                flags: value.flags?,
+                ids: value.ids?,
+                notes: value.notes?,
+                product_status: value.product_status?,
+                remediations: value.remediations?,
+                threats: value.threats?,
             })
         }
     }
-    impl From<super::SlsaProvenanceV1Predicate> for SlsaProvenanceV1Predicate {
-        fn from(value: super::SlsaProvenanceV1Predicate) -> Self {
+    impl From<super::Vulnerability> for Vulnerability {
+        fn from(value: super::Vulnerability) -> Self {
             Self {
-                build_definition: Ok(value.build_definition),
-                run_details: Ok(value.run_details),
+                cve: Ok(value.cve),
+                flags: Ok(value.flags),
+                ids: Ok(value.ids),
+                notes: Ok(value.notes),
+                product_status: Ok(value.product_status),
+                remediations: Ok(value.remediations),
+                threats: Ok(value.threats),
             }
         }
     }
     #[derive(Clone, Debug)]
-    pub struct Subject {
-        digest: Result<super::DigestSet, String>,
-        name: Result<String, String>,
+    pub struct VulnerabilityId {
+        system_name: Result<String, String>,
+        text: Result<String, String>,
     }
-    impl Default for Subject {
+    impl Default for VulnerabilityId {
         fn default() -> Self {
             Self {
-                digest: Err("no value supplied for digest".to_string()),
-                name: Err("no value supplied for name".to_string()),
+                system_name: Err("no value supplied for system_name".to_string()),
+                text: Err("no value supplied for text".to_string()),
             }
         }
     }
-    impl Subject {
-        pub fn digest<T>(mut self, value: T) -> Self
+    impl VulnerabilityId {
+        pub fn system_name<T>(mut self, value: T) -> Self
         where
-            T: std::convert::TryInto<super::DigestSet>,
+            T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .digest = value
+            self.system_name = value
                 .try_into()
                 .map_err(|e| {
-                    format!("error converting supplied value for digest: {}", e)
+                    format!("error converting supplied value for system_name: {}", e)
                 });
             self
         }
-        pub fn name<T>(mut self, value: T) -> Self
+        pub fn text<T>(mut self, value: T) -> Self
         where
             T: std::convert::TryInto<String>,
             T::Error: std::fmt::Display,
         {
-            self
-                .name = value
+            self.text = value
                 .try_into()
-                .map_err(|e| format!("error converting supplied value for name: {}", e));
+                .map_err(|e| format!("error converting supplied value for text: {}", e));
             self
         }
     }
-    impl std::convert::TryFrom<Subject> for super::Subject {
+    impl std::convert::TryFrom<VulnerabilityId> for super::VulnerabilityId {
         type Error = String;
-        fn try_from(value: Subject) -> Result<Self, String> {
+        fn try_from(value: VulnerabilityId) -> Result<Self, String> {
             Ok(Self {
-                digest: value.digest?,
-                name: value.name?,
+                system_name: value.system_name?,
+                text: value.text?,
             })
         }
     }
-    impl From<super::Subject> for Subject {
-        fn from(value: super::Subject) -> Self {
+    impl From<super::VulnerabilityId> for VulnerabilityId {
+        fn from(value: super::VulnerabilityId) -> Self {
             Self {
-                digest: Ok(value.digest),
-                name: Ok(value.name),
+                system_name: Ok(value.system_name),
+                text: Ok(value.text),
             }
         }
     }
 }
+pub mod defaults {
+    pub(super) fn build_environment_contents() -> super::ApkContents {
+        super::ApkContents {}
+    }
+}
 